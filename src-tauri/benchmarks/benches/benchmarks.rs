@@ -34,13 +34,17 @@ use rules::action_handlers::actions::ExecuteAction;
 use rules::legality::legal_actions;
 use rules::legality::legal_actions::LegalActions;
 use rules::planner::spell_planner;
-use testing::ai_testing::test_games;
+use testing::ai_testing::{random_positions, test_games};
 use tracing::{subscriber, Level};
 use utils::command_line;
 use utils::command_line::CommandLine;
 
 criterion_main!(benches);
-criterion_group!(benches, vanilla, uct1, random_playout_evaluator);
+criterion_group!(benches, vanilla, legal_actions, uct1, random_playout_evaluator);
+
+/// Seed for the randomized board used in benchmarks, fixed so that results are
+/// comparable between runs.
+const RANDOM_POSITION_SEED: u64 = 5_729_302_183;
 
 pub fn vanilla(c: &mut Criterion) {
     command_line::FLAGS.set(CommandLine::default()).ok();
@@ -123,6 +127,32 @@ pub fn vanilla(c: &mut Criterion) {
     });
 }
 
+/// Benchmarks legal action generation across representative board states.
+///
+/// Run with `just legal-actions-benchmark` to record results in the
+/// cargo-criterion history under the current commit.
+pub fn legal_actions(c: &mut Criterion) {
+    command_line::FLAGS.set(CommandLine::default()).ok();
+    let mut group = c.benchmark_group("legal_actions");
+    group.significance_level(0.01).sample_size(500).noise_threshold(0.03);
+
+    let scenarios = [
+        ("empty_board", test_games::empty_board_scenario()),
+        ("twenty_permanents", test_games::large_board_scenario()),
+        ("full_stack", test_games::full_stack_scenario()),
+        ("random_position", random_positions::generate(RANDOM_POSITION_SEED)),
+    ];
+    for (name, game) in scenarios {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                legal_actions::compute(&game, PlayerName::One, LegalActions {
+                    for_human_player: false,
+                })
+            })
+        });
+    }
+}
+
 pub fn uct1(c: &mut Criterion) {
     command_line::FLAGS.set(CommandLine::default()).ok();
     let mut group = c.benchmark_group("uct1");
@@ -0,0 +1,139 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::card_name;
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::Damage;
+use data::events::event_context::EventContext;
+use data::game_states::ability_state::DamageRecipient;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use primitives::game_primitives::{
+    AbilityId, AbilityNumber, CardId, PermanentId, PlayerName, Zone,
+};
+use rules::dispatcher::dispatch;
+use rules::mutations::{damage, move_card};
+use rules::steps::step;
+use testing::ai_testing::test_game_builder::TestPlayer;
+use testing::ai_testing::test_games;
+
+#[test]
+fn prevention_shield_is_used_up_by_partial_prevention() {
+    let (mut game, bears) = new_game();
+    let context = context(&mut game, bears);
+    let two = DamageRecipient::Player(PlayerName::Two);
+    damage::prevent_next_this_turn(&mut game, context, two, 3).unwrap();
+
+    deal(&mut game, context, bears, two, 2);
+    assert_eq!(game.player(PlayerName::Two).life, 20);
+    deal(&mut game, context, bears, two, 2);
+    assert_eq!(game.player(PlayerName::Two).life, 19);
+    deal(&mut game, context, bears, two, 2);
+    assert_eq!(game.player(PlayerName::Two).life, 17);
+}
+
+#[test]
+fn prevent_all_shield_lasts_until_end_of_turn() {
+    let (mut game, bears) = new_game();
+    let context = context(&mut game, bears);
+    let two = DamageRecipient::Player(PlayerName::Two);
+    damage::prevent_all_this_turn(&mut game, context, two).unwrap();
+
+    deal(&mut game, context, bears, two, 5);
+    deal(&mut game, context, bears, two, 5);
+    assert_eq!(game.player(PlayerName::Two).life, 20);
+
+    step::advance_turns(&mut game, 1).unwrap();
+    deal(&mut game, context, bears, two, 1);
+    assert_eq!(game.player(PlayerName::Two).life, 19);
+}
+
+#[test]
+fn redirect_next_shield_redirects_once() {
+    let (mut game, bears) = new_game();
+    let context = context(&mut game, bears);
+    let target = permanent(&game, PlayerName::Two);
+    let two = DamageRecipient::Player(PlayerName::Two);
+    damage::redirect_next_this_turn(&mut game, context, two, DamageRecipient::Permanent(target))
+        .unwrap();
+
+    deal(&mut game, context, bears, two, 1);
+    assert_eq!(game.player(PlayerName::Two).life, 20);
+    assert_eq!(game.card(target).unwrap().damage, 1);
+    deal(&mut game, context, bears, two, 1);
+    assert_eq!(game.player(PlayerName::Two).life, 19);
+    assert_eq!(game.card(target).unwrap().damage, 1);
+}
+
+#[test]
+fn redirect_all_shield_redirects_all_damage_this_turn() {
+    let (mut game, bears) = new_game();
+    let context = context(&mut game, bears);
+    let target = permanent(&game, PlayerName::Two);
+    let two = DamageRecipient::Player(PlayerName::Two);
+    damage::redirect_all_this_turn(&mut game, context, two, DamageRecipient::Permanent(target))
+        .unwrap();
+
+    deal(&mut game, context, bears, two, 1);
+    deal(&mut game, context, bears, two, 1);
+    assert_eq!(game.player(PlayerName::Two).life, 20);
+    assert_eq!(game.card(target).unwrap().damage, 2);
+}
+
+#[test]
+fn damage_is_not_redirected_to_missing_permanent() {
+    let (mut game, bears) = new_game();
+    let context = context(&mut game, bears);
+    let target = permanent(&game, PlayerName::Two);
+    let two = DamageRecipient::Player(PlayerName::Two);
+    damage::redirect_next_this_turn(&mut game, context, two, DamageRecipient::Permanent(target))
+        .unwrap();
+    move_card::run(&mut game, context, target, Zone::Graveyard).unwrap();
+
+    deal(&mut game, context, bears, two, 2);
+    assert_eq!(game.player(PlayerName::Two).life, 18);
+}
+
+/// Creates a game in which each player controls a Grizzly Bears, returning the
+/// game and the [CardId] of player one's Grizzly Bears to use as the source
+/// of damage.
+fn new_game() -> (GameState, CardId) {
+    let player = TestPlayer::new().on_battlefield(card_name::GRIZZLY_BEARS);
+    let game = test_games::green_vanilla_scenario(player.clone(), player);
+    let card_id = game.card(permanent(&game, PlayerName::One)).unwrap().id;
+    (game, card_id)
+}
+
+/// Returns the [PermanentId] of the first permanent controlled by `player`.
+fn permanent(game: &GameState, player: PlayerName) -> PermanentId {
+    *game.battlefield(player).iter().next().expect("No permanents")
+}
+
+/// Returns an [EventContext] for an ability of the [CardId] card.
+fn context(game: &mut GameState, card_id: CardId) -> EventContext {
+    dispatch::build_invocation_context(game, AbilityId { card_id, number: AbilityNumber(0) })
+        .expect("Card not found")
+}
+
+/// Deals `amount` damage from the [CardId] card to `recipient`, which is
+/// skipped if all of the damage is prevented.
+fn deal(
+    game: &mut GameState,
+    context: EventContext,
+    card_id: CardId,
+    recipient: DamageRecipient,
+    amount: Damage,
+) {
+    let _ = damage::deal_damage(game, context, card_id, recipient, amount);
+}
@@ -44,7 +44,8 @@ fn run() -> Vec<GameAction> {
         actions::execute(&mut game, player, action, ExecuteAction {
             skip_undo_tracking: true,
             validate: false,
-        });
+        })
+        .unwrap();
     }
     result
 }
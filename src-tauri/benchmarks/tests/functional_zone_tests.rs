@@ -0,0 +1,130 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::card_name;
+use data::card_states::zones::ZoneQueries;
+use data::core::ability_scope::AbilityScope;
+use data::core::layer::Layer;
+use data::game_states::game_state::GameState;
+use data::properties::property_value::EnumSets;
+use enumset::{enum_set, EnumSet};
+use primitives::game_primitives::{
+    AbilityId, AbilityNumber, CardId, Color, PlayerName, Source, Zone,
+};
+use rules::mutations::move_card;
+use rules::queries::card_queries;
+use testing::ai_testing::test_game_builder::TestPlayer;
+use testing::ai_testing::test_games;
+use utils::outcome;
+
+#[test]
+fn graveyard_modifier_applies_only_in_graveyard() {
+    let (mut game, card_id) = new_game();
+    make_blue_in(&mut game, card_id, Zone::Graveyard.into());
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Green));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Graveyard).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Blue));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Exiled).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Green));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Battlefield).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Green));
+}
+
+#[test]
+fn exile_modifier_applies_only_in_exile() {
+    let (mut game, card_id) = new_game();
+    make_blue_in(&mut game, card_id, Zone::Exiled.into());
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Green));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Exiled).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Blue));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Graveyard).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Green));
+}
+
+#[test]
+fn modifier_applies_in_each_declared_zone() {
+    let (mut game, card_id) = new_game();
+    make_blue_in(&mut game, card_id, enum_set!(Zone::Graveyard | Zone::Exiled));
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Green));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Graveyard).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Blue));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Exiled).unwrap();
+    assert_eq!(colors(&game, card_id), EnumSet::only(Color::Blue));
+}
+
+#[test]
+fn validate_scope_accepts_declared_zones() {
+    let scope = scope(CardId::default(), enum_set!(Zone::Graveyard | Zone::Exiled));
+    assert_eq!(scope.validate_scope(Zone::Graveyard.into()), Ok(()));
+    assert_eq!(scope.validate_scope(enum_set!(Zone::Graveyard | Zone::Exiled)), Ok(()));
+    assert_eq!(scope.validate_scope(EnumSet::empty()), Ok(()));
+}
+
+#[test]
+fn validate_scope_rejects_undeclared_zones() {
+    let scope = scope(CardId::default(), Zone::Graveyard.into());
+    assert_eq!(scope.validate_scope(Zone::Battlefield.into()), Err(Zone::Battlefield.into()));
+    assert_eq!(
+        scope.validate_scope(enum_set!(Zone::Graveyard | Zone::Exiled | Zone::Command)),
+        Err(enum_set!(Zone::Exiled | Zone::Command))
+    );
+}
+
+#[test]
+#[should_panic(expected = "does not function in zones")]
+fn event_callback_in_undeclared_zone_panics() {
+    let (mut game, card_id) = new_game();
+    let scope = scope(card_id, Zone::Graveyard.into());
+    game.events.step_began.add_battlefield_ability(scope, |_, _, _| outcome::OK);
+}
+
+/// Creates a game in which player one has a Grizzly Bears in hand, returning
+/// the game and the [CardId] of that card.
+fn new_game() -> (GameState, CardId) {
+    let game = test_games::green_vanilla_scenario(
+        TestPlayer::new().in_hand(card_name::GRIZZLY_BEARS),
+        TestPlayer::new(),
+    );
+    let card_id = *game
+        .hand(PlayerName::One)
+        .iter()
+        .find(|&&id| game.card(id).unwrap().card_name == card_name::GRIZZLY_BEARS)
+        .expect("Grizzly Bears not found");
+    (game, card_id)
+}
+
+/// Adds a printed ability modifier to the [CardId] card which makes it blue
+/// while it is in one of the given `zones`.
+fn make_blue_in(game: &mut GameState, card_id: CardId, zones: EnumSet<Zone>) {
+    let timestamp = game.zones.new_timestamp();
+    game.card_mut(card_id).unwrap().properties.colors.add_ability(
+        scope(card_id, zones),
+        EnumSets::set(Layer::ColorChangingEffects, timestamp, Color::Blue),
+    );
+}
+
+fn scope(card_id: CardId, zones: EnumSet<Zone>) -> AbilityScope {
+    AbilityScope { ability_id: AbilityId { card_id, number: AbilityNumber(100) }, zones }
+}
+
+fn colors(game: &GameState, card_id: CardId) -> EnumSet<Color> {
+    card_queries::colors(game, Source::Game, card_id).unwrap()
+}
@@ -0,0 +1,67 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::card_name;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use enumset::EnumSet;
+use primitives::game_primitives::{PermanentId, PlayerName, Source};
+use rules::mutations::{permanents, turns};
+use rules::queries::combat_queries;
+use rules::steps::step;
+use testing::ai_testing::test_game_builder::TestPlayer;
+use testing::ai_testing::test_games;
+
+#[test]
+fn goad_ends_when_goading_players_next_turn_begins() {
+    let (mut game, bears) = new_game();
+    permanents::goad(&mut game, Source::Game, PlayerName::One, bears).unwrap();
+    assert_eq!(combat_queries::goaded_by(&game, bears), EnumSet::only(PlayerName::One));
+
+    step::advance_turns(&mut game, 1).unwrap();
+    assert_eq!(game.turn.active_player, PlayerName::Two);
+    assert_eq!(combat_queries::goaded_by(&game, bears), EnumSet::only(PlayerName::One));
+
+    step::advance_turns(&mut game, 1).unwrap();
+    assert_eq!(game.turn.active_player, PlayerName::One);
+    assert!(combat_queries::goaded_by(&game, bears).is_empty());
+    assert!(game.card(bears).unwrap().goaded.is_empty());
+}
+
+#[test]
+fn goad_lasts_through_extra_turns_of_other_players() {
+    let (mut game, bears) = new_game();
+    turns::take_extra_turn(&mut game, Source::Game, PlayerName::Two).unwrap();
+    permanents::goad(&mut game, Source::Game, PlayerName::One, bears).unwrap();
+
+    step::advance_turns(&mut game, 2).unwrap();
+    assert_eq!(game.turn.active_player, PlayerName::Two);
+    assert_eq!(combat_queries::goaded_by(&game, bears), EnumSet::only(PlayerName::One));
+
+    step::advance_turns(&mut game, 1).unwrap();
+    assert_eq!(game.turn.active_player, PlayerName::One);
+    assert!(combat_queries::goaded_by(&game, bears).is_empty());
+}
+
+/// Creates a game in player one's precombat main phase in which player two
+/// controls a Grizzly Bears, returning the game and the [PermanentId] of that
+/// permanent.
+fn new_game() -> (GameState, PermanentId) {
+    let game = test_games::green_vanilla_scenario(
+        TestPlayer::new(),
+        TestPlayer::new().on_battlefield(card_name::GRIZZLY_BEARS),
+    );
+    let bears = *game.battlefield(PlayerName::Two).iter().next().expect("No permanents");
+    (game, bears)
+}
@@ -0,0 +1,115 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::card_name;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::properties::duration::Duration;
+use primitives::game_primitives::{CardId, PlayerName, Source, Zone};
+use rules::mutations::{linked_exile, move_card};
+use rules::queries::card_queries;
+use rules::steps::step;
+use testing::ai_testing::test_game_builder::TestPlayer;
+use testing::ai_testing::test_games;
+
+#[test]
+fn exiled_card_is_linked() {
+    let (mut game, source) = new_game();
+    let card_id = top_of_library(&game);
+    let exiled = linked_exile::exile(&mut game, Source::Game, source, card_id).unwrap();
+    assert_eq!(exiled.internal_card_id, card_id);
+    assert_eq!(card_queries::linked_exile_cards(&game, source), vec![exiled]);
+}
+
+#[test]
+fn card_which_leaves_exile_and_returns_is_no_longer_linked() {
+    let (mut game, source) = new_game();
+    let card_id = top_of_library(&game);
+    linked_exile::exile(&mut game, Source::Game, source, card_id).unwrap();
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Hand).unwrap();
+    assert_eq!(card_queries::linked_exile_cards(&game, source), vec![]);
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Exiled).unwrap();
+    assert_eq!(game.card(card_id).unwrap().zone, Zone::Exiled);
+    assert_eq!(card_queries::linked_exile_cards(&game, source), vec![]);
+}
+
+#[test]
+fn impulse_draw_allows_playing_from_exile() {
+    let (mut game, source) = new_game();
+    let card_id = top_of_library(&game);
+    let duration = Duration::ThisTurn(game.turn);
+    linked_exile::impulse_draw(&mut game, Source::Game, source, PlayerName::One, duration).unwrap();
+    assert_eq!(game.card(card_id).unwrap().zone, Zone::Exiled);
+    assert!(card_queries::can_play_from_exile(&game, PlayerName::One, card_id));
+    assert!(!card_queries::can_play_from_exile(&game, PlayerName::Two, card_id));
+}
+
+#[test]
+fn play_permission_is_cleared_when_object_changes() {
+    let (mut game, source) = new_game();
+    let card_id = top_of_library(&game);
+    let duration = Duration::ThisTurn(game.turn);
+    linked_exile::impulse_draw(&mut game, Source::Game, source, PlayerName::One, duration).unwrap();
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Graveyard).unwrap();
+    assert!(!card_queries::can_play_from_exile(&game, PlayerName::One, card_id));
+
+    move_card::run(&mut game, Source::Game, card_id, Zone::Exiled).unwrap();
+    assert!(!card_queries::can_play_from_exile(&game, PlayerName::One, card_id));
+}
+
+#[test]
+fn play_permission_expires_with_duration() {
+    let (mut game, source) = new_game();
+    let card_id = top_of_library(&game);
+    let duration = Duration::ThisTurn(game.turn);
+    linked_exile::impulse_draw(&mut game, Source::Game, source, PlayerName::One, duration).unwrap();
+    step::advance_turns(&mut game, 1).unwrap();
+    assert_eq!(game.card(card_id).unwrap().zone, Zone::Exiled);
+    assert!(!card_queries::can_play_from_exile(&game, PlayerName::One, card_id));
+}
+
+#[test]
+fn return_to_hand_only_returns_linked_cards_in_exile() {
+    let (mut game, source) = new_game();
+    let returned = top_of_library(&game);
+    linked_exile::exile(&mut game, Source::Game, source, returned).unwrap();
+    let left_exile = top_of_library(&game);
+    linked_exile::exile(&mut game, Source::Game, source, left_exile).unwrap();
+    move_card::run(&mut game, Source::Game, left_exile, Zone::Graveyard).unwrap();
+    move_card::run(&mut game, Source::Game, left_exile, Zone::Exiled).unwrap();
+
+    linked_exile::return_to_hand(&mut game, Source::Game, source).unwrap();
+    assert_eq!(game.card(returned).unwrap().zone, Zone::Hand);
+    assert_eq!(game.card(left_exile).unwrap().zone, Zone::Exiled);
+}
+
+/// Creates a game in which player one controls a Grizzly Bears, returning the
+/// game and the [CardId] of that card to use as the source of linked exile
+/// effects.
+fn new_game() -> (GameState, CardId) {
+    let game = test_games::green_vanilla_scenario(
+        TestPlayer::new().on_battlefield(card_name::GRIZZLY_BEARS),
+        TestPlayer::new(),
+    );
+    let permanent_id = *game.battlefield(PlayerName::One).iter().next().expect("No permanents");
+    let card_id = game.card(permanent_id).unwrap().id;
+    (game, card_id)
+}
+
+fn top_of_library(game: &GameState) -> CardId {
+    *game.library(PlayerName::One).back().expect("Library is empty")
+}
@@ -12,5 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod damage_tests;
 pub mod determinism_tests;
+pub mod functional_zone_tests;
+pub mod goad_tests;
+pub mod linked_exile_tests;
+pub mod query_cache_tests;
 pub mod random_playout_evaluator_tests;
+pub mod search_checkpoint_tests;
+pub mod trigger_tests;
+pub mod turn_schedule_tests;
+pub mod two_headed_giant_tests;
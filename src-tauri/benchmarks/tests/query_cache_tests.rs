@@ -0,0 +1,126 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::game_action::GameAction;
+use data::card_states::zones::ZoneQueries;
+use data::decks::deck_name;
+use data::decks::deck_name::DeckName;
+use data::game_states::game_state::{GameState, GameStatus};
+use primitives::game_primitives::{EventId, PermanentId, PlayerName, Source, Zone};
+use rand::prelude::SliceRandom;
+use rand::SeedableRng;
+use rand_xoshiro::SplitMix64;
+use rules::action_handlers::actions;
+use rules::action_handlers::actions::ExecuteAction;
+use rules::legality::legal_actions;
+use rules::legality::legal_actions::LegalActions;
+use rules::mutations::{change_controller, move_card};
+use rules::queries::card_queries;
+use rules::steps::step;
+use testing::ai_testing::test_games;
+
+#[test]
+fn zone_moves() {
+    let mut game = test_games::large_board_scenario();
+    assert_cache_matches(&game);
+    let bear = first_permanent(&game, PlayerName::One);
+    move_card::run(&mut game, Source::Game, bear, Zone::Graveyard).unwrap();
+    assert_cache_matches(&game);
+    let bear = first_permanent(&game, PlayerName::Two);
+    move_card::run(&mut game, Source::Game, bear, Zone::Hand).unwrap();
+    assert_cache_matches(&game);
+}
+
+#[test]
+fn control_changes_and_expires() {
+    let mut game = test_games::large_board_scenario();
+    assert_cache_matches(&game);
+    let bear = first_permanent(&game, PlayerName::Two);
+    let event_id = EventId(game.zones.new_timestamp().0);
+    change_controller::gain_control_this_turn(
+        &mut game,
+        Source::Game,
+        PlayerName::One,
+        event_id,
+        bear,
+    )
+    .unwrap();
+    assert_cache_matches(&game);
+    step::advance_turns(&mut game, 1).unwrap();
+    assert_cache_matches(&game);
+}
+
+#[test]
+fn random_playout_grizzly_bears() {
+    random_playout(deck_name::GRIZZLY_BEAR_GIANT_GROWTH, 3298561);
+}
+
+#[test]
+fn random_playout_dandan() {
+    random_playout(deck_name::DANDAN, 8812047);
+}
+
+/// Plays a game with random legal actions, covering combat and effects such as
+/// Giant Growth expiring at end of turn, and checks the query cache after each
+/// action.
+fn random_playout(deck: DeckName, seed: u64) {
+    let mut game = test_games::create(deck);
+    let mut rng = SplitMix64::seed_from_u64(seed);
+    for _ in 0..1000 {
+        if matches!(game.status, GameStatus::GameOver { .. }) {
+            break;
+        }
+        assert_cache_matches(&game);
+        let player = legal_actions::next_to_act(&game, None).unwrap();
+        let legal = legal_actions::compute(&game, player, LegalActions { for_human_player: false });
+        let action: GameAction = *legal.choose(&mut rng).unwrap();
+        actions::execute(&mut game, player, action, ExecuteAction {
+            skip_undo_tracking: true,
+            validate: false,
+        })
+        .unwrap();
+    }
+}
+
+/// Checks that the cached power, toughness, and card types of each permanent
+/// match the values computed without the query cache.
+///
+/// Querying a permanent also populates its cache, so calling this before a
+/// mutation checks that the mutation invalidates cached results.
+fn assert_cache_matches(game: &GameState) {
+    for player in [PlayerName::One, PlayerName::Two] {
+        for &id in game.battlefield(player) {
+            let card_id = game.card(id).unwrap().id;
+            assert_eq!(
+                card_queries::card_types(game, Source::Game, id),
+                card_queries::compute_card_types(game, Source::Game, card_id),
+                "Card types of {id:?}"
+            );
+            assert_eq!(
+                card_queries::power(game, Source::Game, id),
+                card_queries::compute_power(game, Source::Game, card_id),
+                "Power of {id:?}"
+            );
+            assert_eq!(
+                card_queries::toughness(game, Source::Game, id),
+                card_queries::compute_toughness(game, Source::Game, card_id),
+                "Toughness of {id:?}"
+            );
+        }
+    }
+}
+
+fn first_permanent(game: &GameState, player: PlayerName) -> PermanentId {
+    *game.battlefield(player).iter().next().expect("No permanents")
+}
@@ -19,7 +19,7 @@ use ai::core::win_loss_evaluator::WinLossEvaluator;
 use ai::monte_carlo::monte_carlo_search::RandomPlayoutEvaluator;
 use data::decks::deck_name;
 use primitives::game_primitives::PlayerName;
-use testing::ai_testing::test_games;
+use testing::ai_testing::{random_positions, test_games};
 
 #[test]
 fn all_dandans() {
@@ -36,3 +36,13 @@ fn some_dandans() {
     let game = test_games::create(deck_name::SOME_DANDANS);
     evaluator.evaluate(&game, PlayerName::One);
 }
+
+#[test]
+fn random_positions() {
+    let evaluator =
+        RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData };
+    for seed in 0..10 {
+        let game = random_positions::generate(seed);
+        evaluator.evaluate(&game, game.turn.active_player);
+    }
+}
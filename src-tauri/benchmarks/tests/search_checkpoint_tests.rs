@@ -0,0 +1,112 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::env;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use ai::core::win_loss_evaluator::WinLossEvaluator;
+use ai::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use ai::monte_carlo::search_checkpoint;
+use ai::monte_carlo::search_checkpoint::{CheckpointOptions, SearchCheckpoint};
+use ai::monte_carlo::uct1::Uct1;
+use ai_core::core::monte_carlo_agent_state::{SearchEdge, SearchGraph, SearchNode};
+use testing::nim::nim_game::{NimAction, NimPile, NimPlayer, NimState};
+use utils::command_line;
+use utils::command_line::CommandLine;
+use uuid::Uuid;
+
+#[test]
+pub fn zero_interval_is_rejected() {
+    assert!(CheckpointOptions::new(PathBuf::from("checkpoint.json"), 0, false).is_err());
+}
+
+#[test]
+pub fn positive_interval_is_accepted() {
+    let options = CheckpointOptions::new(PathBuf::from("checkpoint.json"), 250, true).unwrap();
+    assert_eq!(options.interval(), 250);
+    assert!(options.resume());
+}
+
+#[test]
+pub fn resumes_from_checkpoint() {
+    let path = checkpoint_path();
+    write_checkpoint(&path, NimAction { pile: NimPile::PileA, amount: 1 }, 40);
+    assert_eq!(first_iteration(&path), 40);
+}
+
+#[test]
+pub fn ignores_checkpoint_for_different_state() {
+    let path = checkpoint_path();
+    write_checkpoint(&path, NimAction { pile: NimPile::PileA, amount: 10 }, 40);
+    assert_eq!(first_iteration(&path), 0);
+}
+
+#[test]
+pub fn ignores_unreadable_checkpoint() {
+    let path = checkpoint_path();
+    std::fs::write(&path, "not a checkpoint").unwrap();
+    assert_eq!(first_iteration(&path), 0);
+}
+
+#[test]
+pub fn removes_checkpoint_after_search() {
+    let path = checkpoint_path();
+    write_checkpoint(&path, NimAction { pile: NimPile::PileA, amount: 1 }, 40);
+    first_iteration(&path);
+    assert!(!path.exists());
+}
+
+fn checkpoint_path() -> PathBuf {
+    env::temp_dir().join(format!("search_checkpoint_{}.json", Uuid::new_v4()))
+}
+
+/// Writes a checkpoint with a single explored `action` for the first player.
+fn write_checkpoint(path: &Path, action: NimAction, iterations: u32) {
+    let mut graph = SearchGraph::new();
+    let root =
+        graph.add_node(SearchNode { player: NimPlayer::One, total_reward: 0.0, visit_count: 1 });
+    let child =
+        graph.add_node(SearchNode { player: NimPlayer::One, total_reward: 1.0, visit_count: 1 });
+    graph.add_edge(root, child, SearchEdge { action });
+    search_checkpoint::save(path, &SearchCheckpoint { graph, root, iterations, best_action: None })
+        .unwrap();
+}
+
+/// Runs a resumable search over a small Nim game for 10 iterations and returns
+/// the iteration number the search started from.
+fn first_iteration(path: &Path) -> u32 {
+    command_line::FLAGS.set(CommandLine::default()).ok();
+    let algorithm = MonteCarloAlgorithm {
+        child_score_algorithm: Uct1 {},
+        max_iterations: None,
+        checkpoint: Some(CheckpointOptions::new(path.to_path_buf(), 5, true).unwrap()),
+        phantom_data: PhantomData,
+    };
+    let evaluator =
+        RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData };
+    let first = Cell::new(None);
+    algorithm.run_search(
+        |i| {
+            let start = *first.get().get_or_insert(i);
+            first.set(Some(start));
+            i >= start + 10
+        },
+        &NimState::new(3),
+        &evaluator,
+        NimPlayer::One,
+    );
+    first.get().unwrap()
+}
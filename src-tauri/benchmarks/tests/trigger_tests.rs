@@ -0,0 +1,179 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Mutex;
+
+use data::card_definitions::card_name;
+use data::card_states::zones::ZoneQueries;
+use data::core::ability_scope::AbilityScope;
+use data::events::event_context::EventContext;
+use data::events::game_events::StepBeganEvent;
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use enumset::EnumSet;
+use primitives::game_primitives::{AbilityId, AbilityNumber, CardId, GameId, PlayerName, Source};
+use rules::mutations::turns;
+use rules::predicates::trigger_conditions;
+use rules::steps::step;
+use testing::ai_testing::test_game_builder::TestPlayer;
+use testing::ai_testing::test_games;
+use utils::outcome;
+
+/// Record of a watched trigger firing.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Fired {
+    game_id: GameId,
+    number: usize,
+    active_player: PlayerName,
+    step: GamePhaseStep,
+}
+
+/// Triggers which have fired during any test.
+///
+/// Event callbacks cannot capture mutable state, so results are recorded here
+/// and filtered by [GameId].
+static FIRED: Mutex<Vec<Fired>> = Mutex::new(Vec::new());
+
+#[test]
+fn your_end_step_fires_in_your_end_step() {
+    let mut game = new_game();
+    let one = permanent_card(&game, PlayerName::One);
+    watch(&mut game, one, 100, trigger_conditions::beginning_of_your(GamePhaseStep::EndStep));
+    play_until_next_turn(&mut game);
+    assert_eq!(fired(&game, 100), vec![(PlayerName::One, GamePhaseStep::EndStep)]);
+}
+
+#[test]
+fn each_upkeep_fires_in_each_upkeep() {
+    let mut game = new_game();
+    let two = permanent_card(&game, PlayerName::Two);
+    watch(&mut game, two, 100, trigger_conditions::beginning_of_each(GamePhaseStep::Upkeep));
+    play_until_next_turn(&mut game);
+    assert_eq!(fired(&game, 100), vec![
+        (PlayerName::Two, GamePhaseStep::Upkeep),
+        (PlayerName::One, GamePhaseStep::Upkeep),
+    ]);
+}
+
+#[test]
+fn trigger_created_mid_step_waits_for_next_occurrence() {
+    let mut game = new_game();
+    let one = permanent_card(&game, PlayerName::One);
+
+    // The precombat main phase has already begun, so this should not fire
+    // until the next precombat main phase.
+    let main = trigger_conditions::beginning_of_each(GamePhaseStep::PreCombatMain);
+    watch(&mut game, one, 100, main);
+    play_until_next_turn(&mut game);
+    assert_eq!(fired(&game, 100), vec![
+        (PlayerName::Two, GamePhaseStep::PreCombatMain),
+        (PlayerName::One, GamePhaseStep::PreCombatMain),
+    ]);
+}
+
+#[test]
+fn trigger_created_during_step_event_waits_for_next_occurrence() {
+    let mut game = new_game();
+    let two = permanent_card(&game, PlayerName::Two);
+    let registers = AbilityScope {
+        ability_id: AbilityId { card_id: two, number: AbilityNumber(100) },
+        zones: EnumSet::all(),
+    };
+    game.events.step_began.add_battlefield_ability(registers, |game, context, event| {
+        if event.step == GamePhaseStep::Upkeep && event.active_player == PlayerName::Two {
+            let upkeep = trigger_conditions::beginning_of_each(GamePhaseStep::Upkeep);
+            watch(game, context.this.card_id, 101, upkeep);
+        }
+        outcome::OK
+    });
+    play_until_next_turn(&mut game);
+    assert_eq!(fired(&game, 101), vec![(PlayerName::One, GamePhaseStep::Upkeep)]);
+}
+
+#[test]
+fn skipped_step_does_not_fire() {
+    let mut game = new_game();
+    let two = permanent_card(&game, PlayerName::Two);
+    watch(&mut game, two, 100, trigger_conditions::beginning_of_your(GamePhaseStep::EndStep));
+    turns::skip_next_step(&mut game, Source::Game, PlayerName::Two, GamePhaseStep::EndStep)
+        .unwrap();
+    play_until_next_turn(&mut game);
+    assert_eq!(fired(&game, 100), vec![]);
+}
+
+/// Creates a game in player one's precombat main phase in which each player
+/// controls a Grizzly Bears.
+fn new_game() -> GameState {
+    let player = TestPlayer::new().on_battlefield(card_name::GRIZZLY_BEARS);
+    let game = test_games::green_vanilla_scenario(player.clone(), player);
+    assert_eq!(game.turn.active_player, PlayerName::One);
+    game
+}
+
+/// Advances the game without taking any game actions until the start of
+/// player one's precombat main phase on their next turn.
+fn play_until_next_turn(game: &mut GameState) {
+    let start = game.turn;
+    while game.turn == start
+        || game.turn.active_player != PlayerName::One
+        || game.step != GamePhaseStep::PreCombatMain
+    {
+        step::skip_step(game).unwrap();
+    }
+}
+
+/// Returns the [CardId] of the first permanent controlled by `player`.
+fn permanent_card(game: &GameState, player: PlayerName) -> CardId {
+    let permanent_id = game.battlefield(player).iter().next().expect("No permanents");
+    game.card(*permanent_id).expect("Card not found").id
+}
+
+/// Returns the active player and step of each time the watched trigger with
+/// the given `number` fired in this game.
+fn fired(game: &GameState, number: usize) -> Vec<(PlayerName, GamePhaseStep)> {
+    FIRED
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|f| f.game_id == game.id && f.number == number)
+        .map(|f| (f.active_player, f.step))
+        .collect()
+}
+
+/// Registers a callback for a triggered ability of the [CardId] card which
+/// records each time `condition` is true.
+fn watch(
+    game: &mut GameState,
+    card_id: CardId,
+    number: usize,
+    condition: impl Fn(&GameState, EventContext, &StepBeganEvent) -> Option<bool>
+        + Copy
+        + Send
+        + Sync
+        + 'static,
+) {
+    let ability_id = AbilityId { card_id, number: AbilityNumber(number) };
+    let scope = AbilityScope { ability_id, zones: EnumSet::all() };
+    game.events.step_began.add_battlefield_ability(scope, move |game, context, event| {
+        if condition(game, context, event) == Some(true) {
+            FIRED.lock().unwrap().push(Fired {
+                game_id: game.id,
+                number,
+                active_player: event.active_player,
+                step: event.step,
+            });
+        }
+        outcome::OK
+    });
+}
@@ -0,0 +1,134 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::TurnNumber;
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::{GameState, TurnData};
+use primitives::game_primitives::{PlayerName, Source};
+use rules::mutations::turns;
+use rules::steps::step;
+use testing::ai_testing::test_game_builder::TestPlayer;
+use testing::ai_testing::test_games;
+
+#[test]
+fn extra_turn_is_taken_after_current_turn() {
+    let mut game = new_game();
+    turns::take_extra_turn(&mut game, Source::Game, PlayerName::One).unwrap();
+    assert_eq!(next_turns(&mut game, 3), vec![
+        turn(1, PlayerName::One),
+        turn(2, PlayerName::Two),
+        turn(3, PlayerName::One),
+    ]);
+}
+
+#[test]
+fn most_recent_extra_turn_is_taken_first() {
+    let mut game = new_game();
+    turns::take_extra_turn(&mut game, Source::Game, PlayerName::One).unwrap();
+    turns::take_extra_turn(&mut game, Source::Game, PlayerName::Two).unwrap();
+    assert_eq!(next_turns(&mut game, 3), vec![
+        turn(1, PlayerName::Two),
+        turn(2, PlayerName::One),
+        turn(3, PlayerName::Two),
+    ]);
+}
+
+#[test]
+fn skipped_turn_does_not_use_a_turn_number() {
+    let mut game = new_game();
+    turns::skip_next_turn(&mut game, Source::Game, PlayerName::Two).unwrap();
+    assert_eq!(next_turns(&mut game, 2), vec![turn(1, PlayerName::One), turn(2, PlayerName::Two)]);
+}
+
+#[test]
+fn additional_combat_phase_is_added_after_main_phase() {
+    let mut game = new_game();
+    turns::add_combat_phase(&mut game, Source::Game).unwrap();
+    let steps = steps_until_next_turn(&mut game);
+    let count = |step| steps.iter().filter(|&&s| s == step).count();
+    assert_eq!(count(GamePhaseStep::BeginCombat), 2);
+    assert_eq!(count(GamePhaseStep::PostCombatMain), 2);
+    assert_eq!(game.turn_schedule.additional_combat_phases, 0);
+}
+
+#[test]
+fn additional_combat_phase_ends_with_turn() {
+    let mut game = new_game();
+    step::advance_to(&mut game, GamePhaseStep::EndStep).unwrap();
+    turns::add_combat_phase(&mut game, Source::Game).unwrap();
+    step::advance_turns(&mut game, 1).unwrap();
+    let steps = steps_until_next_turn(&mut game);
+    assert_eq!(steps.iter().filter(|&&s| s == GamePhaseStep::BeginCombat).count(), 1);
+}
+
+#[test]
+fn skipped_draw_step_does_not_draw() {
+    let mut game = new_game();
+    let hand = game.hand(PlayerName::Two).len();
+    turns::skip_next_step(&mut game, Source::Game, PlayerName::Two, GamePhaseStep::Draw).unwrap();
+    step::advance_turns(&mut game, 1).unwrap();
+    assert_eq!(game.turn.active_player, PlayerName::Two);
+    assert_eq!(game.hand(PlayerName::Two).len(), hand);
+    assert!(game.turn_schedule.skipped.is_empty());
+}
+
+#[test]
+fn combat_steps_cannot_be_skipped_individually() {
+    let mut game = new_game();
+    let declare_attackers = GamePhaseStep::DeclareAttackers;
+    assert!(
+        turns::skip_next_step(&mut game, Source::Game, PlayerName::One, declare_attackers).is_err()
+    );
+    assert!(game.turn_schedule.skipped.is_empty());
+}
+
+/// Creates a game in player one's precombat main phase on the first turn.
+fn new_game() -> GameState {
+    let game = test_games::green_vanilla_scenario(TestPlayer::new(), TestPlayer::new());
+    assert_eq!(game.turn, turn(0, PlayerName::One));
+    game
+}
+
+fn turn(turn_number: TurnNumber, active_player: PlayerName) -> TurnData {
+    TurnData { turn_number, active_player }
+}
+
+/// Advances the game without taking any game actions, returning the next
+/// `count` turns which begin.
+fn next_turns(game: &mut GameState, count: usize) -> Vec<TurnData> {
+    let mut result = vec![];
+    while result.len() < count {
+        let turn = game.turn;
+        while game.turn == turn {
+            step::skip_step(game).unwrap();
+        }
+        result.push(game.turn);
+    }
+    result
+}
+
+/// Advances the game without taking any game actions until the next turn
+/// begins, returning each step which began during the current turn.
+fn steps_until_next_turn(game: &mut GameState) -> Vec<GamePhaseStep> {
+    let turn = game.turn;
+    let mut steps = vec![];
+    loop {
+        step::skip_step(game).unwrap();
+        if game.turn != turn {
+            return steps;
+        }
+        steps.push(game.step);
+    }
+}
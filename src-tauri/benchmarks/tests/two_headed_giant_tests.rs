@@ -0,0 +1,83 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::decks::deck_name;
+use data::game_states::game_state::{GameStatus, TeamConfiguration};
+use enumset::EnumSet;
+use primitives::game_primitives::PlayerName;
+use rules::queries::player_queries;
+use rules::steps::step;
+use testing::ai_testing::test_games;
+
+#[test]
+pub fn all_four_players_draw_opening_hands() {
+    let game = test_games::create_two_headed_giant(deck_name::GREEN_VANILLA);
+    assert_eq!(game.configuration.all_players, EnumSet::all());
+    assert_eq!(game.configuration.teams, Some(TeamConfiguration::two_headed_giant()));
+    assert_eq!(game.status, GameStatus::Playing);
+    for player in EnumSet::<PlayerName>::all().iter() {
+        assert_eq!(game.hand(player).len(), 7);
+        assert!(!game.library(player).is_empty());
+    }
+}
+
+#[test]
+pub fn teammates_are_not_opponents() {
+    let game = test_games::create_two_headed_giant(deck_name::GREEN_VANILLA);
+    assert_eq!(
+        player_queries::all_opponents(&game, PlayerName::One),
+        PlayerName::Two | PlayerName::Four
+    );
+    assert_eq!(player_queries::team(&game, PlayerName::Three), PlayerName::One | PlayerName::Three);
+    assert_eq!(player_queries::next_opponent_after(&game, PlayerName::One), PlayerName::Two);
+}
+
+#[test]
+pub fn teams_take_turns_together() {
+    let mut game = test_games::create_two_headed_giant(deck_name::GREEN_VANILLA);
+    let mut turns = vec![game.turn];
+    while turns.len() < 4 {
+        step::skip_step(&mut game).unwrap();
+        if game.turn != turns[turns.len() - 1] {
+            turns.push(game.turn);
+        }
+    }
+    assert_eq!(turns.iter().map(|turn| turn.active_player).collect::<Vec<_>>(), vec![
+        PlayerName::One,
+        PlayerName::Two,
+        PlayerName::One,
+        PlayerName::Two
+    ]);
+    assert_eq!(turns.iter().map(|turn| turn.turn_number).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+pub fn turn_passes_to_remaining_teammate() {
+    let mut game = test_games::create_two_headed_giant(deck_name::GREEN_VANILLA);
+    game.configuration.all_players.remove(PlayerName::One);
+    assert_eq!(player_queries::next_opponent_after(&game, PlayerName::Two), PlayerName::Three);
+    assert_eq!(player_queries::next_opponent_after(&game, PlayerName::Three), PlayerName::Two);
+}
+
+#[test]
+pub fn history_counters_are_tracked_for_each_player() {
+    let mut game = test_games::create_two_headed_giant(deck_name::GREEN_VANILLA);
+    for (i, player) in EnumSet::<PlayerName>::all().iter().enumerate() {
+        game.history_counters_mut(player).lands_played = i;
+    }
+    for (i, player) in EnumSet::<PlayerName>::all().iter().enumerate() {
+        assert_eq!(game.history_counters(player).lands_played, i);
+    }
+}
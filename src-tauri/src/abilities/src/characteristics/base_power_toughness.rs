@@ -20,7 +20,8 @@ use data::game_states::game_state::GameState;
 use data::properties::duration::Duration;
 use data::properties::property_value::Ints;
 use primitives::game_primitives::{HasSource, PermanentId};
-use utils::outcome::Outcome;
+use rules::mutations::expiring_effects;
+use utils::outcome::{OrSkip, Outcome};
 
 /// Sets a card's base power and toughness for the current turn
 pub fn set_this_turn(
@@ -31,14 +32,16 @@ pub fn set_this_turn(
     toughness: Toughness,
 ) -> Outcome {
     let turn = game.turn;
-    game.card_mut(id)?.properties.base_power.add_effect(
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    game.card_mut(id).or_skip()?.properties.base_power.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(id, turn),
+        duration,
         Ints::set(Layer::PowerToughnessSettingEffects, context, power),
-    );
-    game.card_mut(id)?.properties.base_toughness.add_effect(
+    )?;
+    game.card_mut(id).or_skip()?.properties.base_toughness.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(id, turn),
+        duration,
         Ints::set(Layer::PowerToughnessSettingEffects, context, toughness),
     )
 }
@@ -22,8 +22,9 @@ use data::properties::property_value::{ChangeText, EnumSets};
 use data::text_strings::Text;
 use either::Either;
 use primitives::game_primitives::{Color, HasSource, PermanentId, PlayerName, SpellId, COLORS};
+use rules::mutations::expiring_effects;
 use rules::prompt_handling::prompts;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 pub type LandSubtypesOrColors = Either<(LandType, LandType), (Color, Color)>;
 
@@ -57,9 +58,11 @@ fn change_permanent_color_text(
     new_color: Color,
 ) -> Outcome {
     let turn = game.turn;
-    game.card_mut(permanent_id)?.properties.change_color_text.add_effect(
+    let duration = Duration::WhileOnBattlefieldThisTurn(permanent_id, turn);
+    expiring_effects::add_modifiers(game, context.event_id, permanent_id, duration)?;
+    game.card_mut(permanent_id).or_skip()?.properties.change_color_text.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(permanent_id, turn),
+        duration,
         ChangeText::replace(context.event_id, old_color, new_color),
     )
 }
@@ -71,9 +74,11 @@ fn change_spell_color_text(
     old_color: Color,
     new_color: Color,
 ) -> Outcome {
-    game.card_mut(spell_id)?.properties.change_color_text.add_effect(
+    let duration = Duration::WhileOnStackOrBattlefieldThisTurn(spell_id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, spell_id, duration)?;
+    game.card_mut(spell_id).or_skip()?.properties.change_color_text.add_effect(
         context,
-        Duration::WhileOnStackOrBattlefieldThisTurn(spell_id, context.current_turn),
+        duration,
         ChangeText::replace(context.event_id, old_color, new_color),
     )
 }
@@ -86,14 +91,16 @@ fn change_permanent_land_type_text(
     new_type: LandType,
 ) -> Outcome {
     let turn = game.turn;
-    game.card_mut(permanent_id)?.properties.land_types.add_effect(
+    let duration = Duration::WhileOnBattlefieldThisTurn(permanent_id, turn);
+    expiring_effects::add_modifiers(game, context.event_id, permanent_id, duration)?;
+    game.card_mut(permanent_id).or_skip()?.properties.land_types.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(permanent_id, turn),
+        duration,
         EnumSets::replace(Layer::TextChangingEffects, context.event_id, old_type, new_type),
-    );
-    game.card_mut(permanent_id)?.properties.change_land_type_text.add_effect(
+    )?;
+    game.card_mut(permanent_id).or_skip()?.properties.change_land_type_text.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(permanent_id, turn),
+        duration,
         ChangeText::replace(context.event_id, old_type, new_type),
     )
 }
@@ -106,14 +113,16 @@ fn change_spell_land_type_text(
     old_type: LandType,
 ) -> Outcome {
     let turn = game.turn;
-    game.card_mut(spell_id)?.properties.land_types.add_effect(
+    let duration = Duration::WhileOnStackOrBattlefieldThisTurn(spell_id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, spell_id, duration)?;
+    game.card_mut(spell_id).or_skip()?.properties.land_types.add_effect(
         context,
-        Duration::WhileOnStackOrBattlefieldThisTurn(spell_id, context.current_turn),
+        duration,
         EnumSets::replace(Layer::TextChangingEffects, context.event_id, old_type, new_type),
-    );
-    game.card_mut(spell_id)?.properties.change_land_type_text.add_effect(
+    )?;
+    game.card_mut(spell_id).or_skip()?.properties.change_land_type_text.add_effect(
         context,
-        Duration::WhileOnStackOrBattlefieldThisTurn(spell_id, context.current_turn),
+        duration,
         ChangeText::replace(context.event_id, old_type, new_type),
     )
 }
@@ -20,8 +20,9 @@ use data::properties::duration::Duration;
 use data::properties::property_value::EnumSets;
 use enumset::EnumSet;
 use primitives::game_primitives::{Color, HasSource, PermanentId};
+use rules::mutations::expiring_effects;
 use rules::queries::text_change_queries;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 pub fn set_this_turn(
     game: &mut GameState,
@@ -30,9 +31,11 @@ pub fn set_this_turn(
     new_color: Color,
 ) -> Outcome {
     let color = text_change_queries::color(game, context.source(), new_color);
-    game.card_mut(id)?.properties.colors.add_effect(
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    game.card_mut(id).or_skip()?.properties.colors.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(id, context.current_turn),
+        duration,
         EnumSets::set(Layer::ColorChangingEffects, context, color),
     )
 }
@@ -21,7 +21,8 @@ use data::properties::duration::Duration;
 use data::properties::property_value::EnumSets;
 use enumset::EnumSet;
 use primitives::game_primitives::{Color, HasSource, PermanentId};
-use utils::outcome::Outcome;
+use rules::mutations::expiring_effects;
+use utils::outcome::{OrSkip, Outcome};
 
 /// Sets a card's creature subtypes for the current turn
 pub fn set_this_turn(
@@ -30,9 +31,11 @@ pub fn set_this_turn(
     id: PermanentId,
     colors: impl Into<EnumSet<CreatureType>>,
 ) -> Outcome {
-    game.card_mut(id)?.properties.creature_types.add_effect(
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    game.card_mut(id).or_skip()?.properties.creature_types.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(id, context.current_turn),
+        duration,
         EnumSets::set(Layer::TypeChangingEffects, context, colors.into()),
     )
 }
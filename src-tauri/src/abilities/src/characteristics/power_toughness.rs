@@ -19,7 +19,8 @@ use data::game_states::game_state::GameState;
 use data::properties::duration::Duration;
 use data::properties::property_value::Ints;
 use primitives::game_primitives::{HasSource, PermanentId};
-use utils::outcome::Outcome;
+use rules::mutations::expiring_effects;
+use utils::outcome::{OrSkip, Outcome};
 
 /// Adds to a card's power and toughness for the current turn
 pub fn add_this_turn(
@@ -29,14 +30,12 @@ pub fn add_this_turn(
     power: Power,
     toughness: Toughness,
 ) -> Outcome {
-    game.card_mut(id)?.properties.power.add_effect(
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    game.card_mut(id).or_skip()?.properties.power.add_effect(context, duration, Ints::add(power))?;
+    game.card_mut(id).or_skip()?.properties.toughness.add_effect(
         context,
-        Duration::WhileOnBattlefieldThisTurn(id, context.current_turn),
-        Ints::add(power),
-    );
-    game.card_mut(id)?.properties.toughness.add_effect(
-        context,
-        Duration::WhileOnBattlefieldThisTurn(id, context.current_turn),
+        duration,
         Ints::add(toughness),
     )
 }
@@ -0,0 +1,36 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, StaticAbility};
+use data::core::modifier_data::{ConditionFn, ModifierMode};
+use data::properties::card_properties::CardProperties;
+
+/// A static ability which modifies this card only as long as `condition`
+/// holds, e.g. "As long as you control three or more artifacts, this creature
+/// gets +2/+2."
+///
+/// The `properties` function receives a [ModifierMode] which should be used
+/// to register each modifier for the ability. The condition is evaluated each
+/// time a modified property is queried, so no events are needed to add or
+/// remove the effect as the game state changes.
+///
+/// See [rules::predicates::static_conditions] for common conditions.
+pub fn ability(
+    condition: ConditionFn,
+    properties: impl Fn(ModifierMode, &mut CardProperties) + 'static + Copy + Send + Sync,
+) -> impl Ability {
+    StaticAbility::new().properties(move |scope, card_properties| {
+        properties(ModifierMode::PrintedAbilityWhile(scope, condition), card_properties);
+    })
+}
@@ -20,15 +20,18 @@ use data::printed_cards::card_subtypes::CreatureType;
 use data::properties::duration::Duration;
 use enumset::EnumSet;
 use primitives::game_primitives::PermanentId;
+use rules::mutations::expiring_effects;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 /// Marks a permanent as having lost all abilities while it is on the
 /// battlefield this turn.
 pub fn set_this_turn(game: &mut GameState, context: EventContext, id: PermanentId) -> Outcome {
-    game.card_mut(id)?.lost_all_abilities.push(LostAllAbilities {
-        duration: Duration::WhileOnBattlefieldThisTurn(id, context.current_turn),
-        timestamp: context.timestamp(),
-    });
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    game.card_mut(id)
+        .or_skip()?
+        .lost_all_abilities
+        .push(LostAllAbilities { duration, timestamp: context.timestamp() });
     outcome::OK
 }
@@ -0,0 +1,51 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::events::event_context::EventContext;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{CardType, HasSource};
+use rules::mutations::library;
+use rules::mutations::library::IfNotCast;
+use rules::queries::card_queries;
+use utils::outcome::{OrSkip, Outcome};
+
+/// Applies the effect of the cascade ability of the card which owns the
+/// [EventContext] ability.
+///
+/// > 702.85a. Cascade is a triggered ability that functions only while the
+/// > spell with cascade is on the stack. "Cascade" means "When you cast this
+/// > spell, exile cards from the top of your library until you exile a nonland
+/// > card whose mana value is less than this spell's mana value. You may cast
+/// > that card without paying its mana cost if its mana value is less than this
+/// > spell's mana value. Then put all cards exiled this way that weren't cast
+/// > on the bottom of your library in a random order."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70285a>
+pub fn cascade(game: &mut GameState, context: EventContext) -> Outcome {
+    let mana_value =
+        card_queries::mana_value(game, context.source(), context.this.card_id).or_skip()?;
+    library::exile_until_and_cast(
+        game,
+        context,
+        context.controller,
+        move |g, s, id| {
+            let card_types = card_queries::card_types(g, s, id)?;
+            Some(
+                !card_types.contains(CardType::Land)
+                    && card_queries::mana_value(g, s, id)? < mana_value,
+            )
+        },
+        IfNotCast::PutOnBottom,
+    )
+}
@@ -0,0 +1,47 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::numerics::ManaValue;
+use data::events::event_context::EventContext;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::CardType;
+use rules::mutations::library;
+use rules::mutations::library::IfNotCast;
+use rules::queries::card_queries;
+use utils::outcome::Outcome;
+
+/// Causes the controller of the [EventContext] ability to discover `value`.
+///
+/// > 701.57a. "Discover N" means "Exile cards from the top of your library
+/// > until you exile a nonland card with mana value N or less. You may cast
+/// > that card without paying its mana cost. If you don't cast it, put that
+/// > card into your hand. Put the remaining exiled cards on the bottom of your
+/// > library in a random order."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70157a>
+pub fn discover(game: &mut GameState, context: EventContext, value: ManaValue) -> Outcome {
+    library::exile_until_and_cast(
+        game,
+        context,
+        context.controller,
+        move |g, s, id| {
+            let card_types = card_queries::card_types(g, s, id)?;
+            Some(
+                !card_types.contains(CardType::Land)
+                    && card_queries::mana_value(g, s, id)? <= value,
+            )
+        },
+        IfNotCast::PutIntoHand,
+    )
+}
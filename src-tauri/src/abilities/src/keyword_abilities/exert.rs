@@ -0,0 +1,35 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, StaticAbility};
+use data::core::card_tags::CardTag;
+use data::core::modifier_data::ModifierMode;
+use data::properties::property_value::EnumSets;
+
+/// Marks a creature as having the option to be exerted as it attacks.
+///
+/// The attacking player is prompted to exert this creature when attackers are
+/// confirmed. Effects which happen "when you do" should be implemented as
+/// triggers on the `exerted` card event.
+///
+/// > 701.43a. To exert a permanent, you choose to have it not untap during
+/// > your next untap step.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70143a>
+pub fn ability() -> impl Ability {
+    StaticAbility::new().properties(|scope, properties| {
+        let mode = ModifierMode::PrintedAbility(scope);
+        let _ = properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Exert));
+    })
+}
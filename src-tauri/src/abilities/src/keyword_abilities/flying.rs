@@ -28,8 +28,9 @@ use enumset::EnumSet;
 use primitives::game_primitives::{
     HasSource, PermanentId, Source, Timestamp, PRINTED_TEXT_TIMESTAMP,
 };
+use rules::mutations::expiring_effects;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 /// The Flying ability.
 ///
@@ -45,17 +46,22 @@ use utils::outcome::Outcome;
 /// <https://yawgatog.com/resources/magic-rules/#R7029>
 pub fn ability() -> impl Ability {
     StaticAbility::new().properties(|scope, properties| {
-        gain(ModifierMode::PrintedAbility(scope), properties);
+        let _ = gain(ModifierMode::PrintedAbility(scope), properties);
     })
 }
 
 /// Causes the [PermanentId] permanent to gain flying until the end of the turn.
 pub fn gain_this_turn(game: &mut GameState, context: EventContext, id: PermanentId) -> Outcome {
-    gain(ModifierMode::add_ability_this_turn(context, id), &mut game.card_mut(id)?.properties)
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    gain(
+        ModifierMode::add_ability_this_turn(context, id),
+        &mut game.card_mut(id).or_skip()?.properties,
+    )
 }
 
 fn gain(mode: ModifierMode, properties: &mut CardProperties) -> Outcome {
-    properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Flying));
+    properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Flying))?;
     properties.can_be_blocked.add_with_mode(
         mode,
         Flag::and(move |g, s, data: &CanBeBlocked| {
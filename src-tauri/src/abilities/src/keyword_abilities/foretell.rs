@@ -0,0 +1,84 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, AbilityData, AbilityType};
+use data::card_states::card_state::CardState;
+use data::card_states::play_card_plan::{AlternativeCost, AlternativeCostKind, PlayCardChoices};
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::ability_scope::AbilityScope;
+use data::events::card_events::CardEvents;
+use data::events::event_context::EventContext;
+use data::events::game_events::GlobalEvents;
+use data::game_states::game_state::GameState;
+use data::printed_cards::mana_cost::ManaCost;
+use rules::queries::card_queries;
+use utils::outcome;
+use utils::outcome::Outcome;
+
+/// The Foretell ability.
+///
+/// > 702.143a. Foretell is a keyword that functions while the card with
+/// > foretell is in a player's hand. Any time a player has priority during
+/// > their turn, that player may pay {2} and exile a card with foretell from
+/// > their hand face down. That player may look at that card as long as it
+/// > remains in exile. They may cast it on a later turn for its foretell cost
+/// > rather than its mana cost.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702143a>
+pub fn ability(cost: ManaCost) -> impl Ability {
+    Foretell { cost }
+}
+
+/// Returns true if the [ToCardId] card is currently foretold, i.e. exiled
+/// face down via its foretell ability.
+pub fn is_foretold(game: &GameState, id: impl ToCardId) -> bool {
+    game.card(id).is_some_and(|card| card_queries::foretold_turn(game, card.id).is_some())
+}
+
+struct Foretell {
+    cost: ManaCost,
+}
+
+impl AbilityData for Foretell {
+    fn add_properties(&self, scope: AbilityScope, card: &mut CardState) {
+        card.alternative_costs.push(AlternativeCost {
+            ability_id: scope.ability_id,
+            cost: self.cost.clone(),
+            kind: AlternativeCostKind::Foretell,
+        });
+    }
+
+    fn add_global_events(&self, _: AbilityScope, _: &mut GlobalEvents) {}
+
+    fn add_card_events(&self, _: AbilityScope, _: &mut CardEvents) {}
+
+    fn get_ability_type(&self) -> AbilityType {
+        AbilityType::Static
+    }
+}
+
+impl Ability for Foretell {
+    fn requires_targets(&self) -> bool {
+        false
+    }
+
+    fn invoke_effect(
+        &self,
+        _: &mut GameState,
+        _: EventContext,
+        _: &Option<PlayCardChoices>,
+    ) -> Outcome {
+        outcome::OK
+    }
+}
@@ -24,7 +24,8 @@ use data::properties::duration::Duration;
 use data::properties::flag::Flag;
 use data::properties::property_value::EnumSets;
 use primitives::game_primitives::{HasSource, PermanentId};
-use utils::outcome::Outcome;
+use rules::mutations::expiring_effects;
+use utils::outcome::{OrSkip, Outcome};
 
 /// The Haste ability.
 ///
@@ -44,16 +45,21 @@ use utils::outcome::Outcome;
 /// <https://yawgatog.com/resources/magic-rules/#R70210>
 pub fn ability() -> impl Ability {
     StaticAbility::new().properties(|scope, properties| {
-        gain(ModifierMode::PrintedAbility(scope), properties);
+        let _ = gain(ModifierMode::PrintedAbility(scope), properties);
     })
 }
 
 /// Causes the [PermanentId] permanent to gain haste until the end of the turn.
 pub fn gain_this_turn(game: &mut GameState, context: EventContext, id: PermanentId) -> Outcome {
-    gain(ModifierMode::add_ability_this_turn(context, id), &mut game.card_mut(id)?.properties)
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    gain(
+        ModifierMode::add_ability_this_turn(context, id),
+        &mut game.card_mut(id).or_skip()?.properties,
+    )
 }
 
 fn gain(mode: ModifierMode, properties: &mut CardProperties) -> Outcome {
-    properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Haste));
+    properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Haste))?;
     properties.can_attack_same_turn.add_with_mode(mode, Flag::set_with_mode(mode, true))
 }
@@ -0,0 +1,121 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, AbilityData, AbilityType};
+use data::card_states::card_state::CardState;
+use data::card_states::play_card_plan::{
+    CastSpellPlanAdditionalChoice, OptionalCost, OptionalCostKind, PlayCardChoices,
+};
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::ability_scope::AbilityScope;
+use data::events::card_events::CardEvents;
+use data::events::event_context::EventContext;
+use data::events::game_events::GlobalEvents;
+use data::game_states::game_state::GameState;
+use data::printed_cards::mana_cost::ManaCost;
+use utils::outcome;
+use utils::outcome::Outcome;
+
+/// The Kicker ability.
+///
+/// > 702.33a. Kicker is a static ability that functions while the spell with
+/// > kicker is on the stack. "Kicker [cost]" means "You may pay an additional
+/// > [cost] as you cast this spell." Paying a spell's kicker cost(s) follows
+/// > the rules for paying additional costs in rules 601.2b and 601.2f-h.
+///
+/// > 702.33d. A spell is "kicked" if its controller declared the intention to
+/// > pay any or all of its kicker costs.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70233>
+pub fn ability(cost: ManaCost) -> impl Ability {
+    Kicker { cost, kind: OptionalCostKind::Kicker }
+}
+
+/// The Multikicker ability.
+///
+/// > 702.33c. Multikicker is a variant of the kicker ability. "Multikicker
+/// > [cost]" means "You may pay an additional [cost] any number of times as you
+/// > cast this spell." A multikicker cost is a kicker cost.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70233c>
+pub fn multikicker(cost: ManaCost) -> impl Ability {
+    Kicker { cost, kind: OptionalCostKind::Multikicker }
+}
+
+/// Returns true if the [ToCardId] card was kicked when it was cast.
+///
+/// This can be queried while the spell is on the stack and after it resolves
+/// as a permanent, e.g. "When this creature enters, if it was kicked...".
+pub fn was_kicked(game: &GameState, id: impl ToCardId) -> bool {
+    times_kicked(game, id) > 0
+}
+
+/// Returns the number of times the kicker costs of the [ToCardId] card were
+/// paid when it was cast, e.g. for multikicker.
+pub fn times_kicked(game: &GameState, id: impl ToCardId) -> usize {
+    let Some(card) = game.card(id) else {
+        return 0;
+    };
+    let Some(choices) = &card.cast_choices else {
+        return 0;
+    };
+    choices
+        .additional_choices
+        .iter()
+        .filter(|choice| {
+            let CastSpellPlanAdditionalChoice::AdditionalCostChoice(ability_id) = choice else {
+                return false;
+            };
+            card.optional_costs.iter().any(|optional| optional.ability_id == *ability_id)
+        })
+        .count()
+}
+
+struct Kicker {
+    cost: ManaCost,
+    kind: OptionalCostKind,
+}
+
+impl AbilityData for Kicker {
+    fn add_properties(&self, scope: AbilityScope, card: &mut CardState) {
+        card.optional_costs.push(OptionalCost {
+            ability_id: scope.ability_id,
+            cost: self.cost.clone(),
+            kind: self.kind,
+        });
+    }
+
+    fn add_global_events(&self, _: AbilityScope, _: &mut GlobalEvents) {}
+
+    fn add_card_events(&self, _: AbilityScope, _: &mut CardEvents) {}
+
+    fn get_ability_type(&self) -> AbilityType {
+        AbilityType::Static
+    }
+}
+
+impl Ability for Kicker {
+    fn requires_targets(&self) -> bool {
+        false
+    }
+
+    fn invoke_effect(
+        &self,
+        _: &mut GameState,
+        _: EventContext,
+        _: &Option<PlayCardChoices>,
+    ) -> Outcome {
+        outcome::OK
+    }
+}
@@ -12,5 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cascade;
+pub mod deathtouch;
+pub mod discover;
+pub mod exert;
 pub mod flying;
+pub mod foretell;
 pub mod haste;
+pub mod infect;
+pub mod kicker;
+pub mod lifelink;
+pub mod mutate;
+pub mod ninjutsu;
+pub mod protection;
+pub mod storm;
+pub mod vigilance;
+pub mod wither;
@@ -0,0 +1,82 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, AbilityData, AbilityType};
+use data::card_states::card_state::CardState;
+use data::card_states::play_card_plan::{AlternativeCost, AlternativeCostKind, PlayCardChoices};
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::ability_scope::AbilityScope;
+use data::events::card_events::CardEvents;
+use data::events::event_context::EventContext;
+use data::events::game_events::GlobalEvents;
+use data::game_states::game_state::GameState;
+use data::printed_cards::mana_cost::ManaCost;
+use utils::outcome;
+use utils::outcome::Outcome;
+
+/// The Mutate ability.
+///
+/// > 702.140a. Mutate appears on some creature cards. It represents a static
+/// > ability that functions while the spell with mutate is on the stack.
+/// > "Mutate [cost]" means "You may pay [cost] rather than pay this spell's
+/// > mana cost. If you do, it becomes a mutating creature spell and targets a
+/// > non-Human creature with the same owner as this spell."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702140a>
+pub fn ability(cost: ManaCost) -> impl Ability {
+    Mutate { cost }
+}
+
+/// Returns true if the [ToCardId] permanent is a merged permanent, i.e. if one
+/// or more mutating creature spells have merged with it.
+pub fn is_merged(game: &GameState, id: impl ToCardId) -> bool {
+    game.card(id).is_some_and(|card| !card.merged_components.is_empty())
+}
+
+struct Mutate {
+    cost: ManaCost,
+}
+
+impl AbilityData for Mutate {
+    fn add_properties(&self, scope: AbilityScope, card: &mut CardState) {
+        card.alternative_costs.push(AlternativeCost {
+            ability_id: scope.ability_id,
+            cost: self.cost.clone(),
+            kind: AlternativeCostKind::Mutate,
+        });
+    }
+
+    fn add_global_events(&self, _: AbilityScope, _: &mut GlobalEvents) {}
+
+    fn add_card_events(&self, _: AbilityScope, _: &mut CardEvents) {}
+
+    fn get_ability_type(&self) -> AbilityType {
+        AbilityType::Static
+    }
+}
+
+impl Ability for Mutate {
+    fn requires_targets(&self) -> bool {
+        false
+    }
+
+    fn invoke_effect(
+        &self,
+        _: &mut GameState,
+        _: EventContext,
+        _: &Option<PlayCardChoices>,
+    ) -> Outcome {
+        outcome::OK
+    }
+}
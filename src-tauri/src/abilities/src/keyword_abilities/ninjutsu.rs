@@ -0,0 +1,78 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, AbilityData, AbilityType};
+use data::card_states::card_state::CardState;
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::core::ability_scope::AbilityScope;
+use data::events::card_events::CardEvents;
+use data::events::event_context::EventContext;
+use data::events::game_events::GlobalEvents;
+use data::game_states::combat_state::CombatSpecialAction;
+use data::game_states::game_state::GameState;
+use data::printed_cards::mana_cost::ManaCost;
+use utils::outcome;
+use utils::outcome::Outcome;
+
+/// The Ninjutsu ability.
+///
+/// > 702.49a. Ninjutsu is an activated ability that functions only while the
+/// > card with ninjutsu is in a player's hand. "Ninjutsu [cost]" means "[Cost],
+/// > Reveal this card from your hand, Return an unblocked attacking creature
+/// > you control to its owner's hand: Put this card onto the battlefield from
+/// > your hand tapped and attacking."
+///
+/// > 702.49c. A ninjutsu ability may be activated only while a creature on the
+/// > battlefield is unblocked (see rule 509.1h). The creature with ninjutsu is
+/// > put onto the battlefield unblocked. It will be attacking the same player,
+/// > planeswalker, or battle as the creature that was returned to its owner's
+/// > hand.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70249>
+pub fn ability(cost: ManaCost) -> impl Ability {
+    Ninjutsu { cost }
+}
+
+struct Ninjutsu {
+    cost: ManaCost,
+}
+
+impl AbilityData for Ninjutsu {
+    fn add_properties(&self, _: AbilityScope, card: &mut CardState) {
+        card.combat_special_actions.push(CombatSpecialAction::Ninjutsu(self.cost.clone()));
+    }
+
+    fn add_global_events(&self, _: AbilityScope, _: &mut GlobalEvents) {}
+
+    fn add_card_events(&self, _: AbilityScope, _: &mut CardEvents) {}
+
+    fn get_ability_type(&self) -> AbilityType {
+        AbilityType::Activated
+    }
+}
+
+impl Ability for Ninjutsu {
+    fn requires_targets(&self) -> bool {
+        false
+    }
+
+    fn invoke_effect(
+        &self,
+        _: &mut GameState,
+        _: EventContext,
+        _: &Option<PlayCardChoices>,
+    ) -> Outcome {
+        outcome::OK
+    }
+}
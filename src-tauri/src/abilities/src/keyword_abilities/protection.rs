@@ -0,0 +1,98 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, StaticAbility};
+use data::card_states::zones::ZoneQueries;
+use data::core::card_tags::CardTag;
+use data::core::modifier_data::ModifierMode;
+use data::events::event_context::EventContext;
+use data::game_states::game_state::GameState;
+use data::properties::card_properties::CardProperties;
+use data::properties::card_property_data::{CanBeBlocked, CanBeTargeted};
+use data::properties::duration::Duration;
+use data::properties::flag::Flag;
+use data::properties::property_value::EnumSets;
+use primitives::game_primitives::{Color, HasSource, PermanentId, Source};
+use rules::mutations::expiring_effects;
+use rules::queries::{card_queries, text_change_queries};
+use utils::outcome::{OrSkip, Outcome};
+
+/// The "Protection from [color]" ability.
+///
+/// > 702.16a. Protection is a static ability, written "Protection from
+/// > [quality]." This quality is usually a color (as in "protection from
+/// > black") but can be any characteristic value or information.
+///
+/// > 702.16b. A permanent or player with protection can't be targeted by
+/// > spells with the stated quality and can't be targeted by abilities from a
+/// > source with the stated quality.
+///
+/// > 702.16f. A permanent or player with protection can't be blocked by
+/// > creatures that have the stated quality.
+///
+/// The protected color is read from the rules text of the card this ability
+/// appears on, so text-changing effects which replace that color word also
+/// change what the permanent has protection from.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70216>
+pub fn from_color(color: Color) -> impl Ability {
+    StaticAbility::new().properties(move |scope, properties| {
+        let _ = gain(ModifierMode::PrintedAbility(scope), properties, color);
+    })
+}
+
+/// Causes the [PermanentId] permanent to gain protection from the given color
+/// until the end of the turn.
+pub fn gain_this_turn(
+    game: &mut GameState,
+    context: EventContext,
+    id: PermanentId,
+    color: Color,
+) -> Outcome {
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    gain(
+        ModifierMode::add_ability_this_turn(context, id),
+        &mut game.card_mut(id).or_skip()?.properties,
+        color,
+    )
+}
+
+fn gain(mode: ModifierMode, properties: &mut CardProperties, color: Color) -> Outcome {
+    // Source whose rules text names the protected color
+    let text_source = match mode {
+        ModifierMode::PrintedAbility(scope) | ModifierMode::PrintedAbilityWhile(scope, _) => {
+            Source::Ability(scope.ability_id)
+        }
+        ModifierMode::Effect(context, ..) => context.source(),
+    };
+    properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Protection))?;
+    properties.can_be_blocked.add_with_mode(
+        mode,
+        Flag::and(move |g, s, data: &CanBeBlocked| {
+            let protected = text_change_queries::color(g, text_source, color);
+            Some(!card_queries::colors(g, s, data.blocker_id)?.contains(protected))
+        }),
+    )?;
+    properties.can_be_targeted.add_with_mode(
+        mode,
+        Flag::and(move |g, s, data: &CanBeTargeted| {
+            let Source::Ability(ability_id) = data.source else {
+                return Some(true);
+            };
+            let protected = text_change_queries::color(g, text_source, color);
+            Some(!card_queries::colors(g, s, ability_id.card_id)?.contains(protected))
+        }),
+    )
+}
@@ -0,0 +1,55 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::events::event_context::EventContext;
+use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
+use primitives::game_primitives::CardId;
+use rules::mutations::create_copy;
+use rules::mutations::create_copy::ChooseNewTargets;
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+/// Applies the effect of the storm ability of the spell which owns the
+/// [EventContext] ability, copying it once for each other spell cast before it
+/// this turn. The controller may choose new targets for each copy.
+///
+/// > 702.40a. Storm is a triggered ability that functions on the stack. "Storm"
+/// > means "When you cast this spell, copy it for each other spell that was
+/// > cast before it this turn. If the spell has any targets, you may choose new
+/// > targets for any of the copies."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70240a>
+pub fn storm(game: &mut GameState, context: EventContext) -> Outcome {
+    let spell_id = game.card(context.this.card_id).or_skip()?.spell_id().or_skip()?;
+    for _ in 0..storm_count(game, context.this.card_id) {
+        create_copy::of_spell(game, context, spell_id, context.controller, ChooseNewTargets::Yes)?;
+    }
+    outcome::OK
+}
+
+/// Returns the number of spells cast by any player this turn before the
+/// [CardId] spell was most recently cast.
+pub fn storm_count(game: &GameState, card_id: CardId) -> usize {
+    let cast_this_turn = game
+        .history
+        .for_turn(game.turn)
+        .filter_map(|event| match event {
+            HistoryEvent::CastSpell(_, id) => Some(*id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    cast_this_turn.iter().rposition(|&id| id == card_id).unwrap_or(cast_this_turn.len())
+}
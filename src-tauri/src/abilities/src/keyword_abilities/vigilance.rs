@@ -0,0 +1,59 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, StaticAbility};
+use data::card_states::zones::ZoneQueries;
+use data::core::card_tags::CardTag;
+use data::core::modifier_data::ModifierMode;
+use data::events::event_context::EventContext;
+use data::game_states::game_state::GameState;
+use data::properties::card_properties::CardProperties;
+use data::properties::duration::Duration;
+use data::properties::property_value::EnumSets;
+use primitives::game_primitives::PermanentId;
+use rules::mutations::expiring_effects;
+use utils::outcome::{OrSkip, Outcome};
+
+/// The Vigilance ability.
+///
+/// > 702.20a. Vigilance is a static ability that modifies the rules for the
+/// > declare attackers step.
+///
+/// > 702.20b. Attacking doesn't cause creatures with vigilance to tap. (See
+/// > rule 508, "Declare Attackers Step.")
+///
+/// > 702.20c. Multiple instances of vigilance on the same creature are
+/// > redundant.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70220>
+pub fn ability() -> impl Ability {
+    StaticAbility::new().properties(|scope, properties| {
+        let _ = gain(ModifierMode::PrintedAbility(scope), properties);
+    })
+}
+
+/// Causes the [PermanentId] permanent to gain vigilance until the end of the
+/// turn.
+pub fn gain_this_turn(game: &mut GameState, context: EventContext, id: PermanentId) -> Outcome {
+    let duration = Duration::WhileOnBattlefieldThisTurn(id, context.current_turn);
+    expiring_effects::add_modifiers(game, context.event_id, id, duration)?;
+    gain(
+        ModifierMode::add_ability_this_turn(context, id),
+        &mut game.card_mut(id).or_skip()?.properties,
+    )
+}
+
+fn gain(mode: ModifierMode, properties: &mut CardProperties) -> Outcome {
+    properties.tags.add_with_mode(mode, EnumSets::add_with_mode(mode, CardTag::Vigilance))
+}
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 pub mod graveyard_selectors;
+pub mod multiple_selectors;
 pub mod pair_selector;
 pub mod permanent_selectors;
+pub mod player_selectors;
 pub mod player_set;
 pub mod spell_selectors;
+pub mod stack_ability_selectors;
 pub mod targets;
@@ -0,0 +1,111 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::slice;
+
+use data::card_definitions::ability_definition::{TargetCount, TargetSelector};
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{EntityId, Source};
+
+/// Selects several distinct targets using another [TargetSelector], e.g. "up
+/// to two target creatures".
+pub struct MultipleSelector<T: TargetSelector> {
+    pub selector: T,
+    pub count: TargetCount,
+}
+
+impl<T: TargetSelector> TargetSelector for MultipleSelector<T> {
+    type Target = Vec<T::Target>;
+
+    fn valid_targets<'a>(
+        &'a self,
+        game: &'a GameState,
+        choices: &'a PlayCardChoices,
+        source: Source,
+    ) -> Box<dyn Iterator<Item = EntityId> + 'a> {
+        self.selector.valid_targets(game, choices, source)
+    }
+
+    fn build_target_data(&self, game: &GameState, targets: &[EntityId]) -> Option<Self::Target> {
+        Some(
+            targets
+                .iter()
+                .filter_map(|target| self.selector.build_target_data(game, slice::from_ref(target)))
+                .collect(),
+        )
+    }
+
+    fn target_count(&self) -> TargetCount {
+        self.count
+    }
+}
+
+/// Selects several distinct targets using another [TargetSelector] and divides
+/// a total amount among them, e.g. "3 damage divided as you choose among one,
+/// two, or three targets".
+///
+/// Each chosen target is assigned at least 1.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6012d>
+pub struct DividedSelector<T: TargetSelector> {
+    pub selector: T,
+    pub count: TargetCount,
+    pub total: u32,
+}
+
+impl<T: TargetSelector> TargetSelector for DividedSelector<T> {
+    type Target = Vec<(T::Target, u32)>;
+
+    fn valid_targets<'a>(
+        &'a self,
+        game: &'a GameState,
+        choices: &'a PlayCardChoices,
+        source: Source,
+    ) -> Box<dyn Iterator<Item = EntityId> + 'a> {
+        self.selector.valid_targets(game, choices, source)
+    }
+
+    /// Divided targets cannot be built without the amount assigned to each
+    /// target, see [Self::build_divided_target_data].
+    fn build_target_data(&self, _: &GameState, _: &[EntityId]) -> Option<Self::Target> {
+        None
+    }
+
+    fn target_count(&self) -> TargetCount {
+        self.count
+    }
+
+    fn divided_total(&self) -> Option<u32> {
+        Some(self.total)
+    }
+
+    fn build_divided_target_data(
+        &self,
+        game: &GameState,
+        targets: &[EntityId],
+        amounts: &[u32],
+    ) -> Option<Self::Target> {
+        Some(
+            targets
+                .iter()
+                .zip(amounts)
+                .filter_map(|(target, &amount)| {
+                    let data = self.selector.build_target_data(game, slice::from_ref(target))?;
+                    Some((data, amount))
+                })
+                .collect(),
+        )
+    }
+}
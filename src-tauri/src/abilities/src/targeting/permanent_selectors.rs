@@ -18,6 +18,7 @@ use data::card_states::zones::ZoneQueries;
 use data::core::function_types::CardPredicate;
 use data::game_states::game_state::GameState;
 use primitives::game_primitives::{EntityId, HasSource, PermanentId, PlayerName, Source};
+use rules::queries::card_queries;
 
 use crate::targeting::player_set;
 use crate::targeting::player_set::PlayerSet;
@@ -56,7 +57,10 @@ where
                 .iter()
                 .flat_map(move |player| {
                     game.battlefield(player).iter().filter_map(move |&permanent_id| {
-                        if (self.predicate)(game, source.source(), permanent_id) == Some(true) {
+                        if (self.predicate)(game, source.source(), permanent_id) == Some(true)
+                            && card_queries::can_be_targeted(game, source, permanent_id)
+                                == Some(true)
+                        {
                             Some(permanent_id.into())
                         } else {
                             None
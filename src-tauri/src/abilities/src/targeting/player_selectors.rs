@@ -0,0 +1,56 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::TargetSelector;
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{EntityId, PlayerName, Source};
+
+use crate::targeting::player_set;
+use crate::targeting::player_set::PlayerSet;
+
+/// Selects a single player as a target.
+pub struct SinglePlayerSelector {
+    pub players: PlayerSet,
+}
+
+impl SinglePlayerSelector {
+    pub fn new(players: PlayerSet) -> Self {
+        Self { players }
+    }
+}
+
+impl TargetSelector for SinglePlayerSelector {
+    type Target = PlayerName;
+
+    fn valid_targets<'a>(
+        &'a self,
+        game: &'a GameState,
+        choices: &'a PlayCardChoices,
+        source: Source,
+    ) -> Box<dyn Iterator<Item = EntityId> + 'a> {
+        Box::new(
+            player_set::players_in_set(game, choices.controller, source, self.players)
+                .iter()
+                .map(EntityId::Player),
+        )
+    }
+
+    fn build_target_data(&self, game: &GameState, targets: &[EntityId]) -> Option<Self::Target> {
+        match targets.first()? {
+            EntityId::Player(player) => Some(*player),
+            _ => None,
+        }
+    }
+}
@@ -18,6 +18,7 @@ use data::card_states::zones::ZoneQueries;
 use data::core::function_types::CardPredicate;
 use data::game_states::game_state::GameState;
 use primitives::game_primitives::{EntityId, HasSource, PlayerName, Source, SpellId, StackItemId};
+use rules::queries::card_queries;
 
 use crate::targeting::player_set;
 use crate::targeting::player_set::PlayerSet;
@@ -59,7 +60,9 @@ where
                         let StackItemId::Spell(spell_id) = stack_item_id else {
                             return None;
                         };
-                        if (self.predicate)(game, source, spell_id) == Some(true) {
+                        if (self.predicate)(game, source, spell_id) == Some(true)
+                            && card_queries::can_be_targeted(game, source, spell_id) == Some(true)
+                        {
                             Some(spell_id.into())
                         } else {
                             None
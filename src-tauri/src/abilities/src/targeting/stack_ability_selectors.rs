@@ -0,0 +1,62 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::TargetSelector;
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{EntityId, Source, StackAbilityId, StackItemId};
+
+use crate::targeting::player_set;
+use crate::targeting::player_set::PlayerSet;
+
+/// Selects a single activated or triggered ability on the stack as a target.
+pub struct SingleStackAbilitySelector {
+    /// Players whose abilities can be targeted.
+    pub players: PlayerSet,
+}
+
+impl SingleStackAbilitySelector {
+    pub fn new(players: PlayerSet) -> Self {
+        Self { players }
+    }
+}
+
+impl TargetSelector for SingleStackAbilitySelector {
+    type Target = StackAbilityId;
+
+    fn valid_targets<'a>(
+        &'a self,
+        game: &'a GameState,
+        choices: &'a PlayCardChoices,
+        source: Source,
+    ) -> Box<dyn Iterator<Item = EntityId> + 'a> {
+        let players = player_set::players_in_set(game, choices.controller, source, self.players);
+        Box::new(game.stack().iter().filter_map(move |&stack_item_id| {
+            let StackItemId::StackAbility(stack_ability_id) = stack_item_id else {
+                return None;
+            };
+            players
+                .contains(game.stack_ability(stack_ability_id).controller)
+                .then_some(EntityId::StackAbility(stack_ability_id))
+        }))
+    }
+
+    fn build_target_data(&self, game: &GameState, targets: &[EntityId]) -> Option<Self::Target> {
+        match targets.first()? {
+            EntityId::StackAbility(stack_ability_id) => Some(*stack_ability_id),
+            _ => None,
+        }
+    }
+}
@@ -12,17 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::card_definitions::ability_definition::TargetSelector;
+use data::card_definitions::ability_definition::{TargetCount, TargetSelector};
 use either::Either;
 use enumset::EnumSet;
-use primitives::game_primitives::{CardType, GraveyardCardId, PermanentId, SpellId};
+use primitives::game_primitives::{
+    CardType, GraveyardCardId, PermanentId, PlayerName, SpellId, StackAbilityId,
+};
 use rules::predicates::card_predicates;
 
 use crate::targeting::graveyard_selectors::SingleGraveyardSelector;
+use crate::targeting::multiple_selectors::{DividedSelector, MultipleSelector};
 use crate::targeting::pair_selector::PairSelector;
 use crate::targeting::permanent_selectors::SinglePermanentSelector;
+use crate::targeting::player_selectors::SinglePlayerSelector;
 use crate::targeting::player_set::PlayerSet;
 use crate::targeting::spell_selectors::SingleSpellSelector;
+use crate::targeting::stack_ability_selectors::SingleStackAbilitySelector;
 
 /// Target any creature on the battlefield
 pub fn creature() -> impl TargetSelector<Target = PermanentId> {
@@ -54,6 +59,11 @@ pub fn spell_with_type(types: EnumSet<CardType>) -> impl TargetSelector<Target =
     SingleSpellSelector::new(PlayerSet::AllPlayers, card_predicates::has_any_types_in(types))
 }
 
+/// Target any activated or triggered ability on the stack.
+pub fn ability_on_stack() -> impl TargetSelector<Target = StackAbilityId> {
+    SingleStackAbilitySelector::new(PlayerSet::AllPlayers)
+}
+
 /// Target any spell or permanent
 pub fn spell_or_permanent() -> impl TargetSelector<Target = Either<SpellId, PermanentId>> {
     PairSelector { first: spell(), second: permanent() }
@@ -71,3 +81,50 @@ pub fn card_in_your_graveyard_with_type(
 ) -> impl TargetSelector<Target = GraveyardCardId> {
     SingleGraveyardSelector::new(PlayerSet::You, card_predicates::has_any_types_in(types))
 }
+
+/// Target any player
+pub fn player() -> impl TargetSelector<Target = PlayerName> {
+    SinglePlayerSelector::new(PlayerSet::AllPlayers)
+}
+
+/// Target an opponent
+pub fn opponent() -> impl TargetSelector<Target = PlayerName> {
+    SinglePlayerSelector::new(PlayerSet::Opponents)
+}
+
+/// Target any creature, player, planeswalker, or battle.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1154>
+pub fn any_target() -> impl TargetSelector<Target = Either<PlayerName, PermanentId>> {
+    PairSelector {
+        first: player(),
+        second: SinglePermanentSelector::new(
+            PlayerSet::AllPlayers,
+            card_predicates::has_any_types_in(
+                CardType::Creature | CardType::Planeswalker | CardType::Battle,
+            ),
+        ),
+    }
+}
+
+/// Target up to `maximum` distinct entities using the given selector.
+pub fn up_to<T: TargetSelector>(
+    maximum: usize,
+    selector: T,
+) -> impl TargetSelector<Target = Vec<T::Target>> {
+    MultipleSelector { selector, count: TargetCount::up_to(maximum) }
+}
+
+/// Target between one and `maximum` distinct entities using the given
+/// selector, dividing `total` among them.
+///
+/// At most `total` targets can be chosen, since each target must be assigned
+/// at least 1.
+pub fn divided<T: TargetSelector>(
+    total: u32,
+    maximum: usize,
+    selector: T,
+) -> impl TargetSelector<Target = Vec<(T::Target, u32)>> {
+    let maximum = maximum.min(total as usize);
+    DividedSelector { selector, count: TargetCount::between(1.min(maximum), maximum), total }
+}
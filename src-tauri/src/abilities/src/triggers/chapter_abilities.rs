@@ -0,0 +1,98 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, AbilityData, AbilityType};
+use data::card_states::card_state::CardState;
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::card_states::zones::ZoneQueries;
+use data::core::ability_scope::AbilityScope;
+use data::events::card_events::CardEvents;
+use data::events::event_context::EventContext;
+use data::events::game_events::GlobalEvents;
+use data::game_states::game_state::GameState;
+use data::game_states::state_based_event::StateBasedEvent;
+use rules::mutations::trigger_extension::TriggerExt;
+use utils::outcome::Outcome;
+
+/// A Saga chapter ability which triggers when the number of lore counters on
+/// this Saga reaches `chapter`.
+///
+/// > 714.2b. A chapter ability is a triggered ability. "[Roman numeral] --
+/// > [Effect]" means "When one or more lore counters are put onto this Saga,
+/// > if the number of lore counters on it was less than [N] and became at least
+/// > [N], [effect]."
+///
+/// > 714.2c. As a chapter ability with two or more roman numerals, "[Roman
+/// > numeral 1], [Roman numeral 2] -- [Effect]" is the same as "[Roman numeral
+/// > 1] -- [Effect]" and "[Roman numeral 2] -- [Effect]."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7142>
+pub fn chapter(
+    chapter: u32,
+    effect: impl Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+) -> impl Ability {
+    Chapter { chapter, effect }
+}
+
+struct Chapter<TFn> {
+    chapter: u32,
+    effect: TFn,
+}
+
+impl<TFn> AbilityData for Chapter<TFn>
+where
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+{
+    fn add_properties(&self, _: AbilityScope, card: &mut CardState) {
+        card.saga_chapters.push(self.chapter);
+    }
+
+    fn add_global_events(&self, _: AbilityScope, _: &mut GlobalEvents) {}
+
+    fn add_card_events(&self, scope: AbilityScope, events: &mut CardEvents) {
+        let chapter = self.chapter;
+        events.lore_counters_added.add_trigger(scope, move |_, _, event| {
+            Some(event.before < chapter && event.after >= chapter)
+        });
+    }
+
+    fn get_ability_type(&self) -> AbilityType {
+        AbilityType::Triggered
+    }
+}
+
+impl<TFn> Ability for Chapter<TFn>
+where
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+{
+    fn requires_targets(&self) -> bool {
+        false
+    }
+
+    fn invoke_effect(
+        &self,
+        game: &mut GameState,
+        context: EventContext,
+        _: &Option<PlayCardChoices>,
+    ) -> Outcome {
+        let result = (self.effect)(game, context);
+
+        // Check whether the Saga should be sacrificed once this ability leaves
+        // the stack.
+        if let Some(permanent_id) = game.card(context.this.card_id).and_then(|c| c.permanent_id()) {
+            game.add_state_based_event(StateBasedEvent::SagaChapterChanged(permanent_id));
+        }
+        result
+    }
+}
@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod chapter_abilities;
+pub mod mutate_triggers;
 pub mod state_triggers;
@@ -0,0 +1,76 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, AbilityData, AbilityType};
+use data::card_states::card_state::CardState;
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::core::ability_scope::AbilityScope;
+use data::events::card_events::CardEvents;
+use data::events::event_context::EventContext;
+use data::events::game_events::GlobalEvents;
+use data::game_states::game_state::GameState;
+use rules::mutations::trigger_extension::TriggerExt;
+use utils::outcome::Outcome;
+
+/// A triggered ability which fires "whenever this creature mutates".
+///
+/// > 702.140d. An ability that triggers whenever a creature mutates triggers
+/// > when a spell merges with a creature as a result of a resolving mutating
+/// > creature spell.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702140d>
+pub fn whenever_mutates(
+    effect: impl Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+) -> impl Ability {
+    WheneverMutates { effect }
+}
+
+struct WheneverMutates<TFn> {
+    effect: TFn,
+}
+
+impl<TFn> AbilityData for WheneverMutates<TFn>
+where
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+{
+    fn add_properties(&self, _: AbilityScope, _: &mut CardState) {}
+
+    fn add_global_events(&self, _: AbilityScope, _: &mut GlobalEvents) {}
+
+    fn add_card_events(&self, scope: AbilityScope, events: &mut CardEvents) {
+        events.mutated.add_trigger(scope, |_, _, _| Some(true));
+    }
+
+    fn get_ability_type(&self) -> AbilityType {
+        AbilityType::Triggered
+    }
+}
+
+impl<TFn> Ability for WheneverMutates<TFn>
+where
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+{
+    fn requires_targets(&self) -> bool {
+        false
+    }
+
+    fn invoke_effect(
+        &self,
+        game: &mut GameState,
+        context: EventContext,
+        _: &Option<PlayCardChoices>,
+    ) -> Outcome {
+        (self.effect)(game, context)
+    }
+}
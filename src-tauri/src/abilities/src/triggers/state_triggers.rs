@@ -33,6 +33,6 @@ pub fn when_controls_no(
             });
         })
         .effect(move |g, c| {
-            mutation(g, c.source(), c.this.card_id);
+            mutation(g, c.source(), c.this.card_id)
         })
 }
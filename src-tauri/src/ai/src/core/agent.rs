@@ -89,6 +89,17 @@ where
             combiner: state_combiner::first,
         }
     }
+
+    /// Creates an Agent using the provided [StatePredictor] to guess at hidden
+    /// game state. Uses the [state_combiner::first] combiner.
+    pub const fn with_predictor(
+        name: &'static str,
+        predictor: StatePredictor<TNode>,
+        selector: TSelector,
+        evaluator: TEvaluator,
+    ) -> Self {
+        Self { name, predictor, selector, evaluator, combiner: state_combiner::first }
+    }
 }
 
 impl<TSelector, TEvaluator, TNode> Agent<TNode> for AgentData<TSelector, TEvaluator, TNode>
@@ -17,6 +17,8 @@ use std::hash::Hash;
 
 use ai_core::core::agent_state::AgentState;
 use enumset::{EnumSet, EnumSetType};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 #[derive(PartialEq, Eq)]
 pub enum GameStatus<TPlayer: EnumSetType> {
@@ -34,10 +36,10 @@ pub enum GameStatus<TPlayer: EnumSetType> {
 /// doing broadly correct things.
 pub trait GameStateNode {
     /// A game action to transition the game to a new state.
-    type Action: Eq + Hash + Copy + Send + Debug + Ord;
+    type Action: Eq + Hash + Copy + Send + Debug + Ord + Serialize + DeserializeOwned;
 
     /// A player in the game.
-    type PlayerName: EnumSetType + Send + Debug;
+    type PlayerName: EnumSetType + Send + Debug + Serialize + DeserializeOwned;
 
     /// Create a copy of this search node to be mutated by selection algorithms.
     /// A basic implementation of this would be to simply call `.clone()`, but
@@ -16,17 +16,22 @@ use std::marker::PhantomData;
 
 use clap::ValueEnum;
 use data::game_states::game_state::GameState;
+use serde::{Deserialize, Serialize};
 
 use crate::core::agent::{Agent, AgentData};
 use crate::core::first_available_action::FirstAvailableActionAlgorithm;
 use crate::core::win_loss_evaluator::WinLossEvaluator;
+use crate::game::determinization;
 use crate::game::evaluators::CustomHeuristicEvaluator;
 use crate::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use crate::monte_carlo::search_checkpoint::CheckpointOptions;
 use crate::monte_carlo::uct1::Uct1;
 use crate::tree_search::alpha_beta::AlphaBetaAlgorithm;
 use crate::tree_search::iterative_deepening_search::IterativeDeepeningSearch;
 
-#[derive(ValueEnum, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(
+    ValueEnum, Copy, Clone, Eq, PartialEq, Hash, Debug, Ord, PartialOrd, Serialize, Deserialize,
+)]
 pub enum AgentName {
     AlphaBetaDepth5,
     AlphaBetaDepth25,
@@ -35,10 +40,22 @@ pub enum AgentName {
     Uct1Iterations1,
     Uct1Iterations250,
     Uct1Iterations10_000,
+    Uct1Determinized,
     FirstAvailableAction,
 }
 
 pub fn get_agent(name: AgentName) -> Box<dyn Agent<GameState>> {
+    get_agent_with_checkpoint(name, None)
+}
+
+/// Returns the named agent, periodically saving search progress to disk as
+/// described by `checkpoint` if this is a Monte Carlo agent.
+///
+/// Other agents ignore the checkpoint configuration.
+pub fn get_agent_with_checkpoint(
+    name: AgentName,
+    checkpoint: Option<CheckpointOptions>,
+) -> Box<dyn Agent<GameState>> {
     match name {
         AgentName::AlphaBetaDepth5 => Box::new(AgentData::omniscient(
             "ALPHA_BETA_5",
@@ -60,6 +77,7 @@ pub fn get_agent(name: AgentName) -> Box<dyn Agent<GameState>> {
             MonteCarloAlgorithm {
                 child_score_algorithm: Uct1 {},
                 max_iterations: None,
+                checkpoint: checkpoint.clone(),
                 phantom_data: PhantomData,
             },
             RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
@@ -69,6 +87,7 @@ pub fn get_agent(name: AgentName) -> Box<dyn Agent<GameState>> {
             MonteCarloAlgorithm {
                 child_score_algorithm: Uct1 {},
                 max_iterations: Some(1),
+                checkpoint: checkpoint.clone(),
                 phantom_data: PhantomData,
             },
             RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
@@ -78,6 +97,7 @@ pub fn get_agent(name: AgentName) -> Box<dyn Agent<GameState>> {
             MonteCarloAlgorithm {
                 child_score_algorithm: Uct1 {},
                 max_iterations: Some(250),
+                checkpoint: checkpoint.clone(),
                 phantom_data: PhantomData,
             },
             RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
@@ -87,6 +107,18 @@ pub fn get_agent(name: AgentName) -> Box<dyn Agent<GameState>> {
             MonteCarloAlgorithm {
                 child_score_algorithm: Uct1 {},
                 max_iterations: Some(10_000),
+                checkpoint: checkpoint.clone(),
+                phantom_data: PhantomData,
+            },
+            RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
+        )),
+        AgentName::Uct1Determinized => Box::new(AgentData::with_predictor(
+            "UCT1_DETERMINIZED",
+            determinization::determinize,
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                checkpoint: checkpoint.clone(),
                 phantom_data: PhantomData,
             },
             RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
@@ -0,0 +1,218 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::game_action::{CombatAction, GameAction};
+use data::card_states::card_state::TappedState;
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::{LifeValue, Power, Toughness};
+use data::game_states::combat_state::{AttackTarget, AttackerId, BlockerId, CombatState};
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use primitives::game_primitives::{PermanentId, PlayerName, Source};
+use rules::legality::legal_actions;
+use rules::predicates::card_predicates;
+use rules::queries::{card_queries, combat_queries, player_queries};
+
+/// Combat statistics for a creature, as relevant to attack and block planning.
+#[derive(Debug, Clone, Copy)]
+struct Combatant {
+    id: PermanentId,
+    power: Power,
+    toughness: Toughness,
+    flying: bool,
+}
+
+impl Combatant {
+    fn new(game: &GameState, id: PermanentId) -> Option<Self> {
+        Some(Self {
+            id,
+            power: card_queries::power(game, Source::Game, id)?,
+            toughness: card_queries::toughness(game, Source::Game, id)?,
+            flying: combat_queries::has_flying(game, Source::Game, id)?,
+        })
+    }
+
+    /// Rough measure of how valuable this creature is to keep on the
+    /// battlefield.
+    fn value(&self) -> i64 {
+        self.power + self.toughness
+    }
+
+    fn kills(&self, other: &Combatant) -> bool {
+        self.power > 0 && self.power >= other.toughness
+    }
+
+    fn can_block(&self, attacker: &Combatant) -> bool {
+        !attacker.flying || self.flying
+    }
+}
+
+/// Returns the next combat action `player` should take while declaring
+/// attackers or blockers, or None if they are not currently making combat
+/// decisions.
+///
+/// The combinatorial space of possible attacks and blocks is too large for
+/// tree search to explore well, so agents instead plan their whole
+/// declaration up front with [plan_attacks] or [plan_blocks] and then submit
+/// it one action at a time.
+pub fn select_action(game: &GameState, player: PlayerName) -> Option<GameAction> {
+    let action = match game.combat.as_ref()? {
+        CombatState::ProposingAttackers(proposed) => {
+            let target = AttackTarget::Player(player_queries::next_opponent_after(game, player));
+            if !proposed.selected_attackers.is_empty() {
+                CombatAction::SetSelectedAttackersTarget(target)
+            } else if let Some(attacker) = plan_attacks(game, player).into_iter().find(|&id| {
+                !proposed.proposed_attacks.contains(id)
+                    && !proposed.selected_attackers.contains(&id)
+            }) {
+                CombatAction::AddSelectedAttacker(attacker)
+            } else {
+                CombatAction::ConfirmAttackers
+            }
+        }
+        CombatState::ProposingBlockers(proposed) => {
+            let plan = plan_blocks(game, player);
+            if let Some(&selected) = proposed.selected_blockers.first() {
+                let (_, attacker) = plan.iter().find(|(blocker, _)| *blocker == selected)?;
+                CombatAction::SetSelectedBlockersTarget(*attacker)
+            } else if let Some((blocker, _)) =
+                plan.iter().find(|(blocker, _)| !proposed.proposed_blocks.contains_key(blocker))
+            {
+                CombatAction::AddSelectedBlocker(*blocker)
+            } else {
+                CombatAction::ConfirmBlockers
+            }
+        }
+        _ => {
+            return None;
+        }
+    };
+
+    let action = GameAction::CombatAction(action);
+    legal_actions::can_take_action(game, player, &action).then_some(action)
+}
+
+/// Returns the creatures `player` should attack their next opponent with.
+///
+/// Attacks with everything if doing so is lethal even if the opponent blocks
+/// the largest attackers. Otherwise, each creature attacks only if no
+/// potential blocker can kill it in an unfavorable trade, and creatures
+/// without vigilance stay home if the opponent threatens lethal damage on the
+/// next turn.
+pub fn plan_attacks(game: &GameState, player: PlayerName) -> Vec<AttackerId> {
+    let opponent = player_queries::next_opponent_after(game, player);
+    let mut attackers = combat_queries::legal_attackers(game, Source::Game, player)
+        .filter_map(|id| Combatant::new(game, id))
+        .collect::<Vec<_>>();
+    let blockers = untapped_creatures(game, opponent);
+    let opponent_life = game.player(opponent).life;
+
+    attackers.sort_by_key(|a| -a.power);
+    let unblockable_damage: LifeValue =
+        attackers.iter().skip(blockers.len()).map(|a| a.power.max(0)).sum();
+    if unblockable_damage >= opponent_life {
+        return attackers.iter().map(|a| a.id).collect();
+    }
+
+    let incoming: LifeValue =
+        creatures(game, opponent).iter().map(|c| c.power.max(0)).sum::<LifeValue>();
+    let must_defend = incoming >= game.player(player).life;
+    attackers
+        .iter()
+        .filter(|a| a.power > 0)
+        .filter(|a| {
+            !must_defend
+                || combat_queries::has_vigilance(game, Source::Game, a.id).unwrap_or_default()
+        })
+        .filter(|a| {
+            blockers.iter().filter(|b| b.can_block(a)).all(|b| {
+                // Attacker survives, or trades with something at least as valuable
+                !b.kills(a) || (a.kills(b) && b.value() >= a.value())
+            })
+        })
+        .map(|a| a.id)
+        .collect()
+}
+
+/// Returns pairs of blockers and the attackers they should block for
+/// `player`.
+///
+/// If the incoming damage is lethal, chump blocks the largest attackers with
+/// the least valuable creatures until the player survives. Otherwise, blocks
+/// where the blocker survives are preferred, followed by even or favorable
+/// trades.
+pub fn plan_blocks(game: &GameState, player: PlayerName) -> Vec<(BlockerId, AttackerId)> {
+    let Some(attacks) = game.combat.as_ref().and_then(|c| c.confirmed_attackers()) else {
+        return vec![];
+    };
+    let mut attackers = attacks
+        .all()
+        .filter(|(_, target)| target.defending_player() == player)
+        .filter_map(|(&id, _)| Combatant::new(game, id))
+        .collect::<Vec<_>>();
+    attackers.sort_by_key(|a| -a.power);
+    let mut blockers = combat_queries::legal_blockers(game, Source::Game, player)
+        .filter_map(|id| Combatant::new(game, id))
+        .collect::<Vec<_>>();
+    blockers.sort_by_key(|b| b.value());
+
+    let mut result = vec![];
+    let mut unblocked = vec![];
+    for attacker in &attackers {
+        let choice = blockers
+            .iter()
+            .position(|b| b.can_block(attacker) && b.kills(attacker) && !attacker.kills(b))
+            .or_else(|| blockers.iter().position(|b| b.can_block(attacker) && !attacker.kills(b)))
+            .or_else(|| {
+                blockers.iter().position(|b| {
+                    b.can_block(attacker) && b.kills(attacker) && b.value() <= attacker.value()
+                })
+            });
+        if let Some(index) = choice {
+            result.push((blockers.remove(index).id, attacker.id));
+        } else {
+            unblocked.push(attacker);
+        }
+    }
+
+    let mut incoming: LifeValue = unblocked.iter().map(|a| a.power.max(0)).sum();
+    let life = game.player(player).life;
+    for attacker in unblocked {
+        if incoming < life {
+            break;
+        }
+        if let Some(index) = blockers.iter().position(|b| b.can_block(attacker)) {
+            // Chump block to survive
+            result.push((blockers.remove(index).id, attacker.id));
+            incoming -= attacker.power.max(0);
+        }
+    }
+
+    result
+}
+
+fn creatures(game: &GameState, player: PlayerName) -> Vec<Combatant> {
+    game.battlefield(player)
+        .iter()
+        .filter(|&&id| card_predicates::creature(game, Source::Game, id).unwrap_or_default())
+        .filter_map(|&id| Combatant::new(game, id))
+        .collect()
+}
+
+fn untapped_creatures(game: &GameState, player: PlayerName) -> Vec<Combatant> {
+    creatures(game, player)
+        .into_iter()
+        .filter(|c| game.card(c.id).is_some_and(|card| card.tapped_state != TappedState::Tapped))
+        .collect()
+}
@@ -0,0 +1,91 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter;
+
+use data::card_states::card_state::Visibility;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::{GameOperationMode, GameState};
+use primitives::game_primitives::{CardId, PlayerName, Zone};
+use tracing::error;
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+/// A [StatePredictor] which hides information the searching player could not
+/// know.
+///
+/// Returns a single copy of the game state in which each opponent's library
+/// has been shuffled and each card in an opponent's hand which is not visible
+/// to the searching player has been swapped with a random card from that
+/// opponent's library which is also not visible to them. Cards which have
+/// been revealed, e.g. via a 'reveal your hand' effect, stay where they are.
+///
+/// The searching player is read from [GameOperationMode::AgentSearch], if the
+/// game is not in agent search mode the state is returned unchanged.
+///
+/// [StatePredictor]: crate::core::state_predictor::StatePredictor
+pub fn determinize(game: &GameState) -> Box<dyn Iterator<Item = GameState>> {
+    let mut copy = game.shallow_clone();
+    if let GameOperationMode::AgentSearch(player) = copy.operation_mode {
+        for opponent in copy.configuration.all_players {
+            if opponent != player {
+                if let Err(halt) = redeal_hidden_cards(&mut copy, player, opponent) {
+                    error!(?halt, "Error redealing hidden cards");
+                }
+            }
+        }
+    }
+    Box::new(iter::once(copy))
+}
+
+fn redeal_hidden_cards(game: &mut GameState, player: PlayerName, opponent: PlayerName) -> Outcome {
+    game.shuffle_library(opponent);
+    let hidden_in_hand = hidden_cards(game, player, game.hand(opponent).iter().copied());
+    let mut hidden_in_library =
+        hidden_cards(game, player, game.library(opponent).iter().copied()).into_iter();
+
+    for hand_card in hidden_in_hand {
+        let Some(library_card) = hidden_in_library.next() else {
+            break;
+        };
+        outcome::execute(|| {
+            move_hidden_card(game, library_card, Zone::Hand)?;
+            move_hidden_card(game, hand_card, Zone::Library)
+        })?;
+    }
+
+    game.shuffle_library(opponent);
+    outcome::OK
+}
+
+fn hidden_cards(
+    game: &GameState,
+    player: PlayerName,
+    cards: impl Iterator<Item = CardId>,
+) -> Vec<CardId> {
+    cards
+        .filter(|&card_id| game.card(card_id).is_some_and(|card| !card.is_visible_to(player)))
+        .collect()
+}
+
+/// Moves a card between hidden zones without firing any game events, since
+/// this swap does not represent an actual game action.
+fn move_hidden_card(game: &mut GameState, card_id: CardId, zone: Zone) -> Outcome {
+    let object_id = game.zones.new_object_id();
+    game.zones.move_card(card_id, zone, object_id)?;
+    let card = game.card_mut(card_id).or_skip()?;
+    card.visibility = Visibility::for_zone(zone);
+    card.revealed_to.clear();
+    outcome::OK
+}
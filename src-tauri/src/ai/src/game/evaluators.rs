@@ -28,14 +28,15 @@ impl StateEvaluator<GameState> for CustomHeuristicEvaluator {
         match game.status {
             GameStatus::Playing => {
                 let life = (game.player(player).life
-                    - game.player(player_queries::next_player_after(game, player)).life)
+                    - game.player(player_queries::next_opponent_after(game, player)).life)
                     as i32;
                 if life != 0 {
                     return life;
                 }
 
                 game.battlefield(player).len() as i32
-                    - game.battlefield(player_queries::next_player_after(game, player)).len() as i32
+                    - game.battlefield(player_queries::next_opponent_after(game, player)).len()
+                        as i32
             }
             GameStatus::GameOver { winners } => {
                 if winners.contains(player) {
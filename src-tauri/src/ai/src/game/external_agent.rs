@@ -0,0 +1,300 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for AI agents implemented by a separate program.
+//!
+//! The program is started on first use and receives one JSON request per line
+//! on its standard input. Each request has the following fields:
+//!
+//! - `player`: Name of the player the program is choosing for, e.g. `"One"`.
+//! - `state`: An [ExternalGameState] describing the visible game state.
+//! - `legal_actions`: List of legal actions, each in its standard serde JSON
+//!   form.
+//!
+//! The program must respond with a single line on its standard output
+//! containing a JSON object with an `action` field giving the index of the
+//! selected action within `legal_actions`, e.g. `{"action": 0}`.
+//!
+//! Requests contain the complete game state, and programs should not rely on
+//! state persisting between requests since the program may be restarted at
+//! any time. A program which exits, fails to respond within its configured
+//! timeout, or sends an invalid response forfeits the game.
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use data::actions::game_action::GameAction;
+use data::actions::prompt_action::PromptAction;
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::numerics::{LifeValue, TurnNumber};
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use data::player_states::game_agent::{ExternalAgent, GameAgentImpl, PromptAgentImpl};
+use data::player_states::player_state::PlayerQueries;
+use data::prompts::prompt::Prompt;
+use primitives::game_primitives::{CardId, PlayerName};
+use rules::legality::legal_actions::LegalActions;
+use rules::legality::{legal_actions, legal_prompt_actions};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+/// Game agent which forwards decisions to an external program.
+#[derive(Clone)]
+pub struct ExternalAgentImpl {
+    config: ExternalAgent,
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl ExternalAgentImpl {
+    pub fn new(config: ExternalAgent) -> Self {
+        Self { config, connection: Arc::new(Mutex::new(Connection::NotStarted)) }
+    }
+
+    /// Asks the external program to select one of `legal_actions`.
+    ///
+    /// Returns None if the program has violated the protocol, in which case
+    /// the agent is considered to have forfeited the game.
+    fn request<T: Serialize + Copy>(
+        &self,
+        game: &GameState,
+        player: PlayerName,
+        legal_actions: &[T],
+    ) -> Option<T> {
+        let mut connection = self.connection.lock().expect("Mutex is poisoned");
+        if matches!(*connection, Connection::Forfeited) {
+            return None;
+        }
+
+        let request = ExternalRequest { player, state: game_state(game, player), legal_actions };
+        match self.exchange(&mut connection, &request) {
+            Ok(index) if index < legal_actions.len() => Some(legal_actions[index]),
+            Ok(index) => {
+                self.forfeit(&mut connection, format!("Invalid action index {index}"));
+                None
+            }
+            Err(message) => {
+                self.forfeit(&mut connection, message);
+                None
+            }
+        }
+    }
+
+    fn exchange<T: Serialize>(
+        &self,
+        connection: &mut Connection,
+        request: &ExternalRequest<T>,
+    ) -> Result<usize, String> {
+        if matches!(connection, Connection::NotStarted) {
+            *connection = Connection::Running(ExternalProcess::start(&self.config)?);
+        }
+        let Connection::Running(process) = connection else {
+            return Err("Agent is not running".to_string());
+        };
+
+        let mut line = serde_json::to_string(request)
+            .map_err(|e| format!("Error serializing request {e:?}"))?;
+        line.push('\n');
+        process
+            .stdin
+            .write_all(line.as_bytes())
+            .and_then(|()| process.stdin.flush())
+            .map_err(|e| format!("Error writing request {e:?}"))?;
+
+        let response = match process.responses.recv_timeout(self.config.timeout) {
+            Ok(response) => response,
+            Err(RecvTimeoutError::Timeout) => return Err("Timed out".to_string()),
+            Err(RecvTimeoutError::Disconnected) => return Err("Program exited".to_string()),
+        };
+        serde_json::from_str::<ExternalResponse>(&response)
+            .map(|response| response.action)
+            .map_err(|e| format!("Invalid response {response:?} {e:?}"))
+    }
+
+    fn forfeit(&self, connection: &mut Connection, reason: String) {
+        error!(command = ?self.config.command, ?reason, "External agent forfeits the game");
+        *connection = Connection::Forfeited;
+    }
+}
+
+impl GameAgentImpl for ExternalAgentImpl {
+    fn select_action(&self, game: &GameState, player: PlayerName) -> GameAction {
+        let legal = legal_actions::compute(game, player, LegalActions { for_human_player: false });
+        if legal.len() == 1 && !self.has_forfeited() {
+            return legal[0];
+        }
+        self.request(game, player, &legal).unwrap_or(GameAction::Concede)
+    }
+
+    fn incremental_prompt_action(
+        &self,
+        game: &mut GameState,
+        prompt: &Prompt,
+        player: PlayerName,
+    ) -> PromptAction {
+        self.prompt_action(game, prompt, player)
+    }
+}
+
+impl PromptAgentImpl for ExternalAgentImpl {
+    fn top_level_prompt_action(
+        &self,
+        game: &GameState,
+        prompt: &Prompt,
+        player: PlayerName,
+    ) -> PromptAction {
+        self.prompt_action(game, prompt, player)
+    }
+}
+
+impl ExternalAgentImpl {
+    /// Selects a response to a prompt.
+    ///
+    /// Prompts cannot be conceded, so an agent which has forfeited picks the
+    /// first legal response and then concedes at its next game action.
+    fn prompt_action(&self, game: &GameState, prompt: &Prompt, player: PlayerName) -> PromptAction {
+        let legal =
+            legal_prompt_actions::compute(prompt, player, LegalActions { for_human_player: false });
+        assert!(!legal.is_empty(), "No legal prompt actions available");
+        if legal.len() == 1 {
+            return legal[0];
+        }
+        self.request(game, player, &legal).unwrap_or(legal[0])
+    }
+
+    fn has_forfeited(&self) -> bool {
+        matches!(*self.connection.lock().expect("Mutex is poisoned"), Connection::Forfeited)
+    }
+}
+
+enum Connection {
+    NotStarted,
+    Running(ExternalProcess),
+    Forfeited,
+}
+
+struct ExternalProcess {
+    child: Child,
+    stdin: ChildStdin,
+    responses: Receiver<String>,
+}
+
+impl ExternalProcess {
+    fn start(config: &ExternalAgent) -> Result<Self, String> {
+        info!(command = ?config.command, "Starting external agent");
+        let mut child = Command::new(&config.command)
+            .args(&config.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Error starting {:?} {e:?}", config.command))?;
+        let stdin = child.stdin.take().ok_or("Error opening stdin")?;
+        let stdout = child.stdout.take().ok_or("Error opening stdout")?;
+
+        // Responses are read on a separate thread so that requests can time out.
+        let (sender, responses) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { child, stdin, responses })
+    }
+}
+
+impl Drop for ExternalProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[derive(Serialize)]
+struct ExternalRequest<'a, T> {
+    player: PlayerName,
+    state: ExternalGameState,
+    legal_actions: &'a [T],
+}
+
+#[derive(Deserialize)]
+struct ExternalResponse {
+    action: usize,
+}
+
+/// Game state information sent to external agents.
+///
+/// Only information visible to the requesting player is included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalGameState {
+    pub turn_number: TurnNumber,
+    pub active_player: PlayerName,
+    pub step: GamePhaseStep,
+    pub players: Vec<ExternalPlayerState>,
+    pub stack: Vec<ExternalCard>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalPlayerState {
+    pub name: PlayerName,
+    pub life: LifeValue,
+    /// Cards in this player's hand, if they are the requesting player.
+    pub hand: Option<Vec<ExternalCard>>,
+    pub hand_size: usize,
+    pub library_size: usize,
+    pub graveyard: Vec<ExternalCard>,
+    pub battlefield: Vec<ExternalCard>,
+    pub exile: Vec<ExternalCard>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalCard {
+    pub id: CardId,
+    pub name: String,
+}
+
+fn game_state(game: &GameState, player: PlayerName) -> ExternalGameState {
+    ExternalGameState {
+        turn_number: game.turn.turn_number,
+        active_player: game.turn.active_player,
+        step: game.step,
+        players: enum_iterator::all::<PlayerName>()
+            .filter(|&p| game.configuration.all_players.contains(p))
+            .map(|p| ExternalPlayerState {
+                name: p,
+                life: game.player(p).life,
+                hand: (p == player).then(|| cards(game, game.hand(p).iter().copied())),
+                hand_size: game.hand(p).len(),
+                library_size: game.library(p).len(),
+                graveyard: cards(game, game.graveyard(p).iter().copied()),
+                battlefield: cards(game, game.battlefield(p).iter().copied()),
+                exile: cards(game, game.exile(p).iter().copied()),
+            })
+            .collect(),
+        stack: cards(game, game.stack().iter().copied()),
+    }
+}
+
+fn cards(game: &GameState, ids: impl Iterator<Item = impl ToCardId>) -> Vec<ExternalCard> {
+    ids.filter_map(|id| game.card(id))
+        .map(|card| ExternalCard { id: card.id, name: card.displayed_name().to_string() })
+        .collect()
+}
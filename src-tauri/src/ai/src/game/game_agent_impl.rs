@@ -32,17 +32,25 @@ use crate::core::agent::{Agent, AgentData};
 use crate::core::game_state_node::GameStateNode;
 use crate::core::selection_algorithm::SelectionAlgorithm;
 use crate::core::state_evaluator::StateEvaluator;
+use crate::game::combat_evaluator;
 use crate::game::prompt_state_node_impl::PromptStateNode;
 
+/// Maximum time to spend searching for an action.
+const SEARCH_DURATION: Duration = Duration::from_secs(10);
+
 impl<TSelector, TEvaluator> GameAgentImpl for AgentData<TSelector, TEvaluator, GameState>
 where
     TSelector: SelectionAlgorithm<GameState, TEvaluator> + Clone,
     TEvaluator: StateEvaluator<GameState> + Clone,
 {
     fn select_action(&self, game: &GameState, player: game_primitives::PlayerName) -> GameAction {
+        if let Some(action) = combat_evaluator::select_action(game, player) {
+            return action;
+        }
+
         let mut copy = game.shallow_clone();
         copy.operation_mode = GameOperationMode::AgentSearch(player);
-        select_action_impl(self, copy, player).as_game_action()
+        select_action_impl(self, copy, player, SEARCH_DURATION).as_game_action()
     }
 
     fn incremental_prompt_action(
@@ -75,7 +83,11 @@ where
         let mut copy = game.shallow_clone();
         copy.operation_mode = GameOperationMode::AgentSearch(player);
         let state = PromptStateNode { game: copy, prompt: Some(prompt.clone()) };
-        select_action_impl(self, state, player).as_prompt_action()
+        let duration = game
+            .configuration
+            .prompt_timeout
+            .map_or(SEARCH_DURATION, |timeout| timeout.min(SEARCH_DURATION));
+        select_action_impl(self, state, player, duration).as_prompt_action()
     }
 }
 
@@ -83,6 +95,7 @@ fn select_action_impl<TState, TSelector, TEvaluator>(
     agent: &AgentData<TSelector, TEvaluator, TState>,
     state: TState,
     player: TState::PlayerName,
+    duration: Duration,
 ) -> TState::Action
 where
     TState: GameStateNode + Clone,
@@ -95,15 +108,14 @@ where
         return legal[0];
     }
 
-    let deadline = Duration::from_secs(10);
     match command_line::flags().tracing_style {
         TracingStyle::AggregateTime | TracingStyle::None => {
-            agent.pick_action(Instant::now() + deadline, &state)
+            agent.pick_action(Instant::now() + duration, &state)
         }
         TracingStyle::Forest => {
             let info_subscriber = tracing_subscriber::fmt().with_max_level(Level::INFO).finish();
             subscriber::with_default(info_subscriber, || {
-                agent.pick_action(Instant::now() + deadline, &state)
+                agent.pick_action(Instant::now() + duration, &state)
             })
         }
     }
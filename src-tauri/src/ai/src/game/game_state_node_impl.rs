@@ -16,11 +16,13 @@ use ai_core::core::agent_state::AgentState;
 use data::actions::agent_action::AgentAction;
 use data::game_states::game_state;
 use data::game_states::game_state::GameState;
+use enumset::EnumSet;
 use primitives::game_primitives;
 use rules::action_handlers::actions;
 use rules::action_handlers::actions::ExecuteAction;
 use rules::legality::legal_actions;
 use rules::legality::legal_actions::LegalActions;
+use tracing::error;
 
 use crate::core::game_state_node::{GameStateNode, GameStatus};
 
@@ -50,10 +52,16 @@ impl GameStateNode for GameState {
     }
 
     fn execute_action(&mut self, player: game_primitives::PlayerName, action: AgentAction) {
-        actions::execute(self, player, action.as_game_action(), ExecuteAction {
+        let result = actions::execute(self, player, action.as_game_action(), ExecuteAction {
             skip_undo_tracking: true,
             validate: false,
         });
+        if let Err(rule_error) = result {
+            // The simulated game is no longer consistent, so end it as a draw
+            // rather than continuing to search from this state.
+            error!(?player, %rule_error, "Rule error during agent search");
+            self.status = game_state::GameStatus::GameOver { winners: EnumSet::empty() };
+        }
     }
 
     fn set_agent_state(&mut self, agent_state: AgentState<Self::PlayerName, Self::Action>) {
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 pub mod agents;
+pub mod combat_evaluator;
+pub mod determinization;
 pub mod evaluators;
+pub mod external_agent;
 pub mod game_agent_impl;
 pub mod game_state_node_impl;
+pub mod opening_hand;
 mod prompt_state_node_impl;
@@ -0,0 +1,115 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::ManaValue;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{CardId, PlayerName, Source};
+use rules::predicates::card_predicates;
+use rules::queries::card_queries;
+
+/// Hand size at or below which a hand is always kept.
+const ALWAYS_KEEP_SIZE: usize = 5;
+
+/// Highest mana value of a spell considered castable in the first few turns.
+const EARLY_PLAY_MANA_VALUE: ManaValue = 3;
+
+/// Average mana value of nonland cards above which a deck prefers to draw
+/// first.
+const SLOW_DECK_MANA_VALUE: f64 = 4.0;
+
+/// Returns true if `player` should play first when given the choice, or false
+/// if they should draw first.
+///
+/// Playing first is almost always correct in two-player games, except for
+/// slow decks which care more about card advantage than tempo.
+pub fn choose_to_play_first(game: &GameState, player: PlayerName) -> bool {
+    let mana_values = game
+        .library(player)
+        .iter()
+        .chain(game.hand(player))
+        .filter(|&&id| !is_land(game, id))
+        .map(|&id| mana_value(game, id))
+        .collect::<Vec<_>>();
+    if mana_values.is_empty() {
+        return true;
+    }
+
+    let average = mana_values.iter().sum::<ManaValue>() as f64 / mana_values.len() as f64;
+    average < SLOW_DECK_MANA_VALUE
+}
+
+/// Returns true if `player` should mulligan their current hand, given that
+/// they have already taken `mulligans_taken` mulligans.
+///
+/// Uses the London mulligan: the hand being evaluated is always seven cards,
+/// but one card will be put on the bottom of the library for each mulligan
+/// taken beyond the free mulligans allowed by the game's house rules.
+pub fn should_mulligan(game: &GameState, player: PlayerName, mulligans_taken: usize) -> bool {
+    let hand = game.hand(player);
+    let keep_size = hand
+        .len()
+        .saturating_sub(game.configuration.rules.mulligan_cards_to_bottom(mulligans_taken));
+    if keep_size <= ALWAYS_KEEP_SIZE {
+        return false;
+    }
+
+    let lands = hand.iter().filter(|&&id| is_land(game, id)).count();
+    if lands < 2 || lands > keep_size - 2 {
+        return true;
+    }
+
+    let early_plays = hand
+        .iter()
+        .filter(|&&id| !is_land(game, id) && mana_value(game, id) <= EARLY_PLAY_MANA_VALUE)
+        .count();
+    early_plays == 0
+}
+
+/// Selects `count` cards from `player`'s hand to put on the bottom of their
+/// library after a mulligan.
+///
+/// Aims to keep roughly three lands for every seven cards, bottoming excess
+/// lands first and otherwise the most expensive spells.
+pub fn cards_to_bottom(game: &GameState, player: PlayerName, count: usize) -> Vec<CardId> {
+    let mut lands =
+        game.hand(player).iter().copied().filter(|&id| is_land(game, id)).collect::<Vec<_>>();
+    let mut spells =
+        game.hand(player).iter().copied().filter(|&id| !is_land(game, id)).collect::<Vec<_>>();
+    spells.sort_by_key(|&id| mana_value(game, id));
+
+    let keep_size = (lands.len() + spells.len()).saturating_sub(count);
+    let target_lands = ((keep_size * 3 + 3) / 7).max(2);
+    let mut result = vec![];
+    while result.len() < count {
+        let next = if lands.len() > target_lands || spells.is_empty() {
+            lands.pop()
+        } else {
+            spells.pop()
+        };
+        let Some(id) = next else {
+            break;
+        };
+        result.push(id);
+    }
+    result
+}
+
+fn is_land(game: &GameState, id: CardId) -> bool {
+    card_predicates::land(game, Source::Game, id).unwrap_or_default()
+}
+
+fn mana_value(game: &GameState, id: CardId) -> ManaValue {
+    card_queries::mana_value(game, Source::Game, id).unwrap_or_default()
+}
@@ -14,4 +14,5 @@
 
 pub mod child_score;
 pub mod monte_carlo_search;
+pub mod search_checkpoint;
 pub mod uct1;
@@ -33,7 +33,7 @@ use petgraph::Direction;
 use rand::prelude::IteratorRandom;
 use rand::SeedableRng;
 use rand_xoshiro::SplitMix64;
-use tracing::{info, instrument};
+use tracing::{error, info, instrument};
 use utils::command_line;
 use utils::command_line::TracingStyle;
 
@@ -41,6 +41,8 @@ use crate::core::game_state_node::{GameStateNode, GameStatus};
 use crate::core::selection_algorithm::SelectionAlgorithm;
 use crate::core::state_evaluator::StateEvaluator;
 use crate::monte_carlo::child_score::{ChildScoreAlgorithm, SelectionMode};
+use crate::monte_carlo::search_checkpoint;
+use crate::monte_carlo::search_checkpoint::{CheckpointOptions, SearchCheckpoint};
 
 /// Plays out a game using random moves until a terminal state is reached, then
 /// evaluates the result using the provided state evaluator.
@@ -123,6 +125,9 @@ where
 {
     pub child_score_algorithm: TScoreAlgorithm,
     pub max_iterations: Option<u32>,
+    /// Options for periodically saving search progress to disk, or None to
+    /// disable checkpointing.
+    pub checkpoint: Option<CheckpointOptions>,
     pub phantom_data: PhantomData<TState>,
 }
 
@@ -219,15 +224,32 @@ where
         evaluator: &TEvaluator,
         player: TState::PlayerName,
     ) -> TState::Action {
-        let mut agent_state =
-            MonteCarloAgentState { graph: SearchGraph::new(), search_operation: None };
-        let root =
-            agent_state.graph.add_node(SearchNode { total_reward: 0.0, visit_count: 1, player });
-        let mut i = 0;
+        let resumed = self
+            .checkpoint
+            .as_ref()
+            .filter(|options| options.resume())
+            .and_then(|options| Self::load_checkpoint(options, initial_game, player));
+        let (mut agent_state, root, mut i) = if let Some(checkpoint) = resumed {
+            info!("Resuming search from checkpoint after {} iterations", checkpoint.iterations);
+            let state = MonteCarloAgentState { graph: checkpoint.graph, search_operation: None };
+            (state, checkpoint.root, checkpoint.iterations)
+        } else {
+            let mut state =
+                MonteCarloAgentState { graph: SearchGraph::new(), search_operation: None };
+            let root =
+                state.graph.add_node(SearchNode { total_reward: 0.0, visit_count: 1, player });
+            (state, root, 0)
+        };
+
         while !should_halt(i) {
             if i > 0 && i % 1000 == 0 {
                 println!("Iteration {}", i);
             }
+            if let Some(options) = &self.checkpoint {
+                if i > 0 && i % options.interval() == 0 {
+                    self.write_checkpoint(options, initial_game, &agent_state.graph, root, i);
+                }
+            }
             let mut game_copy = initial_game.make_copy();
             game_copy.set_state(agent_state);
             let node = self.tree_policy(&mut game_copy, root);
@@ -247,10 +269,72 @@ where
             SelectionMode::Best,
         );
 
+        if let Some(options) = &self.checkpoint {
+            // The next search starts from a different game state, so a completed
+            // search must not be resumed.
+            if let Err(e) = search_checkpoint::remove(options.path()) {
+                error!(%e, "Error removing search checkpoint");
+            }
+        }
         self.log_results(i, &agent_state.graph, root);
         action
     }
 
+    /// Saves the current search progress to disk and logs the best action
+    /// found so far.
+    fn write_checkpoint(
+        &self,
+        options: &CheckpointOptions,
+        initial_game: &TState,
+        graph: &SearchGraph<TState::PlayerName, TState::Action>,
+        root: NodeIndex,
+        iterations: u32,
+    ) {
+        let legal = initial_game.legal_actions(graph[root].player).collect::<BTreeSet<_>>();
+        let best_action = if graph.edges(root).any(|e| legal.contains(&e.weight().action)) {
+            Some(self.best_child(graph, root, legal, SelectionMode::Best).0)
+        } else {
+            None
+        };
+        info!(?best_action, "Current best action after {} iterations", iterations);
+        let checkpoint = SearchCheckpoint { graph: graph.clone(), root, iterations, best_action };
+        if let Err(e) = search_checkpoint::save(options.path(), &checkpoint) {
+            error!(%e, "Error writing search checkpoint");
+        }
+    }
+
+    /// Reads the checkpoint described by `options`, if any.
+    ///
+    /// Returns None if the checkpoint cannot be read or if it was not created
+    /// by a search from `initial_game` for `player`, in which case the search
+    /// starts over.
+    fn load_checkpoint(
+        options: &CheckpointOptions,
+        initial_game: &TState,
+        player: TState::PlayerName,
+    ) -> Option<SearchCheckpoint<TState::PlayerName, TState::Action>> {
+        let checkpoint = match search_checkpoint::load(options.path()) {
+            Ok(checkpoint) => checkpoint?,
+            Err(e) => {
+                error!(%e, "Error loading search checkpoint");
+                return None;
+            }
+        };
+        let legal = initial_game.legal_actions(player).collect::<BTreeSet<_>>();
+        let matches = checkpoint.graph.node_weight(checkpoint.root).is_some_and(|root| {
+            root.player == player
+                && checkpoint
+                    .graph
+                    .edges(checkpoint.root)
+                    .all(|edge| legal.contains(&edge.weight().action))
+        });
+        if !matches {
+            info!(path = ?options.path(), "Ignoring checkpoint for a different game state");
+            return None;
+        }
+        Some(checkpoint)
+    }
+
     #[instrument(level = "debug", skip_all)]
     fn log_results(
         &self,
@@ -0,0 +1,126 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for persisting Monte Carlo search progress to disk.
+//!
+//! For very long search budgets, the search graph is periodically written to
+//! a checkpoint file so that work is not lost if the process is killed. A
+//! search can then be resumed from the most recent checkpoint.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use ai_core::core::monte_carlo_agent_state::SearchGraph;
+use petgraph::prelude::NodeIndex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for periodically saving search progress.
+#[derive(Debug, Clone)]
+pub struct CheckpointOptions {
+    path: PathBuf,
+    interval: u32,
+    resume: bool,
+}
+
+impl CheckpointOptions {
+    /// Creates options to write a checkpoint to the file at `path` every
+    /// `interval` search iterations.
+    ///
+    /// If `resume` is true, an existing checkpoint file at `path` is loaded
+    /// when a search starts and the search continues from that point. A
+    /// checkpoint is discarded if it does not match the game state being
+    /// searched, and the file is deleted once a search runs to completion.
+    ///
+    /// Returns an error if `interval` is zero.
+    pub fn new(path: PathBuf, interval: u32, resume: bool) -> Result<Self, String> {
+        if interval == 0 {
+            return Err(format!("Checkpoint interval for {path:?} must be greater than zero"));
+        }
+        Ok(Self { path, interval, resume })
+    }
+
+    /// File to write checkpoints to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of search iterations between each checkpoint. Always greater
+    /// than zero.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Whether to continue from an existing checkpoint file when a search
+    /// starts.
+    pub fn resume(&self) -> bool {
+        self.resume
+    }
+}
+
+/// Serialized state of an in-progress search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchCheckpoint<TPlayerName, TAction> {
+    /// Search graph at the time of the checkpoint.
+    pub graph: SearchGraph<TPlayerName, TAction>,
+
+    /// Root node of the search within `graph`.
+    pub root: NodeIndex,
+
+    /// Number of search iterations completed.
+    pub iterations: u32,
+
+    /// The best action found so far, if any.
+    pub best_action: Option<TAction>,
+}
+
+/// Writes a checkpoint to the file at `path`.
+///
+/// The checkpoint is first written to a temporary file and then renamed, so
+/// that a process kill during writing does not corrupt an existing checkpoint.
+pub fn save<TPlayerName: Serialize, TAction: Serialize>(
+    path: &Path,
+    checkpoint: &SearchCheckpoint<TPlayerName, TAction>,
+) -> Result<(), String> {
+    let temp = path.with_extension("tmp");
+    let data = serde_json::to_vec(checkpoint)
+        .map_err(|e| format!("Error serializing search checkpoint {e:?}"))?;
+    fs::write(&temp, data)
+        .map_err(|e| format!("Error writing search checkpoint {temp:?} {e:?}"))?;
+    fs::rename(&temp, path).map_err(|e| format!("Error renaming search checkpoint {path:?} {e:?}"))
+}
+
+/// Reads a checkpoint from the file at `path`.
+///
+/// Returns None if no checkpoint file exists.
+pub fn load<TPlayerName: DeserializeOwned, TAction: DeserializeOwned>(
+    path: &Path,
+) -> Result<Option<SearchCheckpoint<TPlayerName, TAction>>, String> {
+    let Ok(data) = fs::read(path) else {
+        return Ok(None);
+    };
+    serde_json::from_slice(&data)
+        .map(Some)
+        .map_err(|e| format!("Error deserializing search checkpoint {path:?} {e:?}"))
+}
+
+/// Deletes the checkpoint file at `path`, if any.
+pub fn remove(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Error removing search checkpoint {path:?} {e:?}")),
+    }
+}
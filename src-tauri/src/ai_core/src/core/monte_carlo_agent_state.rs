@@ -15,8 +15,9 @@
 use petgraph::graph::NodeIndex;
 use petgraph::Graph;
 use rand_xoshiro::SplitMix64;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchNode<TPlayerName> {
     /// Player who acted to create this node
     pub player: TPlayerName,
@@ -26,7 +27,7 @@ pub struct SearchNode<TPlayerName> {
     pub visit_count: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchEdge<TAction> {
     pub action: TAction,
 }
@@ -17,7 +17,7 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader, LineWriter, Write};
+use std::io::{LineWriter, Write};
 use std::path::Path;
 use std::{env, fs};
 
@@ -78,15 +78,16 @@ fn main() {
     writeln!(file, "{}", output).expect("Error writing to file");
 }
 
+/// Finds card definition functions in a file, either written by hand or
+/// declared via the `card!` macro, in the order they appear.
 fn find_functions(path: impl AsRef<Path>) -> Vec<String> {
-    let mut result = vec![];
-    let re = Regex::new(r"pub fn (?P<name>\w+)\(\) -> CardDefinition").expect("Invalid regex");
-    for l in BufReader::new(File::open(path).expect("File not found")).lines() {
-        let line = l.expect("Error reading line");
-        if let Some(captures) = re.captures(&line) {
-            result.push(captures["name"].to_string());
-        }
-    }
-
-    result
+    let text = fs::read_to_string(path).expect("File not found");
+    let re = Regex::new(
+        r"pub fn (?P<name>\w+)\(\) -> CardDefinition|card!\s*[\(\{]\s*(?:///[^\n]*\s*)*(?P<macro_name>\w+)",
+    )
+    .expect("Invalid regex");
+    re.captures_iter(&text)
+        .filter_map(|captures| captures.name("name").or_else(|| captures.name("macro_name")))
+        .map(|name| name.as_str().to_string())
+        .collect()
 }
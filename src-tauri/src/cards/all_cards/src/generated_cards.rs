@@ -0,0 +1,164 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Once;
+
+use abilities::keyword_abilities::{
+    deathtouch, flying, haste, infect, lifelink, protection, vigilance, wither,
+};
+use data::card_definitions::card_definition::CardDefinition;
+use data::card_definitions::card_name::CardName;
+use data::card_definitions::definitions;
+use data::printed_cards::custom_card::{self, CustomCardKeyword};
+use data::printed_cards::database_card::DatabaseOracleText;
+use database::sqlite_database::SqliteDatabase;
+use primitives::game_primitives::Color;
+use tracing::info;
+use uuid::Uuid;
+
+static INITIALIZED: Once = Once::new();
+
+/// Keyword abilities which can be added to generated card definitions.
+#[derive(Debug, Clone, Copy)]
+enum Keyword {
+    Deathtouch,
+    Flying,
+    Haste,
+    Infect,
+    Lifelink,
+    ProtectionFrom(Color),
+    Vigilance,
+    Wither,
+}
+
+/// Registers generated card definitions for every card in the Oracle database
+/// whose rules text is empty (vanilla cards) or consists only of implemented
+/// keyword abilities (French vanilla cards), as well as for every user-defined
+/// [custom_card].
+///
+/// Cards with hand-written definitions are not affected. This must be called
+/// before any card definition is looked up, and has no effect after the first
+/// call.
+pub fn initialize(database: &SqliteDatabase) {
+    INITIALIZED.call_once(|| {
+        let mut faces = BTreeMap::<Uuid, Vec<DatabaseOracleText>>::new();
+        for face in database.fetch_all_oracle_texts() {
+            faces.entry(face.scryfall_oracle_id).or_default().push(face);
+        }
+
+        let mut count = 0;
+        for (oracle_id, faces) in faces {
+            let Some(keywords) = single_face_keywords(&faces) else {
+                continue;
+            };
+            definitions::register_generated(definition(CardName(oracle_id), keywords));
+            count += 1;
+        }
+        info!(count, "Generated card definitions");
+
+        let mut custom = 0;
+        for card in custom_card::all() {
+            let keywords = card.keywords.iter().map(|&keyword| keyword.into());
+            definitions::register_generated(definition(card.card_name(), keywords));
+            custom += 1;
+        }
+        info!(custom, "Generated custom card definitions");
+    });
+}
+
+impl From<CustomCardKeyword> for Keyword {
+    fn from(value: CustomCardKeyword) -> Self {
+        match value {
+            CustomCardKeyword::Deathtouch => Keyword::Deathtouch,
+            CustomCardKeyword::Flying => Keyword::Flying,
+            CustomCardKeyword::Haste => Keyword::Haste,
+            CustomCardKeyword::Infect => Keyword::Infect,
+            CustomCardKeyword::Lifelink => Keyword::Lifelink,
+            CustomCardKeyword::Vigilance => Keyword::Vigilance,
+            CustomCardKeyword::Wither => Keyword::Wither,
+        }
+    }
+}
+
+/// Builds a card definition with the provided keyword abilities.
+fn definition(name: CardName, keywords: impl IntoIterator<Item = Keyword>) -> CardDefinition {
+    let mut definition = CardDefinition::new(name);
+    for keyword in keywords {
+        definition = match keyword {
+            Keyword::Deathtouch => definition.ability(deathtouch::ability()),
+            Keyword::Flying => definition.ability(flying::ability()),
+            Keyword::Haste => definition.ability(haste::ability()),
+            Keyword::Infect => definition.ability(infect::ability()),
+            Keyword::Lifelink => definition.ability(lifelink::ability()),
+            Keyword::ProtectionFrom(color) => definition.ability(protection::from_color(color)),
+            Keyword::Vigilance => definition.ability(vigilance::ability()),
+            Keyword::Wither => definition.ability(wither::ability()),
+        };
+    }
+    definition
+}
+
+/// Returns the keywords of a card if it has a single face with a "normal"
+/// layout whose rules text consists only of implemented keywords.
+fn single_face_keywords(faces: &[DatabaseOracleText]) -> Option<Vec<Keyword>> {
+    let [face] = faces else {
+        return None;
+    };
+    if face.layout != "normal" || face.side.is_some() {
+        return None;
+    }
+    parse_keywords(face.text.as_deref().unwrap_or_default())
+}
+
+/// Parses rules text consisting of comma-separated keyword lists, ignoring
+/// reminder text. Returns None if any other text is present.
+fn parse_keywords(text: &str) -> Option<Vec<Keyword>> {
+    without_reminder_text(text)
+        .split(['\n', ','])
+        .map(str::trim)
+        .filter(|keyword| !keyword.is_empty())
+        .map(|keyword| match keyword.to_lowercase().as_str() {
+            "deathtouch" => Some(Keyword::Deathtouch),
+            "flying" => Some(Keyword::Flying),
+            "haste" => Some(Keyword::Haste),
+            "infect" => Some(Keyword::Infect),
+            "lifelink" => Some(Keyword::Lifelink),
+            "vigilance" => Some(Keyword::Vigilance),
+            "wither" => Some(Keyword::Wither),
+            keyword => keyword
+                .strip_prefix("protection from ")
+                .and_then(Color::from_word)
+                .map(Keyword::ProtectionFrom),
+        })
+        .collect()
+}
+
+/// Removes parenthesized reminder text from rules text.
+fn without_reminder_text(text: &str) -> String {
+    let mut depth = 0;
+    text.chars()
+        .filter(|&c| {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    return false;
+                }
+                _ => {}
+            }
+            depth == 0
+        })
+        .collect()
+}
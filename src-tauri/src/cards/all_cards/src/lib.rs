@@ -13,3 +13,5 @@
 // limitations under the License.
 
 pub mod card_list;
+pub mod generated_cards;
+pub mod scripted_cards;
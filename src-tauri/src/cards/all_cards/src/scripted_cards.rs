@@ -0,0 +1,71 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Once;
+
+use data::card_definitions::card_definition::CardDefinition;
+use data::card_definitions::card_name::CardName;
+use data::card_definitions::definitions;
+use scripting::{scripted_abilities, scripts};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+static INITIALIZED: Once = Once::new();
+
+/// Registers card definitions for every script in the
+/// [scripts::directory] which defines a `card_name()` function returning the
+/// Scryfall Oracle ID of the card it implements, e.g.
+///
+/// ```rhai
+/// fn card_name() {
+///     "00000000-0000-0000-0000-000000000000"
+/// }
+/// ```
+///
+/// Scripts which define `can_target` are given a
+/// [scripted_abilities::targeted_spell] ability, and all other scripts are
+/// given a [scripted_abilities::spell] ability.
+///
+/// Cards with hand-written definitions are not affected. This must be called
+/// before any card definition is looked up, and has no effect after the first
+/// call.
+pub fn initialize() {
+    INITIALIZED.call_once(|| {
+        let mut count = 0;
+        for name in scripts::all_names() {
+            if !scripts::has_function(&name, "card_name") {
+                continue;
+            }
+            let Some(oracle_id) = scripts::call::<String>(&name, "card_name", ()) else {
+                continue;
+            };
+            let Ok(oracle_id) = Uuid::parse_str(&oracle_id) else {
+                warn!(?name, ?oracle_id, "Invalid card name in script");
+                continue;
+            };
+
+            // Abilities refer to their script by name for the rest of the program.
+            let name: &'static str = Box::leak(name.into_boxed_str());
+            let definition = CardDefinition::new(CardName(oracle_id));
+            let definition = if scripts::has_function(name, "can_target") {
+                definition.ability(scripted_abilities::targeted_spell(name))
+            } else {
+                definition.ability(scripted_abilities::spell(name))
+            };
+            definitions::register_generated(definition);
+            count += 1;
+        }
+        info!(count, "Registered scripted card definitions");
+    });
+}
@@ -12,13 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::card_definitions::card_definition::CardDefinition;
-use data::card_definitions::card_name;
+use invokable_macro::card;
 
-pub fn alpine_grizzly() -> CardDefinition {
-    CardDefinition::new(card_name::ALPINE_GRIZZLY)
-}
+card!(alpine_grizzly);
 
-pub fn alpha_tyrranax() -> CardDefinition {
-    CardDefinition::new(card_name::ALPHA_TYRRANAX)
-}
+card!(alpha_tyrranax);
@@ -23,7 +23,7 @@ use rules::prompt_handling::prompts;
 
 pub fn brainstorm() -> CardDefinition {
     CardDefinition::new(card_name::BRAINSTORM).ability(SpellAbility::new().effect(|g, c| {
-        library::draw_cards(g, c, c.controller, 3);
+        library::draw_cards(g, c, c.controller, 3)?;
         let cards = prompts::select_ordered_from(
             g,
             c.controller,
@@ -18,6 +18,7 @@ use data::card_definitions::ability_definition::SpellAbility;
 use data::card_definitions::card_definition::CardDefinition;
 use data::card_definitions::card_name;
 use rules::mutations::library;
+use utils::outcome;
 
 pub fn craw_wurm() -> CardDefinition {
     CardDefinition::new(card_name::CRAW_WURM)
@@ -26,8 +27,10 @@ pub fn craw_wurm() -> CardDefinition {
 pub fn crystal_spray() -> CardDefinition {
     CardDefinition::new(card_name::CRYSTAL_SPRAY).ability(
         SpellAbility::new().targets(targets::spell_or_permanent()).effect(|g, c, target| {
-            change_text::change_basic_land_types_or_colors_this_turn(g, c, target);
-            library::draw(g, c, c.controller);
+            outcome::execute(|| {
+                change_text::change_basic_land_types_or_colors_this_turn(g, c, target)
+            })?;
+            library::draw(g, c, c.controller)
         }),
     )
 }
@@ -29,12 +29,12 @@ use rules::predicates::card_predicates;
 pub fn dance_of_the_skywise() -> CardDefinition {
     CardDefinition::new(card_name::DANCE_OF_THE_SKYWISE).ability(
         SpellAbility::new().targets(targets::creature_you_control()).effect(move |g, c, target| {
-            colors::set_this_turn(g, c, target, Color::Blue);
+            colors::set_this_turn(g, c, target, Color::Blue)?;
             let types = CreatureType::Dragon | CreatureType::Illusion;
-            creature_types::set_this_turn(g, c, target, types);
-            base_power_toughness::set_this_turn(g, c, target, 4, 4);
-            lose_all_abilities::set_this_turn(g, c, target);
-            flying::gain_this_turn(g, c, target);
+            creature_types::set_this_turn(g, c, target, types)?;
+            base_power_toughness::set_this_turn(g, c, target, 4, 4)?;
+            lose_all_abilities::set_this_turn(g, c, target)?;
+            flying::gain_this_turn(g, c, target)
         }),
     )
 }
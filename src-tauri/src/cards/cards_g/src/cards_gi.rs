@@ -20,9 +20,9 @@ use data::card_definitions::card_name;
 
 pub fn giant_growth() -> CardDefinition {
     CardDefinition::new(card_name::GIANT_GROWTH).ability(
-        SpellAbility::new().targets(targets::creature()).effect(|g, s, target| {
-            power_toughness::add_this_turn(g, s, target, 3, 3);
-        }),
+        SpellAbility::new()
+            .targets(targets::creature())
+            .effect(|g, s, target| power_toughness::add_this_turn(g, s, target, 3, 3)),
     )
 }
 
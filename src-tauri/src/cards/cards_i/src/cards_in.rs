@@ -25,23 +25,17 @@ pub fn insidious_will() -> CardDefinition {
     CardDefinition::new(card_name::INSIDIOUS_WILL).ability(
         SpellAbility::new().modal_effect(
             ModalEffect::new()
-                .mode(AbilityMode::new().targets(targets::spell()).effect(|g, c, target| {
-                    spells::counter(g, c, target);
-                }))
-                .mode(AbilityMode::new().targets(targets::spell()).effect(|g, c, target| {
-                    spells::choose_new_targets(g, c, c.controller, target);
-                }))
+                .mode(AbilityMode::new().targets(targets::spell()).effect(spells::counter))
+                .mode(
+                    AbilityMode::new().targets(targets::spell()).effect(|g, c, target| {
+                        spells::choose_new_targets(g, c, c.controller, target)
+                    }),
+                )
                 .mode(
                     AbilityMode::new()
                         .targets(targets::spell_with_type(CardType::Instant | CardType::Sorcery))
                         .effect(|g, c, target| {
-                            create_copy::of_spell(
-                                g,
-                                c,
-                                target,
-                                c.controller,
-                                ChooseNewTargets::Yes,
-                            );
+                            create_copy::of_spell(g, c, target, c.controller, ChooseNewTargets::Yes)
                         }),
                 ),
         ),
@@ -25,8 +25,6 @@ pub fn mystic_retrieval() -> CardDefinition {
             .targets(targets::card_in_your_graveyard_with_type(
                 CardType::Instant | CardType::Sorcery,
             ))
-            .effect(|g, c, target| {
-                move_card::run(g, c, target, Zone::Hand);
-            }),
+            .effect(|g, c, target| move_card::run(g, c, target, Zone::Hand)),
     )
 }
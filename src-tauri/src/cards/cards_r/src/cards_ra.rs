@@ -26,19 +26,17 @@ pub fn ray_of_command() -> CardDefinition {
     CardDefinition::new(card_name::RAY_OF_COMMAND).ability(
         SpellAbility::new().targets(targets::creature_opponent_controls()).effect(
             |g, c, target| {
-                permanents::untap(g, c.source(), target);
-                change_controller::gain_control_this_turn(g, c, c.controller, c.event_id, target);
-                haste::gain_this_turn(g, c, target);
+                permanents::untap(g, c.source(), target)?;
+                change_controller::gain_control_this_turn(g, c, c.controller, c.event_id, target)?;
+                haste::gain_this_turn(g, c, target)?;
                 dispatch::add_card_event(g, target, move |events| {
                     events.controller_changed.add_one_time_trigger(
                         c,
                         target,
                         move |_, _, data| Some(data.old_controller == c.controller),
-                        move |g, _| {
-                            permanents::tap(g, c.source(), target);
-                        },
+                        move |g, _| permanents::tap(g, c.source(), target),
                     )
-                });
+                })
             },
         ),
     )
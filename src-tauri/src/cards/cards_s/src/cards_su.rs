@@ -19,24 +19,27 @@ use data::card_definitions::card_name;
 use data::card_states::zones::ZoneQueries;
 use primitives::game_primitives::{PermanentId, Zone};
 use rules::mutations::{create_copy, permanents};
+use utils::outcome;
+use utils::outcome::OrSkip;
 
 pub fn supplant_form() -> CardDefinition {
     CardDefinition::new(card_name::SUPPLANT_FORM).ability(
         SpellAbility::new().targets(targets::creature()).effect(|g, c, target: PermanentId| {
-            if let Some(card) = g.card(target) {
-                // Store card state before target leaves battlefield
-                let (card_id, facing, choices) = (card.id, card.facing, card.cast_choices.clone());
-                permanents::return_to_hand(g, c, card_id);
-                create_copy::of_card_in_zone(
-                    g,
-                    c,
-                    card_id,
-                    c.controller,
-                    Zone::Battlefield,
-                    facing,
-                    choices,
-                );
-            }
+            let card = g.card(target).or_skip()?;
+            // Store card state before target leaves battlefield
+            let (card_id, facing, choices) = (card.id, card.facing, card.cast_choices.clone());
+            permanents::return_to_hand(g, c, card_id)?;
+            create_copy::of_card_in_zone(
+                g,
+                c,
+                card_id,
+                c.controller,
+                Zone::Battlefield,
+                facing,
+                choices,
+            )
+            .or_skip()?;
+            outcome::OK
         }),
     )
 }
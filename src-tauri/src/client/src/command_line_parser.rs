@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::PathBuf;
+
 use clap::Parser;
 use utils::command_line::{CommandLine, TracingStyle};
 
@@ -26,10 +28,16 @@ pub struct CommandLineParser {
         default_value_t = TracingStyle::Forest,
         help = "Configuration for capturing program traces")]
     pub tracing_style: TracingStyle,
+    #[arg(long, help = "Program to play against in place of the built-in AI opponent")]
+    pub external_agent: Option<PathBuf>,
+    #[arg(long, help = "Play in a text-mode terminal interface instead of the desktop client")]
+    pub tui: bool,
+    #[arg(long, help = "Import rulings from a Scryfall 'Rulings' bulk data file and exit")]
+    pub import_rulings: Option<PathBuf>,
 }
 
 impl CommandLineParser {
     pub fn build(self) -> CommandLine {
-        CommandLine { tracing_style: self.tracing_style }
+        CommandLine { tracing_style: self.tracing_style, external_agent: self.external_agent }
     }
 }
@@ -0,0 +1,141 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, thread};
+
+use data::game_states::serialized_game_state::SerializedGameState;
+use data::users::user_state::UserActivity;
+use database::sqlite_database::SqliteDatabase;
+use display::commands::command::Command;
+use display::commands::scene_identifier::SceneIdentifier;
+use game::server_data;
+use utils::paths;
+use utils::paths::LOG_FILE;
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+/// Number of lines from the end of the log file to include in a crash report.
+const LOG_LINES: usize = 500;
+
+/// Set while a crash report is being written, so that errors while writing
+/// the report do not themselves produce a report.
+static WRITING_REPORT: AtomicBool = AtomicBool::new(false);
+
+/// Returns true if a crash report is currently being written.
+pub fn is_writing() -> bool {
+    WRITING_REPORT.load(Ordering::SeqCst)
+}
+
+/// Writes a crash report bundle to the data directory and returns its path.
+///
+/// The bundle is a zip file containing the panic message, the serialized state
+/// of the local user's current game (including its random seed and the log of
+/// actions taken), and the most recent log output.
+///
+/// The report is written on a separate thread with its own database
+/// connection, since the panicking thread may hold the lock on the shared
+/// connection. Returns None if the report could not be written.
+pub fn write(message: String) -> Option<PathBuf> {
+    WRITING_REPORT.store(true, Ordering::SeqCst);
+    let result = thread::spawn(move || write_bundle(&message)).join().ok().flatten();
+    WRITING_REPORT.store(false, Ordering::SeqCst);
+    result
+}
+
+fn write_bundle(message: &str) -> Option<PathBuf> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    let directory = paths::get_data_dir().join("crash_reports");
+    fs::create_dir_all(&directory).ok()?;
+    let path = directory.join(format!("crash_{timestamp}.zip"));
+
+    let mut zip = ZipWriter::new(File::create(&path).ok()?);
+    let options = FileOptions::default();
+    let game = current_game();
+
+    zip.start_file("summary.txt", options).ok()?;
+    zip.write_all(summary(message, game.as_ref()).as_bytes()).ok()?;
+
+    if let Some(game) = &game {
+        zip.start_file("game.json", options).ok()?;
+        zip.write_all(serde_json::to_string_pretty(game).ok()?.as_bytes()).ok()?;
+    }
+
+    if let Ok(log) = fs::read_to_string(paths::get_data_dir().join(LOG_FILE.clone())) {
+        let lines = log.lines().collect::<Vec<_>>();
+        zip.start_file("log.txt", options).ok()?;
+        zip.write_all(lines[lines.len().saturating_sub(LOG_LINES)..].join("\n").as_bytes()).ok()?;
+    }
+
+    zip.finish().ok()?;
+    Some(path)
+}
+
+/// Displays the location of a crash report to the local user.
+///
+/// The path is sent to each of the user's connections which is displaying the
+/// scene they were in when the crash occurred. Like [write], this runs on a
+/// separate thread with its own database connection.
+pub fn notify(path: &Path) {
+    let path = path.display().to_string();
+    thread::spawn(move || {
+        let database = SqliteDatabase::new(paths::get_data_dir());
+        let Some(user) = database.fetch_local_user_id().and_then(|id| database.fetch_user(id))
+        else {
+            return;
+        };
+        let scene = match user.activity {
+            UserActivity::Menu => SceneIdentifier::MainMenu,
+            UserActivity::Playing(game_id) => SceneIdentifier::Game(game_id),
+        };
+        for client in server_data::connected_clients(user.id, scene) {
+            client.send(Command::ShowCrashReport(path.clone()));
+        }
+    });
+}
+
+/// Fetches the serialized state of the game the local user is currently
+/// playing, if any.
+fn current_game() -> Option<SerializedGameState> {
+    let database = SqliteDatabase::new(paths::get_data_dir());
+    let user_id = database.fetch_local_user_id()?;
+    let UserActivity::Playing(game_id) = database.fetch_user(user_id)?.activity else {
+        return None;
+    };
+    database.fetch_game(game_id)
+}
+
+fn summary(message: &str, game: Option<&SerializedGameState>) -> String {
+    let mut result = format!("{}\n\n{message}\n", crate::initialize::version());
+    let Some(game) = game else {
+        result.push_str("\nNo game in progress\n");
+        return result;
+    };
+
+    result.push_str(&format!("\nGame: {}\nSeed: {}\n", game.id, game.seed));
+    for (player, actions) in game.player_actions.values() {
+        if actions.is_empty() {
+            continue;
+        }
+        result.push_str(&format!("\nActions for {player:?}:\n"));
+        for taken in actions {
+            result.push_str(&format!("{:?}\n", taken.action));
+        }
+    }
+    result
+}
@@ -20,6 +20,8 @@ use color_eyre::eyre;
 use utils::paths;
 use utils::paths::LOG_FILE;
 
+use crate::crash_report;
+
 pub fn initialize_panic_handler() {
     let (panic_hook, eyre_hook) = HookBuilder::default()
         .panic_section(format!(
@@ -41,8 +43,18 @@ pub fn initialize_panic_handler() {
 }
 
 fn on_panic(panic_hook: &PanicHook, panic_info: &PanicInfo) {
-    let msg = format!("{}", panic_hook.panic_report(panic_info));
-    log::error!("Error: {}", strip_ansi_escapes::strip_str(msg));
+    let msg = strip_ansi_escapes::strip_str(format!("{}", panic_hook.panic_report(panic_info)));
+    log::error!("Error: {}", msg);
+    if crash_report::is_writing() {
+        // Errors while writing a crash report are logged and otherwise ignored
+        return;
+    }
+
+    if let Some(path) = crash_report::write(msg) {
+        log::error!("Crash report written to {}", path.display());
+        eprintln!("A crash report was written to {}", path.display());
+        crash_report::notify(&path);
+    }
 
     // human_panic stack trace for release builds
     #[cfg(not(debug_assertions))]
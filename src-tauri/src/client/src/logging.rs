@@ -21,8 +21,8 @@ use tracing_forest::{ForestLayer, PrettyPrinter, Tag};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Layer};
-use utils::paths;
 use utils::paths::{LOG_ENV, LOG_FILE};
+use utils::{paths, span_timing};
 
 /// Initializes global logging behavior for the 'tracing' crate.
 pub fn initialize() {
@@ -56,6 +56,7 @@ pub fn initialize() {
         .with(forest_layer)
         .with(file_subscriber)
         .with(ErrorLayer::default())
+        .with(span_timing::layer())
         .init();
 }
 
@@ -17,17 +17,17 @@
 
 use std::env;
 
-use all_cards::card_list;
+use all_cards::{card_list, generated_cards, scripted_cards};
 use clap::Parser;
 use data::actions::user_action::UserAction;
 use data::prompts::select_order_prompt::CardOrderLocation;
 use database::sqlite_database::SqliteDatabase;
+use database::{rulings, users};
 use display::commands::field_state::{FieldKey, FieldValue};
 use display::core::card_view::ClientCardId;
 use game::server;
 use game::server_data::{Client, ClientData, GameResponse};
 use once_cell::sync::Lazy;
-use primitives::game_primitives::UserId;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use tauri::{AppHandle, EventTarget, Manager};
@@ -42,8 +42,10 @@ use uuid::Uuid;
 use crate::command_line_parser::CommandLineParser;
 
 mod command_line_parser;
+mod crash_report;
 mod initialize;
 mod logging;
+mod tui;
 
 static DATABASE: Lazy<SqliteDatabase> = Lazy::new(|| SqliteDatabase::new(paths::get_data_dir()));
 
@@ -56,7 +58,8 @@ async fn connect(app: AppHandle) {
     info!("Got connect request");
     let (sender, mut receiver) = mpsc::unbounded_channel();
     tokio::spawn(async move {
-        server::connect(DATABASE.clone(), sender, UserId(Uuid::default()));
+        let user_id = users::local_user_id(&DATABASE);
+        server::connect(DATABASE.clone(), sender, user_id);
     });
     while let Some(response) = receiver.recv().await {
         app.emit_to(EventTarget::app(), "game_response", response).unwrap();
@@ -93,6 +96,77 @@ async fn update_field(client_data: ClientData, key: FieldKey, value: FieldValue,
     }
 }
 
+#[tauri::command]
+#[specta::specta]
+async fn send_chat_message(client_data: ClientData, message: String, app: AppHandle) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        server::handle_send_chat_message(
+            DATABASE.clone(),
+            &mut new_client(client_data, sender),
+            message,
+        );
+    });
+    while let Some(response) = receiver.recv().await {
+        app.emit_to(EventTarget::app(), "game_response", response).unwrap();
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn fetch_card_details(client_data: ClientData, card_id: ClientCardId, app: AppHandle) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        server::handle_fetch_card_details(
+            DATABASE.clone(),
+            &mut new_client(client_data, sender),
+            card_id,
+        );
+    });
+    while let Some(response) = receiver.recv().await {
+        app.emit_to(EventTarget::app(), "game_response", response).unwrap();
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn search_cards(client_data: ClientData, query: String, app: AppHandle) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        server::handle_search_cards(DATABASE.clone(), &mut new_client(client_data, sender), query);
+    });
+    while let Some(response) = receiver.recv().await {
+        app.emit_to(EventTarget::app(), "game_response", response).unwrap();
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn fetch_deck_catalog(client_data: ClientData, app: AppHandle) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        server::handle_fetch_deck_catalog(DATABASE.clone(), &mut new_client(client_data, sender));
+    });
+    while let Some(response) = receiver.recv().await {
+        app.emit_to(EventTarget::app(), "game_response", response).unwrap();
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+async fn fetch_match_statistics(client_data: ClientData, app: AppHandle) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        server::handle_fetch_match_statistics(
+            DATABASE.clone(),
+            &mut new_client(client_data, sender),
+        );
+    });
+    while let Some(response) = receiver.recv().await {
+        app.emit_to(EventTarget::app(), "game_response", response).unwrap();
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 async fn drag_card(
@@ -123,10 +197,15 @@ fn new_client(mut client_data: ClientData, sender: UnboundedSender<GameResponse>
 }
 
 fn main() {
-    let args = CommandLineParser::parse().build();
+    let parser = CommandLineParser::parse();
+    let run_tui = parser.tui;
+    let import_rulings = parser.import_rulings.clone();
+    let args = parser.build();
     command_line::FLAGS.set(args).expect("Flags should not be set multiple times");
 
     match command_line::flags().tracing_style {
+        // Tracing output to stdout would be drawn over the terminal interface
+        _ if run_tui => {}
         TracingStyle::AggregateTime => {
             tracing_span_tree::span_tree().aggregate(true).enable();
         }
@@ -140,16 +219,34 @@ fn main() {
         initialize::initialize_panic_handler();
     }
     card_list::initialize();
+    scripted_cards::initialize();
+    generated_cards::initialize(&DATABASE);
 
     let commit = env!("VERGEN_GIT_SHA");
     info!(commit, "Starting game");
 
+    if let Some(path) = import_rulings {
+        let count = rulings::import_scryfall(&DATABASE, &path).unwrap_or_else(|e| panic!("{e}"));
+        println!("Imported {count} rulings from {path:?}");
+        return;
+    }
+
+    if run_tui {
+        tui::run(DATABASE.clone()).expect("Error running terminal interface");
+        return;
+    }
+
     let (invoke_handler, register_events) = {
         let builder = tauri_specta::ts::builder()
             .commands(tauri_specta::collect_commands![
                 connect,
                 handle_action,
                 update_field,
+                send_chat_message,
+                fetch_card_details,
+                search_cards,
+                fetch_deck_catalog,
+                fetch_match_statistics,
                 drag_card
             ])
             .events(tauri_specta::collect_events![GameResponseEvent]);
@@ -0,0 +1,288 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use data::actions::game_action::GameAction;
+use data::actions::user_action::UserAction;
+use data::prompts::select_order_prompt::CardOrderLocation;
+use display::commands::command::{Command, SceneView};
+use display::commands::field_state::{FieldKey, FieldValue};
+use display::core::card_view::ClientCardId;
+use display::core::game_message::GameMessage;
+use display::core::game_view::{GameButtonView, GameControlView, GameView};
+use display::panels::modal_panel::{ModalPanel, PanelData};
+use display::rendering::diff;
+use game::server_data::{ClientData, GameResponse};
+
+/// A request to send to the game server.
+#[derive(Clone, Debug)]
+pub enum Request {
+    Action(UserAction),
+    UpdateField(FieldKey, FieldValue),
+    DragCard(ClientCardId, CardOrderLocation),
+}
+
+/// Effect of selecting a [Choice].
+#[derive(Clone, Debug)]
+pub enum ChoiceAction {
+    Request(Request),
+
+    /// Begin editing the text field with the given key
+    TextInput(FieldKey),
+}
+
+/// An interaction which can currently be selected from the list of choices.
+#[derive(Clone, Debug)]
+pub struct Choice {
+    pub label: String,
+    pub action: ChoiceAction,
+}
+
+/// Result of handling a key press.
+pub enum KeyResult {
+    None,
+    Quit,
+    Request(Request),
+}
+
+/// Client-side state of the terminal interface, built up from the
+/// [GameResponse]s sent by the server.
+#[derive(Default)]
+pub struct TuiApp {
+    /// Most recent client data received from the server, required to make
+    /// requests.
+    pub client_data: Option<ClientData>,
+
+    /// Currently-displayed top level scene
+    pub scene: Option<SceneView>,
+
+    /// Modal panel displayed on top of the scene, if any
+    pub modal: Option<ModalPanel>,
+
+    /// Most recent game message
+    pub message: Option<GameMessage>,
+
+    /// Lines of the game log, oldest first
+    pub log: Vec<String>,
+
+    /// Index of the selected entry in [Self::choices]
+    pub selected: usize,
+
+    /// Text field currently being edited, along with its current contents
+    pub editing: Option<(FieldKey, String)>,
+}
+
+impl TuiApp {
+    /// Updates this state based on a response from the server.
+    pub fn apply(&mut self, response: GameResponse) {
+        self.client_data = Some(response.client_data);
+        match response.command {
+            Command::UpdateScene(scene) => {
+                if !matches!(scene, SceneView::GameView(_)) {
+                    self.message = None;
+                    self.log.clear();
+                }
+                self.scene = Some(scene);
+            }
+            Command::UpdateGameView(update) => {
+                if let Some(SceneView::GameView(previous)) = &self.scene {
+                    self.scene = Some(SceneView::GameView(diff::apply(previous, update)));
+                }
+            }
+            Command::SetModalPanel(panel) => {
+                self.modal = panel;
+                self.selected = 0;
+            }
+            Command::DisplayGameMessage(message) => {
+                self.message = Some(message.message);
+            }
+            Command::UpdateGameLog(log) => {
+                self.log = log
+                    .entries
+                    .into_iter()
+                    .map(|entry| format!("T{}: {}", entry.turn_number, entry.text))
+                    .collect();
+            }
+            _ => {}
+        }
+        self.selected = self.selected.min(self.choices().len().saturating_sub(1));
+    }
+
+    /// Returns the game view currently being displayed, if any.
+    pub fn game_view(&self) -> Option<&GameView> {
+        match &self.scene {
+            Some(SceneView::GameView(view)) => Some(view),
+            _ => None,
+        }
+    }
+
+    /// Returns the list of interactions which can currently be selected.
+    pub fn choices(&self) -> Vec<Choice> {
+        if let Some(modal) = &self.modal {
+            return modal_choices(modal);
+        }
+
+        match &self.scene {
+            Some(SceneView::MainMenuView(menu)) => menu.buttons.iter().map(button).collect(),
+            Some(SceneView::GameView(view)) => game_choices(view),
+            _ => vec![],
+        }
+    }
+
+    /// Handles a key press, returning a request to send to the server if one
+    /// results.
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyResult {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return KeyResult::Quit;
+        }
+
+        if let Some((field, text)) = &mut self.editing {
+            match key.code {
+                KeyCode::Char(c) => text.push(c),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Esc => self.editing = None,
+                KeyCode::Enter => {
+                    let request = Request::UpdateField(*field, FieldValue::String(text.clone()));
+                    self.editing = None;
+                    return KeyResult::Request(request);
+                }
+                _ => {}
+            }
+            return KeyResult::None;
+        }
+
+        let choices = self.choices();
+        match key.code {
+            KeyCode::Char('q') => return KeyResult::Quit,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(choices.len().saturating_sub(1));
+            }
+            KeyCode::Enter => match choices.get(self.selected).map(|c| c.action.clone()) {
+                Some(ChoiceAction::Request(request)) => return KeyResult::Request(request),
+                Some(ChoiceAction::TextInput(field)) => {
+                    self.editing = Some((field, String::new()));
+                }
+                None => {}
+            },
+            KeyCode::Esc => {
+                if let Some(modal) = &self.modal {
+                    return KeyResult::Request(Request::Action(modal.on_close.clone()));
+                }
+            }
+            KeyCode::Char('p') if self.game_view().is_some() => {
+                return KeyResult::Request(Request::Action(GameAction::PassPriority.into()));
+            }
+            _ => {}
+        }
+        KeyResult::None
+    }
+}
+
+fn button(button: &GameButtonView) -> Choice {
+    Choice {
+        label: button.label.clone(),
+        action: ChoiceAction::Request(Request::Action(button.action.clone())),
+    }
+}
+
+fn game_choices(view: &GameView) -> Vec<Choice> {
+    let mut result = vec![];
+    for control in view.bottom_controls.iter().chain(view.top_controls.iter()) {
+        match control {
+            GameControlView::Button(b) => result.push(button(b)),
+            GameControlView::TextInput(input) => result.push(Choice {
+                label: "Enter value".to_string(),
+                action: ChoiceAction::TextInput(input.key),
+            }),
+            GameControlView::Text(_) => {}
+        }
+    }
+
+    for card in &view.cards {
+        let Some(revealed) = &card.revealed else {
+            continue;
+        };
+        if let Some(action) = &revealed.click_action {
+            result.push(Choice {
+                label: format!("{} ({:?})", revealed.face.name, card.position.position),
+                action: ChoiceAction::Request(Request::Action(action.clone())),
+            });
+        }
+        if revealed.can_drag {
+            for &location in &view.card_drag_targets {
+                result.push(Choice {
+                    label: format!("Move {} to {:?}", revealed.face.name, location),
+                    action: ChoiceAction::Request(Request::DragCard(card.id.clone(), location)),
+                });
+            }
+        }
+    }
+    result
+}
+
+fn modal_choices(modal: &ModalPanel) -> Vec<Choice> {
+    let mut result = match &modal.data {
+        PanelData::Debug(panel) => {
+            let mut choices = vec![Choice {
+                label: "Run a debug command".to_string(),
+                action: ChoiceAction::TextInput(panel.input.key),
+            }];
+            choices.extend(panel.buttons.iter().map(button));
+            choices
+        }
+        PanelData::RulesConsole(panel) => vec![Choice {
+            label: "Ask a question".to_string(),
+            action: ChoiceAction::TextInput(panel.input.key),
+        }],
+        PanelData::Settings(panel) => panel
+            .sections
+            .iter()
+            .flat_map(|section| {
+                section.toggles.iter().filter(|toggle| !toggle.disabled).map(move |toggle| Choice {
+                    label: format!(
+                        "[{}] {}: {}",
+                        if toggle.value { "x" } else { " " },
+                        section.title,
+                        toggle.label
+                    ),
+                    action: ChoiceAction::Request(Request::UpdateField(
+                        toggle.key,
+                        FieldValue::Bool(!toggle.value),
+                    )),
+                })
+            })
+            .collect(),
+        PanelData::Campaign(panel) => {
+            panel.stages.iter().filter_map(|stage| stage.play.as_ref()).map(button).collect()
+        }
+        PanelData::Profile(panel) => vec![Choice {
+            label: "Change display name".to_string(),
+            action: ChoiceAction::TextInput(panel.input.key),
+        }],
+        PanelData::ZoneBrowser(panel) => {
+            panel.previous.iter().chain(panel.next.iter()).map(button).collect()
+        }
+        PanelData::KnownCards(_) => vec![],
+    };
+    result.push(Choice {
+        label: "Close".to_string(),
+        action: ChoiceAction::Request(Request::Action(modal.on_close.clone())),
+    });
+    result
+}
@@ -0,0 +1,125 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::Stdout;
+use std::time::Duration;
+
+use crossterm::event::{Event, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::{event, execute};
+use database::sqlite_database::SqliteDatabase;
+use database::users;
+use game::server;
+use game::server_data::{Client, GameResponse};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::UnboundedSender;
+use uuid::Uuid;
+
+use crate::tui::app::{KeyResult, Request, TuiApp};
+
+mod app;
+mod render;
+
+/// How long to wait for keyboard input before checking for new responses from
+/// the server.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Runs a text-mode client in the current terminal, for playing over SSH or
+/// without a graphical environment.
+///
+/// This communicates with the game server via the same API as the desktop
+/// client and renders the resulting commands as a terminal user interface.
+pub fn run(database: SqliteDatabase) -> io::Result<()> {
+    let runtime = Runtime::new()?;
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let user_id = users::local_user_id(&database);
+    let connect_database = database.clone();
+    let connect_sender = sender.clone();
+    runtime.spawn(async move {
+        server::connect(connect_database, connect_sender, user_id);
+    });
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let result = event_loop(&mut terminal, &runtime, &database, &sender, &mut receiver);
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    result
+}
+
+fn event_loop(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    runtime: &Runtime,
+    database: &SqliteDatabase,
+    sender: &UnboundedSender<GameResponse>,
+    receiver: &mut mpsc::UnboundedReceiver<GameResponse>,
+) -> io::Result<()> {
+    let mut app = TuiApp::default();
+    loop {
+        while let Ok(response) = receiver.try_recv() {
+            app.apply(response);
+        }
+        terminal.draw(|frame| render::draw(frame, &app))?;
+
+        if !event::poll(POLL_INTERVAL)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key) {
+            KeyResult::None => {}
+            KeyResult::Quit => return Ok(()),
+            KeyResult::Request(request) => {
+                send_request(runtime, database, sender, &app, request);
+            }
+        }
+    }
+}
+
+fn send_request(
+    runtime: &Runtime,
+    database: &SqliteDatabase,
+    sender: &UnboundedSender<GameResponse>,
+    app: &TuiApp,
+    request: Request,
+) {
+    let Some(mut data) = app.client_data.clone() else {
+        return;
+    };
+    data.id = Uuid::new_v4();
+    let mut client = Client { data, channel: sender.clone() };
+    let database = database.clone();
+    runtime.spawn(async move {
+        match request {
+            Request::Action(action) => server::handle_action(database, &mut client, action).await,
+            Request::UpdateField(key, value) => {
+                server::handle_update_field(database, &mut client, key, value)
+            }
+            Request::DragCard(card_id, location) => {
+                server::handle_drag_card(database, &mut client, card_id, location, 0)
+            }
+        }
+    });
+}
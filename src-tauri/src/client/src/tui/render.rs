@@ -0,0 +1,132 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use display::commands::command::SceneView;
+use display::core::game_message::GameMessage;
+use display::panels::modal_panel::{ModalPanel, PanelData};
+use display::rendering::board_description;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::tui::app::TuiApp;
+
+/// Maximum number of rows to use for the list of choices.
+const MAX_CHOICE_ROWS: u16 = 12;
+
+/// Draws the current state of the terminal interface.
+pub fn draw(frame: &mut Frame, app: &TuiApp) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(75), Constraint::Percentage(25)])
+        .split(frame.size());
+    let choices = app.choices();
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length((choices.len() as u16 + 2).min(MAX_CHOICE_ROWS)),
+            Constraint::Length(1),
+        ])
+        .split(columns[0]);
+
+    draw_scene(frame, app, rows[0]);
+
+    let items =
+        choices.iter().map(|choice| ListItem::new(choice.label.clone())).collect::<Vec<_>>();
+    let title = app.modal.as_ref().and_then(|m| m.title.clone()).unwrap_or("Actions".to_string());
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(app.selected));
+    frame.render_stateful_widget(list, rows[1], &mut state);
+
+    let help = match &app.editing {
+        Some((_, text)) => format!("Input: {text}_  (enter: submit, esc: cancel)"),
+        None => "up/down: select  enter: choose  p: pass priority  esc: close panel  q: quit"
+            .to_string(),
+    };
+    frame.render_widget(Paragraph::new(help), rows[2]);
+
+    let log_lines = app.log.iter().rev().take(columns[1].height as usize).rev().cloned();
+    frame.render_widget(
+        Paragraph::new(log_lines.collect::<Vec<_>>().join("\n"))
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title("Game Log")),
+        columns[1],
+    );
+}
+
+fn draw_scene(frame: &mut Frame, app: &TuiApp, area: Rect) {
+    let (title, text) = if let Some(modal) = &app.modal {
+        (modal.title.clone().unwrap_or_default(), modal_text(modal))
+    } else {
+        match &app.scene {
+            Some(SceneView::GameView(view)) => {
+                let mut text = board_description::describe(view);
+                if let Some(message) = &app.message {
+                    text = format!("{}\n\n{}", message_text(message), text);
+                }
+                ("Game".to_string(), text)
+            }
+            Some(SceneView::MainMenuView(_)) => ("Spellclash".to_string(), "Main Menu".to_string()),
+            Some(SceneView::Loading) | None => ("Spellclash".to_string(), "Loading...".to_string()),
+        }
+    };
+
+    frame.render_widget(
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(title)),
+        area,
+    );
+}
+
+fn modal_text(modal: &ModalPanel) -> String {
+    match &modal.data {
+        PanelData::Debug(panel) => panel.lines.join("\n"),
+        PanelData::RulesConsole(panel) => panel.lines.join("\n"),
+        PanelData::Campaign(panel) => panel
+            .stages
+            .iter()
+            .map(|stage| format!("{} ({:?}): {}", stage.name, stage.status, stage.description))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        PanelData::Profile(panel) => {
+            format!("{}\n\n{}", panel.display_name, panel.statistics.join("\n"))
+        }
+        PanelData::Settings(_) => String::new(),
+        PanelData::ZoneBrowser(panel) => {
+            let names = panel.cards.iter().map(|card| card.name.as_str()).collect::<Vec<_>>();
+            format!("{}\n\n{}", panel.page_description, names.join("\n"))
+        }
+        PanelData::KnownCards(panel) => panel
+            .cards
+            .iter()
+            .map(|card| format!("{}: {} ({})", card.name, card.location, card.revealed_on))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+fn message_text(message: &GameMessage) -> &'static str {
+    match message {
+        GameMessage::YourTurn => "Your Turn",
+        GameMessage::OpponentTurn => "Opponent Turn",
+        GameMessage::Victory => "Victory!",
+        GameMessage::Defeat => "Defeat",
+    }
+}
@@ -12,18 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::PlayerName;
+use primitives::game_primitives::{CardId, PlayerName, Zone};
 use serde::{Deserialize, Serialize};
 
 use crate::actions::game_action::GameAction;
 use crate::actions::user_action::UserAction;
+use crate::card_definitions::card_name::CardName;
 use crate::core::numerics::LifeValue;
+use crate::game_states::game_phase_step::GamePhaseStep;
+use crate::printed_cards::printed_card_id::PrintedCardId;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum DebugGameAction {
     SetLifeTotal(PlayerName),
     RevealHand(PlayerName),
     DestroyAllLands(PlayerName),
+    CreateCard(PlayerName, PrintedCardId, Zone),
+    SetLifeTotalTo(PlayerName, LifeValue),
+    AddPlusOneCounters(CardId, u32),
+    AddMinusOneCounters(CardId, u32),
+    ForceUpkeepTriggers(PlayerName),
+    ForceEnterBattlefieldTriggers(CardId),
+    ToggleRevealAllCards,
+
+    /// Toggle pausing before each step of resolving spells and abilities.
+    ToggleStepThroughResolution,
+    MoveToTopOfLibrary(CardId),
+    RigNextDraw(PlayerName, CardName),
+    SetRngSeed(u64),
+
+    /// Advance the game until the indicated step begins, performing all
+    /// intermediate turn-based actions.
+    SkipToStep(GamePhaseStep),
+
+    /// Advance the game by the indicated number of turns, stopping in the
+    /// precombat main phase.
+    SkipTurns(u32),
 }
 
 impl From<DebugGameAction> for GameAction {
@@ -83,6 +83,11 @@ pub enum CombatAction {
 
     /// Lock in block ordering decisions for the declare blockers step.
     ConfirmBlockerOrder,
+
+    /// Activate the ninjutsu ability of the indicated card in hand, returning
+    /// an unblocked attacker to hand and putting this card onto the
+    /// battlefield tapped and attacking in its place.
+    Ninjutsu(CardId),
 }
 
 impl From<CombatAction> for GameAction {
@@ -125,6 +130,43 @@ pub enum GameAction {
 
     /// Take an action within a combat phase
     CombatAction(CombatAction),
+
+    /// Foretell the indicated card in hand, paying {2} to exile it face down.
+    ///
+    /// > 116.2h. A player who has a card with foretell in their hand may pay
+    /// > {2} and exile that card face down. This is a special action. A player
+    /// > may take this action any time they have priority during their turn
+    /// > (see rule 702.143a).
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R1162h>
+    Foretell(CardId),
+
+    /// Roll the planar die in a Planechase game.
+    ///
+    /// The active player may take this special action any time they have
+    /// priority during a main phase of their turn while the stack is empty.
+    /// The first roll each turn costs {0}, and each additional roll costs {1}
+    /// more than the previous one.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R901>
+    RollPlanarDie,
+
+    /// Retain priority after the next spell this player casts instead of
+    /// automatically passing it.
+    ///
+    /// > 117.3c. If a player has priority when they cast a spell, activate an
+    /// > ability, or take a special action, that player receives priority
+    /// > afterward.
+    /// <https://yawgatog.com/resources/magic-rules/#R1173c>
+    HoldPriority,
+
+    /// Concede the game.
+    ///
+    /// > 104.3a. A player can concede the game at any time. A player who
+    /// > concedes leaves the game immediately. That player loses the game.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R1043a>
+    Concede,
 }
 
 impl GameAction {
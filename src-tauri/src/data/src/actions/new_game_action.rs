@@ -18,8 +18,10 @@ use primitives::game_primitives::GameId;
 use serde::{Deserialize, Serialize};
 
 use crate::actions::user_action::UserAction;
+use crate::campaign::campaign_stage::CampaignStageId;
 use crate::decks::deck_name::DeckName;
-use crate::game_states::game_state::DebugConfiguration;
+use crate::decks::format::Format;
+use crate::game_states::game_state::{DebugConfiguration, RulesConfiguration};
 use crate::player_states::player_state::PlayerType;
 
 /// Debug options for a new game
@@ -44,15 +46,53 @@ pub struct NewGameAction {
     /// Deck for opponent to use
     pub opponent_deck: DeckName,
 
+    /// Campaign stage this game is being played for, if any
+    #[serde(default)]
+    pub campaign_stage: Option<CampaignStageId>,
+
+    /// House rules for this game, or None to use the rules the user creating
+    /// the game has selected
+    #[serde(default)]
+    pub rules: Option<RulesConfiguration>,
+
+    /// Teammates for the user and their opponent, if this is a Two-Headed
+    /// Giant game
+    #[serde(default)]
+    pub teammates: Option<NewGameTeammates>,
+
+    /// Constructed format every deck in this game must be legal in, if any
+    #[serde(default)]
+    pub format: Option<Format>,
+
     /// Debug options
     pub debug_options: NewGameDebugOptions,
 }
 
+/// Additional players for a Two-Headed Giant game
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NewGameTeammates {
+    /// AI configuration for the user's teammate
+    pub teammate: PlayerType,
+
+    /// Deck for the user's teammate to use
+    pub teammate_deck: DeckName,
+
+    /// AI configuration for the opponent's teammate
+    pub opponent_teammate: PlayerType,
+
+    /// Deck for the opponent's teammate to use
+    pub opponent_teammate_deck: DeckName,
+}
+
 impl Debug for NewGameAction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("NewGameAction")
             .field("deck", &self.deck)
             .field("opponent_deck", &self.opponent_deck)
+            .field("campaign_stage", &self.campaign_stage)
+            .field("rules", &self.rules)
+            .field("two_headed_giant", &self.teammates.is_some())
+            .field("format", &self.format)
             .field("debug_options", &self.debug_options)
             .finish()
     }
@@ -60,6 +100,6 @@ impl Debug for NewGameAction {
 
 impl From<NewGameAction> for UserAction {
     fn from(value: NewGameAction) -> Self {
-        UserAction::NewGameAction(value)
+        UserAction::NewGameAction(Box::new(value))
     }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::{CardId, EntityId};
+use primitives::game_primitives::{CardId, EntityId, PermanentId};
 use serde::{Deserialize, Serialize};
 
 use crate::actions::user_action::UserAction;
@@ -34,11 +34,37 @@ pub enum PromptAction {
     /// Pick a single entity from a set of choices.
     SelectEntity(EntityId),
 
+    /// Decline to pick an entity in an optional entity choice prompt
+    SkipEntityChoice,
+
     /// Confirm selected card choices on a card selection prompt
     SubmitCardSelection,
 
     /// Pick a choice at a given index in a multiple choice prompt
     SelectChoice(usize),
+
+    /// Add or remove the choice at a given index from the selection in a
+    /// multi-select prompt
+    ToggleChoice(usize),
+
+    /// Confirm the selected choices in a multi-select prompt
+    SubmitMultiSelect,
+
+    /// Set the amount assigned to the target at a given index in a distribute
+    /// prompt
+    AssignAmount(usize, u32),
+
+    /// Confirm the assigned amounts in a distribute prompt
+    SubmitDistribution,
+
+    /// Add or remove a mana source from the selection in a mana payment prompt
+    ToggleManaSource(PermanentId),
+
+    /// Restore the automatically-suggested selection in a mana payment prompt
+    ResetManaPayment,
+
+    /// Confirm the selected sources in a mana payment prompt
+    SubmitManaPayment,
 }
 
 impl From<PromptAction> for UserAction {
@@ -12,17 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use primitives::game_primitives::AbilityId;
 use serde::{Deserialize, Serialize};
 use specta::{DataType, EnumType, Generics, Type, TypeMap};
 
 use crate::actions::game_action::GameAction;
 use crate::actions::new_game_action::NewGameAction;
 use crate::actions::prompt_action::PromptAction;
+use crate::campaign::campaign_stage::CampaignStageId;
+use crate::card_definitions::card_name::CardName;
 use crate::core::panel_address::PanelAddress;
+use crate::decks::deck_name::DeckName;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum UserAction {
-    NewGameAction(NewGameAction),
+    NewGameAction(Box<NewGameAction>),
     GameAction(GameAction),
     PromptAction(PromptAction),
     Undo,
@@ -31,6 +35,40 @@ pub enum UserAction {
     OpenPanel(PanelAddress),
     ClosePanel,
     PanelTransition(PanelTransition),
+
+    /// Automatically pass priority until the end of the current turn.
+    PassUntilEndOfTurn,
+
+    /// Automatically pass priority until the start of this player's next turn.
+    PassUntilNextTurn,
+
+    /// Toggle whether the player will retain priority after casting their next
+    /// spell.
+    HoldPriority,
+
+    /// Toggle whether this player receives priority every time it would be
+    /// given to them, disabling all automatic passing and confirmation.
+    ToggleFullControl,
+
+    /// Take this action as soon as the player is next able to act, e.g. to
+    /// respond in advance while the opponent is deciding.
+    QueueGameAction(GameAction),
+
+    /// Discard all actions added via [Self::QueueGameAction] which have not
+    /// yet been taken.
+    ClearQueuedActions,
+
+    /// Start a new game against the opponent for a stage of the single-player
+    /// campaign.
+    StartCampaignStage(CampaignStageId),
+
+    /// Select a deck from the deck catalog to play with in campaign games.
+    SelectDeck(DeckName),
+
+    /// Toggle whether the game should always stop for priority when this
+    /// triggered ability of a card with the given [CardName] is on the stack,
+    /// instead of automatically resolving it.
+    ToggleAlwaysAskTrigger(AbilityId, CardName),
 }
 
 #[derive(Serialize, Deserialize, Type)]
@@ -0,0 +1,57 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::campaign::campaign_stage::{CampaignStage, CampaignStageId};
+use crate::decks::deck_name::DeckName;
+
+/// Tracks a user's progression through the single-player campaign.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CampaignProgress {
+    /// Stages the user has defeated.
+    pub completed: BTreeSet<CampaignStageId>,
+
+    /// Decks the user has unlocked as campaign rewards.
+    pub unlocked_decks: Vec<DeckName>,
+}
+
+impl CampaignProgress {
+    /// Returns true if the user has defeated the indicated stage.
+    pub fn is_completed(&self, stage: CampaignStageId) -> bool {
+        self.completed.contains(&stage)
+    }
+
+    /// Returns true if the user has unlocked the indicated deck.
+    pub fn is_unlocked(&self, deck: DeckName) -> bool {
+        self.unlocked_decks.contains(&deck)
+    }
+
+    /// Marks a stage as completed and grants its reward, if any.
+    ///
+    /// Returns false if this stage had already been completed.
+    pub fn complete(&mut self, stage: &CampaignStage) -> bool {
+        if !self.completed.insert(stage.id) {
+            return false;
+        }
+        if let Some(deck) = stage.reward {
+            if !self.is_unlocked(deck) {
+                self.unlocked_decks.push(deck);
+            }
+        }
+        true
+    }
+}
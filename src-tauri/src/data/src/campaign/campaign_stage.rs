@@ -0,0 +1,83 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use primitives::game_primitives::PlayerName;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::numerics::LifeValue;
+use crate::decks::deck_name::DeckName;
+
+/// Identifies a stage within the single-player campaign.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize, Type,
+)]
+pub struct CampaignStageId(pub u32);
+
+/// A scripted opponent within the single-player campaign.
+///
+/// Stages are played in order: each stage becomes available once the previous
+/// stage has been completed.
+#[derive(Debug, Clone)]
+pub struct CampaignStage {
+    pub id: CampaignStageId,
+
+    /// Name of this stage to display on the campaign map
+    pub name: String,
+
+    /// Flavor text describing this stage's opponent
+    pub description: String,
+
+    /// Deck the opponent plays
+    pub opponent_deck: DeckName,
+
+    /// How long the AI opponent searches before selecting each action
+    pub opponent_search_duration: Duration,
+
+    /// Special rules modifications which apply to games in this stage
+    pub rules: Vec<CampaignRule>,
+
+    /// Deck which is unlocked for the user when this stage is completed
+    pub reward: Option<DeckName>,
+}
+
+/// Modification to the normal rules of the game which applies during a
+/// campaign stage.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CampaignRule {
+    /// The indicated player starts the game at this life total.
+    StartingLife(PlayerName, LifeValue),
+
+    /// The indicated player draws this many additional cards in their opening
+    /// hand.
+    ExtraOpeningHandCards(PlayerName, usize),
+}
+
+impl CampaignRule {
+    /// Describes this rule for display to the user. The user is always
+    /// [PlayerName::One] in campaign games.
+    pub fn description(&self) -> String {
+        let who = |player: PlayerName| if player == PlayerName::One { "You" } else { "Opponent" };
+        match self {
+            CampaignRule::StartingLife(player, life) => {
+                format!("{} starts at {} life", who(*player), life)
+            }
+            CampaignRule::ExtraOpeningHandCards(player, count) => {
+                format!("{} starts with {} extra cards in hand", who(*player), count)
+            }
+        }
+    }
+}
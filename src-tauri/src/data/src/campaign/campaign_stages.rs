@@ -0,0 +1,77 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use primitives::game_primitives::PlayerName;
+
+use crate::campaign::campaign_progress::CampaignProgress;
+use crate::campaign::campaign_stage::{CampaignRule, CampaignStage, CampaignStageId};
+use crate::decks::deck_name;
+
+static STAGES: Lazy<Vec<CampaignStage>> = Lazy::new(|| {
+    vec![
+        CampaignStage {
+            id: CampaignStageId(1),
+            name: "The Shallows".to_string(),
+            description: "A school of Dandans circles the harbor.".to_string(),
+            opponent_deck: deck_name::ALL_DANDANS,
+            opponent_search_duration: Duration::from_secs(1),
+            rules: vec![],
+            reward: Some(deck_name::SOME_DANDANS),
+        },
+        CampaignStage {
+            id: CampaignStageId(2),
+            name: "Bear Country".to_string(),
+            description: "The forest's largest residents do not welcome visitors.".to_string(),
+            opponent_deck: deck_name::GREEN_VANILLA,
+            opponent_search_duration: Duration::from_secs(2),
+            rules: vec![CampaignRule::StartingLife(PlayerName::Two, 30)],
+            reward: Some(deck_name::GRIZZLY_BEAR_GIANT_GROWTH),
+        },
+        CampaignStage {
+            id: CampaignStageId(3),
+            name: "The Deep".to_string(),
+            description: "Something ancient stirs beneath the waves.".to_string(),
+            opponent_deck: deck_name::SOME_DANDANS,
+            opponent_search_duration: Duration::from_secs(3),
+            rules: vec![CampaignRule::ExtraOpeningHandCards(PlayerName::Two, 2)],
+            reward: None,
+        },
+    ]
+});
+
+/// Returns all stages of the single-player campaign, in order.
+pub fn all() -> &'static [CampaignStage] {
+    &STAGES
+}
+
+/// Looks up a campaign stage by ID.
+///
+/// Panics if no stage exists with this ID.
+pub fn get(id: CampaignStageId) -> &'static CampaignStage {
+    all().iter().find(|stage| stage.id == id).unwrap_or_else(|| panic!("Unknown stage {id:?}"))
+}
+
+/// Returns true if the user can currently play the indicated stage, i.e. if it
+/// is the first stage or they have completed the stage before it.
+pub fn is_available(progress: &CampaignProgress, id: CampaignStageId) -> bool {
+    let index = all().iter().position(|stage| stage.id == id);
+    match index {
+        Some(0) => true,
+        Some(i) => progress.is_completed(all()[i - 1].id),
+        None => false,
+    }
+}
@@ -14,7 +14,10 @@
 
 use std::iter;
 
-use primitives::game_primitives::{EntityId, PlayerName, Source};
+use enumset::EnumSet;
+use primitives::game_primitives::{EntityId, PlayerName, Source, Zone};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::card_definitions::modal_effect::ModalEffect;
 use crate::card_states::card_state::CardState;
@@ -24,8 +27,10 @@ use crate::core::ability_scope::AbilityScope;
 use crate::events::card_events::CardEvents;
 use crate::events::event_context::EventContext;
 use crate::events::game_events::GlobalEvents;
+use crate::game_states::ability_state::{DelayedTriggerCondition, DelayedTriggerState};
 use crate::game_states::game_state::GameState;
 use crate::properties::card_properties::CardProperties;
+use crate::properties::duration::Duration;
 
 /// Represents the possible types of ability
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,6 +53,23 @@ pub trait AbilityData: Sync + Send {
 
     /// Returns the type of this ability.
     fn get_ability_type(&self) -> AbilityType;
+
+    /// Returns the zones in which this ability functions.
+    ///
+    /// > 113.6. Abilities of an instant or sorcery spell usually function only
+    /// > while that object is on the stack. Abilities of all other objects
+    /// > usually function only while that object is on the battlefield.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R1136>
+    ///
+    /// Global event callbacks always specify their own zones. This value
+    /// controls the zones in which property modifiers created by the ability
+    /// apply. By default abilities function in all zones, since many abilities
+    /// which modify a card's own characteristics must apply everywhere (e.g.
+    /// while a spell is on the stack).
+    fn functional_zones(&self) -> EnumSet<Zone> {
+        EnumSet::all()
+    }
 }
 
 pub trait Ability: AbilityData {
@@ -86,6 +108,22 @@ pub trait Ability: AbilityData {
         Box::new(iter::empty())
     }
 
+    /// Returns the number of distinct targets which must be chosen for this
+    /// ability, given a set of [PlayCardChoices].
+    fn target_count(&self, choices: &PlayCardChoices) -> TargetCount {
+        TargetCount::ONE
+    }
+
+    /// Returns the total amount to divide among this ability's targets, e.g.
+    /// damage for "3 damage divided as you choose among one, two, or three
+    /// targets".
+    ///
+    /// Returns None if this ability does not divide an amount among its
+    /// targets.
+    fn divided_total(&self, choices: &PlayCardChoices) -> Option<u32> {
+        None
+    }
+
     /// Invokes the effect of this ability, given a set of [PlayCardChoices].
     ///
     /// This is a no-op if invoked on an ability with no effect, like a static
@@ -95,7 +133,36 @@ pub trait Ability: AbilityData {
         game: &mut GameState,
         context: EventContext,
         choices: &Option<PlayCardChoices>,
-    );
+    ) -> Outcome;
+}
+
+/// Number of targets an ability requires.
+///
+/// The same player or object can't be chosen more than once for a single
+/// instance of the word "target", so the chosen targets are always distinct.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6012c>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetCount {
+    pub minimum: usize,
+    pub maximum: usize,
+}
+
+impl TargetCount {
+    /// Exactly one target, e.g. "target creature".
+    pub const ONE: TargetCount = TargetCount { minimum: 1, maximum: 1 };
+
+    /// Any number of targets from zero to `maximum`, e.g. "up to two target
+    /// creatures".
+    pub fn up_to(maximum: usize) -> Self {
+        Self { minimum: 0, maximum }
+    }
+
+    /// Between `minimum` and `maximum` targets, e.g. "one, two, or three
+    /// targets".
+    pub fn between(minimum: usize, maximum: usize) -> Self {
+        Self { minimum, maximum }
+    }
 }
 
 pub trait TargetSelector: Sync + Send {
@@ -109,6 +176,28 @@ pub trait TargetSelector: Sync + Send {
     ) -> Box<dyn Iterator<Item = EntityId> + 'a>;
 
     fn build_target_data(&self, game: &GameState, targets: &[EntityId]) -> Option<Self::Target>;
+
+    /// Number of distinct targets to choose from [Self::valid_targets].
+    fn target_count(&self) -> TargetCount {
+        TargetCount::ONE
+    }
+
+    /// Total amount to divide among the chosen targets, if any.
+    fn divided_total(&self) -> Option<u32> {
+        None
+    }
+
+    /// Equivalent to [Self::build_target_data] for selectors which divide an
+    /// amount among their targets. `amounts` contains the amount assigned to
+    /// each entry in `targets`.
+    fn build_divided_target_data(
+        &self,
+        game: &GameState,
+        targets: &[EntityId],
+        amounts: &[u32],
+    ) -> Option<Self::Target> {
+        self.build_target_data(game, targets)
+    }
 }
 
 pub struct SpellAbility;
@@ -121,6 +210,7 @@ impl SpellAbility {
             properties: None,
             global_events: None,
             card_events: None,
+            functional_zones: None,
             effect: NoEffect,
         }
     }
@@ -136,6 +226,7 @@ impl TriggeredAbility {
             properties: None,
             global_events: None,
             card_events: None,
+            functional_zones: None,
             effect: NoEffect,
         }
     }
@@ -151,6 +242,7 @@ impl StaticAbility {
             properties: None,
             global_events: None,
             card_events: None,
+            functional_zones: None,
             effect: StaticEffect,
         }
     }
@@ -164,7 +256,7 @@ pub struct WithSelector<TSelector> {
 
 pub struct UntargetedEffect<TFn>
 where
-    TFn: Fn(&mut GameState, EventContext) + 'static + Send + Sync + Clone,
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Send + Sync + Clone,
 {
     function: TFn,
 }
@@ -194,6 +286,8 @@ pub struct AbilityBuilder<TEffect> {
 
     pub(crate) card_events: Option<CardEventsFn>,
 
+    pub(crate) functional_zones: Option<EnumSet<Zone>>,
+
     pub(crate) effect: TEffect,
 }
 
@@ -221,12 +315,21 @@ impl<TEffect> AbilityBuilder<TEffect> {
         self.global_events = Some(Box::new(initialize));
         self
     }
+
+    /// Sets the zones in which this ability functions, e.g. for "As long as
+    /// this card is in your graveyard..."
+    ///
+    /// See [AbilityData::functional_zones].
+    pub fn functional_zones(mut self, zones: impl Into<EnumSet<Zone>>) -> Self {
+        self.functional_zones = Some(zones.into());
+        self
+    }
 }
 
 impl AbilityBuilder<NoEffect> {
     pub fn effect<TFn>(self, effect: TFn) -> AbilityBuilder<UntargetedEffect<TFn>>
     where
-        TFn: Fn(&mut GameState, EventContext) + 'static + Clone + Send + Sync,
+        TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
     {
         AbilityBuilder {
             ability_type: self.ability_type,
@@ -234,6 +337,7 @@ impl AbilityBuilder<NoEffect> {
             properties: self.properties,
             global_events: self.global_events,
             card_events: self.card_events,
+            functional_zones: self.functional_zones,
         }
     }
 
@@ -244,6 +348,7 @@ impl AbilityBuilder<NoEffect> {
             properties: self.properties,
             global_events: self.global_events,
             card_events: self.card_events,
+            functional_zones: self.functional_zones,
         }
     }
 
@@ -257,6 +362,7 @@ impl AbilityBuilder<NoEffect> {
             properties: self.properties,
             global_events: self.global_events,
             card_events: self.card_events,
+            functional_zones: self.functional_zones,
         }
     }
 }
@@ -267,7 +373,7 @@ where
 {
     pub fn effect<TTarget, TFn>(self, effect: TFn) -> AbilityBuilder<TargetedEffect<TSelector, TFn>>
     where
-        TFn: Fn(&mut GameState, EventContext, TTarget) + 'static + Send + Sync,
+        TFn: Fn(&mut GameState, EventContext, TTarget) -> Outcome + 'static + Send + Sync,
     {
         AbilityBuilder {
             ability_type: self.ability_type,
@@ -275,6 +381,7 @@ where
             properties: self.properties,
             global_events: self.global_events,
             card_events: self.card_events,
+            functional_zones: self.functional_zones,
         }
     }
 }
@@ -305,11 +412,15 @@ where
     fn get_ability_type(&self) -> AbilityType {
         self.ability_type
     }
+
+    fn functional_zones(&self) -> EnumSet<Zone> {
+        self.functional_zones.unwrap_or(EnumSet::all())
+    }
 }
 
 impl<TFn> Ability for AbilityBuilder<UntargetedEffect<TFn>>
 where
-    TFn: Fn(&mut GameState, EventContext) + 'static + Clone + Send + Sync,
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
 {
     #[doc(hidden)]
     fn requires_targets(&self) -> bool {
@@ -322,7 +433,7 @@ where
         game: &mut GameState,
         context: EventContext,
         _: &Option<PlayCardChoices>,
-    ) {
+    ) -> Outcome {
         (self.effect.function)(game, context)
     }
 }
@@ -330,7 +441,11 @@ where
 impl<TSelector, TFn> Ability for AbilityBuilder<TargetedEffect<TSelector, TFn>>
 where
     TSelector: TargetSelector,
-    TFn: Fn(&mut GameState, EventContext, TSelector::Target) + 'static + Clone + Send + Sync,
+    TFn: Fn(&mut GameState, EventContext, TSelector::Target) -> Outcome
+        + 'static
+        + Clone
+        + Send
+        + Sync,
 {
     #[doc(hidden)]
     fn requires_targets(&self) -> bool {
@@ -347,20 +462,30 @@ where
         self.effect.selector.valid_targets(game, choices, source)
     }
 
+    #[doc(hidden)]
+    fn target_count(&self, _: &PlayCardChoices) -> TargetCount {
+        self.effect.selector.target_count()
+    }
+
+    #[doc(hidden)]
+    fn divided_total(&self, _: &PlayCardChoices) -> Option<u32> {
+        self.effect.selector.divided_total()
+    }
+
     #[doc(hidden)]
     fn invoke_effect(
         &self,
         game: &mut GameState,
         context: EventContext,
         _: &Option<PlayCardChoices>,
-    ) {
-        let Some(targets) = game.card(context.this).map(|c| &c.targets) else {
-            return;
-        };
-
-        if let Some(data) = self.effect.selector.build_target_data(game, targets) {
-            (self.effect.function)(game, context, data);
-        }
+    ) -> Outcome {
+        let card = game.card(context.this).or_skip()?;
+        let data = self
+            .effect
+            .selector
+            .build_divided_target_data(game, &card.targets, &card.target_amounts)
+            .or_skip()?;
+        (self.effect.function)(game, context, data)
     }
 }
 
@@ -376,7 +501,8 @@ impl Ability for AbilityBuilder<StaticEffect> {
         game: &mut GameState,
         context: EventContext,
         _: &Option<PlayCardChoices>,
-    ) {
+    ) -> Outcome {
+        outcome::OK
     }
 }
 
@@ -392,7 +518,7 @@ impl DelayedTrigger<NoEffect> {
 
     pub fn effect<TFn>(self, effect: TFn) -> DelayedTrigger<UntargetedEffect<TFn>>
     where
-        TFn: Fn(&mut GameState, EventContext) + 'static + Clone + Send + Sync,
+        TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
     {
         DelayedTrigger { delayed_trigger_effect: UntargetedEffect { function: effect } }
     }
@@ -405,13 +531,41 @@ impl DelayedTrigger<NoEffect> {
     }
 }
 
+impl<TFn> DelayedTrigger<UntargetedEffect<TFn>>
+where
+    TFn: Fn(&mut GameState, EventContext) -> Outcome + 'static + Clone + Send + Sync,
+{
+    /// Registers this delayed triggered ability for the ability in the
+    /// provided [EventContext].
+    ///
+    /// The ability triggers once, the next time `condition` occurs while
+    /// `duration` is active. It is discarded without triggering if the duration
+    /// ends first.
+    pub fn register(
+        self,
+        game: &mut GameState,
+        context: EventContext,
+        condition: DelayedTriggerCondition,
+        duration: Duration,
+    ) {
+        game.ability_state.add_delayed_trigger(DelayedTriggerState {
+            event_id: context.event_id,
+            ability_id: context.this,
+            controller: context.controller,
+            condition,
+            duration,
+            effect: Box::new(self.delayed_trigger_effect.function),
+        });
+    }
+}
+
 impl<TSelector> DelayedTrigger<WithSelector<TSelector>>
 where
     TSelector: TargetSelector,
 {
     pub fn effect<TTarget, TFn>(self, effect: TFn) -> DelayedTrigger<TargetedEffect<TSelector, TFn>>
     where
-        TFn: Fn(&mut GameState, EventContext, TTarget) + 'static + Clone + Send + Sync,
+        TFn: Fn(&mut GameState, EventContext, TTarget) -> Outcome + 'static + Clone + Send + Sync,
     {
         DelayedTrigger {
             delayed_trigger_effect: TargetedEffect {
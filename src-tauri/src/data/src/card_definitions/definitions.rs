@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::num::NonZeroU64;
+use std::sync::Mutex;
 
 use dashmap::DashSet;
 use once_cell::sync::Lazy;
@@ -25,6 +26,14 @@ pub type CardFn = fn() -> CardDefinition;
 
 pub static DEFINITIONS: Lazy<DashSet<(u64, CardFn)>> = Lazy::new(DashSet::new);
 
+/// Card definitions which are generated at startup instead of being written by
+/// hand, e.g. from the Oracle text of vanilla creatures.
+///
+/// Hand-written definitions in [DEFINITIONS] take precedence over generated
+/// definitions for the same card. Definitions must be registered before the
+/// first lookup of any card definition.
+static GENERATED: Lazy<Mutex<Vec<CardDefinition>>> = Lazy::new(|| Mutex::new(vec![]));
+
 struct CardMap {
     cards: BTreeMap<CardName, CardDefinition>,
 }
@@ -39,9 +48,20 @@ static CARDS: Lazy<CardMap> = Lazy::new(|| {
         assert!(!map.contains_key(&card.card_name()), "Duplicate card name found");
         map.insert(card.card_name(), card);
     }
+    let generated = std::mem::take(&mut *GENERATED.lock().expect("Error locking definitions"));
+    for card in generated {
+        map.entry(card.card_name()).or_insert(card);
+    }
     CardMap { cards: map }
 });
 
+/// Registers a generated [CardDefinition].
+///
+/// Has no effect if a hand-written definition exists for the same card.
+pub fn register_generated(card: CardDefinition) {
+    GENERATED.lock().expect("Error locking definitions").push(card);
+}
+
 /// Returns an iterator over all known card definitions in an undefined order
 pub fn all_cards() -> impl Iterator<Item = &'static CardDefinition> {
     assert!(!CARDS.cards.is_empty(), "Cards not found. Call card_list::initialize() first.");
@@ -13,8 +13,12 @@
 // limitations under the License.
 
 use primitives::game_primitives::{EntityId, PlayerName, Source};
+use utils::outcome;
+use utils::outcome::Outcome;
 
-use crate::card_definitions::ability_definition::{Ability, AbilityBuilder, AbilityType, NoEffect};
+use crate::card_definitions::ability_definition::{
+    Ability, AbilityBuilder, AbilityType, NoEffect, TargetCount,
+};
 use crate::card_states::play_card_plan::{ModalChoice, PlayCardChoices};
 use crate::events::event_context::EventContext;
 use crate::game_states::game_state::GameState;
@@ -52,11 +56,24 @@ impl AbilityMode {
             properties: None,
             global_events: None,
             card_events: None,
+            functional_zones: None,
             effect: NoEffect,
         }
     }
 }
 
+impl AbilityBuilder<ModalEffect> {
+    /// Returns the first chosen mode of this ability which requires targets.
+    fn targeted_mode(&self, choices: &PlayCardChoices) -> Option<&dyn Ability> {
+        self.effect
+            .modes
+            .iter()
+            .enumerate()
+            .find(|(i, mode)| choices.modes.contains(&ModalChoice(*i)) && mode.requires_targets())
+            .map(|(_, mode)| mode.as_ref())
+    }
+}
+
 impl Ability for AbilityBuilder<ModalEffect> {
     fn is_modal(&self) -> bool {
         true
@@ -86,20 +103,29 @@ impl Ability for AbilityBuilder<ModalEffect> {
         )
     }
 
+    fn target_count(&self, choices: &PlayCardChoices) -> TargetCount {
+        self.targeted_mode(choices).map_or(TargetCount::ONE, |mode| mode.target_count(choices))
+    }
+
+    fn divided_total(&self, choices: &PlayCardChoices) -> Option<u32> {
+        self.targeted_mode(choices)?.divided_total(choices)
+    }
+
     fn invoke_effect(
         &self,
         game: &mut GameState,
         context: EventContext,
         choices: &Option<PlayCardChoices>,
-    ) {
+    ) -> Outcome {
         let Some(modal_choices) = choices.as_ref().map(|c| &c.modes) else {
             panic!("Expected modal choices for modal effect");
         };
 
         for (i, mode) in self.effect.modes.iter().enumerate() {
             if modal_choices.contains(&ModalChoice(i)) {
-                mode.invoke_effect(game, context, choices);
+                outcome::execute(|| mode.invoke_effect(game, context, choices))?;
             }
         }
+        outcome::OK
     }
 }
@@ -17,8 +17,9 @@ use std::sync::Arc;
 
 use enumset::EnumSet;
 use primitives::game_primitives::{
-    AbilityId, CardId, EntityId, EventId, GraveyardCardId, HasController, HasPlayerName, HasSource,
-    ObjectId, PermanentId, PlayerName, SpellId, Timestamp, Zone,
+    AbilityId, CardId, EntityId, EventId, ExiledCardId, GraveyardCardId, HasController,
+    HasPlayerName, HasSource, ObjectId, PermanentId, PlayerName, SpellId, Timestamp, Zone,
+    ALL_POSSIBLE_PLAYERS,
 };
 use serde::Deserialize;
 use slotmap::__impl::Serialize;
@@ -28,13 +29,14 @@ use crate::card_definitions::card_name::CardName;
 use crate::card_states::card_kind::CardKind;
 use crate::card_states::counters::Counters;
 use crate::card_states::custom_card_state::CustomCardStateList;
-use crate::card_states::play_card_plan::PlayCardChoices;
+use crate::card_states::play_card_plan::{AlternativeCost, OptionalCost, PlayCardChoices};
 #[allow(unused)] // Used in docs
 use crate::card_states::zones::Zones;
 use crate::card_states::zones::{HasZones, ToCardId};
 use crate::core::card_tags::CardTag;
 use crate::core::numerics::Damage;
 use crate::events::card_events::CardEvents;
+use crate::game_states::combat_state::CombatSpecialAction;
 #[allow(unused)] // Used in docs
 use crate::game_states::game_state::{GameState, TurnData};
 use crate::printed_cards::printed_card::{Face, PrintedCard, PrintedCardFace};
@@ -148,7 +150,17 @@ pub struct CardState {
     /// Whether this card is phased out.
     pub phasing_state: PhasingState,
 
-    /// Players who this card has been revealed to.
+    /// Which players can see the identity of this card by default in its
+    /// current zone.
+    ///
+    /// Do not read this field directly to check whether a player can see a
+    /// card, use [Self::visible_to] instead.
+    pub visibility: Visibility,
+
+    /// Players who this card has been individually revealed to, in addition
+    /// to those who can see it due to its [Visibility].
+    ///
+    /// Cleared when this card changes zones.
     pub revealed_to: EnumSet<PlayerName>,
 
     /// Counters on this card.
@@ -168,6 +180,10 @@ pub struct CardState {
     /// Cards which are not on the stack cannot have targets.
     pub targets: Vec<EntityId>,
 
+    /// Amount assigned to each entry in `targets` when this card divides an
+    /// amount among its targets. Empty otherwise.
+    pub target_amounts: Vec<u32>,
+
     /// The entity this card is attached to.
     ///
     /// Cards such as Equipment, Auras, and Fortifications can be attached to a
@@ -175,6 +191,64 @@ pub struct CardState {
     /// attached to each other.
     pub attached_to: Option<EntityId>,
 
+    /// Players who have goaded this permanent.
+    ///
+    /// Do not mutate this field directly, use the `permanents` module instead.
+    pub goaded: Vec<GoadedState>,
+
+    /// Effects which prevent this permanent from untapping during its
+    /// controller's untap step.
+    ///
+    /// Do not mutate this field directly, use the `permanents` module instead.
+    pub untap_restrictions: Vec<UntapRestriction>,
+
+    /// Players who may play this card while it is in exile, e.g. as a result
+    /// of an "impulse draw" effect.
+    ///
+    /// Do not mutate this field directly, use the `linked_exile` module
+    /// instead.
+    pub exile_play_permissions: Vec<ExilePlayPermission>,
+
+    /// Optional additional costs which can be paid while casting this card,
+    /// e.g. kicker.
+    pub optional_costs: Vec<OptionalCost>,
+
+    /// Alternative costs which can be paid instead of this card's mana cost
+    /// while casting it, e.g. mutate.
+    pub alternative_costs: Vec<AlternativeCost>,
+
+    /// The player responsible for defending this permanent, if it is a battle.
+    ///
+    /// Do not mutate this field directly, use the `battles` module instead.
+    pub protector: Option<PlayerName>,
+
+    /// Chapter numbers of this card's chapter abilities, if it is a Saga.
+    pub saga_chapters: Vec<u32>,
+
+    /// Cards which make up this permanent if it is a merged permanent, ordered
+    /// from top to bottom. Includes this card itself. Empty if this permanent
+    /// is not merged.
+    ///
+    /// Do not mutate this field directly, use the `mutate` module instead.
+    pub merged_components: Vec<CardId>,
+
+    /// The merged permanent this card is a component of, if it is a component
+    /// other than the card which represents that permanent.
+    ///
+    /// Such cards are considered to be on the battlefield but do not appear
+    /// in the battlefield indices of [Zones].
+    ///
+    /// Do not mutate this field directly, use the `mutate` module instead.
+    pub merged_into: Option<PermanentId>,
+
+    /// Rules of this card from before any other cards merged with it,
+    /// restored when the merged permanent leaves the battlefield.
+    pub premerge_rules: Option<Box<PremergeRules>>,
+
+    /// Special actions this card can take from its owner's hand during combat,
+    /// e.g. ninjutsu.
+    pub combat_special_actions: Vec<CombatSpecialAction>,
+
     /// Stores custom state entries for this card.
     ///
     /// See [CustomCardStateList].
@@ -207,7 +281,38 @@ pub struct CardState {
     pub printed_card_reference: Option<Arc<PrintedCard>>,
 }
 
+/// Rules of a card from before any other cards merged with it.
+///
+/// See [CardState::premerge_rules].
+#[derive(Clone)]
+pub struct PremergeRules {
+    pub properties: CardProperties,
+    pub events: CardEvents,
+    pub optional_costs: Vec<OptionalCost>,
+    pub alternative_costs: Vec<AlternativeCost>,
+    pub combat_special_actions: Vec<CombatSpecialAction>,
+    pub saga_chapters: Vec<u32>,
+}
+
 impl CardState {
+    /// Players who can currently see the identity of this card, combining its
+    /// [Visibility] with any players it has been individually revealed to.
+    pub fn visible_to(&self) -> EnumSet<PlayerName> {
+        self.revealed_to
+            | match self.visibility {
+                Visibility::None => EnumSet::empty(),
+                Visibility::OwnerOnly => EnumSet::only(self.owner),
+                Visibility::ControllerOnly => EnumSet::only(self.controller()),
+                Visibility::AllPlayers => ALL_POSSIBLE_PLAYERS,
+            }
+    }
+
+    /// Returns true if the [PlayerName] player can currently see the identity
+    /// of this card. See [Self::visible_to].
+    pub fn is_visible_to(&self, player: PlayerName) -> bool {
+        self.visible_to().contains(player)
+    }
+
     /// Returns the [EntityId] for this card.
     ///
     /// Panics if this card was assigned an invalid entity id.
@@ -242,6 +347,15 @@ impl CardState {
         }
     }
 
+    /// Returns this card's [ExiledCardId] if it is in exile.
+    pub fn exiled_card_id(&self) -> Option<ExiledCardId> {
+        if self.zone == Zone::Exiled {
+            Some(ExiledCardId::new(self.object_id, self.id))
+        } else {
+            None
+        }
+    }
+
     /// Queries the whether the current set of [CardTag]s for this card contains
     /// a given tag.
     pub fn has_tag(&self, game: &GameState, source: impl HasSource, tag: CardTag) -> Option<bool> {
@@ -304,6 +418,43 @@ impl TappedState {
     }
 }
 
+/// Which players can see the identity of a card.
+///
+/// > 400.2. Public zones are zones in which all players can see the cards'
+/// > faces, except for those cards that some rule or effect specifically allow
+/// > to be face down. Graveyard, battlefield, stack, exile, ante, and command
+/// > are public zones. Hidden zones are zones in which not all players can be
+/// > expected to see the cards' faces. Library and hand are hidden zones, even
+/// > if all the cards in one such zone happen to be revealed.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R4002>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Visibility {
+    /// No player can see this card, e.g. a card in a library.
+    None,
+
+    /// Only this card's owner can see it, e.g. a card in hand or a foretold
+    /// card in exile.
+    OwnerOnly,
+
+    /// Only this card's controller can see it, e.g. a face-down permanent.
+    ControllerOnly,
+
+    /// All players can see this card, e.g. a card in a public zone.
+    AllPlayers,
+}
+
+impl Visibility {
+    /// Default visibility for a card which has just entered the given [Zone].
+    pub fn for_zone(zone: Zone) -> Self {
+        match zone {
+            Zone::Library => Visibility::None,
+            Zone::Hand => Visibility::OwnerOnly,
+            _ => Visibility::AllPlayers,
+        }
+    }
+}
+
 /// Facing for this card, corresponding to the [PrintedCard] faces.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Type, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -329,6 +480,45 @@ pub enum PhasingState {
     PhasedOut,
 }
 
+/// Stores information about a player who has goaded a permanent.
+///
+/// > 701.15b. Goaded is a designation a permanent can have. A goaded creature
+/// > attacks each combat if able and attacks a player other than the goading
+/// > player if able.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70115b>
+#[derive(Clone, Copy)]
+pub struct GoadedState {
+    /// Player who goaded this permanent.
+    pub player: PlayerName,
+
+    /// Duration for which this permanent is goaded.
+    pub duration: Duration,
+}
+
+/// Permission for a player to play a card from exile.
+#[derive(Clone, Copy)]
+pub struct ExilePlayPermission {
+    /// Player who may play this card.
+    pub player: PlayerName,
+
+    /// Duration for which this card may be played.
+    pub duration: Duration,
+}
+
+/// An effect which prevents a permanent from untapping during its controller's
+/// untap step.
+#[derive(Clone, Copy)]
+pub enum UntapRestriction {
+    /// Permanent does not untap during its controller's next untap step, e.g.
+    /// because it was exerted.
+    NextUntapStep,
+
+    /// Permanent does not untap during its controller's untap step while this
+    /// [Duration] is active.
+    WhileActive(Duration),
+}
+
 /// Stores information about a card which has lost all abilities.
 #[derive(Clone)]
 pub struct LostAllAbilities {
@@ -29,6 +29,68 @@ pub struct Counters {
     pub other_counters: BTreeMap<CounterType, u32>,
 }
 
+impl Counters {
+    /// Returns the number of counters of the given [CounterType] on this
+    /// object.
+    pub fn get(&self, counter: CounterType) -> u32 {
+        match counter {
+            CounterType::P1p1 => self.p1p1,
+            CounterType::M1m1 => self.m1m1,
+            CounterType::Loyalty => self.loyalty as u32,
+            _ => self.other_counters.get(&counter).copied().unwrap_or(0),
+        }
+    }
+
+    /// Adds `count` counters of the given [CounterType] to this object.
+    pub fn add(&mut self, counter: CounterType, count: u32) {
+        match counter {
+            CounterType::P1p1 => self.p1p1 += count,
+            CounterType::M1m1 => self.m1m1 += count,
+            CounterType::Loyalty => self.loyalty += count as Loyalty,
+            _ => *self.other_counters.entry(counter).or_default() += count,
+        }
+    }
+
+    /// Removes up to `count` counters of the given [CounterType] from this
+    /// object.
+    ///
+    /// Returns the number of counters which were removed.
+    pub fn remove(&mut self, counter: CounterType, count: u32) -> u32 {
+        let removed = count.min(self.get(counter));
+        match counter {
+            CounterType::P1p1 => self.p1p1 -= removed,
+            CounterType::M1m1 => self.m1m1 -= removed,
+            CounterType::Loyalty => self.loyalty -= removed as Loyalty,
+            _ => {
+                if let Some(current) = self.other_counters.get_mut(&counter) {
+                    *current -= removed;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Returns each [CounterType] of which there is at least one counter on
+    /// this object.
+    pub fn kinds(&self) -> impl Iterator<Item = CounterType> + '_ {
+        [CounterType::P1p1, CounterType::M1m1, CounterType::Loyalty]
+            .into_iter()
+            .chain(self.other_counters.keys().copied())
+            .filter(|&counter| self.get(counter) > 0)
+    }
+
+    /// Removes equal numbers of +1/+1 and -1/-1 counters from this object,
+    /// such that it has only one of those kinds of counters on it.
+    ///
+    /// Returns the number of counters of each kind which were removed.
+    pub fn annihilate(&mut self) -> u32 {
+        let count = self.p1p1.min(self.m1m1);
+        self.p1p1 -= count;
+        self.m1m1 -= count;
+        count
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub enum CounterType {
     Acorn,
@@ -142,6 +204,7 @@ pub enum CounterType {
     Lifelink,
     Loot,
     Lore,
+    Loyalty,
     Luck,
     Manifestation,
     Mannequin,
@@ -150,6 +213,7 @@ pub enum CounterType {
     M0m1,
     M0m2,
     M1m0,
+    M1m1,
     M2m1,
     M2m2,
     Mine,
@@ -165,6 +229,7 @@ pub enum CounterType {
     Ore,
     P0p1,
     P1p0,
+    P1p1,
     P1p2,
     P2p2,
     Page,
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::EntityId;
+use primitives::game_primitives::{EntityId, ExiledCardId, PlayerName};
+
+use crate::game_states::game_state::TurnData;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CustomCardState {
@@ -20,6 +22,22 @@ pub enum CustomCardState {
     /// once this ObjectId expires (e.g. by the target moving to a different
     /// zone).
     TargetEntity { object_id: EntityId },
+
+    /// A card which was exiled by this card, e.g. by an "impulse draw" or
+    /// "Oblivion Ring" effect. The link is broken if the exiled card leaves
+    /// exile, since it becomes a new object.
+    LinkedExile { exiled_card_id: ExiledCardId },
+
+    /// This card was exiled "on an adventure" after resolving as an Adventure
+    /// spell controlled by `player`, who may cast it from exile. The state no
+    /// longer applies once the card leaves exile, since it becomes a new
+    /// object.
+    OnAdventure { exiled_card_id: ExiledCardId, player: PlayerName },
+
+    /// This card was foretold, i.e. exiled face down from its owner's hand
+    /// during the indicated turn. It may be cast for its foretell cost on a
+    /// later turn for as long as it remains exiled.
+    Foretold { exiled_card_id: ExiledCardId, turn: TurnData },
 }
 
 /// Records custom state entries for a given card.
@@ -32,3 +50,16 @@ pub enum CustomCardState {
 pub struct CustomCardStateList {
     list: Vec<CustomCardState>,
 }
+
+impl CustomCardStateList {
+    /// Appends a new state entry to this list.
+    pub fn push(&mut self, state: CustomCardState) {
+        self.list.push(state);
+    }
+
+    /// Returns an iterator over all state entries in this list, in the order
+    /// in which they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &CustomCardState> {
+        self.list.iter()
+    }
+}
@@ -18,6 +18,7 @@ use enumset::{EnumSet, EnumSetType};
 use primitives::game_primitives::{AbilityId, CardId, Color, EntityId, PermanentId, PlayerName};
 
 use crate::core::numerics::ManaValue;
+use crate::printed_cards::mana_cost::ManaCost;
 use crate::printed_cards::printed_card::Face;
 use crate::text_strings::Text;
 
@@ -38,6 +39,12 @@ pub struct PlayCardPlan {
     /// <https://yawgatog.com/resources/magic-rules/#R6012c>
     pub targets: Vec<EntityId>,
 
+    /// Amount assigned to each entry in `targets`, for spells which divide an
+    /// amount such as damage among their targets. Empty otherwise.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R6012d>
+    pub target_amounts: Vec<u32>,
+
     /// How the user will pay mana costs for this card if it is a spell
     pub mana_payment: ManaPaymentPlan,
 }
@@ -52,8 +59,10 @@ impl PlayCardPlan {
                 alternative_cost: None,
                 additional_choices: Vec::new(),
                 variable: None,
+                without_paying_mana_cost: false,
             },
             targets: Vec::new(),
+            target_amounts: Vec::new(),
             mana_payment: ManaPaymentPlan::default(),
         }
     }
@@ -110,6 +119,20 @@ pub struct PlayCardChoices {
     /// > player makes that choice at this time instead of that later time.
     /// <https://yawgatog.com/resources/magic-rules/#R6012b>
     pub variable: Option<ManaValue>,
+
+    /// True if this spell is being cast without paying its mana cost, e.g. via
+    /// cascade.
+    ///
+    /// > 118.9. Some spells have alternative costs. An alternative cost is a
+    /// > cost listed in a spell's text, or applied to it from another effect,
+    /// > that its controller may pay rather than paying the spell's mana cost.
+    ///
+    /// > 118.9d. If an alternative cost is being paid to cast a spell, any
+    /// > additional costs, cost increases, and cost reductions that affect that
+    /// > spell are applied to that alternative cost.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R1189>
+    pub without_paying_mana_cost: bool,
 }
 
 /// Describes a user's proposed plan for paying mana costs for a spell.
@@ -192,3 +215,93 @@ pub enum CastSpellPlanAdditionalChoice {
     /// <https://yawgatog.com/resources/magic-rules/#R6012b>
     SpliceWith(CardId),
 }
+
+/// An optional additional cost a player may choose to pay while casting a
+/// spell, such as a kicker cost.
+///
+/// > 118.8. Some spells and abilities have additional costs. An additional cost
+/// > is a cost listed in a spell's rules text, or applied to a spell or ability
+/// > from another effect, that its controller must pay at the same time they
+/// > pay the spell's mana cost or the ability's activation cost.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1188>
+#[derive(Debug, Clone)]
+pub struct OptionalCost {
+    /// Ability which provides this cost
+    pub ability_id: AbilityId,
+
+    /// Mana cost to pay each time this cost is chosen
+    pub cost: ManaCost,
+
+    /// How many times this cost can be paid
+    pub kind: OptionalCostKind,
+}
+
+/// Identifies the rules for paying an [OptionalCost].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OptionalCostKind {
+    /// Cost may be paid at most once.
+    ///
+    /// > 702.33a. Kicker is a static ability that functions while the spell
+    /// > with kicker is on the stack. "Kicker [cost]" means "You may pay an
+    /// > additional [cost] as you cast this spell."
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R70233a>
+    Kicker,
+
+    /// Cost may be paid any number of times.
+    ///
+    /// > 702.33c. Multikicker is a variant of the kicker ability. "Multikicker
+    /// > [cost]" means "You may pay an additional [cost] any number of times as
+    /// > you cast this spell."
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R70233c>
+    Multikicker,
+}
+
+/// An alternative cost which can be paid instead of a spell's mana cost, such
+/// as mutate.
+///
+/// > 118.9. Some spells have alternative costs. An alternative cost is a cost
+/// > listed in a spell's text, or applied to it from another effect, that its
+/// > controller may pay rather than paying the spell's mana cost.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1189>
+#[derive(Debug, Clone)]
+pub struct AlternativeCost {
+    /// Ability which provides this cost
+    pub ability_id: AbilityId,
+
+    /// Mana cost to pay instead of the spell's mana cost
+    pub cost: ManaCost,
+
+    /// Additional rules which apply when this cost is paid
+    pub kind: AlternativeCostKind,
+}
+
+/// Identifies the rules for paying an [AlternativeCost].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AlternativeCostKind {
+    /// Spell becomes a mutating creature spell.
+    ///
+    /// > 702.140a. Mutate appears on some creature cards. It represents a
+    /// > static ability that functions while the spell with mutate is on the
+    /// > stack. "Mutate [cost]" means "You may pay [cost] rather than pay this
+    /// > spell's mana cost. If you do, it becomes a mutating creature spell and
+    /// > targets a non-Human creature with the same owner as this spell."
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R702140a>
+    Mutate,
+
+    /// Spell is being cast from exile after being foretold.
+    ///
+    /// > 702.143a. Foretell is a keyword that functions while the card with
+    /// > foretell is in a player's hand. Any time a player has priority during
+    /// > their turn, that player may pay {2} and exile a card with foretell
+    /// > from their hand face down. That player may look at that card as long
+    /// > as it remains in exile. They may cast it on a later turn for its
+    /// > foretell cost rather than its mana cost.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R702143a>
+    Foretell,
+}
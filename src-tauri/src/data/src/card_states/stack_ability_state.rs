@@ -16,6 +16,7 @@ use primitives::game_primitives::{
     AbilityId, EntityId, EventId, HasController, HasPlayerName, ObjectId, PlayerName,
     StackAbilityId,
 };
+use utils::outcome::Outcome;
 
 use crate::card_states::play_card_plan::PlayCardChoices;
 use crate::card_states::zones::HasZones;
@@ -38,7 +39,7 @@ pub struct StackAbilityCustomEffect {
 impl StackAbilityCustomEffect {
     pub fn new(
         event_id: EventId,
-        effect: impl Fn(&mut GameState, EventContext) + Copy + Send + Sync + 'static,
+        effect: impl Fn(&mut GameState, EventContext) -> Outcome + Copy + Send + Sync + 'static,
     ) -> Self {
         Self { event_id, effect: Box::new(effect) }
     }
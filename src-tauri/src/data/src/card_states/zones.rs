@@ -12,25 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::Debug;
 use std::hash::Hash;
 
 use either::Either;
 use enumset::EnumSet;
 use primitives::game_primitives::{
-    AbilityId, CardId, EntityId, GraveyardCardId, HasController, HasPlayerName, ObjectId,
-    PermanentId, PlayerName, SpellId, StackAbilityId, StackItemId, Timestamp, Zone,
+    AbilityId, CardId, EntityId, ExiledCardId, GraveyardCardId, HasController, HasPlayerName,
+    ObjectId, PermanentId, PlayerName, SpellId, StackAbilityId, StackItemId, Timestamp, Zone,
 };
 use rand::prelude::SliceRandom;
 use rand_xoshiro::Xoshiro256StarStar;
 use slotmap::SlotMap;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::card_states::card_kind::CardKind;
 use crate::card_states::card_reference::CardReference;
-use crate::card_states::card_state::{CardFacing, CardState, PhasingState, TappedState};
+use crate::card_states::card_state::{
+    CardFacing, CardState, PhasingState, TappedState, Visibility,
+};
 use crate::card_states::counters::Counters;
 use crate::card_states::custom_card_state::CustomCardStateList;
 use crate::card_states::stack_ability_state::StackAbilityState;
@@ -61,6 +63,10 @@ pub trait ZoneQueries {
     /// Panics if this stack ability does not exist.
     fn stack_ability(&self, id: StackAbilityId) -> &StackAbilityState;
 
+    /// Looks up the state for an ability on the stack, returning None if this
+    /// stack ability no longer exists.
+    fn get_stack_ability(&self, id: StackAbilityId) -> Option<&StackAbilityState>;
+
     /// Mutable equivalent of [Self::stack_ability].
     fn stack_ability_mut(&mut self, id: StackAbilityId) -> &mut StackAbilityState;
 
@@ -151,6 +157,16 @@ impl ToCardId for GraveyardCardId {
     }
 }
 
+impl ToCardId for ExiledCardId {
+    fn to_card_id(&self, zones: &impl HasZones) -> Option<CardId> {
+        if zones.zones().card(self.internal_card_id)?.object_id == self.object_id {
+            Some(self.internal_card_id)
+        } else {
+            None
+        }
+    }
+}
+
 impl ToCardId for StackItemId {
     fn to_card_id(&self, zones: &impl HasZones) -> Option<CardId> {
         match self {
@@ -267,6 +283,10 @@ impl ZoneQueries for Zones {
         &self.stack_abilities[id]
     }
 
+    fn get_stack_ability(&self, id: StackAbilityId) -> Option<&StackAbilityState> {
+        self.stack_abilities.get(id)
+    }
+
     fn stack_ability_mut(&mut self, id: StackAbilityId) -> &mut StackAbilityState {
         &mut self.stack_abilities[id]
     }
@@ -360,11 +380,24 @@ impl Zones {
             cast_choices: None,
             tapped_state: TappedState::Untapped,
             phasing_state: PhasingState::PhasedIn,
+            visibility: Visibility::for_zone(zone),
             revealed_to: EnumSet::empty(),
             counters: Counters::default(),
             damage: 0,
             targets: vec![],
+            target_amounts: vec![],
             attached_to: None,
+            goaded: vec![],
+            untap_restrictions: vec![],
+            exile_play_permissions: vec![],
+            optional_costs: vec![],
+            alternative_costs: vec![],
+            protector: None,
+            saga_chapters: vec![],
+            merged_components: vec![],
+            merged_into: None,
+            premerge_rules: None,
+            combat_special_actions: vec![],
             custom_state: CustomCardStateList::default(),
             entered_current_zone: current_turn,
             last_changed_control: current_turn,
@@ -437,8 +470,8 @@ impl Zones {
     /// This is most commonly used via a state-based action to remove token
     /// cards that have left the battlefield.
     pub fn destroy_card(&mut self, id: CardId) -> Outcome {
-        let card = self.card(id)?;
-        self.remove_from_zone(card.owner, card.id, card.zone);
+        let card = self.card(id).or_skip()?;
+        self.remove_from_zone(card.owner, card.id, card.zone)?;
         self.all_cards.remove(id);
         outcome::OK
     }
@@ -449,15 +482,16 @@ impl Zones {
     ///
     /// The card is added as the top card of the target zone if it is ordered.
     ///
-    /// Returns None if this card was not found in the previous zone.
+    /// Returns None if this card does not exist, and reports a rule error if
+    /// it was not found in its previous zone.
     pub fn move_card(&mut self, id: impl ToCardId, zone: Zone, new_object_id: ObjectId) -> Outcome {
-        let card = self.card(id)?;
+        let card = self.card(id).or_skip()?;
         let card_id = card.id;
         let old_zone = card.zone;
         let owner = card.owner;
-        self.remove_from_zone(owner, card_id, old_zone);
+        self.remove_from_zone(owner, card_id, old_zone)?;
         let timestamp = self.new_timestamp();
-        let card = self.card_mut(card_id).expect("Card not found");
+        let card = self.card_mut(card_id).or_skip()?;
         card.zone = zone;
         card.previous_object_id = Some(card.object_id);
         card.object_id = new_object_id;
@@ -466,6 +500,37 @@ impl Zones {
         outcome::OK
     }
 
+    /// Merges a card with a permanent, e.g. via mutate, and assigns a new
+    /// [ObjectId] to it. Do not call this method directly, use the `mutate`
+    /// module instead.
+    ///
+    /// The card is removed from its previous zone and moved to the
+    /// battlefield, but is not added to the battlefield indices since it is
+    /// represented by the merged permanent.
+    ///
+    /// Returns None if this card does not exist, and reports a rule error if
+    /// it was not found in its previous zone.
+    pub fn merge_card(
+        &mut self,
+        id: impl ToCardId,
+        into: PermanentId,
+        new_object_id: ObjectId,
+    ) -> Outcome {
+        let card = self.card(id).or_skip()?;
+        let card_id = card.id;
+        let old_zone = card.zone;
+        let owner = card.owner;
+        self.remove_from_zone(owner, card_id, old_zone)?;
+        let timestamp = self.new_timestamp();
+        let card = self.card_mut(card_id).or_skip()?;
+        card.zone = Zone::Battlefield;
+        card.previous_object_id = Some(card.object_id);
+        card.object_id = new_object_id;
+        card.timestamp = timestamp;
+        card.merged_into = Some(into);
+        outcome::OK
+    }
+
     /// Adds a list of items to the top of the stack in the given order.
     pub fn add_abilities_to_stack(&mut self, mut ids: Vec<StackItemId>) {
         self.stack.append(&mut ids);
@@ -513,24 +578,24 @@ impl Zones {
     /// Do not invoke this method directly, use the `change_controller` module
     /// instead.
     ///
-    /// Returns None if this card does not exist.
+    /// Skipped if this card does not exist.
     pub fn on_controller_changed(
         &mut self,
         id: impl ToCardId,
         old_controller: PlayerName,
         new_controller: PlayerName,
         current_turn: TurnData,
-    ) -> Option<()> {
-        let card = self.card(id)?;
+    ) -> Outcome {
+        let card = self.card(id).or_skip()?;
 
         if let Some(permanent_id) = card.permanent_id() {
             if card.zone == Zone::Battlefield && old_controller != new_controller {
-                self.battlefield_controlled.remove(permanent_id, old_controller);
+                self.battlefield_controlled.remove(permanent_id, old_controller)?;
                 self.battlefield_controlled.cards_mut(new_controller).insert(permanent_id);
             }
         }
 
-        Some(())
+        outcome::OK
     }
 
     /// Shuffles the order of cards in a player's library
@@ -538,22 +603,41 @@ impl Zones {
         self.libraries.cards_mut(player.player_name()).make_contiguous().shuffle(rng);
     }
 
-    fn remove_from_zone(&mut self, owner: PlayerName, card_id: CardId, zone: Zone) {
+    /// Moves a card in a player's library to the bottom of that library
+    pub fn move_to_bottom_of_library(&mut self, player: impl HasPlayerName, card_id: CardId) {
+        let library = self.libraries.cards_mut(player.player_name());
+        library.retain(|&id| id != card_id);
+        library.push_front(card_id);
+    }
+
+    /// Moves a card in a player's library to the top of that library
+    pub fn move_to_top_of_library(&mut self, player: impl HasPlayerName, card_id: CardId) {
+        let library = self.libraries.cards_mut(player.player_name());
+        library.retain(|&id| id != card_id);
+        library.push_back(card_id);
+    }
+
+    fn remove_from_zone(&mut self, owner: PlayerName, card_id: CardId, zone: Zone) -> Outcome {
         match zone {
             Zone::Hand => self.hands.remove(card_id, owner),
             Zone::Graveyard => {
                 let Some(graveyard_id) = self.card(card_id).and_then(|c| c.graveyard_card_id())
                 else {
-                    return;
+                    return outcome::OK;
                 };
                 self.graveyards.remove(graveyard_id, owner)
             }
             Zone::Library => self.libraries.remove(card_id, owner),
             Zone::Battlefield => {
-                let Some(permanent_id) = self.card(card_id).and_then(|c| c.permanent_id()) else {
-                    return;
+                let card = self.card(card_id).or_skip()?;
+                if card.merged_into.is_some() {
+                    // Merged components are not present in battlefield indices
+                    return outcome::OK;
+                }
+                let Some(permanent_id) = card.permanent_id() else {
+                    return outcome::OK;
                 };
-                self.battlefield_owned.remove(permanent_id, owner);
+                self.battlefield_owned.remove(permanent_id, owner)?;
                 if !self.battlefield_controlled.cards_mut(owner).remove(&permanent_id) {
                     let mut removed = false;
                     for player in enum_iterator::all::<PlayerName>() {
@@ -561,13 +645,16 @@ impl Zones {
                             self.battlefield_controlled.cards_mut(player).remove(&permanent_id);
                     }
                     if !removed {
-                        panic!("Card not found {card_id:?} in controller set");
+                        return outcome::error(format!(
+                            "Card {card_id:?} not found in controller set"
+                        ));
                     }
                 }
+                outcome::OK
             }
             Zone::Stack => {
                 let Some(spell_id) = self.card(card_id).and_then(|c| c.spell_id()) else {
-                    return;
+                    return outcome::OK;
                 };
                 if let Some((i, _)) = self
                     .stack
@@ -577,8 +664,9 @@ impl Zones {
                     .find(|(_, id)| **id == StackItemId::Spell(spell_id))
                 {
                     self.stack.remove(i);
+                    outcome::OK
                 } else {
-                    panic!("Card not found {card_id:?}");
+                    outcome::error(format!("Card {card_id:?} not found on the stack"))
                 }
             }
             Zone::Exiled => self.exile.remove(card_id, owner),
@@ -625,6 +713,140 @@ impl Zones {
         }
     }
 
+    /// Checks that the zone indices are consistent with the state of each
+    /// card, returning a description of each problem found.
+    ///
+    /// This verifies that every card is present in exactly the index for its
+    /// zone and owner, that permanents are present in the controlled set of
+    /// only their controller, that every index entry and stack item refers
+    /// to an existing object, and that no two objects share an [ObjectId].
+    ///
+    /// This examines every object in the game, so it should only be used for
+    /// debugging.
+    pub fn check_invariants(&self) -> Vec<String> {
+        let mut errors = vec![];
+        let mut entries = vec![];
+        for player in enum_iterator::all::<PlayerName>() {
+            let owner = Some(player);
+            entries.extend(
+                self.libraries.cards(player).iter().map(|&id| (Zone::Library, owner, id, None)),
+            );
+            entries
+                .extend(self.hands.cards(player).iter().map(|&id| (Zone::Hand, owner, id, None)));
+            entries.extend(
+                self.graveyards
+                    .cards(player)
+                    .iter()
+                    .map(|id| (Zone::Graveyard, owner, id.internal_card_id, Some(id.object_id))),
+            );
+            entries.extend(
+                self.battlefield_owned
+                    .cards(player)
+                    .iter()
+                    .map(|id| (Zone::Battlefield, owner, id.internal_card_id, Some(id.object_id))),
+            );
+            entries
+                .extend(self.exile.cards(player).iter().map(|&id| (Zone::Exiled, owner, id, None)));
+            entries.extend(
+                self.command_zone.cards(player).iter().map(|&id| (Zone::Command, owner, id, None)),
+            );
+            entries.extend(
+                self.outside_the_game_zone
+                    .cards(player)
+                    .iter()
+                    .map(|&id| (Zone::OutsideTheGame, owner, id, None)),
+            );
+        }
+        for item in &self.stack {
+            match item {
+                StackItemId::Spell(id) => {
+                    entries.push((Zone::Stack, None, id.internal_card_id, Some(id.object_id)))
+                }
+                StackItemId::StackAbility(id) => {
+                    if !self.stack_abilities.contains_key(*id) {
+                        errors.push(format!("Stack contains nonexistent ability {id:?}"));
+                    }
+                }
+            }
+        }
+
+        let mut memberships = BTreeMap::<CardId, Vec<String>>::new();
+        for (zone, owner, card_id, object_id) in entries {
+            let location = match owner {
+                Some(owner) => format!("{zone:?} of {owner:?}"),
+                None => format!("{zone:?}"),
+            };
+            let Some(card) = self.all_cards.get(card_id) else {
+                errors.push(format!("{location} contains nonexistent card {card_id:?}"));
+                continue;
+            };
+            if card.zone != zone || owner.is_some_and(|owner| owner != card.owner) {
+                errors.push(format!("{location} contains {}", describe_card(card)));
+            }
+            if object_id.is_some_and(|object_id| object_id != card.object_id) {
+                errors
+                    .push(format!("{location} contains {object_id:?} for {}", describe_card(card)));
+            }
+            memberships.entry(card_id).or_default().push(location);
+        }
+
+        for card in self.all_cards.values() {
+            let found = memberships.get(&card.id).map_or(&[][..], Vec::as_slice);
+            // Merged components are not present in any zone index
+            let expected = if card.merged_into.is_some() { 0 } else { 1 };
+            if found.len() != expected {
+                errors.push(format!(
+                    "{} found in {} zone indices {found:?}, expected {expected}",
+                    describe_card(card),
+                    found.len()
+                ));
+            }
+            if let Some(permanent_id) = card.permanent_id().filter(|_| card.merged_into.is_none()) {
+                for player in enum_iterator::all::<PlayerName>() {
+                    let controlled =
+                        self.battlefield_controlled.cards(player).contains(&permanent_id);
+                    if controlled != (player == card.controller()) {
+                        errors.push(format!(
+                            "{} has incorrect membership {controlled} in controlled set of \
+                            {player:?}",
+                            describe_card(card)
+                        ));
+                    }
+                }
+            }
+        }
+
+        for player in enum_iterator::all::<PlayerName>() {
+            for permanent_id in self.battlefield_controlled.cards(player) {
+                let card = self.all_cards.get(permanent_id.internal_card_id);
+                if card.and_then(|card| card.permanent_id()) != Some(*permanent_id) {
+                    errors.push(format!(
+                        "Controlled set of {player:?} contains {permanent_id:?}, which is {}",
+                        card.map_or("nonexistent".to_string(), describe_card)
+                    ));
+                }
+            }
+        }
+
+        let mut object_ids = BTreeMap::new();
+        let objects =
+            self.all_cards.values().map(|card| (card.object_id, describe_card(card))).chain(
+                self.stack_abilities.values().map(|ability| {
+                    (ability.object_id, format!("{:?} of {:?}", ability.id, ability.ability_id))
+                }),
+            );
+        for (object_id, description) in objects {
+            if object_id >= self.next_object_id {
+                errors.push(format!("{description} has unallocated {object_id:?}"));
+            }
+            if let Some(previous) = object_ids.insert(object_id, description.clone()) {
+                errors.push(format!("{object_id:?} is shared by {previous} and {description}"));
+            }
+        }
+
+        errors
+    }
+
     /// Returns a new unique, monotonically-increasing [Timestamp].
     pub fn new_timestamp(&mut self) -> Timestamp {
         let result = self.next_timestamp;
@@ -639,6 +861,19 @@ impl Zones {
     }
 }
 
+/// Describes a card for diagnostic messages.
+fn describe_card(card: &CardState) -> String {
+    let name = card.printed_card_reference.as_ref().map_or("Unknown", |_| card.displayed_name());
+    format!(
+        "{:?} '{name}' ({:?}, zone {:?}, owner {:?}, controller {:?})",
+        card.id,
+        card.object_id,
+        card.zone,
+        card.owner,
+        card.controller()
+    )
+}
+
 #[derive(Default, Debug, Clone)]
 struct UnorderedZone<T> {
     player1: BTreeSet<T>,
@@ -668,11 +903,13 @@ impl<T: ToCardId + Hash + Eq + PartialEq + Debug + Ord> UnorderedZone<T> {
 
     /// Removes a card from this zone.
     ///
-    /// Panics if this card is not present in this zone owned by `owner`.
-    pub fn remove(&mut self, card_id: T, owner: PlayerName) {
-        let removed = self.cards_mut(owner).remove(&card_id);
-        if !removed {
-            panic!("Card {card_id:?} not found");
+    /// Reports a rule error if this card is not present in this zone owned by
+    /// `owner`.
+    pub fn remove(&mut self, card_id: T, owner: PlayerName) -> Outcome {
+        if self.cards_mut(owner).remove(&card_id) {
+            outcome::OK
+        } else {
+            outcome::error(format!("Card {card_id:?} not found for {owner:?}"))
         }
     }
 }
@@ -706,15 +943,15 @@ impl<T: ToCardId + Hash + Eq + PartialEq + Debug + Ord> OrderedZone<T> {
 
     /// Removes a card from this zone.
     ///
-    /// The search is started from the top card in the zone. Panics if this
-    /// card is not present in this zone owned by `owner`.
-    pub fn remove(&mut self, card_id: T, owner: PlayerName) {
-        if let Some((i, _)) =
+    /// The search is started from the top card in the zone. Reports a rule
+    /// error if this card is not present in this zone owned by `owner`.
+    pub fn remove(&mut self, card_id: T, owner: PlayerName) -> Outcome {
+        let Some((i, _)) =
             self.cards_mut(owner).iter().enumerate().rev().find(|(_, &id)| id == card_id)
-        {
-            self.cards_mut(owner).remove(i).expect("Index {i:?} not found");
-        } else {
-            panic!("Card not found {card_id:?}");
-        }
+        else {
+            return outcome::error(format!("Card {card_id:?} not found for {owner:?}"));
+        };
+        self.cards_mut(owner).remove(i).or_skip()?;
+        outcome::OK
     }
 }
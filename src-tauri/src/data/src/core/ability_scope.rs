@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::AbilityId;
+use enumset::EnumSet;
+use primitives::game_primitives::{AbilityId, Zone};
 
 use crate::card_states::zones::HasZones;
 
@@ -25,4 +26,30 @@ use crate::card_states::zones::HasZones;
 pub struct AbilityScope {
     /// ID of this ability
     pub ability_id: AbilityId,
+
+    /// Zones in which this ability functions.
+    ///
+    /// Property modifiers created by this ability only apply while the card is
+    /// in one of these zones. See [AbilityData::functional_zones].
+    ///
+    /// [AbilityData::functional_zones]: crate::card_definitions::ability_definition::AbilityData::functional_zones
+    pub zones: EnumSet<Zone>,
+}
+
+impl AbilityScope {
+    /// Checks that this ability functions in all of the provided `zones`, e.g.
+    /// before registering an event callback which fires in those zones.
+    ///
+    /// Returns the zones which were not declared via
+    /// [AbilityData::functional_zones] as an error.
+    ///
+    /// [AbilityData::functional_zones]: crate::card_definitions::ability_definition::AbilityData::functional_zones
+    pub fn validate_scope(&self, zones: EnumSet<Zone>) -> Result<(), EnumSet<Zone>> {
+        let undeclared = zones - self.zones;
+        if undeclared.is_empty() {
+            Ok(())
+        } else {
+            Err(undeclared)
+        }
+    }
 }
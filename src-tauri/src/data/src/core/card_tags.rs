@@ -16,6 +16,31 @@ use enumset::EnumSetType;
 
 #[derive(Debug, Hash, Ord, PartialOrd, EnumSetType)]
 pub enum CardTag {
+    Deathtouch,
+    Exert,
     Flying,
     Haste,
+    Infect,
+    Lifelink,
+    Protection,
+    Vigilance,
+    Wither,
+
+    /// Heuristic tag for cards which destroy, exile, or otherwise remove
+    /// opposing permanents.
+    Removal,
+
+    /// Heuristic tag for cards which produce additional mana or search for
+    /// lands.
+    Ramp,
+
+    /// Heuristic tag for cards which draw additional cards or otherwise
+    /// generate extra resources.
+    CardAdvantage,
+
+    /// Heuristic tag for creatures which are difficult to block.
+    Evasive,
+
+    /// Heuristic tag for expensive threats which can end the game quickly.
+    Finisher,
 }
@@ -41,6 +41,23 @@ where
     }
 }
 
+/// Function which computes a modified version of a value, e.g. to implement a
+/// replacement effect which changes the amount of life a player would gain.
+pub trait Transformation<TArg, TResult>: DynClone + Send + Sync + 'static {
+    fn invoke(&self, data: &GameState, source: Source, arg: &TArg, current: TResult) -> TResult;
+}
+
+dyn_clone::clone_trait_object!(<TArg, TResult> Transformation<TArg, TResult>);
+
+impl<TArg, TResult, F> Transformation<TArg, TResult> for F
+where
+    F: Fn(&GameState, Source, &TArg, TResult) -> TResult + Copy + Clone + Send + Sync + 'static,
+{
+    fn invoke(&self, data: &GameState, source: Source, arg: &TArg, current: TResult) -> TResult {
+        self(data, source, arg, current)
+    }
+}
+
 /// Function which performs a boolean query on the state of a card.
 pub trait CardPredicate<TId: ToCardId>:
     Fn(&GameState, Source, TId) -> Option<bool> + 'static + Copy + Send + Sync
@@ -56,31 +73,31 @@ pub type CardPredicateFn<TId> =
     Box<dyn Fn(&GameState, Source, TId) -> Option<bool> + 'static + Send + Sync>;
 
 pub trait Effect: DynClone + Send + Sync + 'static {
-    fn invoke(&self, data: &mut GameState, context: EventContext);
+    fn invoke(&self, data: &mut GameState, context: EventContext) -> Outcome;
 }
 
 dyn_clone::clone_trait_object!(Effect);
 
 impl<F> Effect for F
 where
-    F: Fn(&mut GameState, EventContext) + Clone + Send + Sync + 'static,
+    F: Fn(&mut GameState, EventContext) -> Outcome + Clone + Send + Sync + 'static,
 {
-    fn invoke(&self, data: &mut GameState, context: EventContext) {
+    fn invoke(&self, data: &mut GameState, context: EventContext) -> Outcome {
         self(data, context)
     }
 }
 
 pub trait Mutation<TArg>: DynClone + Send + Sync + 'static {
-    fn invoke(&self, data: &mut GameState, context: EventContext, arg: &TArg);
+    fn invoke(&self, data: &mut GameState, context: EventContext, arg: &TArg) -> Outcome;
 }
 
 dyn_clone::clone_trait_object!(<TArg> Mutation<TArg>);
 
 impl<TArg, F> Mutation<TArg> for F
 where
-    F: Fn(&mut GameState, EventContext, &TArg) + Copy + Send + Sync + 'static,
+    F: Fn(&mut GameState, EventContext, &TArg) -> Outcome + Copy + Send + Sync + 'static,
 {
-    fn invoke(&self, data: &mut GameState, context: EventContext, arg: &TArg) {
+    fn invoke(&self, data: &mut GameState, context: EventContext, arg: &TArg) -> Outcome {
         self(data, context, arg)
     }
 }
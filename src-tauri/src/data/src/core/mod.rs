@@ -20,3 +20,4 @@ pub mod modifier_data;
 pub mod numerics;
 pub mod panel_address;
 pub mod rule_type;
+pub mod sound_effect;
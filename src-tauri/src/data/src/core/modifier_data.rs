@@ -12,19 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::{PermanentId, Source, PRINTED_TEXT_TIMESTAMP};
+use primitives::game_primitives::{CardId, PermanentId, Source, PRINTED_TEXT_TIMESTAMP};
 
 use crate::core::ability_scope::AbilityScope;
 use crate::core::layer::{EffectSortingKey, Layer, PRINTED_RULE_SORTING_KEY};
 use crate::events::event_context::EventContext;
+use crate::game_states::game_state::GameState;
 use crate::properties::duration::Duration;
 
+/// Condition for a static ability which applies "as long as" some game state
+/// holds.
+///
+/// This is invoked with the [CardId] of the card which has the ability each
+/// time a property it modifies is queried, rather than adding and removing
+/// the modifier in response to game events. Conditions must not query the
+/// property they modify on the same card, since this would recurse forever.
+pub type ConditionFn = fn(&GameState, Source, CardId) -> Option<bool>;
+
 /// Possible ways of modifying a card.
 #[derive(Clone, Copy)]
 pub enum ModifierMode {
     /// Modifier based on an ability printed on a card.
     PrintedAbility(AbilityScope),
 
+    /// Modifier based on an ability printed on a card which only applies
+    /// while a condition holds, e.g. "as long as you control three or more
+    /// artifacts".
+    ///
+    /// See [ConditionFn].
+    PrintedAbilityWhile(AbilityScope, ConditionFn),
+
     /// Modifier created by a game effect.
     Effect(EventContext, Layer, Duration),
 }
@@ -42,7 +59,9 @@ impl ModifierMode {
 
     pub fn sorting_key(&self) -> EffectSortingKey {
         match self {
-            ModifierMode::PrintedAbility(_) => PRINTED_RULE_SORTING_KEY,
+            ModifierMode::PrintedAbility(_) | ModifierMode::PrintedAbilityWhile(..) => {
+                PRINTED_RULE_SORTING_KEY
+            }
             ModifierMode::Effect(context, layer, _) => {
                 EffectSortingKey::new(*layer, context.event_id.timestamp())
             }
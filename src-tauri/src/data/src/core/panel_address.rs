@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::PlayerName;
+use primitives::game_primitives::{PlayerName, Zone};
 use serde::{Deserialize, Serialize};
 
 /// Identifier for a piece of UI which can be contextually displayed.
@@ -28,6 +28,20 @@ pub enum PanelAddress {
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum GamePanelAddress {
     GameDebugPanel,
+    RulesConsolePanel,
+    SettingsPanel,
+
+    /// Cards in other players' hidden zones which have been revealed to the
+    /// viewer.
+    KnownCardsPanel,
+
+    /// Ordered list of the cards owned by a player in a graveyard, exile, or
+    /// library. `page` is the zero-indexed page of results to display.
+    ZoneBrowser {
+        owner: PlayerName,
+        zone: Zone,
+        page: usize,
+    },
 }
 
 impl From<GamePanelAddress> for PanelAddress {
@@ -37,7 +51,11 @@ impl From<GamePanelAddress> for PanelAddress {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
-pub enum UserPanelAddress {}
+pub enum UserPanelAddress {
+    CampaignMap,
+    Profile,
+    HouseRules,
+}
 
 impl From<UserPanelAddress> for PanelAddress {
     fn from(address: UserPanelAddress) -> Self {
@@ -0,0 +1,39 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use enum_iterator::Sequence;
+use enumset::EnumSetType;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Semantic sound events which can be played by the client in response to
+/// changes in game state.
+#[derive(Debug, Ord, PartialOrd, Hash, EnumSetType, Sequence, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum SoundEffect {
+    /// A card was drawn from a library
+    CardDrawn,
+
+    /// A spell was put onto the stack
+    SpellCast,
+
+    /// A creature was put into a graveyard from the battlefield
+    CreatureDies,
+
+    /// The game ended and this player won
+    GameWon,
+
+    /// The game ended and this player lost
+    GameLost,
+}
@@ -28,4 +28,25 @@ pub struct Deck {
     /// Quantities of cards in this deck
     #[serde_as(as = "Vec<(_, _)>")]
     pub cards: BTreeMap<PrintedCardId, u64>,
+
+    /// Preferred printings of cards in this deck, keyed by the ID used for the
+    /// card in [Self::cards].
+    ///
+    /// Cards without an entry here, or whose preferred printing cannot be
+    /// found, use the printing in [Self::cards].
+    #[serde(default)]
+    #[serde_as(as = "Vec<(_, _)>")]
+    pub printings: BTreeMap<PrintedCardId, CardPrinting>,
+}
+
+/// Identifies a specific printing of a card, used to select alternate card
+/// art.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardPrinting {
+    /// Code of the set this card was printed in, e.g. "MH3".
+    pub set_code: String,
+
+    /// Collector number of this printing within its set, e.g. "123a".
+    pub collector_number: String,
 }
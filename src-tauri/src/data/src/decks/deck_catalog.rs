@@ -0,0 +1,84 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::game_primitives::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::campaign::campaign_progress::CampaignProgress;
+use crate::decks::deck::Deck;
+use crate::decks::deck_name;
+use crate::decks::deck_name::DeckName;
+use crate::users::user_state::UserState;
+
+/// Collection of pre-constructed decks available for play.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeckCatalog {
+    pub decks: Vec<CatalogDeck>,
+
+    /// Shared planar deck of plane and phenomenon cards used for games played
+    /// with the Planechase variant, if any.
+    #[serde(default)]
+    pub planar_deck: Option<Deck>,
+}
+
+impl DeckCatalog {
+    /// Looks up a deck in this catalog by its [DeckName].
+    pub fn get(&self, name: DeckName) -> Option<&CatalogDeck> {
+        self.decks.iter().find(|deck| deck.id == name)
+    }
+
+    /// Returns the deck the indicated user plays with in campaign games.
+    ///
+    /// This is the deck the user has selected if it is still available to
+    /// them, or [deck_name::DANDAN] otherwise.
+    pub fn selected_deck(&self, user: &UserState) -> DeckName {
+        user.selected_deck
+            .filter(|&name| self.get(name).is_some_and(|deck| deck.is_available(&user.campaign)))
+            .unwrap_or(deck_name::DANDAN)
+    }
+}
+
+/// A pre-constructed deck along with descriptive metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogDeck {
+    /// Unique identifier for this deck
+    pub id: DeckName,
+
+    /// Name of this deck to display to the user
+    pub name: String,
+
+    /// Colors of cards in this deck, in display order
+    #[serde(default)]
+    pub colors: Vec<Color>,
+
+    /// Short description of this deck's strategy
+    #[serde(default)]
+    pub description: String,
+
+    /// If true, this deck can only be used after it has been unlocked as a
+    /// campaign reward.
+    #[serde(default)]
+    pub locked: bool,
+
+    /// Cards in this deck
+    pub deck: Deck,
+}
+
+impl CatalogDeck {
+    /// Returns true if a user with the given campaign progress may play with
+    /// this deck, i.e. if it is not locked or they have unlocked it.
+    pub fn is_available(&self, campaign: &CampaignProgress) -> bool {
+        !self.locked || campaign.is_unlocked(self.id)
+    }
+}
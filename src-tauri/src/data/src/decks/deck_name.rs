@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use serde::{Deserialize, Serialize};
+use specta::Type;
 use uuid::{uuid, Uuid};
 
 /// Unique identifier for a deck.
-#[derive(Debug, Copy, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Copy, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, Type)]
 pub struct DeckName(pub Uuid);
 
 pub const GREEN_VANILLA: DeckName = DeckName(uuid!("9eefebd4-25c0-4f46-91ab-6b3efff302e4"));
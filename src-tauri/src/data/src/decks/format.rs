@@ -0,0 +1,167 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::printed_cards::database_card::DatabaseCardPrinting;
+
+/// Minimum number of cards in a constructed deck.
+///
+/// > 100.2a. In constructed play (a way of playing in which each player creates
+/// > their own deck ahead of time), each deck has a minimum deck size of 60
+/// > cards.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1002a>
+pub const MINIMUM_DECK_SIZE: u64 = 60;
+
+/// Maximum number of copies of a card with the same name in a constructed
+/// deck, other than basic lands.
+pub const MAXIMUM_COPIES: u64 = 4;
+
+/// A constructed format with its own card pool and banned list.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum Format {
+    Standard,
+    Modern,
+    Pauper,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Standard => write!(f, "Standard"),
+            Format::Modern => write!(f, "Modern"),
+            Format::Pauper => write!(f, "Pauper"),
+        }
+    }
+}
+
+/// Rules determining which cards may be included in decks for a [Format].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatDefinition {
+    pub format: Format,
+
+    /// Codes of sets whose cards are legal in this format, e.g. "MH3". A card
+    /// is legal if any of its printings is in one of these sets.
+    ///
+    /// If None, cards from every set are legal.
+    #[serde(default)]
+    pub sets: Option<BTreeSet<String>>,
+
+    /// Rarity at which a card must have been printed to be legal in this
+    /// format, e.g. "common" for Pauper.
+    ///
+    /// If None, cards of every rarity are legal.
+    #[serde(default)]
+    pub rarity: Option<String>,
+
+    /// Names of cards which are banned in this format.
+    #[serde(default)]
+    pub banned: BTreeSet<String>,
+}
+
+impl FormatDefinition {
+    /// Returns true if a card with the provided printings is in the card pool
+    /// of this format, without considering the banned list.
+    pub fn is_in_card_pool(&self, printings: &[DatabaseCardPrinting]) -> bool {
+        printings.iter().any(|printing| {
+            self.sets.as_ref().map_or(true, |sets| sets.contains(&printing.set_code))
+                && self
+                    .rarity
+                    .as_ref()
+                    .map_or(true, |rarity| rarity.eq_ignore_ascii_case(&printing.rarity))
+        })
+    }
+
+    /// Returns true if the card with the given name is on this format's banned
+    /// list.
+    pub fn is_banned(&self, name: &str) -> bool {
+        self.banned.contains(name)
+    }
+
+    /// Checks a deck against the rules of this format.
+    ///
+    /// `cards` contains the known printings and quantity of each distinct card
+    /// in the deck. Banned cards are permitted if `ignore_banned_list` is true.
+    /// Returns a list of all problems found, which is empty if the deck is
+    /// legal.
+    pub fn check_deck(
+        &self,
+        cards: &[(Vec<DatabaseCardPrinting>, u64)],
+        ignore_banned_list: bool,
+    ) -> Vec<DeckLegalityProblem> {
+        let mut result = vec![];
+        let total = cards.iter().map(|(_, quantity)| quantity).sum::<u64>();
+        if total < MINIMUM_DECK_SIZE {
+            result.push(DeckLegalityProblem::TooFewCards(total));
+        }
+
+        for (printings, quantity) in cards {
+            let Some(first) = printings.first() else {
+                result.push(DeckLegalityProblem::UnknownCard);
+                continue;
+            };
+            let name = first.name.clone();
+            if *quantity > MAXIMUM_COPIES && !is_basic_land(first) {
+                result.push(DeckLegalityProblem::TooManyCopies(name.clone(), *quantity));
+            }
+            if !self.is_in_card_pool(printings) {
+                result.push(DeckLegalityProblem::NotInCardPool(name.clone()));
+            }
+            if !ignore_banned_list && self.is_banned(&name) {
+                result.push(DeckLegalityProblem::Banned(name));
+            }
+        }
+        result
+    }
+}
+
+/// Collection of all known [FormatDefinition]s.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormatCatalog {
+    pub formats: Vec<FormatDefinition>,
+}
+
+impl FormatCatalog {
+    /// Looks up the definition of a [Format] in this catalog.
+    pub fn get(&self, format: Format) -> Option<&FormatDefinition> {
+        self.formats.iter().find(|definition| definition.format == format)
+    }
+}
+
+/// Reason a deck is not legal in a [Format].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DeckLegalityProblem {
+    /// The deck contains fewer than [MINIMUM_DECK_SIZE] cards.
+    TooFewCards(u64),
+
+    /// The named card appears more than [MAXIMUM_COPIES] times.
+    TooManyCopies(String, u64),
+
+    /// The named card is not in the card pool of this format.
+    NotInCardPool(String),
+
+    /// The named card is banned in this format.
+    Banned(String),
+
+    /// No printings were found for a card in the deck.
+    UnknownCard,
+}
+
+fn is_basic_land(printing: &DatabaseCardPrinting) -> bool {
+    printing.supertypes.as_ref().is_some_and(|supertypes| supertypes.contains("Basic"))
+}
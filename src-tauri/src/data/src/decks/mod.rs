@@ -13,4 +13,6 @@
 // limitations under the License.
 
 pub mod deck;
+pub mod deck_catalog;
 pub mod deck_name;
+pub mod format;
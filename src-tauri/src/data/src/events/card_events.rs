@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use invokable_macro::Invokable;
-use primitives::game_primitives::{PermanentId, PlayerName, Source};
+use primitives::game_primitives::{CardId, PermanentId, PlayerName, Source, Zone};
 use utils::outcome;
 use utils::outcome::Outcome;
 
@@ -28,6 +28,24 @@ pub struct PermanentControllerChangedEvent {
     pub new_controller: PlayerName,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct LoreCountersAddedEvent {
+    pub permanent_id: PermanentId,
+
+    /// Number of lore counters on this permanent before counters were added.
+    pub before: u32,
+
+    /// Number of lore counters on this permanent after counters were added.
+    pub after: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZoneChangedEvent {
+    pub card_id: CardId,
+    pub old: Zone,
+    pub new: Zone,
+}
+
 #[derive(Default, Clone, Debug, Invokable)]
 pub struct CardEvents {
     /// A card is about to enter the battlefield and be assigned a
@@ -39,9 +57,33 @@ pub struct CardEvents {
     /// The card with the given [PermanentId] is about to leave the battlefield.
     pub will_leave_battlefield: GameEvent<PermanentId>,
 
+    /// This card moved from one zone to another.
+    ///
+    /// Listeners for this event and for [GlobalEvents::zone_changed] are
+    /// invoked together in [ListenerPriority] order, see
+    /// `dispatch::zone_change`.
+    ///
+    /// [GlobalEvents::zone_changed]: crate::events::game_events::GlobalEvents::zone_changed
+    /// [ListenerPriority]: crate::events::game_event::ListenerPriority
+    pub zone_changed: GameEvent<ZoneChangedEvent>,
+
     /// Invoked whenever a permanent's controller explicitly changes
     ///
     /// This is *not* invoked when e.g. the permanent changes zones and reverts
     /// to its owner's control.
     pub controller_changed: GameEvent<PermanentControllerChangedEvent>,
+
+    /// The card with the given [PermanentId] was exerted as it attacked.
+    pub exerted: GameEvent<PermanentId>,
+
+    /// One or more lore counters were put on the indicated Saga.
+    pub lore_counters_added: GameEvent<LoreCountersAddedEvent>,
+
+    /// A mutating creature spell merged with the permanent with the given
+    /// [PermanentId].
+    ///
+    /// Because the abilities of each component of a merged permanent are
+    /// added to it, this is also invoked for the abilities of the card which
+    /// mutated onto the permanent.
+    pub mutated: GameEvent<PermanentId>,
 }
@@ -17,6 +17,7 @@ use std::fmt::{Debug, Formatter};
 use enumset::EnumSet;
 use invokable::InvokableType;
 use primitives::game_primitives::{AbilityId, HasController, Source, Zone};
+use utils::outcome::Outcome;
 
 use crate::card_states::zones::ZoneQueries;
 use crate::core::ability_scope::AbilityScope;
@@ -27,9 +28,30 @@ use crate::events::event_context::EventContext;
 use crate::game_states::game_state::GameState;
 use crate::properties::duration::Duration;
 
-#[derive(Clone)]
+/// Band in which an event listener fires relative to other listeners for the
+/// same event.
+///
+/// Listeners always fire in band order, regardless of the order in which they
+/// were added. Within a band, listeners fire in the order they were added.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ListenerPriority {
+    /// Listeners which modify how an event happens, such as a permanent
+    /// entering the battlefield tapped or with counters. These fire first so
+    /// that later listeners observe the modified result.
+    Replacement,
+
+    /// Listeners which check trigger conditions for triggered abilities. This
+    /// is the default band.
+    Trigger,
+
+    /// Listeners which update internal state in response to an event and do not
+    /// affect game rules, e.g. history tracking or display state.
+    Bookkeeping,
+}
+
 pub struct GameEventCallback<TArg> {
     pub ability_id: AbilityId,
+    pub priority: ListenerPriority,
     pub zones: EnumSet<Zone>,
     pub duration: Duration,
     pub rule_type: RuleType,
@@ -37,14 +59,34 @@ pub struct GameEventCallback<TArg> {
     pub function: Box<dyn Mutation<TArg>>,
 }
 
+// Implemented manually since deriving would require `TArg: Clone`.
+impl<TArg> Clone for GameEventCallback<TArg> {
+    fn clone(&self) -> Self {
+        Self {
+            ability_id: self.ability_id,
+            priority: self.priority,
+            zones: self.zones,
+            duration: self.duration,
+            rule_type: self.rule_type,
+            effect_sorting_key: self.effect_sorting_key,
+            function: self.function.clone(),
+        }
+    }
+}
+
 impl<TArg> Debug for GameEventCallback<TArg> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("GameEventCallback").field("zones", &self.zones).finish()
+        f.debug_struct("GameEventCallback")
+            .field("ability_id", &self.ability_id)
+            .field("priority", &self.priority)
+            .field("zones", &self.zones)
+            .finish()
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct GameEvent<TArg> {
+    /// Registered callbacks, in firing order. See [ListenerPriority].
     pub callbacks: Vec<GameEventCallback<TArg>>,
 }
 
@@ -60,7 +102,7 @@ impl<TArg> GameEvent<TArg> {
     pub fn add_battlefield_ability(
         &mut self,
         scope: AbilityScope,
-        function: impl Fn(&mut GameState, EventContext, &TArg) + Copy + Send + Sync + 'static,
+        function: impl Fn(&mut GameState, EventContext, &TArg) -> Outcome + Copy + Send + Sync + 'static,
     ) {
         self.add_ability(scope, Zone::Battlefield, function);
     }
@@ -70,11 +112,31 @@ impl<TArg> GameEvent<TArg> {
         &mut self,
         scope: AbilityScope,
         zones: impl Into<EnumSet<Zone>>,
-        function: impl Fn(&mut GameState, EventContext, &TArg) + Copy + Send + Sync + 'static,
+        function: impl Fn(&mut GameState, EventContext, &TArg) -> Outcome + Copy + Send + Sync + 'static,
     ) {
-        self.callbacks.push(GameEventCallback {
+        self.add_ability_with_priority(scope, zones, ListenerPriority::Trigger, function);
+    }
+
+    /// Adds an event callback for a printed ability of a card which fires in
+    /// the given [ListenerPriority] band.
+    ///
+    /// Panics if the ability does not function in all of the given zones, see
+    /// [AbilityScope::validate_scope].
+    pub fn add_ability_with_priority(
+        &mut self,
+        scope: AbilityScope,
+        zones: impl Into<EnumSet<Zone>>,
+        priority: ListenerPriority,
+        function: impl Fn(&mut GameState, EventContext, &TArg) -> Outcome + Copy + Send + Sync + 'static,
+    ) {
+        let zones = zones.into();
+        if let Err(undeclared) = scope.validate_scope(zones) {
+            panic!("Ability {:?} does not function in zones {undeclared:?}", scope.ability_id);
+        }
+        self.insert(GameEventCallback {
             ability_id: scope.ability_id,
-            zones: zones.into(),
+            priority,
+            zones,
             duration: Duration::Continuous,
             rule_type: RuleType::Ability(scope.ability_id.card_id),
             effect_sorting_key: Some(PRINTED_RULE_SORTING_KEY),
@@ -90,10 +152,23 @@ impl<TArg> GameEvent<TArg> {
         &mut self,
         context: EventContext,
         zones: impl Into<EnumSet<Zone>>,
-        function: impl Fn(&mut GameState, EventContext, &TArg) + Copy + Send + Sync + 'static,
+        function: impl Fn(&mut GameState, EventContext, &TArg) -> Outcome + Copy + Send + Sync + 'static,
     ) {
-        self.callbacks.push(GameEventCallback {
+        self.add_effect_with_priority(context, zones, ListenerPriority::Trigger, function);
+    }
+
+    /// Adds an effect callback which fires in the given [ListenerPriority]
+    /// band.
+    pub fn add_effect_with_priority(
+        &mut self,
+        context: EventContext,
+        zones: impl Into<EnumSet<Zone>>,
+        priority: ListenerPriority,
+        function: impl Fn(&mut GameState, EventContext, &TArg) -> Outcome + Copy + Send + Sync + 'static,
+    ) {
+        self.insert(GameEventCallback {
             ability_id: context.this,
+            priority,
             zones: zones.into(),
             duration: Duration::Continuous,
             rule_type: RuleType::Effect,
@@ -106,4 +181,11 @@ impl<TArg> GameEvent<TArg> {
     pub fn remove_callbacks(&mut self, ability_id: AbilityId) {
         self.callbacks.retain(|callback| callback.ability_id != ability_id);
     }
+
+    /// Inserts a callback after all existing callbacks in the same or an
+    /// earlier [ListenerPriority] band, keeping `callbacks` in firing order.
+    fn insert(&mut self, callback: GameEventCallback<TArg>) {
+        let index = self.callbacks.partition_point(|c| c.priority <= callback.priority);
+        self.callbacks.insert(index, callback);
+    }
 }
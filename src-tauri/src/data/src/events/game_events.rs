@@ -12,14 +12,97 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::Source;
+use primitives::game_primitives::{CardId, PermanentId, PlayerName, Source};
 
+use crate::core::numerics::LifeValue;
+use crate::events::card_events::ZoneChangedEvent;
 use crate::events::event_context::EventContext;
 use crate::events::game_event::GameEvent;
+use crate::game_states::game_phase_step::GamePhaseStep;
 use crate::game_states::game_state::GameState;
+use crate::game_states::random_result::CoinFace;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LifeGainedEvent {
+    pub player: PlayerName,
+    pub amount: LifeValue,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LifeLostEvent {
+    pub player: PlayerName,
+    pub amount: LifeValue,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CoinFlippedEvent {
+    pub player: PlayerName,
+    pub result: CoinFace,
+
+    /// Whether the player won the flip, or None if this flip did not care
+    /// about winning or losing.
+    pub won: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DieRolledEvent {
+    pub player: PlayerName,
+    pub sides: u32,
+    pub result: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StepBeganEvent {
+    /// Player whose turn it is.
+    pub active_player: PlayerName,
+    pub step: GamePhaseStep,
+}
 
 #[derive(Default, Clone, Debug)]
 pub struct GlobalEvents {
     /// Invoked every time game state-triggered abilities are checked.
     pub state_triggered_ability: GameEvent<()>,
+
+    /// The upkeep step of the indicated active player's turn began.
+    pub upkeep: GameEvent<PlayerName>,
+
+    /// A step of the indicated active player's turn began, for "at the
+    /// beginning of" triggered abilities.
+    ///
+    /// This is fired for every step, including the untap and cleanup steps,
+    /// after delayed triggers for the step have triggered. Steps which are
+    /// skipped do not begin.
+    pub step_began: GameEvent<StepBeganEvent>,
+
+    /// A permanent entered the battlefield with the given [PermanentId].
+    pub permanent_entered_battlefield: GameEvent<PermanentId>,
+
+    /// Any card moved from one zone to another.
+    ///
+    /// Fired together with the moved card's own [CardEvents::zone_changed]
+    /// listeners.
+    ///
+    /// [CardEvents::zone_changed]: crate::events::card_events::CardEvents::zone_changed
+    pub zone_changed: GameEvent<ZoneChangedEvent>,
+
+    /// A player gained life.
+    pub life_gained: GameEvent<LifeGainedEvent>,
+
+    /// A player lost life, including via damage.
+    pub life_lost: GameEvent<LifeLostEvent>,
+
+    /// A player flipped a coin.
+    pub coin_flipped: GameEvent<CoinFlippedEvent>,
+
+    /// A player rolled a die.
+    pub die_rolled: GameEvent<DieRolledEvent>,
+
+    /// The indicated player rolled {CHAOS} on the planar die.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R311>
+    pub chaos_ensues: GameEvent<PlayerName>,
+
+    /// A plane or phenomenon card with the given [CardId] became the face-up
+    /// card of the planar deck.
+    pub planeswalked_to: GameEvent<CardId>,
 }
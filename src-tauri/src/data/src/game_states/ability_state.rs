@@ -14,9 +14,12 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
-use primitives::game_primitives::{CardId, EventId, PermanentId, Timestamp};
+use primitives::game_primitives::{AbilityId, CardId, EventId, PermanentId, PlayerName, Timestamp};
 
+use crate::core::function_types::Effect;
+use crate::core::numerics::Damage;
 use crate::game_states::effect_state::EffectState;
+use crate::game_states::game_phase_step::GamePhaseStep;
 use crate::game_states::state_value::StateValue;
 use crate::properties::duration::Duration;
 
@@ -34,20 +37,177 @@ pub struct AbilityState {
     /// not trigger again.
     pub fired_one_time_effects: BTreeSet<EventId>,
 
-    /// List of control-changing effects to automatically clean up at end of
-    /// turn.
-    pub change_control_this_turn: Option<Vec<(EventId, CardId)>>,
+    /// Effects which must be reverted once their [Duration] ends, e.g. "until
+    /// end of turn" effects which are cleaned up during the cleanup step.
+    pub expiring_effects: Vec<ExpiringEffect>,
+
+    /// Delayed triggered abilities which are waiting for their trigger
+    /// condition to occur.
+    pub delayed_triggers: Vec<DelayedTriggerState>,
+
+    /// Prevention and redirection effects which modify damage that would be
+    /// dealt, in the order in which they were created.
+    pub damage_shields: Vec<DamageShield>,
 }
 
 impl AbilityState {
-    /// Returns & removes the list of control-changing effects to automatically
-    /// clean up at end of turn
-    pub fn remove_control_changing_effects(&mut self) -> Vec<(EventId, CardId)> {
-        self.change_control_this_turn.take().unwrap_or_default()
+    /// Returns & removes the list of effects which must be reverted once their
+    /// duration ends.
+    pub fn remove_expiring_effects(&mut self) -> Vec<ExpiringEffect> {
+        std::mem::take(&mut self.expiring_effects)
+    }
+
+    /// Registers an effect to automatically revert once its duration ends.
+    pub fn add_expiring_effect(&mut self, effect: ExpiringEffect) {
+        self.expiring_effects.push(effect);
+    }
+
+    /// Registers a delayed triggered ability to fire the next time its
+    /// condition occurs.
+    pub fn add_delayed_trigger(&mut self, trigger: DelayedTriggerState) {
+        self.delayed_triggers.push(trigger);
     }
 
-    /// Adds a control-changing effect to automatically clean up at end of turn.
-    pub fn add_control_changing_effect(&mut self, event_id: EventId, card_id: CardId) {
-        self.change_control_this_turn.get_or_insert_with(Vec::new).push((event_id, card_id));
+    /// Registers an effect to prevent or redirect damage until it is used up
+    /// or its duration ends.
+    pub fn add_damage_shield(&mut self, shield: DamageShield) {
+        self.damage_shields.push(shield);
     }
 }
+
+/// Possible kinds of effect which are reverted when their duration ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiringEffectKind {
+    /// Effect changed the controller of a card.
+    ChangeControl,
+
+    /// Effect modified the properties of a card, e.g. by changing its power
+    /// and toughness, granting it an ability, or changing its types.
+    Modifiers,
+}
+
+/// An effect applied to a card which must be reverted when its [Duration]
+/// ends.
+#[derive(Clone, Copy)]
+pub struct ExpiringEffect {
+    /// Event which created this effect.
+    pub event_id: EventId,
+
+    /// Card this effect applies to.
+    pub card_id: CardId,
+
+    /// Duration of the effect.
+    pub duration: Duration,
+
+    /// Kind of effect to revert.
+    pub kind: ExpiringEffectKind,
+}
+
+/// Describes the event which causes a delayed triggered ability to trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelayedTriggerCondition {
+    /// Triggers at the beginning of the next [GamePhaseStep] step, e.g. "at the
+    /// beginning of the next end step".
+    NextStep(GamePhaseStep),
+
+    /// Triggers at the beginning of the next [GamePhaseStep] step during the
+    /// indicated player's turn, e.g. "at the beginning of your next upkeep".
+    PlayersNextStep(PlayerName, GamePhaseStep),
+}
+
+/// A delayed triggered ability created by a resolving spell or ability.
+///
+/// > 603.7. An effect may create a delayed triggered ability that can do
+/// > something at a later time.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6037>
+#[derive(Clone)]
+pub struct DelayedTriggerState {
+    /// Event which created this trigger.
+    pub event_id: EventId,
+
+    /// Ability which created this trigger.
+    pub ability_id: AbilityId,
+
+    /// Player who controls the triggered ability.
+    ///
+    /// > 603.7d. If a spell creates a delayed triggered ability, the source of
+    /// > that delayed triggered ability is that spell. The controller of that
+    /// > delayed triggered ability is the player who controlled that spell as
+    /// > it resolved.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R6037d>
+    pub controller: PlayerName,
+
+    /// Event which causes this ability to trigger.
+    pub condition: DelayedTriggerCondition,
+
+    /// Duration during which this trigger can still fire. Once the duration
+    /// ends the trigger is removed without firing.
+    pub duration: Duration,
+
+    /// Effect to apply when the triggered ability resolves.
+    pub effect: Box<dyn Effect>,
+}
+
+/// A player or permanent which can be dealt damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageRecipient {
+    Player(PlayerName),
+    Permanent(PermanentId),
+}
+
+/// Describes how a [DamageShield] modifies damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageShieldKind {
+    /// Prevents up to the given amount of damage, e.g. "prevent the next 3
+    /// damage that would be dealt to any target this turn". The amount is
+    /// reduced as damage is prevented, and the shield is used up once it
+    /// reaches 0.
+    Prevent(Damage),
+
+    /// Prevents all damage for the duration of the shield, e.g. "prevent all
+    /// damage that would be dealt to you this turn".
+    PreventAll,
+
+    /// Deals the next damage to the indicated recipient instead, after which
+    /// the shield is used up.
+    RedirectNext(DamageRecipient),
+
+    /// Deals all damage to the indicated recipient instead for the duration of
+    /// the shield.
+    RedirectAll(DamageRecipient),
+}
+
+/// A prevention or redirection effect which applies to damage that would be
+/// dealt to a [DamageRecipient].
+///
+/// > 615.1. Some continuous effects are prevention effects. Like replacement
+/// > effects (see rule 614), prevention effects apply continuously as events
+/// > happen—they aren't locked in ahead of time.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6151>
+///
+/// Redirection effects are replacement effects which change the recipient of
+/// damage.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R614>
+#[derive(Clone, Copy)]
+pub struct DamageShield {
+    /// Event which created this shield.
+    pub event_id: EventId,
+
+    /// Player or permanent whose damage is modified.
+    pub recipient: DamageRecipient,
+
+    /// If provided, only damage dealt by this card is modified, e.g. "the next
+    /// time a source of your choice would deal damage to you this turn".
+    pub damage_source: Option<CardId>,
+
+    /// How damage is modified.
+    pub kind: DamageShieldKind,
+
+    /// Duration during which this shield applies. Once the duration ends the
+    /// shield is removed.
+    pub duration: Duration,
+}
@@ -0,0 +1,120 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::game_primitives::PlayerName;
+use serde::{Deserialize, Serialize};
+
+use crate::card_states::card_state::CardFacing;
+use crate::core::numerics::{Damage, LifeValue, TurnNumber};
+use crate::decks::deck_name::DeckName;
+use crate::game_states::game_phase_step::GamePhaseStep;
+use crate::printed_cards::printed_card_id::PrintedCardId;
+
+/// Portable, human-readable description of the position of a game, used to
+/// attach a loadable board state to bug reports.
+///
+/// Unlike a serialized game, which records the actions taken from the start
+/// of the game, a position records only the current contents of each zone.
+/// Continuous effects, abilities on the stack, and pending choices are not
+/// included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardPosition {
+    pub step: GamePhaseStep,
+
+    pub active_player: PlayerName,
+
+    pub turn_number: TurnNumber,
+
+    pub players: Vec<PlayerPosition>,
+
+    /// Spells on the stack, from bottom to top.
+    #[serde(default)]
+    pub stack: Vec<SpellPosition>,
+}
+
+/// State of a single player within a [BoardPosition].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerPosition {
+    pub player: PlayerName,
+
+    pub deck_name: DeckName,
+
+    pub life: LifeValue,
+
+    /// Cards in this player's library, from top to bottom.
+    #[serde(default)]
+    pub library: Vec<CardPosition>,
+
+    #[serde(default)]
+    pub hand: Vec<CardPosition>,
+
+    #[serde(default)]
+    pub battlefield: Vec<CardPosition>,
+
+    /// Cards in this player's graveyard, from bottom to top.
+    #[serde(default)]
+    pub graveyard: Vec<CardPosition>,
+
+    #[serde(default)]
+    pub exile: Vec<CardPosition>,
+
+    #[serde(default)]
+    pub command_zone: Vec<CardPosition>,
+}
+
+/// State of a single card within a [BoardPosition].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardPosition {
+    /// Name of this card.
+    pub name: String,
+
+    /// Printing of this card to create.
+    ///
+    /// If None, a printing is looked up by name when the position is
+    /// validated, which makes it easier to write positions by hand.
+    #[serde(default)]
+    pub printed_card_id: Option<PrintedCardId>,
+
+    pub facing: CardFacing,
+
+    #[serde(default)]
+    pub tapped: bool,
+
+    #[serde(default)]
+    pub plus_one_counters: u32,
+
+    #[serde(default)]
+    pub minus_one_counters: u32,
+
+    #[serde(default)]
+    pub damage: Damage,
+
+    /// True if this card entered its current zone or changed control during
+    /// the current turn, e.g. a creature with summoning sickness.
+    #[serde(default)]
+    pub entered_this_turn: bool,
+}
+
+/// A spell on the stack within a [BoardPosition].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellPosition {
+    pub owner: PlayerName,
+
+    #[serde(flatten)]
+    pub card: CardPosition,
+}
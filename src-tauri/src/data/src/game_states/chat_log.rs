@@ -0,0 +1,93 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::game_primitives::PlayerName;
+use serde::{Deserialize, Serialize};
+
+use crate::core::numerics::TurnNumber;
+
+/// Maximum number of characters allowed in a single chat message.
+pub const MAX_MESSAGE_LENGTH: usize = 280;
+
+/// Record of chat messages sent between players during a game.
+///
+/// This is stored in the database alongside the game it belongs to, but is not
+/// part of the [crate::game_states::game_state::GameState] since chat has no
+/// effect on game rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChatLog {
+    pub messages: Vec<ChatMessage>,
+}
+
+impl ChatLog {
+    /// Adds a new message to this log.
+    pub fn add(&mut self, message: ChatMessage) {
+        self.messages.push(message);
+    }
+}
+
+/// A single message sent by a player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    /// Player who sent this message.
+    pub sender: PlayerName,
+
+    /// Turn number during which this message was sent.
+    pub turn_number: TurnNumber,
+
+    /// Contents of the message.
+    pub content: ChatContent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ChatContent {
+    /// Free-form text written by a human player.
+    Text(String),
+
+    /// A predefined quick-message.
+    Emote(Emote),
+}
+
+impl ChatContent {
+    pub fn text(&self) -> &str {
+        match self {
+            ChatContent::Text(text) => text,
+            ChatContent::Emote(emote) => emote.text(),
+        }
+    }
+}
+
+/// Predefined quick-messages, used by AI opponents to respond to chat.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Emote {
+    Hello,
+    GoodLuck,
+    GoodGame,
+    WellPlayed,
+    Thinking,
+    Oops,
+}
+
+impl Emote {
+    pub fn text(&self) -> &'static str {
+        match self {
+            Emote::Hello => "Hello!",
+            Emote::GoodLuck => "Good luck, have fun!",
+            Emote::GoodGame => "Good game!",
+            Emote::WellPlayed => "Well played.",
+            Emote::Thinking => "Hmm...",
+            Emote::Oops => "Oops.",
+        }
+    }
+}
@@ -17,6 +17,8 @@ use std::collections::{BTreeMap, BTreeSet};
 use enum_kinds::EnumKind;
 use primitives::game_primitives::{PermanentId, PlayerName};
 use serde::{Deserialize, Serialize};
+
+use crate::printed_cards::mana_cost::ManaCost;
 /// Entity which has been declared as an attacker
 pub type AttackerId = PermanentId;
 
@@ -41,6 +43,19 @@ impl AttackTarget {
     }
 }
 
+/// Special actions a card can take from its owner's hand during combat.
+#[derive(Debug, Clone)]
+pub enum CombatSpecialAction {
+    /// > 702.49a. Ninjutsu is an activated ability that functions only while
+    /// > the card with ninjutsu is in a player's hand. "Ninjutsu [cost]" means
+    /// > "[Cost], Reveal this card from your hand, Return an unblocked
+    /// > attacking creature you control to its owner's hand: Put this card
+    /// > onto the battlefield from your hand tapped and attacking."
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R70249a>
+    Ninjutsu(ManaCost),
+}
+
 /// Tracks the state of creatures participating in a combat phase
 #[derive(Debug, Clone, EnumKind)]
 #[enum_kind(CombatStateKind)]
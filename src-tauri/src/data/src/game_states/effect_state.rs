@@ -34,6 +34,7 @@ impl<T: Into<StateValue> + TryFrom<StateValue> + PartialEq> EffectState<T> {
     /// Sets the value of the state associated with the provided [EventId] to
     /// the given value.
     pub fn store(&self, game: &mut GameState, event_id: EventId, value: T) {
+        game.invalidate_query_caches();
         game.ability_state.effect_state.insert(event_id, value.into());
     }
 
@@ -50,6 +51,7 @@ impl<T: Into<StateValue> + TryFrom<StateValue> + PartialEq> EffectState<T> {
     /// Retrieves and removes the state value associated with the provided
     /// [EventId], if one is present.
     pub fn pop(&self, game: &mut GameState, event_id: EventId) -> Option<T> {
+        game.invalidate_query_caches();
         let state = game.ability_state.effect_state.remove(&event_id)?;
         T::try_from(state).ok()
     }
@@ -14,8 +14,11 @@
 
 use enum_iterator::Sequence;
 use enumset::EnumSetType;
+use serde::{Deserialize, Serialize};
+use specta::Type;
 
-#[derive(Debug, Ord, PartialOrd, Hash, EnumSetType, Sequence)]
+#[derive(Debug, Ord, PartialOrd, Hash, EnumSetType, Sequence, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
 pub enum GamePhaseStep {
     Untap,
     Upkeep,
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::{BTreeSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use ai_core::core::agent_state::AgentState;
 use enumset::EnumSet;
@@ -27,22 +29,26 @@ use utils::outcome;
 use utils::outcome::Outcome;
 
 use crate::actions::agent_action::AgentAction;
+use crate::campaign::campaign_stage::CampaignStageId;
 use crate::card_states::card_state::{CardState, LostAllAbilities};
 use crate::card_states::stack_ability_state::StackAbilityState;
 use crate::card_states::zones::{HasZones, ToCardId, ZoneQueries, Zones};
-use crate::core::numerics::TurnNumber;
+use crate::core::numerics::{LifeValue, TurnNumber};
 use crate::events::game_events::GlobalEvents;
 use crate::game_states::ability_state::AbilityState;
+use crate::game_states::board_position::BoardPosition;
 use crate::game_states::combat_state::CombatState;
 use crate::game_states::game_phase_step::GamePhaseStep;
 use crate::game_states::history_data::{GameHistory, HistoryCounters, HistoryEvent};
 use crate::game_states::oracle::Oracle;
+use crate::game_states::planechase_state::PlanechaseState;
 use crate::game_states::state_based_event::StateBasedEvent;
+use crate::game_states::turn_schedule::TurnSchedule;
 use crate::player_states::player_map::PlayerMap;
 use crate::player_states::player_state::{PlayerQueries, PlayerState, Players};
-use crate::prompts::game_update::UpdateChannel;
+use crate::prompts::game_update::{AnimationTimeline, GameAnimation, UpdateChannel};
 use crate::prompts::prompt::PromptResponse;
-use crate::properties::duration::Duration;
+use crate::properties::query_cache::EffectsGeneration;
 
 /// The high-level activity which this [GameState] is being used for.
 #[derive(Debug, Clone)]
@@ -111,6 +117,10 @@ pub struct GameState {
     /// State associated with abilities in this game.
     pub ability_state: AbilityState,
 
+    /// Extra turns, additional phases, and skipped steps created by effects.
+    /// See [TurnSchedule].
+    pub turn_schedule: TurnSchedule,
+
     /// Channel on which to send game updates.
     ///
     /// If no channel is provided here, game mutations will be applied silently
@@ -121,6 +131,16 @@ pub struct GameState {
     /// if any.
     pub combat: Option<CombatState>,
 
+    /// State of the shared planar deck, if this game is using the Planechase
+    /// variant.
+    pub planechase: Option<PlanechaseState>,
+
+    /// Position this game was started from, if it was created from a
+    /// [BoardPosition] instead of from each player's deck.
+    ///
+    /// Recorded so that the game can be rebuilt when it is deserialized.
+    pub starting_position: Option<Arc<BoardPosition>>,
+
     ///  History of events which have happened during this game. See
     /// [GameHistory].
     pub history: GameHistory,
@@ -157,6 +177,16 @@ pub struct GameState {
 
     /// True if game initialization has been run on this game.
     pub initialized: bool,
+
+    /// Current version of the game state for the purpose of caching property
+    /// queries. See [Self::invalidate_query_caches].
+    pub effects_generation: EffectsGeneration,
+
+    /// Animations recorded since the last update was sent to the client.
+    ///
+    /// Only populated while an [UpdateChannel] is attached, see
+    /// [Self::add_animation].
+    pub animations: AnimationTimeline,
 }
 
 impl GameState {
@@ -193,8 +223,40 @@ impl GameState {
         self.turn.active_player
     }
 
+    /// Discards all cached property query results, e.g. the power and
+    /// toughness values stored in each card's
+    /// [QueryCache](crate::properties::query_cache::QueryCache).
+    ///
+    /// This is invoked automatically whenever a card, player, or stack ability
+    /// is mutably accessed. Code which mutates other game state that property
+    /// queries depend on, such as the current step or combat state, must call
+    /// this explicitly.
+    pub fn invalidate_query_caches(&mut self) {
+        self.effects_generation += 1;
+    }
+
+    /// Records a [GameAnimation] to display to the client.
+    ///
+    /// Animations are discarded if no client is observing this game, e.g.
+    /// during AI search.
+    pub fn add_animation(&mut self, animation: GameAnimation) {
+        if self.updates.is_some() {
+            self.animations.add(animation);
+        }
+    }
+
+    /// Invokes `function`, displaying all animations it records at the same
+    /// time instead of in sequence.
+    pub fn simultaneous_animations<T>(&mut self, function: impl FnOnce(&mut Self) -> T) -> T {
+        self.animations.begin_simultaneous();
+        let result = function(self);
+        self.animations.end_simultaneous();
+        result
+    }
+
     /// Adds a current [HistoryEvent] for the current turn.
     pub fn add_history_event(&mut self, event: HistoryEvent) {
+        self.invalidate_query_caches();
         self.history.add_event(self.turn, event)
     }
 
@@ -211,6 +273,7 @@ impl GameState {
 
     /// Adds a new tracked [StateBasedEvent].
     pub fn add_state_based_event(&mut self, event: StateBasedEvent) {
+        self.invalidate_query_caches();
         if let Some(events) = &mut self.state_based_events {
             events.push(event);
         } else {
@@ -243,6 +306,7 @@ impl ZoneQueries for GameState {
     }
 
     fn card_mut(&mut self, id: impl ToCardId) -> Option<&mut CardState> {
+        self.invalidate_query_caches();
         self.zones.card_mut(id)
     }
 
@@ -250,7 +314,12 @@ impl ZoneQueries for GameState {
         self.zones.stack_ability(id)
     }
 
+    fn get_stack_ability(&self, id: StackAbilityId) -> Option<&StackAbilityState> {
+        self.zones.get_stack_ability(id)
+    }
+
     fn stack_ability_mut(&mut self, id: StackAbilityId) -> &mut StackAbilityState {
+        self.invalidate_query_caches();
         self.zones.stack_ability_mut(id)
     }
 
@@ -297,6 +366,7 @@ impl PlayerQueries for GameState {
     }
 
     fn player_mut(&mut self, name: PlayerName) -> &mut PlayerState {
+        self.invalidate_query_caches();
         self.players.player_mut(name)
     }
 }
@@ -330,15 +400,18 @@ pub enum GameStatus {
 }
 
 /// Identifies a turn within the game.
+///
+/// Turns are ordered chronologically.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct TurnData {
-    /// Player whose turn it is or was.
-    pub active_player: PlayerName,
-
-    /// Turn number for that player.
+    /// Number of turns taken in the game before this turn, including extra
+    /// turns.
     ///
     /// The first turn of the game is turn 0.
     pub turn_number: TurnNumber,
+
+    /// Player whose turn it is or was.
+    pub active_player: PlayerName,
 }
 
 /// Options controlling overall gameplay
@@ -362,6 +435,29 @@ pub struct GameConfiguration {
     /// future expansion.
     pub all_players: EnumSet<PlayerName>,
 
+    /// Teams players are grouped into for team-based variants such as
+    /// Two-Headed Giant, if any.
+    pub teams: Option<TeamConfiguration>,
+
+    /// Stage of the single-player campaign this game is being played for, if
+    /// any.
+    pub campaign_stage: Option<CampaignStageId>,
+
+    /// Time at which this game was created, if known.
+    pub started_at: Option<SystemTime>,
+
+    /// Maximum time to wait for a response to a prompt before a default
+    /// choice is selected on the responding player's behalf, or None to wait
+    /// indefinitely.
+    ///
+    /// AI agents limit their search to this duration. Human players are only
+    /// subject to the timeout once their client has disconnected, after which
+    /// they decline any optional effect and otherwise make a random choice.
+    pub prompt_timeout: Option<Duration>,
+
+    /// House rules for this game
+    pub rules: RulesConfiguration,
+
     /// Debug options for this game
     pub debug: DebugConfiguration,
 }
@@ -373,11 +469,98 @@ impl GameConfiguration {
             simulation: false,
             scripted_tutorial: false,
             all_players,
+            teams: None,
+            campaign_stage: None,
+            started_at: None,
+            prompt_timeout: Some(Duration::from_secs(300)),
+            rules: RulesConfiguration::default(),
             debug,
         }
     }
 }
 
+/// Groups players into teams.
+///
+/// Teammates share a single life total, attack together during a combined
+/// declare attackers step, and win or lose the game together.
+///
+/// > 810.1. Two-Headed Giant is a multiplayer variant played by two two-player
+/// > teams, each with a shared life total and a shared turn.
+/// <https://yawgatog.com/resources/magic-rules/#R8101>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TeamConfiguration {
+    /// Players on each team. Players who do not appear in any team play
+    /// alone.
+    pub teams: [EnumSet<PlayerName>; 2],
+}
+
+impl TeamConfiguration {
+    /// Teams for a Two-Headed Giant game, in which players one and three play
+    /// against players two and four.
+    ///
+    /// Turns alternate between teams, so player one's team takes the first
+    /// turn and player two's team takes the second.
+    pub fn two_headed_giant() -> Self {
+        Self { teams: [PlayerName::One | PlayerName::Three, PlayerName::Two | PlayerName::Four] }
+    }
+
+    /// Returns the team containing the indicated player, if any.
+    pub fn team(&self, player: PlayerName) -> Option<EnumSet<PlayerName>> {
+        self.teams.iter().find(|team| team.contains(player)).copied()
+    }
+}
+
+/// Optional changes to the standard rules of the game, selected when the game
+/// is created.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RulesConfiguration {
+    /// Life total each player starts the game with.
+    ///
+    /// > 103.4. Each player begins the game with a starting life total of 20.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R1034>
+    pub starting_life: LifeValue,
+
+    /// Number of mulligans each player may take without putting a card on the
+    /// bottom of their library.
+    pub free_mulligans: usize,
+
+    /// If true, decks are not checked against the banned and restricted lists
+    /// of their format.
+    pub ignore_banned_list: bool,
+
+    /// If true, players may undo their actions in any game. Otherwise undo is
+    /// only available when every opponent is an AI agent.
+    pub always_allow_takebacks: bool,
+
+    /// If true, the game is played with the Planechase casual variant using a
+    /// shared planar deck.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R901>
+    #[serde(default)]
+    pub planechase: bool,
+}
+
+impl Default for RulesConfiguration {
+    fn default() -> Self {
+        Self {
+            starting_life: 20,
+            free_mulligans: 0,
+            ignore_banned_list: false,
+            always_allow_takebacks: false,
+            planechase: false,
+        }
+    }
+}
+
+impl RulesConfiguration {
+    /// Returns the number of cards a player who has taken `mulligans_taken`
+    /// mulligans puts on the bottom of their library when keeping a hand.
+    pub fn mulligan_cards_to_bottom(&self, mulligans_taken: usize) -> usize {
+        mulligans_taken.saturating_sub(self.free_mulligans)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DebugConfiguration {
     /// If true, all cards are revealed to all players
@@ -386,6 +569,16 @@ pub struct DebugConfiguration {
     /// Allows the user in a game to take actions as though they were another
     /// specified player.
     pub act_as_player: Option<DebugActAsPlayer>,
+
+    /// If true, the consistency of zone indices is checked after every game
+    /// action in debug builds.
+    #[serde(default)]
+    pub validate_zones: bool,
+
+    /// If true, resolving spells and abilities pause before each step of their
+    /// resolution to describe what they are doing.
+    #[serde(default)]
+    pub step_through_resolution: bool,
 }
 
 /// Allows a player to take actions for another player during debugging
@@ -12,16 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 
 use enum_kinds::EnumKind;
-use primitives::game_primitives::PlayerName;
+use enumset::EnumSet;
+use primitives::game_primitives::{AbilityId, CardId, CardType, PermanentId, PlayerName, Zone};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 use crate::actions::game_action::GameAction;
 use crate::actions::prompt_action::PromptAction;
+use crate::core::numerics::{Damage, LifeValue};
+use crate::game_states::combat_state::{AttackTarget, AttackerId};
 use crate::game_states::game_state::TurnData;
+use crate::game_states::random_result::RandomResult;
 use crate::player_states::player_map::PlayerMap;
 use crate::prompts::prompt::PromptResponse;
 
@@ -29,7 +33,32 @@ use crate::prompts::prompt::PromptResponse;
 #[derive(Debug, Clone, EnumKind)]
 #[enum_kind(HistoryEventKind)]
 pub enum HistoryEvent {
-    AttackWithCreature,
+    /// The [PlayerName] player cast the [CardId] spell.
+    CastSpell(PlayerName, CardId),
+
+    /// A creature was declared as an attacker.
+    AttackWithCreature(AttackerId, AttackTarget),
+
+    /// A player was dealt damage.
+    DamageToPlayer(PlayerName, Damage),
+
+    /// A permanent was dealt damage.
+    DamageToPermanent(PermanentId, Damage),
+
+    /// A player gained life.
+    LifeGained(PlayerName, LifeValue),
+
+    /// A player lost life other than by being dealt damage.
+    LifeLost(PlayerName, LifeValue),
+
+    /// A card moved from one zone to another.
+    ZoneChange(CardId, Zone, Zone),
+
+    /// A triggered ability was put on the stack.
+    AbilityTriggered(AbilityId),
+
+    /// A coin was flipped, a die was rolled, or players clashed.
+    RandomResult(RandomResult),
 }
 
 impl HistoryEvent {
@@ -46,7 +75,17 @@ struct HistoryEntry {
     event: HistoryEvent,
 }
 
-static DEFAULT_COUNTERS: HistoryCounters = HistoryCounters { cards_drawn: 0, lands_played: 0 };
+/// Maximum number of entries retained in the [GameHistory::log].
+const LOG_CAPACITY: usize = 100;
+
+static DEFAULT_COUNTERS: HistoryCounters = HistoryCounters {
+    cards_drawn: 0,
+    lands_played: 0,
+    spells_cast: Vec::new(),
+    planar_die_rolls: 0,
+    life_gained: 0,
+    life_lost: 0,
+};
 
 /// Counters for events that happen during a given turn. Each player has their
 /// own set of counters for game events.
@@ -63,6 +102,31 @@ pub struct HistoryCounters {
     pub cards_drawn: usize,
     /// Lands played so far this turn by this player.
     pub lands_played: usize,
+    /// Card types of each spell cast so far this turn by this player, in the
+    /// order in which they were cast. Copies of spells are not included, since
+    /// they are not cast.
+    pub spells_cast: Vec<EnumSet<CardType>>,
+    /// Number of times this player has rolled the planar die this turn by
+    /// paying its activation cost.
+    pub planar_die_rolls: usize,
+    /// Total amount of life gained so far this turn by this player.
+    pub life_gained: LifeValue,
+    /// Total amount of life lost so far this turn by this player, including
+    /// life lost due to damage.
+    pub life_lost: LifeValue,
+}
+
+impl HistoryCounters {
+    /// Returns the number of spells cast so far this turn by this player.
+    pub fn spells_cast_count(&self) -> usize {
+        self.spells_cast.len()
+    }
+
+    /// Returns the number of spells with the [CardType] card type cast so far
+    /// this turn by this player.
+    pub fn spells_cast_of_type(&self, card_type: CardType) -> usize {
+        self.spells_cast.iter().filter(|types| types.contains(card_type)).count()
+    }
 }
 
 /// A game action taken by a player.
@@ -88,8 +152,8 @@ pub struct TakenGameAction {
 pub struct GameHistory {
     current: Vec<HistoryEntry>,
     entries: BTreeMap<TurnData, Vec<HistoryEvent>>,
-    p1_counters: BTreeMap<TurnData, HistoryCounters>,
-    p2_counters: BTreeMap<TurnData, HistoryCounters>,
+    counters: PlayerMap<BTreeMap<TurnData, HistoryCounters>>,
+    log: VecDeque<HistoryEntry>,
 
     /// Stores actions taken thus far in the game.
     pub player_actions: PlayerMap<Vec<TakenGameAction>>,
@@ -105,14 +169,24 @@ impl GameHistory {
         self.entries.get(&turn).into_iter().flatten()
     }
 
+    /// Returns events which have been added via [Self::add_event] but not yet
+    /// written to the game history, oldest first.
+    pub fn current_events(&self) -> impl Iterator<Item = &HistoryEvent> {
+        self.current.iter().map(|entry| &entry.event)
+    }
+
+    /// Returns the most recent history events in this game, oldest first.
+    ///
+    /// This is a bounded buffer intended for display to the user, older
+    /// entries are discarded as new events are written.
+    pub fn log(&self) -> impl Iterator<Item = (TurnData, &HistoryEvent)> {
+        self.log.iter().map(|entry| (entry.turn, &entry.event))
+    }
+
     /// Returns a reference to the [HistoryCounters] entry for the provided
     /// turn.
     pub fn counters_for_turn(&self, turn: TurnData, player: PlayerName) -> &HistoryCounters {
-        match player {
-            PlayerName::One => self.p1_counters.get(&turn).unwrap_or(&DEFAULT_COUNTERS),
-            PlayerName::Two => self.p2_counters.get(&turn).unwrap_or(&DEFAULT_COUNTERS),
-            _ => todo!("Not implemented"),
-        }
+        self.counters.get(player).get(&turn).unwrap_or(&DEFAULT_COUNTERS)
     }
 
     /// Returns a mutable reference to the [HistoryCounters] entry for the
@@ -122,11 +196,7 @@ impl GameHistory {
         turn: TurnData,
         player: PlayerName,
     ) -> &mut HistoryCounters {
-        match player {
-            PlayerName::One => self.p1_counters.entry(turn).or_default(),
-            PlayerName::Two => self.p2_counters.entry(turn).or_default(),
-            _ => todo!("Not implemented"),
-        }
+        self.counters.get_mut(player).entry(turn).or_default()
     }
 
     /// Adds a new history entry to the 'current events' buffer. Events do
@@ -140,10 +210,12 @@ impl GameHistory {
     /// Writes all stored history events to the game history and clears the
     /// 'current events' buffer.
     pub fn write_events(&mut self) {
-        for entry in &self.current {
+        for entry in self.current.drain(..) {
             self.entries.entry(entry.turn).or_default().push(entry.event.clone());
+            if self.log.len() >= LOG_CAPACITY {
+                self.log.pop_front();
+            }
+            self.log.push_back(entry);
         }
-
-        self.current.clear();
     }
 }
@@ -13,12 +13,18 @@
 // limitations under the License.
 
 pub mod ability_state;
+pub mod board_position;
+pub mod chat_log;
 pub mod combat_state;
 pub mod effect_state;
 pub mod game_phase_step;
 pub mod game_state;
 pub mod history_data;
 pub mod oracle;
+pub mod planechase_state;
+pub mod random_result;
+pub mod resolution_step;
 pub mod serialized_game_state;
 pub mod state_based_event;
 pub mod state_value;
+pub mod turn_schedule;
@@ -0,0 +1,37 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use primitives::game_primitives::CardId;
+
+#[allow(unused_imports)] // Used in docs
+use crate::card_states::zones::Zones;
+
+/// State of the shared planar deck in a Planechase game.
+///
+/// All players share a single planar deck of plane and phenomenon cards, which
+/// stays in the command zone for the entire game.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R901>
+#[derive(Debug, Clone, Default)]
+pub struct PlanechaseState {
+    /// Face-down cards in the planar deck. The back of this queue is the top of
+    /// the deck, matching the library order in [Zones].
+    pub planar_deck: VecDeque<CardId>,
+
+    /// The face-up plane or phenomenon card, if any. This card is controlled
+    /// by the active player.
+    pub active_plane: Option<CardId>,
+}
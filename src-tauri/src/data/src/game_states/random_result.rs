@@ -0,0 +1,125 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::game_primitives::{CardId, PlayerName};
+use serde::{Deserialize, Serialize};
+
+use crate::core::numerics::ManaValue;
+use crate::text_strings::Text;
+
+/// One side of a coin.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum CoinFace {
+    Heads,
+    Tails,
+}
+
+impl From<CoinFace> for Text {
+    fn from(value: CoinFace) -> Self {
+        match value {
+            CoinFace::Heads => Text::Heads,
+            CoinFace::Tails => Text::Tails,
+        }
+    }
+}
+
+/// One face of the planar die.
+///
+/// The planar die is a six-sided die with a planeswalker symbol on one face, a
+/// chaos symbol on one other face, and four blank faces.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R901>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum PlanarDieFace {
+    Blank,
+    Chaos,
+    Planeswalk,
+}
+
+impl From<PlanarDieFace> for Text {
+    fn from(value: PlanarDieFace) -> Self {
+        match value {
+            PlanarDieFace::Blank => Text::PlanarBlank,
+            PlanarDieFace::Chaos => Text::PlanarChaos,
+            PlanarDieFace::Planeswalk => Text::Planeswalk,
+        }
+    }
+}
+
+/// A card revealed by a player during a clash.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ClashReveal {
+    pub player: PlayerName,
+
+    /// Card revealed from the top of the player's library, or None if their
+    /// library was empty.
+    pub card: Option<CardId>,
+
+    /// Mana value of the revealed card. A player with an empty library is
+    /// treated as having revealed a mana value of 0.
+    pub mana_value: ManaValue,
+}
+
+/// Outcome of a random event such as a coin flip, displayed to all players.
+#[derive(Debug, Clone)]
+pub enum RandomResult {
+    /// The [PlayerName] player flipped a coin.
+    ///
+    /// > 705.2. To flip a coin for an object that cares whether a player wins
+    /// > or loses the flip, the affected player calls heads or tails.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R7052>
+    CoinFlip {
+        player: PlayerName,
+
+        /// Side called by the player, if this flip cares about winning or
+        /// losing.
+        call: Option<CoinFace>,
+
+        result: CoinFace,
+    },
+
+    /// The [PlayerName] player rolled a die with `sides` sides.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R706>
+    DieRoll { player: PlayerName, sides: u32, result: u32 },
+
+    /// The [PlayerName] player rolled the planar die.
+    ///
+    /// <https://yawgatog.com/resources/magic-rules/#R901>
+    PlanarDie { player: PlayerName, result: PlanarDieFace },
+
+    /// The [PlayerName] player clashed with an opponent.
+    Clash {
+        player: PlayerName,
+
+        /// Cards revealed by each clashing player, starting with `player`.
+        revealed: Vec<ClashReveal>,
+
+        /// True if `player` won the clash.
+        won: bool,
+    },
+}
+
+impl RandomResult {
+    /// Player who performed this random event.
+    pub fn player(&self) -> PlayerName {
+        match self {
+            RandomResult::CoinFlip { player, .. }
+            | RandomResult::DieRoll { player, .. }
+            | RandomResult::PlanarDie { player, .. }
+            | RandomResult::Clash { player, .. } => *player,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+
+use primitives::game_primitives::{AbilityNumber, PlayerName, Zone};
+
+use crate::core::numerics::{Damage, LifeValue};
+use crate::game_states::history_data::HistoryEvent;
+
+/// Describes a single step in the resolution of a spell or ability, displayed
+/// to players while stepping through resolution.
+///
+/// See [crate::game_states::game_state::DebugConfiguration::step_through_resolution].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ResolutionStep {
+    /// The spell or ability is about to check whether its targets are still
+    /// legal.
+    CheckTargets,
+
+    /// Every target is illegal, so the spell or ability does not resolve.
+    AllTargetsIllegal,
+
+    /// The indicated ability is skipped because its targets are illegal.
+    SkipAbility(AbilityNumber),
+
+    /// The effect of the indicated ability is about to be applied.
+    ApplyEffect(AbilityNumber),
+
+    /// A card moved from one zone to another.
+    ZoneChange(Zone, Zone),
+
+    /// A player was dealt damage.
+    DamageToPlayer(PlayerName, Damage),
+
+    /// A permanent was dealt damage.
+    DamageToPermanent(Damage),
+
+    /// A player gained life.
+    LifeGained(PlayerName, LifeValue),
+
+    /// A player lost life.
+    LifeLost(PlayerName, LifeValue),
+
+    /// A spell was cast.
+    CastSpell(PlayerName),
+
+    /// A triggered ability triggered.
+    AbilityTriggered,
+
+    /// A coin was flipped, a die was rolled, or players clashed.
+    RandomResult,
+
+    /// Resolution is complete and the resolved object is about to be put into
+    /// the indicated zone.
+    Finish(Zone),
+}
+
+impl ResolutionStep {
+    /// Returns the step describing a [HistoryEvent] which occurred while
+    /// applying an effect, if any.
+    pub fn for_event(event: &HistoryEvent) -> Option<Self> {
+        match event {
+            HistoryEvent::CastSpell(player, _) => Some(Self::CastSpell(*player)),
+            HistoryEvent::AttackWithCreature(..) => None,
+            HistoryEvent::DamageToPlayer(player, damage) => {
+                Some(Self::DamageToPlayer(*player, *damage))
+            }
+            HistoryEvent::DamageToPermanent(_, damage) => Some(Self::DamageToPermanent(*damage)),
+            HistoryEvent::LifeGained(player, amount) => Some(Self::LifeGained(*player, *amount)),
+            HistoryEvent::LifeLost(player, amount) => Some(Self::LifeLost(*player, *amount)),
+            HistoryEvent::ZoneChange(_, old, new) => Some(Self::ZoneChange(*old, *new)),
+            HistoryEvent::AbilityTriggered(_) => Some(Self::AbilityTriggered),
+            HistoryEvent::RandomResult(_) => Some(Self::RandomResult),
+        }
+    }
+}
+
+impl Display for ResolutionStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CheckTargets => write!(f, "Checking whether targets are still legal"),
+            Self::AllTargetsIllegal => write!(f, "All targets are illegal, nothing happens"),
+            Self::SkipAbility(number) => {
+                write!(f, "Skipping ability {} because its target is illegal", number.0)
+            }
+            Self::ApplyEffect(number) => write!(f, "Applying the effect of ability {}", number.0),
+            Self::ZoneChange(old, new) => write!(f, "A card moved from {old:?} to {new:?}"),
+            Self::DamageToPlayer(player, damage) => {
+                write!(f, "Player {player:?} was dealt {damage} damage")
+            }
+            Self::DamageToPermanent(damage) => write!(f, "A permanent was dealt {damage} damage"),
+            Self::LifeGained(player, amount) => write!(f, "Player {player:?} gained {amount} life"),
+            Self::LifeLost(player, amount) => write!(f, "Player {player:?} lost {amount} life"),
+            Self::CastSpell(player) => write!(f, "Player {player:?} cast a spell"),
+            Self::AbilityTriggered => write!(f, "An ability triggered"),
+            Self::RandomResult => write!(f, "A random result was determined"),
+            Self::Finish(zone) => write!(f, "Resolution is complete, moving to {zone:?}"),
+        }
+    }
+}
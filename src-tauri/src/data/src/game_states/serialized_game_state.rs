@@ -12,13 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::SystemTime;
+
 use primitives::game_primitives::{GameId, PlayerName};
 use serde::{Deserialize, Serialize};
 
+use crate::campaign::campaign_stage::CampaignStageId;
 use crate::decks::deck_name::DeckName;
-use crate::game_states::game_state::DebugConfiguration;
+use crate::game_states::board_position::BoardPosition;
+use crate::game_states::game_state::{
+    DebugConfiguration, RulesConfiguration, TeamConfiguration,
+};
 use crate::game_states::history_data::TakenGameAction;
 use crate::player_states::player_map::PlayerMap;
+use crate::player_states::player_options::PlayerOptions;
 use crate::player_states::player_state::PlayerType;
 use crate::prompts::prompt::PromptResponse;
 
@@ -43,4 +50,21 @@ pub struct SerializedGameState {
     pub player_actions: PlayerMap<Vec<TakenGameAction>>,
     pub prompt_responses: PlayerMap<Vec<PromptResponse>>,
     pub debug_configuration: DebugConfiguration,
+    #[serde(default)]
+    pub rules: RulesConfiguration,
+    #[serde(default)]
+    pub teams: Option<TeamConfiguration>,
+    #[serde(default)]
+    pub campaign_stage: Option<CampaignStageId>,
+    #[serde(default)]
+    pub started_at: Option<SystemTime>,
+
+    /// Position the game was started from instead of each player's deck, if
+    /// any.
+    #[serde(default)]
+    pub position: Option<BoardPosition>,
+
+    /// Options each player has configured for this game.
+    #[serde(default)]
+    pub player_options: PlayerMap<PlayerOptions>,
 }
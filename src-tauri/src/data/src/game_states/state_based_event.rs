@@ -29,6 +29,20 @@ pub enum StateBasedEvent {
     CreatureToughnessChanged(PermanentId),
     CreatureDamaged(PermanentId),
     CreatureDamagedByDeathtouch(PermanentId),
+    CountersAdded(PermanentId),
     PlaneswalkerLostLoyalty(PermanentId),
+    BattleLostDefense(PermanentId),
     LegendaryPermanentEntered(PermanentId),
+
+    /// A card changed zones and received a new object ID, which may end
+    /// effects whose duration references its previous object.
+    ObjectIdChanged(CardId),
+
+    /// A card changed controllers, which may end effects lasting "for as long
+    /// as" a player controls it.
+    ControllerChanged(CardId),
+
+    /// Lore counters were put on a Saga or one of its chapter abilities left
+    /// the stack, which may cause it to be sacrificed.
+    SagaChapterChanged(PermanentId),
 }
@@ -0,0 +1,85 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::game_primitives::PlayerName;
+
+use crate::game_states::game_phase_step::GamePhaseStep;
+
+/// A turn, phase, or step which a player will skip the next time it would
+/// begin.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SkippedTurnPart {
+    /// The player's next turn, e.g. "skip your next turn".
+    Turn,
+
+    /// The player's next combat phase.
+    CombatPhase,
+
+    /// The player's next instance of a step, e.g. "skip your next draw step".
+    ///
+    /// Combat steps cannot be skipped individually, use
+    /// [SkippedTurnPart::CombatPhase] instead.
+    Step(GamePhaseStep),
+}
+
+/// Modifications to the normal sequence of turns, phases, and steps which have
+/// been created by effects.
+///
+/// > 500.7. Some effects can give a player extra turns. They do this by adding
+/// > the turns directly after the specified turn. If a player is given multiple
+/// > extra turns, the extra turns are added one at a time. If multiple players
+/// > are given extra turns, the extra turns are added one at a time, in APNAP
+/// > order. The most recently created turn will be taken first.
+///
+/// > 500.8. Some effects can add phases to a turn. They do this by adding the
+/// > phases directly after the specified phase.
+///
+/// > 500.11. No game events can occur between turns, phases, or steps. Some
+/// > effects cause a player to skip a step, phase, or turn. To skip a step,
+/// > phase, or turn is to proceed past it as though it didn't exist.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R5007>
+#[derive(Debug, Clone, Default)]
+pub struct TurnSchedule {
+    /// Extra turns which have been created and not yet taken. The last entry
+    /// is the most recently created turn and will be taken first.
+    pub extra_turns: Vec<PlayerName>,
+
+    /// Player whose turn was in progress when the current sequence of extra
+    /// turns was created. Normal turn order resumes after this player once all
+    /// extra turns have been taken.
+    pub resume_after: Option<PlayerName>,
+
+    /// Number of additional combat phases, each followed by an additional main
+    /// phase, to add after the current postcombat main phase.
+    pub additional_combat_phases: u32,
+
+    /// Turns, phases, and steps which players will skip.
+    pub skipped: Vec<(PlayerName, SkippedTurnPart)>,
+}
+
+impl TurnSchedule {
+    /// Removes a scheduled skip of the given [SkippedTurnPart] by the
+    /// [PlayerName] player, if any.
+    ///
+    /// Returns true if the player should skip this part of the turn.
+    pub fn take_skip(&mut self, player: PlayerName, part: SkippedTurnPart) -> bool {
+        if let Some(i) = self.skipped.iter().position(|&skipped| skipped == (player, part)) {
+            self.skipped.remove(i);
+            true
+        } else {
+            false
+        }
+    }
+}
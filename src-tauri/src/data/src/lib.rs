@@ -16,6 +16,7 @@
 #![allow(unused_variables)]
 
 pub mod actions;
+pub mod campaign;
 pub mod card_definitions;
 pub mod card_states;
 pub mod core;
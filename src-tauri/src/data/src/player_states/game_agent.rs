@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt::Debug;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use dyn_clone::DynClone;
@@ -60,6 +61,10 @@ impl GameAgent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StatePredictor {
     Omniscient,
+
+    /// Hides cards in opponent hands and libraries which the agent could not
+    /// have seen.
+    Determinized,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,6 +77,7 @@ pub enum AgentType {
     FirstAvailableAction,
     TreeSearch(TreeSearchAgent),
     MonteCarlo(MonteCarloAgent),
+    External(ExternalAgent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +89,46 @@ pub struct TreeSearchAgent {
 pub struct MonteCarloAgent {
     pub child_score_algorithm: ChildScoreAlgorithm,
     pub max_iterations: Option<usize>,
+
+    /// Configuration for periodically saving search progress to disk, or None
+    /// to disable checkpointing.
+    #[serde(default)]
+    pub checkpoint: Option<SearchCheckpointConfiguration>,
+}
+
+/// Configuration for periodically saving the progress of a Monte Carlo search
+/// to disk, so that long searches can be resumed if the process is killed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchCheckpointConfiguration {
+    /// File to write checkpoints to.
+    pub path: PathBuf,
+
+    /// Number of search iterations between each checkpoint. Must be greater
+    /// than zero.
+    pub interval: u32,
+
+    /// If true, an existing checkpoint file at `path` is loaded when a search
+    /// starts and the search continues from that point.
+    pub resume: bool,
+}
+
+/// An agent implemented by a separate program, which communicates with the
+/// game over its standard input and output streams.
+///
+/// The program receives one JSON request per line describing the current game
+/// state and the available legal actions, and must respond with one JSON line
+/// identifying the action it selects. An agent which fails to respond within
+/// `timeout` or sends an invalid response forfeits the game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalAgent {
+    /// Path to the program to run
+    pub command: PathBuf,
+
+    /// Arguments to pass to the program
+    pub args: Vec<String>,
+
+    /// Maximum time to wait for the program to respond to each request
+    pub timeout: Duration,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+
 use enumset::EnumSet;
+use primitives::game_primitives::AbilityNumber;
 use serde::{Deserialize, Serialize};
 
+use crate::card_definitions::card_name::CardName;
+use crate::core::sound_effect::SoundEffect;
 use crate::game_states::game_phase_step::GamePhaseStep;
+use crate::text_strings::language_id::LanguageId;
 
 /// Configurable options for a player within a game
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +54,47 @@ pub struct PlayerOptions {
     /// Should this player receive priority after each item on the stack
     /// resolves?
     pub resolve_individual_stack_items: bool,
+
+    /// If true, this player receives priority every time it would be given to
+    /// them, regardless of their stops or of [Self::auto_pass]. Empty
+    /// attacker and blocker declarations are also not confirmed automatically.
+    #[serde(default)]
+    pub full_control: bool,
+
+    /// If true, the player will automatically pass priority while a triggered
+    /// ability they control is on top of the stack.
+    #[serde(default)]
+    pub auto_yield_own_triggers: bool,
+
+    /// Triggered abilities which should always stop for priority, even if
+    /// [Self::auto_yield_own_triggers] is enabled.
+    ///
+    /// Abilities are identified by card name and ability number, so that this
+    /// applies to every copy of the card in every game.
+    #[serde(default)]
+    pub always_ask_triggers: BTreeSet<(CardName, AbilityNumber)>,
+
+    /// If true, card movement animations are not displayed to this player.
+    #[serde(default)]
+    pub reduce_motion: bool,
+
+    /// Sound effects which should not be played for this player.
+    #[serde(default)]
+    pub muted_sounds: EnumSet<SoundEffect>,
+
+    /// If true, a plain text description of the board is sent to this player
+    /// with each update, for use with screen readers.
+    #[serde(default)]
+    pub screen_reader: bool,
+
+    /// If true, the latency of recent rules engine operations is displayed to
+    /// this player in an overlay.
+    #[serde(default)]
+    pub performance_hud: bool,
+
+    /// Language in which user interface text is displayed to this player.
+    #[serde(default)]
+    pub language: LanguageId,
 }
 
 impl Default for PlayerOptions {
@@ -63,6 +110,14 @@ impl Default for PlayerOptions {
             auto_pass: true,
             hold_priority: false,
             resolve_individual_stack_items: false,
+            full_control: false,
+            auto_yield_own_triggers: false,
+            always_ask_triggers: BTreeSet::new(),
+            reduce_motion: false,
+            muted_sounds: EnumSet::empty(),
+            screen_reader: false,
+            performance_hud: false,
+            language: LanguageId::English,
         }
     }
 }
@@ -12,16 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use primitives::game_primitives::{
-    CardId, EntityId, HasController, HasPlayerName, PlayerName, UserId,
+    CardId, EntityId, HasController, HasPlayerName, ObjectId, PlayerName, UserId, Zone,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::card_states::counters::Counters;
 use crate::core::numerics::LifeValue;
 use crate::decks::deck_name::DeckName;
+use crate::game_states::game_state::TurnData;
 use crate::player_states::game_agent::{GameAgent, GameAgentImpl, PromptAgentImpl};
 use crate::player_states::mana_pool::ManaPool;
+use crate::player_states::player_map::PlayerMap;
 use crate::player_states::player_options::PlayerOptions;
 use crate::player_states::prompt_stack::PromptStack;
 
@@ -51,6 +55,16 @@ impl Players {
             player_4: PlayerState::new(PlayerName::Four, PlayerType::None, DeckName::default(), 0),
         }
     }
+
+    /// Creates a set of players with the provided state for each player name.
+    pub fn from_map(states: PlayerMap<PlayerState>) -> Self {
+        Self {
+            player_1: states.one,
+            player_2: states.two,
+            player_3: states.three,
+            player_4: states.four,
+        }
+    }
 }
 
 impl PlayerQueries for Players {
@@ -118,6 +132,12 @@ pub struct PlayerState {
     /// Current amount of life for this player
     pub life: LifeValue,
 
+    /// Most recent turn this player has taken, including the current turn.
+    ///
+    /// Players on a team take each of the team's turns together. None if the
+    /// player has not yet taken a turn.
+    pub last_turn: Option<TurnData>,
+
     /// Player currently controlling this player
     pub controller: PlayerName,
 
@@ -137,6 +157,32 @@ pub struct PlayerState {
     /// Typically used as part of a multi-part prompt resolution like "pick two
     /// target creatures".
     pub selected_cards: Vec<CardId>,
+
+    /// Cards owned by other players whose identity this player has seen while
+    /// they were in a hidden zone.
+    ///
+    /// See [KnownCard].
+    pub known_cards: BTreeMap<CardId, KnownCard>,
+}
+
+/// Information a player has learned about a card in another player's hand or
+/// library.
+///
+/// A card remains known for as long as it is the same object, even after it
+/// stops being revealed. Once it changes zones it becomes a new object and
+/// this knowledge no longer applies.
+///
+/// See <https://yawgatog.com/resources/magic-rules/#R4007>
+#[derive(Debug, Clone, Copy)]
+pub struct KnownCard {
+    /// Object the card was when it was revealed.
+    pub object_id: ObjectId,
+
+    /// Hidden zone the card was in when it was revealed.
+    pub zone: Zone,
+
+    /// Turn on which the card was first revealed as this object.
+    pub revealed_on: TurnData,
 }
 
 impl PlayerState {
@@ -153,11 +199,13 @@ impl PlayerState {
             deck_name,
             options: PlayerOptions::default(),
             life,
+            last_turn: None,
             controller: name,
             counters: Counters::default(),
             mana_pool: ManaPool::default(),
             prompts: Default::default(),
             selected_cards: vec![],
+            known_cards: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,28 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// An official ruling clarifying the behavior of a card, as it appears in the
+/// `cardRulings` table of the MTGJSON card database or in imported Scryfall
+/// rulings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardRuling {
+    /// Date this ruling was issued, in YYYY-MM-DD format.
+    pub date: String,
+
+    /// Text of the ruling.
+    pub text: String,
+}
@@ -0,0 +1,216 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use utils::paths;
+use uuid::Uuid;
+
+use crate::card_definitions::card_name::CardName;
+use crate::printed_cards::database_card::DatabaseCardFace;
+use crate::printed_cards::printed_card_id::PrintedCardId;
+
+/// Name of the file in the data directory from which custom cards are loaded.
+const CUSTOM_CARDS_FILE: &str = "custom_cards.json";
+
+static CUSTOM_CARDS: Lazy<BTreeMap<PrintedCardId, CustomCard>> = Lazy::new(load);
+
+/// A user-defined card which does not exist in the Oracle card database, e.g.
+/// a proxy for a playtest card.
+///
+/// Custom cards are single-faced, and their rules are limited to keyword
+/// abilities which have already been implemented.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomCard {
+    /// Identifies this card, used as both its [PrintedCardId] and its
+    /// [CardName].
+    ///
+    /// This must not collide with the identifier of any card in the Oracle
+    /// card database.
+    pub id: Uuid,
+
+    /// The name of the card.
+    pub name: String,
+
+    /// The mana cost of the card wrapped in brackets for each value, e.g.
+    /// "{2}{G}".
+    #[serde(default)]
+    pub mana_cost: Option<String>,
+
+    /// Supertypes of the card, e.g. "Legendary".
+    #[serde(default)]
+    pub supertypes: Vec<String>,
+
+    /// Card types of the card, e.g. "Creature".
+    pub types: Vec<String>,
+
+    /// Subtypes of the card, e.g. "Bear".
+    #[serde(default)]
+    pub subtypes: Vec<String>,
+
+    #[serde(default)]
+    pub power: Option<String>,
+
+    #[serde(default)]
+    pub toughness: Option<String>,
+
+    /// Keyword abilities of the card.
+    #[serde(default)]
+    pub keywords: Vec<CustomCardKeyword>,
+}
+
+/// Keyword abilities which can be given to a [CustomCard].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CustomCardKeyword {
+    Deathtouch,
+    Flying,
+    Haste,
+    Infect,
+    Lifelink,
+    Vigilance,
+    Wither,
+}
+
+impl CustomCard {
+    pub fn printed_card_id(&self) -> PrintedCardId {
+        PrintedCardId(self.id)
+    }
+
+    pub fn card_name(&self) -> CardName {
+        CardName(self.id)
+    }
+
+    /// Returns the rules text of this card, consisting of a comma-separated
+    /// list of its keywords.
+    pub fn rules_text(&self) -> Option<String> {
+        if self.keywords.is_empty() {
+            return None;
+        }
+        Some(self.keywords.iter().map(|k| format!("{k:?}")).collect::<Vec<_>>().join(", "))
+    }
+
+    /// Describes this card in the format used by the Oracle card database, so
+    /// that it can be parsed like any other card.
+    pub fn to_database_face(&self) -> DatabaseCardFace {
+        let symbols = self.mana_cost.as_deref().map(mana_symbols).unwrap_or_default();
+        let colors = ["W", "U", "B", "R", "G"]
+            .into_iter()
+            .filter(|color| symbols.iter().any(|symbol| symbol == color))
+            .collect::<Vec<_>>();
+        let mana_value = symbols
+            .iter()
+            .map(|symbol| match symbol.parse::<u32>() {
+                Ok(value) => value,
+                Err(_) if symbol == "X" => 0,
+                Err(_) => 1,
+            })
+            .sum::<u32>();
+        let type_line = if self.subtypes.is_empty() {
+            join(&[&self.supertypes[..], &self.types[..]].concat(), " ")
+        } else {
+            format!(
+                "{} — {}",
+                join(&[&self.supertypes[..], &self.types[..]].concat(), " "),
+                join(&self.subtypes, " ")
+            )
+        };
+
+        DatabaseCardFace {
+            artist: None,
+            attraction_lights: None,
+            colors: optional(join(&colors, ", ")),
+            defense: None,
+            face_name: None,
+            layout: "normal".to_string(),
+            loyalty: None,
+            mana_cost: self.mana_cost.clone(),
+            mana_value: mana_value as f32,
+            name: self.name.clone(),
+            power: self.power.clone(),
+            scryfall_oracle_id: self.id,
+            side: None,
+            subtypes: optional(join(&self.subtypes, ", ")),
+            supertypes: optional(join(&self.supertypes, ", ")),
+            text: self.rules_text(),
+            toughness: self.toughness.clone(),
+            type_line: Some(type_line),
+            types: optional(join(&self.types, ", ")),
+            uuid: self.id,
+        }
+    }
+}
+
+/// Returns all custom cards.
+///
+/// Cards are read from a JSON file in the data directory if one exists.
+pub fn all() -> impl Iterator<Item = &'static CustomCard> {
+    CUSTOM_CARDS.values()
+}
+
+/// Looks up the custom card with the given ID, if any.
+pub fn get(id: PrintedCardId) -> Option<&'static CustomCard> {
+    CUSTOM_CARDS.get(&id)
+}
+
+/// Looks up a custom card by name, ignoring case.
+pub fn find(name: &str) -> Option<&'static CustomCard> {
+    all().find(|card| card.name.eq_ignore_ascii_case(name))
+}
+
+fn load() -> BTreeMap<PrintedCardId, CustomCard> {
+    let path = paths::get_data_dir().join(CUSTOM_CARDS_FILE);
+    if !path.exists() {
+        return BTreeMap::new();
+    }
+
+    let result = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| serde_json::from_str::<Vec<CustomCard>>(&json).map_err(|e| e.to_string()));
+    match result {
+        Ok(cards) => {
+            info!("Loaded {} custom cards from {:?}", cards.len(), path);
+            cards.into_iter().map(|card| (card.printed_card_id(), card)).collect()
+        }
+        Err(e) => {
+            warn!("Error loading custom cards {:?}: {}", path, e);
+            BTreeMap::new()
+        }
+    }
+}
+
+fn mana_symbols(cost: &str) -> Vec<String> {
+    let re = Regex::new(r"\{(.*?)}").expect("Invalid regex");
+    re.captures_iter(cost)
+        .filter_map(|capture| capture.get(1))
+        .map(|symbol| symbol.as_str().to_string())
+        .collect()
+}
+
+fn join(values: &[impl AsRef<str>], separator: &str) -> String {
+    values.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(separator)
+}
+
+fn optional(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
@@ -30,6 +30,10 @@ pub struct DatabaseCardFace {
     /// values, such as cards with "Devoid" in its text.
     pub colors: Option<String>,
 
+    /// The starting defense value of the card. Used only on cards
+    /// with "Battle" in its types.
+    pub defense: Option<String>,
+
     /// The name on the face of the card.
     pub face_name: Option<String>,
 
@@ -76,6 +80,10 @@ pub struct DatabaseCardFace {
     /// The toughness of the card.
     pub toughness: Option<String>,
 
+    /// Type line of the card as printed, e.g. "Legendary Creature — Elf".
+    #[serde(rename = "type")]
+    pub type_line: Option<String>,
+
     /// A list of all card types of the card, including Un‑sets and
     /// gameplay variants.
     pub types: Option<String>,
@@ -84,3 +92,42 @@ pub struct DatabaseCardFace {
     /// Each entry is unique.
     pub uuid: Uuid,
 }
+
+/// A single printing of a card face in a specific set, used to check which
+/// formats a card is legal in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseCardPrinting {
+    /// The name of the card. Cards with multiple faces, like
+    /// "Split" and "Meld" cards are given a
+    /// delimiter.
+    pub name: String,
+
+    /// Code of the set this card was printed in, e.g. "MH3".
+    pub set_code: String,
+
+    /// The rarity of this printing, e.g. "common".
+    pub rarity: String,
+
+    /// A list of card supertypes found before em-dash.
+    pub supertypes: Option<String>,
+}
+
+/// Oracle text of a single card face, used to find cards whose rules can be
+/// generated without hand-written code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseOracleText {
+    /// Identifier for this card as a named rules entity within the Scryfall
+    /// database.
+    pub scryfall_oracle_id: Uuid,
+
+    /// The type of card layout, e.g. "normal".
+    pub layout: String,
+
+    /// Identifies this face within a multi-face card.
+    pub side: Option<String>,
+
+    /// The rules text of the card.
+    pub text: Option<String>,
+}
@@ -21,6 +21,7 @@ use specta::Type;
 use uuid::Uuid;
 
 use crate::card_definitions::card_name::CardName;
+use crate::core::card_tags::CardTag;
 use crate::core::numerics::ManaValue;
 use crate::printed_cards::card_subtypes::CardSubtypes;
 use crate::printed_cards::layout::{CardLayout, FaceLayout};
@@ -72,6 +73,14 @@ pub struct PrintedCard {
     ///
     /// See <https://scryfall.com/docs/api/layouts>
     pub layout: CardLayout,
+
+    /// True if this is a user-defined card which does not exist in the Oracle
+    /// card database.
+    ///
+    /// See [CustomCard].
+    ///
+    /// [CustomCard]: crate::printed_cards::custom_card::CustomCard
+    pub custom: bool,
 }
 
 impl PrintedCard {
@@ -139,6 +148,12 @@ pub struct PrintedCardFace {
     /// The printed toughness of the card.
     pub toughness: Option<PrintedToughness>,
 
+    /// The printed defense of the card, if it is a battle with a numeric
+    /// defense.
+    ///
+    /// See <https://yawgatog.com/resources/magic-rules/#R3104>
+    pub defense: Option<u32>,
+
     /// Face layout, describing its printed structure.
     ///
     /// See <https://scryfall.com/docs/api/layouts> for descriptions & examples of layouts.
@@ -150,6 +165,13 @@ pub struct PrintedCardFace {
     /// This *does* take into account color-characteristic-defining abilities
     /// like Devoid.
     pub colors: EnumSet<Color>,
+
+    /// Heuristic [CardTag]s describing the role this face plays in a game,
+    /// derived from its printed characteristics during oracle import.
+    ///
+    /// These are intended for AI feature extraction and are not guaranteed to
+    /// be accurate.
+    pub tags: EnumSet<CardTag>,
 }
 
 /// A visually unique printed version of a card face.
@@ -0,0 +1,61 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::game_primitives::EntityId;
+
+/// A prompt for a player to divide a quantity, such as an amount of damage or
+/// a number of +1/+1 counters, among a set of targets.
+///
+/// See <https://yawgatog.com/resources/magic-rules/#R6012d>
+#[derive(Clone, Debug)]
+pub struct DistributePrompt {
+    /// Total quantity which must be distributed
+    pub total: u32,
+
+    /// Entities to distribute the quantity among
+    pub targets: Vec<EntityId>,
+
+    /// Amount currently assigned to each target, in the same order as
+    /// [Self::targets]
+    pub assigned: Vec<u32>,
+
+    /// Smallest amount which can be assigned to each target. This is
+    /// typically 1, since each target must receive at least one of the
+    /// quantity being divided.
+    pub minimum_per_target: u32,
+}
+
+impl DistributePrompt {
+    pub fn new(total: u32, targets: Vec<EntityId>, minimum_per_target: u32) -> Self {
+        let assigned = vec![minimum_per_target; targets.len()];
+        Self { total, targets, assigned, minimum_per_target }
+    }
+
+    /// Sum of the amounts currently assigned to all targets.
+    pub fn assigned_total(&self) -> u32 {
+        self.assigned.iter().sum()
+    }
+
+    /// Largest amount which can currently be assigned to the target at
+    /// `index` without exceeding the total.
+    pub fn maximum_for(&self, index: usize) -> u32 {
+        self.total.saturating_sub(self.assigned_total() - self.assigned[index])
+    }
+
+    /// Returns true if the current assignment can be submitted.
+    pub fn is_valid_distribution(&self) -> bool {
+        self.assigned_total() == self.total
+            && self.assigned.iter().all(|&amount| amount >= self.minimum_per_target)
+    }
+}
@@ -12,14 +12,95 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::mem;
+
+use enumset::EnumSet;
+use primitives::game_primitives::{CardId, PermanentId, PlayerName, Zone};
 use tokio::sync::{mpsc, oneshot};
 
+use crate::core::numerics::Damage;
 use crate::game_states::game_state::GameState;
+use crate::game_states::random_result::RandomResult;
 use crate::prompts::prompt::{Prompt, PromptResponse};
+
 /// Represents a change to the state of the game which should be translated
 /// into a client animation
 #[derive(Debug, Clone)]
-pub enum GameAnimation {}
+pub enum GameAnimation {
+    /// The [PlayerName] player drew the [CardId] card.
+    DrawCard(PlayerName, CardId),
+
+    /// Damage was dealt to the [PermanentId] permanent.
+    DamagePermanent(PermanentId, Damage),
+
+    /// Damage was dealt to the [PlayerName] player.
+    DamagePlayer(PlayerName, Damage),
+
+    /// The [PermanentId] creature was declared as an attacker.
+    AttackLunge(PermanentId),
+
+    /// The [CardId] card moved from the first [Zone] to the second [Zone].
+    ZoneMove(CardId, Zone, Zone),
+
+    /// A coin was flipped, a die was rolled, or players clashed.
+    RandomResult(RandomResult),
+
+    /// The game ended. The indicated players won the game.
+    GameOver(EnumSet<PlayerName>),
+}
+
+/// A [GameAnimation] within an [AnimationTimeline].
+#[derive(Debug, Clone)]
+pub struct AnimationStep {
+    /// Sequencing group for this animation.
+    ///
+    /// Animations in the same group play at the same time. Each group begins
+    /// playing once all animations in lower-numbered groups have finished.
+    pub group: u32,
+
+    pub animation: GameAnimation,
+}
+
+/// Records [GameAnimation]s in the order in which they occur during game
+/// mutations, so they can be played back by the client.
+#[derive(Debug, Clone, Default)]
+pub struct AnimationTimeline {
+    steps: Vec<AnimationStep>,
+    group: u32,
+    simultaneous_depth: u32,
+}
+
+impl AnimationTimeline {
+    /// Adds an animation to the timeline.
+    ///
+    /// The animation plays after all previously-added animations have finished,
+    /// unless it is added within [Self::begin_simultaneous].
+    pub fn add(&mut self, animation: GameAnimation) {
+        if self.simultaneous_depth == 0 {
+            self.group += 1;
+        }
+        self.steps.push(AnimationStep { group: self.group, animation });
+    }
+
+    /// Starts a new sequencing group. All animations added until the matching
+    /// call to [Self::end_simultaneous] play at the same time.
+    pub fn begin_simultaneous(&mut self) {
+        if self.simultaneous_depth == 0 {
+            self.group += 1;
+        }
+        self.simultaneous_depth += 1;
+    }
+
+    /// Ends a sequencing group started by [Self::begin_simultaneous].
+    pub fn end_simultaneous(&mut self) {
+        self.simultaneous_depth = self.simultaneous_depth.saturating_sub(1);
+    }
+
+    /// Removes and returns all animations recorded so far.
+    pub fn take(&mut self) -> Vec<AnimationStep> {
+        mem::take(&mut self.steps)
+    }
+}
 
 /// Represents an incremental update to the game state as the rules engine is
 /// running.
@@ -27,9 +108,8 @@ pub struct GameUpdate {
     /// Snapshot of the game state at the time of the update.
     pub game: GameState,
 
-    /// An animation to perform after displaying this game state
-    /// snapshot.
-    pub animation: Option<GameAnimation>,
+    /// Animations to perform before displaying this game state snapshot.
+    pub animations: Vec<AnimationStep>,
 
     /// A prompt to display to a named player.
     pub prompt: Option<Prompt>,
@@ -43,11 +123,16 @@ pub struct GameUpdate {
 
 impl GameUpdate {
     pub fn new(game: &GameState) -> Self {
-        Self { game: game.shallow_clone(), prompt: None, animation: None, response_channel: None }
+        Self {
+            game: game.shallow_clone(),
+            prompt: None,
+            animations: vec![],
+            response_channel: None,
+        }
     }
 
-    pub fn animation(mut self, animation: GameAnimation) -> Self {
-        self.animation = Some(animation);
+    pub fn animations(mut self, animations: Vec<AnimationStep>) -> Self {
+        self.animations = animations;
         self
     }
 
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod distribute_prompt;
 pub mod entity_choice_prompt;
 pub mod game_update;
+pub mod multi_select_prompt;
 pub mod multiple_choice_prompt;
+pub mod pay_mana_prompt;
 pub mod pick_number_prompt;
 pub mod play_cards_prompt;
 pub mod prompt;
@@ -0,0 +1,39 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::text_strings::Text;
+
+/// A prompt for a player to pick between `minimum` and `maximum` items from a
+/// list of choice buttons.
+#[derive(Clone, Debug)]
+pub struct MultiSelectPrompt {
+    /// Choices to display for this prompt
+    pub choices: Vec<Text>,
+
+    /// Indices of the currently selected choices, in ascending order
+    pub selected: Vec<usize>,
+
+    /// Smallest number of choices which must be selected
+    pub minimum: usize,
+
+    /// Largest number of choices which can be selected
+    pub maximum: usize,
+}
+
+impl MultiSelectPrompt {
+    /// Returns true if the current selection can be submitted.
+    pub fn is_valid_selection(&self) -> bool {
+        (self.minimum..=self.maximum).contains(&self.selected.len())
+    }
+}
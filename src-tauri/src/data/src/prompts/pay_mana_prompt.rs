@@ -0,0 +1,93 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use enumset::EnumSet;
+use primitives::game_primitives::{CardId, ManaColor, PermanentId};
+
+use crate::printed_cards::mana_cost::ManaCostItem;
+
+/// A prompt for a player to select which mana sources to tap in order to pay
+/// the mana cost of a spell.
+///
+/// The prompt starts out populated with an automatically-computed suggestion,
+/// which the player can accept as-is or override by toggling individual
+/// sources.
+#[derive(Clone, Debug)]
+pub struct PayManaPrompt {
+    /// Card whose cost is being paid.
+    pub card_id: CardId,
+
+    /// Mana cost to pay.
+    pub cost: Vec<ManaCostItem>,
+
+    /// Permanents which can be tapped for mana, along with the colors of mana
+    /// each can produce.
+    pub sources: BTreeMap<PermanentId, EnumSet<ManaColor>>,
+
+    /// Sources suggested by the automatic payment planner.
+    pub suggested: Vec<PermanentId>,
+
+    /// Sources currently selected to be tapped.
+    pub selected: Vec<PermanentId>,
+}
+
+impl PayManaPrompt {
+    /// True if the player has changed the selection away from the automatic
+    /// suggestion.
+    pub fn is_manual(&self) -> bool {
+        self.selected != self.suggested
+    }
+
+    /// True if tapping exactly the currently selected sources pays this cost.
+    pub fn is_valid_payment(&self) -> bool {
+        if self.selected.len() != self.cost.len() {
+            return false;
+        }
+
+        let colors = self
+            .selected
+            .iter()
+            .map(|id| self.sources.get(id).copied().unwrap_or_default())
+            .collect::<Vec<_>>();
+        let colored = self
+            .cost
+            .iter()
+            .filter_map(|item| match item {
+                ManaCostItem::Colored(color) => Some(*color),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assign_colors(&colored, &colors, &mut vec![false; colors.len()])
+    }
+}
+
+/// Returns true if each color in `needed` can be assigned a distinct source
+/// from `sources` which produces that color.
+fn assign_colors(needed: &[ManaColor], sources: &[EnumSet<ManaColor>], used: &mut [bool]) -> bool {
+    let Some((&color, rest)) = needed.split_first() else {
+        return true;
+    };
+    for i in 0..sources.len() {
+        if !used[i] && sources[i].contains(color) {
+            used[i] = true;
+            if assign_colors(rest, sources, used) {
+                return true;
+            }
+            used[i] = false;
+        }
+    }
+    false
+}
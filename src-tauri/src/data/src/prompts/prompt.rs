@@ -14,13 +14,16 @@
 
 use std::collections::BTreeMap;
 
-use primitives::game_primitives::{CardId, EntityId, PlayerName};
+use primitives::game_primitives::{CardId, EntityId, PermanentId, PlayerName};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use strum::EnumDiscriminants;
 
+use crate::prompts::distribute_prompt::DistributePrompt;
 use crate::prompts::entity_choice_prompt::EntityChoicePrompt;
+use crate::prompts::multi_select_prompt::MultiSelectPrompt;
 use crate::prompts::multiple_choice_prompt::MultipleChoicePromptTrait;
+use crate::prompts::pay_mana_prompt::PayManaPrompt;
 use crate::prompts::pick_number_prompt::PickNumberPrompt;
 use crate::prompts::play_cards_prompt::PlayCardsPrompt;
 use crate::prompts::select_order_prompt::{CardOrderLocation, SelectOrderPrompt};
@@ -48,6 +51,9 @@ pub enum PromptType {
     PlayCards(PlayCardsPrompt),
     PickNumber(PickNumberPrompt),
     MultipleChoice(Box<dyn MultipleChoicePromptTrait>),
+    MultiSelect(MultiSelectPrompt),
+    Distribute(DistributePrompt),
+    PayMana(PayManaPrompt),
 }
 
 impl PromptType {
@@ -73,10 +79,14 @@ impl SelectedOrder {
 #[strum_discriminants(name(PromptResponseKind))]
 pub enum PromptResponse {
     EntityChoice(EntityId),
+    SkipEntityChoice,
     SelectOrder(SelectedOrder),
     PlayCards(Vec<CardId>),
     PickNumber(u32),
     MultipleChoice(usize),
+    MultiSelect(Vec<usize>),
+    Distribute(Vec<u32>),
+    PayMana(Vec<PermanentId>),
 }
 
 impl PromptResponse {
@@ -14,9 +14,12 @@
 
 use color_eyre::owo_colors::Effect;
 use dyn_clone::DynClone;
-use primitives::game_primitives::Source;
+use enumset::EnumSet;
+use primitives::game_primitives::{AbilityId, EventId, Source, Zone};
 
+use crate::card_states::zones::ZoneQueries;
 use crate::core::layer::{EffectSortingKey, Layer};
+use crate::core::modifier_data::ConditionFn;
 use crate::core::rule_type;
 use crate::core::rule_type::RuleType;
 use crate::game_states::game_state::GameState;
@@ -27,14 +30,56 @@ use crate::properties::property_value::PropertyValue;
 #[derive(Clone)]
 pub struct CardModifier<TModifier> {
     pub source: Source,
+
+    /// Event which created this modifier, if it was created by a game effect.
+    pub event_id: Option<EventId>,
+
     pub duration: Duration,
     pub rule_type: RuleType,
     pub effect: TModifier,
+
+    /// Condition which must hold for this modifier to apply, if any.
+    pub condition: Option<ModifierCondition>,
+
+    /// Zones in which the card which created this modifier must be for it to
+    /// apply. Only checked for modifiers with [RuleType::Ability].
+    pub zones: EnumSet<Zone>,
+}
+
+/// A condition under which a [CardModifier] applies.
+#[derive(Clone, Copy)]
+pub struct ModifierCondition {
+    /// The ability which created this modifier.
+    pub ability_id: AbilityId,
+
+    pub predicate: ConditionFn,
+}
+
+impl ModifierCondition {
+    /// Returns true if this condition currently holds.
+    pub fn holds(&self, game: &GameState) -> bool {
+        (self.predicate)(game, Source::Ability(self.ability_id), self.ability_id.card_id)
+            == Some(true)
+    }
 }
 
 impl<TModifier: PropertyValue> CardModifier<TModifier> {
     /// Returns true if this modifier should currently be applied to the game.
     pub fn active(&self, game: &GameState) -> bool {
         rule_type::is_active(game, self.duration, self.rule_type, self.effect.effect_sorting_key())
+            && self.in_functional_zone(game)
+            && self.condition.map_or(true, |condition| condition.holds(game))
+    }
+
+    /// Returns true if the card whose ability created this modifier is in one
+    /// of the zones in which that ability functions.
+    fn in_functional_zone(&self, game: &GameState) -> bool {
+        if self.zones == EnumSet::all() {
+            return true;
+        }
+        let RuleType::Ability(card_id) = self.rule_type else {
+            return true;
+        };
+        game.card(card_id).is_some_and(|card| self.zones.contains(card.zone))
     }
 }
@@ -14,15 +14,19 @@
 
 use std::fmt::{Debug, Formatter};
 
-use primitives::game_primitives::Color;
+use primitives::game_primitives::{Color, EventId};
 
 use crate::core::card_tags::CardTag;
-use crate::core::numerics::{Power, Toughness};
+use crate::core::numerics::{LifeValue, Power, Toughness};
 use crate::printed_cards::card_subtypes::{CreatureType, LandType};
 use crate::properties::card_property::CardProperty;
-use crate::properties::card_property_data::{CanAttackTarget, CanBeBlocked};
+use crate::properties::card_property_data::{
+    CanAttackTarget, CanBeBlocked, CanBeTargeted, CountersPlacedQuery, GameResultQuery,
+    LifeGainQuery,
+};
 use crate::properties::flag::Flag;
-use crate::properties::property_value::{ChangeText, EnumSets, Ints};
+use crate::properties::property_value::{ChangeText, EnumSets, Ints, Replacement};
+use crate::properties::query_cache::QueryCache;
 
 #[derive(Default, Clone)]
 pub struct CardProperties {
@@ -35,10 +39,40 @@ pub struct CardProperties {
     /// Can this creature be blocked by the indicated blocker?
     pub can_be_blocked: CardProperty<Flag<CanBeBlocked>>,
 
+    /// Can this card be the target of the indicated spell or ability?
+    pub can_be_targeted: CardProperty<Flag<CanBeTargeted>>,
+
     /// 'Haste' effect. Can this creature attack on the same turn it is played,
     /// or immediately after switching controllers?
     pub can_attack_same_turn: CardProperty<Flag<()>>,
 
+    /// Can the indicated player lose the game?
+    ///
+    /// This is queried for every permanent on the battlefield, e.g. to
+    /// implement "You can't lose the game".
+    pub can_lose_game: CardProperty<Flag<GameResultQuery>>,
+
+    /// Can the indicated player win the game?
+    ///
+    /// This is queried for every permanent on the battlefield, e.g. to
+    /// implement "Your opponents can't win the game".
+    pub can_win_game: CardProperty<Flag<GameResultQuery>>,
+
+    /// Modifies the amount of life the indicated player would gain.
+    ///
+    /// This is queried for every permanent on the battlefield, e.g. to
+    /// implement "If you would gain life, you gain twice that much life
+    /// instead."
+    pub life_gain: CardProperty<Replacement<LifeGainQuery, LifeValue>>,
+
+    /// Modifies the number of counters which would be put on the indicated
+    /// permanent.
+    ///
+    /// This is queried for every permanent on the battlefield, e.g. to
+    /// implement "If one or more +1/+1 counters would be put on a creature you
+    /// control, twice that many +1/+1 counters are put on it instead."
+    pub counters_placed: CardProperty<Replacement<CountersPlacedQuery, u32>>,
+
     /// Queries the colors of a card.
     ///
     /// An empty set represents colorless.
@@ -75,6 +109,34 @@ pub struct CardProperties {
     /// Queries the base toughness value for a card. This is added to other
     /// modifiers to compute a final power value.
     pub base_toughness: CardProperty<Ints<Toughness>>,
+
+    /// Memoized results of queries which walk the properties above.
+    pub cache: QueryCache,
+}
+
+impl CardProperties {
+    /// Removes all effect modifiers to this card's properties which were
+    /// created by the [EventId] event.
+    pub fn remove_effects(&mut self, event_id: EventId) {
+        self.tags.remove_effects(event_id);
+        self.can_attack_target.remove_effects(event_id);
+        self.can_be_blocked.remove_effects(event_id);
+        self.can_be_targeted.remove_effects(event_id);
+        self.can_attack_same_turn.remove_effects(event_id);
+        self.can_lose_game.remove_effects(event_id);
+        self.can_win_game.remove_effects(event_id);
+        self.life_gain.remove_effects(event_id);
+        self.counters_placed.remove_effects(event_id);
+        self.colors.remove_effects(event_id);
+        self.creature_types.remove_effects(event_id);
+        self.land_types.remove_effects(event_id);
+        self.change_land_type_text.remove_effects(event_id);
+        self.change_color_text.remove_effects(event_id);
+        self.power.remove_effects(event_id);
+        self.base_power.remove_effects(event_id);
+        self.toughness.remove_effects(event_id);
+        self.base_toughness.remove_effects(event_id);
+    }
 }
 
 impl Debug for CardProperties {
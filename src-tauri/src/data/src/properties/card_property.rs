@@ -16,20 +16,20 @@ use std::marker::PhantomData;
 use std::ops::Add;
 
 use enumset::{EnumSet, EnumSetType};
-use primitives::game_primitives::{HasSource, Source, Timestamp};
+use primitives::game_primitives::{EventId, HasSource, Source, Timestamp};
 use utils::outcome;
 use utils::outcome::Outcome;
 
 use crate::core::ability_scope::AbilityScope;
 use crate::core::layer::{EffectSortingKey, Layer};
-use crate::core::modifier_data::ModifierMode;
+use crate::core::modifier_data::{ConditionFn, ModifierMode};
 use crate::core::rule_type::RuleType;
 use crate::events::event_context::EventContext;
 use crate::game_states::game_state::GameState;
-use crate::properties::card_modifier::CardModifier;
+use crate::properties::card_modifier::{CardModifier, ModifierCondition};
 use crate::properties::duration::Duration;
 use crate::properties::flag::Flag;
-use crate::properties::property_value::{ChangeText, EnumSets, Ints, PropertyValue};
+use crate::properties::property_value::{ChangeText, EnumSets, Ints, PropertyValue, Replacement};
 
 /// Represents a permanent card losing all its current abilities as of a given
 /// [Timestamp].
@@ -58,34 +58,85 @@ impl<TModifier: PropertyValue> CardProperty<TModifier> {
     pub fn add_ability(&mut self, scope: AbilityScope, effect: TModifier) {
         self.add(CardModifier {
             source: Source::Game,
+            event_id: None,
             duration: Duration::Continuous,
             rule_type: RuleType::Ability(scope.ability_id.card_id),
             effect,
+            condition: None,
+            zones: scope.zones,
+        });
+    }
+
+    /// Adds a printed ability modifier to this card which only applies while
+    /// the provided condition holds.
+    ///
+    /// See [ConditionFn].
+    pub fn add_ability_while(
+        &mut self,
+        scope: AbilityScope,
+        condition: ConditionFn,
+        effect: TModifier,
+    ) {
+        self.add(CardModifier {
+            source: Source::Ability(scope.ability_id),
+            event_id: None,
+            duration: Duration::Continuous,
+            rule_type: RuleType::Ability(scope.ability_id.card_id),
+            effect,
+            condition: Some(ModifierCondition {
+                ability_id: scope.ability_id,
+                predicate: condition,
+            }),
+            zones: scope.zones,
         });
     }
 
     /// Applies an effect modifier to this card for a given [Duration].
     pub fn add_effect(
         &mut self,
-        source: impl HasSource,
+        context: EventContext,
         duration: Duration,
         modifier: TModifier,
     ) -> Outcome {
         self.add(CardModifier {
-            source: source.source(),
+            source: context.source(),
+            event_id: Some(context.event_id),
             duration,
             rule_type: RuleType::Effect,
             effect: modifier,
+            condition: None,
+            zones: EnumSet::all(),
         });
         outcome::OK
     }
 
+    /// Returns an iterator over modifiers to this property which currently
+    /// apply to the game.
+    ///
+    /// This is primarily intended for debugging and for explaining the results
+    /// of queries to the user.
+    pub fn active_modifiers<'a>(
+        &'a self,
+        game: &'a GameState,
+    ) -> impl Iterator<Item = &'a CardModifier<TModifier>> + 'a {
+        self.modifiers.iter().filter(move |modifier| modifier.active(game))
+    }
+
+    /// Removes all effect modifiers to this property which were created by the
+    /// [EventId] event.
+    pub fn remove_effects(&mut self, event_id: EventId) {
+        self.modifiers.retain(|modifier| modifier.event_id != Some(event_id));
+    }
+
     /// Adds a modifier to this card with a given [ModifierMode].
     pub fn add_with_mode(&mut self, mode: ModifierMode, modifier: TModifier) -> Outcome {
         match mode {
             ModifierMode::PrintedAbility(scope) => self.add_ability(scope, modifier),
+            ModifierMode::PrintedAbilityWhile(scope, condition) => {
+                self.add_ability_while(scope, condition, modifier)
+            }
             ModifierMode::Effect(context, _, duration) => {
-                self.add_effect(context, duration, modifier);
+                self.add_effect(context, duration, modifier)?;
             }
         }
         outcome::OK
@@ -196,6 +247,24 @@ impl<T: Default + Copy + Add<Output = T>> CardProperty<Ints<T>> {
     }
 }
 
+impl<TArg, T> CardProperty<Replacement<TArg, T>> {
+    /// Applies each active modifier to `current` in the order in which they
+    /// were added, returning the resulting value.
+    #[must_use]
+    pub fn query_with(&self, game: &GameState, _: Source, arg: &TArg, current: T) -> T {
+        let mut result = current;
+        for modifier in &self.modifiers {
+            if !modifier.active(game) {
+                continue;
+            }
+
+            result = modifier.effect.0.invoke(game, modifier.source, arg, result);
+        }
+
+        result
+    }
+}
+
 impl<TResult: EnumSetType> CardProperty<ChangeText<TResult>> {
     #[must_use]
     pub fn query(&self, game: &GameState, _: Source, current: TResult) -> TResult {
@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::CardId;
+use primitives::game_primitives::{CardId, PermanentId, PlayerName, Source};
 
+use crate::card_states::counters::CounterType;
 use crate::card_states::zones::{HasZones, ToCardId};
 use crate::game_states::combat_state::{AttackTarget, AttackerId, BlockerId};
 
@@ -41,3 +42,70 @@ impl ToCardId for CanBeBlocked {
         self.attacker_id.to_card_id(zones)
     }
 }
+
+/// Argument for queries about whether a card can be the target of a spell or
+/// ability.
+#[derive(Debug, Clone, Copy)]
+pub struct CanBeTargeted {
+    /// Card which would be targeted
+    pub card_id: CardId,
+
+    /// Spell or ability which would target this card
+    pub source: Source,
+}
+
+impl ToCardId for CanBeTargeted {
+    fn to_card_id(&self, _: &impl HasZones) -> Option<CardId> {
+        Some(self.card_id)
+    }
+}
+
+/// Argument for queries about whether a player can win or lose the game.
+///
+/// These queries are evaluated against every permanent on the battlefield, with
+/// `permanent_id` identifying the permanent being checked.
+#[derive(Debug, Clone, Copy)]
+pub struct GameResultQuery {
+    pub permanent_id: PermanentId,
+    pub player: PlayerName,
+}
+
+impl ToCardId for GameResultQuery {
+    fn to_card_id(&self, zones: &impl HasZones) -> Option<CardId> {
+        self.permanent_id.to_card_id(zones)
+    }
+}
+
+/// Argument for queries about how much life a player would gain.
+///
+/// These queries are evaluated against every permanent on the battlefield, with
+/// `permanent_id` identifying the permanent being checked.
+#[derive(Debug, Clone, Copy)]
+pub struct LifeGainQuery {
+    pub permanent_id: PermanentId,
+    pub player: PlayerName,
+}
+
+impl ToCardId for LifeGainQuery {
+    fn to_card_id(&self, zones: &impl HasZones) -> Option<CardId> {
+        self.permanent_id.to_card_id(zones)
+    }
+}
+
+/// Argument for queries about how many counters would be put on a permanent.
+///
+/// These queries are evaluated against every permanent on the battlefield, with
+/// `permanent_id` identifying the permanent being checked and `recipient`
+/// identifying the permanent which would receive the counters.
+#[derive(Debug, Clone, Copy)]
+pub struct CountersPlacedQuery {
+    pub permanent_id: PermanentId,
+    pub recipient: PermanentId,
+    pub counter: CounterType,
+}
+
+impl ToCardId for CountersPlacedQuery {
+    fn to_card_id(&self, zones: &impl HasZones) -> Option<CardId> {
+        self.permanent_id.to_card_id(zones)
+    }
+}
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use primitives::game_primitives::{HasObjectId, PermanentId, SpellId, Zone};
+use primitives::game_primitives::{
+    HasController, HasObjectId, PermanentId, PlayerName, SpellId, Zone,
+};
 
 use crate::card_states::zones::ZoneQueries;
 use crate::game_states::game_phase_step::GamePhaseStep;
 use crate::game_states::game_state::{GameState, TurnData};
+use crate::player_states::player_state::PlayerQueries;
 
 /// Controls how long an effect should apply to the game.
 #[derive(Clone, Copy)]
@@ -24,9 +27,26 @@ pub enum Duration {
     /// Effect applies until the end of the game
     Continuous,
 
+    /// Effect applies until the end of the [TurnData] turn.
+    ThisTurn(TurnData),
+
     /// Effect applies while the [PermanentId] permanent is on the battlefield.
     WhileOnBattlefield(PermanentId),
 
+    /// Effect applies until the [PlayerName] player's next turn after the
+    /// [TurnData] turn begins, e.g. "until your next turn".
+    UntilPlayersNextTurn(PlayerName, TurnData),
+
+    /// Effect applies while the [PermanentId] permanent is on the battlefield
+    /// and controlled by the [PlayerName] player, e.g. "for as long as you
+    /// control this permanent".
+    WhileControlledBy(PermanentId, PlayerName),
+
+    /// Effect applies while the first [PermanentId] permanent is attached to
+    /// the second [PermanentId] permanent, e.g. an effect which lasts "for as
+    /// long as this Aura remains attached to enchanted creature".
+    WhileAttachedTo(PermanentId, PermanentId),
+
     /// Effect applies while the [SpellId] spell is on the stack.
     WhileOnStack(SpellId),
 
@@ -49,7 +69,18 @@ impl Duration {
     fn is_active_helper(&self, game: &GameState) -> Option<bool> {
         Some(match self {
             Duration::Continuous => true,
+            Duration::ThisTurn(turn) => game.turn == *turn && game.step != GamePhaseStep::Cleanup,
             Duration::WhileOnBattlefield(permanent_id) => game.has_card(*permanent_id),
+            Duration::UntilPlayersNextTurn(player, turn) => {
+                game.player(*player).last_turn.is_none_or(|last| last <= *turn)
+            }
+            Duration::WhileControlledBy(permanent_id, player) => {
+                game.card(*permanent_id)?.controller() == *player
+            }
+            Duration::WhileAttachedTo(attached_id, permanent_id) => {
+                let attached_to = game.card(*attached_id)?.attached_to;
+                attached_to == Some(game.card(*permanent_id)?.entity_id())
+            }
             Duration::WhileOnStack(spell_id) => game.has_card(*spell_id),
             Duration::WhileOnStackOrBattlefieldThisTurn(spell_id, turn) => {
                 let exists = game.has_card(*spell_id)
@@ -15,10 +15,12 @@
 use std::ops::Add;
 
 use enumset::{EnumSet, EnumSetType};
-use primitives::game_primitives::{Timestamp, PRINTED_TEXT_TIMESTAMP};
+use primitives::game_primitives::{Source, Timestamp, PRINTED_TEXT_TIMESTAMP};
 
+use crate::core::function_types::Transformation;
 use crate::core::layer::{EffectSortingKey, Layer};
 use crate::core::modifier_data::ModifierMode;
+use crate::game_states::game_state::GameState;
 
 /// Marker trait for the return value of queries
 pub trait PropertyValue {
@@ -113,3 +115,24 @@ impl<T: EnumSetType> PropertyValue for ChangeText<T> {
         }
     }
 }
+
+/// Modifies a value via a function, e.g. to implement replacement effects
+/// such as "If you would gain life, you gain twice that much life instead."
+///
+/// Modifiers are applied in the order in which they were added.
+#[derive(Clone)]
+pub struct Replacement<TArg: 'static, T: 'static>(pub Box<dyn Transformation<TArg, T>>);
+
+impl<TArg: 'static, T: 'static> Replacement<TArg, T> {
+    pub fn new(
+        function: impl Fn(&GameState, Source, &TArg, T) -> T + Copy + Send + Sync + 'static,
+    ) -> Replacement<TArg, T> {
+        Self(Box::new(function))
+    }
+}
+
+impl<TArg, T> PropertyValue for Replacement<TArg, T> {
+    fn effect_sorting_key(&self) -> Option<EffectSortingKey> {
+        None
+    }
+}
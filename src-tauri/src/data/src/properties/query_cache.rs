@@ -0,0 +1,130 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use enumset::EnumSet;
+use primitives::game_primitives::{CardType, Source};
+
+use crate::core::numerics::{Power, Toughness};
+
+/// Identifies a version of the game state for the purpose of caching property
+/// queries.
+///
+/// This is incremented via
+/// [crate::game_states::game_state::GameState::invalidate_query_caches]
+/// whenever the game is mutated in a way which could change the result of a
+/// property query, e.g. whenever a card or player is mutably accessed.
+pub type EffectsGeneration = u64;
+
+/// Memoizes the results of expensive property queries for a card.
+///
+/// Cached values are only returned if they were computed during the current
+/// [EffectsGeneration]. Cloning a [QueryCache] produces an empty cache.
+#[derive(Default)]
+pub struct QueryCache {
+    entries: Mutex<CacheEntries>,
+}
+
+#[derive(Default)]
+struct CacheEntries {
+    generation: EffectsGeneration,
+    power: HashMap<Source, Power>,
+    toughness: HashMap<Source, Toughness>,
+    card_types: HashMap<Source, EnumSet<CardType>>,
+}
+
+impl CacheEntries {
+    /// Discards all entries if they were not computed during `generation`.
+    fn validate(&mut self, generation: EffectsGeneration) {
+        if self.generation != generation {
+            self.generation = generation;
+            self.power.clear();
+            self.toughness.clear();
+            self.card_types.clear();
+        }
+    }
+}
+
+impl QueryCache {
+    /// Returns the cached power of this card, or computes it via `compute`.
+    pub fn power(
+        &self,
+        generation: EffectsGeneration,
+        source: Source,
+        compute: impl FnOnce() -> Option<Power>,
+    ) -> Option<Power> {
+        self.get_or_compute(generation, source, |e| &mut e.power, compute)
+    }
+
+    /// Returns the cached toughness of this card, or computes it via `compute`.
+    pub fn toughness(
+        &self,
+        generation: EffectsGeneration,
+        source: Source,
+        compute: impl FnOnce() -> Option<Toughness>,
+    ) -> Option<Toughness> {
+        self.get_or_compute(generation, source, |e| &mut e.toughness, compute)
+    }
+
+    /// Returns the cached card types of this card, or computes them via
+    /// `compute`.
+    pub fn card_types(
+        &self,
+        generation: EffectsGeneration,
+        source: Source,
+        compute: impl FnOnce() -> Option<EnumSet<CardType>>,
+    ) -> Option<EnumSet<CardType>> {
+        self.get_or_compute(generation, source, |e| &mut e.card_types, compute)
+    }
+
+    /// Looks up a value in the cache, computing and storing it if it is not
+    /// present.
+    ///
+    /// The lock is not held while `compute` runs, since queries frequently
+    /// query other properties of the same card.
+    fn get_or_compute<T: Copy>(
+        &self,
+        generation: EffectsGeneration,
+        source: Source,
+        map: impl Fn(&mut CacheEntries) -> &mut HashMap<Source, T>,
+        compute: impl FnOnce() -> Option<T>,
+    ) -> Option<T> {
+        let cached = self.with_entries(generation, |e| map(e).get(&source).copied());
+        if cached.is_some() {
+            return cached;
+        }
+
+        let value = compute()?;
+        self.with_entries(generation, |e| map(e).insert(source, value));
+        Some(value)
+    }
+
+    fn with_entries<T>(
+        &self,
+        generation: EffectsGeneration,
+        function: impl FnOnce(&mut CacheEntries) -> T,
+    ) -> T {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.validate(generation);
+        function(&mut entries)
+    }
+}
+
+impl Clone for QueryCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Identifies a language in which user interface text can be displayed.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Ord,
+    PartialOrd,
+    Sequence,
+    Serialize,
+    Deserialize,
+    Type,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum LanguageId {
+    #[default]
+    English,
+    French,
+    German,
+    Italian,
+    Japanese,
+    Korean,
+    Portuguese,
+    SimplifiedChinese,
+    Spanish,
+}
+
+impl LanguageId {
+    /// IETF language tag for this language, used to locate its language pack.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LanguageId::English => "en",
+            LanguageId::French => "fr",
+            LanguageId::German => "de",
+            LanguageId::Italian => "it",
+            LanguageId::Japanese => "ja",
+            LanguageId::Korean => "ko",
+            LanguageId::Portuguese => "pt",
+            LanguageId::SimplifiedChinese => "zh-Hans",
+            LanguageId::Spanish => "es",
+        }
+    }
+
+    /// Name of this language, written in that language.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            LanguageId::English => "English",
+            LanguageId::French => "Français",
+            LanguageId::German => "Deutsch",
+            LanguageId::Italian => "Italiano",
+            LanguageId::Japanese => "日本語",
+            LanguageId::Korean => "한국어",
+            LanguageId::Portuguese => "Português",
+            LanguageId::SimplifiedChinese => "简体中文",
+            LanguageId::Spanish => "Español",
+        }
+    }
+}
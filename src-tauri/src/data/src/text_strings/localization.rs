@@ -0,0 +1,102 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use utils::paths;
+
+use crate::text_strings::language_id::LanguageId;
+use crate::text_strings::Text;
+
+/// Name of the directory within the data directory from which language packs
+/// are loaded.
+const LANGUAGES_DIRECTORY: &str = "languages";
+
+/// Localized string templates for a single language, keyed by [Text::key].
+///
+/// Templates may contain positional placeholders such as `{0}` which are
+/// replaced with the corresponding value from [Text::arguments].
+pub type StringTable = HashMap<String, String>;
+
+/// Language packs which have been loaded so far. A value of None indicates
+/// that no valid pack exists for that language.
+static TABLES: Lazy<DashMap<LanguageId, Option<Arc<StringTable>>>> = Lazy::new(DashMap::new);
+
+/// Path to the language pack file for the given language.
+///
+/// Each language pack is a JSON object mapping [Text::key] values to string
+/// templates, stored in the `languages` directory of the data directory and
+/// named after the [LanguageId::code] of its language, e.g. `fr.json`.
+pub fn path(language: LanguageId) -> PathBuf {
+    paths::get_data_dir().join(LANGUAGES_DIRECTORY).join(format!("{}.json", language.code()))
+}
+
+/// Returns true if user interface text can be displayed in the given
+/// language.
+///
+/// English is always available, since it is used as the fallback for strings
+/// which are missing from a language pack.
+pub fn is_available(language: LanguageId) -> bool {
+    language == LanguageId::English || table(language).is_some()
+}
+
+/// Returns the provided [Text] rendered in the given language.
+///
+/// Falls back to the English text if no language pack is available or the
+/// pack does not contain an entry for this string.
+pub fn localize(text: Text, language: LanguageId) -> String {
+    let Some(template) = table(language).and_then(|t| t.get(text.key()).cloned()) else {
+        return text.to_string();
+    };
+    format(&template, &text.arguments())
+}
+
+/// Replaces positional placeholders like `{0}` in `template` with the
+/// corresponding `arguments`.
+fn format(template: &str, arguments: &[String]) -> String {
+    arguments.iter().enumerate().fold(template.to_string(), |result, (i, argument)| {
+        result.replace(&format!("{{{i}}}"), argument)
+    })
+}
+
+fn table(language: LanguageId) -> Option<Arc<StringTable>> {
+    TABLES.entry(language).or_insert_with(|| load(language)).clone()
+}
+
+fn load(language: LanguageId) -> Option<Arc<StringTable>> {
+    let path = path(language);
+    if !path.exists() {
+        return None;
+    }
+
+    let result = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| serde_json::from_str::<StringTable>(&json).map_err(|e| e.to_string()));
+    match result {
+        Ok(table) => {
+            info!("Loaded {} strings for {:?} from {:?}", table.len(), language, path);
+            Some(Arc::new(table))
+        }
+        Err(e) => {
+            warn!("Error loading language pack {:?}: {}", path, e);
+            None
+        }
+    }
+}
@@ -12,28 +12,168 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod language_id;
+pub mod localization;
+
 use std::fmt::{Display, Formatter};
 
 use either::Either;
-use primitives::game_primitives::Color;
+use primitives::game_primitives::{Color, PlayerName, Zone};
 use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
 
 use crate::card_states::play_card_plan::ModalChoice;
+use crate::core::numerics::TurnNumber;
+use crate::game_states::game_phase_step::GamePhaseStep;
+use crate::game_states::resolution_step::ResolutionStep;
 use crate::printed_cards::card_subtypes::LandType;
 
 /// Canonical text displayed in the user interface, suitable for localization
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///
+/// The [Display] implementation for this type produces English text. See
+/// [localization::localize] for other languages.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntoStaticStr)]
 pub enum Text {
     HandToTopOfLibraryPrompt,
     SelectNumber,
     SelectMode,
     SelectTarget,
     SelectNewTargets,
+    DivideAmongTargets,
+    ResolutionStep(ResolutionStep),
     ModalChoice(ModalChoice),
     Color(Color),
     LandSubtype(LandType),
     SelectTypeToChange,
     SelectNewType,
+    PayManaCost,
+    ExertPrompt,
+    Exert,
+    DoNotExert,
+    SelectUnblockedAttacker,
+    PayKickerPrompt,
+    PayKicker,
+    DoNotPayKicker,
+    CastWithoutPayingPrompt,
+    CastSpell,
+    DoNotCast,
+    SelectProtector,
+    SelectProliferate,
+    MutatePrompt,
+    PayMutate,
+    DoNotPayMutate,
+    SelectMutateTarget,
+    MergePositionPrompt,
+    MergeOnTop,
+    MergeOnBottom,
+    CallCoinFlip,
+    Heads,
+    Tails,
+    PlanarBlank,
+    PlanarChaos,
+    Planeswalk,
+    RollPlanarDie(u32),
+    ClashPrompt,
+    LeaveOnTop,
+    PutOnBottom,
+    LeaveGame,
+    DebugPanel,
+    SettingsPanel,
+    KnownCards,
+    Undo,
+    FullControl,
+    ToDraw,
+    ToMain,
+    Continue,
+    ToAttackers,
+    ToBlockers,
+    ToDamage,
+    EndCombat,
+    EndTurn,
+    NextTurn,
+    Resolve,
+    ConfirmAttackers(usize),
+    ConfirmBlockers(usize),
+    ConfirmOrder,
+    HoldingPriority,
+    FullControlEnabled,
+    QueuedActions(usize),
+    ClearQueue,
+    PickEntity,
+    Submit,
+    SelectBetween(usize, usize),
+    DistributedTotal(u32, u32),
+    Pay,
+    AutoTap,
+    SetNumber(u32),
+    GameStatus(GamePhaseStep, TurnNumber, PlayerName),
+    NotYourPriority,
+    NotInPlayableZone,
+    RequiresSorceryTiming,
+    NoLandPlaysRemaining,
+    NoValidTargets,
+    InsufficientMana,
+    SummoningSickness,
+    IsTapped,
+    NotACreature,
+    NotDeclaringAttackers,
+    RestrictedByEffect,
+    Zone(Zone),
+    Player(PlayerName),
+    YourZone(Zone),
+    PlayersZone(PlayerName, Zone),
+    YourLife,
+    PlayersLife(PlayerName),
+}
+
+impl Text {
+    /// Returns true if this text labels a choice to decline an optional
+    /// effect.
+    pub fn is_decline(&self) -> bool {
+        matches!(
+            self,
+            Text::DoNotExert | Text::DoNotPayKicker | Text::DoNotCast | Text::DoNotPayMutate
+        )
+    }
+
+    /// Stable identifier for this string, used to look it up in language
+    /// packs.
+    pub fn key(&self) -> &'static str {
+        self.into()
+    }
+
+    /// Values to substitute into the positional placeholders of a localized
+    /// string template for this text.
+    pub fn arguments(&self) -> Vec<String> {
+        match self {
+            Text::ModalChoice(choice) => vec![choice.to_string()],
+            Text::ResolutionStep(step) => vec![step.to_string()],
+            Text::Color(color) => vec![color.to_string()],
+            Text::LandSubtype(subtype) => vec![subtype.to_string()],
+            Text::SelectBetween(minimum, maximum) => {
+                vec![minimum.to_string(), maximum.to_string()]
+            }
+            Text::DistributedTotal(assigned, total) => {
+                vec![assigned.to_string(), total.to_string()]
+            }
+            Text::ConfirmAttackers(count) | Text::ConfirmBlockers(count) => {
+                vec![count.to_string()]
+            }
+            Text::SetNumber(n) | Text::RollPlanarDie(n) => vec![n.to_string()],
+            Text::QueuedActions(count) => vec![count.to_string()],
+            Text::GameStatus(step, turn, player) => {
+                vec![format!("{:?}", step), turn.to_string(), format!("{:?}", player)]
+            }
+            Text::Zone(zone) => vec![format!("{:?}", zone)],
+            Text::Player(player) => vec![format!("{:?}", player)],
+            Text::YourZone(zone) => vec![Text::Zone(*zone).to_string()],
+            Text::PlayersZone(player, zone) => {
+                vec![Text::Player(*player).to_string(), Text::Zone(*zone).to_string()]
+            }
+            Text::PlayersLife(player) => vec![Text::Player(*player).to_string()],
+            _ => vec![],
+        }
+    }
 }
 
 impl<T: Into<Text>, U: Into<Text>> From<Either<T, U>> for Text {
@@ -61,11 +201,124 @@ impl Display for Text {
             Text::SelectMode => write!(f, "Select mode"),
             Text::SelectTarget => write!(f, "Select target"),
             Text::SelectNewTargets => write!(f, "Select new target"),
+            Text::DivideAmongTargets => write!(f, "Divide among targets"),
+            Text::ResolutionStep(step) => write!(f, "{step}"),
             Text::ModalChoice(choice) => write!(f, "Mode {}", choice),
             Text::Color(color) => write!(f, "{}", color),
             Text::LandSubtype(subtype) => write!(f, "{}", subtype),
             Text::SelectTypeToChange => write!(f, "Select type to change"),
             Text::SelectNewType => write!(f, "Select new type"),
+            Text::PayManaCost => write!(f, "Choose lands to tap"),
+            Text::ExertPrompt => write!(f, "Exert attacking creature?"),
+            Text::Exert => write!(f, "Exert"),
+            Text::DoNotExert => write!(f, "Don't exert"),
+            Text::SelectUnblockedAttacker => write!(f, "Select unblocked attacker to return"),
+            Text::PayKickerPrompt => write!(f, "Pay kicker cost?"),
+            Text::PayKicker => write!(f, "Pay kicker"),
+            Text::DoNotPayKicker => write!(f, "Don't pay kicker"),
+            Text::CastWithoutPayingPrompt => write!(f, "Cast without paying its mana cost?"),
+            Text::CastSpell => write!(f, "Cast"),
+            Text::DoNotCast => write!(f, "Don't cast"),
+            Text::SelectProtector => write!(f, "Select protector for battle"),
+            Text::SelectProliferate => write!(f, "Choose permanents and players to proliferate"),
+            Text::MutatePrompt => write!(f, "Pay mutate cost?"),
+            Text::PayMutate => write!(f, "Mutate"),
+            Text::DoNotPayMutate => write!(f, "Cast normally"),
+            Text::SelectMutateTarget => write!(f, "Select creature to mutate onto"),
+            Text::MergePositionPrompt => write!(f, "Put mutating creature on top or bottom?"),
+            Text::MergeOnTop => write!(f, "On top"),
+            Text::MergeOnBottom => write!(f, "On bottom"),
+            Text::CallCoinFlip => write!(f, "Call the coin flip"),
+            Text::Heads => write!(f, "Heads"),
+            Text::Tails => write!(f, "Tails"),
+            Text::PlanarBlank => write!(f, "Blank"),
+            Text::PlanarChaos => write!(f, "Chaos"),
+            Text::Planeswalk => write!(f, "Planeswalk"),
+            Text::RollPlanarDie(0) => write!(f, "Roll Planar Die"),
+            Text::RollPlanarDie(cost) => write!(f, "Roll Planar Die ({})", cost),
+            Text::ClashPrompt => {
+                write!(f, "Put the revealed card on the bottom of your library?")
+            }
+            Text::LeaveOnTop => write!(f, "Leave on top"),
+            Text::PutOnBottom => write!(f, "Put on bottom"),
+            Text::LeaveGame => write!(f, "Leave Game"),
+            Text::DebugPanel => write!(f, "Debug"),
+            Text::SettingsPanel => write!(f, "Settings"),
+            Text::KnownCards => write!(f, "Known Cards"),
+            Text::Undo => write!(f, "Undo"),
+            Text::FullControl => write!(f, "Full Control"),
+            Text::ToDraw => write!(f, "To Draw"),
+            Text::ToMain => write!(f, "To Main"),
+            Text::Continue => write!(f, "Continue"),
+            Text::ToAttackers => write!(f, "To Attackers"),
+            Text::ToBlockers => write!(f, "To Blockers"),
+            Text::ToDamage => write!(f, "To Damage"),
+            Text::EndCombat => write!(f, "End Combat"),
+            Text::EndTurn => write!(f, "End Turn"),
+            Text::NextTurn => write!(f, "Next Turn"),
+            Text::Resolve => write!(f, "Resolve"),
+            Text::ConfirmAttackers(count) => {
+                write!(f, "{} Attacker{}", count, if *count == 1 { "" } else { "s" })
+            }
+            Text::ConfirmBlockers(count) => {
+                write!(f, "{} Blocker{}", count, if *count == 1 { "" } else { "s" })
+            }
+            Text::ConfirmOrder => write!(f, "Confirm Order"),
+            Text::HoldingPriority => write!(f, "Holding Priority"),
+            Text::FullControlEnabled => write!(f, "Full Control Enabled"),
+            Text::QueuedActions(count) => {
+                write!(f, "{} Queued Action{}", count, if *count == 1 { "" } else { "s" })
+            }
+            Text::ClearQueue => write!(f, "Clear Queue"),
+            Text::PickEntity => write!(f, "Pick Entity"),
+            Text::Submit => write!(f, "Submit"),
+            Text::SelectBetween(minimum, maximum) if minimum == maximum => {
+                write!(f, "Select {minimum}")
+            }
+            Text::SelectBetween(minimum, maximum) => {
+                write!(f, "Select {minimum} to {maximum}")
+            }
+            Text::DistributedTotal(assigned, total) => {
+                write!(f, "Assigned {assigned} of {total}")
+            }
+            Text::Pay => write!(f, "Pay"),
+            Text::AutoTap => write!(f, "Auto Tap"),
+            Text::SetNumber(n) => write!(f, "Set {}", n),
+            Text::GameStatus(step, turn, player) => {
+                write!(f, "{:?}\nTurn {}\nPlayer {:?}", step, turn, player)
+            }
+            Text::NotYourPriority => write!(f, "You can't act right now"),
+            Text::NotInPlayableZone => write!(f, "This card can't be played from here"),
+            Text::RequiresSorceryTiming => {
+                write!(f, "Only during your main phase while the stack is empty")
+            }
+            Text::NoLandPlaysRemaining => write!(f, "You've already played a land this turn"),
+            Text::NoValidTargets => write!(f, "No valid targets"),
+            Text::InsufficientMana => write!(f, "Not enough mana"),
+            Text::SummoningSickness => {
+                write!(f, "Not controlled continuously since your turn began")
+            }
+            Text::IsTapped => write!(f, "Tapped"),
+            Text::NotACreature => write!(f, "Not a creature"),
+            Text::NotDeclaringAttackers => write!(f, "Attackers are not being declared"),
+            Text::RestrictedByEffect => write!(f, "Prevented by an effect"),
+            Text::Zone(zone) => match zone {
+                Zone::Hand => write!(f, "Hand"),
+                Zone::Graveyard => write!(f, "Graveyard"),
+                Zone::Library => write!(f, "Library"),
+                Zone::Battlefield => write!(f, "Battlefield"),
+                Zone::Stack => write!(f, "Stack"),
+                Zone::Exiled => write!(f, "Exile"),
+                Zone::Command => write!(f, "Command Zone"),
+                Zone::OutsideTheGame => write!(f, "Outside the Game"),
+            },
+            Text::Player(player) => write!(f, "Player {:?}", player),
+            Text::YourZone(zone) => write!(f, "Your {}", Text::Zone(*zone)),
+            Text::PlayersZone(player, zone) => {
+                write!(f, "{}'s {}", Text::Player(*player), Text::Zone(*zone))
+            }
+            Text::YourLife => write!(f, "Your life"),
+            Text::PlayersLife(player) => write!(f, "{}'s life", Text::Player(*player)),
         }
     }
 }
@@ -0,0 +1,48 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, SystemTime};
+
+use primitives::game_primitives::GameId;
+use serde::{Deserialize, Serialize};
+
+use crate::core::numerics::TurnNumber;
+use crate::decks::deck_name::DeckName;
+use crate::users::user_statistics::GameResult;
+
+/// Summary of a finished game, stored in a user's match history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchRecord {
+    pub game_id: GameId,
+
+    /// Deck the user played in this game
+    pub deck: DeckName,
+
+    /// Deck the user's opponent played in this game
+    pub opponent_deck: DeckName,
+
+    /// Description of the user's opponent, e.g. the type of AI agent
+    pub opponent: String,
+
+    pub result: GameResult,
+
+    /// Number of turns taken over the course of the game
+    pub turns: TurnNumber,
+
+    /// Time elapsed between the start and end of the game, if known
+    pub duration: Option<Duration>,
+
+    /// Time at which the game ended
+    pub finished_at: SystemTime,
+}
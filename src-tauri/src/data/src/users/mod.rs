@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod match_record;
 pub mod user_state;
+pub mod user_statistics;
@@ -15,6 +15,13 @@
 use primitives::game_primitives::{GameId, UserId};
 use serde::Deserialize;
 use slotmap::__impl::Serialize;
+
+use crate::campaign::campaign_progress::CampaignProgress;
+use crate::decks::deck_name::DeckName;
+use crate::game_states::game_state::RulesConfiguration;
+use crate::player_states::player_options::PlayerOptions;
+use crate::users::user_statistics::UserStatistics;
+
 /// Holds state for a user.
 ///
 /// A 'user' is an operator of this software outside of the context of any game.
@@ -25,6 +32,33 @@ pub struct UserState {
     pub id: UserId,
     /// Current game activity of this user
     pub activity: UserActivity,
+    /// Priority stops and other options this user has configured, applied to
+    /// every game they play in.
+    #[serde(default)]
+    pub options: PlayerOptions,
+    /// Progress this user has made in the single-player campaign.
+    #[serde(default)]
+    pub campaign: CampaignProgress,
+    /// Name this user has chosen to display to other players, if any.
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Lifetime game statistics for this user.
+    #[serde(default)]
+    pub statistics: UserStatistics,
+    /// House rules applied to new non-campaign games this user creates.
+    #[serde(default)]
+    pub house_rules: RulesConfiguration,
+    /// Deck this user has selected from the deck catalog to play with in
+    /// campaign games, if any.
+    #[serde(default)]
+    pub selected_deck: Option<DeckName>,
+}
+
+impl UserState {
+    /// Returns the name to display for this user.
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or("Player")
+    }
 }
 
 /// Represents the current game activity a user is participating in
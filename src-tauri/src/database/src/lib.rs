@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod rulings;
 pub mod sqlite_database;
+pub mod users;
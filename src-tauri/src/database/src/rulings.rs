@@ -0,0 +1,55 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::Path;
+
+use data::printed_cards::card_ruling::CardRuling;
+use serde::Deserialize;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::sqlite_database::SqliteDatabase;
+
+/// A ruling as it appears in the Scryfall 'Rulings' bulk data file.
+///
+/// See <https://scryfall.com/docs/api/rulings>.
+#[derive(Debug, Clone, Deserialize)]
+struct ScryfallRuling {
+    /// Oracle ID of the card this ruling applies to, shared by every printing
+    /// of the card.
+    oracle_id: Uuid,
+
+    /// Date this ruling was published, in YYYY-MM-DD format.
+    published_at: String,
+
+    /// Text of the ruling.
+    comment: String,
+}
+
+/// Imports rulings from a Scryfall 'Rulings' bulk data file, making them
+/// available via [SqliteDatabase::fetch_rulings].
+///
+/// Rulings which have already been imported are skipped. Returns the number of
+/// new rulings written.
+pub fn import_scryfall(database: &SqliteDatabase, path: &Path) -> Result<usize, String> {
+    let json = fs::read_to_string(path).map_err(|e| format!("Error reading {path:?}: {e}"))?;
+    let rulings: Vec<ScryfallRuling> =
+        serde_json::from_str(&json).map_err(|e| format!("Error parsing {path:?}: {e}"))?;
+    let count = database.write_rulings(rulings.into_iter().map(|ruling| {
+        (ruling.oracle_id, CardRuling { date: ruling.published_at, text: ruling.comment })
+    }));
+    info!(?path, count, "Imported Scryfall rulings");
+    Ok(count)
+}
@@ -15,13 +15,20 @@
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex, MutexGuard};
 
+use data::card_definitions::card_name::CardName;
+use data::game_states::chat_log::ChatLog;
 use data::game_states::serialized_game_state::SerializedGameState;
-use data::printed_cards::database_card::DatabaseCardFace;
+use data::printed_cards::card_ruling::CardRuling;
+use data::printed_cards::database_card::{
+    DatabaseCardFace, DatabaseCardPrinting, DatabaseOracleText,
+};
 use data::printed_cards::printed_card_id::PrintedCardId;
+use data::users::match_record::MatchRecord;
 use data::users::user_state::UserState;
 use primitives::game_primitives::{GameId, UserId};
 use rusqlite::{Connection, Error, OptionalExtension};
 use serde_json::{de, ser};
+use uuid::Uuid;
 
 /// SQLite database connection.
 ///
@@ -71,6 +78,46 @@ impl SqliteDatabase {
                 (),
             )
             .expect("Error creating table");
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS local_user (
+                   id       INTEGER PRIMARY KEY CHECK (id = 0),
+                   user_id  BLOB
+                ) STRICT;",
+                (),
+            )
+            .expect("Error creating table");
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS match_history (
+                   game_id  BLOB PRIMARY KEY,
+                   user_id  BLOB NOT NULL,
+                   data     BLOB NOT NULL
+                ) STRICT;",
+                (),
+            )
+            .expect("Error creating table");
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS chats (
+                   game_id  BLOB PRIMARY KEY REFERENCES games(id) ON DELETE CASCADE,
+                   data     BLOB
+                ) STRICT;",
+                (),
+            )
+            .expect("Error creating table");
+
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS rulings (
+                   oracle_id  TEXT NOT NULL,
+                   date       TEXT NOT NULL,
+                   text       TEXT NOT NULL,
+                   PRIMARY KEY (oracle_id, date, text)
+                ) STRICT;",
+                (),
+            )
+            .expect("Error creating table");
 
         Self { connection: Arc::new(Mutex::new(connection)) }
     }
@@ -104,6 +151,41 @@ impl SqliteDatabase {
             .unwrap_or_else(|e| panic!("Error writing game to sqlite {:?} {e:?}", game.id));
     }
 
+    /// Fetches the chat log for the game with the given ID, if any messages
+    /// have been sent.
+    pub fn fetch_chat(&self, game_id: GameId) -> Option<ChatLog> {
+        let data = self
+            .db()
+            .query_row("SELECT data FROM chats WHERE game_id = ?1", [&game_id.0], |row| {
+                let data: Vec<u8> = row.get(0)?;
+                Ok(data)
+            })
+            .optional()
+            .unwrap_or_else(|e| panic!("Error fetching chat {game_id:?} {e:?}"));
+
+        data.map(|data| {
+            de::from_slice::<ChatLog>(&data)
+                .unwrap_or_else(|e| panic!("Error deserializing chat {game_id:?} {e:?}"))
+        })
+    }
+
+    /// Writes the chat log for the game with the given ID.
+    ///
+    /// Chat is stored separately from the game itself so that messages sent
+    /// while a game action is being processed are not overwritten.
+    pub fn write_chat(&self, game_id: GameId, chat: &ChatLog) {
+        let data = ser::to_vec(chat)
+            .unwrap_or_else(|e| panic!("Error serializing chat {game_id:?} {e:?}"));
+        self.db()
+            .execute(
+                "INSERT INTO chats (game_id, data)
+                 VALUES (?1, ?2)
+                 ON CONFLICT(game_id) DO UPDATE SET data = ?2",
+                (&game_id.0, &data),
+            )
+            .unwrap_or_else(|e| panic!("Error writing chat to sqlite {game_id:?} {e:?}"));
+    }
+
     pub fn fetch_user(&self, id: UserId) -> Option<UserState> {
         let data = self
             .db()
@@ -133,6 +215,68 @@ impl SqliteDatabase {
             .unwrap_or_else(|e| panic!("Error writing user to sqlite {:?} {e:?}", user.id));
     }
 
+    /// Fetches the ID of the user who operates this installation, if one has
+    /// been created.
+    pub fn fetch_local_user_id(&self) -> Option<UserId> {
+        self.db()
+            .query_row("SELECT user_id FROM local_user WHERE id = 0", [], |row| {
+                let id: Uuid = row.get(0)?;
+                Ok(UserId(id))
+            })
+            .optional()
+            .unwrap_or_else(|e| panic!("Error fetching local user {e:?}"))
+    }
+
+    /// Records the ID of the user who operates this installation.
+    pub fn write_local_user_id(&self, id: UserId) {
+        self.db()
+            .execute(
+                "INSERT INTO local_user (id, user_id)
+                 VALUES (0, ?1)
+                 ON CONFLICT(id) DO UPDATE SET user_id = ?1",
+                [&id.0],
+            )
+            .unwrap_or_else(|e| panic!("Error writing local user {id:?} {e:?}"));
+    }
+
+    /// Fetches all games the indicated user has finished.
+    pub fn fetch_match_records(&self, user_id: UserId) -> Vec<MatchRecord> {
+        let connection = self.db();
+        let mut statement = connection
+            .prepare("SELECT data FROM match_history WHERE user_id = ?1")
+            .expect("Error preparing query");
+
+        let rows = statement
+            .query_map([&user_id.0], |row| row.get::<_, Vec<u8>>(0))
+            .unwrap_or_else(|e| panic!("Error fetching match history {user_id:?} {e:?}"));
+        let data = rows.collect::<Result<Vec<_>, _>>().expect("Error fetching match history");
+        data.iter()
+            .map(|data| {
+                de::from_slice::<MatchRecord>(data)
+                    .unwrap_or_else(|e| panic!("Error deserializing match {user_id:?} {e:?}"))
+            })
+            .collect()
+    }
+
+    /// Writes a finished game to the indicated user's match history.
+    ///
+    /// Returns false without modifying the match history if this game has
+    /// already been recorded.
+    pub fn write_match_record(&self, user_id: UserId, record: &MatchRecord) -> bool {
+        let game_id = record.game_id;
+        let data = ser::to_vec(record)
+            .unwrap_or_else(|e| panic!("Error serializing match {game_id:?} {e:?}"));
+        self.db()
+            .execute(
+                "INSERT INTO match_history (game_id, user_id, data)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT(game_id) DO NOTHING",
+                (&game_id.0, &user_id.0, &data),
+            )
+            .unwrap_or_else(|e| panic!("Error writing match to sqlite {game_id:?} {e:?}"))
+            > 0
+    }
+
     /// Fetch the [DatabaseCardFace]s of a given [PrintedCardId].
     pub fn fetch_printed_faces(&self, id: PrintedCardId) -> Vec<DatabaseCardFace> {
         let connection = self.db();
@@ -150,6 +294,174 @@ impl SqliteDatabase {
         cards.collect::<Result<_, _>>().expect("Error fetching card")
     }
 
+    /// Fetch every printing of the card with a given [PrintedCardId], including
+    /// printings in other sets.
+    pub fn fetch_printings(&self, id: PrintedCardId) -> Vec<DatabaseCardPrinting> {
+        let connection = self.db();
+        let mut statement = connection
+            .prepare(
+                "SELECT DISTINCT name, setCode, rarity, supertypes
+                 FROM oracle.cards
+                 WHERE name = (
+                     SELECT name
+                     FROM oracle.cards NATURAL JOIN oracle.cardIdentifiers
+                     WHERE scryfallId = ?1
+                     LIMIT 1
+                 )",
+            )
+            .expect("Error preparing query");
+
+        // Note: database stores UUIDs as literal strings, not blobs.
+        let rows = statement.query([id.0.to_string()]).expect("Error querying database");
+        let printings = serde_rusqlite::from_rows::<DatabaseCardPrinting>(rows);
+        printings.collect::<Result<_, _>>().expect("Error fetching printings")
+    }
+
+    /// Fetch a [PrintedCardId] for a card with the given name, ignoring case.
+    ///
+    /// If multiple printings of the card exist, an arbitrary one is returned.
+    pub fn fetch_printed_card_id(&self, name: &str) -> Option<PrintedCardId> {
+        let id = self
+            .db()
+            .query_row(
+                "SELECT scryfallId
+                 FROM oracle.cards NATURAL JOIN oracle.cardIdentifiers
+                 WHERE name = ?1 COLLATE NOCASE
+                 LIMIT 1",
+                [name],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .unwrap_or_else(|e| panic!("Error fetching card named {name:?} {e:?}"))?;
+
+        // Note: database stores UUIDs as literal strings, not blobs.
+        Some(PrintedCardId(
+            Uuid::parse_str(&id).unwrap_or_else(|e| panic!("Invalid card ID {id:?} {e:?}")),
+        ))
+    }
+
+    /// Fetch a [PrintedCardId] for the card with the given [CardName].
+    ///
+    /// If multiple printings of the card exist, an arbitrary one is returned.
+    pub fn fetch_printed_card_id_for_name(&self, name: CardName) -> Option<PrintedCardId> {
+        let id = self
+            .db()
+            .query_row(
+                "SELECT scryfallId
+                 FROM oracle.cards NATURAL JOIN oracle.cardIdentifiers
+                 WHERE scryfallOracleId = ?1
+                 LIMIT 1",
+                [name.0.to_string()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .unwrap_or_else(|e| panic!("Error fetching card {name:?} {e:?}"))?;
+
+        // Note: database stores UUIDs as literal strings, not blobs.
+        Some(PrintedCardId(
+            Uuid::parse_str(&id).unwrap_or_else(|e| panic!("Invalid card ID {id:?} {e:?}")),
+        ))
+    }
+
+    /// Fetch the [PrintedCardId] of an alternate printing of a card, identified
+    /// by its set code and collector number.
+    ///
+    /// Returns None if the card was not printed with this set code and
+    /// collector number.
+    pub fn fetch_alternate_printing(
+        &self,
+        id: PrintedCardId,
+        set_code: &str,
+        collector_number: &str,
+    ) -> Option<PrintedCardId> {
+        let printing = self
+            .db()
+            .query_row(
+                "SELECT scryfallId
+                 FROM oracle.cards NATURAL JOIN oracle.cardIdentifiers
+                 WHERE setCode = ?2 COLLATE NOCASE
+                 AND number = ?3
+                 AND name = (
+                     SELECT name
+                     FROM oracle.cards NATURAL JOIN oracle.cardIdentifiers
+                     WHERE scryfallId = ?1
+                     LIMIT 1
+                 )
+                 LIMIT 1",
+                [id.0.to_string().as_str(), set_code, collector_number],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .unwrap_or_else(|e| panic!("Error fetching printing of {id:?} {e:?}"))?;
+
+        Some(PrintedCardId(
+            Uuid::parse_str(&printing)
+                .unwrap_or_else(|e| panic!("Invalid card ID {printing:?} {e:?}")),
+        ))
+    }
+
+    /// Fetch the [DatabaseOracleText] of every card face in the Oracle
+    /// database, with one entry per distinct card face.
+    pub fn fetch_all_oracle_texts(&self) -> Vec<DatabaseOracleText> {
+        let connection = self.db();
+        let mut statement = connection
+            .prepare(
+                "SELECT DISTINCT scryfallOracleId, layout, side, text
+                 FROM oracle.cards NATURAL JOIN oracle.cardIdentifiers",
+            )
+            .expect("Error preparing query");
+
+        let rows = statement.query([]).expect("Error querying database");
+        let texts = serde_rusqlite::from_rows::<DatabaseOracleText>(rows);
+        texts.collect::<Result<_, _>>().expect("Error fetching oracle text")
+    }
+
+    /// Fetch the [CardRuling]s for a given [PrintedCardId], oldest first.
+    pub fn fetch_rulings(&self, id: PrintedCardId) -> Vec<CardRuling> {
+        let connection = self.db();
+        let mut statement = connection
+            .prepare(
+                "SELECT date, text
+                 FROM oracle.cardRulings NATURAL JOIN oracle.cardIdentifiers
+                 WHERE scryfallId = ?1
+                 UNION
+                 SELECT rulings.date, rulings.text
+                 FROM rulings JOIN oracle.cardIdentifiers AS identifiers
+                   ON rulings.oracle_id = identifiers.scryfallOracleId
+                 WHERE identifiers.scryfallId = ?1
+                 ORDER BY date",
+            )
+            .expect("Error preparing query");
+
+        let rows = statement.query([id.0.to_string()]).expect("Error querying database");
+        let rulings = serde_rusqlite::from_rows::<CardRuling>(rows);
+        rulings.collect::<Result<_, _>>().expect("Error fetching rulings")
+    }
+
+    /// Writes rulings for the cards with the given Scryfall oracle IDs,
+    /// skipping any which already exist. Returns the number of rulings
+    /// written.
+    pub fn write_rulings(&self, rulings: impl Iterator<Item = (Uuid, CardRuling)>) -> usize {
+        let mut connection = self.db();
+        let transaction = connection.transaction().expect("Error starting transaction");
+        let mut count = 0;
+        {
+            let mut statement = transaction
+                .prepare(
+                    "INSERT OR IGNORE INTO rulings (oracle_id, date, text)
+                     VALUES (?1, ?2, ?3)",
+                )
+                .expect("Error preparing query");
+            for (oracle_id, ruling) in rulings {
+                count += statement
+                    .execute((oracle_id.to_string(), &ruling.date, &ruling.text))
+                    .unwrap_or_else(|e| panic!("Error writing ruling for {oracle_id:?} {e:?}"));
+            }
+        }
+        transaction.commit().expect("Error committing transaction");
+        count
+    }
+
     fn db(&self) -> MutexGuard<Connection> {
         match self.connection.lock() {
             Ok(guard) => guard,
@@ -0,0 +1,95 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::campaign::campaign_progress::CampaignProgress;
+use data::game_states::game_state::RulesConfiguration;
+use data::player_states::player_options::PlayerOptions;
+use data::users::match_record::MatchRecord;
+use data::users::user_state::{UserActivity, UserState};
+use data::users::user_statistics::UserStatistics;
+use primitives::game_primitives::UserId;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::sqlite_database::SqliteDatabase;
+
+/// Returns the ID of the user who operates this installation, creating a new
+/// ID on first launch.
+pub fn local_user_id(database: &SqliteDatabase) -> UserId {
+    if let Some(id) = database.fetch_local_user_id() {
+        id
+    } else {
+        let id = UserId(Uuid::new_v4());
+        database.write_local_user_id(id);
+        info!(?id, "Created local user ID");
+        id
+    }
+}
+
+/// Loads the profile for the user with the given ID, creating a new profile
+/// with default preferences if none exists.
+pub fn fetch_or_create(database: &SqliteDatabase, id: UserId) -> UserState {
+    if let Some(user) = database.fetch_user(id) {
+        user
+    } else {
+        let user = UserState {
+            id,
+            activity: UserActivity::Menu,
+            options: PlayerOptions::default(),
+            campaign: CampaignProgress::default(),
+            display_name: None,
+            statistics: UserStatistics::default(),
+            house_rules: RulesConfiguration::default(),
+            selected_deck: None,
+        };
+        database.write_user(&user);
+        info!(?id, "Created new user");
+        user
+    }
+}
+
+/// Sets the name the indicated user displays to other players.
+///
+/// Leading and trailing whitespace is removed. An empty name clears the
+/// user's display name.
+pub fn set_display_name(database: &SqliteDatabase, id: UserId, name: &str) {
+    let mut user = fetch_or_create(database, id);
+    let name = name.trim();
+    user.display_name = if name.is_empty() { None } else { Some(name.to_string()) };
+    database.write_user(&user);
+}
+
+/// Sets the house rules applied to new games the indicated user creates.
+pub fn set_house_rules(database: &SqliteDatabase, id: UserId, rules: RulesConfiguration) {
+    let mut user = fetch_or_create(database, id);
+    user.house_rules = rules;
+    info!(?id, ?rules, "Updated house rules");
+    database.write_user(&user);
+}
+
+/// Records a completed game in the indicated user's statistics and match
+/// history.
+///
+/// Games which are already present in the match history are ignored, so
+/// statistics always agree with the recorded matches.
+pub fn record_match(database: &SqliteDatabase, id: UserId, record: &MatchRecord) {
+    if !database.write_match_record(id, record) {
+        info!(?id, game_id = ?record.game_id, "Game result already recorded");
+        return;
+    }
+    let mut user = fetch_or_create(database, id);
+    user.statistics.record(record.result);
+    info!(?id, result = ?record.result, "Recorded game result");
+    database.write_user(&user);
+}
@@ -0,0 +1,84 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::DisplayPlayer;
+use crate::core::object_position::Position;
+
+/// A sequence of animations for the client to play before displaying the next
+/// game view.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationTimelineView {
+    /// Groups of animations, in the order in which they should be played.
+    pub groups: Vec<AnimationGroupView>,
+}
+
+/// Animations which play at the same time.
+///
+/// Each group begins playing once all animations in the previous group have
+/// finished.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationGroupView {
+    pub steps: Vec<AnimationStepView>,
+}
+
+/// A single discrete animation.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum AnimationStepView {
+    /// A card was drawn into a player's hand.
+    Draw(ClientCardId),
+
+    /// Damage was dealt to a card or player.
+    DamageFlash(DamageFlashAnimation),
+
+    /// A creature was declared as an attacker.
+    AttackLunge(ClientCardId),
+
+    /// A card moved from one position to its current position.
+    ZoneMove(ZoneMoveAnimation),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DamageFlashAnimation {
+    /// Card or player which was dealt damage
+    pub target: AnimationTarget,
+
+    /// Amount of damage dealt
+    pub amount: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneMoveAnimation {
+    /// Card which moved
+    pub card: ClientCardId,
+
+    /// Position the card moved from
+    pub from: Position,
+}
+
+/// Object affected by an animation.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum AnimationTarget {
+    Card(ClientCardId),
+    Player(DisplayPlayer),
+}
@@ -12,13 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data::core::sound_effect::SoundEffect;
 use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use crate::commands::animation_timeline::AnimationTimelineView;
+use crate::core::card_details_view::CardDetailsView;
+use crate::core::card_search_view::CardSearchView;
+use crate::core::chat_view::ChatView;
+use crate::core::deck_catalog_view::DeckCatalogView;
+use crate::core::game_log_view::GameLogView;
 use crate::core::game_message::GameMessage;
-use crate::core::game_view::GameView;
+use crate::core::game_view::{GameView, GameViewDiff};
 use crate::core::main_menu_view::MainMenuView;
+use crate::core::match_statistics_view::MatchStatisticsView;
+use crate::core::performance_hud_view::PerformanceHudView;
+use crate::core::random_result_view::RandomResultView;
+use crate::core::recent_changes_view::RecentChangesView;
 use crate::panels::modal_panel::ModalPanel;
 
 /// Represents an instruction to the client to perform some visual update.
@@ -29,11 +40,62 @@ pub enum Command {
     /// Update the primary visual state of the game.
     UpdateScene(SceneView),
 
+    /// Apply incremental changes to the [GameView] most recently sent via
+    /// [Self::UpdateScene] or [Self::UpdateGameView].
+    UpdateGameView(GameViewDiff),
+
+    /// Play a sequence of animations before applying the next game view
+    /// update.
+    PlayAnimations(AnimationTimelineView),
+
+    /// Play sound effects for recent changes to the game state.
+    PlaySounds(Vec<SoundEffect>),
+
+    /// Replace the plain text description of the board used by screen
+    /// readers.
+    UpdateBoardDescription(String),
+
     /// Hide or show a modal panel on top of the scene view.
     SetModalPanel(Option<ModalPanel>),
 
     /// Display a message to the player.
     DisplayGameMessage(DisplayGameMessageCommand),
+
+    /// Replace the contents of the game log with recent game events.
+    UpdateGameLog(GameLogView),
+
+    /// Replace the contents of the chat window.
+    UpdateChat(ChatView),
+
+    /// Display full oracle details for a card in the detail pane.
+    ShowCardDetails(CardDetailsView),
+
+    /// Display the list of available pre-constructed decks.
+    ShowDeckCatalog(DeckCatalogView),
+
+    /// Display aggregated results of the user's finished games.
+    ShowMatchStatistics(MatchStatisticsView),
+
+    /// Display a summary of what changed since the player last had the
+    /// opportunity to act.
+    ShowRecentChanges(RecentChangesView),
+
+    /// Display cards matching a search in the card browser panel.
+    ShowCardSearchResults(CardSearchView),
+
+    /// Display the result of a coin flip, die roll, or clash.
+    ShowRandomResult(RandomResultView),
+
+    /// Hide or show the performance HUD overlay.
+    UpdatePerformanceHud(Option<PerformanceHudView>),
+
+    /// Display an error which prevented a game action from being applied.
+    ///
+    /// The game is restored to its last saved state when this occurs.
+    ShowRuleError(String),
+
+    /// Display the path to a crash report written after an unexpected error.
+    ShowCrashReport(String),
 }
 
 impl Command {
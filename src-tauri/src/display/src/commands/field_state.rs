@@ -14,6 +14,9 @@
 
 use std::collections::BTreeMap;
 
+use data::core::sound_effect::SoundEffect;
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::text_strings::language_id::LanguageId;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use specta::Type;
@@ -24,18 +27,59 @@ use specta::Type;
 #[serde(rename_all = "camelCase")]
 pub enum FieldKey {
     PickNumberPrompt,
+    DistributeAmount(usize),
+    RulesConsoleQuery,
+    DebugCommand,
+    ActiveTurnStop(GamePhaseStep),
+    InactiveTurnStop(GamePhaseStep),
+    FullControl,
+    AutoYieldOwnTriggers,
+    ReduceMotion,
+    PlaySound(SoundEffect),
+    ScreenReader,
+    PerformanceHud,
+    Language(LanguageId),
+    DisplayName,
+    StartingLife(u32),
+    FreeMulligans(u32),
+    IgnoreBannedList,
+    AlwaysAllowTakebacks,
+    Planechase,
+}
+
+impl FieldKey {
+    /// Returns true if this field edits the user's house rules.
+    pub fn is_house_rule(&self) -> bool {
+        matches!(
+            self,
+            FieldKey::StartingLife(_)
+                | FieldKey::FreeMulligans(_)
+                | FieldKey::IgnoreBannedList
+                | FieldKey::AlwaysAllowTakebacks
+                | FieldKey::Planechase
+        )
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub enum FieldValue {
     String(String),
+    Bool(bool),
 }
 
 impl FieldValue {
     pub fn as_u32(&self) -> Option<u32> {
         match self {
             FieldValue::String(s) => s.parse().ok(),
+            FieldValue::Bool(_) => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FieldValue::Bool(b) => Some(*b),
+            FieldValue::String(_) => None,
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod animation_timeline;
 pub mod command;
 pub mod field_state;
 pub mod scene_identifier;
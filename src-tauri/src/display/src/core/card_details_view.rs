@@ -0,0 +1,69 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::GameButtonView;
+use crate::core::rich_text::TextRun;
+
+/// Full oracle information about a card, displayed in a detail pane when the
+/// user hovers over a card.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CardDetailsView {
+    /// Card these details describe.
+    pub id: ClientCardId,
+
+    /// Faces of the card, primary face first.
+    pub faces: Vec<CardDetailsFaceView>,
+
+    /// Official rulings for this card, oldest first.
+    pub rulings: Vec<CardRulingView>,
+
+    /// Buttons to toggle whether the game should always stop for priority
+    /// when each of this card's triggered abilities is on the stack. Only
+    /// populated for cards controlled by the viewer.
+    pub trigger_buttons: Vec<GameButtonView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CardDetailsFaceView {
+    pub name: String,
+
+    /// Type line as printed, e.g. "Creature — Bear".
+    pub type_line: Option<String>,
+
+    /// Mana symbols of this face's cost without braces, e.g. `["2", "G"]` for
+    /// a cost of `{2}{G}`.
+    pub mana_cost: Vec<String>,
+
+    /// Full oracle text of this face.
+    pub oracle_text: Option<String>,
+
+    /// Oracle text split into styled runs, for display.
+    pub rich_oracle_text: Vec<TextRun>,
+
+    /// Printed power & toughness, e.g. "2/2".
+    pub power_toughness: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CardRulingView {
+    pub date: String,
+    pub text: String,
+}
@@ -0,0 +1,53 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::card_view::ClientCardId;
+
+/// Results of searching the visible cards in graveyards, exile, and other
+/// revealed zones, displayed in a card browser panel.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CardSearchView {
+    /// Query these results were produced for. An empty query matches every
+    /// visible card.
+    pub query: String,
+
+    /// Matching cards grouped by owner & zone, in the order they should be
+    /// displayed in the browser.
+    pub sections: Vec<CardSearchSectionView>,
+}
+
+/// A group of matching cards in a single zone.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CardSearchSectionView {
+    /// Heading for this section, e.g. "Your Graveyard".
+    pub title: String,
+
+    /// Matching cards in this zone, top card first.
+    pub cards: Vec<CardSearchResultView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CardSearchResultView {
+    pub card: ClientCardId,
+    pub name: String,
+
+    /// Type line of the card's primary face, e.g. "Creature — Bear".
+    pub type_line: String,
+}
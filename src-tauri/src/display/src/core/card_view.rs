@@ -22,6 +22,7 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 
 use crate::core::object_position::ObjectPosition;
+use crate::core::rich_text::TextRun;
 
 /// Represents the visual state of a card or ability in a game
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -126,6 +127,11 @@ pub struct RevealedCardView {
     /// True if this card represents a token
     pub is_token: bool,
 
+    /// True if this is a user-defined card which does not exist in the Oracle
+    /// card database. Custom cards have no card image, so their name should be
+    /// displayed instead.
+    pub is_custom: bool,
+
     /// Action to take when this card is clicked, if any.
     pub click_action: Option<UserAction>,
 
@@ -140,6 +146,31 @@ pub struct RevealedCardView {
 
     /// Visual style of this card, how the faces are displayed
     pub layout: CardLayout,
+
+    /// Current chapter of this card if it is a Saga on the battlefield, i.e.
+    /// the number of lore counters on it.
+    pub saga_chapter: Option<u32>,
+
+    /// Current defense of this card if it is a battle on the battlefield, i.e.
+    /// the number of defense counters on it.
+    pub battle_defense: Option<u32>,
+
+    /// Names of the other cards which make up this permanent if it is a
+    /// merged permanent, e.g. as a result of mutate, ordered from top to
+    /// bottom.
+    pub merged_with: Vec<String>,
+
+    /// True if this card is in exile "on an adventure", i.e. it was cast as
+    /// an Adventure and may now be cast from exile.
+    pub on_an_adventure: bool,
+
+    /// True if this card is in exile face down as a foretold card. Foretold
+    /// cards are only revealed to their owner.
+    pub foretold: bool,
+
+    /// Explanation of why the player can't currently play or attack with this
+    /// card, displayed as a tooltip.
+    pub disabled_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Type)]
@@ -150,6 +181,7 @@ pub enum RevealedCardStatus {
     CanPlay,
     Attacking(String),
     Blocking(String),
+    Goaded(String),
 }
 
 /// Visual state of a revealed card face
@@ -164,4 +196,7 @@ pub struct RevealedCardFace {
 
     /// Rules text_strings for this face, if any.
     pub rules_text: Option<String>,
+
+    /// Rules text for this face split into styled runs, for display.
+    pub rich_rules_text: Vec<TextRun>,
 }
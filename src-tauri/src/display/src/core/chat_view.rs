@@ -0,0 +1,38 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::numerics::TurnNumber;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Chat messages exchanged between players in the current game.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatView {
+    /// Messages, oldest first.
+    pub messages: Vec<ChatMessageView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessageView {
+    /// True if this message was sent by the viewing player.
+    pub from_self: bool,
+
+    /// Turn number on which this message was sent.
+    pub turn_number: TurnNumber,
+
+    /// Text of the message.
+    pub text: String,
+}
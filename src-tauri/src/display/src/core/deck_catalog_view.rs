@@ -0,0 +1,51 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::decks::deck_name::DeckName;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::game_view::GameButtonView;
+
+/// List of pre-constructed decks, displayed on the deck selection screen.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DeckCatalogView {
+    pub decks: Vec<DeckSummaryView>,
+}
+
+/// Summary of a single pre-constructed deck.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DeckSummaryView {
+    pub id: DeckName,
+
+    pub name: String,
+
+    /// Names of the colors of cards in this deck, e.g. "Green".
+    pub colors: Vec<String>,
+
+    pub description: String,
+
+    /// Total number of cards in this deck.
+    pub card_count: u32,
+
+    /// Names of the constructed formats this deck is legal in, e.g.
+    /// "Pauper".
+    pub legal_formats: Vec<String>,
+
+    /// Button to play with this deck in campaign games, or None if it is
+    /// already selected.
+    pub select: Option<GameButtonView>,
+}
@@ -14,7 +14,9 @@
 
 use std::collections::BTreeMap;
 
+use data::actions::game_action::GameAction;
 use data::actions::user_action::UserAction;
+use data::core::numerics::TurnNumber;
 use data::game_states::game_state::GameState;
 use data::prompts::prompt::{Prompt, PromptResponse};
 use serde::{Deserialize, Serialize};
@@ -22,6 +24,7 @@ use specta::{DataType, Generics, Type, TypeMap};
 use tokio::sync::oneshot;
 
 use crate::commands::field_state::{FieldKey, FieldValue};
+use crate::rendering::recent_changes::BoardSnapshot;
 
 /// Contains user interface state information which is persisted in memory
 /// during gameplay, but which is not serialized to the database.
@@ -43,6 +46,39 @@ pub struct DisplayState {
     /// Current state of the game, used to render correct updates when a prompt
     /// is active.
     pub game_snapshot: Option<GameState>,
+
+    /// Shortcut the player has selected to automatically pass priority for
+    /// some period of time, if any.
+    ///
+    /// This is cancelled when an opponent takes an action.
+    pub pass_until: Option<PassUntil>,
+
+    /// True if the player has requested to retain priority after casting their
+    /// next spell.
+    pub hold_priority: bool,
+
+    /// Actions the player has chosen in advance while another player is
+    /// deciding, in the order they should be taken.
+    ///
+    /// When the player is next able to act, these are submitted automatically.
+    /// Actions which are no longer legal at that point are discarded.
+    pub queued_actions: Vec<GameAction>,
+
+    /// Snapshot of the game taken when the player last had the opportunity to
+    /// act, used to summarize what has changed since then.
+    pub last_snapshot: Option<BoardSnapshot>,
+}
+
+/// Period of time for which a player has chosen to automatically pass
+/// priority.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PassUntil {
+    /// Pass until the turn with this number ends.
+    EndOfTurn(TurnNumber),
+
+    /// Pass until a turn after the turn with this number begins in which the
+    /// player is the active player.
+    NextTurn(TurnNumber),
 }
 
 impl Type for DisplayState {
@@ -0,0 +1,37 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::numerics::TurnNumber;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Represents the recent history of an ongoing game, displayed to the user as
+/// a scrollable log.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLogView {
+    /// Log entries, oldest first.
+    pub entries: Vec<GameLogEntryView>,
+}
+
+/// A single human-readable entry in the game log.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GameLogEntryView {
+    /// Turn number on which this event happened.
+    pub turn_number: TurnNumber,
+
+    /// Description of the event.
+    pub text: String,
+}
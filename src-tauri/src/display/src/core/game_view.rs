@@ -20,7 +20,8 @@ use serde_with::serde_derive::Serialize;
 use specta::Type;
 
 use crate::commands::field_state::FieldKey;
-use crate::core::card_view::CardView;
+use crate::core::card_view::{CardView, ClientCardId};
+use crate::core::object_position::ObjectPosition;
 
 /// Represents the visual state of an ongoing game
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -35,6 +36,9 @@ pub struct GameView {
     /// Visual state of cards in the game
     pub cards: Vec<CardView>,
 
+    /// Items on the stack, from top to bottom.
+    pub stack: Vec<StackItemView>,
+
     /// Describes the status of the game, e.g. which phase & step the game is in
     pub status_description: String,
 
@@ -52,6 +56,51 @@ pub struct GameView {
 
     /// Bottom user interaction options
     pub bottom_controls: Vec<GameControlView>,
+
+    /// Face-up plane or phenomenon card, if this game is using the Planechase
+    /// variant.
+    pub active_plane: Option<PlaneView>,
+}
+
+/// Describes a single spell or ability on the stack, so that players can
+/// evaluate how to respond to it.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StackItemView {
+    /// Card or ability this item is displayed as
+    pub card: ClientCardId,
+
+    /// Player who controls this item
+    pub controller: DisplayPlayer,
+
+    /// Name of the card which created this item
+    pub name: String,
+
+    /// True if this is an activated or triggered ability rather than a spell
+    pub is_ability: bool,
+
+    /// Rules text of the card which created this item
+    pub summary: String,
+
+    /// Modes chosen for this item, if it is modal
+    pub modes: Vec<String>,
+
+    /// Describes each target chosen for this item
+    pub targets: Vec<String>,
+}
+
+/// Represents the face-up card of the shared planar deck in a Planechase game.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaneView {
+    /// Identifies the plane or phenomenon card
+    pub card: ClientCardId,
+
+    /// Name of the card
+    pub name: String,
+
+    /// URL of the card image
+    pub image: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
@@ -88,6 +137,23 @@ pub struct TextInputView {
     pub key: FieldKey,
 }
 
+/// Data to render a checkbox which toggles a boolean field
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleView {
+    /// Unique identifier for this field
+    pub key: FieldKey,
+
+    /// Label to display next to the checkbox
+    pub label: String,
+
+    /// Current value of this field
+    pub value: bool,
+
+    /// If true, this field cannot currently be changed
+    pub disabled: bool,
+}
+
 /// Controls color for buttons
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -109,7 +175,9 @@ pub enum GameViewState {
 }
 
 /// Identifies a player in the context of the user interface.
-#[derive(Clone, Debug, Eq, PartialEq, Copy, Hash, Serialize, Deserialize, Type)]
+#[derive(
+    Clone, Debug, Eq, PartialEq, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize, Type,
+)]
 #[serde(rename_all = "camelCase")]
 pub enum DisplayPlayer {
     /// Player who is currently operating the client
@@ -120,7 +188,7 @@ pub enum DisplayPlayer {
 }
 
 /// Represents the visual state of a player in a game
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerView {
     /// Current life total
@@ -129,6 +197,68 @@ pub struct PlayerView {
     /// display layer we use floats for JavaScript compatibility.
     pub life: f64,
 
+    /// Total amount of life this player has gained during the current turn.
+    pub life_gained_this_turn: f64,
+
+    /// Total amount of life this player has lost during the current turn,
+    /// including life lost due to damage.
+    pub life_lost_this_turn: f64,
+
     /// Can this player currently take a game action?
     pub can_act: bool,
+
+    /// Action to open a browser panel listing this player's graveyard.
+    pub browse_graveyard: UserAction,
+
+    /// Action to open a browser panel listing this player's exiled cards.
+    pub browse_exile: UserAction,
+
+    /// Action to open a browser panel listing this player's library.
+    pub browse_library: UserAction,
+
+    /// Action to choose this player in an entity choice prompt, e.g. as the
+    /// target of a spell.
+    ///
+    /// None if this player is not currently a valid choice.
+    pub select_action: Option<UserAction>,
+}
+
+/// Describes changes to the visual state of an ongoing game relative to the
+/// previous [GameView] sent to a client.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GameViewDiff {
+    /// New state of the game view.
+    ///
+    /// The [GameView::cards] field of this view is always empty. Changes to
+    /// cards are described by [Self::card_updates] instead.
+    pub view: GameView,
+
+    /// Changes to cards since the previous view, in the order in which they
+    /// should be applied.
+    pub card_updates: Vec<CardUpdate>,
+}
+
+/// A change to a single card in a [GameViewDiff].
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum CardUpdate {
+    /// Create a new card or replace the full state of an existing card.
+    UpdateCard(Box<CardView>),
+
+    /// Move an existing card to a new position without otherwise changing it.
+    MoveCard(MoveCardUpdate),
+
+    /// Remove a card which is no longer visible.
+    RemoveCard(ClientCardId),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveCardUpdate {
+    /// Card to move
+    pub id: ClientCardId,
+
+    /// New position of this card
+    pub position: ObjectPosition,
 }
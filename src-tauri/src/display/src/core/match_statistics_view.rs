@@ -0,0 +1,68 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Aggregated results of the games a user has finished, displayed on the
+/// statistics screen.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchStatisticsView {
+    /// Results grouped by the deck the user played.
+    pub decks: Vec<WinRateView>,
+
+    /// Results grouped by the type of opponent the user faced.
+    pub opponents: Vec<WinRateView>,
+
+    /// Most recently finished games, newest first.
+    pub recent_matches: Vec<MatchHistoryEntryView>,
+}
+
+/// Combined results for a group of games.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WinRateView {
+    pub name: String,
+
+    pub games: u32,
+
+    pub wins: u32,
+
+    pub losses: u32,
+
+    pub draws: u32,
+
+    /// Fraction of games in this group which were won, between 0 and 1.
+    pub win_rate: f64,
+}
+
+/// Summary of a single finished game.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchHistoryEntryView {
+    pub deck: String,
+
+    pub opponent_deck: String,
+
+    pub opponent: String,
+
+    /// Outcome of the game, e.g. "Win".
+    pub result: String,
+
+    pub turns: u32,
+
+    /// Length of the game in seconds, if known.
+    pub duration_seconds: Option<u32>,
+}
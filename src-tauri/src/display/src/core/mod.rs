@@ -12,10 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod card_details_view;
+pub mod card_search_view;
 pub mod card_view;
+pub mod chat_view;
+pub mod deck_catalog_view;
 pub mod display_state;
+pub mod game_log_view;
 pub mod game_message;
 pub mod game_view;
 pub mod main_menu_view;
+pub mod match_statistics_view;
 pub mod object_position;
+pub mod performance_hud_view;
+pub mod random_result_view;
+pub mod recent_changes_view;
 pub mod response_builder;
+pub mod rich_text;
@@ -0,0 +1,41 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Recent latency of rules engine operations, displayed in an overlay when
+/// the performance HUD option is enabled.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceHudView {
+    pub timings: Vec<OperationTimingView>,
+}
+
+/// Latency of a single kind of operation, e.g. executing a game action.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationTimingView {
+    pub name: String,
+
+    /// Number of times this operation has been performed since recording
+    /// was enabled.
+    pub count: u32,
+
+    /// Median duration of recent operations, in milliseconds.
+    pub p50_millis: f64,
+
+    /// 95th percentile duration of recent operations, in milliseconds.
+    pub p95_millis: f64,
+}
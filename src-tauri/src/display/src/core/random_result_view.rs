@@ -0,0 +1,94 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::DisplayPlayer;
+
+/// Result of a coin flip, die roll, planar die roll, or clash, displayed to both players.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum RandomResultView {
+    CoinFlip(CoinFlipView),
+    DieRoll(DieRollView),
+    PlanarDie(PlanarDieView),
+    Clash(ClashView),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinFlipView {
+    /// Player who flipped the coin
+    pub player: DisplayPlayer,
+
+    /// Side called by the player, if any
+    pub call: Option<String>,
+
+    /// Side the coin landed on
+    pub result: String,
+
+    /// Whether the player won the flip, if they made a call
+    pub won: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DieRollView {
+    /// Player who rolled the die
+    pub player: DisplayPlayer,
+
+    /// Number of sides on the die
+    pub sides: u32,
+
+    pub result: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanarDieView {
+    /// Player who rolled the planar die
+    pub player: DisplayPlayer,
+
+    /// Face the planar die landed on
+    pub result: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashView {
+    /// Player who initiated the clash
+    pub player: DisplayPlayer,
+
+    /// Cards revealed by each player in the clash
+    pub revealed: Vec<ClashRevealView>,
+
+    /// Whether the initiating player won the clash
+    pub won: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ClashRevealView {
+    pub player: DisplayPlayer,
+
+    /// Revealed card, or None if the player's library was empty
+    pub card: Option<ClientCardId>,
+
+    /// Name of the revealed card
+    pub name: Option<String>,
+
+    pub mana_value: u32,
+}
@@ -0,0 +1,75 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::card_view::ClientCardId;
+
+/// Summary of changes to the game since the viewer last had the opportunity to
+/// act, displayed so they can see what happened in the meantime.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentChangesView {
+    /// Cards which moved between zones.
+    pub moved_cards: Vec<MovedCardView>,
+
+    /// Players whose life total changed.
+    pub life_changes: Vec<LifeChangeView>,
+
+    /// Cards whose number of counters changed.
+    pub counter_changes: Vec<CounterChangeView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MovedCardView {
+    /// Card which moved, for highlighting.
+    pub card: ClientCardId,
+
+    /// Name of the card, or a placeholder if it is hidden from the viewer.
+    pub name: String,
+
+    /// Name of the zone the card moved from.
+    pub from: String,
+
+    /// Name of the zone the card moved to.
+    pub to: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LifeChangeView {
+    /// Describes whose life total changed, e.g. "Your life".
+    pub label: String,
+
+    pub previous: f64,
+
+    pub current: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CounterChangeView {
+    /// Card whose counters changed, for highlighting.
+    pub card: ClientCardId,
+
+    pub name: String,
+
+    /// Total number of counters on the card previously.
+    pub previous: u32,
+
+    /// Total number of counters on the card now.
+    pub current: u32,
+}
@@ -14,8 +14,11 @@
 
 use std::collections::BTreeMap;
 
+use data::card_states::card_state::CardState;
 use data::game_states::game_state::{DebugActAsPlayer, GameState};
 use data::prompts::prompt::Prompt;
+use data::text_strings::language_id::LanguageId;
+use data::text_strings::{localization, Text};
 use primitives::game_primitives::PlayerName;
 use rules::legality::legal_actions;
 
@@ -55,6 +58,9 @@ pub struct ResponseState<'a> {
 
     /// Whether UI actions should be allowed during this response
     pub allow_actions: AllowActions,
+
+    /// Language in which to render user interface text
+    pub language: LanguageId,
 }
 
 /// Primary builder used to render game state.
@@ -104,6 +110,11 @@ impl<'a> ResponseBuilder<'a> {
         self.commands.push(Command::UpdateScene(SceneView::GameView(game)));
     }
 
+    /// Returns the provided [Text] rendered in the language for this response.
+    pub fn text(&self, text: impl Into<Text>) -> String {
+        localization::localize(text.into(), self.response_state.language)
+    }
+
     /// Whether user interface actions should be enabled during this response
     pub fn allow_actions(&self) -> bool {
         self.response_state.allow_actions == AllowActions::Yes
@@ -134,6 +145,12 @@ impl<'a> ResponseBuilder<'a> {
         self.player
     }
 
+    /// Returns true if the identity of the given card should be displayed to
+    /// the [Self::display_as_player] player.
+    pub fn is_visible(&self, card: &CardState) -> bool {
+        self.response_state.reveal_all_cards || card.is_visible_to(self.player)
+    }
+
     /// Returns the [PlayerName] that should be used for *actions* in the
     /// rendered UI.
     ///
@@ -0,0 +1,41 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A segment of rules text with a single visual style.
+///
+/// Oracle text is converted into a sequence of runs so that the client can
+/// render e.g. mana symbols as icons instead of literal text like "{2}{U}".
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum TextRun {
+    /// Plain rules text.
+    Text(String),
+
+    /// A symbol which appeared between braces in oracle text, without the
+    /// braces. For example "2", "U", "T", or "W/P".
+    Symbol(String),
+
+    /// Parenthesized reminder text, including the parentheses.
+    Reminder(String),
+
+    /// An ability word which has no rules meaning, such as "Landfall". The
+    /// separating dash is not included.
+    AbilityWord(String),
+
+    /// Start of a new paragraph.
+    LineBreak,
+}
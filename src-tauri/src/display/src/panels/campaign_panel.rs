@@ -0,0 +1,57 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::campaign::campaign_stage::CampaignStage;
+use data::campaign::campaign_stages;
+use data::users::user_state::UserState;
+
+use crate::core::game_view::GameButtonView;
+use crate::panels::modal_panel::{
+    CampaignPanel, CampaignStageStatus, CampaignStageView, ModalPanel, PanelData,
+};
+
+pub fn render(user: &UserState) -> ModalPanel {
+    ModalPanel {
+        title: Some("Campaign".to_string()),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::Campaign(CampaignPanel {
+            stages: campaign_stages::all().iter().map(|stage| stage_view(user, stage)).collect(),
+        }),
+    }
+}
+
+fn stage_view(user: &UserState, stage: &CampaignStage) -> CampaignStageView {
+    let status = if user.campaign.is_completed(stage.id) {
+        CampaignStageStatus::Completed
+    } else if campaign_stages::is_available(&user.campaign, stage.id) {
+        CampaignStageStatus::Available
+    } else {
+        CampaignStageStatus::Locked
+    };
+
+    CampaignStageView {
+        name: stage.name.clone(),
+        description: stage.description.clone(),
+        rules: stage.rules.iter().map(|rule| rule.description()).collect(),
+        has_reward: stage.reward.is_some(),
+        status,
+        play: (status != CampaignStageStatus::Locked).then(|| {
+            GameButtonView::new_primary(
+                if status == CampaignStageStatus::Completed { "Replay" } else { "Play" },
+                UserAction::StartCampaignStage(stage.id),
+            )
+        }),
+    }
+}
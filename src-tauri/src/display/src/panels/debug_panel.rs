@@ -15,13 +15,49 @@
 use data::actions::debug_action::DebugGameAction;
 use data::actions::game_action::GameAction;
 use data::actions::user_action::{PanelTransition, UserAction};
+use data::card_states::card_state::{CardFacing, CardState};
+use data::core::panel_address::GamePanelAddress;
+use data::game_states::game_phase_step::GamePhaseStep;
 use data::game_states::game_state::GameState;
-use primitives::game_primitives::PlayerName;
+use primitives::game_primitives::{PlayerName, Source, Zone};
+use rules::queries::card_queries;
 
-use crate::core::game_view::GameButtonView;
+use crate::commands::field_state::FieldKey;
+use crate::core::game_view::{GameButtonView, TextInputView};
 use crate::panels::modal_panel::{DebugPanel, ModalPanel, PanelData};
 
-pub fn render(_game: &GameState, _player: PlayerName) -> ModalPanel {
+/// Renders the debug panel.
+///
+/// The `message` is the result of the most recent text command, if any. The
+/// panel lists every card in the game along with its current state.
+pub fn render(game: &GameState, _player: PlayerName, message: Option<&str>) -> ModalPanel {
+    let mut lines = match message {
+        Some(message) => vec![message.to_string()],
+        None => vec![
+            "Commands:".to_string(),
+            "add [p1|p2] <hand|library|battlefield|graveyard|exile> <card name>".to_string(),
+            "life <p1|p2> <amount>".to_string(),
+            "counters <+1/+1|-1/-1> <amount> <card name>".to_string(),
+            "trigger upkeep <p1|p2>".to_string(),
+            "trigger enter <card name>".to_string(),
+            "reveal".to_string(),
+            "step".to_string(),
+            "top <card name>".to_string(),
+            "draws [p1|p2] <card name>, <card name>, ...".to_string(),
+            "seed <number>".to_string(),
+            "skip <step name>".to_string(),
+            "skip turns <count>".to_string(),
+            "export".to_string(),
+            "import <position file path or JSON>".to_string(),
+            "reload".to_string(),
+        ],
+    };
+    lines.push(String::new());
+
+    let mut cards = game.zones.all_cards().collect::<Vec<_>>();
+    cards.sort_by_key(|card| (card.owner, card.zone, card.id));
+    lines.extend(cards.into_iter().map(|card| describe(game, card)));
+
     ModalPanel {
         title: Some("Debug".to_string()),
         on_close: UserAction::ClosePanel,
@@ -30,14 +66,72 @@ pub fn render(_game: &GameState, _player: PlayerName) -> ModalPanel {
                 button("P2 Life", DebugGameAction::SetLifeTotal(PlayerName::Two)),
                 button("Reveal P2 Hand", DebugGameAction::RevealHand(PlayerName::Two)),
                 button("Destroy P1 Lands", DebugGameAction::DestroyAllLands(PlayerName::One)),
+                button("P1 Upkeep Triggers", DebugGameAction::ForceUpkeepTriggers(PlayerName::One)),
+                button("Skip to End Step", DebugGameAction::SkipToStep(GamePhaseStep::EndStep)),
+                button("Skip Turn", DebugGameAction::SkipTurns(1)),
+                button(
+                    if game.configuration.debug.reveal_all_cards {
+                        "Hide Cards"
+                    } else {
+                        "Reveal All Cards"
+                    },
+                    DebugGameAction::ToggleRevealAllCards,
+                ),
+                button(
+                    if game.configuration.debug.step_through_resolution {
+                        "Stop Stepping"
+                    } else {
+                        "Step Through Resolution"
+                    },
+                    DebugGameAction::ToggleStepThroughResolution,
+                ),
+                open_panel_button("Rules Console", GamePanelAddress::RulesConsolePanel),
             ],
+            input: TextInputView { key: FieldKey::DebugCommand },
+            lines,
         }),
     }
 }
 
+/// Describes a card and its properties on a single line.
+fn describe(game: &GameState, card: &CardState) -> String {
+    let mut properties = vec![format!("{:?}", card.owner), format!("{:?}", card.zone)];
+    if card.facing == CardFacing::FaceDown {
+        properties.push("face down".to_string());
+    }
+    if card.tapped_state.is_tapped() {
+        properties.push("tapped".to_string());
+    }
+    if card.damage > 0 {
+        properties.push(format!("{} damage", card.damage));
+    }
+    if card.counters.p1p1 > 0 {
+        properties.push(format!("{} +1/+1", card.counters.p1p1));
+    }
+    if card.counters.m1m1 > 0 {
+        properties.push(format!("{} -1/-1", card.counters.m1m1));
+    }
+    if card.zone == Zone::Battlefield {
+        if let (Some(power), Some(toughness)) = (
+            card_queries::power(game, Source::Game, card.id),
+            card_queries::toughness(game, Source::Game, card.id),
+        ) {
+            properties.push(format!("{power}/{toughness}"));
+        }
+    }
+    format!("{}: {}", card.displayed_name(), properties.join(", "))
+}
+
 fn button(label: impl Into<String>, action: impl Into<GameAction>) -> GameButtonView {
     GameButtonView::new_primary(
         label,
         UserAction::PanelTransition(PanelTransition { action: Some(action.into()), open: None }),
     )
 }
+
+fn open_panel_button(label: impl Into<String>, panel: GamePanelAddress) -> GameButtonView {
+    GameButtonView::new_default(
+        label,
+        UserAction::PanelTransition(PanelTransition { action: None, open: Some(panel.into()) }),
+    )
+}
@@ -0,0 +1,91 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::decks::deck_catalog::DeckCatalog;
+use data::users::user_state::UserState;
+
+use crate::commands::field_state::FieldKey;
+use crate::core::game_view::ToggleView;
+use crate::panels::modal_panel::{ModalPanel, PanelData, SettingsPanel, SettingsSection};
+
+/// Starting life totals which can be selected.
+const STARTING_LIFE_OPTIONS: [u32; 4] = [20, 25, 30, 40];
+
+/// Free mulligan counts which can be selected.
+const FREE_MULLIGAN_OPTIONS: [u32; 3] = [0, 1, 2];
+
+/// Renders the house rules panel.
+///
+/// The Planechase option is disabled unless the deck catalog provides a planar
+/// deck to play with.
+pub fn render(user: &UserState, catalog: &DeckCatalog) -> ModalPanel {
+    let rules = &user.house_rules;
+    let has_planar_deck = catalog.planar_deck.is_some();
+    ModalPanel {
+        title: Some("House Rules".to_string()),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::Settings(SettingsPanel {
+            sections: vec![
+                SettingsSection {
+                    title: "Starting Life".to_string(),
+                    toggles: STARTING_LIFE_OPTIONS
+                        .iter()
+                        .map(|&life| ToggleView {
+                            key: FieldKey::StartingLife(life),
+                            label: life.to_string(),
+                            value: rules.starting_life == life as i64,
+                            disabled: false,
+                        })
+                        .collect(),
+                },
+                SettingsSection {
+                    title: "Free Mulligans".to_string(),
+                    toggles: FREE_MULLIGAN_OPTIONS
+                        .iter()
+                        .map(|&count| ToggleView {
+                            key: FieldKey::FreeMulligans(count),
+                            label: count.to_string(),
+                            value: rules.free_mulligans == count as usize,
+                            disabled: false,
+                        })
+                        .collect(),
+                },
+                SettingsSection {
+                    title: "Options".to_string(),
+                    toggles: vec![
+                        ToggleView {
+                            key: FieldKey::IgnoreBannedList,
+                            label: "Ignore banned lists".to_string(),
+                            value: rules.ignore_banned_list,
+                            disabled: false,
+                        },
+                        ToggleView {
+                            key: FieldKey::AlwaysAllowTakebacks,
+                            label: "Always allow takebacks".to_string(),
+                            value: rules.always_allow_takebacks,
+                            disabled: false,
+                        },
+                        ToggleView {
+                            key: FieldKey::Planechase,
+                            label: "Planechase".to_string(),
+                            value: rules.planechase && has_planar_deck,
+                            disabled: !has_planar_deck,
+                        },
+                    ],
+                },
+            ],
+        }),
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use primitives::game_primitives::{PlayerName, Zone};
+
+use crate::core::card_view::ClientCardId;
+use crate::panels::modal_panel::{KnownCardView, KnownCardsPanel, ModalPanel, PanelData};
+
+/// Renders a list of the cards in other players' hands & libraries which have
+/// been revealed to `player` and are still the same object they were when
+/// revealed.
+pub fn render(game: &GameState, player: PlayerName) -> ModalPanel {
+    let cards = game
+        .player(player)
+        .known_cards
+        .iter()
+        .filter_map(|(&id, known)| {
+            let card = game.card(id)?;
+            if card.object_id != known.object_id {
+                return None;
+            }
+            Some(KnownCardView {
+                card: ClientCardId::new(id),
+                name: card.displayed_name().to_string(),
+                location: match known.zone {
+                    Zone::Hand => "In hand".to_string(),
+                    Zone::Library => "In library".to_string(),
+                    zone => format!("{zone:?}"),
+                },
+                revealed_on: format!("Revealed on turn {}", known.revealed_on.turn_number),
+            })
+        })
+        .collect();
+
+    ModalPanel {
+        title: Some("Known Cards".to_string()),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::KnownCards(KnownCardsPanel { cards }),
+    }
+}
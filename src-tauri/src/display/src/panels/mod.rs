@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod debug_panel;
 pub mod modal_panel;
 pub mod panel;
+pub mod rules_console_panel;
 
-mod debug_panel;
+mod campaign_panel;
+mod house_rules_panel;
+mod known_cards_panel;
+mod profile_panel;
+mod settings_panel;
+mod zone_browser_panel;
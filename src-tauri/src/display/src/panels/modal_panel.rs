@@ -16,7 +16,8 @@ use data::actions::user_action::UserAction;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use crate::core::game_view::GameButtonView;
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::{GameButtonView, TextInputView, ToggleView};
 
 /// Rendering options for a modal window which can be displayed on top of other
 /// game content
@@ -31,10 +32,124 @@ pub struct ModalPanel {
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
 pub enum PanelData {
     Debug(DebugPanel),
+    RulesConsole(RulesConsolePanel),
+    Settings(SettingsPanel),
+    Campaign(CampaignPanel),
+    Profile(ProfilePanel),
+    ZoneBrowser(ZoneBrowserPanel),
+    KnownCards(KnownCardsPanel),
 }
 
 /// Debug options
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
 pub struct DebugPanel {
     pub buttons: Vec<GameButtonView>,
+
+    /// Input for text commands which mutate the game state
+    pub input: TextInputView,
+
+    /// Result of the last command, followed by a description of every card in
+    /// the game
+    pub lines: Vec<String>,
+}
+
+/// Console for asking questions about the current game state
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct RulesConsolePanel {
+    pub input: TextInputView,
+    pub lines: Vec<String>,
+}
+
+/// Options controlling when the game stops to give the player priority
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct SettingsPanel {
+    pub sections: Vec<SettingsSection>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct SettingsSection {
+    pub title: String,
+    pub toggles: Vec<ToggleView>,
+}
+
+/// Map of stages in the single-player campaign
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct CampaignPanel {
+    pub stages: Vec<CampaignStageView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct CampaignStageView {
+    pub name: String,
+    pub description: String,
+
+    /// Descriptions of special rules which apply in this stage
+    pub rules: Vec<String>,
+
+    /// True if completing this stage unlocks a new deck
+    pub has_reward: bool,
+
+    pub status: CampaignStageStatus,
+
+    /// Button to start a game for this stage, if it can currently be played
+    pub play: Option<GameButtonView>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, Type)]
+pub enum CampaignStageStatus {
+    Locked,
+    Available,
+    Completed,
+}
+
+/// Display name and lifetime statistics for the current user
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct ProfilePanel {
+    pub display_name: String,
+
+    /// Input for changing the user's display name
+    pub input: TextInputView,
+
+    /// Descriptions of the user's game statistics
+    pub statistics: Vec<String>,
+}
+
+/// Ordered list of the cards in a zone, one page at a time
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct ZoneBrowserPanel {
+    /// Cards on the current page, top card first
+    pub cards: Vec<ZoneBrowserCardView>,
+
+    /// Description of the current page, e.g. "Cards 1-20 of 45"
+    pub page_description: String,
+
+    pub previous: Option<GameButtonView>,
+    pub next: Option<GameButtonView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct ZoneBrowserCardView {
+    /// Card to show details for, or None if this card is hidden from the
+    /// viewer
+    pub card: Option<ClientCardId>,
+
+    pub name: String,
+}
+
+/// Cards in other players' hands & libraries which the viewer has seen
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct KnownCardsPanel {
+    pub cards: Vec<KnownCardView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct KnownCardView {
+    pub card: ClientCardId,
+    pub name: String,
+
+    /// Description of the zone the card is in, e.g. "In hand"
+    pub location: String,
+
+    /// Description of when the card was revealed, e.g. "Revealed on turn 3"
+    pub revealed_on: String,
 }
@@ -13,11 +13,16 @@
 // limitations under the License.
 
 use data::core::panel_address::{GamePanelAddress, UserPanelAddress};
+use data::decks::deck_catalog::DeckCatalog;
 use data::game_states::game_state::GameState;
+use data::users::user_state::UserState;
 use primitives::game_primitives::PlayerName;
 
-use crate::panels::debug_panel;
 use crate::panels::modal_panel::ModalPanel;
+use crate::panels::{
+    campaign_panel, debug_panel, house_rules_panel, known_cards_panel, profile_panel,
+    rules_console_panel, settings_panel, zone_browser_panel,
+};
 
 pub fn build_game_panel(
     game: &GameState,
@@ -25,14 +30,24 @@ pub fn build_game_panel(
     address: GamePanelAddress,
 ) -> ModalPanel {
     match address {
-        GamePanelAddress::GameDebugPanel => debug_panel::render(game, player),
+        GamePanelAddress::GameDebugPanel => debug_panel::render(game, player, None),
+        GamePanelAddress::RulesConsolePanel => rules_console_panel::render(game, player, None),
+        GamePanelAddress::SettingsPanel => settings_panel::render(game, player),
+        GamePanelAddress::KnownCardsPanel => known_cards_panel::render(game, player),
+        GamePanelAddress::ZoneBrowser { owner, zone, page } => {
+            zone_browser_panel::render(game, player, owner, zone, page)
+        }
     }
 }
 
 pub fn build_user_panel(
-    _game: &GameState,
-    _player: PlayerName,
+    user: &UserState,
+    catalog: &DeckCatalog,
     address: UserPanelAddress,
 ) -> ModalPanel {
-    match address {}
+    match address {
+        UserPanelAddress::CampaignMap => campaign_panel::render(user),
+        UserPanelAddress::Profile => profile_panel::render(user),
+        UserPanelAddress::HouseRules => house_rules_panel::render(user, catalog),
+    }
 }
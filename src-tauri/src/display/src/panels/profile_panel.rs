@@ -0,0 +1,38 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::users::user_state::UserState;
+
+use crate::commands::field_state::FieldKey;
+use crate::core::game_view::TextInputView;
+use crate::panels::modal_panel::{ModalPanel, PanelData, ProfilePanel};
+
+pub fn render(user: &UserState) -> ModalPanel {
+    let stats = &user.statistics;
+    ModalPanel {
+        title: Some("Profile".to_string()),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::Profile(ProfilePanel {
+            display_name: user.display_name().to_string(),
+            input: TextInputView { key: FieldKey::DisplayName },
+            statistics: vec![
+                format!("Games played: {}", stats.games_played),
+                format!("Wins: {}", stats.wins),
+                format!("Losses: {}", stats.losses),
+                format!("Draws: {}", stats.draws),
+            ],
+        }),
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{PermanentId, PlayerName, Source};
+use rules::queries::explain_queries::Explanation;
+use rules::queries::{explain_queries, player_queries};
+
+use crate::commands::field_state::FieldKey;
+use crate::core::game_view::TextInputView;
+use crate::panels::modal_panel::{ModalPanel, PanelData, RulesConsolePanel};
+
+/// Renders a console which answers questions about the current game state,
+/// such as "why can't I block with X?" or "what is X's power?".
+pub fn render(game: &GameState, _player: PlayerName, query: Option<&str>) -> ModalPanel {
+    let lines = match query.map(str::trim).filter(|q| !q.is_empty()) {
+        Some(query) => answer(game, query),
+        None => vec![
+            "Ask a question about a permanent on the battlefield, for example:".to_string(),
+            "\"Why can't I block with Grizzly Bears?\"".to_string(),
+            "\"What is the power of Grizzly Bears?\"".to_string(),
+        ],
+    };
+
+    ModalPanel {
+        title: Some("Rules Console".to_string()),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::RulesConsole(RulesConsolePanel {
+            input: TextInputView { key: FieldKey::RulesConsoleQuery },
+            lines,
+        }),
+    }
+}
+
+fn answer(game: &GameState, query: &str) -> Vec<String> {
+    let query = query.to_lowercase();
+    let Some(permanent_id) = find_permanent(game, &query) else {
+        return vec!["No permanent on the battlefield matches this question.".to_string()];
+    };
+
+    let explanations = if query.contains("block") {
+        vec![explain_queries::can_block(game, Source::Game, permanent_id)]
+    } else if query.contains("attack") {
+        vec![explain_queries::can_attack(game, Source::Game, permanent_id)]
+    } else if query.contains("toughness") {
+        vec![explain_queries::toughness(game, Source::Game, permanent_id)]
+    } else if query.contains("power") {
+        vec![explain_queries::power(game, Source::Game, permanent_id)]
+    } else {
+        vec![
+            explain_queries::power(game, Source::Game, permanent_id),
+            explain_queries::toughness(game, Source::Game, permanent_id),
+        ]
+    };
+
+    explanations.into_iter().flatten().flat_map(to_lines).collect()
+}
+
+/// Finds the permanent whose name best matches the provided query.
+fn find_permanent(game: &GameState, query: &str) -> Option<PermanentId> {
+    player_queries::all_players(game)
+        .iter()
+        .flat_map(|player| game.battlefield(player).iter().copied())
+        .filter_map(|id| {
+            let name = game.card(id)?.displayed_name().to_lowercase();
+            query.contains(&name).then_some((name.len(), id))
+        })
+        .max_by_key(|(length, _)| *length)
+        .map(|(_, id)| id)
+}
+
+fn to_lines(explanation: Explanation) -> Vec<String> {
+    let mut result = vec![explanation.summary];
+    result.extend(explanation.reasons.into_iter().map(|reason| format!("- {reason}")));
+    result
+}
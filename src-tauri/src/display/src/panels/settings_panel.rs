@@ -0,0 +1,162 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::core::sound_effect::SoundEffect;
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use data::text_strings::language_id::LanguageId;
+use data::text_strings::localization;
+use primitives::game_primitives::PlayerName;
+
+use crate::commands::field_state::FieldKey;
+use crate::core::game_view::ToggleView;
+use crate::panels::modal_panel::{ModalPanel, PanelData, SettingsPanel, SettingsSection};
+
+pub fn render(game: &GameState, player: PlayerName) -> ModalPanel {
+    let options = &game.player(player).options;
+    let steps = enum_iterator::all::<GamePhaseStep>()
+        .filter(|step| !matches!(step, GamePhaseStep::Untap | GamePhaseStep::Cleanup))
+        .collect::<Vec<_>>();
+
+    ModalPanel {
+        title: Some("Settings".to_string()),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::Settings(SettingsPanel {
+            sections: vec![
+                SettingsSection {
+                    title: "Stops on Your Turn".to_string(),
+                    toggles: steps
+                        .iter()
+                        .map(|&step| ToggleView {
+                            key: FieldKey::ActiveTurnStop(step),
+                            label: step_name(step),
+                            value: options.active_turn_stops.contains(step),
+                            disabled: false,
+                        })
+                        .collect(),
+                },
+                SettingsSection {
+                    title: "Stops on Opponent's Turn".to_string(),
+                    toggles: steps
+                        .iter()
+                        .map(|&step| ToggleView {
+                            key: FieldKey::InactiveTurnStop(step),
+                            label: step_name(step),
+                            value: options.inactive_turn_stops.contains(step),
+                            disabled: false,
+                        })
+                        .collect(),
+                },
+                SettingsSection {
+                    title: "Priority".to_string(),
+                    toggles: vec![
+                        ToggleView {
+                            key: FieldKey::FullControl,
+                            label: "Full control".to_string(),
+                            value: options.full_control,
+                            disabled: false,
+                        },
+                        ToggleView {
+                            key: FieldKey::AutoYieldOwnTriggers,
+                            label: "Auto-yield to my triggers".to_string(),
+                            value: options.auto_yield_own_triggers,
+                            disabled: false,
+                        },
+                    ],
+                },
+                SettingsSection {
+                    title: "Display".to_string(),
+                    toggles: vec![
+                        ToggleView {
+                            key: FieldKey::ReduceMotion,
+                            label: "Reduce motion".to_string(),
+                            value: options.reduce_motion,
+                            disabled: false,
+                        },
+                        ToggleView {
+                            key: FieldKey::PerformanceHud,
+                            label: "Performance HUD".to_string(),
+                            value: options.performance_hud,
+                            disabled: false,
+                        },
+                    ],
+                },
+                SettingsSection {
+                    title: "Accessibility".to_string(),
+                    toggles: vec![ToggleView {
+                        key: FieldKey::ScreenReader,
+                        label: "Screen reader board descriptions".to_string(),
+                        value: options.screen_reader,
+                        disabled: false,
+                    }],
+                },
+                SettingsSection {
+                    title: "Language".to_string(),
+                    toggles: enum_iterator::all::<LanguageId>()
+                        .filter(|&language| localization::is_available(language))
+                        .map(|language| ToggleView {
+                            key: FieldKey::Language(language),
+                            label: language.native_name().to_string(),
+                            value: options.language == language,
+                            disabled: false,
+                        })
+                        .collect(),
+                },
+                SettingsSection {
+                    title: "Sounds".to_string(),
+                    toggles: enum_iterator::all::<SoundEffect>()
+                        .map(|sound| ToggleView {
+                            key: FieldKey::PlaySound(sound),
+                            label: sound_name(sound),
+                            value: !options.muted_sounds.contains(sound),
+                            disabled: false,
+                        })
+                        .collect(),
+                },
+            ],
+        }),
+    }
+}
+
+fn step_name(step: GamePhaseStep) -> String {
+    match step {
+        GamePhaseStep::Untap => "Untap",
+        GamePhaseStep::Upkeep => "Upkeep",
+        GamePhaseStep::Draw => "Draw",
+        GamePhaseStep::PreCombatMain => "Main 1",
+        GamePhaseStep::BeginCombat => "Begin Combat",
+        GamePhaseStep::DeclareAttackers => "Attackers",
+        GamePhaseStep::DeclareBlockers => "Blockers",
+        GamePhaseStep::FirstStrikeDamage => "First Strike",
+        GamePhaseStep::CombatDamage => "Damage",
+        GamePhaseStep::EndCombat => "End Combat",
+        GamePhaseStep::PostCombatMain => "Main 2",
+        GamePhaseStep::EndStep => "End Step",
+        GamePhaseStep::Cleanup => "Cleanup",
+    }
+    .to_string()
+}
+
+fn sound_name(sound: SoundEffect) -> String {
+    match sound {
+        SoundEffect::CardDrawn => "Card drawn",
+        SoundEffect::SpellCast => "Spell cast",
+        SoundEffect::CreatureDies => "Creature dies",
+        SoundEffect::GameWon => "Victory",
+        SoundEffect::GameLost => "Defeat",
+    }
+    .to_string()
+}
@@ -0,0 +1,84 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::card_states::card_state::CardState;
+use data::card_states::zones::ZoneQueries;
+use data::core::panel_address::GamePanelAddress;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{PlayerName, Zone};
+use rules::queries::card_queries;
+
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::GameButtonView;
+use crate::panels::modal_panel::{ModalPanel, PanelData, ZoneBrowserCardView, ZoneBrowserPanel};
+use crate::rendering::zone_contents;
+
+/// Maximum number of cards to display on a single page of the browser.
+const PAGE_SIZE: usize = 20;
+
+/// Renders an ordered list of the cards owned by `owner` in `zone`, top card
+/// first.
+///
+/// Cards which are not revealed to `player`, such as most cards in a library,
+/// are listed without identifying information so that only the size of the
+/// zone is disclosed.
+pub fn render(
+    game: &GameState,
+    player: PlayerName,
+    owner: PlayerName,
+    zone: Zone,
+    page: usize,
+) -> ModalPanel {
+    let cards = zone_contents::card_ids(game, owner, zone);
+    let page_count = cards.len().div_ceil(PAGE_SIZE).max(1);
+    let page = page.min(page_count - 1);
+    let start = page * PAGE_SIZE;
+    let end = (start + PAGE_SIZE).min(cards.len());
+
+    let browse = |page: usize| {
+        UserAction::OpenPanel(GamePanelAddress::ZoneBrowser { owner, zone, page }.into())
+    };
+
+    ModalPanel {
+        title: Some(zone_contents::title(game, player, owner, zone)),
+        on_close: UserAction::ClosePanel,
+        data: PanelData::ZoneBrowser(ZoneBrowserPanel {
+            cards: cards[start..end]
+                .iter()
+                .filter_map(|&id| game.card(id))
+                .map(|card| card_view(game, player, card))
+                .collect(),
+            page_description: if cards.is_empty() {
+                "No cards".to_string()
+            } else {
+                format!("Cards {}-{} of {}", start + 1, end, cards.len())
+            },
+            previous: (page > 0).then(|| GameButtonView::new_default("Previous", browse(page - 1))),
+            next: (page + 1 < page_count)
+                .then(|| GameButtonView::new_default("Next", browse(page + 1))),
+        }),
+    }
+}
+
+fn card_view(game: &GameState, player: PlayerName, card: &CardState) -> ZoneBrowserCardView {
+    if card_queries::is_visible_to(game, player, card) {
+        ZoneBrowserCardView {
+            card: Some(ClientCardId::new(card.id)),
+            name: card.displayed_name().to_string(),
+        }
+    } else {
+        ZoneBrowserCardView { card: None, name: "Hidden card".to_string() }
+    }
+}
@@ -14,20 +14,30 @@
 
 use data::card_states::card_state::{CardFacing, CardState, TappedState};
 use data::card_states::stack_ability_state::StackAbilityState;
+use data::game_states::game_state::GameState;
 use data::printed_cards::layout::{CardLayout, FaceLayout};
 use data::printed_cards::printed_card::Face;
+use primitives::game_primitives::EntityId;
 
-use crate::core::card_view::{CardView, ClientCardId, RevealedCardFace, RevealedCardView};
+use crate::core::card_view::{
+    CardView, ClientCardId, RevealedCardFace, RevealedCardStatus, RevealedCardView,
+};
 use crate::core::object_position::Position;
 use crate::core::response_builder::ResponseBuilder;
+use crate::core::rich_text::TextRun;
 use crate::rendering::card_view_context::CardViewContext;
-use crate::rendering::{card_sync, positions};
+use crate::rendering::{card_sync, positions, sync};
 
 pub fn stack_ability_view(
     builder: &ResponseBuilder,
+    game: &GameState,
     parent: &CardState,
     ability: &StackAbilityState,
 ) -> CardView {
+    // Abilities on the stack can be chosen in entity choice prompts, e.g. as
+    // the target of a spell which counters an ability.
+    let select_action =
+        sync::select_entity_action(builder, game, EntityId::StackAbility(ability.id));
     CardView {
         id: ClientCardId::new_for_stack_ability(ability.id),
         position: positions::for_stack_ability(ability, Position::Stack),
@@ -38,14 +48,22 @@ pub fn stack_ability_view(
                 name: parent.displayed_name().to_string(),
                 layout: FaceLayout::Normal,
                 rules_text: Some("Hello".to_string()),
+                rich_rules_text: vec![TextRun::Text("Hello".to_string())],
             },
-            status: None,
+            status: select_action.as_ref().map(|_| RevealedCardStatus::CanSelect),
             is_ability: true,
             is_token: false,
-            click_action: None,
+            is_custom: false,
+            click_action: select_action,
             can_drag: false,
             face_b: None,
             layout: CardLayout::Normal,
+            saga_chapter: None,
+            battle_defense: None,
+            merged_with: vec![],
+            on_an_adventure: false,
+            foretold: false,
+            disabled_reason: None,
         }),
         revealed_to_opponents: true,
         card_facing: CardFacing::FaceUp(Face::Primary),
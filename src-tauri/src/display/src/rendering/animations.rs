@@ -12,11 +12,78 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data::card_states::zones::ZoneQueries;
 use data::game_states::game_state::GameState;
-use data::prompts::game_update::GameAnimation;
+use data::prompts::game_update::{AnimationStep, GameAnimation};
 
+use crate::commands::animation_timeline::{
+    AnimationGroupView, AnimationStepView, AnimationTarget, AnimationTimelineView,
+    DamageFlashAnimation, ZoneMoveAnimation,
+};
+use crate::commands::command::Command;
+use crate::core::card_view::ClientCardId;
 use crate::core::response_builder::ResponseBuilder;
+use crate::rendering::positions;
 
-/// Populates commands in this [ResponseBuilder] corresponding to the provided
-/// [GameAnimation] for this [GameState].
-pub fn render(_builder: &mut ResponseBuilder, _animation: &GameAnimation, _snapshot: &GameState) {}
+/// Populates a command in this [ResponseBuilder] to play the provided
+/// [AnimationStep]s for this [GameState], grouped by their sequencing group.
+///
+/// Does nothing if animations are disabled for this response.
+pub fn render(builder: &mut ResponseBuilder, steps: &[AnimationStep], game: &GameState) {
+    if !builder.response_state.animate {
+        return;
+    }
+
+    let mut groups: Vec<AnimationGroupView> = vec![];
+    let mut current_group = None;
+    for step in steps {
+        let Some(view) = step_view(builder, &step.animation, game) else {
+            continue;
+        };
+        match groups.last_mut() {
+            Some(group) if current_group == Some(step.group) => group.steps.push(view),
+            _ => groups.push(AnimationGroupView { steps: vec![view] }),
+        }
+        current_group = Some(step.group);
+    }
+
+    if !groups.is_empty() {
+        builder.commands.push(Command::PlayAnimations(AnimationTimelineView { groups }));
+    }
+}
+
+fn step_view(
+    builder: &ResponseBuilder,
+    animation: &GameAnimation,
+    game: &GameState,
+) -> Option<AnimationStepView> {
+    Some(match animation {
+        GameAnimation::DrawCard(_, card_id) => AnimationStepView::Draw(ClientCardId::new(*card_id)),
+        GameAnimation::DamagePermanent(permanent_id, damage) => {
+            AnimationStepView::DamageFlash(DamageFlashAnimation {
+                target: AnimationTarget::Card(ClientCardId::new(permanent_id.internal_card_id)),
+                amount: *damage as f64,
+            })
+        }
+        GameAnimation::DamagePlayer(player, damage) => {
+            AnimationStepView::DamageFlash(DamageFlashAnimation {
+                target: AnimationTarget::Player(builder.to_display_player(*player)),
+                amount: *damage as f64,
+            })
+        }
+        GameAnimation::AttackLunge(permanent_id) => {
+            AnimationStepView::AttackLunge(ClientCardId::new(permanent_id.internal_card_id))
+        }
+        GameAnimation::ZoneMove(card_id, from, _) => {
+            let owner = game.card(*card_id)?.owner;
+            AnimationStepView::ZoneMove(ZoneMoveAnimation {
+                card: ClientCardId::new(*card_id),
+                from: positions::for_zone(builder, owner, *from),
+            })
+        }
+        // Displayed via Command::ShowRandomResult instead
+        GameAnimation::RandomResult(_) => return None,
+        // Displayed via Command::DisplayGameMessage instead
+        GameAnimation::GameOver(_) => return None,
+    })
+}
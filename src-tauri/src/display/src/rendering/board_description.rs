@@ -0,0 +1,183 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use data::card_states::card_state::{CardFacing, TappedState};
+
+use crate::commands::command::{Command, SceneView};
+use crate::core::card_view::{CardView, RevealedCardStatus};
+use crate::core::game_view::{DisplayPlayer, GameControlView, GameView, PlayerView};
+use crate::core::object_position::Position;
+use crate::core::response_builder::ResponseBuilder;
+
+/// Populates a command in this [ResponseBuilder] containing a textual
+/// description of the most recent [GameView] it contains, for use with screen
+/// readers.
+///
+/// Does nothing if the builder does not contain a game view.
+pub fn run(builder: &mut ResponseBuilder) {
+    let description = builder.commands.iter().rev().find_map(|command| match command {
+        Command::UpdateScene(SceneView::GameView(view)) => Some(describe(view)),
+        _ => None,
+    });
+    if let Some(description) = description {
+        builder.push(Command::UpdateBoardDescription(description));
+    }
+}
+
+/// Returns a plain text description of the provided [GameView], covering
+/// player life totals, the cards in each visible zone, the stack, and the
+/// currently available controls.
+pub fn describe(view: &GameView) -> String {
+    let mut lines = vec![view.status_description.replace('\n', ", ")];
+    lines.push(player_line("You", &view.viewer));
+    lines.push(player_line("Opponent", &view.opponent));
+
+    let mut sections = BTreeMap::<Section, Vec<&CardView>>::new();
+    for card in &view.cards {
+        if let Some(section) = section(&card.position.position) {
+            sections.entry(section).or_default().push(card);
+        }
+    }
+    for (section, mut cards) in sections {
+        cards.sort_by(|a, b| {
+            a.position
+                .sorting_key
+                .total_cmp(&b.position.sorting_key)
+                .then(a.position.sorting_sub_key.total_cmp(&b.position.sorting_sub_key))
+        });
+        let names = cards.into_iter().map(card_description).collect::<Vec<_>>();
+        lines.push(format!("{}: {}.", section.label(), names.join("; ")));
+    }
+
+    let controls = view
+        .top_controls
+        .iter()
+        .chain(view.bottom_controls.iter())
+        .filter_map(|control| match control {
+            GameControlView::Button(button) => Some(button.label.clone()),
+            GameControlView::Text(text) => Some(text.clone()),
+            GameControlView::TextInput(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if !controls.is_empty() {
+        lines.push(format!("Options: {}.", controls.join(", ")));
+    }
+
+    lines.join("\n")
+}
+
+fn player_line(label: &str, player: &PlayerView) -> String {
+    format!(
+        "{label}: {} life{}{}.",
+        player.life,
+        if player.can_act { ", can act" } else { "" },
+        if player.select_action.is_some() { ", can be selected" } else { "" }
+    )
+}
+
+/// Groups of cards in a board description, in the order in which they are
+/// described.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    Battlefield(DisplayPlayer),
+    Attacking,
+    Blocking,
+    Stack,
+    Hand(DisplayPlayer),
+    Graveyard(DisplayPlayer),
+    Exile(DisplayPlayer),
+    CommandZone(DisplayPlayer),
+    Choices,
+}
+
+impl Section {
+    fn label(&self) -> String {
+        let owner = |player: &DisplayPlayer| match player {
+            DisplayPlayer::Viewer => "Your",
+            DisplayPlayer::Opponent => "Opponent's",
+        };
+        match self {
+            Section::Battlefield(p) => format!("{} battlefield", owner(p)),
+            Section::Attacking => "Attacking".to_string(),
+            Section::Blocking => "Blocking".to_string(),
+            Section::Stack => "Stack".to_string(),
+            Section::Hand(p) => format!("{} hand", owner(p)),
+            Section::Graveyard(p) => format!("{} graveyard", owner(p)),
+            Section::Exile(p) => format!("{} exile", owner(p)),
+            Section::CommandZone(p) => format!("{} command zone", owner(p)),
+            Section::Choices => "Choices".to_string(),
+        }
+    }
+}
+
+fn section(position: &Position) -> Option<Section> {
+    Some(match position {
+        Position::Battlefield(p, _) => Section::Battlefield(*p),
+        Position::Attacking(_) => Section::Attacking,
+        Position::Blocking(..) => Section::Blocking,
+        Position::Stack | Position::Played => Section::Stack,
+        Position::Hand(p) => Section::Hand(*p),
+        Position::DiscardPile(p) => Section::Graveyard(*p),
+        Position::Exile(p) => Section::Exile(*p),
+        Position::CommandZone(p) => Section::CommandZone(*p),
+        Position::Browser
+        | Position::CardSelectionChoices
+        | Position::CardOrderLocation(_)
+        | Position::Revealed => Section::Choices,
+        Position::Default
+        | Position::Offscreen
+        | Position::Deck(_)
+        | Position::HandStorage
+        | Position::InsideCard(_)
+        | Position::AttachedToCard(_) => return None,
+    })
+}
+
+fn card_description(card: &CardView) -> String {
+    let Some(revealed) = &card.revealed else {
+        return match card.card_facing {
+            CardFacing::FaceDown => "face-down card".to_string(),
+            _ => "hidden card".to_string(),
+        };
+    };
+
+    let mut result = revealed.face.name.clone();
+    if let Some(face_b) = &revealed.face_b {
+        result.push_str(&format!(" // {}", face_b.name));
+    }
+    if revealed.is_ability {
+        result.push_str(" ability");
+    }
+    if revealed.is_token {
+        result.push_str(", token");
+    }
+    if card.tapped_state == TappedState::Tapped {
+        result.push_str(", tapped");
+    }
+    if card.damage > 0.0 {
+        result.push_str(&format!(", {} damage", card.damage));
+    }
+    match &revealed.status {
+        Some(RevealedCardStatus::Selected) => result.push_str(", selected"),
+        Some(RevealedCardStatus::CanSelect) => result.push_str(", can select"),
+        Some(RevealedCardStatus::CanPlay) => result.push_str(", can play"),
+        Some(RevealedCardStatus::Attacking(s)) => result.push_str(&format!(", attacking {s}")),
+        Some(RevealedCardStatus::Blocking(s)) => result.push_str(&format!(", blocking {s}")),
+        Some(RevealedCardStatus::Goaded(s)) => result.push_str(&format!(", goaded {s}")),
+        None => {}
+    }
+    result
+}
@@ -0,0 +1,104 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::card_definitions::ability_definition::{AbilityData, AbilityType};
+use data::card_definitions::definitions;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use data::printed_cards::card_ruling::CardRuling;
+use data::printed_cards::database_card::DatabaseCardFace;
+use primitives::game_primitives::{AbilityId, CardId, HasController, PlayerName};
+
+use crate::commands::command::Command;
+use crate::core::card_details_view::{CardDetailsFaceView, CardDetailsView, CardRulingView};
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::GameButtonView;
+use crate::rendering::rich_text;
+
+/// Returns a command to display oracle details & rulings for a card.
+pub fn render(
+    id: ClientCardId,
+    faces: &[DatabaseCardFace],
+    rulings: &[CardRuling],
+    trigger_buttons: Vec<GameButtonView>,
+) -> Command {
+    let mut faces = faces.iter().collect::<Vec<_>>();
+    faces.sort_by(|a, b| a.side.cmp(&b.side));
+    Command::ShowCardDetails(CardDetailsView {
+        id,
+        faces: faces.into_iter().map(face).collect(),
+        rulings: rulings
+            .iter()
+            .map(|r| CardRulingView { date: r.date.clone(), text: r.text.clone() })
+            .collect(),
+        trigger_buttons,
+    })
+}
+
+/// Returns buttons to toggle whether the game always stops for priority for
+/// each triggered ability of a card controlled by `player`.
+pub fn trigger_buttons(
+    game: &GameState,
+    player: PlayerName,
+    card_id: CardId,
+) -> Vec<GameButtonView> {
+    let Some(card) = game.card(card_id) else {
+        return vec![];
+    };
+    if card.controller() != player {
+        return vec![];
+    }
+
+    let always_ask = &game.player(player).options.always_ask_triggers;
+    let oracle_text = card.printed().face.oracle_text.clone().unwrap_or_default();
+    let lines = oracle_text.lines().collect::<Vec<_>>();
+    definitions::get(card.card_name)
+        .iterate_abilities()
+        .filter(|(_, ability)| ability.get_ability_type() == AbilityType::Triggered)
+        .map(|(number, _)| {
+            let ability_id = AbilityId { card_id, number };
+            let text = lines.get(number.0).copied().unwrap_or("Triggered ability");
+            let label = if always_ask.contains(&(card.card_name, number)) {
+                format!("Auto-resolve: {text}")
+            } else {
+                format!("Always ask: {text}")
+            };
+            GameButtonView::new_default(
+                label,
+                UserAction::ToggleAlwaysAskTrigger(ability_id, card.card_name),
+            )
+        })
+        .collect()
+}
+
+fn face(face: &DatabaseCardFace) -> CardDetailsFaceView {
+    CardDetailsFaceView {
+        name: face.face_name.clone().unwrap_or_else(|| face.name.clone()),
+        type_line: face.type_line.clone(),
+        mana_cost: face.mana_cost.as_deref().map(mana_symbols).unwrap_or_default(),
+        oracle_text: face.text.clone(),
+        rich_oracle_text: face.text.as_deref().map(rich_text::tokenize).unwrap_or_default(),
+        power_toughness: match (&face.power, &face.toughness) {
+            (Some(power), Some(toughness)) => Some(format!("{power}/{toughness}")),
+            _ => None,
+        },
+    }
+}
+
+/// Splits a mana cost string like `{2}{W}{W}` into its component symbols.
+fn mana_symbols(cost: &str) -> Vec<String> {
+    cost.split(['{', '}']).filter(|s| !s.is_empty()).map(str::to_string).collect()
+}
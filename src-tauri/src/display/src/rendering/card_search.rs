@@ -0,0 +1,120 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::card_state::CardState;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::printed_cards::printed_card::PrintedCardFace;
+use primitives::game_primitives::{PlayerName, Zone};
+use rules::queries::card_queries;
+
+use crate::commands::command::Command;
+use crate::core::card_search_view::{CardSearchResultView, CardSearchSectionView, CardSearchView};
+use crate::core::card_view::ClientCardId;
+use crate::rendering::zone_contents;
+
+/// Zones which are displayed in the card browser, in display order.
+const SEARCH_ZONES: [Zone; 5] =
+    [Zone::Graveyard, Zone::Exiled, Zone::Command, Zone::Hand, Zone::Library];
+
+/// Returns a command to display all cards visible to `player` in graveyards,
+/// exile, the command zone, or revealed in a hidden zone which match `query`.
+///
+/// A card matches if every whitespace-separated word of the query appears,
+/// case-insensitively, in its name, type line, or oracle text. Cards on the
+/// battlefield or stack are not included since they are already visible on
+/// the board.
+pub fn render(game: &GameState, player: PlayerName, query: &str) -> Command {
+    let words = query.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>();
+    let mut sections = vec![];
+    for owner in game.configuration.all_players {
+        for zone in SEARCH_ZONES {
+            let cards = zone_contents::card_ids(game, owner, zone)
+                .into_iter()
+                .filter_map(|id| game.card(id))
+                .filter(|card| is_visible(game, player, card))
+                .filter(|card| matches(card, &words))
+                .map(|card| CardSearchResultView {
+                    card: ClientCardId::new(card.id),
+                    name: card.displayed_name().to_string(),
+                    type_line: type_line(&card.printed().face),
+                })
+                .collect::<Vec<_>>();
+            if !cards.is_empty() {
+                sections.push(CardSearchSectionView {
+                    title: zone_contents::title(game, player, owner, zone),
+                    cards,
+                });
+            }
+        }
+    }
+
+    Command::ShowCardSearchResults(CardSearchView { query: query.to_string(), sections })
+}
+
+fn is_visible(game: &GameState, player: PlayerName, card: &CardState) -> bool {
+    if card.zone == Zone::Hand && card.owner == player {
+        // The viewer's own hand is always displayed on the board.
+        return false;
+    }
+    card_queries::is_visible_to(game, player, card)
+}
+
+fn matches(card: &CardState, words: &[String]) -> bool {
+    let text = card
+        .printed()
+        .all_faces()
+        .map(|face| {
+            format!(
+                "{} {} {}",
+                face.displayed_name,
+                type_line(face),
+                face.oracle_text.as_deref().unwrap_or_default()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    words.iter().all(|word| text.contains(word.as_str()))
+}
+
+fn type_line(face: &PrintedCardFace) -> String {
+    let types = face
+        .supertypes
+        .iter()
+        .map(|t| format!("{t:?}"))
+        .chain(face.card_types.iter().map(|t| format!("{t:?}")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let subtypes = face
+        .subtypes
+        .artifact
+        .iter()
+        .map(|t| format!("{t:?}"))
+        .chain(face.subtypes.enchantment.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.land.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.planeswalker.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.instant_or_sorcery_subtype.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.creature.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.plane.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.dungeon.iter().map(|t| format!("{t:?}")))
+        .chain(face.subtypes.battle.iter().map(|t| format!("{t:?}")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if subtypes.is_empty() {
+        types
+    } else {
+        format!("{types} — {subtypes}")
+    }
+}
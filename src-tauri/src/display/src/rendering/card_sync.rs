@@ -18,15 +18,17 @@ use data::actions::user_action::UserAction;
 use data::card_states::card_kind::CardKind;
 use data::card_states::card_state::{CardFacing, CardState, TappedState};
 use data::card_states::zones::ZoneQueries;
+use data::game_states::combat_state::CombatState;
 use data::game_states::game_state::GameState;
+use data::printed_cards::custom_card;
 use data::printed_cards::printed_card::{Face, PrintedCardFace};
 use data::printed_cards::printed_card_id::PrintedCardId;
 use data::prompts::prompt::{Prompt, PromptType};
-use primitives::game_primitives::{PlayerName, Source};
-use rules::legality::legal_actions;
+use primitives::game_primitives::{HasController, PlayerName, Source, Zone};
+use rules::legality::{illegal_action, legal_actions, legal_prompt_actions};
 use rules::play_cards::play_card;
-use rules::queries::combat_queries;
 use rules::queries::combat_queries::CombatRole;
+use rules::queries::{card_queries, combat_queries};
 
 use crate::core::card_view::{
     CardView, ClientCardId, RevealedCardFace, RevealedCardStatus, RevealedCardView,
@@ -34,33 +36,62 @@ use crate::core::card_view::{
 use crate::core::object_position::ObjectPosition;
 use crate::core::response_builder::ResponseBuilder;
 use crate::rendering::card_view_context::CardViewContext;
-use crate::rendering::positions;
+use crate::rendering::{positions, rich_text};
+
+/// Image URL for the back of a card.
+const CARD_BACK: &str = "https://i.imgur.com/gCqKv0M.png";
 
 /// Builds a display representation of the state of a single card or card-like
 /// object
 pub fn card_view(builder: &ResponseBuilder, context: &CardViewContext) -> CardView {
-    let is_revealed = context
-        .query_or(true, |_, card| card.revealed_to.contains(builder.display_as_player()))
-        || builder.response_state.reveal_all_cards;
+    let is_revealed = context.query_or(true, |_, card| builder.is_visible(card));
     CardView {
         id: ClientCardId::new(context.card_id()),
         position: context.query_or(ObjectPosition::default(), |game, card| {
             positions::calculate(builder, game, card)
         }),
-        card_back: "https://i.imgur.com/gCqKv0M.png".to_string(),
+        card_back: CARD_BACK.to_string(),
         revealed: is_revealed.then(|| RevealedCardView {
-            image: card_image(context.printed_card_id(), context.image_face()),
+            image: context.query_or(
+                card_image(context.printed_card_id(), context.image_face()),
+                merged_image,
+            ),
             face: card_face(&context.printed().face),
             status: context.query_or(None, |game, card| card_status(builder, game, card)),
             is_ability: false,
             is_token: context.query_or(false, |_, card| card.kind == CardKind::TokenOrStackCopy),
+            is_custom: context.printed().custom,
             click_action: context.query_or(None, |game, card| card_action(builder, game, card)),
             can_drag: context.query_or(false, |game, card| can_drag(builder, game, card)),
             face_b: context.printed().face_b.as_ref().map(card_face),
             layout: context.printed().layout,
+            saga_chapter: context.query_or(None, |game, card| {
+                if card.saga_chapters.is_empty() {
+                    return None;
+                }
+                card_queries::lore_counters(game, card.permanent_id()?)
+            }),
+            battle_defense: context.query_or(None, |game, card| {
+                card.protector?;
+                card_queries::defense(game, card.permanent_id()?)
+            }),
+            merged_with: context.query_or(vec![], |game, card| {
+                card.merged_components
+                    .iter()
+                    .filter(|&&id| id != card.id)
+                    .filter_map(|&id| Some(game.card(id)?.displayed_name().to_string()))
+                    .collect()
+            }),
+            on_an_adventure: context.query_or(false, |game, card| {
+                card_queries::on_an_adventure(game, card.id).is_some()
+            }),
+            foretold: context
+                .query_or(false, |game, card| card_queries::foretold_turn(game, card.id).is_some()),
+            disabled_reason: context
+                .query_or(None, |game, card| disabled_reason(builder, game, card)),
         }),
         revealed_to_opponents: context
-            .query_or(false, |_, card| !card.zone.is_public() && card.revealed_to.len() > 1),
+            .query_or(false, |_, card| !card.zone.is_public() && card.visible_to().len() > 1),
         card_facing: context.query_or(CardFacing::FaceUp(Face::Primary), |_, card| card.facing),
         tapped_state: context.query_or(TappedState::Untapped, |_, card| card.tapped_state),
         damage: Default::default(),
@@ -82,6 +113,11 @@ fn card_face(printed: &PrintedCardFace) -> RevealedCardFace {
         name: printed.displayed_name.clone(),
         layout: printed.layout,
         rules_text: printed.oracle_text.clone(),
+        rich_rules_text: printed
+            .oracle_text
+            .as_deref()
+            .map(rich_text::tokenize)
+            .unwrap_or_default(),
     }
 }
 
@@ -99,7 +135,11 @@ fn card_status(
     {
         Some(RevealedCardStatus::CanPlay)
     } else {
-        match combat_queries::role(game, card.permanent_id()?) {
+        let permanent_id = card.permanent_id()?;
+        match combat_queries::role(game, permanent_id) {
+            None if !combat_queries::goaded_by(game, permanent_id).is_empty() => {
+                Some(RevealedCardStatus::Goaded("Goaded".to_string()))
+            }
             None => None,
             Some(CombatRole::SelectedAttacker) => {
                 Some(RevealedCardStatus::Attacking("SA".to_string()))
@@ -123,6 +163,32 @@ fn card_status(
     }
 }
 
+/// Explains why the player can't play a card in their hand or declare a
+/// creature they control as an attacker, if applicable.
+fn disabled_reason(
+    builder: &ResponseBuilder,
+    game: &GameState,
+    card: &CardState,
+) -> Option<String> {
+    if !builder.allow_actions() || builder.current_prompt().is_some() {
+        return None;
+    }
+
+    let player = builder.act_as_player(game);
+    if card.controller() != player || card_action(builder, game, card).is_some() {
+        return None;
+    }
+
+    let action = match card.zone {
+        Zone::Hand => GameAction::ProposePlayingCard(card.id),
+        Zone::Battlefield if matches!(game.combat, Some(CombatState::ProposingAttackers(_))) => {
+            CombatAction::AddSelectedAttacker(card.permanent_id()?).into()
+        }
+        _ => return None,
+    };
+    Some(builder.text(illegal_action::explain(game, player, &action)?))
+}
+
 fn prompt_card_status(
     builder: &ResponseBuilder,
     game: &GameState,
@@ -134,6 +200,14 @@ fn prompt_card_status(
             return Some(RevealedCardStatus::CanSelect);
         }
     }
+    if let PromptType::PayMana(pay_mana) = &prompt.prompt_type {
+        let permanent_id = card.permanent_id()?;
+        if pay_mana.selected.contains(&permanent_id) {
+            return Some(RevealedCardStatus::Selected);
+        } else if pay_mana.sources.contains_key(&permanent_id) {
+            return Some(RevealedCardStatus::CanSelect);
+        }
+    }
     None
 }
 
@@ -152,10 +226,29 @@ fn card_action(
         return prompt_card_action(builder, game, prompt, card);
     }
 
+    if legal_actions::next_to_act(game, None) != Some(player) {
+        // Another player is deciding, cards in hand can be queued to play once
+        // the player is able to act.
+        return (card.zone == Zone::Hand && card.owner == player)
+            .then_some(UserAction::QueueGameAction(GameAction::ProposePlayingCard(card.id)));
+    }
+
     if play_card::can_play_card(game, player, Source::Game, card.id) {
         return Some(GameAction::ProposePlayingCard(card.id).into());
     }
 
+    if legal_actions::can_take_action(game, player, &GameAction::Foretell(card.id)) {
+        return Some(GameAction::Foretell(card.id).into());
+    }
+
+    if legal_actions::can_take_action(
+        game,
+        player,
+        &GameAction::CombatAction(CombatAction::Ninjutsu(card.id)),
+    ) {
+        return Some(CombatAction::Ninjutsu(card.id).into());
+    }
+
     let permanent_id = card.permanent_id()?;
     if legal_actions::can_take_action(
         game,
@@ -203,6 +296,12 @@ fn prompt_card_action(
             return Some(PromptAction::SelectEntity(card.entity_id()).into());
         }
     }
+    if let PromptType::PayMana(_) = &prompt.prompt_type {
+        let action = PromptAction::ToggleManaSource(card.permanent_id()?);
+        if legal_prompt_actions::can_take_action(prompt, builder.act_as_player(game), action) {
+            return Some(action.into());
+        }
+    }
     None
 }
 
@@ -220,7 +319,24 @@ fn can_drag(builder: &ResponseBuilder, game: &GameState, card: &CardState) -> bo
     false
 }
 
+/// Returns the image to display for a permanent, using the image of the
+/// topmost component if it is a merged permanent.
+fn merged_image(game: &GameState, card: &CardState) -> String {
+    let top = card.merged_components.first().and_then(|&id| game.card(id)).unwrap_or(card);
+    let face = match top.facing {
+        CardFacing::FaceUp(Face::FaceB) => Face::FaceB,
+        _ => Face::Primary,
+    };
+    card_image(top.printed_card_id, face)
+}
+
+/// Returns the image URL for a face of a printed card.
+///
+/// Custom cards have no image, so the card back is used instead.
 pub fn card_image(card_id: PrintedCardId, face: Face) -> String {
+    if custom_card::get(card_id).is_some() {
+        return CARD_BACK.to_string();
+    }
     let id = card_id.0.to_string();
     let dir1 = id.chars().next().unwrap();
     let dir2 = id.chars().nth(1).unwrap();
@@ -0,0 +1,35 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::game_states::chat_log::ChatLog;
+use primitives::game_primitives::PlayerName;
+
+use crate::commands::command::Command;
+use crate::core::chat_view::{ChatMessageView, ChatView};
+
+/// Returns a command to replace the contents of the chat window with the
+/// messages in the provided [ChatLog], as seen by `player`.
+pub fn render(chat: &ChatLog, player: PlayerName) -> Command {
+    Command::UpdateChat(ChatView {
+        messages: chat
+            .messages
+            .iter()
+            .map(|message| ChatMessageView {
+                from_self: message.sender == player,
+                turn_number: message.turn_number,
+                text: message.content.text().to_string(),
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,57 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::user_action::UserAction;
+use data::decks::deck::Deck;
+use data::decks::deck_catalog::{CatalogDeck, DeckCatalog};
+use data::decks::format::Format;
+use data::users::user_state::UserState;
+
+use crate::commands::command::Command;
+use crate::core::deck_catalog_view::{DeckCatalogView, DeckSummaryView};
+use crate::core::game_view::GameButtonView;
+
+/// Returns a command to display the list of pre-constructed decks available to
+/// the indicated user.
+///
+/// Locked decks are omitted unless the user has unlocked them. The formats
+/// each deck is legal in are computed via `legal_formats`.
+pub fn render(
+    catalog: &DeckCatalog,
+    user: &UserState,
+    legal_formats: impl Fn(&Deck) -> Vec<Format>,
+) -> Command {
+    let selected = catalog.selected_deck(user);
+    Command::ShowDeckCatalog(DeckCatalogView {
+        decks: catalog
+            .decks
+            .iter()
+            .filter(|deck| deck.is_available(&user.campaign))
+            .map(|deck| summary(deck, legal_formats(&deck.deck), deck.id == selected))
+            .collect(),
+    })
+}
+
+fn summary(deck: &CatalogDeck, legal_formats: Vec<Format>, selected: bool) -> DeckSummaryView {
+    DeckSummaryView {
+        id: deck.id,
+        name: deck.name.clone(),
+        colors: deck.colors.iter().map(|color| color.to_string()).collect(),
+        description: deck.description.clone(),
+        card_count: deck.deck.cards.values().sum::<u64>() as u32,
+        legal_formats: legal_formats.iter().map(|format| format.to_string()).collect(),
+        select: (!selected)
+            .then(|| GameButtonView::new_default("Select", UserAction::SelectDeck(deck.id))),
+    }
+}
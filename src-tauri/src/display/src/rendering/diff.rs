@@ -0,0 +1,143 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::Value;
+
+use crate::commands::command::{Command, SceneView};
+use crate::core::card_view::CardView;
+use crate::core::game_view::{CardUpdate, GameView, GameViewDiff, MoveCardUpdate};
+
+/// Replaces full [GameView] updates in `commands` with [GameViewDiff]s which
+/// only describe changes since the previous view sent to a client.
+///
+/// `previous` is the most recent [GameView] sent to the client, if any. If it
+/// is None, the first game view in `commands` is sent in full. It is updated
+/// to the last game view in `commands`.
+pub fn run(previous: &mut Option<GameView>, commands: Vec<Command>) -> Vec<Command> {
+    commands
+        .into_iter()
+        .map(|command| match command {
+            Command::UpdateScene(SceneView::GameView(view)) => {
+                let result = match previous.as_ref() {
+                    Some(previous) => Command::UpdateGameView(diff(previous, &view)),
+                    None => Command::UpdateScene(SceneView::GameView(view.clone())),
+                };
+                *previous = Some(view);
+                result
+            }
+            Command::UpdateScene(scene) => {
+                // Client is no longer displaying a game view
+                *previous = None;
+                Command::UpdateScene(scene)
+            }
+            command => command,
+        })
+        .collect()
+}
+
+/// Applies the changes described by a [GameViewDiff] to the previous
+/// [GameView], returning the new game view.
+///
+/// This is the inverse of the diffing performed by [run], for use by clients
+/// which are implemented in Rust.
+pub fn apply(previous: &GameView, diff: GameViewDiff) -> GameView {
+    let mut cards = previous.cards.clone();
+    for update in diff.card_updates {
+        match update {
+            CardUpdate::UpdateCard(card) => {
+                match cards.iter_mut().find(|existing| existing.id == card.id) {
+                    Some(existing) => *existing = *card,
+                    None => cards.push(*card),
+                }
+            }
+            CardUpdate::MoveCard(MoveCardUpdate { id, position }) => {
+                if let Some(card) = cards.iter_mut().find(|card| card.id == id) {
+                    card.position = position;
+                }
+            }
+            CardUpdate::RemoveCard(id) => {
+                cards.retain(|card| card.id != id);
+            }
+        }
+    }
+    GameView { cards, ..diff.view }
+}
+
+/// Computes the changes required to turn `previous` into `new`.
+fn diff(previous: &GameView, new: &GameView) -> GameViewDiff {
+    let previous_cards =
+        previous.cards.iter().map(|card| (&card.id, card)).collect::<HashMap<_, _>>();
+    let mut card_updates = vec![];
+
+    for card in &new.cards {
+        let Some(previous_card) = previous_cards.get(&card.id) else {
+            card_updates.push(CardUpdate::UpdateCard(Box::new(card.clone())));
+            continue;
+        };
+
+        let mut previous_value = to_value(previous_card);
+        let mut new_value = to_value(card);
+        if previous_value == new_value {
+            continue;
+        }
+
+        remove_position(&mut previous_value);
+        remove_position(&mut new_value);
+        if previous_value == new_value {
+            card_updates.push(CardUpdate::MoveCard(MoveCardUpdate {
+                id: card.id.clone(),
+                position: card.position.clone(),
+            }));
+        } else {
+            card_updates.push(CardUpdate::UpdateCard(Box::new(card.clone())));
+        }
+    }
+
+    let new_ids = new.cards.iter().map(|card| &card.id).collect::<HashSet<_>>();
+    for card in &previous.cards {
+        if !new_ids.contains(&card.id) {
+            card_updates.push(CardUpdate::RemoveCard(card.id.clone()));
+        }
+    }
+
+    GameViewDiff {
+        view: GameView {
+            viewer: new.viewer.clone(),
+            opponent: new.opponent.clone(),
+            cards: vec![],
+            stack: new.stack.clone(),
+            status_description: new.status_description.clone(),
+            card_drag_targets: new.card_drag_targets.clone(),
+            state: new.state,
+            top_controls: new.top_controls.clone(),
+            bottom_controls: new.bottom_controls.clone(),
+            active_plane: new.active_plane.clone(),
+        },
+        card_updates,
+    }
+}
+
+/// Card views are compared via their serialized representation, since this is
+/// exactly the data the client receives.
+fn to_value(card: &CardView) -> Value {
+    serde_json::to_value(card).expect("Failed to serialize card view")
+}
+
+fn remove_position(value: &mut Value) {
+    if let Value::Object(map) = value {
+        map.remove("position");
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::card_state::Visibility;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
+use data::game_states::random_result::RandomResult;
+use primitives::game_primitives::{CardId, HasController, PlayerName, Zone};
+
+use crate::commands::command::Command;
+use crate::core::game_log_view::{GameLogEntryView, GameLogView};
+use crate::core::response_builder::ResponseBuilder;
+
+/// Adds a command to update the game log with the recent history of the
+/// provided game.
+pub fn run(builder: &mut ResponseBuilder, game: &GameState) {
+    let entries = game
+        .history
+        .log()
+        .filter_map(|(turn, event)| {
+            Some(GameLogEntryView {
+                turn_number: turn.turn_number,
+                text: describe(builder, game, event)?,
+            })
+        })
+        .collect();
+    builder.push(Command::UpdateGameLog(GameLogView { entries }));
+}
+
+/// Returns a human-readable description of a [HistoryEvent], or None if this
+/// event should not be displayed in the log.
+fn describe(builder: &ResponseBuilder, game: &GameState, event: &HistoryEvent) -> Option<String> {
+    Some(match event {
+        HistoryEvent::CastSpell(player, card_id) => {
+            format!("{} cast {}", player_name(builder, *player), card_name(game, *card_id, true))
+        }
+        HistoryEvent::AttackWithCreature(attacker_id, _) => {
+            format!("{} attacked", card_name(game, attacker_id.internal_card_id, true))
+        }
+        HistoryEvent::DamageToPlayer(player, damage) => {
+            format!("{} took {} damage", player_name(builder, *player), damage)
+        }
+        HistoryEvent::DamageToPermanent(permanent_id, damage) => {
+            format!(
+                "{} was dealt {} damage",
+                card_name(game, permanent_id.internal_card_id, true),
+                damage
+            )
+        }
+        HistoryEvent::LifeGained(player, amount) => {
+            format!("{} gained {} life", player_name(builder, *player), amount)
+        }
+        HistoryEvent::LifeLost(player, amount) => {
+            format!("{} lost {} life", player_name(builder, *player), amount)
+        }
+        HistoryEvent::ZoneChange(card_id, old, new) => {
+            let owner = game.card(*card_id).map(|c| c.owner);
+            let visible = zone_change_visible(builder, game, *card_id, *new);
+            let name = card_name(game, *card_id, visible);
+            let player = owner.map_or_else(|| "A player".to_string(), |p| player_name(builder, p));
+            match (old, new) {
+                (Zone::Hand, Zone::Stack) => return None,
+                (Zone::Library, Zone::Hand) => format!("{player} drew {name}"),
+                (Zone::Hand, Zone::Battlefield) => format!("{player} played {name}"),
+                (Zone::Stack, Zone::Battlefield) => format!("{name} resolved"),
+                (Zone::Battlefield, Zone::Graveyard) => format!("{name} died"),
+                (_, Zone::Exiled) => format!("{name} was exiled"),
+                _ => format!("{name} moved from {old:?} to {new:?}"),
+            }
+        }
+        HistoryEvent::AbilityTriggered(ability_id) => {
+            format!("{} triggered", card_name(game, ability_id.card_id, true))
+        }
+        HistoryEvent::RandomResult(RandomResult::CoinFlip { player, call, result }) => {
+            let player = player_name(builder, *player);
+            match call {
+                Some(call) if call == result => format!("{player} won a coin flip ({result:?})"),
+                Some(_) => format!("{player} lost a coin flip ({result:?})"),
+                None => format!("{player} flipped {result:?}"),
+            }
+        }
+        HistoryEvent::RandomResult(RandomResult::DieRoll { player, sides, result }) => {
+            format!("{} rolled {} on a d{}", player_name(builder, *player), result, sides)
+        }
+        HistoryEvent::RandomResult(RandomResult::PlanarDie { player, result }) => {
+            format!("{} rolled {:?} on the planar die", player_name(builder, *player), result)
+        }
+        HistoryEvent::RandomResult(RandomResult::Clash { player, won, .. }) => {
+            let player = player_name(builder, *player);
+            format!("{player} {} a clash", if *won { "won" } else { "lost" })
+        }
+    })
+}
+
+/// Returns true if the identity of a card which moved to the `new` zone should
+/// be displayed.
+///
+/// Uses the card's current visibility if it is still in that zone, e.g. to
+/// hide cards exiled face down, and otherwise falls back to the default
+/// [Visibility] for the zone.
+fn zone_change_visible(
+    builder: &ResponseBuilder,
+    game: &GameState,
+    card_id: CardId,
+    new: Zone,
+) -> bool {
+    let Some(card) = game.card(card_id) else {
+        return builder.response_state.reveal_all_cards;
+    };
+    if card.zone == new {
+        return builder.is_visible(card);
+    }
+    builder.response_state.reveal_all_cards
+        || match Visibility::for_zone(new) {
+            Visibility::None => false,
+            Visibility::OwnerOnly => card.owner == builder.display_as_player(),
+            Visibility::ControllerOnly => card.controller() == builder.display_as_player(),
+            Visibility::AllPlayers => true,
+        }
+}
+
+fn player_name(builder: &ResponseBuilder, player: PlayerName) -> String {
+    if player == builder.display_as_player() {
+        "You".to_string()
+    } else {
+        "Opponent".to_string()
+    }
+}
+
+fn card_name(game: &GameState, card_id: CardId, visible: bool) -> String {
+    match game.card(card_id) {
+        Some(card) if visible => card.displayed_name().to_string(),
+        _ => "a card".to_string(),
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use data::decks::deck_catalog::DeckCatalog;
+use data::decks::deck_name::DeckName;
+use data::users::match_record::MatchRecord;
+use data::users::user_statistics::{GameResult, UserStatistics};
+
+use crate::commands::command::Command;
+use crate::core::match_statistics_view::{MatchHistoryEntryView, MatchStatisticsView, WinRateView};
+
+/// Maximum number of individual games to include in the match history.
+const RECENT_MATCH_COUNT: usize = 20;
+
+/// Returns a command to display aggregated win rates for the provided match
+/// history.
+pub fn render(catalog: &DeckCatalog, records: &[MatchRecord]) -> Command {
+    let mut records = records.iter().collect::<Vec<_>>();
+    records.sort_by_key(|record| std::cmp::Reverse(record.finished_at));
+
+    Command::ShowMatchStatistics(MatchStatisticsView {
+        decks: win_rates(&records, |record| deck_name(catalog, record.deck)),
+        opponents: win_rates(&records, |record| record.opponent.clone()),
+        recent_matches: records
+            .iter()
+            .take(RECENT_MATCH_COUNT)
+            .map(|record| MatchHistoryEntryView {
+                deck: deck_name(catalog, record.deck),
+                opponent_deck: deck_name(catalog, record.opponent_deck),
+                opponent: record.opponent.clone(),
+                result: format!("{:?}", record.result),
+                turns: record.turns as u32,
+                duration_seconds: record.duration.map(|duration| duration.as_secs() as u32),
+            })
+            .collect(),
+    })
+}
+
+fn win_rates(records: &[&MatchRecord], group: impl Fn(&MatchRecord) -> String) -> Vec<WinRateView> {
+    let mut groups = BTreeMap::<String, UserStatistics>::new();
+    for record in records {
+        groups.entry(group(record)).or_default().record(record.result);
+    }
+
+    groups
+        .into_iter()
+        .map(|(name, stats)| WinRateView {
+            name,
+            games: stats.games_played,
+            wins: stats.wins,
+            losses: stats.losses,
+            draws: stats.draws,
+            win_rate: stats.wins as f64 / stats.games_played as f64,
+        })
+        .collect()
+}
+
+fn deck_name(catalog: &DeckCatalog, deck: DeckName) -> String {
+    catalog.get(deck).map_or_else(|| "Unknown Deck".to_string(), |deck| deck.name.clone())
+}
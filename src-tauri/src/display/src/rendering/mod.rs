@@ -14,8 +14,23 @@
 
 mod ability_sync;
 pub mod animations;
+pub mod board_description;
+pub mod card_details;
+pub mod card_search;
 pub mod card_sync;
 pub mod card_view_context;
+pub mod chat;
+pub mod deck_catalog;
+pub mod diff;
+pub mod game_log;
+pub mod match_statistics;
+pub mod performance_hud;
 pub mod positions;
+pub mod random_results;
+pub mod recent_changes;
 pub mod render;
+pub mod rich_text;
+pub mod sounds;
+pub mod stack_sync;
 pub mod sync;
+pub mod zone_contents;
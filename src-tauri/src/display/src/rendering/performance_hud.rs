@@ -0,0 +1,48 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use utils::span_timing;
+
+use crate::commands::command::Command;
+use crate::core::performance_hud_view::{OperationTimingView, PerformanceHudView};
+use crate::core::response_builder::ResponseBuilder;
+
+/// Populates a command in this [ResponseBuilder] containing the latency of
+/// recent rules engine operations.
+pub fn run(builder: &mut ResponseBuilder) {
+    builder.push(Command::UpdatePerformanceHud(Some(PerformanceHudView {
+        timings: span_timing::summary()
+            .into_iter()
+            .map(|timing| OperationTimingView {
+                name: operation_name(timing.name),
+                count: u32::try_from(timing.count).unwrap_or(u32::MAX),
+                p50_millis: timing.p50.as_secs_f64() * 1000.0,
+                p95_millis: timing.p95.as_secs_f64() * 1000.0,
+            })
+            .collect(),
+    })));
+}
+
+fn operation_name(span: &str) -> String {
+    match span {
+        "actions_execute" => "Game action",
+        "legal_actions_compute" => "Legal actions",
+        "card_queries_power" => "Power query",
+        "card_queries_toughness" => "Toughness query",
+        "card_queries_card_types" => "Card types query",
+        "render_updates" => "Render",
+        _ => span,
+    }
+    .to_string()
+}
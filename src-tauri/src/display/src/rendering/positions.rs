@@ -19,6 +19,7 @@ use data::prompts::prompt::PromptType;
 use data::prompts::select_order_prompt::CardOrderLocation;
 use primitives::game_primitives::{CardType, EntityId, HasController, PlayerName, Zone};
 
+use crate::core::card_view::ClientCardId;
 use crate::core::object_position::{BattlefieldPosition, ObjectPosition, Position};
 use crate::core::response_builder::ResponseBuilder;
 
@@ -28,6 +29,15 @@ pub fn calculate(builder: &ResponseBuilder, _game: &GameState, card: &CardState)
         return position;
     }
 
+    if let Some(merged_into) = card.merged_into {
+        // Components of a merged permanent are displayed as part of the card
+        // representing that permanent.
+        return for_card(
+            card,
+            Position::InsideCard(ClientCardId::new(merged_into.internal_card_id)),
+        );
+    }
+
     let owner = builder.to_display_player(card.owner);
     let position = match card.zone {
         Zone::Hand => Position::Hand(owner),
@@ -72,6 +82,22 @@ pub fn discard(builder: &ResponseBuilder, player: PlayerName) -> Position {
     Position::DiscardPile(builder.to_display_player(player))
 }
 
+/// Returns the default [Position] for a card owned by `owner` in the given
+/// [Zone].
+pub fn for_zone(builder: &ResponseBuilder, owner: PlayerName, zone: Zone) -> Position {
+    let player = builder.to_display_player(owner);
+    match zone {
+        Zone::Hand => Position::Hand(player),
+        Zone::Graveyard => Position::DiscardPile(player),
+        Zone::Library => Position::Deck(player),
+        Zone::Battlefield => Position::Battlefield(player, BattlefieldPosition::Permanents),
+        Zone::Stack => Position::Stack,
+        Zone::Exiled => Position::Exile(player),
+        Zone::Command => Position::CommandZone(player),
+        Zone::OutsideTheGame => Position::Offscreen,
+    }
+}
+
 fn position_override(builder: &ResponseBuilder, card: &CardState) -> Option<ObjectPosition> {
     if let Some(prompt) = builder.current_prompt() {
         match &prompt.prompt_type {
@@ -0,0 +1,81 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::game_states::random_result::RandomResult;
+use data::prompts::game_update::{AnimationStep, GameAnimation};
+
+use crate::commands::command::Command;
+use crate::core::card_view::ClientCardId;
+use crate::core::random_result_view::{
+    ClashRevealView, ClashView, CoinFlipView, DieRollView, PlanarDieView, RandomResultView,
+};
+use crate::core::response_builder::ResponseBuilder;
+
+/// Populates a command in this [ResponseBuilder] for each coin flip, die roll,
+/// or clash in the provided [AnimationStep]s.
+///
+/// Results are shown to every player regardless of whether animations are
+/// enabled, since they are often needed to understand what happened.
+pub fn render(builder: &mut ResponseBuilder, steps: &[AnimationStep], game: &GameState) {
+    for step in steps {
+        if let GameAnimation::RandomResult(result) = &step.animation {
+            let view = result_view(builder, result, game);
+            builder.push(Command::ShowRandomResult(view));
+        }
+    }
+}
+
+fn result_view(
+    builder: &ResponseBuilder,
+    result: &RandomResult,
+    game: &GameState,
+) -> RandomResultView {
+    match result {
+        RandomResult::CoinFlip { player, call, result } => {
+            RandomResultView::CoinFlip(CoinFlipView {
+                player: builder.to_display_player(*player),
+                call: call.map(|c| builder.text(c)),
+                result: builder.text(*result),
+                won: call.map(|c| c == *result),
+            })
+        }
+        RandomResult::DieRoll { player, sides, result } => RandomResultView::DieRoll(DieRollView {
+            player: builder.to_display_player(*player),
+            sides: *sides,
+            result: *result,
+        }),
+        RandomResult::PlanarDie { player, result } => RandomResultView::PlanarDie(PlanarDieView {
+            player: builder.to_display_player(*player),
+            result: builder.text(*result),
+        }),
+        RandomResult::Clash { player, revealed, won } => RandomResultView::Clash(ClashView {
+            player: builder.to_display_player(*player),
+            revealed: revealed
+                .iter()
+                .map(|reveal| ClashRevealView {
+                    player: builder.to_display_player(reveal.player),
+                    card: reveal.card.map(ClientCardId::new),
+                    name: reveal
+                        .card
+                        .and_then(|id| game.card(id))
+                        .map(|card| card.displayed_name().to_string()),
+                    mana_value: reveal.mana_value as u32,
+                })
+                .collect(),
+            won: *won,
+        }),
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use data::card_states::card_state::CardState;
+use data::core::numerics::LifeValue;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use data::text_strings::{localization, Text};
+use primitives::game_primitives::{CardId, PlayerName, Zone};
+use rules::queries::card_queries;
+
+use crate::commands::command::Command;
+use crate::core::card_view::ClientCardId;
+use crate::core::recent_changes_view::{
+    CounterChangeView, LifeChangeView, MovedCardView, RecentChangesView,
+};
+
+/// Record of the parts of a game state which are summarized by
+/// [RecentChangesView], taken when the viewer has the opportunity to act.
+#[derive(Debug, Clone, Default)]
+pub struct BoardSnapshot {
+    zones: BTreeMap<CardId, Zone>,
+    counters: BTreeMap<CardId, u32>,
+    life: BTreeMap<PlayerName, LifeValue>,
+}
+
+/// Records the current state of the game for use with [render].
+pub fn snapshot(game: &GameState) -> BoardSnapshot {
+    BoardSnapshot {
+        zones: game.zones.all_cards().map(|card| (card.id, card.zone)).collect(),
+        counters: game.zones.all_cards().map(|card| (card.id, total_counters(card))).collect(),
+        life: game
+            .configuration
+            .all_players
+            .iter()
+            .map(|player| (player, game.player(player).life))
+            .collect(),
+    }
+}
+
+/// Returns a command summarizing the changes to the game since the `previous`
+/// snapshot was taken, or None if nothing relevant has changed.
+pub fn render(game: &GameState, player: PlayerName, previous: &BoardSnapshot) -> Option<Command> {
+    let language = game.player(player).options.language;
+    let mut moved_cards = vec![];
+    let mut counter_changes = vec![];
+    for card in game.zones.all_cards() {
+        let visible = card_queries::is_visible_to(game, player, card);
+        if let Some(&from) = previous.zones.get(&card.id) {
+            if from != card.zone {
+                moved_cards.push(MovedCardView {
+                    card: ClientCardId::new(card.id),
+                    name: name(card, visible),
+                    from: localization::localize(Text::Zone(from), language),
+                    to: localization::localize(Text::Zone(card.zone), language),
+                });
+            }
+        }
+
+        let current = total_counters(card);
+        if let Some(&before) = previous.counters.get(&card.id) {
+            if before != current && card.zone == Zone::Battlefield {
+                counter_changes.push(CounterChangeView {
+                    card: ClientCardId::new(card.id),
+                    name: name(card, visible),
+                    previous: before,
+                    current,
+                });
+            }
+        }
+    }
+
+    let life_changes = previous
+        .life
+        .iter()
+        .filter(|(&p, &life)| game.player(p).life != life)
+        .map(|(&p, &life)| LifeChangeView {
+            label: localization::localize(
+                if p == player { Text::YourLife } else { Text::PlayersLife(p) },
+                language,
+            ),
+            previous: life as f64,
+            current: game.player(p).life as f64,
+        })
+        .collect::<Vec<_>>();
+
+    if moved_cards.is_empty() && life_changes.is_empty() && counter_changes.is_empty() {
+        return None;
+    }
+
+    Some(Command::ShowRecentChanges(RecentChangesView {
+        moved_cards,
+        life_changes,
+        counter_changes,
+    }))
+}
+
+fn total_counters(card: &CardState) -> u32 {
+    card.counters.p1p1
+        + card.counters.m1m1
+        + card.counters.loyalty as u32
+        + card.counters.other_counters.values().sum::<u32>()
+}
+
+fn name(card: &CardState, visible: bool) -> String {
+    if visible {
+        card.displayed_name().to_string()
+    } else {
+        "a card".to_string()
+    }
+}
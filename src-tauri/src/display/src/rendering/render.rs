@@ -13,13 +13,18 @@
 // limitations under the License.
 
 use data::game_states::game_state::{GameState, GameStatus};
+use data::player_states::player_state::PlayerQueries;
+use data::prompts::game_update::AnimationStep;
 use primitives::game_primitives::PlayerName;
+use tracing::instrument;
 
 use crate::commands::command::{Command, DisplayGameMessageCommand};
 use crate::core::display_state::DisplayState;
 use crate::core::game_message::GameMessage;
 use crate::core::response_builder::{AllowActions, ResponseBuilder, ResponseState};
-use crate::rendering::sync;
+use crate::rendering::{
+    animations, board_description, game_log, performance_hud, random_results, sounds, sync,
+};
 
 /// Returns a series of [Command]s which fully describe the current state of the
 /// provided game
@@ -31,8 +36,16 @@ pub fn connect(game: &GameState, player: PlayerName, display_state: &DisplayStat
         reveal_all_cards: game.configuration.debug.reveal_all_cards,
         act_as_player: game.configuration.debug.act_as_player,
         allow_actions: AllowActions::Yes,
+        language: game.player(player).options.language,
     });
     sync::run(&mut builder, game);
+    game_log::run(&mut builder, game);
+    if game.player(player).options.screen_reader {
+        board_description::run(&mut builder);
+    }
+    if game.player(player).options.performance_hud {
+        performance_hud::run(&mut builder);
+    }
 
     if let GameStatus::GameOver { winners } = game.status {
         builder.commands.push(Command::DisplayGameMessage(DisplayGameMessageCommand {
@@ -46,26 +59,39 @@ pub fn connect(game: &GameState, player: PlayerName, display_state: &DisplayStat
     builder.commands
 }
 
-/// Returns a series of commands which contain animations for recent changes to
-/// game states, followed by a snapshot of the current game state in the same
-/// manner as returned by [connect].
+/// Returns a series of commands which contain the provided animations and
+/// sound effects for recent changes to game states, followed by a snapshot of
+/// the current game state in the same manner as returned by [connect].
+#[instrument(name = "render_updates", level = "debug", skip_all)]
 pub fn render_updates(
     game: &GameState,
     player: PlayerName,
     display_state: &DisplayState,
     allow_actions: AllowActions,
+    animations: &[AnimationStep],
 ) -> Vec<Command> {
     let mut builder = ResponseBuilder::new(player, ResponseState {
-        animate: true,
+        animate: !game.player(player).options.reduce_motion,
         is_final_update: false,
         display_state,
         reveal_all_cards: game.configuration.debug.reveal_all_cards,
         act_as_player: game.configuration.debug.act_as_player,
         allow_actions,
+        language: game.player(player).options.language,
     });
 
+    animations::render(&mut builder, animations, game);
+    sounds::render(&mut builder, animations, game);
+    random_results::render(&mut builder, animations, game);
     builder.response_state.is_final_update = true;
     sync::run(&mut builder, game);
+    game_log::run(&mut builder, game);
+    if game.player(player).options.screen_reader {
+        board_description::run(&mut builder);
+    }
+    if game.player(player).options.performance_hud {
+        performance_hud::run(&mut builder);
+    }
 
     if let GameStatus::GameOver { winners } = game.status {
         builder.commands.push(Command::DisplayGameMessage(DisplayGameMessageCommand {
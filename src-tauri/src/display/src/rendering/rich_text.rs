@@ -0,0 +1,88 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::core::rich_text::TextRun;
+
+/// Maximum number of words in a line prefix which will be treated as an
+/// ability word.
+const MAX_ABILITY_WORD_LENGTH: usize = 4;
+
+/// Converts oracle text into a sequence of [TextRun]s.
+pub fn tokenize(text: &str) -> Vec<TextRun> {
+    let mut result = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            result.push(TextRun::LineBreak);
+        }
+        tokenize_line(line, &mut result);
+    }
+    result
+}
+
+fn tokenize_line(line: &str, result: &mut Vec<TextRun>) {
+    let mut rest = line;
+    if let Some((prefix, remainder)) = line.split_once(" — ") {
+        if is_ability_word(prefix) {
+            result.push(TextRun::AbilityWord(prefix.to_string()));
+            result.push(TextRun::Text(" — ".to_string()));
+            rest = remainder;
+        }
+    }
+
+    let mut text = String::new();
+    let mut chars = rest.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let closing = match c {
+            '{' => '}',
+            '(' => ')',
+            _ => {
+                text.push(c);
+                continue;
+            }
+        };
+        let Some(end) = rest[start..].find(closing).map(|offset| start + offset) else {
+            text.push(c);
+            continue;
+        };
+
+        if !text.is_empty() {
+            result.push(TextRun::Text(std::mem::take(&mut text)));
+        }
+        if c == '{' {
+            result.push(TextRun::Symbol(rest[start + 1..end].to_string()));
+        } else {
+            result.push(TextRun::Reminder(rest[start..=end].to_string()));
+        }
+        while chars.peek().is_some_and(|&(i, _)| i <= end) {
+            chars.next();
+        }
+    }
+
+    if !text.is_empty() {
+        result.push(TextRun::Text(text));
+    }
+}
+
+/// Returns true if `prefix` (the text before an em dash at the start of a
+/// line) looks like an ability word, e.g. "Landfall" or "Spell mastery".
+///
+/// Cost-bearing keywords such as "Equip—{2}" use an unspaced dash and are not
+/// matched here.
+fn is_ability_word(prefix: &str) -> bool {
+    let words = prefix.split(' ').collect::<Vec<_>>();
+    words.len() <= MAX_ABILITY_WORD_LENGTH
+        && prefix.chars().next().is_some_and(char::is_uppercase)
+        && prefix.chars().all(|c| c.is_alphabetic() || c == ' ' || c == '\'' || c == '-')
+        && !prefix.chars().all(|c| matches!(c, 'I' | 'V' | 'X' | ' '))
+}
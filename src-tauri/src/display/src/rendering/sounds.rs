@@ -0,0 +1,64 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::core::sound_effect::SoundEffect;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use data::prompts::game_update::{AnimationStep, GameAnimation};
+use primitives::game_primitives::{CardType, PlayerName, Zone};
+
+use crate::commands::command::Command;
+use crate::core::response_builder::ResponseBuilder;
+
+/// Populates a command in this [ResponseBuilder] to play sound effects
+/// describing the provided [AnimationStep]s.
+///
+/// Sounds the viewing player has muted are omitted.
+pub fn render(builder: &mut ResponseBuilder, steps: &[AnimationStep], game: &GameState) {
+    let player = builder.display_as_player();
+    let muted = game.player(player).options.muted_sounds;
+    let mut sounds = vec![];
+    for step in steps {
+        if let Some(sound) = sound_for_animation(&step.animation, game, player) {
+            if !sounds.contains(&sound) {
+                sounds.push(sound);
+            }
+        }
+    }
+
+    sounds.retain(|sound| !muted.contains(*sound));
+    if !sounds.is_empty() {
+        builder.commands.push(Command::PlaySounds(sounds));
+    }
+}
+
+fn sound_for_animation(
+    animation: &GameAnimation,
+    game: &GameState,
+    player: PlayerName,
+) -> Option<SoundEffect> {
+    match animation {
+        GameAnimation::DrawCard(..) => Some(SoundEffect::CardDrawn),
+        GameAnimation::ZoneMove(_, _, Zone::Stack) => Some(SoundEffect::SpellCast),
+        GameAnimation::ZoneMove(card_id, Zone::Battlefield, Zone::Graveyard)
+            if game.card(*card_id)?.printed().face.card_types.contains(CardType::Creature) =>
+        {
+            Some(SoundEffect::CreatureDies)
+        }
+        GameAnimation::GameOver(winners) if winners.contains(player) => Some(SoundEffect::GameWon),
+        GameAnimation::GameOver(_) => Some(SoundEffect::GameLost),
+        _ => None,
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::text_strings::Text;
+use primitives::game_primitives::{EntityId, HasController, StackItemId};
+
+use crate::core::card_view::ClientCardId;
+use crate::core::game_view::StackItemView;
+use crate::core::response_builder::ResponseBuilder;
+use crate::rendering::sync;
+
+/// Describes each item on the stack, from top to bottom.
+pub fn stack_view(builder: &ResponseBuilder, game: &GameState) -> Vec<StackItemView> {
+    game.stack().iter().rev().filter_map(|&item| stack_item_view(builder, game, item)).collect()
+}
+
+fn stack_item_view(
+    builder: &ResponseBuilder,
+    game: &GameState,
+    item: StackItemId,
+) -> Option<StackItemView> {
+    match item {
+        StackItemId::Spell(spell_id) => {
+            let card = game.card(spell_id)?;
+            Some(StackItemView {
+                card: ClientCardId::new(card.id),
+                controller: builder.to_display_player(card.controller()),
+                name: card.displayed_name().to_string(),
+                is_ability: false,
+                summary: card
+                    .face_up_printed_face()
+                    .and_then(|face| face.oracle_text.clone())
+                    .unwrap_or_default(),
+                modes: modes(builder, card.cast_choices.as_ref()),
+                targets: targets(game, &card.targets, &card.target_amounts),
+            })
+        }
+        StackItemId::StackAbility(stack_ability_id) => {
+            let ability = game.stack_ability(stack_ability_id);
+            let parent = game.card(ability.ability_id.card_id)?;
+            Some(StackItemView {
+                card: ClientCardId::new_for_stack_ability(stack_ability_id),
+                controller: builder.to_display_player(ability.controller),
+                name: parent.displayed_name().to_string(),
+                is_ability: true,
+                summary: parent.printed().face.oracle_text.clone().unwrap_or_default(),
+                modes: modes(builder, ability.choices.as_ref()),
+                targets: targets(game, &ability.targets, &[]),
+            })
+        }
+    }
+}
+
+fn modes(builder: &ResponseBuilder, choices: Option<&PlayCardChoices>) -> Vec<String> {
+    choices
+        .map(|choices| {
+            choices.modes.iter().map(|&mode| builder.text(Text::ModalChoice(mode))).collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Names each target, along with the amount assigned to it if an amount was
+/// divided among the targets.
+fn targets(game: &GameState, targets: &[EntityId], amounts: &[u32]) -> Vec<String> {
+    targets
+        .iter()
+        .enumerate()
+        .map(|(i, &target)| match amounts.get(i) {
+            Some(amount) => format!("{} ({amount})", sync::entity_name(game, target)),
+            None => sync::entity_name(game, target),
+        })
+        .collect()
+}
@@ -22,19 +22,22 @@ use data::game_states::combat_state::CombatState;
 use data::game_states::game_phase_step::GamePhaseStep;
 use data::game_states::game_state::GameState;
 use data::player_states::player_state::PlayerQueries;
+use data::printed_cards::printed_card::Face;
 use data::prompts::prompt::{Prompt, PromptType};
 use data::prompts::select_order_prompt::CardOrderLocation;
-use primitives::game_primitives::{PlayerName, Zone};
+use data::text_strings::Text;
+use primitives::game_primitives::{EntityId, PlayerName, Zone};
 use rules::legality::{can_undo, legal_actions, legal_prompt_actions};
+use rules::queries::planechase_queries;
 
 use crate::commands::field_state::FieldKey;
-use crate::core::display_state::DisplayState;
+use crate::core::card_view::ClientCardId;
 use crate::core::game_view::{
-    GameButtonView, GameControlView, GameView, GameViewState, PlayerView, TextInputView,
+    GameButtonView, GameControlView, GameView, GameViewState, PlaneView, PlayerView, TextInputView,
 };
 use crate::core::response_builder::ResponseBuilder;
 use crate::rendering::card_view_context::CardViewContext;
-use crate::rendering::{ability_sync, card_sync};
+use crate::rendering::{ability_sync, card_sync, stack_sync};
 
 /// Converts a [GameState] into a series of commands inside the provided
 /// [ResponseBuilder] describing the visual game state.
@@ -42,7 +45,7 @@ pub fn run(builder: &mut ResponseBuilder, game: &GameState) {
     let mut cards = game
         .zones
         .all_cards()
-        .filter(|c| !skip_sending_to_client(c))
+        .filter(|c| !skip_sending_to_client(game, c))
         .map(|c| card_sync::card_view(builder, &CardViewContext::Game(c.printed(), game, c)))
         .collect::<Vec<_>>();
     cards.append(
@@ -50,24 +53,30 @@ pub fn run(builder: &mut ResponseBuilder, game: &GameState) {
             .zones
             .all_stack_abilities()
             .filter_map(|a| {
-                Some(ability_sync::stack_ability_view(builder, game.card(a.ability_id.card_id)?, a))
+                Some(ability_sync::stack_ability_view(
+                    builder,
+                    game,
+                    game.card(a.ability_id.card_id)?,
+                    a,
+                ))
             })
             .collect(),
     );
 
-    let display_state = builder.response_state.display_state;
     builder.push_game_view(GameView {
-        viewer: player_view(display_state, game, builder.display_as_player()),
-        opponent: player_view(display_state, game, match builder.display_as_player() {
+        viewer: player_view(builder, game, builder.display_as_player()),
+        opponent: player_view(builder, game, match builder.display_as_player() {
             PlayerName::One => PlayerName::Two,
             PlayerName::Two => PlayerName::One,
             _ => todo!("Not implemented"),
         }),
         cards,
-        status_description: format!(
-            "{:?}\nTurn {}\nPlayer {:?}",
-            game.step, game.turn.turn_number, game.turn.active_player
-        ),
+        stack: stack_sync::stack_view(builder, game),
+        status_description: builder.text(Text::GameStatus(
+            game.step,
+            game.turn.turn_number,
+            game.turn.active_player,
+        )),
         card_drag_targets: card_drag_targets(builder, game),
         state: if game.combat.is_some() {
             GameViewState::CombatActive
@@ -76,6 +85,7 @@ pub fn run(builder: &mut ResponseBuilder, game: &GameState) {
         },
         top_controls: top_game_controls(game, builder, builder.act_as_player(game)),
         bottom_controls: bottom_game_controls(game, builder, builder.act_as_player(game)),
+        active_plane: active_plane(game),
     });
 }
 
@@ -95,15 +105,52 @@ fn card_drag_targets(
     vec![]
 }
 
-fn player_view(display_state: &DisplayState, game: &GameState, player: PlayerName) -> PlayerView {
+fn player_view(builder: &ResponseBuilder, game: &GameState, player: PlayerName) -> PlayerView {
     PlayerView {
         life: game.player(player).life as f64,
-        can_act: legal_actions::next_to_act(game, display_state.prompt.as_ref()) == Some(player),
+        life_gained_this_turn: game.history_counters(player).life_gained as f64,
+        life_lost_this_turn: game.history_counters(player).life_lost as f64,
+        can_act: legal_actions::next_to_act(game, builder.current_prompt()) == Some(player),
+        browse_graveyard: browse(player, Zone::Graveyard),
+        browse_exile: browse(player, Zone::Exiled),
+        browse_library: browse(player, Zone::Library),
+        select_action: select_entity_action(builder, game, EntityId::Player(player)),
     }
 }
 
-fn skip_sending_to_client(card: &CardState) -> bool {
-    card.revealed_to.is_empty() && card.zone == Zone::Library
+/// Returns an action to pick the [EntityId] entity in the current entity
+/// choice prompt, if it is one of the available choices.
+pub fn select_entity_action(
+    builder: &ResponseBuilder,
+    game: &GameState,
+    entity_id: EntityId,
+) -> Option<UserAction> {
+    if !builder.allow_actions() {
+        return None;
+    }
+    let prompt = builder.current_prompt()?;
+    let action = PromptAction::SelectEntity(entity_id);
+    legal_prompt_actions::can_take_action(prompt, builder.act_as_player(game), action)
+        .then_some(action.into())
+}
+
+fn browse(owner: PlayerName, zone: Zone) -> UserAction {
+    UserAction::OpenPanel(GamePanelAddress::ZoneBrowser { owner, zone, page: 0 }.into())
+}
+
+fn skip_sending_to_client(game: &GameState, card: &CardState) -> bool {
+    let hidden = card.visible_to().is_empty() && matches!(card.zone, Zone::Library | Zone::Command);
+    // The face-up plane is displayed separately via [GameView::active_plane].
+    hidden || planechase_queries::is_active_plane(game, card.id)
+}
+
+fn active_plane(game: &GameState) -> Option<PlaneView> {
+    let card = game.card(planechase_queries::active_plane(game)?)?;
+    Some(PlaneView {
+        card: ClientCardId::new(card.id),
+        name: card.displayed_name().to_string(),
+        image: card_sync::card_image(card.printed_card_id, Face::Primary),
+    })
 }
 
 fn top_game_controls(
@@ -116,14 +163,26 @@ fn top_game_controls(
     }
 
     let mut result = vec![
-        GameButtonView::new_default("Leave Game", UserAction::LeaveGameAction),
+        GameButtonView::new_default(builder.text(Text::LeaveGame), UserAction::LeaveGameAction),
         GameButtonView::new_default(
-            "Debug",
+            builder.text(Text::DebugPanel),
             UserAction::OpenPanel(GamePanelAddress::GameDebugPanel.into()),
         ),
+        GameButtonView::new_default(
+            builder.text(Text::SettingsPanel),
+            UserAction::OpenPanel(GamePanelAddress::SettingsPanel.into()),
+        ),
+        GameButtonView::new_default(
+            builder.text(Text::KnownCards),
+            UserAction::OpenPanel(GamePanelAddress::KnownCardsPanel.into()),
+        ),
     ];
+    result.push(GameButtonView::new_default(
+        builder.text(Text::FullControl),
+        UserAction::ToggleFullControl,
+    ));
     if can_undo::can_undo(game) {
-        result.push(GameButtonView::new_default("Undo", UserAction::Undo));
+        result.push(GameButtonView::new_default(builder.text(Text::Undo), UserAction::Undo));
     }
     result.into_iter().map(GameControlView::Button).collect()
 }
@@ -138,31 +197,41 @@ fn bottom_game_controls(
     }
 
     if let Some(current) = &builder.display_state().prompt {
-        return prompt_view(builder.display_state(), current, player);
+        return prompt_view(game, builder, current, player);
     }
 
     let mut result = vec![];
     if legal_actions::can_take_action(game, player, &GameAction::PassPriority) {
         if game.stack().is_empty() {
             let next = match game.step {
-                GamePhaseStep::Upkeep => "To Draw",
-                GamePhaseStep::Draw => "To Main",
-                GamePhaseStep::PreCombatMain => "Continue",
-                GamePhaseStep::BeginCombat => "To Attackers",
-                GamePhaseStep::DeclareAttackers => "To Blockers",
-                GamePhaseStep::DeclareBlockers => "To Damage",
-                GamePhaseStep::FirstStrikeDamage => "To Damage",
-                GamePhaseStep::CombatDamage => "End Combat",
-                GamePhaseStep::EndCombat => "End Combat",
-                GamePhaseStep::PostCombatMain => "End Turn",
-                GamePhaseStep::EndStep => "Next Turn",
-                _ => "Continue",
+                GamePhaseStep::Upkeep => Text::ToDraw,
+                GamePhaseStep::Draw => Text::ToMain,
+                GamePhaseStep::PreCombatMain => Text::Continue,
+                GamePhaseStep::BeginCombat => Text::ToAttackers,
+                GamePhaseStep::DeclareAttackers => Text::ToBlockers,
+                GamePhaseStep::DeclareBlockers => Text::ToDamage,
+                GamePhaseStep::FirstStrikeDamage => Text::ToDamage,
+                GamePhaseStep::CombatDamage => Text::EndCombat,
+                GamePhaseStep::EndCombat => Text::EndCombat,
+                GamePhaseStep::PostCombatMain => Text::EndTurn,
+                GamePhaseStep::EndStep => Text::NextTurn,
+                _ => Text::Continue,
             };
-            result.push(GameButtonView::new_primary(next, GameAction::PassPriority));
+            result.push(GameButtonView::new_primary(builder.text(next), GameAction::PassPriority));
         } else {
-            result.push(GameButtonView::new_primary("Resolve", GameAction::PassPriority));
+            result.push(GameButtonView::new_primary(
+                builder.text(Text::Resolve),
+                GameAction::PassPriority,
+            ));
         }
     }
+    if legal_actions::can_take_action(game, player, &GameAction::RollPlanarDie) {
+        let cost = planechase_queries::planar_die_cost(game, player).items.len();
+        result.push(GameButtonView::new_default(
+            builder.text(Text::RollPlanarDie(cost as u32)),
+            GameAction::RollPlanarDie,
+        ));
+    }
     if legal_actions::can_take_action(
         game,
         player,
@@ -171,7 +240,7 @@ fn bottom_game_controls(
         if let Some(CombatState::ProposingAttackers(attackers)) = &game.combat {
             let count = attackers.proposed_attacks.len();
             result.push(GameButtonView::new_primary(
-                format!("{} Attacker{}", count, if count == 1 { "" } else { "s" }),
+                builder.text(Text::ConfirmAttackers(count)),
                 CombatAction::ConfirmAttackers,
             ));
         }
@@ -184,7 +253,7 @@ fn bottom_game_controls(
         if let Some(CombatState::ProposingBlockers(blockers)) = &game.combat {
             let count = blockers.proposed_blocks.len();
             result.push(GameButtonView::new_primary(
-                format!("{} Blocker{}", count, if count == 1 { "" } else { "s" }),
+                builder.text(Text::ConfirmBlockers(count)),
                 CombatAction::ConfirmBlockers,
             ));
         }
@@ -194,17 +263,60 @@ fn bottom_game_controls(
         player,
         &GameAction::CombatAction(CombatAction::ConfirmBlockerOrder),
     ) {
-        result
-            .push(GameButtonView::new_primary("Confirm Order", CombatAction::ConfirmBlockerOrder));
+        result.push(GameButtonView::new_primary(
+            builder.text(Text::ConfirmOrder),
+            CombatAction::ConfirmBlockerOrder,
+        ));
     }
 
-    result.into_iter().map(GameControlView::Button).collect()
+    let mut controls = result.into_iter().map(GameControlView::Button).collect::<Vec<_>>();
+    if builder.display_state().hold_priority {
+        controls.push(GameControlView::Text(builder.text(Text::HoldingPriority)));
+    }
+    if game.player(player).options.full_control {
+        controls.push(GameControlView::Text(builder.text(Text::FullControlEnabled)));
+    }
+    let queued = builder.display_state().queued_actions.len();
+    if queued > 0 {
+        controls.push(GameControlView::Text(builder.text(Text::QueuedActions(queued))));
+        controls.push(GameControlView::Button(GameButtonView::new_default(
+            builder.text(Text::ClearQueue),
+            UserAction::ClearQueuedActions,
+        )));
+    }
+    controls
 }
 
-fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec<GameControlView> {
+fn prompt_view(
+    game: &GameState,
+    builder: &ResponseBuilder,
+    prompt: &Prompt,
+    player: PlayerName,
+) -> Vec<GameControlView> {
+    let mut result = vec![];
+    if let Some(label) = prompt.label {
+        result.push(GameControlView::Text(builder.text(label)));
+    }
+    result.append(&mut prompt_controls(game, builder, prompt, player));
+    result
+}
+
+fn prompt_controls(
+    game: &GameState,
+    builder: &ResponseBuilder,
+    prompt: &Prompt,
+    player: PlayerName,
+) -> Vec<GameControlView> {
     match &prompt.prompt_type {
-        PromptType::EntityChoice(_) => {
-            vec![GameControlView::Text("Pick Entity".to_string())]
+        PromptType::EntityChoice(data) => {
+            let mut result = vec![GameControlView::Text(builder.text(Text::PickEntity))];
+            if data.optional {
+                result.push(GameControlView::Button(GameButtonView::new_default(
+                    builder.text(Text::Continue),
+                    PromptAction::SkipEntityChoice,
+                )));
+            }
+            result
         }
         PromptType::SelectOrder(_) => {
             if legal_prompt_actions::can_take_action(
@@ -213,7 +325,7 @@ fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec
                 PromptAction::SubmitCardSelection,
             ) {
                 return vec![GameControlView::Button(GameButtonView::new_primary(
-                    "Submit",
+                    builder.text(Text::Submit),
                     PromptAction::SubmitCardSelection,
                 ))];
             }
@@ -226,7 +338,7 @@ fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec
         PromptType::PickNumber(pick_number) => {
             let mut result =
                 vec![GameControlView::TextInput(TextInputView { key: FieldKey::PickNumberPrompt })];
-            if let Some(value) = state.fields.get(&FieldKey::PickNumberPrompt) {
+            if let Some(value) = builder.display_state().fields.get(&FieldKey::PickNumberPrompt) {
                 if let Some(n) = value.as_u32() {
                     if legal_prompt_actions::can_take_action(
                         prompt,
@@ -234,7 +346,7 @@ fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec
                         PromptAction::PickNumber(n),
                     ) {
                         result.push(GameControlView::Button(GameButtonView::new_primary(
-                            format!("Set {}", n),
+                            builder.text(Text::SetNumber(n)),
                             PromptAction::PickNumber(n),
                         )));
                     }
@@ -243,6 +355,98 @@ fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec
 
             result
         }
+        PromptType::PayMana(_) => {
+            let mut result = vec![];
+            if legal_prompt_actions::can_take_action(
+                prompt,
+                player,
+                PromptAction::SubmitManaPayment,
+            ) {
+                result.push(GameControlView::Button(GameButtonView::new_primary(
+                    builder.text(Text::Pay),
+                    PromptAction::SubmitManaPayment,
+                )));
+            }
+            if legal_prompt_actions::can_take_action(prompt, player, PromptAction::ResetManaPayment)
+            {
+                result.push(GameControlView::Button(GameButtonView::new_default(
+                    builder.text(Text::AutoTap),
+                    PromptAction::ResetManaPayment,
+                )));
+            }
+
+            result
+        }
+        PromptType::MultiSelect(data) => {
+            let mut result = vec![];
+            for (i, choice) in data.choices.iter().enumerate() {
+                if legal_prompt_actions::can_take_action(
+                    prompt,
+                    player,
+                    PromptAction::ToggleChoice(i),
+                ) {
+                    let label = builder.text(*choice);
+                    result.push(GameControlView::Button(if data.selected.contains(&i) {
+                        GameButtonView::new_primary(label, PromptAction::ToggleChoice(i))
+                    } else {
+                        GameButtonView::new_default(label, PromptAction::ToggleChoice(i))
+                    }));
+                }
+            }
+            result.push(GameControlView::Text(
+                builder.text(Text::SelectBetween(data.minimum, data.maximum)),
+            ));
+            if legal_prompt_actions::can_take_action(
+                prompt,
+                player,
+                PromptAction::SubmitMultiSelect,
+            ) {
+                result.push(GameControlView::Button(GameButtonView::new_primary(
+                    builder.text(Text::Submit),
+                    PromptAction::SubmitMultiSelect,
+                )));
+            }
+
+            result
+        }
+        PromptType::Distribute(data) => {
+            let mut result = vec![];
+            for (i, (&target, &amount)) in data.targets.iter().zip(&data.assigned).enumerate() {
+                result.push(GameControlView::Text(format!(
+                    "{}: {amount}",
+                    entity_name(game, target)
+                )));
+                let key = FieldKey::DistributeAmount(i);
+                result.push(GameControlView::TextInput(TextInputView { key }));
+                if let Some(n) = builder.display_state().fields.get(&key).and_then(|v| v.as_u32()) {
+                    if legal_prompt_actions::can_take_action(
+                        prompt,
+                        player,
+                        PromptAction::AssignAmount(i, n),
+                    ) {
+                        result.push(GameControlView::Button(GameButtonView::new_default(
+                            builder.text(Text::SetNumber(n)),
+                            PromptAction::AssignAmount(i, n),
+                        )));
+                    }
+                }
+            }
+            result.push(GameControlView::Text(
+                builder.text(Text::DistributedTotal(data.assigned_total(), data.total)),
+            ));
+            if legal_prompt_actions::can_take_action(
+                prompt,
+                player,
+                PromptAction::SubmitDistribution,
+            ) {
+                result.push(GameControlView::Button(GameButtonView::new_primary(
+                    builder.text(Text::Submit),
+                    PromptAction::SubmitDistribution,
+                )));
+            }
+
+            result
+        }
         PromptType::MultipleChoice(data) => {
             let mut result = vec![];
             for (i, choice) in data.choices().iter().enumerate() {
@@ -252,7 +456,7 @@ fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec
                     PromptAction::SelectChoice(i),
                 ) {
                     result.push(GameControlView::Button(GameButtonView::new_primary(
-                        choice.to_string(),
+                        builder.text(*choice),
                         PromptAction::SelectChoice(i),
                     )));
                 }
@@ -262,3 +466,17 @@ fn prompt_view(state: &DisplayState, prompt: &Prompt, player: PlayerName) -> Vec
         }
     }
 }
+
+/// Returns a short name describing the [EntityId] entity.
+pub fn entity_name(game: &GameState, entity_id: EntityId) -> String {
+    match entity_id {
+        EntityId::Player(player) => format!("Player {player:?}"),
+        EntityId::Card(card_id, _) => {
+            game.card(card_id).map(|card| card.displayed_name().to_string()).unwrap_or_default()
+        }
+        EntityId::StackAbility(id) => game
+            .card(game.stack_ability(id).ability_id.card_id)
+            .map(|card| card.displayed_name().to_string())
+            .unwrap_or_default(),
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Reverse;
+
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use data::text_strings::{localization, Text};
+use primitives::game_primitives::{CardId, PlayerName, Zone};
+
+/// Returns the IDs of cards owned by `owner` in `zone`, top card first.
+///
+/// Exile has no inherent ordering, so cards there are listed with the most
+/// recently exiled card first.
+pub fn card_ids(game: &GameState, owner: PlayerName, zone: Zone) -> Vec<CardId> {
+    match zone {
+        Zone::Graveyard => {
+            game.graveyard(owner).iter().rev().filter_map(|id| id.to_card_id(game)).collect()
+        }
+        Zone::Library => game.library(owner).iter().rev().copied().collect(),
+        Zone::Exiled => {
+            let mut cards =
+                game.exile(owner).iter().filter_map(|&id| game.card(id)).collect::<Vec<_>>();
+            cards.sort_by_key(|card| Reverse(card.object_id));
+            cards.into_iter().map(|card| card.id).collect()
+        }
+        Zone::Command => game.command_zone(owner).iter().copied().collect(),
+        Zone::Hand => game.hand(owner).iter().copied().collect(),
+        _ => vec![],
+    }
+}
+
+/// Describes `owner`'s `zone` as seen by `player`, in that player's language.
+pub fn title(game: &GameState, player: PlayerName, owner: PlayerName, zone: Zone) -> String {
+    let text = if owner == player { Text::YourZone(zone) } else { Text::PlayersZone(owner, zone) };
+    localization::localize(text, game.player(player).options.language)
+}
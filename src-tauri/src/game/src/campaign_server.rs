@@ -0,0 +1,86 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::new_game_action::{NewGameAction, NewGameDebugOptions};
+use data::campaign::campaign_stage::CampaignStageId;
+use data::campaign::campaign_stages;
+use data::decks::deck_name::DeckName;
+use data::game_states::game_state::{GameState, GameStatus, RulesConfiguration};
+use database::sqlite_database::SqliteDatabase;
+use display::commands::command::Command;
+use tracing::{error, info, instrument};
+
+use crate::game_creation::deck_catalog;
+use crate::server_data::Client;
+use crate::{main_menu_server, new_game_server, requests, server};
+
+/// Starts a new game against the opponent for the indicated campaign stage.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_start_stage(database: SqliteDatabase, client: &mut Client, id: CampaignStageId) {
+    let user = requests::fetch_user(database.clone(), client.data.user_id);
+    if !campaign_stages::is_available(&user.campaign, id) {
+        error!(?id, "Campaign stage is not available");
+        return;
+    }
+
+    let stage = campaign_stages::get(id);
+    let deck = deck_catalog::get().selected_deck(&user);
+    client.send(Command::SetModalPanel(None));
+    new_game_server::create(database, client, NewGameAction {
+        deck,
+        opponent: main_menu_server::ai_opponent(stage.opponent_search_duration),
+        opponent_deck: stage.opponent_deck,
+        campaign_stage: Some(id),
+        rules: Some(RulesConfiguration::default()),
+        teammates: None,
+        format: None,
+        debug_options: NewGameDebugOptions::default(),
+    });
+}
+
+/// Selects the deck the user plays with in campaign games.
+///
+/// The deck must be in the deck catalog and available to the user.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_select_deck(database: SqliteDatabase, client: &mut Client, deck: DeckName) {
+    let mut user = requests::fetch_user(database.clone(), client.data.user_id);
+    if !deck_catalog::get().get(deck).is_some_and(|d| d.is_available(&user.campaign)) {
+        error!(?deck, "Deck is not available");
+        return;
+    }
+
+    info!(?deck, "Selected campaign deck");
+    user.selected_deck = Some(deck);
+    database.write_user(&user);
+    server::handle_fetch_deck_catalog(database, client);
+}
+
+/// Records completion of a campaign stage if the user won a campaign game.
+pub fn handle_game_over(database: SqliteDatabase, client: &mut Client, game: &GameState) {
+    let Some(id) = game.configuration.campaign_stage else {
+        return;
+    };
+    let GameStatus::GameOver { winners } = &game.status else {
+        return;
+    };
+    if !winners.contains(game.find_player_name(client.data.user_id)) {
+        return;
+    }
+
+    let mut user = requests::fetch_user(database.clone(), client.data.user_id);
+    if user.campaign.complete(campaign_stages::get(id)) {
+        info!(?id, "Completed campaign stage");
+        database.write_user(&user);
+    }
+}
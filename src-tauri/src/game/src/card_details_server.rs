@@ -0,0 +1,60 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::printed_cards::custom_card;
+use database::sqlite_database::SqliteDatabase;
+use display::core::card_view::ClientCardId;
+use display::rendering::card_details;
+use rules::queries::card_queries;
+use tracing::{debug, instrument};
+
+use crate::requests;
+use crate::server_data::Client;
+
+/// Sends full oracle text and rulings for the card identified by `id` to the
+/// client.
+///
+/// Nothing is sent if the card is not currently revealed to the requesting
+/// player.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_fetch_card_details(database: SqliteDatabase, client: &mut Client, id: ClientCardId) {
+    let game = requests::fetch_game(database.clone(), client.data.game_id(), None);
+    let player = game.find_player_name(client.data.user_id);
+    let card_id = match &id {
+        ClientCardId::CardId(_) => id.to_card_id(),
+        ClientCardId::StackAbilityId(_) => {
+            let Some(ability) = game.get_stack_ability(id.to_stack_ability_id()) else {
+                debug!(?id, "Stack ability no longer exists");
+                return;
+            };
+            ability.ability_id.card_id
+        }
+    };
+    let Some(card) = game.card(card_id) else {
+        return;
+    };
+    if !card_queries::is_visible_to(&game, player, card) {
+        debug!(?card_id, "Card is not revealed to player");
+        return;
+    }
+
+    let faces = match custom_card::get(card.printed_card_id) {
+        Some(custom) => vec![custom.to_database_face()],
+        None => database.fetch_printed_faces(card.printed_card_id),
+    };
+    let rulings = database.fetch_rulings(card.printed_card_id);
+    let trigger_buttons = card_details::trigger_buttons(&game, player, card_id);
+    client.send(card_details::render(id, &faces, &rulings, trigger_buttons));
+}
@@ -0,0 +1,29 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use database::sqlite_database::SqliteDatabase;
+use display::rendering::card_search;
+use tracing::instrument;
+
+use crate::requests;
+use crate::server_data::Client;
+
+/// Sends the cards visible to the requesting player in graveyards, exile, and
+/// other revealed zones which match `query` to the client.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_search_cards(database: SqliteDatabase, client: &mut Client, query: String) {
+    let game = requests::fetch_game(database, client.data.game_id(), None);
+    let player = game.find_player_name(client.data.user_id);
+    client.send(card_search::render(&game, player, &query));
+}
@@ -0,0 +1,129 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::game_states::chat_log::{ChatContent, ChatLog, ChatMessage, Emote, MAX_MESSAGE_LENGTH};
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::{PlayerQueries, PlayerType};
+use database::sqlite_database::SqliteDatabase;
+use display::commands::command::Command;
+use display::commands::scene_identifier::SceneIdentifier;
+use display::rendering::chat;
+use primitives::game_primitives::PlayerName;
+use tracing::{info, instrument};
+
+use crate::server_data::Client;
+use crate::{requests, server_data};
+
+/// Handles a chat message sent by the user associated with `client`.
+///
+/// The message is appended to the game's chat log in the database and the
+/// updated log is sent to every user connected to the game. AI opponents may
+/// respond with an [Emote].
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_send_chat_message(database: SqliteDatabase, client: &mut Client, message: String) {
+    let text = message.trim().chars().take(MAX_MESSAGE_LENGTH).collect::<String>();
+    if text.is_empty() {
+        return;
+    }
+
+    let game_id = client.data.game_id();
+    let game = requests::fetch_game(database.clone(), game_id, None);
+    let player = game.find_player_name(client.data.user_id);
+    let mut chat = database.fetch_chat(game_id).unwrap_or_default();
+    let reply = agent_reply(&text);
+    chat.add(ChatMessage {
+        sender: player,
+        turn_number: game.turn.turn_number,
+        content: ChatContent::Text(text),
+    });
+
+    if let Some(emote) = reply {
+        for agent in agents(&game).filter(|&p| p != player) {
+            info!(?agent, ?emote, "Agent sending emote");
+            chat.add(ChatMessage {
+                sender: agent,
+                turn_number: game.turn.turn_number,
+                content: ChatContent::Emote(emote),
+            });
+        }
+    }
+
+    database.write_chat(game_id, &chat);
+    send_to_all(client, &game, &chat);
+}
+
+/// Sends a closing [Emote] from each AI opponent once a game has ended.
+pub fn handle_game_over(database: SqliteDatabase, client: &mut Client, game: &GameState) {
+    let mut chat = database.fetch_chat(game.id).unwrap_or_default();
+    for agent in agents(game) {
+        chat.add(ChatMessage {
+            sender: agent,
+            turn_number: game.turn.turn_number,
+            content: ChatContent::Emote(Emote::GoodGame),
+        });
+    }
+    database.write_chat(game.id, &chat);
+    send_to_all(client, game, &chat);
+}
+
+/// Renders the current chat log for a game as seen by `player`.
+pub fn render(database: SqliteDatabase, game: &GameState, player: PlayerName) -> Vec<Command> {
+    vec![chat::render(&database.fetch_chat(game.id).unwrap_or_default(), player)]
+}
+
+/// Sends the chat log to `client` and to every other connection of a human
+/// player which is currently displaying the game.
+fn send_to_all(client: &Client, game: &GameState, chat: &ChatLog) {
+    client.send(chat::render(chat, game.find_player_name(client.data.user_id)));
+    for player in game.configuration.all_players {
+        let PlayerType::Human(user_id) = game.player(player).player_type else {
+            continue;
+        };
+        for other in server_data::connected_clients(user_id, SceneIdentifier::Game(game.id)) {
+            if other.data.connection_id != client.data.connection_id {
+                other.send(chat::render(chat, player));
+            }
+        }
+    }
+}
+
+fn agents(game: &GameState) -> impl Iterator<Item = PlayerName> + '_ {
+    game.configuration
+        .all_players
+        .iter()
+        .filter(|&p| matches!(game.player(p).player_type, PlayerType::Agent(_)))
+}
+
+/// Picks a quick-message for an AI opponent to send in response to `text`.
+fn agent_reply(text: &str) -> Option<Emote> {
+    let text = text.to_lowercase();
+    let words =
+        text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect::<Vec<_>>();
+    let has_word = |word: &str| words.contains(&word);
+    if has_word("gg") || text.contains("good game") {
+        Some(Emote::GoodGame)
+    } else if has_word("wp") || text.contains("well played") || text.contains("nice") {
+        Some(Emote::WellPlayed)
+    } else if has_word("gl") || has_word("glhf") || text.contains("good luck") {
+        Some(Emote::GoodLuck)
+    } else if has_word("hi") || has_word("hello") || has_word("hey") {
+        Some(Emote::Hello)
+    } else if has_word("sorry") || has_word("oops") {
+        Some(Emote::Oops)
+    } else if text.ends_with('?') {
+        Some(Emote::Thinking)
+    } else {
+        None
+    }
+}
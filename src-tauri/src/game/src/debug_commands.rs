@@ -0,0 +1,187 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::debug_action::DebugGameAction;
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::LifeValue;
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use data::printed_cards::custom_card::{self, CustomCard};
+use database::sqlite_database::SqliteDatabase;
+use primitives::game_primitives::{CardId, PlayerName, Zone};
+use rules::steps::step;
+
+/// Parses a text command entered in the debug panel into a sequence of
+/// [DebugGameAction]s to execute in order.
+///
+/// Returns an error message describing the problem if the command cannot be
+/// parsed.
+pub fn parse(
+    database: &SqliteDatabase,
+    game: &GameState,
+    player: PlayerName,
+    command: &str,
+) -> Result<Vec<DebugGameAction>, String> {
+    let words = command.split_whitespace().collect::<Vec<_>>();
+    match words.as_slice() {
+        ["draws", rest @ ..] => {
+            let (target, rest) = split_player(rest, player);
+            let names = rest.join(" ");
+            let mut actions = names
+                .split(',')
+                .map(|name| find_in_library(game, target, name.trim()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Cards are placed on top of the library one at a time, so the
+            // first card to be drawn must be placed last.
+            actions.reverse();
+            Ok(actions)
+        }
+        _ => parse_action(database, game, player, command, &words).map(|action| vec![action]),
+    }
+}
+
+fn parse_action(
+    database: &SqliteDatabase,
+    game: &GameState,
+    player: PlayerName,
+    command: &str,
+    words: &[&str],
+) -> Result<DebugGameAction, String> {
+    match words {
+        ["add", rest @ ..] => {
+            let (owner, rest) = split_player(rest, player);
+            let [zone, name @ ..] = rest else {
+                return Err("Expected a zone and a card name".to_string());
+            };
+            let zone = parse_zone(zone)?;
+            let name = name.join(" ");
+            let Some(printed_card_id) = database
+                .fetch_printed_card_id(&name)
+                .or_else(|| custom_card::find(&name).map(CustomCard::printed_card_id))
+            else {
+                return Err(format!("No card named '{name}'"));
+            };
+            Ok(DebugGameAction::CreateCard(owner, printed_card_id, zone))
+        }
+        ["life", target, amount] => {
+            let target =
+                parse_player(target).ok_or_else(|| format!("Unknown player '{target}'"))?;
+            let amount = amount
+                .parse::<LifeValue>()
+                .map_err(|_| format!("Invalid life total '{amount}'"))?;
+            Ok(DebugGameAction::SetLifeTotalTo(target, amount))
+        }
+        ["counters", kind, count, name @ ..] => {
+            let count = count.parse::<u32>().map_err(|_| format!("Invalid count '{count}'"))?;
+            let card_id = find_card(game, &name.join(" "))?;
+            match *kind {
+                "+1/+1" => Ok(DebugGameAction::AddPlusOneCounters(card_id, count)),
+                "-1/-1" => Ok(DebugGameAction::AddMinusOneCounters(card_id, count)),
+                _ => Err(format!("Unknown counter type '{kind}'")),
+            }
+        }
+        ["trigger", "upkeep", target] => {
+            let target =
+                parse_player(target).ok_or_else(|| format!("Unknown player '{target}'"))?;
+            Ok(DebugGameAction::ForceUpkeepTriggers(target))
+        }
+        ["trigger", "enter", name @ ..] => {
+            Ok(DebugGameAction::ForceEnterBattlefieldTriggers(find_card(game, &name.join(" "))?))
+        }
+        ["reveal"] => Ok(DebugGameAction::ToggleRevealAllCards),
+        ["step"] => Ok(DebugGameAction::ToggleStepThroughResolution),
+        ["top", name @ ..] => {
+            Ok(DebugGameAction::MoveToTopOfLibrary(find_card(game, &name.join(" "))?))
+        }
+        ["seed", seed] => {
+            let seed = seed.parse::<u64>().map_err(|_| format!("Invalid seed '{seed}'"))?;
+            Ok(DebugGameAction::SetRngSeed(seed))
+        }
+        ["skip", "turns", turns] => {
+            let turns =
+                turns.parse::<u32>().map_err(|_| format!("Invalid turn count '{turns}'"))?;
+            Ok(DebugGameAction::SkipTurns(turns))
+        }
+        ["skip", name @ ..] => {
+            let name = name.join(" ");
+            let step = enum_iterator::all::<GamePhaseStep>()
+                .find(|s| format!("{s:?}").eq_ignore_ascii_case(&name.replace(' ', "")))
+                .ok_or_else(|| format!("Unknown step '{name}'"))?;
+            if !step::can_advance_to(step) {
+                return Err(format!("Cannot skip to {step:?}"));
+            }
+            Ok(DebugGameAction::SkipToStep(step))
+        }
+        _ => Err(format!("Unknown command '{command}'")),
+    }
+}
+
+/// Splits an optional leading player name off of a list of words, defaulting to
+/// `player` if none is present.
+fn split_player<'a>(words: &'a [&'a str], player: PlayerName) -> (PlayerName, &'a [&'a str]) {
+    match words.split_first() {
+        Some((first, rest)) => match parse_player(first) {
+            Some(target) => (target, rest),
+            None => (player, words),
+        },
+        None => (player, words),
+    }
+}
+
+fn parse_player(name: &str) -> Option<PlayerName> {
+    match name.to_lowercase().as_str() {
+        "p1" => Some(PlayerName::One),
+        "p2" => Some(PlayerName::Two),
+        "p3" => Some(PlayerName::Three),
+        "p4" => Some(PlayerName::Four),
+        _ => None,
+    }
+}
+
+fn parse_zone(name: &str) -> Result<Zone, String> {
+    match name.to_lowercase().as_str() {
+        "hand" => Ok(Zone::Hand),
+        "library" => Ok(Zone::Library),
+        "battlefield" => Ok(Zone::Battlefield),
+        "graveyard" => Ok(Zone::Graveyard),
+        "exile" => Ok(Zone::Exiled),
+        _ => Err(format!("Unknown zone '{name}'")),
+    }
+}
+
+/// Finds a card with the given name, preferring cards on the battlefield.
+fn find_card(game: &GameState, name: &str) -> Result<CardId, String> {
+    game.zones
+        .all_cards()
+        .filter(|card| card.displayed_name().eq_ignore_ascii_case(name))
+        .min_by_key(|card| card.zone != Zone::Battlefield)
+        .map(|card| card.id)
+        .ok_or_else(|| format!("No card named '{name}' in the game"))
+}
+
+/// Finds a card with the given name in a player's library and returns an
+/// action to put it on top.
+fn find_in_library(
+    game: &GameState,
+    player: PlayerName,
+    name: &str,
+) -> Result<DebugGameAction, String> {
+    game.library(player)
+        .iter()
+        .filter_map(|&id| game.card(id))
+        .find(|card| card.displayed_name().eq_ignore_ascii_case(name))
+        .map(|card| DebugGameAction::RigNextDraw(player, card.card_name))
+        .ok_or_else(|| format!("No card named '{name}' in {player:?}'s library"))
+}
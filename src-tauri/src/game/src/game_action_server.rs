@@ -12,46 +12,72 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::mem;
+use std::ops::{Deref, DerefMut};
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 
 use data::actions::game_action::{CombatAction, GameAction};
 use data::actions::prompt_action::PromptAction;
+use data::card_definitions::ability_definition::AbilityType;
+use data::card_definitions::card_name::CardName;
+use data::card_definitions::definitions;
 use data::card_states::zones::ZoneQueries;
+use data::core::numerics::TurnNumber;
+use data::core::panel_address::GamePanelAddress;
 use data::game_states::game_phase_step::GamePhaseStep;
 use data::game_states::game_state::GameState;
+use data::player_states::player_options::PlayerOptions;
 use data::player_states::player_state::{PlayerQueries, PlayerType};
+use data::prompts::game_update::AnimationStep;
 use data::prompts::select_order_prompt::CardOrderLocation;
+use data::text_strings::language_id::LanguageId;
 use data::users::user_state::UserState;
 use database::sqlite_database::SqliteDatabase;
+use display::commands::command::Command;
 use display::commands::field_state::{FieldKey, FieldValue};
 use display::commands::scene_identifier::SceneIdentifier;
 use display::core::card_view::ClientCardId;
-use display::core::display_state::DisplayState;
+use display::core::display_state::{DisplayState, PassUntil};
+use display::core::game_view::GameView;
 use display::core::response_builder::AllowActions;
-use display::rendering::render;
+use display::panels::{debug_panel, panel, rules_console_panel};
+use display::rendering::{diff, recent_changes, render};
 use enumset::{enum_set, EnumSet};
 use once_cell::sync::Lazy;
-use primitives::game_primitives::{CardId, GameId, PlayerName, Source};
+use primitives::game_primitives::{
+    AbilityId, CardId, GameId, PlayerName, Source, StackItemId, UserId,
+};
 use rules::action_handlers::actions::ExecuteAction;
 use rules::action_handlers::prompt_actions::PromptExecutionResult;
 use rules::action_handlers::{actions, prompt_actions};
 use rules::legality::legal_actions::LegalActions;
 use rules::legality::{can_undo, legal_actions};
 use rules::queries::combat_queries;
+use scripting::scripts;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::task;
 use tracing::{debug, error, info, instrument};
-use utils::outcome::HaltCondition;
-use uuid::Uuid;
+use utils::outcome::{HaltCondition, RuleError};
+use utils::{outcome, span_timing};
 
-use crate::game_creation::game_serialization;
-use crate::requests;
-use crate::server_data::{Client, ClientData, GameResponse};
+use crate::game_creation::{board_positions, game_serialization};
+use crate::server_data::{Client, GameResponse};
+use crate::{
+    campaign_server, card_details_server, chat_server, debug_commands, profile_server, requests,
+    server_data,
+};
 
-static DISPLAY_STATE: Lazy<Mutex<DisplayState>> = Lazy::new(|| Mutex::new(DisplayState::default()));
+/// Display state for each game session, keyed by user and game.
+static DISPLAY_STATES: Lazy<Mutex<HashMap<(UserId, GameId), DisplayState>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Most recent [GameView] sent to each user for each game, used to send
+/// incremental updates instead of the full game view.
+static GAME_VIEWS: Lazy<Mutex<HashMap<(UserId, GameId), GameView>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 
 /// Connects to an ongoing game scene, returning a [GameResponse] which renders
 /// its current visual state.
@@ -62,27 +88,34 @@ pub fn connect(
     user: &UserState,
     game_id: GameId,
 ) {
-    let game = requests::fetch_game(database, game_id, None);
+    let game = requests::fetch_game(database.clone(), game_id, None);
     let player_name = game.find_player_name(user.id);
 
     info!(?user.id, ?game.id, "Connected to game");
-    let commands = render::connect(&game, player_name, &get_display_state());
-    let client = Client {
-        data: ClientData {
-            user_id: user.id,
-            scene: SceneIdentifier::Game(game.id),
-            id: Uuid::new_v4(),
-        },
-        channel: response_channel,
+    span_timing::set_enabled(user.options.performance_hud);
+    let client = Client::connect(user.id, SceneIdentifier::Game(game.id), response_channel);
+
+    // If the client reconnects while a prompt is active, the prompt must be
+    // rendered against the game state it was created for.
+    let display_state = get_display_state(&client);
+    let current = match (&display_state.prompt, &display_state.game_snapshot) {
+        (Some(_), Some(snapshot)) => snapshot,
+        _ => &game,
     };
-    client.send_all(commands);
+    let mut commands = render::connect(current, player_name, &display_state);
+    drop(display_state);
+    commands.extend(chat_server::render(database, &game, player_name));
+
+    // Newly-connected clients always receive the full game view
+    get_game_views().remove(&(user.id, game.id));
+    send_game_commands(&client, game.id, commands);
 }
 
 #[instrument(level = "debug", skip(database, client))]
 pub async fn handle_game_action(database: SqliteDatabase, client: &mut Client, action: GameAction) {
     let (sender, mut receiver) = mpsc::unbounded_channel();
     assert!(
-        get_display_state().prompt.is_none(),
+        get_display_state(client).prompt.is_none(),
         "Cannot handle action {action:?} with an active prompt"
     );
 
@@ -98,17 +131,89 @@ pub async fn handle_game_action(database: SqliteDatabase, client: &mut Client, a
             let kind = prompt.prompt_type.kind();
             info!(immediate = true, ?kind, "Awaiting prompt response")
         }
-        let mut display_state = get_display_state();
+        let mut display_state = get_display_state(client);
         display_state.prompt = update.prompt;
         display_state.prompt_channel = update.response_channel;
-        send_updates(&update.game, client, &display_state, AllowActions::Yes);
+        send_updates(&update.game, client, &display_state, AllowActions::Yes, &update.animations);
         display_state.game_snapshot = Some(update.game);
     }
 }
 
+/// Sets a shortcut to automatically pass priority for some period of time and
+/// then takes the first action for the player, if any.
+#[instrument(level = "debug", skip(database, client, pass_until))]
+pub async fn handle_pass_until(
+    database: SqliteDatabase,
+    client: &mut Client,
+    pass_until: impl FnOnce(TurnNumber) -> PassUntil,
+) {
+    let game = requests::fetch_game(database.clone(), client.data.game_id(), None);
+    let player = game.find_player_name(client.data.user_id);
+    get_display_state(client).pass_until = Some(pass_until(game.turn.turn_number));
+    if let Some(action) = pass_until_action(&game, client, player) {
+        handle_game_action(database, client, action).await;
+    } else {
+        send_updates(&game, client, &get_display_state(client), AllowActions::Yes, &[]);
+    }
+}
+
+/// Toggles whether the player will retain priority after casting their next
+/// spell.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_hold_priority(database: SqliteDatabase, client: &mut Client) {
+    let game = requests::fetch_game(database, client.data.game_id(), None);
+    let mut display_state = get_display_state(client);
+    display_state.hold_priority = !display_state.hold_priority;
+    info!(?display_state.hold_priority, "Toggled hold priority");
+    send_updates(&game, client, &display_state, AllowActions::Yes, &[]);
+}
+
+/// Toggles whether the player receives priority every time it would be given
+/// to them in the current game.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_toggle_full_control(database: SqliteDatabase, client: &mut Client) {
+    let mut game = requests::fetch_game(database.clone(), client.data.game_id(), None);
+    let player = game.find_player_name(client.data.user_id);
+    let options = &mut game.player_mut(player).options;
+    options.full_control = !options.full_control;
+    info!(?options.full_control, "Toggled full control");
+    database.write_game(&game_serialization::serialize(&game));
+    send_updates(&game, client, &get_display_state(client), AllowActions::Yes, &[]);
+}
+
+/// Adds an action to take as soon as the player is next able to act. If they
+/// can already act, the action is taken immediately.
+#[instrument(level = "debug", skip(database, client))]
+pub async fn handle_queue_game_action(
+    database: SqliteDatabase,
+    client: &mut Client,
+    action: GameAction,
+) {
+    let game = requests::fetch_game(database.clone(), client.data.game_id(), None);
+    let player = game.find_player_name(client.data.user_id);
+    if get_display_state(client).prompt.is_none()
+        && legal_actions::can_take_action(&game, player, &action)
+    {
+        handle_game_action(database, client, action).await;
+    } else {
+        let mut display_state = get_display_state(client);
+        display_state.queued_actions.push(action);
+        send_updates(&game, client, &display_state, AllowActions::Yes, &[]);
+    }
+}
+
+/// Discards all actions the player has queued which have not yet been taken.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_clear_queued_actions(database: SqliteDatabase, client: &mut Client) {
+    let game = requests::fetch_game(database, client.data.game_id(), None);
+    let mut display_state = get_display_state(client);
+    display_state.queued_actions.clear();
+    send_updates(&game, client, &display_state, AllowActions::Yes, &[]);
+}
+
 #[instrument(level = "debug", skip(client))]
 pub fn handle_prompt_action(client: &mut Client, action: PromptAction) {
-    let mut display_state = get_display_state();
+    let mut display_state = get_display_state(client);
     let prompt = display_state.prompt.take().expect("No active prompt");
     match prompt_actions::execute(prompt, action) {
         PromptExecutionResult::Prompt(prompt) => {
@@ -119,7 +224,10 @@ pub fn handle_prompt_action(client: &mut Client, action: PromptAction) {
             display_state.prompt = None;
             let kind = response.kind();
             debug!(?kind, "Sending prompt response");
-            channel.send(response).expect("Failed to send prompt response");
+            if channel.send(response).is_err() {
+                // The prompt timed out and was resolved with a default choice.
+                debug!("Prompt is no longer awaiting a response");
+            }
         }
     }
 }
@@ -130,10 +238,227 @@ pub fn handle_update_field(
     key: FieldKey,
     value: FieldValue,
 ) {
-    let mut display_state = get_display_state();
-    display_state.fields.insert(key, value);
+    let mut display_state = get_display_state(client);
+    display_state.fields.insert(key, value.clone());
+    if let Some(enabled) = value.as_bool() {
+        let mut user = requests::fetch_user(database.clone(), client.data.user_id);
+        if !update_option(&mut user.options, key, enabled) {
+            error!(?key, "Unexpected boolean field");
+            return;
+        }
+        info!(?key, enabled, "Updated user options");
+        database.write_user(&user);
+        if key == FieldKey::PerformanceHud {
+            span_timing::set_enabled(enabled);
+            if !enabled {
+                client.send(Command::UpdatePerformanceHud(None));
+            }
+        }
+
+        // Options are stored with each game, so the current game is updated
+        // as well.
+        let mut game = requests::fetch_game(database.clone(), client.data.game_id(), None);
+        let player = game.find_player_name(client.data.user_id);
+        update_option(&mut game.player_mut(player).options, key, enabled);
+        database.write_game(&game_serialization::serialize(&game));
+        client.send(Command::SetModalPanel(Some(panel::build_game_panel(
+            &game,
+            player,
+            GamePanelAddress::SettingsPanel,
+        ))));
+        return;
+    }
+
     let game = requests::fetch_game(database.clone(), client.data.game_id(), None);
-    send_updates(&game, client, &display_state, AllowActions::Yes);
+    if key == FieldKey::RulesConsoleQuery {
+        let FieldValue::String(query) = value else {
+            return;
+        };
+        let player = game.find_player_name(client.data.user_id);
+        client.send(Command::SetModalPanel(Some(rules_console_panel::render(
+            &game,
+            player,
+            Some(&query),
+        ))));
+        return;
+    }
+    if key == FieldKey::DebugCommand {
+        let FieldValue::String(command) = value else {
+            return;
+        };
+        // Actions below update the display state, so the lock must be released
+        drop(display_state);
+        handle_debug_command(database, client, game, &command);
+        return;
+    }
+    send_updates(&game, client, &display_state, AllowActions::Yes, &[]);
+}
+
+/// Executes a text command entered in the debug panel and re-renders the panel
+/// with the result.
+fn handle_debug_command(
+    database: SqliteDatabase,
+    client: &mut Client,
+    mut game: GameState,
+    command: &str,
+) {
+    let player = game.find_player_name(client.data.user_id);
+    if command.trim() == "export" {
+        let message = match board_positions::write(&game) {
+            Ok(path) => format!("Wrote position to {}", path.display()),
+            Err(e) => format!("Error writing position: {e}"),
+        };
+        client.send(Command::SetModalPanel(Some(debug_panel::render(
+            &game,
+            player,
+            Some(&message),
+        ))));
+        return;
+    }
+    if let Some(source) = command.trim().strip_prefix("import ") {
+        handle_import_position(database, client, game, source);
+        return;
+    }
+    if command.trim() == "reload" {
+        let count = scripts::reload();
+        let message = format!("Reloaded {count} scripts from {}", scripts::directory().display());
+        client.send(Command::SetModalPanel(Some(debug_panel::render(
+            &game,
+            player,
+            Some(&message),
+        ))));
+        return;
+    }
+
+    let message = match debug_commands::parse(&database, &game, player, command) {
+        Ok(actions) => {
+            for &action in &actions {
+                info!(?action, "Executing debug command");
+                handle_game_action_internal(
+                    database.clone(),
+                    client,
+                    action.into(),
+                    &mut game,
+                    false,
+                );
+            }
+            format!("Executed {actions:?}")
+        }
+        Err(message) => message,
+    };
+    client.send(Command::SetModalPanel(Some(debug_panel::render(&game, player, Some(&message)))));
+}
+
+/// Replaces the current game with one starting from a [BoardPosition] entered
+/// in the debug panel, either as JSON or as the path to a position file.
+///
+/// The game's action history is discarded, so the new game cannot be undone
+/// back to the previous position.
+fn handle_import_position(
+    database: SqliteDatabase,
+    client: &mut Client,
+    game: GameState,
+    source: &str,
+) {
+    let player = game.find_player_name(client.data.user_id);
+    let position = board_positions::parse(source).and_then(|mut position| {
+        board_positions::validate(&database, &mut position)?;
+        Ok(position)
+    });
+    let position = match position {
+        Ok(position) => position,
+        Err(message) => {
+            client.send(Command::SetModalPanel(Some(debug_panel::render(
+                &game,
+                player,
+                Some(&message),
+            ))));
+            return;
+        }
+    };
+
+    info!(game_id = ?game.id, "Importing board position");
+    let mut serialized = game_serialization::serialize(&game);
+    serialized.position = Some(position);
+    for name in enum_iterator::all::<PlayerName>() {
+        serialized.player_actions.get_mut(name).clear();
+        serialized.prompt_responses.get_mut(name).clear();
+    }
+    let game = game_serialization::rebuild(database.clone(), serialized);
+    database.write_game(&game_serialization::serialize(&game));
+
+    let mut display_state = get_display_state(client);
+    display_state.prompt = None;
+    display_state.prompt_channel = None;
+    display_state.game_snapshot = None;
+    display_state.pass_until = None;
+    display_state.hold_priority = false;
+    send_updates(&game, client, &display_state, AllowActions::Yes, &[]);
+    drop(display_state);
+    client.send(Command::SetModalPanel(Some(debug_panel::render(
+        &game,
+        player,
+        Some("Imported position"),
+    ))));
+}
+
+/// Updates the [PlayerOptions] value for a boolean settings field.
+///
+/// Returns false if this field does not correspond to a player option.
+fn update_option(options: &mut PlayerOptions, key: FieldKey, enabled: bool) -> bool {
+    match key {
+        FieldKey::ActiveTurnStop(step) if enabled => {
+            options.active_turn_stops.insert(step);
+        }
+        FieldKey::ActiveTurnStop(step) => {
+            options.active_turn_stops.remove(step);
+        }
+        FieldKey::InactiveTurnStop(step) if enabled => {
+            options.inactive_turn_stops.insert(step);
+        }
+        FieldKey::InactiveTurnStop(step) => {
+            options.inactive_turn_stops.remove(step);
+        }
+        FieldKey::FullControl => options.full_control = enabled,
+        FieldKey::AutoYieldOwnTriggers => options.auto_yield_own_triggers = enabled,
+        FieldKey::ReduceMotion => options.reduce_motion = enabled,
+        FieldKey::PlaySound(sound) if enabled => {
+            options.muted_sounds.remove(sound);
+        }
+        FieldKey::PlaySound(sound) => {
+            options.muted_sounds.insert(sound);
+        }
+        FieldKey::ScreenReader => options.screen_reader = enabled,
+        FieldKey::PerformanceHud => options.performance_hud = enabled,
+        FieldKey::Language(language) if enabled => options.language = language,
+        FieldKey::Language(_) => options.language = LanguageId::English,
+        _ => return false,
+    }
+    true
+}
+
+/// Toggles whether the current user is always given priority while the
+/// indicated triggered ability of any card with the given [CardName] is on
+/// the stack, then re-sends the details of the ability's card.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_toggle_always_ask_trigger(
+    database: SqliteDatabase,
+    client: &mut Client,
+    ability_id: AbilityId,
+    card_name: CardName,
+) {
+    let mut user = requests::fetch_user(database.clone(), client.data.user_id);
+    let always_ask = &mut user.options.always_ask_triggers;
+    let key = (card_name, ability_id.number);
+    if !always_ask.remove(&key) {
+        always_ask.insert(key);
+    }
+    database.write_user(&user);
+    card_details_server::handle_fetch_card_details(
+        database,
+        client,
+        ClientCardId::new(ability_id.card_id),
+    );
 }
 
 pub fn handle_drag_card(
@@ -144,7 +469,7 @@ pub fn handle_drag_card(
     index: u32,
 ) {
     info!(?card_id, ?location, "handle_drag_card");
-    let mut display_state = get_display_state();
+    let mut display_state = get_display_state(client);
     let prompt = display_state.prompt.take().expect("No active prompt");
     let result = prompt_actions::execute(
         prompt,
@@ -155,13 +480,13 @@ pub fn handle_drag_card(
     };
     display_state.prompt = Some(prompt);
     let game = display_state.game_snapshot.as_ref().expect("No game snapshot saved");
-    send_updates(game, client, &display_state, AllowActions::Yes);
+    send_updates(game, client, &display_state, AllowActions::Yes, &[]);
 }
 
 #[instrument(level = "debug", skip(database, client))]
 pub fn handle_undo(database: SqliteDatabase, client: &mut Client) {
     // TODO: Handle undoing with an active prompt
-    assert!(get_display_state().prompt.is_none(), "Cannot handle undo with an active prompt");
+    assert!(get_display_state(client).prompt.is_none(), "Cannot handle undo with an active prompt");
 
     let game_id = client.data.game_id();
     let serialized =
@@ -174,14 +499,20 @@ pub fn handle_undo(database: SqliteDatabase, client: &mut Client) {
             can_undo::undoable_action_count(actions) == 1
                 && actions.get(player).first().map(|a| a.track_for_undo).unwrap_or_default()
         });
+    if !can_undo::takebacks_allowed(&game) {
+        error!(?game_id, "Takebacks are not allowed in this game");
+        return;
+    }
     database.write_game(&game_serialization::serialize(&game));
 
-    let mut display_state = get_display_state();
+    let mut display_state = get_display_state(client);
     display_state.prompt = None;
     display_state.prompt_channel = None;
     display_state.fields.clear();
     display_state.game_snapshot = None;
-    send_updates(&game, client, &display_state, AllowActions::Yes);
+    display_state.pass_until = None;
+    display_state.hold_priority = false;
+    send_updates(&game, client, &display_state, AllowActions::Yes, &[]);
 }
 
 pub fn handle_game_action_internal(
@@ -202,24 +533,65 @@ pub fn handle_game_action_internal(
 
     // We send incremental updates while the simulation is running to keep the
     // client informed of AI actions.
-    send_updates(game, client, &get_display_state(), AllowActions::No);
+    let animations = game.animations.take();
+    send_updates(game, client, &get_display_state(client), AllowActions::No, &animations);
+
+    if matches!(action, GameAction::ProposePlayingCard(_))
+        && mem::take(&mut get_display_state(client).hold_priority)
+    {
+        let result =
+            actions::execute(game, current_player, GameAction::HoldPriority, ExecuteAction {
+                skip_undo_tracking: true,
+                validate: true,
+            });
+        if let Err(rule_error) = result {
+            handle_rule_error(database, client, game, rule_error);
+            return;
+        }
+    }
 
     let mut current_action = action;
     let mut skip_undo_tracking = automatic;
 
     loop {
-        actions::execute(game, current_player, current_action, ExecuteAction {
+        let result = actions::execute(game, current_player, current_action, ExecuteAction {
             skip_undo_tracking,
             validate: true,
         });
 
-        send_updates(game, client, &get_display_state(), AllowActions::No);
-        let Some(next_player) = legal_actions::next_to_act(game, None) else {
+        if let Err(rule_error) = result {
+            handle_rule_error(database, client, game, rule_error);
+            break;
+        }
+
+        let next_to_act = legal_actions::next_to_act(game, None);
+        if !next_to_act.is_some_and(|p| mandatory_resolution(game, p)) {
+            // Stack items which nobody can respond to are resolved as a batch, with
+            // their animations sent in a single update afterwards.
+            let animations = game.animations.take();
+            send_updates(game, client, &get_display_state(client), AllowActions::No, &animations);
+        }
+
+        let Some(next_player) = next_to_act else {
             // Game over
+            campaign_server::handle_game_over(database.clone(), client, game);
+            profile_server::handle_game_over(database.clone(), client, game);
+            chat_server::handle_game_over(database, client, game);
             break;
         };
 
-        if let Some(action) = auto_pass_action(game, next_player) {
+        let is_user = next_player == game.find_player_name(client.data.user_id);
+        if let Some(action) = is_user.then(|| queued_action(game, client, next_player)).flatten() {
+            debug!(?next_player, ?action, "Taking queued action");
+            current_player = next_player;
+            current_action = action;
+            skip_undo_tracking = false;
+            continue;
+        }
+
+        let shortcut = if is_user { pass_until_action(game, client, next_player) } else { None };
+
+        if let Some(action) = shortcut.or_else(|| auto_pass_action(game, next_player)) {
             debug!(?next_player, "Automatically passing");
             current_player = next_player;
             current_action = action;
@@ -228,7 +600,17 @@ pub fn handle_game_action_internal(
             match &game.player(next_player).player_type {
                 PlayerType::Human(_) | PlayerType::None => {
                     database.write_game(&game_serialization::serialize(game));
-                    send_updates(game, client, &get_display_state(), AllowActions::Yes);
+                    if is_user {
+                        send_recent_changes(game, client, next_player);
+                    }
+                    let animations = game.animations.take();
+                    send_updates(
+                        game,
+                        client,
+                        &get_display_state(client),
+                        AllowActions::Yes,
+                        &animations,
+                    );
                     break;
                 }
                 PlayerType::Agent(agent) => {
@@ -236,6 +618,10 @@ pub fn handle_game_action_internal(
                     current_player = next_player;
                     current_action = agent.implementation().select_action(game, current_player);
                     skip_undo_tracking = true;
+                    if current_action != GameAction::PassPriority {
+                        // Opponent actions cancel any pass priority shortcut
+                        get_display_state(client).pass_until = None;
+                    }
                     debug!(?next_player, ?current_action, "AI action selected");
                 }
             }
@@ -243,19 +629,105 @@ pub fn handle_game_action_internal(
     }
 }
 
+/// Discards a game in which a [RuleError] occurred, restoring its last saved
+/// state and displaying the error to the user.
+///
+/// Games are only saved when a human player needs to act, so the action which
+/// caused the error and any automatic actions taken after it are discarded
+/// instead of persisting a game with inconsistent state.
+fn handle_rule_error(
+    database: SqliteDatabase,
+    client: &mut Client,
+    game: &mut GameState,
+    rule_error: RuleError,
+) {
+    error!(game_id = ?game.id, %rule_error, "Restoring game after rule error");
+    *game = requests::fetch_game(database, game.id, game.updates.take());
+    let mut display_state = get_display_state(client);
+    display_state.game_snapshot = None;
+    display_state.pass_until = None;
+    send_updates(game, client, &display_state, AllowActions::Yes, &[]);
+    drop(display_state);
+    client.send(Command::ShowRuleError(rule_error.to_string()));
+}
+
 fn send_updates(
     game: &GameState,
-    client: &mut Client,
+    client: &Client,
     display_state: &DisplayState,
     allow_actions: AllowActions,
+    animations: &[AnimationStep],
 ) {
     let user_player_name = game.find_player_name(client.data.user_id);
-    let commands = render::render_updates(game, user_player_name, display_state, allow_actions);
+    let commands =
+        render::render_updates(game, user_player_name, display_state, allow_actions, animations);
+    send_game_commands(client, game.id, commands);
+}
+
+/// Sends a summary of the changes to the game since the player last had the
+/// opportunity to act, and records the current state for the next summary.
+fn send_recent_changes(game: &GameState, client: &Client, player: PlayerName) {
+    let mut display_state = get_display_state(client);
+    let previous = display_state.last_snapshot.replace(recent_changes::snapshot(game));
+    drop(display_state);
+    if let Some(command) = previous.and_then(|p| recent_changes::render(game, player, &p)) {
+        client.send(command);
+    }
+}
+
+/// Sends commands for the [GameId] game to a client, converting full game view
+/// updates into incremental updates relative to the last view sent.
+fn send_game_commands(client: &Client, game_id: GameId, commands: Vec<Command>) {
+    let mut game_views = get_game_views();
+    let key = (client.data.user_id, game_id);
+    let mut previous = game_views.remove(&key);
+    let commands = diff::run(&mut previous, commands);
+    if let Some(view) = previous {
+        server_data::record_checksum(client.data.user_id, game_id, &view);
+        game_views.insert(key, view);
+    }
+    drop(game_views);
     client.send_all(commands);
 }
 
-fn get_display_state() -> MutexGuard<'static, DisplayState> {
-    DISPLAY_STATE.lock().expect("Mutex is poisoned")
+fn get_game_views() -> MutexGuard<'static, HashMap<(UserId, GameId), GameView>> {
+    GAME_VIEWS.lock().expect("Mutex is poisoned")
+}
+
+/// Removes all in-memory state for the client's current game session, e.g.
+/// when they leave the game.
+pub fn close_session(client: &Client) {
+    let key = (client.data.user_id, client.data.game_id());
+    DISPLAY_STATES.lock().expect("Mutex is poisoned").remove(&key);
+    get_game_views().remove(&key);
+    server_data::clear_checksum(key.0, key.1);
+}
+
+fn get_display_state(client: &Client) -> DisplayStateGuard {
+    let key = (client.data.user_id, client.data.game_id());
+    let mut states = DISPLAY_STATES.lock().expect("Mutex is poisoned");
+    states.entry(key).or_default();
+    DisplayStateGuard { states, key }
+}
+
+/// Exclusive access to the [DisplayState] for a single game session.
+struct DisplayStateGuard {
+    states: MutexGuard<'static, HashMap<(UserId, GameId), DisplayState>>,
+    key: (UserId, GameId),
+}
+
+impl Deref for DisplayStateGuard {
+    type Target = DisplayState;
+
+    fn deref(&self) -> &DisplayState {
+        &self.states[&self.key]
+    }
+}
+
+impl DerefMut for DisplayStateGuard {
+    fn deref_mut(&mut self) -> &mut DisplayState {
+        self.states.get_mut(&self.key).expect("Display state not found")
+    }
 }
 
 const ALWAYS_STOP_ACTIVE: EnumSet<GamePhaseStep> =
@@ -281,6 +753,12 @@ pub fn auto_pass_action(game: &GameState, player: PlayerName) -> Option<GameActi
         .map(|attackers| attackers.is_empty())
         .unwrap_or_default();
 
+    let options = &game.player(player).options;
+    if options.full_control {
+        // Player has chosen to make every decision manually
+        return None;
+    }
+
     if legal_actions::can_pass_priority(game, player) {
         if game.stack().is_empty() {
             if (is_active_player
@@ -298,9 +776,7 @@ pub fn auto_pass_action(game: &GameState, player: PlayerName) -> Option<GameActi
             }
 
             if game.player(player).options.auto_pass
-                && legal_actions::compute(game, player, LegalActions { for_human_player: true })
-                    .len()
-                    <= 1
+                && legal_actions::count(game, player, LegalActions { for_human_player: true }) <= 1
                 && !(is_active_player && ALWAYS_STOP_ACTIVE.contains(game.step)
                     || !is_active_player && ALWAYS_STOP_INACTIVE.contains(game.step))
             {
@@ -308,9 +784,12 @@ pub fn auto_pass_action(game: &GameState, player: PlayerName) -> Option<GameActi
                 // pass
                 return Some(GameAction::PassPriority);
             }
+        } else if options.auto_yield_own_triggers && own_triggered_ability_on_top(game, player) {
+            // Player has chosen not to respond to their own triggers which don't
+            // require choices
+            return Some(GameAction::PassPriority);
         } else if game.player(player).options.auto_pass
-            && legal_actions::compute(game, player, LegalActions { for_human_player: true }).len()
-                <= 1
+            && legal_actions::count(game, player, LegalActions { for_human_player: true }) <= 1
         {
             // No response available to item on stack, automatically pass
             return Some(GameAction::PassPriority);
@@ -349,3 +828,86 @@ pub fn auto_pass_action(game: &GameState, player: PlayerName) -> Option<GameActi
 
     None
 }
+
+/// Removes and returns the first action the player has queued which is
+/// currently legal, discarding any queued actions before it which are not.
+fn queued_action(game: &GameState, client: &Client, player: PlayerName) -> Option<GameAction> {
+    let mut display_state = get_display_state(client);
+    while !display_state.queued_actions.is_empty() {
+        let action = display_state.queued_actions.remove(0);
+        if legal_actions::can_take_action(game, player, &action) {
+            return Some(action);
+        }
+        debug!(?action, "Discarding queued action which is no longer legal");
+    }
+    None
+}
+
+/// Returns the action a player should take based on their active
+/// [PassUntil] shortcut, if any.
+///
+/// Clears the shortcut if it has expired.
+fn pass_until_action(game: &GameState, client: &Client, player: PlayerName) -> Option<GameAction> {
+    let mut display_state = get_display_state(client);
+    let expired = match display_state.pass_until? {
+        PassUntil::EndOfTurn(turn) => game.turn.turn_number != turn,
+        PassUntil::NextTurn(turn) => {
+            game.turn.turn_number != turn && game.turn.active_player == player
+        }
+    };
+    if expired {
+        display_state.pass_until = None;
+        return None;
+    }
+
+    if legal_actions::can_pass_priority(game, player) {
+        return Some(GameAction::PassPriority);
+    }
+
+    let confirm_attackers = GameAction::CombatAction(CombatAction::ConfirmAttackers);
+    if legal_actions::can_take_action(game, player, &confirm_attackers) {
+        // Skip attacking while passing through the turn
+        return Some(confirm_attackers);
+    }
+
+    None
+}
+
+/// Returns true if the stack is not empty and the only legal action for
+/// `player` is to pass priority, meaning the top item of the stack will resolve
+/// without any decision being made.
+///
+/// Human players who have disabled [PlayerOptions::auto_pass] or enabled
+/// [PlayerOptions::full_control] still stop for priority here.
+fn mandatory_resolution(game: &GameState, player: PlayerName) -> bool {
+    let options = &game.player(player).options;
+    let auto_passes = match game.player(player).player_type {
+        PlayerType::Agent(_) => true,
+        PlayerType::Human(_) | PlayerType::None => options.auto_pass && !options.full_control,
+    };
+    auto_passes
+        && !game.stack().is_empty()
+        && legal_actions::can_pass_priority(game, player)
+        && legal_actions::count(game, player, LegalActions { for_human_player: true }) <= 1
+}
+
+/// Returns true if the top item of the stack is a triggered ability controlled
+/// by `player` which has no targets or modes and which the player has not
+/// asked to always stop for.
+fn own_triggered_ability_on_top(game: &GameState, player: PlayerName) -> bool {
+    let Some(StackItemId::StackAbility(id)) = game.stack().last() else {
+        return false;
+    };
+    let ability = game.stack_ability(*id);
+    ability.controller == player
+        && ability.targets.is_empty()
+        && ability.choices.is_none()
+        && game.card(ability.ability_id.card_id).is_some_and(|card| {
+            let key = (card.card_name, ability.ability_id.number);
+            !game.player(player).options.always_ask_triggers.contains(&key)
+                && definitions::get(card.card_name)
+                    .get_ability(ability.ability_id.number)
+                    .get_ability_type()
+                    == AbilityType::Triggered
+        })
+}
@@ -0,0 +1,267 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use data::card_states::card_kind::CardKind;
+use data::card_states::card_state::{CardState, TappedState};
+use data::card_states::zones::{ZoneQueries, Zones};
+use data::game_states::board_position::{
+    BoardPosition, CardPosition, PlayerPosition, SpellPosition,
+};
+use data::game_states::game_state::{GameState, TurnData};
+use data::game_states::oracle::Oracle;
+use data::player_states::player_state::PlayerQueries;
+use data::printed_cards::custom_card::{self, CustomCard};
+use database::sqlite_database::SqliteDatabase;
+use enumset::EnumSet;
+use primitives::game_primitives::{PlayerName, Zone};
+use tracing::info;
+use utils::paths;
+
+/// Name of the directory within the data directory to which positions are
+/// written.
+const POSITIONS_DIRECTORY: &str = "positions";
+
+/// Describes the current position of a game.
+pub fn export(game: &GameState) -> BoardPosition {
+    BoardPosition {
+        step: game.step,
+        active_player: game.turn.active_player,
+        turn_number: game.turn.turn_number,
+        players: game
+            .configuration
+            .all_players
+            .iter()
+            .map(|player| player_position(game, player))
+            .collect(),
+        stack: game
+            .stack()
+            .iter()
+            .filter_map(|&item| game.card(item))
+            .map(|card| SpellPosition { owner: card.owner, card: card_position(game, card) })
+            .collect(),
+    }
+}
+
+/// Writes the current position of a game to a JSON file in the data
+/// directory.
+///
+/// Returns the path to the file that was written.
+pub fn write(game: &GameState) -> Result<PathBuf, String> {
+    let directory = paths::get_data_dir().join(POSITIONS_DIRECTORY);
+    fs::create_dir_all(&directory).map_err(|e| e.to_string())?;
+    let path =
+        directory.join(format!("{}_turn{}_{:?}.json", game.id.0, game.turn.turn_number, game.step));
+    let json = serde_json::to_string_pretty(&export(game)).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| e.to_string())?;
+    info!(?path, "Wrote board position");
+    Ok(path)
+}
+
+/// Reads a position previously written via [write].
+pub fn read(path: &Path) -> Result<BoardPosition, String> {
+    let json = fs::read_to_string(path).map_err(|e| format!("Error reading {path:?}: {e}"))?;
+    serde_json::from_str(&json).map_err(|e| format!("Error parsing {path:?}: {e}"))
+}
+
+/// Parses a position from text entered in the debug panel, which is either
+/// the JSON contents of a position file or the path to one.
+///
+/// Relative paths are resolved against the directory positions are written
+/// to.
+pub fn parse(text: &str) -> Result<BoardPosition, String> {
+    let text = text.trim();
+    if text.starts_with('{') {
+        return serde_json::from_str(text).map_err(|e| format!("Error parsing position: {e}"));
+    }
+    let path = Path::new(text);
+    if path.is_absolute() {
+        read(path)
+    } else {
+        read(&paths::get_data_dir().join(POSITIONS_DIRECTORY).join(path))
+    }
+}
+
+/// Checks that every card in a position exists in the Oracle card database.
+///
+/// Cards which are identified only by name are assigned a printing with that
+/// name. Returns a description of each problem found if the position is
+/// invalid.
+pub fn validate(database: &SqliteDatabase, position: &mut BoardPosition) -> Result<(), String> {
+    let mut players = EnumSet::<PlayerName>::empty();
+    let mut errors = vec![];
+    for player in &position.players {
+        if !players.insert(player.player) {
+            errors.push(format!("Player {:?} is described more than once", player.player));
+        }
+    }
+
+    let cards = position
+        .players
+        .iter_mut()
+        .flat_map(|player| {
+            player
+                .library
+                .iter_mut()
+                .chain(player.hand.iter_mut())
+                .chain(player.battlefield.iter_mut())
+                .chain(player.graveyard.iter_mut())
+                .chain(player.exile.iter_mut())
+                .chain(player.command_zone.iter_mut())
+        })
+        .chain(position.stack.iter_mut().map(|spell| &mut spell.card));
+    for card in cards {
+        if let Err(error) = validate_card(database, card) {
+            errors.push(error);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// Creates each card described in a [BoardPosition] in its zone.
+///
+/// Cards are created as of the provided turn, which should be the current
+/// turn of the position.
+pub fn create_cards(
+    oracle: &dyn Oracle,
+    zones: &mut Zones,
+    position: &BoardPosition,
+    turn: TurnData,
+) {
+    for player in &position.players {
+        let owner = player.player;
+        // Cards are added to the top of the library as they are created
+        for card in player.library.iter().rev() {
+            create_card(oracle, zones, card, owner, Zone::Library, turn);
+        }
+        for card in &player.hand {
+            create_card(oracle, zones, card, owner, Zone::Hand, turn);
+        }
+        for card in &player.battlefield {
+            create_card(oracle, zones, card, owner, Zone::Battlefield, turn);
+        }
+        for card in &player.graveyard {
+            create_card(oracle, zones, card, owner, Zone::Graveyard, turn);
+        }
+        for card in &player.exile {
+            create_card(oracle, zones, card, owner, Zone::Exiled, turn);
+        }
+        for card in &player.command_zone {
+            create_card(oracle, zones, card, owner, Zone::Command, turn);
+        }
+    }
+    for spell in &position.stack {
+        create_card(oracle, zones, &spell.card, spell.owner, Zone::Stack, turn);
+    }
+}
+
+fn player_position(game: &GameState, player: PlayerName) -> PlayerPosition {
+    PlayerPosition {
+        player,
+        deck_name: game.player(player).deck_name,
+        life: game.player(player).life,
+        library: cards(game, game.library(player).iter().rev().map(|&id| game.card(id))),
+        hand: cards(game, game.hand(player).iter().map(|&id| game.card(id))),
+        battlefield: cards(game, game.battlefield_owned(player).iter().map(|&id| game.card(id))),
+        graveyard: cards(game, game.graveyard(player).iter().map(|&id| game.card(id))),
+        exile: cards(game, game.exile(player).iter().map(|&id| game.card(id))),
+        command_zone: cards(game, game.command_zone(player).iter().map(|&id| game.card(id))),
+    }
+}
+
+fn cards<'a>(
+    game: &GameState,
+    cards: impl Iterator<Item = Option<&'a CardState>>,
+) -> Vec<CardPosition> {
+    cards.flatten().map(|card| card_position(game, card)).collect()
+}
+
+fn card_position(game: &GameState, card: &CardState) -> CardPosition {
+    CardPosition {
+        name: card.displayed_name().to_string(),
+        printed_card_id: Some(card.printed_card_id),
+        facing: card.facing,
+        tapped: card.tapped_state.is_tapped(),
+        plus_one_counters: card.counters.p1p1,
+        minus_one_counters: card.counters.m1m1,
+        damage: card.damage,
+        entered_this_turn: card.entered_current_zone == game.turn
+            || card.last_changed_control == game.turn,
+    }
+}
+
+fn validate_card(database: &SqliteDatabase, card: &mut CardPosition) -> Result<(), String> {
+    let Some(id) = card.printed_card_id else {
+        let id = database
+            .fetch_printed_card_id(&card.name)
+            .or_else(|| custom_card::find(&card.name).map(CustomCard::printed_card_id))
+            .ok_or_else(|| format!("No card named '{}'", card.name))?;
+        card.printed_card_id = Some(id);
+        return Ok(());
+    };
+
+    let faces = match custom_card::get(id) {
+        Some(custom) => vec![custom.to_database_face()],
+        None => database.fetch_printed_faces(id),
+    };
+    let Some(first) = faces.first() else {
+        return Err(format!("No card with ID {} for '{}'", id.0, card.name));
+    };
+    let matches_name = |name: &str| name.eq_ignore_ascii_case(&card.name);
+    if !faces
+        .iter()
+        .any(|face| matches_name(&face.name) || face.face_name.as_deref().is_some_and(matches_name))
+    {
+        return Err(format!("Card with ID {} is '{}', not '{}'", id.0, first.name, card.name));
+    }
+    Ok(())
+}
+
+fn create_card(
+    oracle: &dyn Oracle,
+    zones: &mut Zones,
+    position: &CardPosition,
+    owner: PlayerName,
+    zone: Zone,
+    turn: TurnData,
+) {
+    let entered = if position.entered_this_turn {
+        turn
+    } else {
+        TurnData {
+            active_player: turn.active_player,
+            turn_number: turn.turn_number.saturating_sub(1),
+        }
+    };
+    let id = zones.create_card_in_zone(
+        oracle.card(position.printed_card_id.expect("Position has not been validated")),
+        zone,
+        CardKind::Normal,
+        owner,
+        entered,
+    );
+    let card = zones.card_mut(id).expect("Card not found");
+    card.facing = position.facing;
+    card.tapped_state = if position.tapped { TappedState::Tapped } else { TappedState::Untapped };
+    card.counters.p1p1 = position.plus_one_counters;
+    card.counters.m1m1 = position.minus_one_counters;
+    card.damage = position.damage;
+}
@@ -0,0 +1,40 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::campaign::campaign_stage::{CampaignRule, CampaignStageId};
+use data::campaign::campaign_stages;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use primitives::game_primitives::Source;
+use rules::mutations::library;
+use tracing::info;
+
+/// Applies the special rules for a campaign stage to a newly-created game.
+///
+/// This must be invoked immediately after the game is created, both when
+/// starting a new game and when rebuilding one from its serialized state.
+pub fn apply(game: &mut GameState, stage: CampaignStageId) {
+    info!(?stage, "Applying campaign rules");
+    game.configuration.campaign_stage = Some(stage);
+    for rule in &campaign_stages::get(stage).rules {
+        match *rule {
+            CampaignRule::StartingLife(player, life) => {
+                game.player_mut(player).life = life;
+            }
+            CampaignRule::ExtraOpeningHandCards(player, count) => {
+                let _ = library::draw_cards(game, Source::Game, player, count);
+            }
+        }
+    }
+}
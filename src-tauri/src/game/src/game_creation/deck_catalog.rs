@@ -0,0 +1,185 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs;
+
+use data::decks::deck::Deck;
+use data::decks::deck_catalog::{CatalogDeck, DeckCatalog};
+use data::decks::deck_name;
+use data::printed_cards::printed_card_id;
+use maplit::btreemap;
+use once_cell::sync::Lazy;
+use primitives::game_primitives::Color;
+use tracing::info;
+use utils::paths;
+
+/// Name of the file in the data directory from which the deck catalog is
+/// loaded.
+const CATALOG_FILE: &str = "decks.json";
+
+static CATALOG: Lazy<DeckCatalog> = Lazy::new(load);
+
+/// Returns the catalog of pre-constructed decks available for play.
+///
+/// The built-in starter decks are always available. Decks read from a JSON
+/// file in the data directory, if one exists, are added to the catalog and
+/// replace any built-in deck with the same [DeckName].
+pub fn get() -> &'static DeckCatalog {
+    &CATALOG
+}
+
+fn load() -> DeckCatalog {
+    let path = paths::get_data_dir().join(CATALOG_FILE);
+    if !path.exists() {
+        info!(?path, "No deck catalog file found, using built-in decks");
+        return built_in();
+    }
+
+    let json = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Error reading deck catalog {path:?}: {e:?}"));
+    let file: DeckCatalog = serde_json::from_str(&json)
+        .unwrap_or_else(|e| panic!("Error parsing deck catalog {path:?}: {e:?}"));
+    info!(?path, decks = file.decks.len(), "Loaded deck catalog");
+
+    let mut catalog = built_in();
+    for deck in file.decks {
+        catalog.decks.retain(|existing| existing.id != deck.id);
+        catalog.decks.push(deck);
+    }
+    if file.planar_deck.is_some() {
+        catalog.planar_deck = file.planar_deck;
+    }
+    catalog
+}
+
+/// Starter decks which are always available.
+fn built_in() -> DeckCatalog {
+    DeckCatalog {
+        decks: vec![
+            CatalogDeck {
+                id: deck_name::GREEN_VANILLA,
+                name: "Green Vanilla".to_string(),
+                colors: vec![Color::Green],
+                description: "A mono-green deck of creatures with no abilities.".to_string(),
+                locked: false,
+                deck: Deck {
+                    cards: btreemap! {
+                        printed_card_id::FOREST => 35,
+                        printed_card_id::GRIZZLY_BEARS => 1,
+                        printed_card_id::GIGANTOSAURUS => 1,
+                        printed_card_id::ALPINE_GRIZZLY => 1,
+                        printed_card_id::LEATHERBACK_BALOTH => 1,
+                        printed_card_id::KALONIAN_TUSKER => 1,
+                        printed_card_id::ANCIENT_BRONTODON => 1,
+                        printed_card_id::GARRUKS_GOREHORN => 1,
+                        printed_card_id::GOLDEN_BEAR => 1,
+                        printed_card_id::PRIMORDIAL_WURM => 1,
+                        printed_card_id::VORSTCLAW => 1,
+                        printed_card_id::TERRAIN_ELEMENTAL => 1,
+                        printed_card_id::ORAZCA_FRILLBACK => 1,
+                        printed_card_id::SWORDWISE_CENTAUR => 1,
+                        printed_card_id::QUILLED_SLAGWURM => 1,
+                        printed_card_id::ELVISH_WARRIOR => 1,
+                        printed_card_id::NYXBORN_COLOSSUS => 1,
+                        printed_card_id::RUMBLING_BALOTH => 1,
+                        printed_card_id::GRIZZLED_OUTRIDER => 1,
+                        printed_card_id::CENTAUR_COURSER => 1,
+                        printed_card_id::GORILLA_WARRIOR => 1,
+                        printed_card_id::SILVERBACK_APE => 1,
+                        printed_card_id::PANTHER_WARRIORS => 1,
+                        printed_card_id::FEROCIOUS_ZHENG => 1,
+                        printed_card_id::ELVISH_RANGER => 1,
+                        printed_card_id::ENORMOUS_BALOTH => 1,
+                        printed_card_id::CRAW_WURM => 1,
+                        printed_card_id::BROODHUNTER_WURM => 1,
+                        printed_card_id::AXEBANE_STAG => 1,
+                        printed_card_id::SPINED_WURM => 1,
+                        printed_card_id::SCALED_WURM => 1,
+                        printed_card_id::ALPHA_TYRRANAX => 1,
+                        printed_card_id::WHIPTAIL_WURM => 1,
+                        printed_card_id::CANOPY_GORGER => 1,
+                        printed_card_id::VASTWOOD_GORGER => 1,
+                        printed_card_id::PHERES_BAND_CENTAURS => 1
+                    },
+                    printings: BTreeMap::new(),
+                },
+            },
+            CatalogDeck {
+                id: deck_name::ALL_DANDANS,
+                name: "All Dandans".to_string(),
+                colors: vec![Color::Blue],
+                description: "Islands and Dandans, nothing else.".to_string(),
+                locked: false,
+                deck: Deck {
+                    cards: btreemap! {
+                        printed_card_id::ISLAND => 30,
+                        printed_card_id::DANDAN => 30,
+                    },
+                    printings: BTreeMap::new(),
+                },
+            },
+            CatalogDeck {
+                id: deck_name::SOME_DANDANS,
+                name: "Some Dandans".to_string(),
+                colors: vec![Color::Blue],
+                description: "Dandans supported by card selection and tricks.".to_string(),
+                locked: true,
+                deck: Deck {
+                    cards: btreemap! {
+                        printed_card_id::ISLAND => 30,
+                        printed_card_id::BRAINSTORM => 5,
+                        printed_card_id::CRYSTAL_SPRAY => 5,
+                        printed_card_id::DANDAN => 20,
+                        printed_card_id::DANCE_OF_THE_SKYWISE => 5,
+                        printed_card_id::RAY_OF_COMMAND => 5,
+                    },
+                    printings: BTreeMap::new(),
+                },
+            },
+            CatalogDeck {
+                id: deck_name::GRIZZLY_BEAR_GIANT_GROWTH,
+                name: "Grizzly Bears".to_string(),
+                colors: vec![Color::Green],
+                description: "Grizzly Bears backed up by Giant Growth.".to_string(),
+                locked: true,
+                deck: Deck {
+                    cards: btreemap! {
+                        printed_card_id::FOREST => 20,
+                        printed_card_id::GRIZZLY_BEARS => 20,
+                        printed_card_id::GIANT_GROWTH => 20,
+                    },
+                    printings: BTreeMap::new(),
+                },
+            },
+            CatalogDeck {
+                id: deck_name::DANDAN,
+                name: "Forgetful Fish".to_string(),
+                colors: vec![Color::Blue],
+                description: "The classic shared-deck Dandan format.".to_string(),
+                locked: false,
+                deck: Deck {
+                    cards: btreemap! {
+                        printed_card_id::ISLAND => 30,
+                        printed_card_id::MYSTIC_RETRIEVAL => 15,
+                        printed_card_id::DANDAN => 15,
+                        printed_card_id::CRYSTAL_SPRAY => 15,
+                    },
+                    printings: BTreeMap::new(),
+                },
+            },
+        ],
+        planar_deck: None,
+    }
+}
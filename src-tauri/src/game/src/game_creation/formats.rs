@@ -0,0 +1,123 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+
+use data::decks::deck::Deck;
+use data::decks::deck_name::DeckName;
+use data::decks::format::{DeckLegalityProblem, Format, FormatCatalog, FormatDefinition};
+use data::printed_cards::database_card::DatabaseCardPrinting;
+use database::sqlite_database::SqliteDatabase;
+use once_cell::sync::Lazy;
+use tracing::info;
+use utils::paths;
+
+use crate::game_creation::deck_catalog;
+
+/// Name of the file in the data directory from which format definitions are
+/// loaded.
+const FORMATS_FILE: &str = "formats.json";
+
+static CATALOG: Lazy<FormatCatalog> = Lazy::new(load);
+
+/// Returns the definitions of all known constructed formats.
+///
+/// Definitions are read from a JSON file in the data directory if one exists.
+/// Otherwise only Pauper is available, since its card pool can be determined
+/// from card rarity alone.
+pub fn get() -> &'static FormatCatalog {
+    &CATALOG
+}
+
+/// Checks a deck against the rules of a [Format].
+///
+/// Returns a list of all problems found, which is empty if the deck is legal.
+/// Returns None if no definition for this format is available.
+pub fn check_deck(
+    database: &SqliteDatabase,
+    deck: &Deck,
+    format: Format,
+    ignore_banned_list: bool,
+) -> Option<Vec<DeckLegalityProblem>> {
+    let definition = get().get(format)?;
+    Some(definition.check_deck(&printings(database, deck), ignore_banned_list))
+}
+
+/// Checks that each of the named decks is legal in a [Format].
+///
+/// Returns a description of the problems found if any deck is not legal or no
+/// definition for this format is available.
+pub fn validate_decks(
+    database: &SqliteDatabase,
+    decks: impl IntoIterator<Item = DeckName>,
+    format: Format,
+    ignore_banned_list: bool,
+) -> Result<(), String> {
+    for name in decks {
+        let deck =
+            &deck_catalog::get().get(name).ok_or_else(|| format!("Unknown deck {name:?}"))?.deck;
+        let problems = check_deck(database, deck, format, ignore_banned_list)
+            .ok_or_else(|| format!("No definition for format {format}"))?;
+        if !problems.is_empty() {
+            return Err(format!("Deck {name:?} is not legal in {format}: {problems:?}"));
+        }
+    }
+    Ok(())
+}
+
+/// Returns all formats in which a deck is legal.
+pub fn legal_formats(
+    database: &SqliteDatabase,
+    deck: &Deck,
+    ignore_banned_list: bool,
+) -> Vec<Format> {
+    let cards = printings(database, deck);
+    get()
+        .formats
+        .iter()
+        .filter(|definition| definition.check_deck(&cards, ignore_banned_list).is_empty())
+        .map(|definition| definition.format)
+        .collect()
+}
+
+fn printings(database: &SqliteDatabase, deck: &Deck) -> Vec<(Vec<DatabaseCardPrinting>, u64)> {
+    deck.cards.iter().map(|(&id, &quantity)| (database.fetch_printings(id), quantity)).collect()
+}
+
+fn load() -> FormatCatalog {
+    let path = paths::get_data_dir().join(FORMATS_FILE);
+    if !path.exists() {
+        info!(?path, "No formats file found, using built-in formats");
+        return built_in();
+    }
+
+    let json = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Error reading formats {path:?}: {e:?}"));
+    let catalog: FormatCatalog = serde_json::from_str(&json)
+        .unwrap_or_else(|e| panic!("Error parsing formats {path:?}: {e:?}"));
+    info!(?path, formats = catalog.formats.len(), "Loaded formats");
+    catalog
+}
+
+/// Formats which are available when no formats file is present.
+fn built_in() -> FormatCatalog {
+    FormatCatalog {
+        formats: vec![FormatDefinition {
+            format: Format::Pauper,
+            sets: None,
+            rarity: Some("common".to_string()),
+            banned: Default::default(),
+        }],
+    }
+}
@@ -16,6 +16,7 @@ use data::game_states::game_state::{GameOperationMode, GameState};
 use data::game_states::history_data::TakenGameAction;
 use data::game_states::serialized_game_state::{SerializedGameState, SerializedGameVersion};
 use data::player_states::player_map::PlayerMap;
+use data::player_states::player_options::PlayerOptions;
 use data::player_states::player_state::PlayerQueries;
 use database::sqlite_database::SqliteDatabase;
 use primitives::game_primitives::PlayerName;
@@ -23,7 +24,7 @@ use rules::action_handlers::actions;
 use rules::action_handlers::actions::ExecuteAction;
 use rules::legality::legal_actions;
 
-use crate::game_creation::new_game;
+use crate::game_creation::{campaign_rules, house_rules, new_game};
 
 /// Serializes the state of a game to a [SerializedGameState].
 pub fn serialize(game: &GameState) -> SerializedGameState {
@@ -39,6 +40,14 @@ pub fn serialize(game: &GameState) -> SerializedGameState {
         player_actions: game.history.player_actions.clone(),
         prompt_responses: game.history.prompt_responses.clone(),
         debug_configuration: game.configuration.debug,
+        rules: game.configuration.rules,
+        teams: game.configuration.teams,
+        campaign_stage: game.configuration.campaign_stage,
+        started_at: game.configuration.started_at,
+        position: game.starting_position.as_deref().cloned(),
+        player_options: PlayerMap::build_from(&game.players, |players, name| {
+            players.player(name).options.clone()
+        }),
     }
 }
 
@@ -55,15 +64,55 @@ pub fn rebuild_until(
     mut serialized: SerializedGameState,
     should_stop: impl Fn(&PlayerMap<Vec<TakenGameAction>>, PlayerName) -> bool,
 ) -> GameState {
-    let mut game = new_game::create_and_start(
-        database,
-        serialized.id,
-        serialized.player_types.one,
-        serialized.decks.one,
-        serialized.player_types.two,
-        serialized.decks.two,
-        serialized.debug_configuration,
-    );
+    let mut game = if let Some(position) = &serialized.position {
+        new_game::create_from_position(
+            database,
+            serialized.id,
+            serialized.player_types.one,
+            serialized.player_types.two,
+            position,
+            serialized.debug_configuration,
+        )
+    } else if let Some(teams) = serialized.teams {
+        new_game::create_team_game_and_start(
+            database,
+            serialized.id,
+            serialized.player_types,
+            serialized.decks,
+            teams,
+            serialized.debug_configuration,
+        )
+        .unwrap_or_else(|e| panic!("Error rebuilding game {:?}: {e}", serialized.id))
+    } else {
+        new_game::create_and_start(
+            database,
+            serialized.id,
+            serialized.player_types.one,
+            serialized.decks.one,
+            serialized.player_types.two,
+            serialized.decks.two,
+            serialized.debug_configuration,
+        )
+        .unwrap_or_else(|e| panic!("Error rebuilding game {:?}: {e}", serialized.id))
+    };
+    if serialized.position.is_some() {
+        // Life totals, planes, and opening hands are already part of the
+        // position, so the rules are recorded without being applied again.
+        game.configuration.rules = serialized.rules;
+        game.configuration.campaign_stage = serialized.campaign_stage;
+    } else {
+        house_rules::apply(&mut game, serialized.rules);
+        if let Some(stage) = serialized.campaign_stage {
+            campaign_rules::apply(&mut game, stage);
+        }
+    }
+    game.configuration.started_at = serialized.started_at;
+    for player in game.configuration.all_players {
+        // Holding priority is recorded as a game action, so it is restored by
+        // replaying actions below.
+        let options = serialized.player_options.get(player).clone();
+        game.player_mut(player).options = PlayerOptions { hold_priority: false, ..options };
+    }
     game.operation_mode = GameOperationMode::SerializationReplay(serialized.prompt_responses);
 
     loop {
@@ -77,7 +126,8 @@ pub fn rebuild_until(
         actions::execute(&mut game, player, taken.action, ExecuteAction {
             skip_undo_tracking: !taken.track_for_undo,
             validate: false,
-        });
+        })
+        .unwrap_or_else(|e| panic!("Rule error replaying game {:?}: {e}", game.id));
     }
 
     game.operation_mode = GameOperationMode::Playing;
@@ -0,0 +1,54 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::game_states::game_state::{GameState, RulesConfiguration};
+use data::player_states::player_state::PlayerQueries;
+use rules::mutations::planechase;
+use tracing::{info, warn};
+
+use crate::game_creation::deck_catalog;
+
+/// Applies a [RulesConfiguration] to a newly-created game.
+///
+/// This must be invoked immediately after the game is created, both when
+/// starting a new game and when rebuilding one from its serialized state, and
+/// before any campaign rules are applied. Games created from a board position
+/// already include the effects of these rules and should not have them
+/// applied again.
+///
+/// In team games the starting life total applies to each team and is scaled
+/// up from the standard 20 to the 30 used for Two-Headed Giant.
+pub fn apply(game: &mut GameState, rules: RulesConfiguration) {
+    info!(?rules, "Applying house rules");
+    game.configuration.rules = rules;
+    let starting_life = if game.configuration.teams.is_some() {
+        // > 810.4. Each team's starting life total is 30.
+        //
+        // <https://yawgatog.com/resources/magic-rules/#R8104>
+        rules.starting_life * 3 / 2
+    } else {
+        rules.starting_life
+    };
+    for player in game.configuration.all_players {
+        game.player_mut(player).life = starting_life;
+    }
+
+    if rules.planechase {
+        let Some(planar_deck) = &deck_catalog::get().planar_deck else {
+            warn!("No planar deck found in deck catalog, skipping Planechase setup");
+            return;
+        };
+        let _ = planechase::setup(game, planar_deck);
+    }
+}
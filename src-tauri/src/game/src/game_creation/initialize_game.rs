@@ -16,16 +16,20 @@ use std::marker::PhantomData;
 
 use ai::core::agent::AgentData;
 use ai::core::first_available_action::FirstAvailableActionAlgorithm;
+use ai::core::state_predictor;
 use ai::core::win_loss_evaluator::WinLossEvaluator;
+use ai::game::determinization;
 use ai::game::evaluators::CustomHeuristicEvaluator;
+use ai::game::external_agent::ExternalAgentImpl;
 use ai::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use ai::monte_carlo::search_checkpoint::CheckpointOptions;
 use ai::monte_carlo::uct1::Uct1;
 use ai::tree_search::iterative_deepening_search::IterativeDeepeningSearch;
 use data::card_definitions::definitions;
 use data::card_states::zones::ZoneQueries;
 use data::core::ability_scope::AbilityScope;
 use data::game_states::game_state::GameState;
-use data::player_states::game_agent::{AgentType, GameAgent};
+use data::player_states::game_agent::{AgentType, GameAgent, StatePredictor};
 use data::player_states::player_state::{PlayerQueries, PlayerType};
 use database::sqlite_database::SqliteDatabase;
 use oracle::card_database;
@@ -46,15 +50,20 @@ pub fn run(database: SqliteDatabase, game: &mut GameState) {
 
     let all_card_ids = game.zones.all_cards().map(|card| card.id).collect::<Vec<_>>();
     for card_id in all_card_ids {
-        initialize_card::run(game, card_id);
+        let _ = initialize_card::run(game, card_id);
     }
 }
 
 fn initialize_agent(agent: &mut GameAgent) {
+    let predictor: state_predictor::StatePredictor<GameState> = match agent.state_predictor {
+        StatePredictor::Omniscient => state_predictor::omniscient,
+        StatePredictor::Determinized => determinization::determinize,
+    };
     match agent.agent_type {
         AgentType::FirstAvailableAction => {
-            agent.game_agent_reference = Some(Box::new(AgentData::omniscient(
+            agent.game_agent_reference = Some(Box::new(AgentData::with_predictor(
                 "FIRST_AVAILABLE_ACTION",
+                predictor,
                 FirstAvailableActionAlgorithm,
                 WinLossEvaluator,
             )));
@@ -65,8 +74,9 @@ fn initialize_agent(agent: &mut GameAgent) {
             )));
         }
         AgentType::TreeSearch(_) => {
-            agent.game_agent_reference = Some(Box::new(AgentData::omniscient(
+            agent.game_agent_reference = Some(Box::new(AgentData::with_predictor(
                 "ITERATIVE_DEEPENING",
+                predictor,
                 IterativeDeepeningSearch,
                 CustomHeuristicEvaluator,
             )));
@@ -76,12 +86,22 @@ fn initialize_agent(agent: &mut GameAgent) {
                 WinLossEvaluator,
             )));
         }
-        AgentType::MonteCarlo(_) => {
-            agent.game_agent_reference = Some(Box::new(AgentData::omniscient(
+        AgentType::MonteCarlo(ref config) => {
+            let checkpoint = config.checkpoint.as_ref().map(|checkpoint| {
+                CheckpointOptions::new(
+                    checkpoint.path.clone(),
+                    checkpoint.interval,
+                    checkpoint.resume,
+                )
+                .unwrap_or_else(|e| panic!("Invalid search checkpoint configuration: {e}"))
+            });
+            agent.game_agent_reference = Some(Box::new(AgentData::with_predictor(
                 "UCT1_10_000",
+                predictor,
                 MonteCarloAlgorithm {
                     child_score_algorithm: Uct1 {},
                     max_iterations: Some(10_000),
+                    checkpoint,
                     phantom_data: PhantomData,
                 },
                 RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
@@ -91,10 +111,16 @@ fn initialize_agent(agent: &mut GameAgent) {
                 MonteCarloAlgorithm {
                     child_score_algorithm: Uct1 {},
                     max_iterations: Some(10_000),
+                    checkpoint: None,
                     phantom_data: PhantomData,
                 },
                 RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
             )));
         }
+        AgentType::External(ref config) => {
+            let implementation = ExternalAgentImpl::new(config.clone());
+            agent.game_agent_reference = Some(Box::new(implementation.clone()));
+            agent.prompt_agent_reference = Some(Box::new(implementation));
+        }
     }
 }
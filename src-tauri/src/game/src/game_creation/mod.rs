@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod board_positions;
+pub mod campaign_rules;
+pub mod deck_catalog;
+pub mod formats;
 pub mod game_serialization;
+pub mod house_rules;
 pub mod initialize_game;
 pub mod new_game;
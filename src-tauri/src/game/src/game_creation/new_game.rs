@@ -12,33 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use data::card_states::card_kind::CardKind;
 use data::card_states::zones::Zones;
 use data::decks::deck::Deck;
-use data::decks::deck_name;
 use data::decks::deck_name::DeckName;
 use data::events::game_events::GlobalEvents;
 use data::game_states::ability_state::AbilityState;
+use data::game_states::board_position::BoardPosition;
 use data::game_states::game_phase_step::GamePhaseStep;
 use data::game_states::game_state::{
-    DebugConfiguration, GameConfiguration, GameOperationMode, GameState, GameStatus, TurnData,
+    DebugConfiguration, GameConfiguration, GameOperationMode, GameState, GameStatus,
+    TeamConfiguration, TurnData,
 };
 use data::game_states::history_data::GameHistory;
 use data::game_states::oracle::Oracle;
+use data::game_states::turn_schedule::TurnSchedule;
+use data::player_states::player_map::PlayerMap;
 use data::player_states::player_state::{PlayerState, PlayerType, Players};
-use data::printed_cards::printed_card_id;
+use data::printed_cards::printed_card_id::PrintedCardId;
+use data::prompts::game_update::AnimationTimeline;
 use database::sqlite_database::SqliteDatabase;
 use enumset::EnumSet;
-use maplit::btreemap;
 use oracle::oracle_impl::OracleImpl;
 use primitives::game_primitives::{EventId, GameId, PlayerName, Source, UserId, Zone};
 use rand::SeedableRng;
 use rand_xoshiro::Xoshiro256StarStar;
 use rules::mutations::library;
 use rules::steps::step;
-use tracing::info;
+use tracing::{info, warn};
 
-use crate::game_creation::initialize_game;
+use crate::game_creation::{board_positions, deck_catalog, initialize_game};
 
 /// Creates a new game using the provided Game ID, User IDs and decks and draws
 /// opening hands.
@@ -46,6 +51,8 @@ use crate::game_creation::initialize_game;
 /// A [SqliteDatabase] is required in order to populate the oracle information
 /// for cards in this game. Nothing is written to the database as a part of
 /// executing this function.
+///
+/// Returns an error if a deck is not present in the deck catalog.
 pub fn create_and_start(
     database: SqliteDatabase,
     game_id: GameId,
@@ -54,20 +61,45 @@ pub fn create_and_start(
     p2: PlayerType,
     p2_deck_name: DeckName,
     debug: DebugConfiguration,
-) -> GameState {
+) -> Result<GameState, String> {
     info!(?game_id, "Creating new game");
-    let mut game = create(database, game_id, p1, p1_deck_name, p2, p2_deck_name, debug);
-    let _ = library::draw_cards(&mut game, Source::Game, PlayerName::One, 7);
-    let _ = library::draw_cards(&mut game, Source::Game, PlayerName::Two, 7);
-    // TODO: Resolve mulligans
-    game.status = GameStatus::Playing;
-    step::advance(&mut game);
-    game
+    let game = create(database, game_id, p1, p1_deck_name, p2, p2_deck_name, debug)?;
+    Ok(start(game))
+}
+
+/// Creates a new game in which players are grouped into the provided teams and
+/// draws opening hands.
+///
+/// Every player who appears in a team is added to the game using their entry
+/// in `player_types` and `decks`. Returns an error if a deck is not present in
+/// the deck catalog.
+pub fn create_team_game_and_start(
+    database: SqliteDatabase,
+    game_id: GameId,
+    player_types: PlayerMap<PlayerType>,
+    decks: PlayerMap<DeckName>,
+    teams: TeamConfiguration,
+    debug: DebugConfiguration,
+) -> Result<GameState, String> {
+    info!(?game_id, ?teams, "Creating new team game");
+    let oracle = Box::new(OracleImpl::new(database.clone()));
+    let all_players = teams.teams[0] | teams.teams[1];
+    let mut game =
+        create_game(&database, oracle, game_id, all_players, player_types, decks, debug)?;
+    game.configuration.teams = Some(teams);
+    initialize_game::run(database, &mut game);
+
+    for player in all_players {
+        game.shuffle_library(player);
+    }
+    Ok(start(game))
 }
 
 /// Creates a new game using the provided Game ID, User IDs and decks but does
 /// not transition the game to the 'playing' state and does not e.g. draw
 /// opening hands.
+///
+/// Returns an error if a deck is not present in the deck catalog.
 pub fn create(
     database: SqliteDatabase,
     game_id: GameId,
@@ -76,63 +108,160 @@ pub fn create(
     p2: PlayerType,
     p2_deck_name: DeckName,
     debug: DebugConfiguration,
-) -> GameState {
+) -> Result<GameState, String> {
     let oracle = Box::new(OracleImpl::new(database.clone()));
+    let player_types =
+        PlayerMap { one: p1, two: p2, three: PlayerType::None, four: PlayerType::None };
+    let decks = PlayerMap {
+        one: p1_deck_name,
+        two: p2_deck_name,
+        three: DeckName::default(),
+        four: DeckName::default(),
+    };
 
-    let mut game = create_game(oracle, game_id, p1, p1_deck_name, p2, p2_deck_name, debug);
+    let mut game = create_game(
+        &database,
+        oracle,
+        game_id,
+        PlayerName::One | PlayerName::Two,
+        player_types,
+        decks,
+        debug,
+    )?;
     initialize_game::run(database.clone(), &mut game);
 
     game.shuffle_library(PlayerName::One);
     game.shuffle_library(PlayerName::Two);
+    Ok(game)
+}
+
+/// Draws opening hands for each player in a newly-created game and begins the
+/// first turn.
+fn start(mut game: GameState) -> GameState {
+    for player in game.configuration.all_players {
+        let _ = library::draw_cards(&mut game, Source::Game, player, 7);
+    }
+    // TODO: Resolve mulligans
+    game.status = GameStatus::Playing;
+    let _ = step::advance(&mut game);
     game
 }
 
-fn create_game(
-    oracle: Box<dyn Oracle>,
+/// Creates a new game in which cards are placed as described in a
+/// [BoardPosition], e.g. one written via [board_positions::write].
+///
+/// The position must already have been checked via
+/// [board_positions::validate].
+///
+/// The game begins in the position's step with priority given to the active
+/// player. Opening hands are not drawn.
+pub fn create_from_position(
+    database: SqliteDatabase,
     game_id: GameId,
     p1: PlayerType,
-    p1_deck_name: DeckName,
     p2: PlayerType,
-    p2_deck_name: DeckName,
+    position: &BoardPosition,
     debug: DebugConfiguration,
 ) -> GameState {
-    let p1_deck = find_deck(p1_deck_name);
-    let p2_deck = find_deck(p2_deck_name);
+    info!(?game_id, "Creating new game from position");
+    let oracle = Box::new(OracleImpl::new(database.clone()));
+    let turn =
+        TurnData { active_player: position.active_player, turn_number: position.turn_number };
+    let mut zones = Zones::default();
+    board_positions::create_cards(oracle.as_ref(), &mut zones, position, turn);
+
+    let player = |name: PlayerName, player_type: PlayerType| {
+        let (deck_name, life) = position
+            .players
+            .iter()
+            .find(|p| p.player == name)
+            .map_or((DeckName::default(), 20), |p| (p.deck_name, p.life));
+        PlayerState::new(name, player_type, deck_name, life)
+    };
+    let mut game = new_state(
+        oracle,
+        game_id,
+        zones,
+        turn,
+        Players::new(player(PlayerName::One, p1), player(PlayerName::Two, p2)),
+        PlayerName::One | PlayerName::Two,
+        debug,
+    );
+    initialize_game::run(database, &mut game);
+    game.starting_position = Some(Arc::new(position.clone()));
+    game.step = position.step;
+    game.status = GameStatus::Playing;
+    game
+}
+
+fn create_game(
+    database: &SqliteDatabase,
+    oracle: Box<dyn Oracle>,
+    game_id: GameId,
+    all_players: EnumSet<PlayerName>,
+    player_types: PlayerMap<PlayerType>,
+    decks: PlayerMap<DeckName>,
+    debug: DebugConfiguration,
+) -> Result<GameState, String> {
     let mut zones = Zones::default();
     let turn = TurnData { active_player: PlayerName::One, turn_number: 0 };
-    create_cards_in_deck(oracle.as_ref(), &mut zones, p1_deck, PlayerName::One, turn);
-    create_cards_in_deck(oracle.as_ref(), &mut zones, p2_deck, PlayerName::Two, turn);
+    for player in all_players {
+        let deck = find_deck(*decks.get(player))?;
+        create_cards_in_deck(database, oracle.as_ref(), &mut zones, deck, player, turn);
+    }
+
+    let players = Players::from_map(PlayerMap::build_from(&player_types, |types, name| {
+        if all_players.contains(name) {
+            PlayerState::new(name, types.get(name).clone(), *decks.get(name), 20)
+        } else {
+            PlayerState::new(name, PlayerType::None, DeckName::default(), 0)
+        }
+    }));
+    Ok(new_state(oracle, game_id, zones, turn, players, all_players, debug))
+}
 
+fn new_state(
+    oracle: Box<dyn Oracle>,
+    game_id: GameId,
+    zones: Zones,
+    turn: TurnData,
+    players: Players,
+    all_players: EnumSet<PlayerName>,
+    debug: DebugConfiguration,
+) -> GameState {
     GameState {
         id: game_id,
         status: GameStatus::Setup,
         step: GamePhaseStep::Untap,
         turn,
-        priority: PlayerName::One,
+        priority: turn.active_player,
         passed: EnumSet::empty(),
-        configuration: GameConfiguration::new(PlayerName::One | PlayerName::Two, debug),
-        players: Players::new(
-            PlayerState::new(PlayerName::One, p1, p1_deck_name, 20),
-            PlayerState::new(PlayerName::Two, p2, p2_deck_name, 20),
-        ),
+        configuration: GameConfiguration::new(all_players, debug),
+        players,
         zones,
         updates: None,
         combat: None,
+        planechase: None,
+        starting_position: None,
         history: GameHistory::default(),
         rng_seed: 3141592653589793,
         rng: Xoshiro256StarStar::seed_from_u64(3141592653589793),
         events: GlobalEvents::default(),
         state_based_events: Some(vec![]),
         ability_state: AbilityState::default(),
+        turn_schedule: TurnSchedule::default(),
         oracle_reference: Some(oracle),
         agent_state: None,
         operation_mode: GameOperationMode::Playing,
         checking_state_triggered_abilities: false,
         initialized: false,
+        effects_generation: 0,
+        animations: AnimationTimeline::default(),
     }
 }
 
 fn create_cards_in_deck(
+    database: &SqliteDatabase,
     oracle: &dyn Oracle,
     zones: &mut Zones,
     deck: Deck,
@@ -142,9 +271,10 @@ fn create_cards_in_deck(
     let mut cards = deck.cards.iter().collect::<Vec<_>>();
     cards.sort();
     for (&id, &quantity) in &cards {
+        let printing = printing_id(database, &deck, id);
         for _ in 0..quantity {
             zones.create_card_in_zone(
-                oracle.card(id),
+                oracle.card(printing),
                 Zone::Library,
                 CardKind::Normal,
                 owner,
@@ -154,81 +284,25 @@ fn create_cards_in_deck(
     }
 }
 
-fn find_deck(name: DeckName) -> Deck {
-    match name {
-        deck_name::GREEN_VANILLA => Deck {
-            cards: btreemap! {
-                printed_card_id::FOREST => 35,
-                printed_card_id::GRIZZLY_BEARS => 1,
-                printed_card_id::GIGANTOSAURUS => 1,
-                printed_card_id::ALPINE_GRIZZLY => 1,
-                printed_card_id::LEATHERBACK_BALOTH => 1,
-                printed_card_id::KALONIAN_TUSKER => 1,
-                printed_card_id::ANCIENT_BRONTODON => 1,
-                printed_card_id::GARRUKS_GOREHORN => 1,
-                printed_card_id::GOLDEN_BEAR => 1,
-                printed_card_id::PRIMORDIAL_WURM => 1,
-                printed_card_id::VORSTCLAW => 1,
-                printed_card_id::TERRAIN_ELEMENTAL => 1,
-                printed_card_id::ORAZCA_FRILLBACK => 1,
-                printed_card_id::SWORDWISE_CENTAUR => 1,
-                printed_card_id::QUILLED_SLAGWURM => 1,
-                printed_card_id::ELVISH_WARRIOR => 1,
-                printed_card_id::NYXBORN_COLOSSUS => 1,
-                printed_card_id::RUMBLING_BALOTH => 1,
-                printed_card_id::GRIZZLED_OUTRIDER => 1,
-                printed_card_id::CENTAUR_COURSER => 1,
-                printed_card_id::GORILLA_WARRIOR => 1,
-                printed_card_id::SILVERBACK_APE => 1,
-                printed_card_id::PANTHER_WARRIORS => 1,
-                printed_card_id::FEROCIOUS_ZHENG => 1,
-                printed_card_id::ELVISH_RANGER => 1,
-                printed_card_id::ENORMOUS_BALOTH => 1,
-                printed_card_id::CRAW_WURM => 1,
-                printed_card_id::BROODHUNTER_WURM => 1,
-                printed_card_id::AXEBANE_STAG => 1,
-                printed_card_id::SPINED_WURM => 1,
-                printed_card_id::SCALED_WURM => 1,
-                printed_card_id::ALPHA_TYRRANAX => 1,
-                printed_card_id::WHIPTAIL_WURM => 1,
-                printed_card_id::CANOPY_GORGER => 1,
-                printed_card_id::VASTWOOD_GORGER => 1,
-                printed_card_id::PHERES_BAND_CENTAURS => 1
-            },
-        },
-        deck_name::ALL_DANDANS => Deck {
-            cards: btreemap! {
-                printed_card_id::ISLAND => 30,
-                printed_card_id::DANDAN => 30,
-            },
-        },
-        deck_name::GRIZZLY_BEAR_GIANT_GROWTH => Deck {
-            cards: btreemap! {
-                printed_card_id::FOREST => 20,
-                printed_card_id::GRIZZLY_BEARS => 20,
-                printed_card_id::GIANT_GROWTH => 20,
-            },
-        },
-        deck_name::SOME_DANDANS => Deck {
-            cards: btreemap! {
-                printed_card_id::ISLAND => 30,
-                printed_card_id::BRAINSTORM => 5,
-                printed_card_id::CRYSTAL_SPRAY => 5,
-                printed_card_id::DANDAN => 20,
-                printed_card_id::DANCE_OF_THE_SKYWISE => 5,
-                printed_card_id::RAY_OF_COMMAND => 5,
-            },
-        },
-        deck_name::DANDAN => Deck {
-            cards: btreemap! {
-                printed_card_id::ISLAND => 30,
-                printed_card_id::MYSTIC_RETRIEVAL => 15,
-                printed_card_id::DANDAN => 15,
-                printed_card_id::CRYSTAL_SPRAY => 15,
-            },
-        },
-        _ => {
-            panic!("Unknown deck {name:?}");
-        }
-    }
+/// Returns the ID of the printing to use for a card in a deck.
+///
+/// Uses the deck's preferred printing of the card if one has been selected,
+/// falling back to the default printing if it cannot be found.
+fn printing_id(database: &SqliteDatabase, deck: &Deck, id: PrintedCardId) -> PrintedCardId {
+    let Some(printing) = deck.printings.get(&id) else {
+        return id;
+    };
+    database
+        .fetch_alternate_printing(id, &printing.set_code, &printing.collector_number)
+        .unwrap_or_else(|| {
+            warn!(?id, ?printing, "Preferred printing not found, using default printing");
+            id
+        })
+}
+
+fn find_deck(name: DeckName) -> Result<Deck, String> {
+    deck_catalog::get()
+        .get(name)
+        .map(|deck| deck.deck.clone())
+        .ok_or_else(|| format!("Unknown deck {name:?}"))
 }
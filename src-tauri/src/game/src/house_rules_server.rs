@@ -0,0 +1,68 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::panel_address::UserPanelAddress;
+use data::game_states::game_state::RulesConfiguration;
+use database::sqlite_database::SqliteDatabase;
+use database::users;
+use display::commands::command::Command;
+use display::commands::field_state::{FieldKey, FieldValue};
+use display::panels::panel;
+use tracing::{error, instrument};
+
+use crate::game_creation::deck_catalog;
+use crate::server_data::Client;
+
+/// Updates one of the house rules selected by the user associated with
+/// `client` and re-renders the house rules panel.
+///
+/// Starting life and free mulligan options behave like radio buttons: enabling
+/// one selects it, and disabling the selected option restores the default.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_update_field(
+    database: SqliteDatabase,
+    client: &mut Client,
+    key: FieldKey,
+    value: FieldValue,
+) {
+    let Some(enabled) = value.as_bool() else {
+        return;
+    };
+    let mut rules = users::fetch_or_create(&database, client.data.user_id).house_rules;
+    let default = RulesConfiguration::default();
+    match key {
+        FieldKey::StartingLife(life) if enabled => rules.starting_life = life as i64,
+        FieldKey::StartingLife(_) => rules.starting_life = default.starting_life,
+        FieldKey::FreeMulligans(count) if enabled => rules.free_mulligans = count as usize,
+        FieldKey::FreeMulligans(_) => rules.free_mulligans = default.free_mulligans,
+        FieldKey::IgnoreBannedList => rules.ignore_banned_list = enabled,
+        FieldKey::AlwaysAllowTakebacks => rules.always_allow_takebacks = enabled,
+        FieldKey::Planechase if enabled && deck_catalog::get().planar_deck.is_none() => {
+            error!("Cannot enable Planechase without a planar deck");
+            return;
+        }
+        FieldKey::Planechase => rules.planechase = enabled,
+        _ => {
+            error!(?key, "Unexpected house rules field");
+            return;
+        }
+    }
+    users::set_house_rules(&database, client.data.user_id, rules);
+    let user = users::fetch_or_create(&database, client.data.user_id);
+    client.send(Command::SetModalPanel(Some(panel::build_user_panel(
+        &user,
+        deck_catalog::get(),
+        UserPanelAddress::HouseRules,
+    ))));
+}
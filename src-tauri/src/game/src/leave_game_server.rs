@@ -21,13 +21,16 @@ use display::commands::scene_identifier::SceneIdentifier;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::server_data::{Client, ClientData, GameResponse};
-use crate::{main_menu_server, requests};
+use crate::{game_action_server, main_menu_server, requests};
 
 pub fn leave(database: SqliteDatabase, client: &mut Client) {
     let id = client.data.user_id;
     let mut user = requests::fetch_user(database.clone(), id);
     user.activity = UserActivity::Menu;
     database.write_user(&user);
-    client.data.scene = SceneIdentifier::MainMenu;
+    if matches!(client.data.scene, SceneIdentifier::Game(_)) {
+        game_action_server::close_session(client);
+    }
+    client.set_scene(SceneIdentifier::MainMenu);
     client.send(Command::UpdateScene(SceneView::MainMenuView(main_menu_server::main_menu_view())));
 }
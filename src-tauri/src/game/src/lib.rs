@@ -19,9 +19,16 @@ pub mod game_creation;
 pub mod server;
 pub mod server_data;
 
+mod campaign_server;
+mod card_details_server;
+mod card_search_server;
+mod chat_server;
+mod debug_commands;
 mod game_action_server;
+mod house_rules_server;
 mod leave_game_server;
 mod main_menu_server;
 mod new_game_server;
 mod panel_server;
+mod profile_server;
 mod requests;
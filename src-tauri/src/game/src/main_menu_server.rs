@@ -15,13 +15,14 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use data::actions::new_game_action::{NewGameAction, NewGameDebugOptions};
+use data::actions::new_game_action::{NewGameAction, NewGameDebugOptions, NewGameTeammates};
 use data::actions::user_action::UserAction;
+use data::core::panel_address::UserPanelAddress;
 use data::decks::deck_name;
 use data::game_states::game_state::{DebugActAsPlayer, DebugConfiguration};
 use data::player_states::game_agent::{
-    AgentEvaluator, AgentType, ChildScoreAlgorithm, GameAgent, MonteCarloAgent, StateCombiner,
-    StatePredictor,
+    AgentEvaluator, AgentType, ChildScoreAlgorithm, ExternalAgent, GameAgent, MonteCarloAgent,
+    StateCombiner, StatePredictor,
 };
 use data::player_states::player_state::PlayerType;
 use data::users::user_state::UserState;
@@ -33,59 +34,101 @@ use display::core::main_menu_view::MainMenuView;
 use primitives::game_primitives::{PlayerName, UserId};
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::info;
-use uuid::{uuid, Uuid};
+use utils::command_line;
+use uuid::uuid;
 
-use crate::server_data::{Client, ClientData, GameResponse};
+use crate::server_data::{Client, GameResponse};
+
+/// Additional time an external agent may take to respond beyond its search
+/// duration, to allow for process startup and communication overhead.
+const EXTERNAL_AGENT_GRACE_PERIOD: Duration = Duration::from_secs(5);
 
 /// Connect to the main menu scene
 pub fn connect(response_channel: UnboundedSender<GameResponse>, user: &UserState) {
     info!(?user.id, "Connected");
-    let client = Client {
-        data: ClientData { user_id: user.id, scene: SceneIdentifier::MainMenu, id: Uuid::new_v4() },
-        channel: response_channel,
-    };
+    let client = Client::connect(user.id, SceneIdentifier::MainMenu, response_channel);
     client.send(Command::UpdateScene(SceneView::MainMenuView(main_menu_view())));
 }
 
 pub fn main_menu_view() -> MainMenuView {
     let deck = deck_name::DANDAN;
     let opponent_id = UserId(uuid!("d5f8cda2-0916-4655-8353-aaf435d562a5"));
-    let new_local = UserAction::NewGameAction(NewGameAction {
+    let new_local = UserAction::from(NewGameAction {
         deck,
         opponent: PlayerType::Human(opponent_id),
         opponent_deck: deck,
+        campaign_stage: None,
+        rules: None,
+        teammates: None,
+        format: None,
         debug_options: NewGameDebugOptions {
             override_game_id: None,
             configuration: DebugConfiguration {
                 reveal_all_cards: true,
                 act_as_player: Some(DebugActAsPlayer { id: opponent_id, name: PlayerName::Two }),
+                validate_zones: true,
+                step_through_resolution: false,
             },
         },
     });
-    let new_ai = UserAction::NewGameAction(NewGameAction {
+    let new_ai = UserAction::from(NewGameAction {
         deck,
-        opponent: PlayerType::Agent(GameAgent {
-            search_duration: Duration::from_secs(3),
-            agent_type: AgentType::MonteCarlo(MonteCarloAgent {
-                child_score_algorithm: ChildScoreAlgorithm::Uct1,
-                max_iterations: None,
-            }),
-            state_predictor: StatePredictor::Omniscient,
-            state_combiner: StateCombiner::First,
-            evaluator: AgentEvaluator::RandomPlayout(Box::new(AgentEvaluator::WinLoss)),
-            prompt_agent_reference: None,
-            game_agent_reference: None,
-        }),
+        opponent: ai_opponent(Duration::from_secs(3)),
         opponent_deck: deck,
+        campaign_stage: None,
+        rules: None,
+        teammates: None,
+        format: None,
         debug_options: NewGameDebugOptions {
             override_game_id: None,
-            configuration: DebugConfiguration { reveal_all_cards: true, act_as_player: None },
+            configuration: DebugConfiguration {
+                reveal_all_cards: true,
+                act_as_player: None,
+                validate_zones: true,
+                step_through_resolution: false,
+            },
+        },
+    });
+    let new_two_headed_giant = UserAction::from(NewGameAction {
+        deck,
+        opponent: ai_opponent(Duration::from_secs(3)),
+        opponent_deck: deck,
+        campaign_stage: None,
+        rules: None,
+        teammates: Some(NewGameTeammates {
+            teammate: ai_opponent(Duration::from_secs(3)),
+            teammate_deck: deck,
+            opponent_teammate: ai_opponent(Duration::from_secs(3)),
+            opponent_teammate_deck: deck,
+        }),
+        format: None,
+        debug_options: NewGameDebugOptions {
+            override_game_id: None,
+            configuration: DebugConfiguration {
+                reveal_all_cards: true,
+                act_as_player: None,
+                validate_zones: true,
+                step_through_resolution: false,
+            },
         },
     });
 
     let buttons = vec![
         GameButtonView::new_primary("vs Local", new_local),
         GameButtonView::new_primary("vs AI", new_ai),
+        GameButtonView::new_primary("Two-Headed Giant", new_two_headed_giant),
+        GameButtonView::new_default(
+            "House Rules",
+            UserAction::OpenPanel(UserPanelAddress::HouseRules.into()),
+        ),
+        GameButtonView::new_primary(
+            "Campaign",
+            UserAction::OpenPanel(UserPanelAddress::CampaignMap.into()),
+        ),
+        GameButtonView::new_default(
+            "Profile",
+            UserAction::OpenPanel(UserPanelAddress::Profile.into()),
+        ),
         GameButtonView::new_default("Codex", UserAction::QuitGameAction),
         GameButtonView::new_default("Community", UserAction::QuitGameAction),
         GameButtonView::new_default("Settings", UserAction::QuitGameAction),
@@ -93,3 +136,33 @@ pub fn main_menu_view() -> MainMenuView {
     ];
     MainMenuView { buttons }
 }
+
+/// Returns the standard AI opponent configuration, searching for
+/// `search_duration` before selecting each action.
+///
+/// If an external agent program was provided on the command line, it is used
+/// in place of the built-in agent.
+pub fn ai_opponent(search_duration: Duration) -> PlayerType {
+    let agent_type = if let Some(command) = &command_line::flags().external_agent {
+        AgentType::External(ExternalAgent {
+            command: command.clone(),
+            args: vec![],
+            timeout: search_duration + EXTERNAL_AGENT_GRACE_PERIOD,
+        })
+    } else {
+        AgentType::MonteCarlo(MonteCarloAgent {
+            child_score_algorithm: ChildScoreAlgorithm::Uct1,
+            max_iterations: None,
+            checkpoint: None,
+        })
+    };
+    PlayerType::Agent(GameAgent {
+        search_duration,
+        agent_type,
+        state_predictor: StatePredictor::Omniscient,
+        state_combiner: StateCombiner::First,
+        evaluator: AgentEvaluator::RandomPlayout(Box::new(AgentEvaluator::WinLoss)),
+        prompt_agent_reference: None,
+        game_agent_reference: None,
+    })
+}
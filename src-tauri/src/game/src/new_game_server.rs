@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::SystemTime;
+
 use data::actions::new_game_action::NewGameAction;
+use data::game_states::game_state::TeamConfiguration;
+use data::player_states::player_map::PlayerMap;
 use data::player_states::player_state::PlayerType;
 use data::users::user_state::UserActivity;
 use database::sqlite_database::SqliteDatabase;
@@ -20,9 +24,10 @@ use display::commands::scene_identifier::SceneIdentifier;
 use display::core::display_state::DisplayState;
 use display::rendering::render;
 use primitives::game_primitives::{GameId, PlayerName, Source, UserId};
+use tracing::error;
 use uuid::Uuid;
 
-use crate::game_creation::{game_serialization, new_game};
+use crate::game_creation::{campaign_rules, formats, game_serialization, house_rules, new_game};
 use crate::server_data::Client;
 use crate::{game_action_server, requests};
 
@@ -35,16 +40,63 @@ pub fn create(database: SqliteDatabase, client: &mut Client, action: NewGameActi
         GameId(Uuid::new_v4())
     };
 
+    let rules = action.rules.unwrap_or(user.house_rules);
+    if let Some(format) = action.format {
+        let decks = [action.deck, action.opponent_deck].into_iter().chain(
+            action.teammates.iter().flat_map(|t| [t.teammate_deck, t.opponent_teammate_deck]),
+        );
+        if let Err(e) = formats::validate_decks(&database, decks, format, rules.ignore_banned_list)
+        {
+            error!(?game_id, %e, "Deck is not legal in format");
+            return;
+        }
+    }
+
     // TODO: Determine start player at random
-    let mut game = new_game::create_and_start(
-        database.clone(),
-        game_id,
-        PlayerType::Human(user.id),
-        action.deck,
-        action.opponent,
-        action.opponent_deck,
-        action.debug_options.configuration,
-    );
+    let result = if let Some(teammates) = action.teammates {
+        new_game::create_team_game_and_start(
+            database.clone(),
+            game_id,
+            PlayerMap {
+                one: PlayerType::Human(user.id),
+                two: action.opponent,
+                three: teammates.teammate,
+                four: teammates.opponent_teammate,
+            },
+            PlayerMap {
+                one: action.deck,
+                two: action.opponent_deck,
+                three: teammates.teammate_deck,
+                four: teammates.opponent_teammate_deck,
+            },
+            TeamConfiguration::two_headed_giant(),
+            action.debug_options.configuration,
+        )
+    } else {
+        new_game::create_and_start(
+            database.clone(),
+            game_id,
+            PlayerType::Human(user.id),
+            action.deck,
+            action.opponent,
+            action.opponent_deck,
+            action.debug_options.configuration,
+        )
+    };
+    let mut game = match result {
+        Ok(game) => game,
+        Err(e) => {
+            error!(?game_id, %e, "Error creating game");
+            return;
+        }
+    };
+    house_rules::apply(&mut game, rules);
+    game.configuration.started_at = Some(SystemTime::now());
+    if let Some(stage) = action.campaign_stage {
+        campaign_rules::apply(&mut game, stage);
+    }
+    requests::apply_user_options(database.clone(), &mut game);
+    client.set_scene(SceneIdentifier::Game(game.id));
     if let Some(action) = game_action_server::auto_pass_action(&game, PlayerName::One) {
         // Pass priority until the first configured stop.
         game_action_server::handle_game_action_internal(
@@ -57,7 +109,6 @@ pub fn create(database: SqliteDatabase, client: &mut Client, action: NewGameActi
     }
 
     user.activity = UserActivity::Playing(game.id);
-    client.data.scene = SceneIdentifier::Game(game.id);
     let state = DisplayState::default();
     let commands = render::connect(&game, game.find_player_name(user.id), &state);
 
@@ -25,6 +25,7 @@ use display::panels::panel;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::{debug, instrument};
 
+use crate::game_creation::deck_catalog;
 use crate::server_data::{Client, ClientData, GameResponse};
 use crate::{game_action_server, requests};
 
@@ -63,6 +64,9 @@ fn open_panel(database: SqliteDatabase, data: &ClientData, panel: PanelAddress)
             let player_name = game.find_player_name(data.user_id);
             panel::build_game_panel(&game, player_name, game_panel)
         }
-        PanelAddress::UserPanel(player_panel) => match player_panel {},
+        PanelAddress::UserPanel(user_panel) => {
+            let user = requests::fetch_user(database, data.user_id);
+            panel::build_user_panel(&user, deck_catalog::get(), user_panel)
+        }
     }
 }
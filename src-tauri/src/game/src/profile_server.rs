@@ -0,0 +1,111 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+
+use data::core::panel_address::UserPanelAddress;
+use data::game_states::game_state::{GameState, GameStatus};
+use data::player_states::game_agent::AgentType;
+use data::player_states::player_state::{PlayerQueries, PlayerType};
+use data::users::match_record::MatchRecord;
+use data::users::user_statistics::GameResult;
+use database::sqlite_database::SqliteDatabase;
+use database::users;
+use display::commands::command::Command;
+use display::commands::field_state::FieldValue;
+use display::panels::panel;
+use primitives::game_primitives::PlayerName;
+use tracing::instrument;
+
+use crate::game_creation::deck_catalog;
+use crate::server_data::Client;
+
+/// Updates the display name of the user associated with `client` and
+/// re-renders their profile.
+#[instrument(level = "debug", skip(database, client))]
+pub fn handle_update_display_name(
+    database: SqliteDatabase,
+    client: &mut Client,
+    value: FieldValue,
+) {
+    let FieldValue::String(name) = value else {
+        return;
+    };
+    users::set_display_name(&database, client.data.user_id, &name);
+    let user = users::fetch_or_create(&database, client.data.user_id);
+    client.send(Command::SetModalPanel(Some(panel::build_user_panel(
+        &user,
+        deck_catalog::get(),
+        UserPanelAddress::Profile,
+    ))));
+}
+
+/// Records the result of a completed game in the statistics and match history
+/// of the user associated with `client`.
+pub fn handle_game_over(database: SqliteDatabase, client: &mut Client, game: &GameState) {
+    let GameStatus::GameOver { winners } = &game.status else {
+        return;
+    };
+    let player = game.find_player_name(client.data.user_id);
+    let result = if winners.is_empty() {
+        GameResult::Draw
+    } else if winners.contains(player) {
+        GameResult::Win
+    } else {
+        GameResult::Loss
+    };
+    let opponent = opponent(game, player);
+    let record = MatchRecord {
+        game_id: game.id,
+        deck: game.player(player).deck_name,
+        opponent_deck: game.player(opponent).deck_name,
+        opponent: opponent_description(&game.player(opponent).player_type),
+        result,
+        turns: game.turn.turn_number + 1,
+        duration: game
+            .configuration
+            .started_at
+            .and_then(|started| SystemTime::now().duration_since(started).ok()),
+        finished_at: SystemTime::now(),
+    };
+    users::record_match(&database, client.data.user_id, &record);
+}
+
+/// Returns the first player in turn order who participated in this game on a
+/// different team from `player`.
+///
+/// Players who have already lost are included.
+fn opponent(game: &GameState, player: PlayerName) -> PlayerName {
+    let team = game.configuration.teams.and_then(|teams| teams.team(player));
+    enum_iterator::all::<PlayerName>()
+        .filter(|&p| !matches!(game.player(p).player_type, PlayerType::None))
+        .find(|&p| p != player && !team.is_some_and(|team| team.contains(p)))
+        .unwrap_or(player)
+}
+
+fn opponent_description(player_type: &PlayerType) -> String {
+    match player_type {
+        PlayerType::Human(_) => "Human".to_string(),
+        PlayerType::Agent(agent) => {
+            let name = match agent.agent_type {
+                AgentType::FirstAvailableAction => "First Available Action",
+                AgentType::TreeSearch(_) => "Tree Search",
+                AgentType::MonteCarlo(_) => "Monte Carlo",
+                AgentType::External(_) => "External",
+            };
+            format!("{name} AI ({}s)", agent.search_duration.as_secs())
+        }
+        PlayerType::None => "None".to_string(),
+    }
+}
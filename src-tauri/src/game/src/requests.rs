@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use data::game_states::game_state::GameState;
+use data::player_states::player_options::PlayerOptions;
+use data::player_states::player_state::{PlayerQueries, PlayerType};
 use data::prompts::game_update::UpdateChannel;
 use data::users::user_state::UserState;
 use database::sqlite_database::SqliteDatabase;
@@ -45,3 +47,18 @@ pub fn fetch_game(
     game.updates = update_channel;
     game
 }
+
+/// Applies the [PlayerOptions] each human player has configured to their
+/// player in a newly-created game.
+///
+/// Options are stored with the game afterwards, so changing a user's options
+/// does not affect their games in progress.
+pub fn apply_user_options(database: SqliteDatabase, game: &mut GameState) {
+    for player in game.configuration.all_players {
+        if let PlayerType::Human(user_id) = game.player(player).player_type {
+            if let Some(user) = database.fetch_user(user_id) {
+                game.player_mut(player).options = user.options;
+            }
+        }
+    }
+}
@@ -17,30 +17,39 @@ use std::sync::Arc;
 
 use data::actions::user_action::UserAction;
 use data::prompts::select_order_prompt::CardOrderLocation;
-use data::users::user_state::{UserActivity, UserState};
+use data::users::user_state::UserActivity;
 use database::sqlite_database::SqliteDatabase;
+use database::users;
 use display::commands::field_state::{FieldKey, FieldValue};
 use display::core::card_view::ClientCardId;
+use display::core::display_state::PassUntil;
+use display::rendering::{deck_catalog, match_statistics};
 use primitives::game_primitives::UserId;
 use rules::action_handlers::actions;
 use tokio::sync::mpsc::UnboundedSender;
-use tracing::{debug_span, info, Instrument};
+use tracing::{debug_span, warn, Instrument};
 
 use crate::server_data::{Client, ClientData, GameResponse};
 use crate::{
-    game_action_server, leave_game_server, main_menu_server, new_game_server, panel_server,
+    campaign_server, card_details_server, card_search_server, chat_server, game_action_server,
+    game_creation, house_rules_server, leave_game_server, main_menu_server, new_game_server,
+    panel_server, profile_server, requests, server_data,
 };
 
 /// Connects to the current game scene.
 ///
 /// This returns commands to load & render the current game state. It's expected
 /// that this will be invoked on application start and on scene change.
+///
+/// The `response_channel` is retained for the lifetime of the new connection to
+/// deliver updates which originate from other requests, such as chat messages
+/// from other players in the same game.
 pub fn connect(
     database: SqliteDatabase,
     response_channel: UnboundedSender<GameResponse>,
     user_id: UserId,
 ) {
-    let user = fetch_or_create_user(database.clone(), user_id);
+    let user = users::fetch_or_create(&database, user_id);
     let _span = debug_span!("connect", ?user_id);
     match user.activity {
         UserActivity::Menu => main_menu_server::connect(response_channel, &user),
@@ -57,8 +66,13 @@ pub fn connect(
 /// [connect]) must be provided to this call.
 pub async fn handle_action(database: SqliteDatabase, client: &mut Client, action: UserAction) {
     let span = debug_span!("handle_action", ?action);
+    if !client.data.is_current_connection() || !client.data.is_in_sync() {
+        resync(database, client);
+        return;
+    }
+
     match action {
-        UserAction::NewGameAction(action) => new_game_server::create(database, client, action),
+        UserAction::NewGameAction(action) => new_game_server::create(database, client, *action),
         UserAction::GameAction(action) => {
             game_action_server::handle_game_action(database, client, action).instrument(span).await;
         }
@@ -79,6 +93,37 @@ pub async fn handle_action(database: SqliteDatabase, client: &mut Client, action
                 .instrument(span)
                 .await;
         }
+        UserAction::PassUntilEndOfTurn => {
+            game_action_server::handle_pass_until(database, client, PassUntil::EndOfTurn)
+                .instrument(span)
+                .await;
+        }
+        UserAction::PassUntilNextTurn => {
+            game_action_server::handle_pass_until(database, client, PassUntil::NextTurn)
+                .instrument(span)
+                .await;
+        }
+        UserAction::HoldPriority => game_action_server::handle_hold_priority(database, client),
+        UserAction::ToggleFullControl => {
+            game_action_server::handle_toggle_full_control(database, client)
+        }
+        UserAction::QueueGameAction(action) => {
+            game_action_server::handle_queue_game_action(database, client, action)
+                .instrument(span)
+                .await;
+        }
+        UserAction::ClearQueuedActions => {
+            game_action_server::handle_clear_queued_actions(database, client)
+        }
+        UserAction::StartCampaignStage(stage) => {
+            campaign_server::handle_start_stage(database, client, stage)
+        }
+        UserAction::SelectDeck(deck) => campaign_server::handle_select_deck(database, client, deck),
+        UserAction::ToggleAlwaysAskTrigger(ability_id, card_name) => {
+            game_action_server::handle_toggle_always_ask_trigger(
+                database, client, ability_id, card_name,
+            )
+        }
     }
 }
 
@@ -88,7 +133,67 @@ pub fn handle_update_field(
     key: FieldKey,
     value: FieldValue,
 ) {
-    game_action_server::handle_update_field(database, client, key, value);
+    if !client.data.is_current_connection() || !client.data.is_in_sync() {
+        resync(database, client);
+        return;
+    }
+
+    if key == FieldKey::DisplayName {
+        profile_server::handle_update_display_name(database, client, value);
+    } else if key.is_house_rule() {
+        house_rules_server::handle_update_field(database, client, key, value);
+    } else {
+        game_action_server::handle_update_field(database, client, key, value);
+    }
+}
+
+/// Handles a request made with [ClientData] from a previous connection, e.g.
+/// from a frontend which was still running when the user reconnected, or with
+/// a checksum which does not match the last game view sent to the user.
+///
+/// The request is discarded and the client is reconnected, receiving a full
+/// view of their current scene.
+fn resync(database: SqliteDatabase, client: &Client) {
+    warn!(?client.data.user_id, "Ignoring request from stale or desynchronized client");
+    connect(database, client.channel.clone(), client.data.user_id);
+}
+
+/// Handles a chat message sent by the user. The updated chat log is sent to
+/// the client as a [GameResponse].
+pub fn handle_send_chat_message(database: SqliteDatabase, client: &mut Client, message: String) {
+    chat_server::handle_send_chat_message(database, client, message);
+}
+
+/// Sends oracle text & rulings for a card to the client as a [GameResponse].
+pub fn handle_fetch_card_details(
+    database: SqliteDatabase,
+    client: &mut Client,
+    client_card_id: ClientCardId,
+) {
+    card_details_server::handle_fetch_card_details(database, client, client_card_id);
+}
+
+/// Sends the visible cards in graveyards, exile, and revealed zones matching
+/// `query` to the client as a [GameResponse].
+pub fn handle_search_cards(database: SqliteDatabase, client: &mut Client, query: String) {
+    card_search_server::handle_search_cards(database, client, query);
+}
+
+/// Sends the list of available pre-constructed decks to the client as a
+/// [GameResponse].
+pub fn handle_fetch_deck_catalog(database: SqliteDatabase, client: &mut Client) {
+    let user = requests::fetch_user(database.clone(), client.data.user_id);
+    let ignore_banned_list = user.house_rules.ignore_banned_list;
+    client.send(deck_catalog::render(game_creation::deck_catalog::get(), &user, |deck| {
+        game_creation::formats::legal_formats(&database, deck, ignore_banned_list)
+    }));
+}
+
+/// Sends aggregated win rates for the user's finished games to the client as a
+/// [GameResponse].
+pub fn handle_fetch_match_statistics(database: SqliteDatabase, client: &mut Client) {
+    let records = database.fetch_match_records(client.data.user_id);
+    client.send(match_statistics::render(game_creation::deck_catalog::get(), &records));
 }
 
 pub fn handle_drag_card(
@@ -106,14 +211,3 @@ pub fn handle_drag_card(
         index,
     );
 }
-
-fn fetch_or_create_user(database: SqliteDatabase, user_id: UserId) -> UserState {
-    if let Some(player) = database.fetch_user(user_id) {
-        player
-    } else {
-        let user = UserState { id: user_id, activity: UserActivity::Menu };
-        database.write_user(&user);
-        info!(?user_id, "Created new user");
-        user
-    }
-}
@@ -12,15 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use dashmap::DashMap;
 use display::commands::command::Command;
 use display::commands::scene_identifier::SceneIdentifier;
+use display::core::game_view::GameView;
 use display::panels::modal_panel::{ModalPanel, PanelData};
+use once_cell::sync::Lazy;
 use primitives::game_primitives::{GameId, UserId};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use tokio::sync::mpsc::UnboundedSender;
+use tracing::debug;
 use uuid::Uuid;
 
+/// Scene currently displayed by each live connection, keyed by user and
+/// connection ID.
+static CONNECTIONS: Lazy<DashMap<(UserId, Uuid), SceneIdentifier>> = Lazy::new(DashMap::new);
+
+/// Checksum of the most recent [GameView] sent to each user for each game.
+static CHECKSUMS: Lazy<DashMap<(UserId, GameId), u32>> = Lazy::new(DashMap::new);
+
+/// Response channel for each live connection, keyed by user and connection
+/// ID, used to deliver updates which originate from other requests.
+static CHANNELS: Lazy<DashMap<(UserId, Uuid), UnboundedSender<GameResponse>>> =
+    Lazy::new(DashMap::new);
+
 #[derive(Debug, Clone)]
 pub struct Client {
     pub data: ClientData,
@@ -28,12 +47,54 @@ pub struct Client {
 }
 
 impl Client {
+    /// Creates a client for a new connection by `user_id` to `scene`, which
+    /// receives updates originating from other requests via `channel`.
+    ///
+    /// Each scene can only be displayed by one connection at a time, so this
+    /// invalidates the user's previous connections to the same scene, e.g.
+    /// from before a frontend reloaded. Connections to other scenes are
+    /// unaffected.
+    pub fn connect(
+        user_id: UserId,
+        scene: SceneIdentifier,
+        channel: UnboundedSender<GameResponse>,
+    ) -> Self {
+        CONNECTIONS.retain(|&(user, connection_id), &mut connected| {
+            let stale = user == user_id && connected == scene;
+            if stale {
+                CHANNELS.remove(&(user, connection_id));
+            }
+            !stale
+        });
+        let connection_id = Uuid::new_v4();
+        CONNECTIONS.insert((user_id, connection_id), scene);
+        CHANNELS.insert((user_id, connection_id), channel.clone());
+        Self {
+            data: ClientData { id: Uuid::new_v4(), user_id, scene, connection_id, checksum: None },
+            channel,
+        }
+    }
+
+    /// Changes the scene displayed by this client's connection.
+    pub fn set_scene(&mut self, scene: SceneIdentifier) {
+        self.data.scene = scene;
+        if let Some(mut connected) = CONNECTIONS.get_mut(&self.data.connection_key()) {
+            *connected = scene;
+        }
+    }
+
     pub fn send(&self, command: impl Into<Command>) {
         let mut client_data = self.data.clone();
         client_data.id = Uuid::new_v4();
-        self.channel
-            .send(GameResponse { client_data, command: command.into() })
-            .expect("Failed to send command, receiver has dropped");
+        client_data.checksum = client_data.current_checksum();
+        if self.channel.send(GameResponse { client_data, command: command.into() }).is_err() {
+            // The frontend disconnected, it will receive the current state when it
+            // reconnects.
+            debug!(?self.data.user_id, "Receiver has dropped, discarding command");
+            CHANNELS.remove_if(&self.data.connection_key(), |_, channel| {
+                channel.same_channel(&self.channel)
+            });
+        }
     }
 
     pub fn send_all(&self, commands: Vec<Command>) {
@@ -68,9 +129,48 @@ pub struct ClientData {
 
     /// Currently-displayed top level screen
     pub scene: SceneIdentifier,
+
+    /// Identifies the connection this client data was issued for.
+    ///
+    /// A new connection ID is issued each time the user connects, and requests
+    /// made with an older connection ID are rejected as stale.
+    pub connection_id: Uuid,
+
+    /// Checksum of the most recent game view sent to this client, if it is
+    /// displaying a game.
+    ///
+    /// Clients must echo this value with each request. A mismatch indicates
+    /// that the client missed an update, and triggers a resync.
+    pub checksum: Option<u32>,
 }
 
 impl ClientData {
+    /// Returns true if this client data carries the checksum of the most
+    /// recent game view sent to the user, or if no game view has been sent.
+    pub fn is_in_sync(&self) -> bool {
+        match self.current_checksum() {
+            Some(expected) => self.checksum == Some(expected),
+            None => true,
+        }
+    }
+
+    fn current_checksum(&self) -> Option<u32> {
+        let SceneIdentifier::Game(game_id) = self.scene else {
+            return None;
+        };
+        CHECKSUMS.get(&(self.user_id, game_id)).map(|checksum| *checksum)
+    }
+
+    /// Returns true if this client data was issued for a connection which
+    /// has not since been invalidated by a newer connection to the same scene.
+    pub fn is_current_connection(&self) -> bool {
+        CONNECTIONS.contains_key(&self.connection_key())
+    }
+
+    fn connection_key(&self) -> (UserId, Uuid) {
+        (self.user_id, self.connection_id)
+    }
+
     pub fn game_id(&self) -> GameId {
         match self.scene {
             SceneIdentifier::Game(id) => id,
@@ -78,3 +178,39 @@ impl ClientData {
         }
     }
 }
+
+/// Records `view` as the most recent [GameView] sent to the user for a game.
+pub fn record_checksum(user_id: UserId, game_id: GameId, view: &GameView) {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_vec(view).expect("Error serializing game view").hash(&mut hasher);
+    CHECKSUMS.insert((user_id, game_id), hasher.finish() as u32);
+}
+
+/// Returns a [Client] for each live connection of `user_id` which is
+/// displaying `scene`.
+pub fn connected_clients(user_id: UserId, scene: SceneIdentifier) -> Vec<Client> {
+    CHANNELS.retain(|_, channel| !channel.is_closed());
+    CONNECTIONS
+        .iter()
+        .filter(|entry| entry.key().0 == user_id && *entry.value() == scene)
+        .filter_map(|entry| {
+            let (user_id, connection_id) = *entry.key();
+            let channel = CHANNELS.get(&(user_id, connection_id))?.clone();
+            Some(Client {
+                data: ClientData {
+                    id: Uuid::new_v4(),
+                    user_id,
+                    scene,
+                    connection_id,
+                    checksum: None,
+                },
+                channel,
+            })
+        })
+        .collect()
+}
+
+/// Clears the recorded checksum for a user's game, e.g. when they leave it.
+pub fn clear_checksum(user_id: UserId, game_id: GameId) {
+    CHECKSUMS.remove(&(user_id, game_id));
+}
@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, Ident, Token};
 
 #[proc_macro_derive(Invokable)]
 pub fn invokable_macro_derive(input: TokenStream) -> TokenStream {
@@ -35,3 +38,65 @@ fn impl_invokable(ast: &syn::DeriveInput) -> TokenStream {
     };
     gen.into()
 }
+
+/// Declares a card definition function.
+///
+/// The first argument is the name of the function to generate, which must be
+/// the snake_case version of the card's name as it appears in the
+/// `card_name` module. The remaining arguments are the card's abilities, in
+/// oracle text order. Faces and other printed characteristics are read from
+/// the card's Oracle data and so are not declared here.
+///
+/// ```ignore
+/// card!(alpine_grizzly);
+///
+/// card!(
+///     giant_growth,
+///     SpellAbility::new().targets(...).effect(...),
+/// );
+/// ```
+///
+/// Expands to a zero-argument `pub fn` returning a `CardDefinition`, which is
+/// registered in `all_cards::card_list` by the `all_cards` build script.
+#[proc_macro]
+pub fn card(input: TokenStream) -> TokenStream {
+    let declaration = syn::parse_macro_input!(input as CardDeclaration);
+    impl_card(&declaration)
+}
+
+struct CardDeclaration {
+    attributes: Vec<Attribute>,
+    name: Ident,
+    abilities: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for CardDeclaration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attributes = input.call(Attribute::parse_outer)?;
+        let name = input.parse()?;
+        let abilities = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+        Ok(Self { attributes, name, abilities })
+    }
+}
+
+fn impl_card(declaration: &CardDeclaration) -> TokenStream {
+    let attributes = &declaration.attributes;
+    let name = &declaration.name;
+    let constant = format_ident!("{}", name.to_string().to_uppercase());
+    let abilities = declaration.abilities.iter();
+    let gen = quote! {
+        #(#attributes)*
+        pub fn #name() -> data::card_definitions::card_definition::CardDefinition {
+            data::card_definitions::card_definition::CardDefinition::new(
+                data::card_definitions::card_name::#constant
+            )
+            #(.ability(#abilities))*
+        }
+    };
+    gen.into()
+}
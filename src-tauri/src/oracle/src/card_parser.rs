@@ -15,6 +15,7 @@
 use std::iter;
 
 use data::card_definitions::card_name::CardName;
+use data::core::card_tags::CardTag;
 use data::printed_cards::card_subtypes::{
     ArtifactSubtype, BattleSubtype, CardSubtypes, CreatureType, DungeonSubtype, EnchantmentSubtype,
     InstantOrSorcerySubtype, LandType, PlaneSubtype, PlaneswalkerSubtype,
@@ -71,11 +72,12 @@ pub fn parse(faces: Vec<DatabaseCardFace>) -> PrintedCard {
         layout: card_layout,
         face: parse_face(primary, Face::Primary),
         face_b: secondary.map(|face| parse_face(face, Face::FaceB)),
+        custom: false,
     }
 }
 
 fn parse_face(face: &DatabaseCardFace, face_identifier: Face) -> PrintedCardFace {
-    PrintedCardFace {
+    let mut result = PrintedCardFace {
         displayed_name: face.face_name.clone().unwrap_or_else(|| face.name.clone()),
         face_identifier,
         supertypes: supertypes(split(&face.supertypes)),
@@ -86,9 +88,13 @@ fn parse_face(face: &DatabaseCardFace, face_identifier: Face) -> PrintedCardFace
         mana_value: face.mana_value.round() as u64,
         power: power(face.power.as_ref()),
         toughness: toughness(face.toughness.as_ref()),
+        defense: defense(face.defense.as_ref()),
         layout: layout(&face.layout),
         colors: colors(split(&face.colors)),
-    }
+        tags: EnumSet::empty(),
+    };
+    result.tags = tags(&result);
+    result
 }
 
 fn split(s: &Option<String>) -> Vec<&str> {
@@ -199,6 +205,12 @@ fn toughness(toughness: Option<&String>) -> Option<PrintedToughness> {
     })
 }
 
+/// Parses a printed defense value, returning None if it is not a number,
+/// e.g. for a defense of `*`.
+fn defense(defense: Option<&String>) -> Option<u32> {
+    defense.and_then(|d| d.parse::<u32>().ok())
+}
+
 fn layout(string: &str) -> FaceLayout {
     string
         .parse::<FaceLayout>()
@@ -213,3 +225,44 @@ fn colors(colors: Vec<&str>) -> EnumSet<Color> {
         })
         .collect()
 }
+
+/// Populates heuristic [CardTag]s for a face based on its oracle text and
+/// printed characteristics.
+fn tags(face: &PrintedCardFace) -> EnumSet<CardTag> {
+    let text = face.oracle_text.as_deref().unwrap_or_default().to_lowercase();
+    let mut result = EnumSet::empty();
+
+    let removal = ["destroy target", "exile target", "damage to target", "damage to any target"];
+    if removal.iter().any(|s| text.contains(s))
+        || (text.contains("target creature") && text.contains("gets -"))
+    {
+        result.insert(CardTag::Removal);
+    }
+
+    if (text.contains("add {") && !face.card_types.contains(CardType::Land))
+        || (text.contains("search your library for") && text.contains("land card"))
+        || text.contains("play an additional land")
+    {
+        result.insert(CardTag::Ramp);
+    }
+
+    let card_advantage = ["draw two", "draw three", "draw a card", "draws a card"];
+    if card_advantage.iter().any(|s| text.contains(s)) {
+        result.insert(CardTag::CardAdvantage);
+    }
+
+    if face.card_types.contains(CardType::Creature) {
+        let evasion = ["flying", "can't be blocked", "menace", "trample", "shadow", "intimidate"];
+        if evasion.iter().any(|s| text.contains(s)) {
+            result.insert(CardTag::Evasive);
+        }
+
+        if let Some(PrintedPower::Number(power)) = face.power {
+            if power >= 5 && face.mana_value >= 5 {
+                result.insert(CardTag::Finisher);
+            }
+        }
+    }
+
+    result
+}
@@ -18,6 +18,7 @@ use dashmap::{DashMap, DashSet};
 use data::card_definitions::definitions::CardFn;
 use data::card_states::card_reference::CardReference;
 use data::game_states::oracle::Oracle;
+use data::printed_cards::custom_card;
 use data::printed_cards::printed_card::PrintedCard;
 use data::printed_cards::printed_card_id::PrintedCardId;
 use database::sqlite_database::SqliteDatabase;
@@ -43,8 +44,11 @@ impl Oracle for OracleImpl {
         if let Some(printed) = CARDS.get(&id) {
             CardReference { identifier: id, printed_card_reference: printed.value().clone() }
         } else {
-            let faces = self.database.fetch_printed_faces(id);
-            let parsed = card_parser::parse(faces);
+            let parsed = if let Some(custom) = custom_card::get(id) {
+                PrintedCard { custom: true, ..card_parser::parse(vec![custom.to_database_face()]) }
+            } else {
+                card_parser::parse(self.database.fetch_printed_faces(id))
+            };
             let reference = Arc::new(parsed);
             CARDS.insert(id, reference.clone());
             CardReference { identifier: id, printed_card_reference: reference }
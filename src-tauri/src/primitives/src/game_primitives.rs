@@ -66,6 +66,14 @@ impl FromStr for Color {
     }
 }
 
+impl Color {
+    /// Returns the color named by a color word in rules text, e.g. "black",
+    /// ignoring case.
+    pub fn from_word(word: &str) -> Option<Color> {
+        COLORS.iter().find(|color| color.to_string().eq_ignore_ascii_case(word))
+    }
+}
+
 /// Possible colors of mana.
 #[derive(Debug, Hash, Ord, PartialOrd, Serialize, Deserialize, EnumSetType, Enum, Sequence)]
 pub enum ManaColor {
@@ -408,6 +416,44 @@ impl TryFrom<EntityId> for GraveyardCardId {
     }
 }
 
+/// Unique identifier for a card in the exile zone
+#[derive(
+    Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize,
+)]
+pub struct ExiledCardId {
+    pub object_id: ObjectId,
+    pub internal_card_id: CardId,
+}
+
+impl ExiledCardId {
+    pub fn new(object_id: ObjectId, card_id: CardId) -> Self {
+        Self { object_id, internal_card_id: card_id }
+    }
+}
+
+impl HasObjectId for ExiledCardId {
+    fn object_id(&self) -> ObjectId {
+        self.object_id
+    }
+}
+
+impl From<ExiledCardId> for EntityId {
+    fn from(value: ExiledCardId) -> Self {
+        EntityId::Card(value.internal_card_id, value.object_id)
+    }
+}
+
+impl TryFrom<EntityId> for ExiledCardId {
+    type Error = ();
+
+    fn try_from(value: EntityId) -> Result<Self, Self::Error> {
+        match value {
+            EntityId::Card(card_id, object_id) => Ok(Self::new(object_id, card_id)),
+            _ => Err(()),
+        }
+    }
+}
+
 /// A unique identifier for an event.
 ///
 /// Each instance of an callback function being invoked gets its own event ID.
@@ -473,7 +519,7 @@ impl StackItemId {
 /// A zone is a place where objects can be during the game.
 ///
 /// See <https://yawgatog.com/resources/magic-rules/#R4001>
-#[derive(Debug, Serialize, Deserialize, Hash, EnumSetType)]
+#[derive(Debug, Hash, Ord, PartialOrd, Serialize, Deserialize, EnumSetType)]
 pub enum Zone {
     Hand,
     Graveyard,
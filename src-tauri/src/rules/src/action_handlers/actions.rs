@@ -17,18 +17,22 @@ use data::actions::game_action::GameAction;
 use data::card_states::zones::ZoneQueries;
 use data::game_states::game_state::{GameOperationMode, GameState, GameStatus};
 use data::game_states::history_data::TakenGameAction;
+use data::player_states::player_state::PlayerQueries;
 use data::printed_cards::printed_card::Face;
 use primitives::game_primitives::{CardId, PlayerName, Source, Zone};
 use tracing::{debug, info, instrument};
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{HaltCondition, OrSkip, Outcome, RuleError};
 
 use crate::action_handlers::{combat_actions, debug_actions, prompt_actions};
-use crate::core::debug_snapshot;
+use crate::core::{debug_snapshot, zone_invariants};
 use crate::legality::legal_actions;
-use crate::mutations::{permanents, priority, state_based_actions};
+use crate::mutations::{
+    foretell, known_cards, permanents, planechase, players, priority, state_based_actions,
+};
+use crate::planner::spell_planner;
 use crate::play_cards::{pick_face_to_play, play_card};
-use crate::queries::player_queries;
+use crate::queries::{card_queries, planechase_queries, player_queries};
 use crate::resolve_cards::resolve;
 use crate::steps::step;
 
@@ -41,13 +45,18 @@ pub struct ExecuteAction {
     pub validate: bool,
 }
 
+/// Executes a game action for the provided player.
+///
+/// Returns a [RuleError] if the game state was found to be inconsistent while
+/// applying the action. The game should be discarded in this case, since some
+/// effects of the action may have been applied and others not.
 #[instrument(name = "actions_execute", level = "debug", skip(game))]
 pub fn execute(
     game: &mut GameState,
     player: PlayerName,
     action: GameAction,
     options: ExecuteAction,
-) {
+) -> Result<(), RuleError> {
     if options.validate {
         assert!(
             legal_actions::can_take_action(game, player, &action) || action.is_debug_action(),
@@ -64,27 +73,104 @@ pub fn execute(
             .push(TakenGameAction { action, track_for_undo: !options.skip_undo_tracking });
     }
 
-    match action {
+    match apply_action(game, player, action) {
+        Err(HaltCondition::RuleError(rule_error)) => Err(rule_error),
+        _ => Ok(()),
+    }
+}
+
+fn apply_action(game: &mut GameState, player: PlayerName, action: GameAction) -> Outcome {
+    outcome::execute(|| match action {
         GameAction::DebugAction(a) => debug_actions::execute(game, player, a),
         GameAction::PassPriority => handle_pass_priority(game, player),
         GameAction::ProposePlayingCard(id) => handle_play_card(game, Source::Game, player, id),
         GameAction::CombatAction(a) => combat_actions::execute(game, player, a),
-    };
+        GameAction::Foretell(id) => handle_foretell(game, Source::Game, player, id),
+        GameAction::RollPlanarDie => handle_roll_planar_die(game, Source::Game, player),
+        GameAction::HoldPriority => handle_hold_priority(game, player),
+        GameAction::Concede => handle_concede(game, player),
+    })?;
 
     if legal_actions::can_any_player_pass_priority(game) {
         // If any player has priority as a result of this game action, check state-based
         // actions.
-        state_based_actions::on_will_receive_priority(game);
+        state_based_actions::on_will_receive_priority(game)?;
     }
+
+    if !matches!(game.operation_mode, GameOperationMode::AgentSearch(_)) {
+        known_cards::update(game);
+    }
+
+    game.history.write_events();
+    zone_invariants::check(game, player, action);
+    outcome::OK
 }
 
 #[instrument(level = "debug", skip(game))]
-fn handle_pass_priority(game: &mut GameState, player: PlayerName) {
+fn handle_pass_priority(game: &mut GameState, player: PlayerName) -> Outcome {
     priority::pass(game, player)
 }
 
 #[instrument(level = "debug", skip(game))]
-fn handle_play_card(game: &mut GameState, source: Source, player: PlayerName, card_id: CardId) {
+fn handle_hold_priority(game: &mut GameState, player: PlayerName) -> Outcome {
+    game.player_mut(player).options.hold_priority = true;
+    outcome::OK
+}
+
+#[instrument(level = "debug", skip(game))]
+fn handle_concede(game: &mut GameState, player: PlayerName) -> Outcome {
+    info!(?player, "Player conceded");
+    players::lose_game(game, Source::Game, player)
+}
+
+/// Pays {2} and exiles a card with foretell from hand face down.
+///
+/// This is a special action, so it does not use the stack and the player
+/// receives priority afterward.
+///
+/// See [GameAction::Foretell].
+#[instrument(level = "debug", skip(game))]
+fn handle_foretell(
+    game: &mut GameState,
+    source: Source,
+    player: PlayerName,
+    card_id: CardId,
+) -> Outcome {
+    debug!(?player, ?card_id, "Foretelling card");
+    let payment =
+        spell_planner::pay_cost(game, player, card_queries::foretell_action_cost()).or_skip()?;
+    for land in &payment.basic_land_abilities_to_activate {
+        permanents::tap(game, source, *land)?;
+    }
+    foretell::exile_face_down(game, source, card_id)
+}
+
+/// Pays the planar die cost and rolls the planar die.
+///
+/// This is a special action, so it does not use the stack and the player
+/// receives priority afterward.
+///
+/// See [GameAction::RollPlanarDie].
+#[instrument(level = "debug", skip(game))]
+fn handle_roll_planar_die(game: &mut GameState, source: Source, player: PlayerName) -> Outcome {
+    debug!(?player, "Rolling planar die");
+    let cost = planechase_queries::planar_die_cost(game, player);
+    let payment = spell_planner::pay_cost(game, player, cost).or_skip()?;
+    for land in &payment.basic_land_abilities_to_activate {
+        permanents::tap(game, source, *land)?;
+    }
+    planechase::roll_planar_die(game, source, player)?;
+    game.history_counters_mut(player).planar_die_rolls += 1;
+    outcome::OK
+}
+
+#[instrument(level = "debug", skip(game))]
+fn handle_play_card(
+    game: &mut GameState,
+    source: Source,
+    player: PlayerName,
+    card_id: CardId,
+) -> Outcome {
     debug!(?player, ?card_id, "Playing card");
-    play_card::execute(game, player, Source::Game, card_id);
+    play_card::execute(game, player, Source::Game, card_id)
 }
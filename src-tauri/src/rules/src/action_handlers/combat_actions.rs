@@ -17,18 +17,30 @@ use std::collections::BTreeMap;
 use data::actions::game_action::CombatAction;
 #[allow(unused)] // Used in docs
 use data::actions::game_action::GameAction;
+use data::card_states::zones::ZoneQueries;
 use data::game_states::combat_state::{
     AttackTarget, AttackerId, BlockerId, BlockerMap, CombatState,
 };
 use data::game_states::game_state::GameState;
-use primitives::game_primitives::{CardType, PlayerName, Source};
+use data::game_states::history_data::HistoryEvent;
+use data::printed_cards::printed_card::Face;
+use data::prompts::entity_choice_prompt::Choice;
+use data::prompts::game_update::GameAnimation;
+use data::text_strings::Text;
+use primitives::game_primitives::{
+    CardId, CardType, EntityId, HasController, PlayerName, Source, Zone, ALL_POSSIBLE_PLAYERS,
+};
 use tracing::instrument;
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
 
-use crate::mutations::permanents;
+use crate::mutations::{move_card, permanents};
+use crate::planner::spell_planner;
+use crate::prompt_handling::prompts;
 use crate::queries::{combat_queries, player_queries};
 
 #[instrument(name = "combat_actions_execute", level = "debug", skip(game))]
-pub fn execute(game: &mut GameState, player: PlayerName, action: CombatAction) {
+pub fn execute(game: &mut GameState, player: PlayerName, action: CombatAction) -> Outcome {
     match action {
         CombatAction::AddSelectedAttacker(card_id) => {
             add_selected_attacker(game, Source::Game, card_id)
@@ -50,14 +62,15 @@ pub fn execute(game: &mut GameState, player: PlayerName, action: CombatAction) {
             order_blocker(game, Source::Game, attacker_id, blocker_id, position)
         }
         CombatAction::ConfirmBlockerOrder => confirm_blocker_order(game, Source::Game),
-    };
+        CombatAction::Ninjutsu(card_id) => ninjutsu(game, player, Source::Game, card_id),
+    }
 }
 
 /// Sets a creature as a selected attacker.
 ///
 /// See [CombatAction::AddSelectedAttacker].
 #[instrument(level = "debug", skip(game))]
-fn add_selected_attacker(game: &mut GameState, source: Source, card_id: AttackerId) {
+fn add_selected_attacker(game: &mut GameState, source: Source, card_id: AttackerId) -> Outcome {
     let next = player_queries::next_player(game);
     // There is more than one possible attack target because a planeswalker or
     // battle is in play (or there are more than 2 players), must select attack
@@ -65,7 +78,7 @@ fn add_selected_attacker(game: &mut GameState, source: Source, card_id: Attacker
     let requires_target = combat_queries::attack_targets(game, source).nth(1).is_some();
 
     let Some(CombatState::ProposingAttackers(attackers)) = &mut game.combat else {
-        panic!("Not in the 'ProposingAttackers' state");
+        return outcome::error("Not in the 'ProposingAttackers' state");
     };
     if requires_target {
         attackers.selected_attackers.insert(card_id);
@@ -73,55 +86,94 @@ fn add_selected_attacker(game: &mut GameState, source: Source, card_id: Attacker
         // Only one attack target, automatically assign it
         attackers.proposed_attacks.insert(card_id, AttackTarget::Player(next));
     }
+    outcome::OK
 }
 
 /// Sets an attack target for selected attackers.
 ///
 /// See [CombatAction::SetSelectedAttackersTarget].
 #[instrument(level = "debug", skip(game))]
-fn set_selected_attackers_target(game: &mut GameState, source: Source, target: AttackTarget) {
+fn set_selected_attackers_target(
+    game: &mut GameState,
+    source: Source,
+    target: AttackTarget,
+) -> Outcome {
     let Some(CombatState::ProposingAttackers(attackers)) = &mut game.combat else {
-        panic!("Not in the 'ProposingAttackers' state");
+        return outcome::error("Not in the 'ProposingAttackers' state");
     };
 
     for attacker_id in attackers.selected_attackers.iter() {
         attackers.proposed_attacks.insert(*attacker_id, target);
     }
+    outcome::OK
 }
 
 /// Removes an attacker proposal.
 ///
 /// See [CombatAction::RemoveAttacker].
 #[instrument(level = "debug", skip(game))]
-fn remove_attacker(game: &mut GameState, source: Source, card_id: AttackerId) {
+fn remove_attacker(game: &mut GameState, source: Source, card_id: AttackerId) -> Outcome {
     let Some(CombatState::ProposingAttackers(attackers)) = &mut game.combat else {
-        panic!("Not in the 'ProposingAttackers' state");
+        return outcome::error("Not in the 'ProposingAttackers' state");
     };
     attackers.selected_attackers.remove(&card_id);
     attackers.proposed_attacks.remove(card_id);
+    outcome::OK
 }
 
 /// Submits the attacker list.
 ///
 /// See [CombatAction::ConfirmAttackers].
 #[instrument(level = "debug", skip(game))]
-fn confirm_attackers(game: &mut GameState, source: Source) {
+fn confirm_attackers(game: &mut GameState, source: Source) -> Outcome {
     let Some(CombatState::ProposingAttackers(attackers)) = game.combat.take() else {
-        panic!("Not in the 'ProposingAttackers' state");
+        return outcome::error("Not in the 'ProposingAttackers' state");
     };
-    for attacker in attackers.proposed_attacks.all_attackers() {
-        permanents::tap(game, Source::Game, attacker);
-    }
+    // > 508.1f. The active player taps the chosen creatures. Tapping a creature
+    // > when it's declared as an attacker isn't a cost; attacking simply causes
+    // > creatures to become tapped.
+    // <https://yawgatog.com/resources/magic-rules/#R5081f>
+    game.simultaneous_animations(|game| {
+        for (&attacker, &target) in attackers.proposed_attacks.all() {
+            if combat_queries::has_vigilance(game, source, attacker) != Some(true) {
+                outcome::execute(|| permanents::tap(game, Source::Game, attacker))?;
+            }
+            game.add_history_event(HistoryEvent::AttackWithCreature(attacker, target));
+            game.add_animation(GameAnimation::AttackLunge(attacker));
+        }
+        outcome::OK
+    })?;
+    let exert_candidates = attackers
+        .proposed_attacks
+        .all_attackers()
+        .filter(|&attacker| combat_queries::can_exert(game, source, attacker) == Some(true))
+        .collect::<Vec<_>>();
+    game.invalidate_query_caches();
     game.combat = Some(CombatState::ConfirmedAttackers(attackers.proposed_attacks));
+
+    for attacker in exert_candidates {
+        outcome::execute(|| {
+            let controller = game.card(attacker).or_skip()?.controller();
+            let choice = prompts::multiple_choice(game, controller, Text::ExertPrompt, vec![
+                Text::Exert,
+                Text::DoNotExert,
+            ]);
+            if choice == Text::Exert {
+                permanents::exert(game, source, attacker)?;
+            }
+            outcome::OK
+        })?;
+    }
+    outcome::OK
 }
 
 /// Sets a creature as a selected blocker.
 ///
 /// See [CombatAction::AddSelectedBlocker].
 #[instrument(level = "debug", skip(game))]
-fn add_selected_blocker(game: &mut GameState, source: Source, card_id: BlockerId) {
+fn add_selected_blocker(game: &mut GameState, source: Source, card_id: BlockerId) -> Outcome {
     let Some(CombatState::ProposingBlockers(blockers)) = &mut game.combat else {
-        panic!("Not in the 'ProposingBlockers' state");
+        return outcome::error("Not in the 'ProposingBlockers' state");
     };
     if let Some(id) = blockers.attackers.exactly_one() {
         // Only one attacker, automatically block it.
@@ -129,41 +181,48 @@ fn add_selected_blocker(game: &mut GameState, source: Source, card_id: BlockerId
     } else {
         blockers.selected_blockers.insert(card_id);
     }
+    outcome::OK
 }
 
 /// Sets a block target for the selected blockers.
 ///
 /// See [CombatAction::SetSelectedBlockersTarget].
 #[instrument(level = "debug", skip(game))]
-fn set_selected_blockers_target(game: &mut GameState, source: Source, attacker: AttackerId) {
+fn set_selected_blockers_target(
+    game: &mut GameState,
+    source: Source,
+    attacker: AttackerId,
+) -> Outcome {
     let Some(CombatState::ProposingBlockers(blockers)) = &mut game.combat else {
-        panic!("Not in the 'ProposingBlockers' state");
+        return outcome::error("Not in the 'ProposingBlockers' state");
     };
 
     for blocker_id in blockers.selected_blockers.iter() {
         blockers.proposed_blocks.insert(*blocker_id, vec![attacker]);
     }
+    outcome::OK
 }
 
 /// Removes a blocker proposal.
 ///
 /// See [CombatAction::RemoveBlocker].
 #[instrument(level = "debug", skip(game))]
-fn remove_blocker(game: &mut GameState, source: Source, card_id: BlockerId) {
+fn remove_blocker(game: &mut GameState, source: Source, card_id: BlockerId) -> Outcome {
     let Some(CombatState::ProposingBlockers(blockers)) = &mut game.combat else {
-        panic!("Not in the 'ProposingBlockers' state");
+        return outcome::error("Not in the 'ProposingBlockers' state");
     };
     blockers.selected_blockers.remove(&card_id);
     blockers.proposed_blocks.remove(&card_id);
+    outcome::OK
 }
 
 /// Submits the blocker list.
 ///
 /// See [CombatAction::ConfirmBlockers].
 #[instrument(level = "debug", skip(game))]
-fn confirm_blockers(game: &mut GameState, source: Source) {
+fn confirm_blockers(game: &mut GameState, source: Source) -> Outcome {
     let Some(CombatState::ProposingBlockers(blockers)) = game.combat.take() else {
-        panic!("Not in the 'ProposingBlockers' state");
+        return outcome::error("Not in the 'ProposingBlockers' state");
     };
     let mut attackers_to_blockers = BTreeMap::new();
     for (&blocker_id, attackers) in &blockers.proposed_blocks {
@@ -173,11 +232,13 @@ fn confirm_blockers(game: &mut GameState, source: Source) {
         // TODO: Figure out some kind of default ordering for blockers
         attackers_to_blockers.entry(attackers[0]).or_insert_with(Vec::new).push(blocker_id);
     }
+    game.invalidate_query_caches();
     game.combat = Some(CombatState::OrderingBlockers(BlockerMap {
         attackers: blockers.attackers,
         blocked_attackers: attackers_to_blockers,
         reverse_lookup: blockers.proposed_blocks,
     }));
+    outcome::OK
 }
 
 /// Sets the order of a blocker for a creature.
@@ -190,25 +251,92 @@ fn order_blocker(
     attacker_id: AttackerId,
     blocker_id: BlockerId,
     position: usize,
-) {
+) -> Outcome {
     let Some(CombatState::OrderingBlockers(blockers)) = &mut game.combat else {
-        panic!("Not in the 'OrderingBlockers' state");
+        return outcome::error("Not in the 'OrderingBlockers' state");
+    };
+    let Some(entry) = blockers.blocked_attackers.get_mut(&attacker_id) else {
+        return outcome::error(format!("Attacker not found {attacker_id:?}"));
     };
-    let entry = blockers
-        .blocked_attackers
-        .get_mut(&attacker_id)
-        .unwrap_or_else(|| panic!("Attacker not found {attacker_id:?}"));
     entry.retain(|id| id != &blocker_id);
-    entry.insert(position, blocker_id);
+    entry.insert(position.min(entry.len()), blocker_id);
+    outcome::OK
 }
 
 /// Submits the blocker order.
 ///
 /// See [CombatAction::ConfirmBlockerOrder].
 #[instrument(level = "debug", skip(game))]
-fn confirm_blocker_order(game: &mut GameState, source: Source) {
+fn confirm_blocker_order(game: &mut GameState, source: Source) -> Outcome {
     let Some(CombatState::OrderingBlockers(blockers)) = game.combat.take() else {
-        panic!("Not in the 'OrderingBlockers' state");
+        return outcome::error("Not in the 'OrderingBlockers' state");
     };
+    game.invalidate_query_caches();
     game.combat = Some(CombatState::ConfirmedBlockers(blockers));
+    outcome::OK
+}
+
+/// Activates the ninjutsu ability of a card in hand, swapping it with an
+/// unblocked attacker.
+///
+/// > 702.49a. Ninjutsu is an activated ability that functions only while the
+/// > card with ninjutsu is in a player's hand. "Ninjutsu [cost]" means
+/// > "[Cost], Reveal this card from your hand, Return an unblocked attacking
+/// > creature you control to its owner's hand: Put this card onto the
+/// > battlefield from your hand tapped and attacking."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70249a>
+///
+/// The ability is currently resolved immediately instead of using the stack.
+///
+/// See [CombatAction::Ninjutsu].
+#[instrument(level = "debug", skip(game))]
+fn ninjutsu(game: &mut GameState, player: PlayerName, source: Source, card_id: CardId) -> Outcome {
+    let cost = combat_queries::ninjutsu_cost(game, card_id).or_skip()?;
+    let payment = spell_planner::pay_cost(game, player, cost).or_skip()?;
+    let unblocked = combat_queries::unblocked_attackers(game, player);
+    let returned: AttackerId = if let [attacker] = unblocked.as_slice() {
+        *attacker
+    } else {
+        let choices = unblocked
+            .iter()
+            .map(|&attacker| Choice { entity_id: EntityId::from(attacker) })
+            .collect();
+        prompts::choose_entity(game, player, Text::SelectUnblockedAttacker, choices)
+            .try_into()
+            .ok()
+            .or_skip()?
+    };
+
+    for land in &payment.basic_land_abilities_to_activate {
+        permanents::tap(game, source, *land)?;
+    }
+    game.card_mut(card_id).or_skip()?.revealed_to = ALL_POSSIBLE_PLAYERS;
+
+    let Some(CombatState::ConfirmedBlockers(blockers)) = &mut game.combat else {
+        return outcome::SKIPPED;
+    };
+    let target = blockers.attackers.get_target(returned).or_skip()?;
+    blockers.attackers.remove(returned);
+    permanents::return_to_hand(game, source, returned)?;
+
+    move_card::run(game, source, card_id, Zone::Battlefield)?;
+    permanents::turn_face_up(game, source, card_id, Face::Primary)?;
+    permanents::tap(game, source, card_id)?;
+    let ninja = game.card(card_id).or_skip()?.permanent_id().or_skip()?;
+
+    // > 506.3a. If an effect puts a creature onto the battlefield attacking,
+    // > that creature's controller chooses which player, planeswalker, or battle
+    // > it's attacking, unless the effect specifies otherwise. It's never
+    // > considered to have been declared as an attacker.
+    //
+    // <https://yawgatog.com/resources/magic-rules/#R5063a>
+    //
+    // The new attacker attacks the same target as the creature it replaced.
+    let Some(CombatState::ConfirmedBlockers(blockers)) = &mut game.combat else {
+        return outcome::SKIPPED;
+    };
+    blockers.attackers.insert(ninja, target);
+    game.passed.clear();
+    outcome::OK
 }
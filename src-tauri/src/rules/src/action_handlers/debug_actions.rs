@@ -14,23 +14,31 @@
 
 use data::actions::debug_action::DebugGameAction;
 use data::actions::game_action::GameAction;
+use data::card_states::card_kind::CardKind;
+use data::card_states::card_state::CardFacing;
 use data::card_states::zones::ZoneQueries;
 use data::core::numerics::LifeValue;
 use data::game_states::game_state::GameState;
 use data::player_states::player_state::PlayerQueries;
+use data::printed_cards::printed_card::Face;
 use data::prompts::pick_number_prompt::PickNumberPrompt;
 use data::text_strings::Text;
 use primitives::game_primitives::{CardType, PlayerName, Source, Zone};
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
 use tracing::{debug, instrument};
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
+use crate::core::initialize_card;
+use crate::dispatcher::dispatch;
 use crate::mutations::{move_card, players};
 use crate::prompt_handling::prompts;
 use crate::queries::card_queries;
+use crate::steps::step;
 
 #[instrument(level = "debug", skip(game))]
-pub fn execute(game: &mut GameState, player: PlayerName, action: DebugGameAction) {
+pub fn execute(game: &mut GameState, player: PlayerName, action: DebugGameAction) -> Outcome {
     match action {
         DebugGameAction::SetLifeTotal(target) => {
             let amount = prompts::pick_number(game, player, Text::SelectNumber, PickNumberPrompt {
@@ -38,28 +46,126 @@ pub fn execute(game: &mut GameState, player: PlayerName, action: DebugGameAction
                 maximum: 20,
             });
             debug!(?target, ?amount, "(Debug) Setting life total");
-            players::set_life_total(game, Source::Game, target, amount as LifeValue);
+            players::set_life_total(game, Source::Game, target, amount as LifeValue)?;
         }
         DebugGameAction::RevealHand(target) => {
             for card_id in game.hand(target).clone() {
                 outcome::execute(|| {
-                    let card = game.card_mut(card_id)?;
+                    let card = game.card_mut(card_id).or_skip()?;
                     card.revealed_to.insert(player);
                     outcome::OK
-                });
+                })?;
             }
         }
         DebugGameAction::DestroyAllLands(target) => {
             for permanent_id in game.battlefield(target).clone() {
                 outcome::execute(|| {
-                    if card_queries::card_types(game, Source::Game, permanent_id)?
+                    if card_queries::card_types(game, Source::Game, permanent_id)
+                        .or_skip()?
                         .contains(CardType::Land)
                     {
                         move_card::run(game, Source::Game, permanent_id, Zone::Graveyard)?;
                     }
                     outcome::OK
-                });
+                })?;
             }
         }
+        DebugGameAction::CreateCard(owner, printed_card_id, zone) => {
+            outcome::execute(|| {
+                let reference = game.oracle().card(printed_card_id);
+                let turn = game.turn;
+                let card_id = game.zones.create_card_in_zone(
+                    reference,
+                    Zone::Library,
+                    CardKind::Normal,
+                    owner,
+                    turn,
+                );
+                initialize_card::run(game, card_id)?;
+                if !matches!(zone, Zone::Library | Zone::Hand) {
+                    game.card_mut(card_id).or_skip()?.facing = CardFacing::FaceUp(Face::Primary);
+                }
+                debug!(?owner, ?printed_card_id, ?zone, "(Debug) Creating card");
+                move_card::run(game, Source::Game, card_id, zone)
+            })?;
+        }
+        DebugGameAction::SetLifeTotalTo(target, amount) => {
+            debug!(?target, ?amount, "(Debug) Setting life total");
+            players::set_life_total(game, Source::Game, target, amount)?;
+        }
+        DebugGameAction::AddPlusOneCounters(card_id, count) => {
+            outcome::execute(|| {
+                game.card_mut(card_id).or_skip()?.counters.p1p1 += count;
+                outcome::OK
+            })?;
+        }
+        DebugGameAction::AddMinusOneCounters(card_id, count) => {
+            outcome::execute(|| {
+                game.card_mut(card_id).or_skip()?.counters.m1m1 += count;
+                outcome::OK
+            })?;
+        }
+        DebugGameAction::ForceUpkeepTriggers(target) => {
+            dispatch::game_event(game, |e| &e.upkeep, Source::Game, target)?;
+        }
+        DebugGameAction::ForceEnterBattlefieldTriggers(card_id) => {
+            outcome::execute(|| {
+                let permanent_id = game.card(card_id).or_skip()?.permanent_id().or_skip()?;
+                dispatch::game_event(
+                    game,
+                    |e| &e.permanent_entered_battlefield,
+                    Source::Game,
+                    permanent_id,
+                )
+            })?;
+        }
+        DebugGameAction::ToggleRevealAllCards => {
+            let debug = &mut game.configuration.debug;
+            debug.reveal_all_cards = !debug.reveal_all_cards;
+        }
+        DebugGameAction::ToggleStepThroughResolution => {
+            let debug = &mut game.configuration.debug;
+            debug.step_through_resolution = !debug.step_through_resolution;
+        }
+        DebugGameAction::MoveToTopOfLibrary(card_id) => {
+            outcome::execute(|| {
+                if game.card(card_id).or_skip()?.zone != Zone::Library {
+                    move_card::run(game, Source::Game, card_id, Zone::Library)?;
+                }
+                let owner = game.card(card_id).or_skip()?.owner;
+                game.zones.move_to_top_of_library(owner, card_id);
+                outcome::OK
+            })?;
+        }
+        DebugGameAction::RigNextDraw(target, name) => {
+            let card_id = game
+                .library(target)
+                .iter()
+                .copied()
+                .find(|&id| game.card(id).is_some_and(|card| card.card_name == name));
+            match card_id {
+                Some(card_id) => game.zones.move_to_top_of_library(target, card_id),
+                None => debug!(?target, ?name, "(Debug) Card not found in library"),
+            }
+        }
+        DebugGameAction::SetRngSeed(seed) => {
+            // The initial seed is retained so that replaying this game's history
+            // reaches this action with the same generator state.
+            debug!(?seed, "(Debug) Setting random seed");
+            game.rng = Xoshiro256StarStar::seed_from_u64(seed);
+        }
+        DebugGameAction::SkipToStep(step) => {
+            if !step::can_advance_to(step) {
+                debug!(?step, "(Debug) Cannot skip to step");
+                return outcome::OK;
+            }
+            debug!(?step, "(Debug) Skipping to step");
+            step::advance_to(game, step)?;
+        }
+        DebugGameAction::SkipTurns(turns) => {
+            debug!(?turns, "(Debug) Skipping turns");
+            step::advance_turns(game, turns)?;
+        }
     }
+    outcome::OK
 }
@@ -15,7 +15,7 @@
 use data::actions::prompt_action::PromptAction;
 use data::prompts::prompt::{Prompt, PromptResponse, PromptType, SelectedOrder};
 use data::prompts::select_order_prompt::CardOrderLocation;
-use primitives::game_primitives::{CardId, PlayerName};
+use primitives::game_primitives::{CardId, PermanentId, PlayerName};
 use tracing::instrument;
 
 pub enum PromptExecutionResult {
@@ -45,6 +45,9 @@ pub fn execute(prompt: Prompt, action: PromptAction) -> PromptExecutionResult {
         PromptAction::SelectEntity(entity_id) => {
             PromptExecutionResult::PromptResponse(PromptResponse::EntityChoice(entity_id))
         }
+        PromptAction::SkipEntityChoice => {
+            PromptExecutionResult::PromptResponse(PromptResponse::SkipEntityChoice)
+        }
         PromptAction::SubmitCardSelection => {
             let PromptType::SelectOrder(prompt_data) = prompt.prompt_type else {
                 panic!("Expected SelectOrder prompt type");
@@ -56,9 +59,72 @@ pub fn execute(prompt: Prompt, action: PromptAction) -> PromptExecutionResult {
         PromptAction::SelectChoice(index) => {
             PromptExecutionResult::PromptResponse(PromptResponse::MultipleChoice(index))
         }
+        PromptAction::ToggleChoice(index) => toggle_choice(prompt, index),
+        PromptAction::SubmitMultiSelect => {
+            let PromptType::MultiSelect(prompt_data) = prompt.prompt_type else {
+                panic!("Expected MultiSelect prompt type");
+            };
+            PromptExecutionResult::PromptResponse(PromptResponse::MultiSelect(prompt_data.selected))
+        }
+        PromptAction::AssignAmount(index, amount) => {
+            let mut prompt = prompt;
+            let PromptType::Distribute(prompt_data) = &mut prompt.prompt_type else {
+                panic!("Expected Distribute prompt type");
+            };
+            prompt_data.assigned[index] = amount;
+            PromptExecutionResult::Prompt(prompt)
+        }
+        PromptAction::SubmitDistribution => {
+            let PromptType::Distribute(prompt_data) = prompt.prompt_type else {
+                panic!("Expected Distribute prompt type");
+            };
+            PromptExecutionResult::PromptResponse(PromptResponse::Distribute(prompt_data.assigned))
+        }
+        PromptAction::ToggleManaSource(permanent_id) => toggle_mana_source(prompt, permanent_id),
+        PromptAction::ResetManaPayment => {
+            let mut prompt = prompt;
+            let PromptType::PayMana(prompt_data) = &mut prompt.prompt_type else {
+                panic!("Expected PayMana prompt type");
+            };
+            prompt_data.selected = prompt_data.suggested.clone();
+            PromptExecutionResult::Prompt(prompt)
+        }
+        PromptAction::SubmitManaPayment => {
+            let PromptType::PayMana(prompt_data) = prompt.prompt_type else {
+                panic!("Expected PayMana prompt type");
+            };
+            PromptExecutionResult::PromptResponse(PromptResponse::PayMana(prompt_data.selected))
+        }
     }
 }
 
+fn toggle_choice(mut prompt: Prompt, index: usize) -> PromptExecutionResult {
+    let PromptType::MultiSelect(prompt_data) = &mut prompt.prompt_type else {
+        panic!("Expected MultiSelect prompt type");
+    };
+
+    if prompt_data.selected.contains(&index) {
+        prompt_data.selected.retain(|&i| i != index);
+    } else {
+        prompt_data.selected.push(index);
+        prompt_data.selected.sort();
+    }
+    PromptExecutionResult::Prompt(prompt)
+}
+
+fn toggle_mana_source(mut prompt: Prompt, permanent_id: PermanentId) -> PromptExecutionResult {
+    let PromptType::PayMana(prompt_data) = &mut prompt.prompt_type else {
+        panic!("Expected PayMana prompt type");
+    };
+
+    if prompt_data.selected.contains(&permanent_id) {
+        prompt_data.selected.retain(|&id| id != permanent_id);
+    } else {
+        prompt_data.selected.push(permanent_id);
+    }
+    PromptExecutionResult::Prompt(prompt)
+}
+
 fn select_order(
     mut prompt: Prompt,
     card_id: CardId,
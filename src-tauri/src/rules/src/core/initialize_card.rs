@@ -18,19 +18,38 @@ use data::core::ability_scope::AbilityScope;
 use data::game_states::game_state::GameState;
 use primitives::game_primitives::{AbilityId, CardId};
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 /// Runs initial setup code for a card's abilities immediately after it is
 /// created.
 pub fn run(game: &mut GameState, card_id: CardId) -> Outcome {
-    let name = game.card(card_id)?.card_name;
+    let name = game.card(card_id).or_skip()?.card_name;
     for (number, ability) in definitions::get(name).iterate_abilities() {
         let ability_id = AbilityId { card_id, number };
-        let ability_scope = AbilityScope { ability_id };
-        let card = game.card_mut(card_id)?;
+        let ability_scope = AbilityScope { ability_id, zones: ability.functional_zones() };
+        let card = game.card_mut(card_id).or_skip()?;
         ability.add_properties(ability_scope, card);
         ability.add_card_events(ability_scope, &mut card.events);
         ability.add_global_events(ability_scope, &mut game.events);
     }
     outcome::OK
 }
+
+/// Adds the abilities of the `component` card to the merged permanent
+/// represented by the `merged` card, e.g. when a mutating creature spell
+/// merges with it.
+///
+/// Global events for the component's abilities were already registered when
+/// it was created, and apply while it is on the battlefield as part of the
+/// merged permanent.
+pub fn add_merged_abilities(game: &mut GameState, merged: CardId, component: CardId) -> Outcome {
+    let name = game.card(component).or_skip()?.card_name;
+    for (number, ability) in definitions::get(name).iterate_abilities() {
+        let ability_id = AbilityId { card_id: component, number };
+        let ability_scope = AbilityScope { ability_id, zones: ability.functional_zones() };
+        let card = game.card_mut(merged).or_skip()?;
+        ability.add_properties(ability_scope, card);
+        ability.add_card_events(ability_scope, &mut card.events);
+    }
+    outcome::OK
+}
@@ -14,3 +14,4 @@
 
 pub mod debug_snapshot;
 pub mod initialize_card;
+pub mod zone_invariants;
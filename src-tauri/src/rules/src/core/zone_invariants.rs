@@ -0,0 +1,58 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::game_action::GameAction;
+#[allow(unused)] // Used in docs
+use data::game_states::game_state::DebugConfiguration;
+use data::game_states::game_state::{GameOperationMode, GameState};
+use primitives::game_primitives::PlayerName;
+use tracing::error;
+
+use crate::core::debug_snapshot;
+
+/// Checks the consistency of the zone indices of a game after `player` takes
+/// `action`, logging a description of each problem found along with a
+/// snapshot of the game.
+///
+/// Checks are only performed in debug builds when enabled via
+/// [DebugConfiguration::validate_zones], and are skipped while AI agents are
+/// searching.
+pub fn check(game: &GameState, player: PlayerName, action: GameAction) {
+    if !cfg!(debug_assertions)
+        || !game.configuration.debug.validate_zones
+        || matches!(game.operation_mode, GameOperationMode::AgentSearch(_))
+    {
+        return;
+    }
+
+    let errors = game.zones.check_invariants();
+    if errors.is_empty() {
+        return;
+    }
+
+    error!(
+        ?player,
+        ?action,
+        turn = ?game.turn,
+        step = ?game.step,
+        count = errors.len(),
+        "Zone invariants violated"
+    );
+    for problem in &errors {
+        error!("Zone invariant: {problem}");
+    }
+    for line in debug_snapshot::capture(game) {
+        error!("Snapshot: {line}");
+    }
+}
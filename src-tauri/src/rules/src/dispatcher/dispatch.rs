@@ -14,44 +14,51 @@
 
 use data::card_states::zones::{ToCardId, ZoneQueries};
 use data::core::rule_type;
-use data::events::card_events::CardEvents;
+use data::events::card_events::{CardEvents, ZoneChangedEvent};
 use data::events::event_context::EventContext;
 use data::events::game_event::{GameEvent, GameEventCallback};
 use data::events::game_events::GlobalEvents;
 use data::game_states::game_state::GameState;
 use primitives::game_primitives::{AbilityId, EventId, HasController, Source};
+use tracing::trace;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 pub fn add_card_event(
     game: &mut GameState,
     id: impl ToCardId,
     function: impl Fn(&mut CardEvents),
 ) -> Outcome {
-    function(&mut game.card_mut(id)?.events);
+    function(&mut game.card_mut(id).or_skip()?.events);
     outcome::OK
 }
 
+/// Invokes all listeners for a global game event.
+///
+/// Only listeners which were registered when the event began are invoked.
+/// Listeners added while the event is being dispatched, e.g. by a permanent
+/// with an "at the beginning of your upkeep" ability entering the battlefield
+/// during the upkeep step, wait for the next occurrence of the event.
 pub fn game_event<TArg: 'static>(
     game: &mut GameState,
     event: fn(&GlobalEvents) -> &GameEvent<TArg>,
     source: Source,
     arg: TArg,
-) {
-    for i in 0..event(&game.events).callbacks.len() {
+) -> Outcome {
+    let callbacks = event(&game.events).callbacks.clone();
+    for callback in &callbacks {
         outcome::execute(|| {
-            let callback = &event(&game.events).callbacks[i];
-            let mut context = build_callback_context(callback, game, source)?;
+            let mut context = build_callback_context(callback, game, source).or_skip()?;
 
             // Do this after checking validity to avoid generating IDs when the function
             // isn't going to run
             context.event_id = EventId(game.zones.new_timestamp().0);
 
-            let function = event(&game.events).callbacks[i].function.clone();
-            function.invoke(game, context, &arg);
-            outcome::OK
-        });
+            trace_listener(callback);
+            callback.function.invoke(game, context, &arg)
+        })?;
     }
+    outcome::OK
 }
 
 pub fn card_event<TArg: 'static>(
@@ -61,19 +68,77 @@ pub fn card_event<TArg: 'static>(
     source: Source,
     arg: &TArg,
 ) -> Outcome {
-    for i in 0..event(&game.card(id)?.events).callbacks.len() {
+    for i in 0..event(&game.card(id).or_skip()?.events).callbacks.len() {
+        outcome::execute(|| {
+            let callback = &event(&game.card(id).or_skip()?.events).callbacks[i];
+            let mut context = build_callback_context(callback, game, source).or_skip()?;
+
+            // Do this after checking validity to avoid generating IDs when the function
+            // isn't going to run
+            context.event_id = EventId(game.zones.new_timestamp().0);
+
+            let callback = &event(&game.card(id).or_skip()?.events).callbacks[i];
+            trace_listener(callback);
+            let function = callback.function.clone();
+            function.invoke(game, context, arg)
+        })?;
+    }
+    outcome::OK
+}
+
+/// Invokes all listeners for a card moving from one zone to another.
+///
+/// Listeners on the moved card's [CardEvents::zone_changed] and on
+/// [GlobalEvents::zone_changed] are treated as a single event bus and fired in
+/// [ListenerPriority] order: all replacement listeners, then all trigger
+/// listeners, then all bookkeeping listeners. Within a band, the moved card's
+/// own listeners fire before global listeners, and each set of listeners fires
+/// in the order it was added.
+///
+/// [ListenerPriority]: data::events::game_event::ListenerPriority
+pub fn zone_change(game: &mut GameState, source: Source, event: ZoneChangedEvent) -> Outcome {
+    let card_id = event.card_id;
+    let mut listeners = game
+        .card(card_id)
+        .or_skip()?
+        .events
+        .zone_changed
+        .callbacks
+        .iter()
+        .enumerate()
+        .map(|(i, callback)| (callback.priority, ZoneChangeListener::Card(i)))
+        .chain(
+            game.events
+                .zone_changed
+                .callbacks
+                .iter()
+                .enumerate()
+                .map(|(i, callback)| (callback.priority, ZoneChangeListener::Global(i))),
+        )
+        .collect::<Vec<_>>();
+    // Sorting is stable, so this preserves the order within each band.
+    listeners.sort_by_key(|(priority, _)| *priority);
+
+    for (_, listener) in listeners {
         outcome::execute(|| {
-            let callback = &event(&game.card(id)?.events).callbacks[i];
-            let mut context = build_callback_context(callback, game, source)?;
+            let callback = match listener {
+                ZoneChangeListener::Card(i) => {
+                    game.card(card_id).or_skip()?.events.zone_changed.callbacks.get(i).or_skip()?
+                }
+                ZoneChangeListener::Global(i) => {
+                    game.events.zone_changed.callbacks.get(i).or_skip()?
+                }
+            };
+            let mut context = build_callback_context(callback, game, source).or_skip()?;
+            trace_listener(callback);
+            let function = callback.function.clone();
 
             // Do this after checking validity to avoid generating IDs when the function
             // isn't going to run
             context.event_id = EventId(game.zones.new_timestamp().0);
 
-            let function = event(&game.card(id)?.events).callbacks[i].function.clone();
-            function.invoke(game, context, arg);
-            outcome::OK
-        });
+            function.invoke(game, context, &event)
+        })?;
     }
     outcome::OK
 }
@@ -97,6 +162,25 @@ pub fn build_invocation_context(
     })
 }
 
+/// Identifies a listener for [zone_change] by its index in either the moved
+/// card's events or the global events.
+#[derive(Clone, Copy)]
+enum ZoneChangeListener {
+    Card(usize),
+    Global(usize),
+}
+
+/// Logs the invocation of an event callback, to aid in debugging the order in
+/// which card interactions happen.
+fn trace_listener<TArg>(callback: &GameEventCallback<TArg>) {
+    trace!(
+        event = std::any::type_name::<TArg>(),
+        ability_id = ?callback.ability_id,
+        priority = ?callback.priority,
+        "Invoking event listener"
+    );
+}
+
 /// Builds the [EventContext] for invoking an event callback function.
 fn build_callback_context<TArg>(
     callback: &GameEventCallback<TArg>,
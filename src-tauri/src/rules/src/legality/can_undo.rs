@@ -14,13 +14,34 @@
 
 use data::game_states::game_state::GameState;
 use data::game_states::history_data::TakenGameAction;
+use data::player_states::player_state::PlayerQueries;
 use data::player_states::player_map::PlayerMap;
 
-/// Returns true if the given game state has any 'undo'-able actions.
+/// Returns true if the given game state has any 'undo'-able actions and the
+/// rules of this game allow takebacks.
 pub fn can_undo(game: &GameState) -> bool {
-    let c = undoable_action_count(&game.history.player_actions);
-    eprintln!("Count: {c}");
-    c > 0
+    takebacks_allowed(game) && undoable_action_count(&game.history.player_actions) > 0
+}
+
+/// Returns true if players may undo their actions in this game.
+///
+/// Takebacks are always available when every human player is either the same
+/// user or being controlled via a debug option, and are otherwise only
+/// available if the game's house rules allow them.
+pub fn takebacks_allowed(game: &GameState) -> bool {
+    if game.configuration.rules.always_allow_takebacks
+        || game.configuration.debug.act_as_player.is_some()
+    {
+        return true;
+    }
+
+    let mut humans = game
+        .configuration
+        .all_players
+        .iter()
+        .filter_map(|player| game.player(player).player_type.user_id());
+    let first = humans.next();
+    humans.all(|user_id| Some(user_id) == first)
 }
 
 /// Returns the number of 'undo'-able actions in the given player map.
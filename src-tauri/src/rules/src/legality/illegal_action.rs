@@ -0,0 +1,125 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::actions::game_action::{CombatAction, GameAction};
+use data::card_states::zones::ZoneQueries;
+use data::game_states::combat_state::CombatState;
+use data::game_states::game_state::GameState;
+use data::text_strings::Text;
+use primitives::game_primitives::{CardType, HasController, PlayerName, Source};
+
+use crate::legality::legal_actions;
+use crate::play_cards::play_card;
+use crate::queries::{card_queries, combat_queries, player_queries};
+
+/// The specific rules predicate which prevents a player from taking a
+/// [GameAction].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum IllegalActionReason {
+    /// Another player is currently allowed to act.
+    NotYourPriority,
+
+    /// The card is not in a zone it can be played from.
+    NotInPlayableZone,
+
+    /// The card can only be played during its controller's main phase while
+    /// the stack is empty.
+    RequiresSorceryTiming,
+
+    /// The player has already played their land for this turn.
+    NoLandPlaysRemaining,
+
+    /// The card requires targets and no legal targets are available.
+    NoValidTargets,
+
+    /// The player cannot pay the mana cost of the card.
+    InsufficientMana,
+
+    /// The creature has not been continuously controlled since the start of
+    /// its controller's most recent turn.
+    SummoningSickness,
+
+    /// The permanent is tapped.
+    Tapped,
+
+    /// The permanent is not a creature.
+    NotACreature,
+
+    /// Attackers are not currently being declared.
+    NotDeclaringAttackers,
+
+    /// An effect prevents this action.
+    RestrictedByEffect,
+}
+
+impl From<IllegalActionReason> for Text {
+    fn from(value: IllegalActionReason) -> Self {
+        match value {
+            IllegalActionReason::NotYourPriority => Text::NotYourPriority,
+            IllegalActionReason::NotInPlayableZone => Text::NotInPlayableZone,
+            IllegalActionReason::RequiresSorceryTiming => Text::RequiresSorceryTiming,
+            IllegalActionReason::NoLandPlaysRemaining => Text::NoLandPlaysRemaining,
+            IllegalActionReason::NoValidTargets => Text::NoValidTargets,
+            IllegalActionReason::InsufficientMana => Text::InsufficientMana,
+            IllegalActionReason::SummoningSickness => Text::SummoningSickness,
+            IllegalActionReason::Tapped => Text::IsTapped,
+            IllegalActionReason::NotACreature => Text::NotACreature,
+            IllegalActionReason::NotDeclaringAttackers => Text::NotDeclaringAttackers,
+            IllegalActionReason::RestrictedByEffect => Text::RestrictedByEffect,
+        }
+    }
+}
+
+/// Explains why the [PlayerName] player cannot currently take the provided
+/// [GameAction].
+///
+/// Returns None if this action is legal.
+pub fn explain(
+    game: &GameState,
+    player: PlayerName,
+    action: &GameAction,
+) -> Option<IllegalActionReason> {
+    if legal_actions::can_take_action(game, player, action) {
+        return None;
+    }
+
+    if legal_actions::next_to_act(game, None) != Some(player) {
+        return Some(IllegalActionReason::NotYourPriority);
+    }
+
+    Some(match action {
+        GameAction::ProposePlayingCard(card_id) => {
+            play_card::cannot_play_reason(game, player, Source::Game, *card_id)?
+        }
+        GameAction::CombatAction(CombatAction::AddSelectedAttacker(attacker_id)) => {
+            if !matches!(game.combat, Some(CombatState::ProposingAttackers(_))) {
+                return Some(IllegalActionReason::NotDeclaringAttackers);
+            }
+            let card = game.card(*attacker_id)?;
+            let types = card_queries::card_types(game, Source::Game, card.id)?;
+            if !types.contains(CardType::Creature) || types.contains(CardType::Battle) {
+                IllegalActionReason::NotACreature
+            } else if !player_queries::active_team(game).contains(card.controller()) {
+                IllegalActionReason::NotYourPriority
+            } else if card.tapped_state.is_tapped() {
+                IllegalActionReason::Tapped
+            } else if combat_queries::has_summoning_sickness(game, Source::Game, *attacker_id)? {
+                IllegalActionReason::SummoningSickness
+            } else {
+                IllegalActionReason::RestrictedByEffect
+            }
+        }
+        _ => IllegalActionReason::RestrictedByEffect,
+    })
+}
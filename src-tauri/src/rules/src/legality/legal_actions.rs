@@ -19,9 +19,12 @@ use data::game_states::game_state::{GameState, GameStatus};
 use data::prompts::prompt::Prompt;
 use primitives::game_primitives::{PlayerName, Source};
 use tracing::instrument;
+use utils::scratch::ScratchVec;
 
 use crate::legality::legal_combat_actions;
+use crate::legality::priority_window::PriorityWindow;
 use crate::play_cards::play_card;
+use crate::queries::{card_queries, planechase_queries, player_queries};
 
 #[derive(Debug, Clone, Copy)]
 pub struct LegalActions {
@@ -38,34 +41,85 @@ pub struct LegalActions {
 #[instrument(name = "legal_actions_compute", level = "trace", skip(game, options))]
 pub fn compute(game: &GameState, player: PlayerName, options: LegalActions) -> Vec<GameAction> {
     let mut result = vec![];
+    append(game, player, &mut result, options);
+    result
+}
+
+/// Returns the number of legal actions the named player can take in the
+/// current game state.
+pub fn count(game: &GameState, player: PlayerName, options: LegalActions) -> usize {
+    let mut result = ScratchVec::new();
+    append(game, player, &mut result, options);
+    result.len()
+}
 
+/// Appends all legal actions the named player can take in the current game
+/// state to `result`.
+///
+/// Prefer this over [compute] with a [ScratchVec] buffer when the list of
+/// actions does not need to outlive the current computation.
+pub fn append(
+    game: &GameState,
+    player: PlayerName,
+    result: &mut Vec<GameAction>,
+    options: LegalActions,
+) {
     if next_to_act(game, None) != Some(player) {
-        return result;
+        return;
     }
 
     if !legal_combat_actions::in_combat_prompt(game, player) {
+        let window = PriorityWindow::default();
         if can_pass_priority(game, player) {
             result.push(GameAction::PassPriority);
         }
 
         for &card_id in game.hand(player) {
-            if play_card::can_play_card(game, player, Source::Game, card_id) {
+            if play_card::can_play_card_in_window(game, &window, player, Source::Game, card_id) {
                 result.push(GameAction::ProposePlayingCard(card_id));
             }
+            if card_queries::can_foretell(game, player, card_id) {
+                result.push(GameAction::Foretell(card_id));
+            }
+        }
+
+        for owner in player_queries::all_players(game) {
+            for &card_id in game.exile(owner) {
+                if play_card::can_play_card_in_window(game, &window, player, Source::Game, card_id)
+                {
+                    result.push(GameAction::ProposePlayingCard(card_id));
+                }
+            }
+        }
+
+        if planechase_queries::can_roll_planar_die(game, player) {
+            result.push(GameAction::RollPlanarDie);
         }
     }
 
-    legal_combat_actions::append(game, player, &mut result, options);
-    result
+    legal_combat_actions::append(game, player, result, options);
 }
 
 /// Returns true if the [PlayerName] player can currently legally take the
 /// provided [GameAction].
 #[instrument(level = "trace", skip(game, game_action))]
 pub fn can_take_action(game: &GameState, player: PlayerName, game_action: &GameAction) -> bool {
-    compute(game, player, LegalActions { for_human_player: true })
-        .iter()
-        .any(|action| action == game_action)
+    if *game_action == GameAction::HoldPriority {
+        // Holding priority is an interface option which is never offered to AI agents
+        // and so is not included in the list of legal actions.
+        return next_to_act(game, None) == Some(player) && can_pass_priority(game, player);
+    }
+
+    if *game_action == GameAction::Concede {
+        // Conceding is always possible for players still in the game, but is
+        // never a useful choice for AI search and so is not a legal action.
+        return next_to_act(game, None).is_some()
+            && player_queries::all_players(game).contains(player);
+    }
+
+    let mut legal = ScratchVec::new();
+    append(game, player, &mut legal, LegalActions { for_human_player: true });
+    legal.contains(game_action)
 }
 
 /// Returns the name of the player who is currently allowed to take an action.
@@ -50,10 +50,8 @@ pub fn append(
 ) {
     match &game.combat {
         None => {}
-        Some(CombatState::ProposingAttackers(ProposedAttackers {
-            proposed_attacks,
-            selected_attackers,
-        })) => {
+        Some(CombatState::ProposingAttackers(attackers)) => {
+            let ProposedAttackers { proposed_attacks, selected_attackers } = attackers;
             extend_actions(
                 actions,
                 combat_queries::legal_attackers(game, Source::Game, player)
@@ -75,7 +73,20 @@ pub fn append(
                                     target: *target,
                                 })
                                 .all(|can_attack| {
-                                    can_attack_target(game, can_attack).unwrap_or(false)
+                                    combat_queries::can_attack_target(
+                                        game,
+                                        Source::Game,
+                                        can_attack,
+                                    )
+                                    .unwrap_or(false)
+                                })
+                                && selected_attackers.iter().all(|&attacker| {
+                                    combat_queries::satisfies_goad(
+                                        game,
+                                        Source::Game,
+                                        attacker,
+                                        *target,
+                                    )
                                 })
                         })
                         .map(CombatAction::SetSelectedAttackersTarget),
@@ -91,7 +102,9 @@ pub fn append(
                         .map(CombatAction::RemoveAttacker),
                 );
             }
-            actions.push(CombatAction::ConfirmAttackers.into());
+            if combat_queries::attack_requirements_met(game, Source::Game, player, attackers) {
+                actions.push(CombatAction::ConfirmAttackers.into());
+            }
         }
         Some(CombatState::ConfirmedAttackers(attackers)) => {}
         Some(CombatState::ProposingBlockers(blockers)) => {
@@ -127,19 +140,20 @@ pub fn append(
             // TODO: Add ordering actions
             actions.push(CombatAction::ConfirmBlockerOrder.into());
         }
-        Some(CombatState::ConfirmedBlockers(blockers)) => {}
+        Some(CombatState::ConfirmedBlockers(_)) if game.priority == player => {
+            extend_actions(
+                actions,
+                game.hand(player)
+                    .iter()
+                    .copied()
+                    .filter(|&card_id| combat_queries::can_activate_ninjutsu(game, player, card_id))
+                    .map(CombatAction::Ninjutsu),
+            );
+        }
+        Some(CombatState::ConfirmedBlockers(_)) => {}
     }
 }
 
-fn can_attack_target(game: &GameState, can_attack_target: CanAttackTarget) -> Option<bool> {
-    game.card(can_attack_target.attacker_id)?.properties.can_attack_target.query_with(
-        game,
-        Source::Game,
-        &can_attack_target,
-        true,
-    )
-}
-
 fn extend_actions(
     actions: &mut Vec<GameAction>,
     combat_action: impl Iterator<Item = CombatAction>,
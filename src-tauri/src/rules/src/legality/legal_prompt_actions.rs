@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use data::actions::prompt_action::PromptAction;
+use data::prompts::distribute_prompt::DistributePrompt;
+use data::prompts::multi_select_prompt::MultiSelectPrompt;
+use data::prompts::pay_mana_prompt::PayManaPrompt;
 use data::prompts::pick_number_prompt::PickNumberPrompt;
 use data::prompts::prompt::{Prompt, PromptType};
 use data::prompts::select_order_prompt::{CardOrderLocation, Quantity, SelectOrderPrompt};
@@ -34,7 +37,15 @@ pub fn compute(prompt: &Prompt, player: PlayerName, options: LegalActions) -> Ve
         }
         PromptType::SelectOrder(select_order) => select_order_prompt_actions(select_order, options),
         PromptType::EntityChoice(data) => {
-            data.choices.iter().map(|choice| PromptAction::SelectEntity(choice.entity_id)).collect()
+            let mut result = data
+                .choices
+                .iter()
+                .map(|choice| PromptAction::SelectEntity(choice.entity_id))
+                .collect::<Vec<_>>();
+            if data.optional {
+                result.push(PromptAction::SkipEntityChoice);
+            }
+            result
         }
         PromptType::PlayCards(_) => todo!("Implement this"),
         PromptType::MultipleChoice(data) => data
@@ -43,6 +54,9 @@ pub fn compute(prompt: &Prompt, player: PlayerName, options: LegalActions) -> Ve
             .enumerate()
             .map(|(i, choice)| PromptAction::SelectChoice(i))
             .collect(),
+        PromptType::MultiSelect(multi_select) => multi_select_prompt_actions(multi_select, options),
+        PromptType::Distribute(distribute) => distribute_prompt_actions(distribute, options),
+        PromptType::PayMana(pay_mana) => pay_mana_prompt_actions(pay_mana, options),
     }
 }
 
@@ -55,6 +69,79 @@ pub fn can_take_action(prompt: &Prompt, player: PlayerName, prompt_action: Promp
         .any(|&action| action == prompt_action)
 }
 
+fn multi_select_prompt_actions(
+    prompt: &MultiSelectPrompt,
+    options: LegalActions,
+) -> Vec<PromptAction> {
+    let mut result = vec![];
+    if prompt.is_valid_selection() {
+        result.push(PromptAction::SubmitMultiSelect);
+    }
+
+    for index in 0..prompt.choices.len() {
+        let selected = prompt.selected.contains(&index);
+        // AI agents only ever add choices to the selection, there is no reason to
+        // search over deselecting them.
+        if (selected && options.for_human_player)
+            || (!selected && prompt.selected.len() < prompt.maximum)
+        {
+            result.push(PromptAction::ToggleChoice(index));
+        }
+    }
+    result
+}
+
+fn distribute_prompt_actions(
+    prompt: &DistributePrompt,
+    options: LegalActions,
+) -> Vec<PromptAction> {
+    let mut result = vec![];
+    if prompt.is_valid_distribution() {
+        result.push(PromptAction::SubmitDistribution);
+    }
+
+    for (index, &current) in prompt.assigned.iter().enumerate() {
+        let maximum = prompt.maximum_for(index);
+        if options.for_human_player {
+            result.extend(
+                (prompt.minimum_per_target..=maximum)
+                    .filter(|&amount| amount != current)
+                    .map(|amount| PromptAction::AssignAmount(index, amount)),
+            );
+        } else if current < maximum {
+            // AI agents assign one at a time, there is no reason to search over
+            // every possible amount for each target.
+            result.push(PromptAction::AssignAmount(index, current + 1));
+        }
+    }
+    result
+}
+
+fn pay_mana_prompt_actions(prompt: &PayManaPrompt, options: LegalActions) -> Vec<PromptAction> {
+    let mut result = vec![];
+    if prompt.is_valid_payment() {
+        result.push(PromptAction::SubmitManaPayment);
+    }
+
+    if options.for_human_player {
+        // AI agents always accept the suggested payment, there is no reason to search
+        // over possible ways of paying costs.
+        if prompt.is_manual() {
+            result.push(PromptAction::ResetManaPayment);
+        }
+        for &permanent_id in prompt.sources.keys() {
+            if prompt.selected.contains(&permanent_id) || prompt.selected.len() < prompt.cost.len()
+            {
+                result.push(PromptAction::ToggleManaSource(permanent_id));
+            }
+        }
+    } else if result.is_empty() {
+        result.push(PromptAction::ResetManaPayment);
+    }
+
+    result
+}
+
 fn select_order_prompt_actions(
     prompt: &SelectOrderPrompt,
     options: LegalActions,
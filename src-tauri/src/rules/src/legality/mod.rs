@@ -13,6 +13,8 @@
 // limitations under the License.
 
 pub mod can_undo;
+pub mod illegal_action;
 pub mod legal_actions;
 pub mod legal_combat_actions;
 pub mod legal_prompt_actions;
+pub mod priority_window;
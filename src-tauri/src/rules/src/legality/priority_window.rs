@@ -0,0 +1,92 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use data::game_states::game_state::GameState;
+use once_cell::unsync::OnceCell;
+use primitives::game_primitives::PlayerName;
+
+use crate::planner::spell_planner;
+use crate::planner::spell_planner::ManaSources;
+use crate::play_cards::pick_face_to_play;
+use crate::queries::player_queries;
+
+/// Caches legality primitives which are shared by every card checked while
+/// computing the legal actions for a single priority window.
+///
+/// Whether a card can be played depends on timing rules and on the mana
+/// sources its controller has available, neither of which changes between
+/// checks against the same [GameState]. Computing them once per window avoids
+/// repeating the same queries for every card in hand and for every candidate
+/// target of each card.
+///
+/// A window must not be reused after the game state it was created for is
+/// mutated.
+#[derive(Default)]
+pub struct PriorityWindow {
+    players: RefCell<BTreeMap<PlayerName, Rc<PlayerPrimitives>>>,
+}
+
+impl PriorityWindow {
+    /// Returns the legality primitives for the [PlayerName] player, computing
+    /// them on first use.
+    pub fn player(&self, game: &GameState, player: PlayerName) -> Rc<PlayerPrimitives> {
+        self.players
+            .borrow_mut()
+            .entry(player)
+            .or_insert_with(|| Rc::new(PlayerPrimitives::new(game, player)))
+            .clone()
+    }
+}
+
+/// Legality primitives for a single player within a [PriorityWindow].
+pub struct PlayerPrimitives {
+    player: PlayerName,
+
+    /// True if this player currently has priority.
+    pub has_priority: bool,
+
+    /// True if this player could currently take an action with sorcery
+    /// timing, i.e. they are on the active team in their main phase with the
+    /// stack empty while they have priority.
+    pub sorcery_timing: bool,
+
+    /// True if this player can play another land this turn.
+    pub has_land_plays: bool,
+
+    mana_sources: OnceCell<ManaSources>,
+}
+
+impl PlayerPrimitives {
+    fn new(game: &GameState, player: PlayerName) -> Self {
+        Self {
+            player,
+            has_priority: game.priority == player,
+            sorcery_timing: pick_face_to_play::in_main_phase_with_stack_empty(game, player),
+            has_land_plays: player_queries::land_plays_remaining(game, player) > 0,
+            mana_sources: OnceCell::new(),
+        }
+    }
+
+    /// Returns the permanents this player can currently tap for mana.
+    ///
+    /// These are computed on first use, since many cards fail their timing
+    /// checks before mana payment is considered.
+    pub fn mana_sources(&self, game: &GameState) -> &ManaSources {
+        self.mana_sources.get_or_init(|| spell_planner::mana_sources(game, self.player))
+    }
+}
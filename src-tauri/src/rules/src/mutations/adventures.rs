@@ -0,0 +1,47 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::custom_card_state::CustomCardState;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{CardId, HasController, HasSource, Zone};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::mutations::move_card;
+
+/// Exiles the [CardId] spell "on an adventure" as it finishes resolving,
+/// allowing its controller to cast it from exile for as long as it remains
+/// exiled.
+///
+/// > 715.3d. Instead of putting a spell that was cast as an Adventure into its
+/// > owner's graveyard as it resolves, its controller exiles it. For as long as
+/// > that card remains exiled, that player may cast it. It can't be cast as an
+/// > Adventure this way.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7153d>
+pub fn exile_on_adventure(
+    game: &mut GameState,
+    source: impl HasSource,
+    card_id: CardId,
+) -> Outcome {
+    let player = game.card(card_id).or_skip()?.controller();
+    move_card::run(game, source, card_id, Zone::Exiled)?;
+    let exiled_card_id = game.card(card_id).or_skip()?.exiled_card_id().or_skip()?;
+    game.card_mut(card_id)
+        .or_skip()?
+        .custom_state
+        .push(CustomCardState::OnAdventure { exiled_card_id, player });
+    outcome::OK
+}
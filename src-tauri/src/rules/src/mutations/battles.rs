@@ -0,0 +1,181 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::counters::CounterType;
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::numerics::Damage;
+use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
+use data::game_states::state_based_event::StateBasedEvent;
+use data::printed_cards::card_subtypes::BattleSubtype;
+use data::printed_cards::printed_card::Face;
+use data::prompts::entity_choice_prompt::Choice;
+use data::prompts::game_update::GameAnimation;
+use data::text_strings::Text;
+use primitives::game_primitives::{
+    CardType, EntityId, HasController, HasSource, PermanentId, PlayerName, Source, Zone,
+};
+use utils::outcome;
+use utils::outcome::{HaltCondition, OrSkip, Outcome};
+
+use crate::mutations::{damage, move_card, players};
+use crate::play_cards::play_card;
+use crate::prompt_handling::prompts;
+use crate::queries::{card_queries, player_queries};
+
+/// Puts defense counters on a battle as it enters the battlefield and has its
+/// controller choose a protector for it.
+///
+/// > 310.4b. A battle has the intrinsic ability "This permanent enters with a
+/// > number of defense counters on it equal to its printed defense number."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R3104b>
+///
+/// > 310.11a. As a Siege enters the battlefield, its controller must choose an
+/// > opponent to be its protector.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R31011a>
+pub fn on_enter_battlefield(game: &mut GameState, id: impl ToCardId) -> Outcome {
+    let card = game.card(id).or_skip()?;
+    let face = card_queries::cast_as_faces(card).iter().next().unwrap_or(Face::Primary);
+    let printed = card.printed().face(face);
+    if !printed.card_types.contains(CardType::Battle) {
+        return outcome::OK;
+    }
+
+    let card_id = card.id;
+    let controller = card.controller();
+    let defense = printed.defense.unwrap_or_default();
+    game.card_mut(card_id).or_skip()?.counters.other_counters.insert(CounterType::Defense, defense);
+    let protector = choose_protector(game, controller).or_skip()?;
+    game.card_mut(card_id).or_skip()?.protector = Some(protector);
+    outcome::OK
+}
+
+/// Causes the `damage_source` card to deal damage to the [PermanentId] battle,
+/// removing that many defense counters from it.
+///
+/// This does not apply prevention or redirection effects, use
+/// [damage::deal_damage] instead for damage dealt by game effects.
+///
+/// > 310.6. Damage dealt to a battle results in that many defense counters
+/// > being removed from it.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R3106>
+pub fn deal_damage(
+    game: &mut GameState,
+    source: impl HasSource,
+    damage_source: impl ToCardId,
+    id: PermanentId,
+    damage: Damage,
+) -> Outcome {
+    game.add_history_event(HistoryEvent::DamageToPermanent(id, damage));
+    game.add_animation(GameAnimation::DamagePermanent(id, damage));
+    remove_defense_counters(game, source.source(), id, damage as u32)?;
+    players::apply_lifelink(game, source.source(), damage_source, damage)
+}
+
+/// Removes up to `count` defense counters from the [PermanentId] battle.
+pub fn remove_defense_counters(
+    game: &mut GameState,
+    _source: impl HasSource,
+    id: PermanentId,
+    count: u32,
+) -> Outcome {
+    let counters = &mut game.card_mut(id).or_skip()?.counters.other_counters;
+    let defense = counters.get(&CounterType::Defense).copied().unwrap_or(0);
+    counters.insert(CounterType::Defense, defense.saturating_sub(count));
+    game.add_state_based_event(StateBasedEvent::BattleLostDefense(id));
+    outcome::OK
+}
+
+/// Removes the [PermanentId] battle from the battlefield if it has no defense
+/// counters on it.
+///
+/// Returns true if the battle was removed.
+///
+/// > 704.5v. If a battle has defense 0 and it isn't the source of an ability
+/// > that has triggered but not yet left the stack, it's put into its owner's
+/// > graveyard.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7045v>
+///
+/// Sieges are instead exiled and may be cast transformed by their controller.
+/// This currently happens immediately instead of via a triggered ability:
+///
+/// > 310.11b. Each Siege has the intrinsic ability "When the last defense
+/// > counter is removed from this permanent, exile it, then you may cast it
+/// > transformed without paying its mana cost."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R31011b>
+pub fn defeat_if_no_defense(game: &mut GameState, id: PermanentId) -> Result<bool, HaltCondition> {
+    let card = game.card(id).or_skip()?;
+    let card_id = card.id;
+    let controller = card.controller();
+    if !card_queries::card_types(game, Source::Game, id).or_skip()?.contains(CardType::Battle)
+        || card_queries::defense(game, id).or_skip()? > 0
+    {
+        return Ok(false);
+    }
+    let ability_on_stack =
+        game.zones.all_stack_abilities().any(|ability| ability.ability_id.card_id == card_id);
+    if ability_on_stack {
+        return Ok(false);
+    }
+
+    if card_queries::battle_subtypes(game, Source::Game, id)
+        .or_skip()?
+        .contains(BattleSubtype::Siege)
+    {
+        move_card::run(game, Source::Game, card_id, Zone::Exiled)?;
+        if play_card::can_cast_transformed_without_paying_mana_cost(
+            game,
+            controller,
+            Source::Game,
+            card_id,
+        ) && prompts::multiple_choice(game, controller, Text::CastWithoutPayingPrompt, vec![
+            Text::CastSpell,
+            Text::DoNotCast,
+        ]) == Text::CastSpell
+        {
+            outcome::execute(|| {
+                play_card::cast_transformed_without_paying_mana_cost(
+                    game,
+                    controller,
+                    Source::Game,
+                    card_id,
+                )
+            })?;
+        }
+    } else {
+        move_card::run(game, Source::Game, card_id, Zone::Graveyard)?;
+    }
+    Ok(true)
+}
+
+/// Prompts the [PlayerName] player to choose one of their opponents to protect
+/// a battle they control.
+fn choose_protector(game: &mut GameState, controller: PlayerName) -> Option<PlayerName> {
+    let opponents = player_queries::all_opponents(game, controller);
+    if opponents.len() <= 1 {
+        return opponents.iter().next();
+    }
+
+    let choices =
+        opponents.iter().map(|player| Choice { entity_id: EntityId::Player(player) }).collect();
+    match prompts::choose_entity(game, controller, Text::SelectProtector, choices) {
+        EntityId::Player(player) => Some(player),
+        _ => None,
+    }
+}
@@ -16,12 +16,15 @@ use data::card_states::card_state::ControlChangingEffect;
 use data::card_states::zones::{ToCardId, ZoneQueries};
 use data::events::card_events;
 use data::events::card_events::PermanentControllerChangedEvent;
+use data::game_states::ability_state::{ExpiringEffect, ExpiringEffectKind};
 use data::game_states::game_state::GameState;
+use data::game_states::state_based_event::StateBasedEvent;
+use data::properties::duration::Duration;
 use primitives::game_primitives::{
     AbilityId, CardId, EventId, HasController, HasSource, PlayerName, Source,
 };
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::dispatcher::dispatch;
 
@@ -38,13 +41,14 @@ pub fn gain_control(
     event_id: EventId,
     card_id: impl ToCardId,
 ) -> Outcome {
-    let card_id = card_id.to_card_id(game)?;
-    let current = game.card(card_id)?.controller();
+    let card_id = card_id.to_card_id(game).or_skip()?;
+    let current = game.card(card_id).or_skip()?.controller();
 
     if current != new_controller {
-        game.zones.on_controller_changed(card_id, current, new_controller, game.turn);
+        game.zones.on_controller_changed(card_id, current, new_controller, game.turn)?;
+        game.add_state_based_event(StateBasedEvent::ControllerChanged(card_id));
         let turn = game.turn;
-        let card = game.card_mut(card_id)?;
+        let card = game.card_mut(card_id).or_skip()?;
         let permanent_id = card.permanent_id();
         card.last_changed_control = turn;
         card.control_changing_effects
@@ -61,7 +65,7 @@ pub fn gain_control(
                     old_controller: current,
                     new_controller,
                 },
-            );
+            )?;
         }
     }
     outcome::OK
@@ -77,22 +81,43 @@ pub fn gain_control_this_turn(
     event_id: EventId,
     id: impl ToCardId,
 ) -> Outcome {
-    let card_id = id.to_card_id(game)?;
-    game.ability_state.add_control_changing_effect(event_id, card_id);
+    let duration = Duration::ThisTurn(game.turn);
+    gain_control_for_duration(game, source, new_controller, event_id, id, duration)
+}
+
+/// Gains control of the [CardId] card as described in [gain_control] for a
+/// given [Duration], e.g. "for as long as you control this permanent". This
+/// effect is automatically ended once the duration ends.
+pub fn gain_control_for_duration(
+    game: &mut GameState,
+    source: impl HasSource,
+    new_controller: PlayerName,
+    event_id: EventId,
+    id: impl ToCardId,
+    duration: Duration,
+) -> Outcome {
+    let card_id = id.to_card_id(game).or_skip()?;
+    game.ability_state.add_expiring_effect(ExpiringEffect {
+        event_id,
+        card_id,
+        duration,
+        kind: ExpiringEffectKind::ChangeControl,
+    });
     gain_control(game, source.source(), new_controller, event_id, card_id)
 }
 
 /// Removes all control-changing effects from the [CardId] card that were added
 /// by the given [EventId].
 pub fn remove_control(game: &mut GameState, event_id: EventId, card_id: CardId) -> Outcome {
-    let card = game.card_mut(card_id)?;
+    let card = game.card_mut(card_id).or_skip()?;
     let current = card.controller();
     card.control_changing_effects.retain(|effect| effect.event_id != event_id);
     let new = card.controller();
     if current != new {
-        game.zones.on_controller_changed(card_id, current, new, game.turn);
+        game.zones.on_controller_changed(card_id, current, new, game.turn)?;
+        game.add_state_based_event(StateBasedEvent::ControllerChanged(card_id));
         let turn = game.turn;
-        let card = game.card_mut(card_id)?;
+        let card = game.card_mut(card_id).or_skip()?;
         card.last_changed_control = turn;
         let permanent_id = card.permanent_id();
         if let Some(id) = permanent_id {
@@ -106,7 +131,7 @@ pub fn remove_control(game: &mut GameState, event_id: EventId, card_id: CardId)
                     old_controller: current,
                     new_controller: new,
                 },
-            );
+            )?;
         }
     }
     outcome::OK
@@ -0,0 +1,144 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::counters::CounterType;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::game_states::state_based_event::StateBasedEvent;
+use data::player_states::player_state::PlayerQueries;
+use data::text_strings::Text;
+use primitives::game_primitives::{EntityId, HasSource, PermanentId, PlayerName};
+use tracing::debug;
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::prompt_handling::prompts;
+use crate::queries::card_queries;
+
+/// Puts `count` counters of the given [CounterType] on a permanent.
+///
+/// The number of counters placed is modified by any applicable replacement
+/// effects, e.g. "If one or more +1/+1 counters would be put on a creature you
+/// control, twice that many +1/+1 counters are put on it instead."
+///
+/// Returns None if this card does not exist.
+pub fn add(
+    game: &mut GameState,
+    source: impl HasSource,
+    id: PermanentId,
+    counter: CounterType,
+    count: u32,
+) -> Outcome {
+    let count = card_queries::counters_placed(game, source.source(), id, counter, count);
+    debug!("Adding {count:?} {counter:?} counters to {id:?}");
+    game.card_mut(id).or_skip()?.counters.add(counter, count);
+    if count > 0 {
+        game.add_state_based_event(StateBasedEvent::CountersAdded(id));
+        if matches!(counter, CounterType::P1p1 | CounterType::M1m1) {
+            game.add_state_based_event(StateBasedEvent::CreatureToughnessChanged(id));
+        }
+    }
+    outcome::OK
+}
+
+/// Removes up to `count` counters of the given [CounterType] from a permanent.
+///
+/// Returns None if this card does not exist.
+pub fn remove(
+    game: &mut GameState,
+    _source: impl HasSource,
+    id: PermanentId,
+    counter: CounterType,
+    count: u32,
+) -> Outcome {
+    let removed = game.card_mut(id).or_skip()?.counters.remove(counter, count);
+    debug!("Removed {removed:?} {counter:?} counters from {id:?}");
+    if removed > 0 && counter == CounterType::P1p1 {
+        game.add_state_based_event(StateBasedEvent::CreatureToughnessChanged(id));
+    }
+    outcome::OK
+}
+
+/// Removes equal numbers of +1/+1 and -1/-1 counters from a permanent.
+///
+/// > 704.5q. If a permanent has both a +1/+1 counter and a -1/-1 counter on it,
+/// > N +1/+1 and N -1/-1 counters are removed from it, where N is the smaller
+/// > of the number of +1/+1 and -1/-1 counters on it.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7045q>
+///
+/// Returns true if any counters were removed.
+pub fn annihilate(game: &mut GameState, id: PermanentId) -> Option<bool> {
+    let removed = game.card_mut(id)?.counters.annihilate();
+    if removed > 0 {
+        debug!("Removed {removed:?} +1/+1 and -1/-1 counters from {id:?}");
+    }
+    Some(removed > 0)
+}
+
+/// Prompts the [PlayerName] player to proliferate.
+///
+/// > 701.27a. To proliferate means to choose any number of permanents and/or
+/// > players, then give each another counter of each kind already there.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70127a>
+pub fn proliferate(game: &mut GameState, source: impl HasSource, player: PlayerName) -> Outcome {
+    let source = source.source();
+    let mut choices = vec![];
+    for controller in game.configuration.all_players {
+        for &permanent_id in game.battlefield(controller) {
+            if let Some(card) = game.card(permanent_id) {
+                if card.counters.kinds().next().is_some() {
+                    choices.push(card.entity_id());
+                }
+            }
+        }
+    }
+    for target in game.configuration.all_players {
+        if game.player(target).counters.kinds().next().is_some() {
+            choices.push(target.entity_id());
+        }
+    }
+
+    let max = choices.len();
+    let selected = prompts::choose_entities(game, player, Text::SelectProliferate, choices, 0, max);
+    for entity_id in selected {
+        match entity_id {
+            EntityId::Card(card_id, _) => {
+                let Some(card) = game.card(card_id) else {
+                    continue;
+                };
+                let Some(permanent_id) = card.permanent_id() else {
+                    continue;
+                };
+                let kinds = card.counters.kinds().collect::<Vec<_>>();
+                for counter in kinds {
+                    add(game, source, permanent_id, counter, 1)?;
+                }
+            }
+            EntityId::Player(target) => {
+                let counters = &mut game.player_mut(target).counters;
+                let kinds = counters.kinds().collect::<Vec<_>>();
+                for &counter in &kinds {
+                    counters.add(counter, 1);
+                }
+                if kinds.contains(&CounterType::Poison) {
+                    game.add_state_based_event(StateBasedEvent::GainedPoisonCounters(target));
+                }
+            }
+            _ => {}
+        }
+    }
+    outcome::OK
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use data::card_states::card_kind::CardKind;
-use data::card_states::card_state::CardFacing;
+use data::card_states::card_state::{CardFacing, Visibility};
 use data::card_states::play_card_plan::PlayCardChoices;
 use data::card_states::zones::{ToCardId, ZoneQueries};
 use data::game_states::game_state::GameState;
@@ -23,11 +23,10 @@ use primitives::game_primitives::{
 };
 use tracing::Instrument;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::core::initialize_card;
 use crate::mutations::spells;
-use crate::queries::player_queries;
 
 /// Creates a copy of a permanent currently on the battlefield as a token owned
 /// by the [PlayerName] player.
@@ -58,9 +57,10 @@ pub fn of_permanent(
         id,
         owner,
         Zone::Battlefield,
-        game.card(id)?.facing,
-        game.card(id)?.cast_choices.clone(),
-    )?;
+        game.card(id).or_skip()?.facing,
+        game.card(id).or_skip()?.cast_choices.clone(),
+    )
+    .or_skip()?;
     outcome::OK
 }
 
@@ -76,15 +76,14 @@ pub fn of_card_in_zone(
     facing: CardFacing,
     cast_choices: Option<PlayCardChoices>,
 ) -> Option<CardId> {
-    let all_players = player_queries::all_players(game);
     let reference = game.oracle().card(game.card(id)?.printed_card_id);
     let turn = game.turn;
     let new_card_id =
         game.zones.create_card_in_zone(reference, zone, CardKind::TokenOrStackCopy, owner, turn);
     game.card_mut(new_card_id)?.facing = facing;
-    game.card_mut(new_card_id)?.revealed_to = all_players;
+    game.card_mut(new_card_id)?.visibility = Visibility::AllPlayers;
     game.card_mut(new_card_id)?.cast_choices = cast_choices;
-    initialize_card::run(game, new_card_id)?;
+    initialize_card::run(game, new_card_id).ok()?;
     Some(new_card_id)
 }
 
@@ -126,9 +125,10 @@ pub fn of_spell(
         id,
         owner,
         Zone::Stack,
-        game.card(id)?.facing,
-        game.card(id)?.cast_choices.clone(),
-    )?;
+        game.card(id).or_skip()?.facing,
+        game.card(id).or_skip()?.cast_choices.clone(),
+    )
+    .or_skip()?;
 
     if let ChooseNewTargets::Yes = choose_new_targets {
         spells::choose_new_targets(game, source, owner, new_card_id)
@@ -0,0 +1,220 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::numerics::Damage;
+use data::events::event_context::EventContext;
+use data::game_states::ability_state::{DamageRecipient, DamageShield, DamageShieldKind};
+use data::game_states::game_state::GameState;
+use data::properties::duration::Duration;
+use primitives::game_primitives::{CardId, HasSource};
+use tracing::debug;
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::mutations::{battles, permanents, players};
+use crate::predicates::card_predicates;
+
+/// Causes the `damage_source` card to deal `damage` to the indicated
+/// recipient.
+///
+/// Prevention and redirection effects registered via [add_shield] are applied
+/// first, in the order in which they were created, and each effect applies at
+/// most once. Damage is then dealt to the resulting recipient via
+/// [players::deal_damage], [battles::deal_damage], or
+/// [permanents::deal_damage].
+///
+/// Returns None if no damage was dealt, e.g. because all of it was prevented.
+pub fn deal_damage(
+    game: &mut GameState,
+    source: impl HasSource,
+    damage_source: impl ToCardId,
+    recipient: DamageRecipient,
+    damage: Damage,
+) -> Outcome {
+    if damage == 0 {
+        return outcome::SKIPPED;
+    }
+
+    let source = source.source();
+    let source_card = game.card(damage_source).map(|card| card.id);
+    let (recipient, damage) = apply_shields(game, source_card, recipient, damage);
+    if damage == 0 {
+        debug!(?recipient, "All damage was prevented");
+        return outcome::SKIPPED;
+    }
+
+    match recipient {
+        DamageRecipient::Player(player) => {
+            players::deal_damage(game, source, damage_source, player, damage)
+        }
+        DamageRecipient::Permanent(permanent_id) => {
+            if card_predicates::battle(game, source, permanent_id).or_skip()? {
+                battles::deal_damage(game, source, damage_source, permanent_id, damage)
+            } else {
+                permanents::deal_damage(game, source, damage_source, permanent_id, damage)
+            }
+        }
+    }
+}
+
+/// Registers a shield which prevents or redirects damage that would be dealt
+/// to the indicated recipient for the given [Duration].
+pub fn add_shield(
+    game: &mut GameState,
+    context: EventContext,
+    recipient: DamageRecipient,
+    damage_source: Option<CardId>,
+    kind: DamageShieldKind,
+    duration: Duration,
+) -> Outcome {
+    debug!(?recipient, ?kind, "Adding damage shield");
+    game.ability_state.add_damage_shield(DamageShield {
+        event_id: context.event_id,
+        recipient,
+        damage_source,
+        kind,
+        duration,
+    });
+    outcome::OK
+}
+
+/// "Prevent the next `amount` damage that would be dealt to [recipient] this
+/// turn."
+pub fn prevent_next_this_turn(
+    game: &mut GameState,
+    context: EventContext,
+    recipient: DamageRecipient,
+    amount: Damage,
+) -> Outcome {
+    let duration = Duration::ThisTurn(context.current_turn);
+    add_shield(game, context, recipient, None, DamageShieldKind::Prevent(amount), duration)
+}
+
+/// "Prevent all damage that would be dealt to [recipient] this turn."
+pub fn prevent_all_this_turn(
+    game: &mut GameState,
+    context: EventContext,
+    recipient: DamageRecipient,
+) -> Outcome {
+    let duration = Duration::ThisTurn(context.current_turn);
+    add_shield(game, context, recipient, None, DamageShieldKind::PreventAll, duration)
+}
+
+/// "The next time a source would deal damage to [recipient] this turn, that
+/// damage is dealt to [redirect_to] instead."
+pub fn redirect_next_this_turn(
+    game: &mut GameState,
+    context: EventContext,
+    recipient: DamageRecipient,
+    redirect_to: DamageRecipient,
+) -> Outcome {
+    let duration = Duration::ThisTurn(context.current_turn);
+    let kind = DamageShieldKind::RedirectNext(redirect_to);
+    add_shield(game, context, recipient, None, kind, duration)
+}
+
+/// "All damage that would be dealt to [recipient] this turn is dealt to
+/// [redirect_to] instead."
+pub fn redirect_all_this_turn(
+    game: &mut GameState,
+    context: EventContext,
+    recipient: DamageRecipient,
+    redirect_to: DamageRecipient,
+) -> Outcome {
+    let duration = Duration::ThisTurn(context.current_turn);
+    let kind = DamageShieldKind::RedirectAll(redirect_to);
+    add_shield(game, context, recipient, None, kind, duration)
+}
+
+/// Removes all damage shields whose duration has ended.
+pub fn remove_expired(game: &mut GameState) {
+    let mut shields = std::mem::take(&mut game.ability_state.damage_shields);
+    shields.retain(|shield| shield.duration.is_active(game));
+    game.ability_state.damage_shields = shields;
+}
+
+/// Applies all damage shields to damage that would be dealt by the
+/// `damage_source` card, returning the resulting recipient and amount of
+/// damage.
+///
+/// Shields which are used up are removed.
+fn apply_shields(
+    game: &mut GameState,
+    damage_source: Option<CardId>,
+    mut recipient: DamageRecipient,
+    mut damage: Damage,
+) -> (DamageRecipient, Damage) {
+    remove_expired(game);
+    let mut shields = std::mem::take(&mut game.ability_state.damage_shields);
+    let mut applied = vec![false; shields.len()];
+    let mut used_up = vec![false; shields.len()];
+    while damage > 0 {
+        let Some(i) = (0..shields.len())
+            .find(|&i| !applied[i] && applies(game, &shields[i], damage_source, recipient))
+        else {
+            break;
+        };
+        applied[i] = true;
+        match shields[i].kind {
+            DamageShieldKind::Prevent(amount) => {
+                let prevented = amount.min(damage);
+                damage -= prevented;
+                shields[i].kind = DamageShieldKind::Prevent(amount - prevented);
+                used_up[i] = prevented == amount;
+            }
+            DamageShieldKind::PreventAll => {
+                damage = 0;
+            }
+            DamageShieldKind::RedirectNext(redirect_to) => {
+                recipient = redirect_to;
+                used_up[i] = true;
+            }
+            DamageShieldKind::RedirectAll(redirect_to) => {
+                recipient = redirect_to;
+            }
+        }
+        debug!(?recipient, ?damage, kind = ?shields[i].kind, "Applied damage shield");
+    }
+
+    game.ability_state.damage_shields = shields
+        .into_iter()
+        .zip(used_up)
+        .filter_map(|(shield, used_up)| (!used_up).then_some(shield))
+        .collect();
+    (recipient, damage)
+}
+
+/// Returns true if a shield should modify damage dealt by `damage_source` to
+/// `recipient`.
+///
+/// Damage is not redirected to a permanent which no longer exists.
+fn applies(
+    game: &GameState,
+    shield: &DamageShield,
+    damage_source: Option<CardId>,
+    recipient: DamageRecipient,
+) -> bool {
+    if shield.recipient != recipient
+        || shield.damage_source.is_some_and(|card_id| Some(card_id) != damage_source)
+    {
+        return false;
+    }
+
+    match shield.kind {
+        DamageShieldKind::RedirectNext(DamageRecipient::Permanent(id))
+        | DamageShieldKind::RedirectAll(DamageRecipient::Permanent(id)) => game.has_card(id),
+        _ => true,
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::stack_ability_state::StackAbilityCustomEffect;
+use data::game_states::ability_state::{DelayedTriggerCondition, DelayedTriggerState};
+use data::game_states::game_state::GameState;
+
+/// Fires all delayed triggered abilities which trigger at the beginning of the
+/// current step.
+///
+/// Triggered abilities are placed on the stack the next time a player would
+/// receive priority. Each delayed trigger fires only once.
+///
+/// > 603.7b. A delayed triggered ability will trigger only once—the next time
+/// > its trigger event occurs—unless it has a stated duration, such as "this
+/// > turn."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6037b>
+pub fn on_begin_step(game: &mut GameState) {
+    remove_expired(game);
+    let (fired, waiting): (Vec<_>, Vec<_>) =
+        std::mem::take(&mut game.ability_state.delayed_triggers)
+            .into_iter()
+            .partition(|trigger| should_trigger(game, trigger));
+    game.ability_state.delayed_triggers = waiting;
+
+    for trigger in fired {
+        let ability =
+            game.zones.create_triggered_ability(trigger.ability_id, trigger.controller, vec![]);
+        ability.custom_effect =
+            Some(StackAbilityCustomEffect { event_id: trigger.event_id, effect: trigger.effect });
+    }
+}
+
+/// Removes all delayed triggered abilities whose duration has ended without
+/// them triggering.
+pub fn remove_expired(game: &mut GameState) {
+    let mut triggers = std::mem::take(&mut game.ability_state.delayed_triggers);
+    triggers.retain(|trigger| trigger.duration.is_active(game));
+    game.ability_state.delayed_triggers = triggers;
+}
+
+fn should_trigger(game: &GameState, trigger: &DelayedTriggerState) -> bool {
+    match trigger.condition {
+        DelayedTriggerCondition::NextStep(step) => game.step == step,
+        DelayedTriggerCondition::PlayersNextStep(player, step) => {
+            game.step == step && game.turn.active_player == player
+        }
+    }
+}
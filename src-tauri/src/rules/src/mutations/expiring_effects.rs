@@ -0,0 +1,75 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::game_states::ability_state::{ExpiringEffect, ExpiringEffectKind};
+use data::game_states::game_state::GameState;
+use data::properties::duration::Duration;
+use primitives::game_primitives::{CardId, EventId};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::mutations::change_controller;
+
+/// Registers property modifiers added to the [CardId] card by the [EventId]
+/// event to be removed once `duration` ends.
+///
+/// Modifiers already stop applying once their duration ends, this ensures they
+/// are also removed from the card instead of accumulating for the rest of the
+/// game.
+pub fn add_modifiers(
+    game: &mut GameState,
+    event_id: EventId,
+    id: impl ToCardId,
+    duration: Duration,
+) -> Outcome {
+    let card_id = id.to_card_id(game).or_skip()?;
+    game.ability_state.add_expiring_effect(ExpiringEffect {
+        event_id,
+        card_id,
+        duration,
+        kind: ExpiringEffectKind::Modifiers,
+    });
+    outcome::OK
+}
+
+/// Reverts all registered effects whose duration has ended.
+///
+/// Effects which are still active remain registered.
+pub fn revert_expired(game: &mut GameState) -> Outcome {
+    for effect in game.ability_state.remove_expiring_effects() {
+        if effect.duration.is_active(game) {
+            game.ability_state.add_expiring_effect(effect);
+        } else {
+            outcome::execute(|| revert(game, effect))?;
+        }
+    }
+    outcome::OK
+}
+
+fn revert(game: &mut GameState, effect: ExpiringEffect) -> Outcome {
+    match effect.kind {
+        ExpiringEffectKind::ChangeControl => {
+            change_controller::remove_control(game, effect.event_id, effect.card_id)
+        }
+        ExpiringEffectKind::Modifiers => remove_modifiers(game, effect.event_id, effect.card_id),
+    }
+}
+
+fn remove_modifiers(game: &mut GameState, event_id: EventId, card_id: CardId) -> Outcome {
+    let card = game.card_mut(card_id).or_skip()?;
+    card.properties.remove_effects(event_id);
+    card.lost_all_abilities.retain(|lost| lost.timestamp != event_id.timestamp());
+    outcome::OK
+}
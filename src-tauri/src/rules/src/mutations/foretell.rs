@@ -0,0 +1,45 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::card_state::{CardFacing, Visibility};
+use data::card_states::custom_card_state::CustomCardState;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{CardId, HasSource, Zone};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::mutations::move_card;
+
+/// Exiles the [CardId] card face down as a foretold card, recording the
+/// current turn so that it can be cast for its foretell cost on a later turn.
+///
+/// The card's owner may look at it while it remains in exile, but it is hidden
+/// from all other players.
+///
+/// > 702.143a. Any time a player has priority during their turn, that player
+/// > may pay {2} and exile a card with foretell from their hand face down. That
+/// > player may look at that card as long as it remains in exile.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702143a>
+pub fn exile_face_down(game: &mut GameState, source: impl HasSource, card_id: CardId) -> Outcome {
+    move_card::run(game, source, card_id, Zone::Exiled)?;
+    let turn = game.turn;
+    let card = game.card_mut(card_id).or_skip()?;
+    let exiled_card_id = card.exiled_card_id().or_skip()?;
+    card.facing = CardFacing::FaceDown;
+    card.visibility = Visibility::OwnerOnly;
+    card.custom_state.push(CustomCardState::Foretold { exiled_card_id, turn });
+    outcome::OK
+}
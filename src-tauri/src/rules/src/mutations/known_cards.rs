@@ -0,0 +1,62 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::{KnownCard, PlayerQueries};
+use primitives::game_primitives::Zone;
+
+/// Records the identity of cards in a hand or library which are currently
+/// revealed to a player other than their owner, and discards knowledge of
+/// cards which have since become new objects.
+///
+/// See [KnownCard].
+pub fn update(game: &mut GameState) {
+    let turn = game.turn;
+    let mut revealed = vec![];
+    for card in game.zones.all_cards() {
+        if !matches!(card.zone, Zone::Hand | Zone::Library) {
+            continue;
+        }
+        for player in card.visible_to() {
+            if player != card.owner {
+                revealed.push((player, card.id, KnownCard {
+                    object_id: card.object_id,
+                    zone: card.zone,
+                    revealed_on: turn,
+                }));
+            }
+        }
+    }
+
+    for player in game.configuration.all_players {
+        let stale = game
+            .player(player)
+            .known_cards
+            .iter()
+            .filter(|(&id, known)| {
+                game.card(id).map_or(true, |card| card.object_id != known.object_id)
+            })
+            .map(|(&id, _)| id)
+            .collect::<Vec<_>>();
+        let known_cards = &mut game.player_mut(player).known_cards;
+        for id in stale {
+            known_cards.remove(&id);
+        }
+    }
+
+    for (player, id, known) in revealed {
+        game.player_mut(player).known_cards.entry(id).or_insert(known);
+    }
+}
@@ -13,13 +13,20 @@
 // limitations under the License.
 
 use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::function_types::CardPredicate;
 use data::game_states::game_state::GameState;
 use data::game_states::state_based_event::StateBasedEvent;
+use data::printed_cards::printed_card::Face;
+use data::prompts::game_update::GameAnimation;
+use data::text_strings::Text;
 use primitives::game_primitives::{CardId, HasPlayerName, HasSource, PlayerName, Zone};
+use rand::prelude::SliceRandom;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
-use crate::mutations::move_card;
+use crate::mutations::{move_card, permanents};
+use crate::play_cards::play_card;
+use crate::prompt_handling::prompts;
 
 /// Draws a card from the top of the `player`'s library.
 ///
@@ -34,7 +41,9 @@ pub fn draw(game: &mut GameState, source: impl HasSource, player: impl HasPlayer
         game.add_state_based_event(StateBasedEvent::DrawFromEmptyLibrary(player));
         return outcome::OK;
     };
-    move_card::run(game, source, id, Zone::Hand)
+    move_card::run(game, source, id, Zone::Hand)?;
+    game.add_animation(GameAnimation::DrawCard(player, id));
+    outcome::OK
 }
 
 /// Draws `count` cards in sequence from the top of the `player`'s library.
@@ -63,6 +72,19 @@ pub fn move_to_top(
     move_card::run(game, source, card_id, Zone::Library)
 }
 
+/// Move a card to the bottom of its owner's library.
+pub fn move_to_bottom(
+    game: &mut GameState,
+    source: impl HasSource,
+    card_id: impl ToCardId,
+) -> Outcome {
+    let card_id = card_id.to_card_id(game).or_skip()?;
+    move_card::run(game, source, card_id, Zone::Library)?;
+    let owner = game.card(card_id).or_skip()?.owner;
+    game.zones.move_to_bottom_of_library(owner, card_id);
+    outcome::OK
+}
+
 /// Moves all provided cards to the top of their owner's library in the given
 /// order.
 ///
@@ -71,9 +93,74 @@ pub fn move_all_to_top<'a>(
     game: &mut GameState,
     source: impl HasSource,
     cards: impl IntoIterator<Item = &'a CardId>,
-) {
+) -> Outcome {
     let source = source.source();
     for card_id in cards {
-        move_to_top(game, source, *card_id);
+        outcome::execute(|| move_to_top(game, source, *card_id))?;
+    }
+    outcome::OK
+}
+
+/// What happens to the card found by [exile_until_and_cast] if the player
+/// does not cast it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IfNotCast {
+    /// Put the card on the bottom of its owner's library with the other
+    /// exiled cards, e.g. for cascade.
+    PutOnBottom,
+
+    /// Put the card into its owner's hand, e.g. for discover.
+    PutIntoHand,
+}
+
+/// Exiles cards from the top of the [PlayerName] player's library until a card
+/// matching `predicate` is exiled. The player may cast that card without
+/// paying its mana cost. The remaining exiled cards are then put on the bottom
+/// of that library in a random order.
+///
+/// This implements the shared behavior of abilities such as cascade and
+/// discover. If no matching card is found, all exiled cards are put on the
+/// bottom of the library.
+pub fn exile_until_and_cast(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+    predicate: impl CardPredicate<CardId>,
+    if_not_cast: IfNotCast,
+) -> Outcome {
+    let source = source.source();
+    let mut to_bottom = vec![];
+    let mut found = None;
+    while let Some(&card_id) = game.library(player).back() {
+        move_card::run(game, source, card_id, Zone::Exiled)?;
+        permanents::turn_face_up(game, source, card_id, Face::Primary)?;
+        if predicate(game, source, card_id) == Some(true) {
+            found = Some(card_id);
+            break;
+        }
+        to_bottom.push(card_id);
+    }
+
+    if let Some(card_id) = found {
+        let cast = play_card::can_cast_without_paying_mana_cost(game, player, source, card_id)
+            && prompts::multiple_choice(game, player, Text::CastWithoutPayingPrompt, vec![
+                Text::CastSpell,
+                Text::DoNotCast,
+            ]) == Text::CastSpell
+            && play_card::cast_without_paying_mana_cost(game, player, source, card_id).is_ok();
+        if !cast {
+            match if_not_cast {
+                IfNotCast::PutOnBottom => to_bottom.push(card_id),
+                IfNotCast::PutIntoHand => {
+                    move_card::run(game, source, card_id, Zone::Hand)?;
+                }
+            }
+        }
     }
+
+    to_bottom.shuffle(&mut game.rng);
+    for card_id in to_bottom {
+        move_to_bottom(game, source, card_id)?;
+    }
+    outcome::OK
 }
@@ -0,0 +1,118 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::card_state::ExilePlayPermission;
+use data::card_states::custom_card_state::CustomCardState;
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::game_states::game_state::GameState;
+use data::printed_cards::printed_card::Face;
+use data::properties::duration::Duration;
+use primitives::game_primitives::{CardId, ExiledCardId, HasSource, PlayerName, Zone};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::mutations::{move_card, permanents};
+use crate::queries::card_queries;
+
+/// Exiles the `id` card and links it to the `linked_to` card.
+///
+/// The `linked_to` card can later refer to exactly the object which was
+/// exiled via [card_queries::linked_exile_cards], e.g. to return it to the
+/// battlefield or to allow it to be played.
+///
+/// > 607.2a. If an object has an activated or triggered ability printed on it
+/// > that instructs a player to exile one or more cards and an ability printed
+/// > on it that refers either to "the exiled cards" or to cards "exiled with
+/// > [this object]," these abilities are linked. The second ability refers only
+/// > to cards in the exile zone that were put there as a result of an
+/// > instruction to exile them in the first ability.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6072a>
+///
+/// Returns the [ExiledCardId] of the newly-exiled card, or None if either card
+/// does not exist.
+pub fn exile(
+    game: &mut GameState,
+    source: impl HasSource,
+    linked_to: impl ToCardId,
+    id: impl ToCardId,
+) -> Option<ExiledCardId> {
+    let linked_to = game.card(linked_to)?.id;
+    let card_id = game.card(id)?.id;
+    move_card::run(game, source, card_id, Zone::Exiled).ok()?;
+    let exiled_card_id = game.card(card_id)?.exiled_card_id()?;
+    game.card_mut(linked_to)?.custom_state.push(CustomCardState::LinkedExile { exiled_card_id });
+    Some(exiled_card_id)
+}
+
+/// Allows the [PlayerName] player to play the [ExiledCardId] card for the
+/// given [Duration].
+///
+/// The permission ends if the card leaves exile.
+pub fn allow_playing(
+    game: &mut GameState,
+    player: PlayerName,
+    id: ExiledCardId,
+    duration: Duration,
+) -> Outcome {
+    game.card_mut(id)
+        .or_skip()?
+        .exile_play_permissions
+        .push(ExilePlayPermission { player, duration });
+    outcome::OK
+}
+
+/// Exiles the top card of the [PlayerName] player's library, links it to the
+/// `linked_to` card, and allows that player to play it for the given
+/// [Duration], i.e. "impulse draw".
+///
+/// Does nothing if the player's library is empty.
+pub fn impulse_draw(
+    game: &mut GameState,
+    source: impl HasSource,
+    linked_to: impl ToCardId,
+    player: PlayerName,
+    duration: Duration,
+) -> Outcome {
+    let &card_id = game.library(player).back().or_skip()?;
+    let exiled_card_id = exile(game, source, linked_to, card_id).or_skip()?;
+    allow_playing(game, player, exiled_card_id, duration)
+}
+
+/// Returns all cards exiled by the [CardId] card which are still in exile to
+/// the battlefield under their owners' control.
+pub fn return_to_battlefield(
+    game: &mut GameState,
+    source: impl HasSource,
+    linked_to: CardId,
+) -> Outcome {
+    let source = source.source();
+    for exiled_card_id in card_queries::linked_exile_cards(game, linked_to) {
+        outcome::execute(|| {
+            move_card::run(game, source, exiled_card_id, Zone::Battlefield)?;
+            permanents::turn_face_up(game, source, exiled_card_id.internal_card_id, Face::Primary)
+        })?;
+    }
+    outcome::OK
+}
+
+/// Returns all cards exiled by the [CardId] card which are still in exile to
+/// their owners' hands.
+pub fn return_to_hand(game: &mut GameState, source: impl HasSource, linked_to: CardId) -> Outcome {
+    let source = source.source();
+    for exiled_card_id in card_queries::linked_exile_cards(game, linked_to) {
+        outcome::execute(|| move_card::run(game, source, exiled_card_id, Zone::Hand))?;
+    }
+    outcome::OK
+}
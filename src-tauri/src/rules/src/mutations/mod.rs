@@ -12,13 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod adventures;
+pub mod battles;
 pub mod change_controller;
+pub mod counters;
 pub mod create_copy;
+pub mod damage;
+pub mod delayed_triggers;
+pub mod expiring_effects;
+pub mod foretell;
+pub mod known_cards;
 pub mod library;
+pub mod linked_exile;
 pub mod move_card;
+pub mod mutate;
 pub mod permanents;
+pub mod planechase;
 pub mod players;
 pub mod priority;
+pub mod random;
+pub mod sagas;
 pub mod spells;
 pub mod state_based_actions;
 pub mod trigger_extension;
+pub mod turns;
@@ -13,20 +13,22 @@
 // limitations under the License.
 
 use data::card_states::card_kind::CardKind;
-use data::card_states::card_state::{CardFacing, TappedState};
+use data::card_states::card_state::{CardFacing, TappedState, Visibility};
 use data::card_states::zones::{ToCardId, ZoneQueries};
 use data::core::numerics::Damage;
 use data::events::card_events;
+use data::events::card_events::ZoneChangedEvent;
 use data::game_states::game_state::{GameState, TurnData};
+use data::game_states::history_data::HistoryEvent;
 use data::game_states::state_based_event::StateBasedEvent;
-use primitives::game_primitives::{
-    CardId, EntityId, HasController, HasSource, PermanentId, Zone, ALL_POSSIBLE_PLAYERS,
-};
+use data::prompts::game_update::GameAnimation;
+use primitives::game_primitives::{CardId, EntityId, HasSource, PermanentId, Zone};
 use tracing::debug;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::dispatcher::dispatch;
+use crate::mutations::{battles, mutate, sagas};
 
 /// Moves a card to a new zone, updates indices, assigns a new
 /// [EntityId] to it, and fires all relevant events.
@@ -35,20 +37,21 @@ use crate::dispatcher::dispatch;
 ///
 /// Panics if this card was not found in its previous zone.
 pub fn run(game: &mut GameState, source: impl HasSource, id: impl ToCardId, new: Zone) -> Outcome {
-    let card_id = id.to_card_id(game)?;
+    let card_id = id.to_card_id(game).or_skip()?;
     let new_object_id = game.zones.new_object_id();
-    let card = game.card(card_id)?;
+    let card = game.card(card_id).or_skip()?;
     let old = card.zone;
     debug!(?card_id, ?old, ?new, "Moving card to zone");
 
-    if old == Zone::Battlefield {
+    // Abilities of merged components are triggered via the merged permanent
+    if old == Zone::Battlefield && card.merged_into.is_none() {
         dispatch::card_event(
             game,
             id,
             |e| &e.will_leave_battlefield,
             source.source(),
             &card.permanent_id().expect("Card on battlefield should have PermanentId"),
-        );
+        )?;
     }
 
     if new == Zone::Battlefield {
@@ -58,36 +61,62 @@ pub fn run(game: &mut GameState, source: impl HasSource, id: impl ToCardId, new:
             |e| &e.will_enter_battlefield,
             source.source(),
             &PermanentId::new(new_object_id, card_id),
-        );
+        )?;
     }
 
     on_leave_zone(game, card_id, old)?;
 
     if !(old == Zone::Stack && new == Zone::Battlefield) {
         // Control-changing effects persist from the stack to the battlefield.
-        game.card_mut(card_id)?.control_changing_effects.clear();
+        game.card_mut(card_id).or_skip()?.control_changing_effects.clear();
     }
 
-    game.zones.move_card(card_id, new, new_object_id);
+    game.zones.move_card(card_id, new, new_object_id)?;
+    game.add_history_event(HistoryEvent::ZoneChange(card_id, old, new));
+    game.add_animation(GameAnimation::ZoneMove(card_id, old, new));
+    game.add_state_based_event(StateBasedEvent::ObjectIdChanged(card_id));
     on_enter_zone(game, card_id, new)?;
-    outcome::OK
+
+    if old == Zone::Battlefield {
+        mutate::on_leave_battlefield(game, source.source(), card_id, new)?;
+    }
+    if new == Zone::Battlefield {
+        sagas::on_enter_battlefield(game, card_id)?;
+        battles::on_enter_battlefield(game, card_id)?;
+        let permanent_id = game.card(card_id).or_skip()?.permanent_id().or_skip()?;
+        dispatch::game_event(
+            game,
+            |e| &e.permanent_entered_battlefield,
+            source.source(),
+            permanent_id,
+        )?;
+    }
+
+    dispatch::zone_change(game, source.source(), ZoneChangedEvent { card_id, old, new })
 }
 
 fn on_leave_zone(game: &mut GameState, card_id: CardId, zone: Zone) -> Outcome {
     match zone {
         Zone::Stack => {
-            let card = game.card_mut(card_id)?;
+            let card = game.card_mut(card_id).or_skip()?;
             card.targets.clear();
+            card.target_amounts.clear();
         }
         Zone::Battlefield => {
-            let card = game.card_mut(card_id)?;
+            let card = game.card_mut(card_id).or_skip()?;
             card.tapped_state = TappedState::Untapped;
             card.damage = 0;
             card.attached_to = None;
+            card.goaded.clear();
+            card.untap_restrictions.clear();
+            card.protector = None;
             if card.kind == CardKind::TokenOrStackCopy {
                 game.add_state_based_event(StateBasedEvent::TokenLeftBattlefield(card_id));
             }
         }
+        Zone::Exiled => {
+            game.card_mut(card_id).or_skip()?.exile_play_permissions.clear();
+        }
         _ => {}
     }
     outcome::OK
@@ -95,26 +124,18 @@ fn on_leave_zone(game: &mut GameState, card_id: CardId, zone: Zone) -> Outcome {
 
 fn on_enter_zone(game: &mut GameState, card_id: CardId, zone: Zone) -> Outcome {
     let turn = game.turn;
-    let card = game.card_mut(card_id)?;
+    let card = game.card_mut(card_id).or_skip()?;
     card.entered_current_zone = turn;
+    card.merged_into = None;
+    card.visibility = Visibility::for_zone(zone);
+    card.revealed_to.clear();
 
     if zone != Zone::Stack && zone != Zone::Battlefield {
         card.cast_choices = None;
     }
 
-    match zone {
-        Zone::Stack | Zone::Battlefield | Zone::Graveyard => {
-            card.revealed_to = ALL_POSSIBLE_PLAYERS;
-        }
-        Zone::Hand => {
-            let controller = card.controller();
-            card.revealed_to.insert(controller);
-            card.facing = CardFacing::FaceDown;
-        }
-        Zone::Library => {
-            card.facing = CardFacing::FaceDown;
-        }
-        _ => {}
+    if matches!(zone, Zone::Hand | Zone::Library) {
+        card.facing = CardFacing::FaceDown;
     }
 
     if card.kind == CardKind::TokenOrStackCopy && zone != Zone::Battlefield {
@@ -0,0 +1,161 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+
+use data::card_states::card_state::PremergeRules;
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
+use data::game_states::state_based_event::StateBasedEvent;
+use data::printed_cards::printed_card::Face;
+use data::text_strings::Text;
+use primitives::game_primitives::{
+    CardId, EntityId, HasController, HasSource, PermanentId, Source, Zone,
+};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::core::initialize_card;
+use crate::dispatcher::dispatch;
+use crate::mutations::{move_card, permanents};
+use crate::prompt_handling::prompts;
+use crate::queries::card_queries;
+
+/// Position at which a card is added to a merged permanent.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MergePosition {
+    Top,
+    Bottom,
+}
+
+/// Resolves the [CardId] mutating creature spell.
+///
+/// > 702.140b. As a mutating creature spell begins resolving, if its target is
+/// > illegal, it ceases to be a mutating creature spell and continues
+/// > resolving as a creature spell and will enter the battlefield the same way
+/// > other creature spells do.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702140b>
+///
+/// > 702.140c. As a mutating creature spell resolves, if its target is legal,
+/// > it doesn't enter the battlefield. Rather, it merges with the target
+/// > creature and becomes one object represented by more than one card or
+/// > token. The spell's controller chooses whether the spell is put on top of
+/// > the creature or on the bottom.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702140c>
+pub fn resolve_mutating_spell(game: &mut GameState, card_id: CardId) -> Outcome {
+    let card = game.card(card_id).or_skip()?;
+    let controller = card.controller();
+    let face = card_queries::cast_as_faces(card).iter().next().unwrap_or(Face::Primary);
+    let target = match card.targets.first() {
+        Some(&EntityId::Card(target_card_id, object_id)) => {
+            Some(PermanentId::new(object_id, target_card_id))
+        }
+        _ => None,
+    };
+
+    let Some(target) = target.filter(|&target| {
+        card_queries::can_mutate_onto(game, Source::Game, card_id, target) == Some(true)
+    }) else {
+        move_card::run(game, Source::Game, card_id, Zone::Battlefield)?;
+        return permanents::turn_face_up(game, Source::Game, card_id, face);
+    };
+
+    let position = if prompts::multiple_choice(game, controller, Text::MergePositionPrompt, vec![
+        Text::MergeOnTop,
+        Text::MergeOnBottom,
+    ]) == Text::MergeOnTop
+    {
+        MergePosition::Top
+    } else {
+        MergePosition::Bottom
+    };
+    merge(game, Source::Game, card_id, target, position)?;
+    permanents::turn_face_up(game, Source::Game, card_id, face)?;
+    dispatch::card_event(game, target, |e| &e.mutated, Source::Game, &target)
+}
+
+/// Merges the [CardId] card with the [PermanentId] permanent, adding it as a
+/// component in the indicated [MergePosition].
+///
+/// The merged permanent is the same object as the [PermanentId] permanent was
+/// before the merge, and gains the abilities of the new component.
+pub fn merge(
+    game: &mut GameState,
+    _source: impl HasSource,
+    card_id: CardId,
+    into: PermanentId,
+    position: MergePosition,
+) -> Outcome {
+    let old = game.card(card_id).or_skip()?.zone;
+    let merged = game.card_mut(into).or_skip()?;
+    let merged_card_id = merged.id;
+    if merged.premerge_rules.is_none() {
+        merged.premerge_rules = Some(Box::new(PremergeRules {
+            properties: merged.properties.clone(),
+            events: merged.events.clone(),
+            optional_costs: merged.optional_costs.clone(),
+            alternative_costs: merged.alternative_costs.clone(),
+            combat_special_actions: merged.combat_special_actions.clone(),
+            saga_chapters: merged.saga_chapters.clone(),
+        }));
+    }
+    if merged.merged_components.is_empty() {
+        merged.merged_components.push(merged_card_id);
+    }
+    match position {
+        MergePosition::Top => merged.merged_components.insert(0, card_id),
+        MergePosition::Bottom => merged.merged_components.push(card_id),
+    }
+
+    let new_object_id = game.zones.new_object_id();
+    game.zones.merge_card(card_id, into, new_object_id)?;
+    game.add_history_event(HistoryEvent::ZoneChange(card_id, old, Zone::Battlefield));
+    game.add_state_based_event(StateBasedEvent::ObjectIdChanged(card_id));
+    initialize_card::add_merged_abilities(game, merged_card_id, card_id)
+}
+
+/// Separates the components of a merged permanent after the [CardId] card
+/// representing it has left the battlefield, moving each other component to
+/// the same [Zone].
+///
+/// The merged card's own properties, events, costs, and other rules added by
+/// its abilities are restored, since it is now a new object which no longer
+/// has the abilities of the other components.
+pub fn on_leave_battlefield(
+    game: &mut GameState,
+    source: impl HasSource,
+    card_id: CardId,
+    zone: Zone,
+) -> Outcome {
+    let card = game.card_mut(card_id).or_skip()?;
+    let components = mem::take(&mut card.merged_components);
+    if let Some(rules) = card.premerge_rules.take() {
+        card.properties = rules.properties;
+        card.events = rules.events;
+        card.optional_costs = rules.optional_costs;
+        card.alternative_costs = rules.alternative_costs;
+        card.combat_special_actions = rules.combat_special_actions;
+        card.saga_chapters = rules.saga_chapters;
+    }
+
+    for component in components {
+        if component != card_id {
+            move_card::run(game, source.source(), component, zone)?;
+        }
+    }
+    outcome::OK
+}
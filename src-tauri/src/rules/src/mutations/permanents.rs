@@ -12,20 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::card_states::card_state::{CardFacing, TappedState};
+use data::card_states::card_state::{
+    CardFacing, GoadedState, TappedState, UntapRestriction, Visibility,
+};
+use data::card_states::counters::CounterType;
 use data::card_states::zones::{ToCardId, ZoneQueries};
 use data::core::numerics::Damage;
 use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
 use data::game_states::state_based_event::StateBasedEvent;
 use data::printed_cards::printed_card::Face;
-use primitives::game_primitives::{
-    CardId, HasSource, PermanentId, Source, Zone, ALL_POSSIBLE_PLAYERS,
-};
+use data::prompts::game_update::GameAnimation;
+use data::properties::duration::Duration;
+use primitives::game_primitives::{CardId, HasSource, PermanentId, PlayerName, Source, Zone};
 use tracing::debug;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
-use crate::mutations::move_card;
+use crate::dispatcher::dispatch;
+use crate::mutations::{counters, damage, move_card, players};
+use crate::predicates::card_predicates;
+use crate::queries::{damage_queries, player_queries};
 
 /// Turns the [Face] face of this card up and reveals it to all players.
 ///
@@ -36,9 +43,9 @@ pub fn turn_face_up(
     id: impl ToCardId,
     face: Face,
 ) -> Outcome {
-    let card = game.card_mut(id)?;
+    let card = game.card_mut(id).or_skip()?;
     card.facing = CardFacing::FaceUp(face);
-    card.revealed_to = ALL_POSSIBLE_PLAYERS;
+    card.visibility = Visibility::AllPlayers;
     outcome::OK
 }
 
@@ -46,7 +53,7 @@ pub fn turn_face_up(
 ///
 /// Returns None if this card does not exist.
 pub fn tap(game: &mut GameState, _source: impl HasSource, id: impl ToCardId) -> Outcome {
-    let card = game.card_mut(id)?;
+    let card = game.card_mut(id).or_skip()?;
     card.tapped_state = TappedState::Tapped;
     outcome::OK
 }
@@ -55,26 +62,114 @@ pub fn tap(game: &mut GameState, _source: impl HasSource, id: impl ToCardId) ->
 ///
 /// Returns None if this card does not exist.
 pub fn untap(game: &mut GameState, _source: impl HasSource, id: impl ToCardId) -> Outcome {
-    let card = game.card_mut(id)?;
+    let card = game.card_mut(id).or_skip()?;
     card.tapped_state = TappedState::Untapped;
     outcome::OK
 }
 
-/// Deals damage to a permanent
+/// Untaps a permanent during its controller's untap step, unless an effect
+/// prevents it from untapping.
+///
+/// Consumes any effects which only apply during the next untap step.
 ///
 /// Returns None if this card does not exist.
-pub fn deal_damage(
+pub fn untap_during_untap_step(game: &mut GameState, id: impl ToCardId) -> Outcome {
+    let card_id = id.to_card_id(game).or_skip()?;
+    let restrictions = game.card(card_id).or_skip()?.untap_restrictions.clone();
+    let prevented = restrictions.iter().any(|restriction| match restriction {
+        UntapRestriction::NextUntapStep => true,
+        UntapRestriction::WhileActive(duration) => duration.is_active(game),
+    });
+
+    game.card_mut(card_id)
+        .or_skip()?
+        .untap_restrictions
+        .retain(|restriction| matches!(restriction, UntapRestriction::WhileActive(_)));
+    if !prevented {
+        untap(game, Source::Game, card_id)?;
+    }
+    outcome::OK
+}
+
+/// Prevents a permanent from untapping during its controller's untap step
+/// while the provided [Duration] is active, e.g. "doesn't untap during its
+/// controller's untap step for as long as you control this permanent".
+///
+/// Returns None if this card does not exist.
+pub fn prevent_untap(
     game: &mut GameState,
     _source: impl HasSource,
+    id: PermanentId,
+    duration: Duration,
+) -> Outcome {
+    game.card_mut(id).or_skip()?.untap_restrictions.push(UntapRestriction::WhileActive(duration));
+    outcome::OK
+}
+
+/// Exerts a permanent, causing it to not untap during its controller's next
+/// untap step.
+///
+/// > 701.43b. A permanent can be exerted even if it's not tapped or has
+/// > already been exerted in a turn. If you exert a permanent more than once
+/// > during a turn, it won't untap during your next untap step.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70143b>
+///
+/// Returns None if this card does not exist.
+pub fn exert(game: &mut GameState, source: impl HasSource, id: PermanentId) -> Outcome {
+    let card = game.card_mut(id).or_skip()?;
+    card.untap_restrictions.push(UntapRestriction::NextUntapStep);
+    dispatch::card_event(game, id, |e| &e.exerted, source.source(), &id)?;
+    outcome::OK
+}
+
+/// Causes the `damage_source` card to deal damage to a permanent.
+///
+/// Damage from a source with wither or infect puts -1/-1 counters on the
+/// permanent instead of being marked on it. Damage from a source with
+/// deathtouch causes a creature to be destroyed the next time state-based
+/// actions are checked.
+///
+/// This does not apply prevention or redirection effects, use
+/// [damage::deal_damage] instead for damage dealt by game effects.
+///
+/// Returns None if this card does not exist.
+pub fn deal_damage(
+    game: &mut GameState,
+    source: impl HasSource,
+    damage_source: impl ToCardId,
     id: impl ToCardId,
     damage: Damage,
 ) -> Outcome {
-    let card = game.card_mut(id)?;
-    let permanent_id = card.permanent_id()?;
+    let source = source.source();
+    let as_counters = damage_queries::deals_damage_as_counters(game, source, damage_source);
+    let deathtouch = damage_queries::has_deathtouch(game, source, damage_source);
+    let permanent_id = game.card(id).or_skip()?.permanent_id().or_skip()?;
     debug!("Dealing {damage:?} damage to {id:?}");
-    card.damage += damage;
-    game.add_state_based_event(StateBasedEvent::CreatureDamaged(permanent_id));
-    outcome::OK
+    if as_counters {
+        add_minus_one_counters(game, source, permanent_id, damage as u32)?;
+    } else {
+        game.card_mut(id).or_skip()?.damage += damage;
+        game.add_state_based_event(StateBasedEvent::CreatureDamaged(permanent_id));
+    }
+    if deathtouch && card_predicates::creature(game, source, permanent_id).or_skip()? {
+        game.add_state_based_event(StateBasedEvent::CreatureDamagedByDeathtouch(permanent_id));
+    }
+    game.add_history_event(HistoryEvent::DamageToPermanent(permanent_id, damage));
+    game.add_animation(GameAnimation::DamagePermanent(permanent_id, damage));
+    players::apply_lifelink(game, source, damage_source, damage)
+}
+
+/// Puts `count` -1/-1 counters on a permanent.
+///
+/// Returns None if this card does not exist.
+pub fn add_minus_one_counters(
+    game: &mut GameState,
+    source: impl HasSource,
+    id: PermanentId,
+    count: u32,
+) -> Outcome {
+    counters::add(game, source, id, CounterType::M1m1, count)
 }
 
 /// Sacrifices a permanent.
@@ -88,3 +183,44 @@ pub fn sacrifice(game: &mut GameState, source: impl HasSource, id: impl ToCardId
 pub fn return_to_hand(game: &mut GameState, source: impl HasSource, id: impl ToCardId) -> Outcome {
     move_card::run(game, source.source(), id, Zone::Hand)
 }
+
+/// Goads a permanent until the goading player's next turn.
+///
+/// > 701.15a. Certain spells and abilities can goad a creature. Until the next
+/// > turn of the controller of that spell or ability, that creature attacks
+/// > each combat if able and attacks a player other than the goading player if
+/// > able.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70115a>
+///
+/// Returns None if this card does not exist.
+pub fn goad(
+    game: &mut GameState,
+    _source: impl HasSource,
+    player: PlayerName,
+    id: PermanentId,
+) -> Outcome {
+    let turn = game.turn;
+    let card = game.card_mut(id).or_skip()?;
+    card.goaded
+        .push(GoadedState { player, duration: Duration::UntilPlayersNextTurn(player, turn) });
+    outcome::OK
+}
+
+/// Removes goaded designations which have ended, e.g. because the goading
+/// player's next turn has begun.
+pub fn remove_expired_goads(game: &mut GameState) {
+    for player in player_queries::all_players(game) {
+        let goaded = game
+            .battlefield(player)
+            .iter()
+            .copied()
+            .filter(|&id| game.card(id).is_some_and(|card| !card.goaded.is_empty()))
+            .collect::<Vec<_>>();
+        for id in goaded {
+            let mut states = std::mem::take(&mut game.card_mut(id).expect("Card not found").goaded);
+            states.retain(|state| state.duration.is_active(game));
+            game.card_mut(id).expect("Card not found").goaded = states;
+        }
+    }
+}
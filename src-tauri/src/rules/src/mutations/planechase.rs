@@ -0,0 +1,115 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Planechase casual variant.
+//!
+//! <https://yawgatog.com/resources/magic-rules/#R901>
+
+use data::card_states::card_kind::CardKind;
+use data::card_states::card_state::{CardFacing, Visibility};
+use data::card_states::zones::ZoneQueries;
+use data::decks::deck::Deck;
+use data::game_states::game_state::GameState;
+use data::game_states::planechase_state::PlanechaseState;
+use data::game_states::random_result::PlanarDieFace;
+use data::printed_cards::printed_card::Face;
+use primitives::game_primitives::{CardId, HasSource, PlayerName, Zone};
+use rand::prelude::SliceRandom;
+use tracing::debug;
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::core::initialize_card;
+use crate::dispatcher::dispatch;
+use crate::mutations::random;
+
+/// Creates the shared planar deck for a Planechase game from the cards in
+/// `deck`, shuffles it, and turns the top card face up.
+///
+/// Planar cards are owned by [PlayerName::One] for bookkeeping purposes, but
+/// the face-up card is always controlled by the active player.
+pub fn setup(game: &mut GameState, deck: &Deck) -> Outcome {
+    let turn = game.turn;
+    let mut planar_deck = vec![];
+    for (&id, &quantity) in &deck.cards {
+        for _ in 0..quantity {
+            let reference = game.oracle().card(id);
+            let card_id = game.zones.create_card_in_zone(
+                reference,
+                Zone::Command,
+                CardKind::Normal,
+                PlayerName::One,
+                turn,
+            );
+            game.card_mut(card_id).or_skip()?.visibility = Visibility::None;
+            initialize_card::run(game, card_id)?;
+            planar_deck.push(card_id);
+        }
+    }
+
+    planar_deck.shuffle(&mut game.rng);
+    debug!(cards = planar_deck.len(), "Created planar deck");
+    game.planechase = Some(PlanechaseState { planar_deck: planar_deck.into(), active_plane: None });
+    turn_top_card_face_up(game).or_skip()?;
+    outcome::OK
+}
+
+/// Rolls the planar die for the [PlayerName] player and applies the result.
+///
+/// Rolling {CHAOS} fires [GlobalEvents::chaos_ensues], causing the chaos
+/// ability of the face-up plane to trigger. Rolling the planeswalker symbol
+/// causes the player to planeswalk.
+///
+/// [GlobalEvents::chaos_ensues]: data::events::game_events::GlobalEvents::chaos_ensues
+pub fn roll_planar_die(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+) -> Outcome {
+    let source = source.source();
+    match random::roll_planar_die(game, player) {
+        PlanarDieFace::Chaos => dispatch::game_event(game, |e| &e.chaos_ensues, source, player),
+        PlanarDieFace::Planeswalk => planeswalk(game, source),
+        PlanarDieFace::Blank => outcome::OK,
+    }
+}
+
+/// Puts the face-up plane or phenomenon card on the bottom of the planar deck
+/// and turns the top card of the planar deck face up.
+///
+/// Does nothing if this game is not using the Planechase variant.
+pub fn planeswalk(game: &mut GameState, source: impl HasSource) -> Outcome {
+    let source = source.source();
+    let state = game.planechase.as_mut().or_skip()?;
+    if let Some(previous) = state.active_plane.take() {
+        state.planar_deck.push_front(previous);
+        let card = game.card_mut(previous).or_skip()?;
+        card.facing = CardFacing::FaceDown;
+        card.visibility = Visibility::None;
+    }
+
+    let plane = turn_top_card_face_up(game).or_skip()?;
+    debug!(?plane, "Planeswalked");
+    dispatch::game_event(game, |e| &e.planeswalked_to, source, plane)
+}
+
+fn turn_top_card_face_up(game: &mut GameState) -> Option<CardId> {
+    let state = game.planechase.as_mut()?;
+    let plane = state.planar_deck.pop_back()?;
+    state.active_plane = Some(plane);
+    let card = game.card_mut(plane)?;
+    card.facing = CardFacing::FaceUp(Face::Primary);
+    card.visibility = Visibility::AllPlayers;
+    Some(plane)
+}
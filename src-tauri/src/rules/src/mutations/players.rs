@@ -12,35 +12,334 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data::card_states::counters::CounterType;
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::card_tags::CardTag;
 use data::core::numerics::{Damage, LifeValue};
-use data::game_states::game_state::GameState;
+use data::events::game_events::{LifeGainedEvent, LifeLostEvent};
+use data::game_states::game_state::{GameState, GameStatus};
+use data::game_states::history_data::HistoryEvent;
 use data::game_states::state_based_event::StateBasedEvent;
 use data::player_states::player_state::PlayerQueries;
-use primitives::game_primitives::{PlayerName, Source};
+use data::prompts::game_update::GameAnimation;
+use enumset::EnumSet;
+use primitives::game_primitives::{HasController, PlayerName, Source, Zone};
 use tracing::debug;
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
+use crate::dispatcher::dispatch;
+use crate::mutations::{change_controller, damage, move_card};
+use crate::queries::{damage_queries, player_queries};
+
+/// Causes the `damage_source` card to deal damage to the indicated `player`.
+///
+/// Damage dealt to a player causes them to lose that much life. In team games,
+/// the resulting life loss is applied to the team's shared life total. Damage
+/// from a source with infect instead gives the player that many poison
+/// counters.
+///
+/// This does not apply prevention or redirection effects, use
+/// [damage::deal_damage] instead for damage dealt by game effects.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1193>
 pub fn deal_damage(
     game: &mut GameState,
-    _source: Source,
+    source: Source,
+    damage_source: impl ToCardId,
     player: PlayerName,
     damage: Damage,
 ) -> Outcome {
     debug!("Dealing {damage:?} damage to {player:?}");
-    game.player_mut(player).life -= damage as i64;
+    let infect = damage_queries::has_infect(game, source, damage_source);
+    game.add_history_event(HistoryEvent::DamageToPlayer(player, damage));
+    game.add_animation(GameAnimation::DamagePlayer(player, damage));
+    if infect {
+        outcome::execute(|| add_poison_counters(game, source, player, damage as u32))?;
+    } else {
+        change_team_life_total(game, player, -(damage as i64));
+        game.add_state_based_event(StateBasedEvent::LifeTotalDecrease(player));
+        life_lost(game, source, player, damage as LifeValue)?;
+    }
+    apply_lifelink(game, source, damage_source, damage)
+}
+
+/// Causes the controller of the `damage_source` card to gain life equal to
+/// `damage` if that card has lifelink.
+///
+/// This should be invoked whenever any source deals damage. Lifelink is not a
+/// triggered ability, the life is gained at the same time the damage is dealt.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70215b>
+///
+/// Returns None if the damage source no longer exists.
+pub fn apply_lifelink(
+    game: &mut GameState,
+    source: Source,
+    damage_source: impl ToCardId,
+    damage: Damage,
+) -> Outcome {
+    let card = game.card(damage_source).or_skip()?;
+    if card.has_tag(game, source, CardTag::Lifelink).or_skip()? {
+        let controller = card.controller();
+        gain_life(game, source, controller, damage as LifeValue)?;
+    }
+    outcome::OK
+}
+
+/// Causes the indicated `player` to gain `amount` life.
+///
+/// > 119.9. Some triggered abilities are written, "Whenever [a player] gains
+/// > life, . . . ." Such abilities are treated as though they are written,
+/// > "Whenever a source causes [a player] to gain life, . . . ." If a player
+/// > gains 0 life, no life gain event has occurred, and these abilities won't
+/// > trigger.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1199>
+///
+/// Replacement effects which modify the amount of life gained are applied
+/// first, see [player_queries::life_gain_amount].
+pub fn gain_life(
+    game: &mut GameState,
+    source: Source,
+    player: PlayerName,
+    amount: LifeValue,
+) -> Outcome {
+    let amount = player_queries::life_gain_amount(game, source, player, amount);
+    if amount <= 0 {
+        return outcome::SKIPPED;
+    }
+    debug!("{player:?} gains {amount:?} life");
+    change_team_life_total(game, player, amount);
+    game.add_history_event(HistoryEvent::LifeGained(player, amount));
+    dispatch::game_event(game, |e| &e.life_gained, source, LifeGainedEvent { player, amount })?;
+    game.history_counters_mut(player).life_gained += amount;
+    outcome::OK
+}
+
+/// Causes the indicated `player` to lose `amount` life.
+///
+/// Use [deal_damage] instead for damage dealt to a player.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1193>
+pub fn lose_life(
+    game: &mut GameState,
+    source: Source,
+    player: PlayerName,
+    amount: LifeValue,
+) -> Outcome {
+    if amount <= 0 {
+        return outcome::SKIPPED;
+    }
+    debug!("{player:?} loses {amount:?} life");
+    change_team_life_total(game, player, -amount);
+    game.add_history_event(HistoryEvent::LifeLost(player, amount));
     game.add_state_based_event(StateBasedEvent::LifeTotalDecrease(player));
+    life_lost(game, source, player, amount)?;
     outcome::OK
 }
 
+/// Sets the life total of the indicated `player` to `value`.
+///
+/// > 119.5. If an effect sets a player's life total to a specific number, the
+/// > player gains or loses the necessary amount of life to end up with the new
+/// > total.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R1195>
 pub fn set_life_total(
     game: &mut GameState,
-    _source: Source,
+    source: Source,
     player: PlayerName,
     value: LifeValue,
 ) -> Outcome {
     debug!("Setting life total to {value:?} for {player:?}");
-    game.player_mut(player).life = value;
-    game.add_state_based_event(StateBasedEvent::LifeTotalDecrease(player));
+    let change = value - game.player(player).life;
+    if change > 0 {
+        gain_life(game, source, player, change)
+    } else {
+        lose_life(game, source, player, -change)
+    }
+}
+
+/// Fires events and updates history after the indicated `player` loses
+/// `amount` life, either from damage or from an effect.
+fn life_lost(
+    game: &mut GameState,
+    source: Source,
+    player: PlayerName,
+    amount: LifeValue,
+) -> Outcome {
+    if amount <= 0 {
+        return outcome::OK;
+    }
+    dispatch::game_event(game, |e| &e.life_lost, source, LifeLostEvent { player, amount })?;
+    game.history_counters_mut(player).life_lost += amount;
+    outcome::OK
+}
+
+/// Changes the shared life total of the indicated player's team by `change`.
+///
+/// Each player's life value is kept equal to their team's life total, so a
+/// change for one player is applied to all of their teammates. In games
+/// without teams this only modifies the indicated player.
+fn change_team_life_total(game: &mut GameState, player: PlayerName, change: LifeValue) {
+    for teammate in player_queries::team(game, player) | player {
+        game.player_mut(teammate).life += change;
+    }
+}
+
+/// Gives `count` poison counters to the indicated `player`.
+pub fn add_poison_counters(
+    game: &mut GameState,
+    _source: Source,
+    player: PlayerName,
+    count: u32,
+) -> Outcome {
+    debug!("Adding {count:?} poison counters to {player:?}");
+    game.player_mut(player).counters.add(CounterType::Poison, count);
+    game.add_state_based_event(StateBasedEvent::GainedPoisonCounters(player));
+    outcome::OK
+}
+
+/// Causes the indicated `player` to win the game, e.g. via an effect which
+/// states "you win the game".
+///
+/// > 104.2b. An effect may state that a player wins the game.
+/// <https://yawgatog.com/resources/magic-rules/#R1042b>
+///
+/// Returns None if this player currently can't win the game or if the game is
+/// already over.
+pub fn win_game(game: &mut GameState, source: Source, player: PlayerName) -> Outcome {
+    if matches!(game.status, GameStatus::GameOver { .. })
+        || !player_queries::can_win_game(game, source, player)
+    {
+        return outcome::SKIPPED;
+    }
+
+    // Players on a team win the game together
+    let winners = player_queries::team(game, player) | player;
+    debug!(?winners, "Players won the game");
+    game.status = GameStatus::GameOver { winners };
+    game.add_animation(GameAnimation::GameOver(winners));
+    outcome::OK
+}
+
+/// Causes the indicated `player` to lose the game.
+///
+/// See [lose_game_simultaneously].
+pub fn lose_game(game: &mut GameState, source: Source, player: PlayerName) -> Outcome {
+    lose_game_simultaneously(game, source, EnumSet::only(player))
+}
+
+/// Causes all of the indicated players to lose the game at the same time.
+///
+/// Players who currently can't lose the game are ignored. If a player on a
+/// team loses, their entire team loses. If one or fewer teams remain in the
+/// game afterwards, the game ends. If all players lose simultaneously, the game
+/// is a draw.
+///
+/// > 810.8a. Players win and lose the game only as a team, not as individuals.
+/// > If either player on a team loses the game, the team loses the game.
+/// <https://yawgatog.com/resources/magic-rules/#R8108a>
+///
+/// > 104.4a. If all the players remaining in a game lose simultaneously, the
+/// > game is a draw.
+/// <https://yawgatog.com/resources/magic-rules/#R1044a>
+///
+/// Returns None if no players lost the game.
+pub fn lose_game_simultaneously(
+    game: &mut GameState,
+    source: Source,
+    players: EnumSet<PlayerName>,
+) -> Outcome {
+    if matches!(game.status, GameStatus::GameOver { .. }) {
+        return outcome::SKIPPED;
+    }
+
+    let losers = players
+        .iter()
+        .filter(|&player| {
+            player_queries::all_players(game).contains(player)
+                && player_queries::can_lose_game(game, source, player)
+        })
+        .flat_map(|player| player_queries::team(game, player) | player)
+        .collect::<EnumSet<_>>();
+    if losers.is_empty() {
+        return outcome::SKIPPED;
+    }
+
+    debug!(?losers, "Players lost the game");
+    let remaining = player_queries::all_players(game).difference(losers);
+    if remaining
+        .iter()
+        .next()
+        .map_or(true, |player| player_queries::team(game, player).is_superset(remaining))
+    {
+        game.status = GameStatus::GameOver { winners: remaining };
+        game.add_animation(GameAnimation::GameOver(remaining));
+        return outcome::OK;
+    }
+
+    for loser in losers {
+        leave_game(game, loser)?;
+    }
+    outcome::OK
+}
+
+/// Removes a player who has lost from a multiplayer game.
+///
+/// > 800.4a. When a player leaves the game, all objects (see rule 109) owned by
+/// > that player leave the game and any effects which give that player control
+/// > of any objects or players end. Then, if that player controlled any objects
+/// > on the stack not represented by cards, those objects cease to exist. Then,
+/// > if there are any objects still controlled by that player, those objects
+/// > are exiled.
+/// <https://yawgatog.com/resources/magic-rules/#R8004a>
+fn leave_game(game: &mut GameState, player: PlayerName) -> Outcome {
+    let owned =
+        game.zones.all_cards().filter(|c| c.owner == player).map(|c| c.id).collect::<Vec<_>>();
+    for card_id in owned {
+        game.zones.destroy_card(card_id)?;
+    }
+
+    let control_effects = game
+        .zones
+        .all_cards()
+        .flat_map(|card| {
+            card.control_changing_effects
+                .iter()
+                .filter(|effect| effect.controller == player)
+                .map(move |effect| (effect.event_id, card.id))
+        })
+        .collect::<Vec<_>>();
+    for (event_id, card_id) in control_effects {
+        change_controller::remove_control(game, event_id, card_id)?;
+    }
+
+    let abilities = game
+        .zones
+        .all_stack_abilities()
+        .filter(|ability| ability.controller == player)
+        .map(|ability| ability.id)
+        .collect::<Vec<_>>();
+    for ability_id in abilities {
+        game.zones.remove_stack_ability(ability_id);
+    }
+
+    let controlled = game
+        .zones
+        .all_cards()
+        .filter(|card| card.controller() == player && card.zone != Zone::Exiled)
+        .map(|card| card.id)
+        .collect::<Vec<_>>();
+    for card_id in controlled {
+        move_card::run(game, Source::Game, card_id, Zone::Exiled)?;
+    }
+
+    game.configuration.all_players.remove(player);
+    game.passed.remove(player);
+    if game.priority == player {
+        game.priority = player_queries::next_player_after(game, player);
+    }
     outcome::OK
 }
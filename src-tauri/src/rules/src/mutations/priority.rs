@@ -16,6 +16,8 @@ use data::card_states::zones::ZoneQueries;
 use data::game_states::game_state::GameState;
 use primitives::game_primitives::PlayerName;
 use tracing::instrument;
+use utils::outcome;
+use utils::outcome::Outcome;
 
 use crate::queries::player_queries;
 use crate::resolve_cards::resolve;
@@ -25,18 +27,19 @@ use crate::steps::step;
 ///
 /// Panics if this player does not have priority.
 #[instrument(level = "debug", skip(game))]
-pub fn pass(game: &mut GameState, player: PlayerName) {
+pub fn pass(game: &mut GameState, player: PlayerName) -> Outcome {
     assert_eq!(game.priority, player, "Player {player:?} does not have priority");
     game.passed.insert(player);
     if game.passed.len() == game.configuration.all_players.len() {
         game.passed.clear();
         game.priority = game.active_player();
         if game.stack().is_empty() {
-            step::advance(game);
+            step::advance(game)
         } else {
-            resolve::resolve_top_of_stack(game);
+            resolve::resolve_top_of_stack(game)
         }
     } else {
         game.priority = player_queries::next_player_after(game, game.priority);
+        outcome::OK
     }
 }
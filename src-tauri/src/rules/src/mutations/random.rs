@@ -0,0 +1,211 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Coin flips, die rolls, planar die rolls, and clashes.
+//!
+//! All randomness is drawn from the game's seeded random number generator, so
+//! results are reproduced exactly when a game is replayed from its serialized
+//! representation.
+
+use std::ops::RangeInclusive;
+
+use data::card_states::zones::ZoneQueries;
+use data::events::game_events::{CoinFlippedEvent, DieRolledEvent};
+use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
+use data::game_states::random_result::{ClashReveal, CoinFace, PlanarDieFace, RandomResult};
+use data::prompts::game_update::GameAnimation;
+use data::text_strings::Text;
+use primitives::game_primitives::{HasSource, PlayerName, ALL_POSSIBLE_PLAYERS};
+use rand::Rng;
+use tracing::debug;
+use utils::outcome;
+use utils::outcome::HaltCondition;
+
+use crate::dispatcher::dispatch;
+use crate::mutations::library;
+use crate::prompt_handling::prompts;
+use crate::queries::card_queries;
+
+/// Flips a coin for the [PlayerName] player without a call, for effects which
+/// only care whether the result is heads or tails.
+pub fn flip_coin(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+) -> Result<CoinFace, HaltCondition> {
+    let result = random_face(game);
+    record(game, RandomResult::CoinFlip { player, call: None, result });
+    dispatch::game_event(game, |e| &e.coin_flipped, source.source(), CoinFlippedEvent {
+        player,
+        result,
+        won: None,
+    })?;
+    Ok(result)
+}
+
+/// Prompts the [PlayerName] player to call heads or tails and then flips a
+/// coin. Returns true if the player won the flip.
+///
+/// > 705.2. To flip a coin for an object that cares whether a player wins or
+/// > loses the flip, the affected player calls heads or tails. If the call
+/// > matches the result, that player wins the flip. Otherwise, that player
+/// > loses the flip.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7052>
+pub fn flip_coin_with_call(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+) -> Result<bool, HaltCondition> {
+    let call = prompts::multiple_choice(game, player, Text::CallCoinFlip, vec![
+        CoinFace::Heads,
+        CoinFace::Tails,
+    ]);
+    let result = random_face(game);
+    let won = call == result;
+    record(game, RandomResult::CoinFlip { player, call: Some(call), result });
+    dispatch::game_event(game, |e| &e.coin_flipped, source.source(), CoinFlippedEvent {
+        player,
+        result,
+        won: Some(won),
+    })?;
+    Ok(won)
+}
+
+/// Rolls a die with `sides` sides for the [PlayerName] player, returning a
+/// result between 1 and `sides`.
+///
+/// > 706.2. To roll a die, a player rolls a die with the indicated number of
+/// > faces and the result is the number which appears on the topmost face.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7062>
+pub fn roll_die(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+    sides: u32,
+) -> Result<u32, HaltCondition> {
+    let result = game.rng.gen_range(1..=sides.max(1));
+    record(game, RandomResult::DieRoll { player, sides, result });
+    dispatch::game_event(game, |e| &e.die_rolled, source.source(), DieRolledEvent {
+        player,
+        sides,
+        result,
+    })?;
+    Ok(result)
+}
+
+/// Rolls the planar die for the [PlayerName] player.
+///
+/// This only records the result, see [planechase::roll_planar_die] for the
+/// effects of rolling the planar die.
+///
+/// [planechase::roll_planar_die]: crate::mutations::planechase::roll_planar_die
+pub fn roll_planar_die(game: &mut GameState, player: PlayerName) -> PlanarDieFace {
+    let result = match game.rng.gen_range(1..=6) {
+        1 => PlanarDieFace::Chaos,
+        2 => PlanarDieFace::Planeswalk,
+        _ => PlanarDieFace::Blank,
+    };
+    record(game, RandomResult::PlanarDie { player, result });
+    result
+}
+
+/// Rolls a twenty-sided die for the [PlayerName] player and returns the value
+/// associated with the first range in `table` containing the result, for
+/// cards with effects such as "1-9 | ..., 10-19 | ..., 20 | ...".
+///
+/// Returns `Ok(None)` if no range in the table contains the result.
+pub fn roll_d20<T: Copy>(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+    table: &[(RangeInclusive<u32>, T)],
+) -> Result<Option<T>, HaltCondition> {
+    let result = roll_die(game, source, player, 20)?;
+    Ok(table.iter().find(|(range, _)| range.contains(&result)).map(|(_, value)| *value))
+}
+
+/// The [PlayerName] player clashes with the `opponent` player. Returns true if
+/// `player` won the clash.
+///
+/// Each player reveals the top card of their library and then chooses whether
+/// to put that card on the bottom of their library. A player wins if their
+/// card had a higher mana value than all other cards revealed in the clash.
+pub fn clash(
+    game: &mut GameState,
+    source: impl HasSource,
+    player: PlayerName,
+    opponent: PlayerName,
+) -> Result<bool, HaltCondition> {
+    let source = source.source();
+    let revealed = [player, opponent]
+        .into_iter()
+        .map(|p| {
+            let card = game.library(p).back().copied();
+            let mana_value = card.and_then(|id| card_queries::mana_value(game, source, id));
+            ClashReveal { player: p, card, mana_value: mana_value.unwrap_or_default() }
+        })
+        .collect::<Vec<_>>();
+
+    // Cards are only revealed for the duration of the clash
+    let mut previously_revealed = vec![];
+    for reveal in &revealed {
+        if let Some(card) = reveal.card.and_then(|id| game.card_mut(id)) {
+            previously_revealed.push((card.id, card.revealed_to));
+            card.revealed_to = ALL_POSSIBLE_PLAYERS;
+        }
+    }
+
+    let won = revealed.iter().skip(1).all(|other| revealed[0].mana_value > other.mana_value);
+    debug!(?player, ?opponent, ?won, "Clash");
+    record(game, RandomResult::Clash { player, revealed: revealed.clone(), won });
+
+    for reveal in revealed {
+        let Some(card_id) = reveal.card else {
+            continue;
+        };
+        if prompts::multiple_choice(game, reveal.player, Text::ClashPrompt, vec![
+            Text::LeaveOnTop,
+            Text::PutOnBottom,
+        ]) == Text::PutOnBottom
+        {
+            outcome::execute(|| library::move_to_bottom(game, source, card_id))?;
+            // Moving the card already hid it
+            previously_revealed.retain(|&(id, _)| id != card_id);
+        }
+    }
+
+    for (card_id, revealed_to) in previously_revealed {
+        if let Some(card) = game.card_mut(card_id) {
+            card.revealed_to = revealed_to;
+        }
+    }
+    Ok(won)
+}
+
+fn random_face(game: &mut GameState) -> CoinFace {
+    if game.rng.gen_bool(0.5) {
+        CoinFace::Heads
+    } else {
+        CoinFace::Tails
+    }
+}
+
+fn record(game: &mut GameState, result: RandomResult) {
+    debug!(?result, "Random result");
+    game.add_history_event(HistoryEvent::RandomResult(result.clone()));
+    game.add_animation(GameAnimation::RandomResult(result));
+}
@@ -0,0 +1,124 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::counters::CounterType;
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::events::card_events::LoreCountersAddedEvent;
+use data::game_states::game_state::GameState;
+use data::game_states::state_based_event::StateBasedEvent;
+use primitives::game_primitives::{HasSource, PermanentId, Source};
+use utils::outcome;
+use utils::outcome::{HaltCondition, OrSkip, Outcome};
+
+use crate::dispatcher::dispatch;
+use crate::mutations::permanents;
+use crate::queries::{card_queries, player_queries};
+
+/// Puts `count` lore counters on the [PermanentId] Saga, triggering any
+/// chapter abilities whose chapter number was reached.
+///
+/// > 714.2b. A chapter ability is a triggered ability. "[Roman numeral] --
+/// > [Effect]" means "When one or more lore counters are put onto this Saga,
+/// > if the number of lore counters on it was less than [N] and became at least
+/// > [N], [effect]."
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7142b>
+pub fn add_lore_counters(
+    game: &mut GameState,
+    source: impl HasSource,
+    id: PermanentId,
+    count: u32,
+) -> Outcome {
+    let counters = &mut game.card_mut(id).or_skip()?.counters.other_counters;
+    let before = counters.get(&CounterType::Lore).copied().unwrap_or(0);
+    let after = before + count;
+    counters.insert(CounterType::Lore, after);
+    game.add_state_based_event(StateBasedEvent::SagaChapterChanged(id));
+    dispatch::card_event(
+        game,
+        id,
+        |e| &e.lore_counters_added,
+        source.source(),
+        &LoreCountersAddedEvent { permanent_id: id, before, after },
+    )?;
+    outcome::OK
+}
+
+/// Puts a lore counter on a Saga as it enters the battlefield.
+///
+/// > 714.3a. As a Saga enters the battlefield, its controller puts a lore
+/// > counter on it.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7143a>
+pub fn on_enter_battlefield(game: &mut GameState, id: impl ToCardId) -> Outcome {
+    let card = game.card(id).or_skip()?;
+    if card.saga_chapters.is_empty() {
+        return outcome::OK;
+    }
+    let permanent_id = card.permanent_id().or_skip()?;
+    add_lore_counters(game, Source::Game, permanent_id, 1)
+}
+
+/// Puts a lore counter on each Saga controlled by the active player as their
+/// precombat main phase begins.
+///
+/// > 714.3b. As a player's precombat main phase begins, that player puts a
+/// > lore counter on each Saga they control with one or more chapter abilities.
+/// > This turn-based action doesn't use the stack.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7143b>
+pub fn on_precombat_main(game: &mut GameState) -> Outcome {
+    for player in player_queries::active_team(game) {
+        let sagas = game
+            .battlefield(player)
+            .iter()
+            .copied()
+            .filter(|&id| game.card(id).is_some_and(|card| !card.saga_chapters.is_empty()))
+            .collect::<Vec<_>>();
+        for id in sagas {
+            outcome::execute(|| add_lore_counters(game, Source::Game, id, 1))?;
+        }
+    }
+    outcome::OK
+}
+
+/// Sacrifices the [PermanentId] Saga if its final chapter has been reached
+/// and none of its chapter abilities are waiting to resolve.
+///
+/// Returns true if the Saga was sacrificed.
+///
+/// > 714.4. If the number of lore counters on a Saga permanent with one or more
+/// > chapter abilities is greater than or equal to its final chapter number,
+/// > and it isn't the source of a chapter ability that has triggered but not
+/// > yet left the stack, that Saga's controller sacrifices it. This
+/// > state-based action doesn't use the stack.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7144>
+pub fn sacrifice_if_final_chapter(
+    game: &mut GameState,
+    id: PermanentId,
+) -> Result<bool, HaltCondition> {
+    let card = game.card(id).or_skip()?;
+    let Some(&final_chapter) = card.saga_chapters.iter().max() else {
+        return Ok(false);
+    };
+    let card_id = card.id;
+    let chapter_on_stack =
+        game.zones.all_stack_abilities().any(|ability| ability.ability_id.card_id == card_id);
+    if card_queries::lore_counters(game, id).or_skip()? < final_chapter || chapter_on_stack {
+        return Ok(false);
+    }
+    permanents::sacrifice(game, Source::Game, id)?;
+    Ok(true)
+}
@@ -18,7 +18,7 @@ use data::game_states::game_state::GameState;
 use data::text_strings::Text;
 use primitives::game_primitives::{HasSource, PlayerName, SpellId, Zone};
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::mutations::move_card;
 use crate::play_cards::play_card;
@@ -57,14 +57,17 @@ pub fn choose_new_targets(
     player: PlayerName,
     target: impl ToCardId,
 ) -> Outcome {
-    let card_id = target.to_card_id(game)?;
+    let card_id = target.to_card_id(game).or_skip()?;
     let mut plan = PlayCardPlan {
-        choices: game.card(card_id)?.cast_choices.as_ref()?.clone(),
+        choices: game.card(card_id).or_skip()?.cast_choices.as_ref().or_skip()?.clone(),
         targets: vec![],
+        target_amounts: vec![],
         mana_payment: ManaPaymentPlan::default(),
     };
 
     play_card::select_targets(game, player, card_id, &mut plan, Text::SelectNewTargets);
-    game.card_mut(card_id)?.targets = plan.targets;
+    let card = game.card_mut(card_id).or_skip()?;
+    card.targets = plan.targets;
+    card.target_amounts = plan.target_amounts;
     outcome::OK
 }
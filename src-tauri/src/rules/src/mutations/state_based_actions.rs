@@ -14,16 +14,18 @@
 
 use data::card_states::zones::ZoneQueries;
 use data::events::game_events;
-use data::game_states::game_state::{GameState, GameStatus};
+use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
 use data::game_states::state_based_event::StateBasedEvent;
 use data::player_states::player_state::PlayerQueries;
 use enumset::EnumSet;
 use primitives::game_primitives::{Source, StackItemId, Zone};
 use tracing::instrument;
 use utils::outcome;
+use utils::outcome::{HaltCondition, OrSkip};
 
 use crate::dispatcher::dispatch;
-use crate::mutations::move_card;
+use crate::mutations::{battles, counters, expiring_effects, move_card, players, sagas};
 use crate::queries::{card_queries, player_queries};
 
 /// Runs actions immediately before a player receives priority
@@ -31,9 +33,9 @@ use crate::queries::{card_queries, player_queries};
 /// Returns true if any state-based actions fired or any triggers were put on
 /// the stack.
 #[instrument(name = "state_based_actions_run", level = "debug", skip(game))]
-pub fn on_will_receive_priority(game: &mut GameState) -> bool {
+pub fn on_will_receive_priority(game: &mut GameState) -> Result<bool, HaltCondition> {
     let mut anything_happened = false;
-    anything_happened |= check_state_triggered_abilities(game);
+    anything_happened |= check_state_triggered_abilities(game)?;
 
     // > 704.3. Whenever a player would get priority (see rule 117, "Timing and
     // > Priority"), the game checks for any of the listed conditions for
@@ -46,7 +48,7 @@ pub fn on_will_receive_priority(game: &mut GameState) -> bool {
     // > to be put on the stack, the appropriate player gets priority
     // <https://yawgatog.com/resources/magic-rules/#R7043>
     loop {
-        let applied_action = state_based_actions(game);
+        let applied_action = state_based_actions(game)?;
         anything_happened |= applied_action;
         let ability_triggered = add_triggers_to_stack(game);
         anything_happened |= ability_triggered;
@@ -55,11 +57,11 @@ pub fn on_will_receive_priority(game: &mut GameState) -> bool {
         }
     }
 
-    anything_happened
+    Ok(anything_happened)
 }
 
 /// Runs state-based actions. Returns 'Some(true)' if any action was performed.
-fn state_based_actions(game: &mut GameState) -> bool {
+fn state_based_actions(game: &mut GameState) -> Result<bool, HaltCondition> {
     // > 117.5. Each time a player would get priority, the game first performs all
     // > applicable state-based actions as a single event (see rule 704,
     // > "State-Based Actions"), then repeats this process until no state-based
@@ -73,6 +75,8 @@ fn state_based_actions(game: &mut GameState) -> bool {
             break;
         }
 
+        let mut check_expiring_effects = false;
+
         for event in events {
             outcome::execute(|| {
                 match event {
@@ -86,22 +90,31 @@ fn state_based_actions(game: &mut GameState) -> bool {
                         lost.insert(player);
                         performed_action = true;
                     }
-                    StateBasedEvent::GainedPoisonCounters(_) => {}
+                    StateBasedEvent::GainedPoisonCounters(player) => {
+                        // > 704.5c. If a player has ten or more poison counters, that player
+                        // > loses the game.
+                        // <https://yawgatog.com/resources/magic-rules/#R7045c>
+                        if player_queries::poison_counters(game, player) >= 10 {
+                            lost.insert(player);
+                            performed_action = true;
+                        }
+                    }
                     StateBasedEvent::TokenLeftBattlefield(card_id) => {
                         game.zones.destroy_card(card_id)?;
                         performed_action = true;
                     }
                     StateBasedEvent::CopyLeftStackOrBattlefield(_) => {}
                     StateBasedEvent::CreatureToughnessChanged(permanent_id) => {
-                        if card_queries::toughness(game, Source::Game, permanent_id)? <= 0 {
+                        if card_queries::toughness(game, Source::Game, permanent_id).or_skip()? <= 0
+                        {
                             move_card::run(game, Source::Game, permanent_id, Zone::Graveyard)?;
                             performed_action = true;
                         }
                     }
                     StateBasedEvent::CreatureDamaged(permanent_id) => {
-                        let card = game.card(permanent_id)?;
+                        let card = game.card(permanent_id).or_skip()?;
                         if card.damage as i64
-                            >= card_queries::toughness(game, Source::Game, card.id)?
+                            >= card_queries::toughness(game, Source::Game, card.id).or_skip()?
                         {
                             move_card::run(game, Source::Game, card.id, Zone::Graveyard)?;
                             performed_action = true;
@@ -111,20 +124,46 @@ fn state_based_actions(game: &mut GameState) -> bool {
                         move_card::run(game, Source::Game, permanent_id, Zone::Graveyard)?;
                         performed_action = true;
                     }
+                    StateBasedEvent::CountersAdded(permanent_id) => {
+                        // > 704.5q. If a permanent has both a +1/+1 counter and a -1/-1
+                        // > counter on it, N +1/+1 and N -1/-1 counters are removed from
+                        // > it, where N is the smaller of the number of +1/+1 and -1/-1
+                        // > counters on it.
+                        //
+                        // <https://yawgatog.com/resources/magic-rules/#R7045q>
+                        performed_action |= counters::annihilate(game, permanent_id).or_skip()?;
+                    }
                     StateBasedEvent::PlaneswalkerLostLoyalty(_) => {}
+                    StateBasedEvent::BattleLostDefense(permanent_id) => {
+                        performed_action |=
+                            battles::defeat_if_no_defense(game, permanent_id)?;
+                    }
                     StateBasedEvent::LegendaryPermanentEntered(_) => {}
+                    StateBasedEvent::SagaChapterChanged(permanent_id) => {
+                        performed_action |=
+                            sagas::sacrifice_if_final_chapter(game, permanent_id)?;
+                    }
+                    StateBasedEvent::ObjectIdChanged(_) | StateBasedEvent::ControllerChanged(_) => {
+                        check_expiring_effects = true;
+                    }
                 }
                 outcome::OK
-            });
+            })?;
+        }
+
+        if check_expiring_effects {
+            // Not a state-based action, but this is a convenient time to end
+            // effects whose duration references an object which no longer
+            // exists or whose controller has changed.
+            expiring_effects::revert_expired(game)?;
         }
     }
 
     if !lost.is_empty() {
-        game.status =
-            GameStatus::GameOver { winners: player_queries::all_players(game).difference(lost) };
+        outcome::execute(|| players::lose_game_simultaneously(game, Source::Game, lost))?;
     }
 
-    performed_action
+    Ok(performed_action)
 }
 
 /// Moves triggered abilities to the stack.
@@ -136,12 +175,17 @@ fn add_triggers_to_stack(game: &mut GameState) -> bool {
     // > state-based actions are performed and no abilities trigger.
     // <https://yawgatog.com/resources/magic-rules/#R1175>
     let mut triggered = vec![];
+    let mut ability_ids = vec![];
     for ability in game.zones.all_stack_abilities_mut() {
         if !ability.placed_on_stack {
             ability.placed_on_stack = true;
             triggered.push(StackItemId::StackAbility(ability.id));
+            ability_ids.push(ability.ability_id);
         }
     }
+    for ability_id in ability_ids {
+        game.add_history_event(HistoryEvent::AbilityTriggered(ability_id));
+    }
     let ability_triggered = !triggered.is_empty();
     game.zones.add_abilities_to_stack(triggered);
     ability_triggered
@@ -169,16 +213,17 @@ fn add_triggers_to_stack(game: &mut GameState) -> bool {
 /// probably the only reasonable way to handle it.
 ///
 /// Returns true if an ability fired.
-pub fn check_state_triggered_abilities(game: &mut GameState) -> bool {
+pub fn check_state_triggered_abilities(game: &mut GameState) -> Result<bool, HaltCondition> {
     if !game.events.state_triggered_ability.callbacks.is_empty()
         && !game.checking_state_triggered_abilities
     {
         // Only run the check if it's not already running to prevent infinite loops.
         game.checking_state_triggered_abilities = true;
-        dispatch::game_event(game, |e| &e.state_triggered_ability, Source::Game, ());
+        let result = dispatch::game_event(game, |e| &e.state_triggered_ability, Source::Game, ());
         game.checking_state_triggered_abilities = false;
-        true
+        result?;
+        Ok(true)
     } else {
-        false
+        Ok(false)
     }
 }
@@ -20,8 +20,12 @@ use data::events::game_event::GameEvent;
 use data::game_states::game_state::GameState;
 use enumset::EnumSet;
 use primitives::game_primitives::{
-    AbilityId, EventId, HasSource, PermanentId, PlayerName, Source, StackItemId,
+    AbilityId, EventId, HasSource, PermanentId, PlayerName, Source, StackItemId, Zone,
 };
+use utils::outcome;
+use utils::outcome::Outcome;
+
+use crate::queries::planechase_queries;
 
 /// Extensions to event delegates for triggering abilities.
 ///
@@ -64,7 +68,7 @@ pub trait TriggerExt<TArg> {
             + Send
             + Sync
             + 'static,
-        effect: impl Fn(&mut GameState, EventContext) + Copy + Send + Sync + 'static,
+        effect: impl Fn(&mut GameState, EventContext) -> Outcome + Copy + Send + Sync + 'static,
     );
 
     /// Equivalent to [Self::add_trigger], but only triggers if the ability is
@@ -78,6 +82,21 @@ pub trait TriggerExt<TArg> {
             + Sync
             + 'static,
     );
+
+    /// Trigger an ability of a plane or phenomenon card if a predicate is true.
+    ///
+    /// Fires only while this card is the face-up card of the planar deck in a
+    /// Planechase game. The triggered ability is controlled by the planar
+    /// controller.
+    fn add_plane_trigger(
+        &mut self,
+        scope: AbilityScope,
+        predicate: impl Fn(&GameState, EventContext, &TArg) -> Option<bool>
+            + Copy
+            + Send
+            + Sync
+            + 'static,
+    );
 }
 
 impl<TArg: Clone> TriggerExt<TArg> for GameEvent<TArg> {
@@ -94,6 +113,7 @@ impl<TArg: Clone> TriggerExt<TArg> for GameEvent<TArg> {
             if predicate(g, c, arg) == Some(true) {
                 trigger_ability(g, c.this, c.controller);
             }
+            outcome::OK
         });
     }
 
@@ -106,7 +126,7 @@ impl<TArg: Clone> TriggerExt<TArg> for GameEvent<TArg> {
             + Send
             + Sync
             + 'static,
-        effect: impl Fn(&mut GameState, EventContext) + Copy + Send + Sync + 'static,
+        effect: impl Fn(&mut GameState, EventContext) -> Outcome + Copy + Send + Sync + 'static,
     ) {
         self.add_effect(context, EnumSet::all(), move |g, c, arg| {
             if g.has_card(permanent_id)
@@ -117,6 +137,7 @@ impl<TArg: Clone> TriggerExt<TArg> for GameEvent<TArg> {
                 ability.custom_effect = Some(StackAbilityCustomEffect::new(c.event_id, effect));
                 g.ability_state.fired_one_time_effects.insert(context.event_id);
             }
+            outcome::OK
         });
     }
 
@@ -133,6 +154,26 @@ impl<TArg: Clone> TriggerExt<TArg> for GameEvent<TArg> {
             if predicate(g, c, arg) == Some(true) && !is_ability_on_stack(g, c.this) {
                 trigger_ability(g, c.this, c.controller);
             }
+            outcome::OK
+        });
+    }
+
+    fn add_plane_trigger(
+        &mut self,
+        scope: AbilityScope,
+        predicate: impl Fn(&GameState, EventContext, &TArg) -> Option<bool>
+            + Copy
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        self.add_ability(scope, Zone::Command, move |g, c, arg| {
+            if planechase_queries::is_active_plane(g, c.this.card_id)
+                && predicate(g, c, arg) == Some(true)
+            {
+                trigger_ability(g, c.this, planechase_queries::planar_controller(g));
+            }
+            outcome::OK
         });
     }
 }
@@ -0,0 +1,98 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use data::game_states::turn_schedule::SkippedTurnPart;
+use primitives::game_primitives::{HasSource, PlayerName};
+use tracing::debug;
+use utils::outcome;
+use utils::outcome::Outcome;
+
+/// Gives the [PlayerName] player an extra turn after this one.
+///
+/// > 500.7. Some effects can give a player extra turns. They do this by adding
+/// > the turns directly after the specified turn. ... The most recently created
+/// > turn will be taken first.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R5007>
+pub fn take_extra_turn(
+    game: &mut GameState,
+    _source: impl HasSource,
+    player: PlayerName,
+) -> Outcome {
+    debug!("Adding an extra turn for {player:?}");
+    game.turn_schedule.extra_turns.push(player);
+    outcome::OK
+}
+
+/// Adds an additional combat phase followed by an additional main phase after
+/// the current main phase, e.g. "After this main phase, there is an additional
+/// combat phase followed by an additional main phase."
+///
+/// > 500.8. Some effects can add phases to a turn. They do this by adding the
+/// > phases directly after the specified phase.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R5008>
+pub fn add_combat_phase(game: &mut GameState, _source: impl HasSource) -> Outcome {
+    game.turn_schedule.additional_combat_phases += 1;
+    outcome::OK
+}
+
+/// Causes the [PlayerName] player to skip their next turn.
+pub fn skip_next_turn(
+    game: &mut GameState,
+    _source: impl HasSource,
+    player: PlayerName,
+) -> Outcome {
+    game.turn_schedule.skipped.push((player, SkippedTurnPart::Turn));
+    outcome::OK
+}
+
+/// Causes the [PlayerName] player to skip their next combat phase.
+pub fn skip_next_combat_phase(
+    game: &mut GameState,
+    _source: impl HasSource,
+    player: PlayerName,
+) -> Outcome {
+    game.turn_schedule.skipped.push((player, SkippedTurnPart::CombatPhase));
+    outcome::OK
+}
+
+/// Causes the [PlayerName] player to skip their next instance of the given
+/// step, e.g. "skip your next draw step".
+///
+/// Returns an error if this is a combat step, use [skip_next_combat_phase]
+/// instead.
+pub fn skip_next_step(
+    game: &mut GameState,
+    _source: impl HasSource,
+    player: PlayerName,
+    step: GamePhaseStep,
+) -> Outcome {
+    if !matches!(
+        step,
+        GamePhaseStep::Untap
+            | GamePhaseStep::Upkeep
+            | GamePhaseStep::Draw
+            | GamePhaseStep::PreCombatMain
+            | GamePhaseStep::PostCombatMain
+            | GamePhaseStep::EndStep
+            | GamePhaseStep::Cleanup
+    ) {
+        return outcome::error(format!("Cannot skip combat step {step:?}"));
+    }
+    game.turn_schedule.skipped.push((player, SkippedTurnPart::Step(step)));
+    outcome::OK
+}
@@ -18,18 +18,18 @@ use data::card_states::play_card_plan::{ManaPaymentPlan, PlayCardPlan};
 use data::card_states::zones::ZoneQueries;
 use data::game_states::game_state::GameState;
 use data::printed_cards::card_subtypes::LandType;
-use data::printed_cards::mana_cost::ManaCostItem;
-use primitives::game_primitives::{CardId, HasController, ManaColor, PermanentId, Source};
+use data::printed_cards::mana_cost::{ManaCost, ManaCostItem};
+use enumset::EnumSet;
+use primitives::game_primitives::{
+    CardId, HasController, ManaColor, PermanentId, PlayerName, Source,
+};
 use tracing::instrument;
-use utils::outcome;
-use utils::outcome::Outcome;
 
-use crate::queries::card_queries;
+use crate::queries::{card_queries, combat_queries};
 
-// Map from mana colors to lists of card id which can produce that color of mana
-// and number of land subtypes that card has.
-type SubtypeCount = usize;
-type LandAbilityMap = BTreeMap<ManaColor, Vec<(PermanentId, SubtypeCount)>>;
+/// Map from permanents which can currently produce mana to the colors of mana
+/// they can produce.
+pub type ManaSources = BTreeMap<PermanentId, EnumSet<ManaColor>>;
 
 /// Builds a plan for paying a spell's mana costs.
 ///
@@ -38,6 +38,11 @@ type LandAbilityMap = BTreeMap<ManaColor, Vec<(PermanentId, SubtypeCount)>>;
 /// paid. A [PlayCardPlan] is provided to describe choices the user made
 /// while putting this spell on the stack.
 ///
+/// Colored costs are paid first, preferring sources which produce the fewest
+/// colors. Generic costs are then paid preferring sources which only produce
+/// colors this spell needs, in order to leave sources of other colors
+/// available for future spells.
+///
 /// None is returned if the planner failed to find a legal combination of
 /// choices which would result in this card's mana cost being paid.
 #[instrument(level = "trace", skip_all)]
@@ -48,72 +53,108 @@ pub fn mana_payment(
     plan: &PlayCardPlan,
 ) -> Option<ManaPaymentPlan> {
     let controller = game.card(card_id)?.controller();
+    let cost = card_queries::mana_cost_for_casting_card(game, card_id, plan)?;
+    pay_cost(game, controller, cost)
+}
 
-    let mut lands: LandAbilityMap = BTreeMap::new();
-    for card in game.battlefield(controller) {
-        add_land_to_map(game, *card, &mut lands, ManaColor::White, LandType::Plains);
-        add_land_to_map(game, *card, &mut lands, ManaColor::Blue, LandType::Island);
-        add_land_to_map(game, *card, &mut lands, ManaColor::Black, LandType::Swamp);
-        add_land_to_map(game, *card, &mut lands, ManaColor::Red, LandType::Mountain);
-        add_land_to_map(game, *card, &mut lands, ManaColor::Green, LandType::Forest);
-    }
-    lands.values_mut().for_each(|v| v.sort_by_key(|(_, subtypes)| *subtypes));
-
+/// Equivalent to [mana_payment], but selects from a precomputed set of
+/// [ManaSources] for the spell's controller instead of querying the
+/// battlefield.
+pub fn mana_payment_from_sources(
+    game: &GameState,
+    _source: Source,
+    card_id: CardId,
+    plan: &PlayCardPlan,
+    sources: &ManaSources,
+) -> Option<ManaPaymentPlan> {
     let cost = card_queries::mana_cost_for_casting_card(game, card_id, plan)?;
+    select_sources(sources.clone(), cost)
+}
+
+/// Builds a plan for the [PlayerName] player to pay an arbitrary [ManaCost],
+/// e.g. the cost of an activated ability.
+///
+/// Uses the same source selection strategy as [mana_payment]. Returns None if
+/// the cost cannot be paid.
+pub fn pay_cost(
+    game: &GameState,
+    controller: PlayerName,
+    cost: ManaCost,
+) -> Option<ManaPaymentPlan> {
+    select_sources(mana_sources(game, controller), cost)
+}
+
+/// Selects which of the provided [ManaSources] to use to pay a [ManaCost].
+fn select_sources(mut sources: ManaSources, cost: ManaCost) -> Option<ManaPaymentPlan> {
+    let needed = cost
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ManaCostItem::Colored(color) => Some(*color),
+            _ => None,
+        })
+        .collect::<EnumSet<_>>();
+
+    let mut items = cost.items;
+    items.sort();
     let mut result = ManaPaymentPlan::default();
-    for item in cost.items {
-        add_land_for_item(&mut result, &mut lands, item)?;
+    for item in items {
+        let land = match item {
+            ManaCostItem::Colored(color) => sources
+                .iter()
+                .filter(|(_, colors)| colors.contains(color))
+                .min_by_key(|(_, colors)| colors.len())
+                .map(|(&id, _)| id)?,
+            ManaCostItem::Generic => sources
+                .iter()
+                .min_by_key(|(_, colors)| (!needed.is_superset(**colors), colors.len()))
+                .map(|(&id, _)| id)?,
+            _ => return None,
+        };
+        sources.remove(&land);
+        result.basic_land_abilities_to_activate.push(land);
     }
 
     Some(result)
 }
 
-fn add_land_to_map(
-    game: &GameState,
-    land_id: PermanentId,
-    lands: &mut LandAbilityMap,
-    color: ManaColor,
-    subtype: LandType,
-) -> Outcome {
-    if game.card(land_id)?.tapped_state.is_tapped() {
-        return outcome::OK;
-    }
-    let subtypes = card_queries::land_subtypes(game, Source::Game, land_id)?;
-    if subtypes.contains(subtype) {
-        lands.entry(color).or_default().push((land_id, subtypes.len()));
+/// Returns the untapped permanents controlled by `player` which can produce
+/// mana, along with the colors each can produce.
+pub fn mana_sources(game: &GameState, player: PlayerName) -> ManaSources {
+    let mut result = ManaSources::new();
+    for &land_id in game.battlefield(player) {
+        if game.card(land_id).map_or(true, |card| card.tapped_state.is_tapped()) {
+            continue;
+        }
+        if combat_queries::has_summoning_sickness(game, Source::Game, land_id) != Some(false) {
+            // The mana ability of a land creature has {T} in its cost.
+            continue;
+        }
+        let Some(subtypes) = card_queries::land_subtypes(game, Source::Game, land_id) else {
+            continue;
+        };
+        let colors = subtypes.iter().filter_map(basic_land_color).collect::<EnumSet<_>>();
+        if !colors.is_empty() {
+            result.insert(land_id, colors);
+        }
     }
-    outcome::OK
+    result
 }
 
-fn add_land_for_item(
-    result: &mut ManaPaymentPlan,
-    lands: &mut LandAbilityMap,
-    item: ManaCostItem,
-) -> Option<()> {
-    match item {
-        ManaCostItem::Colored(color) => {
-            // We prioritize tapping lands with fewer subtypes first.
-            return if let Some((land, _)) = lands.get_mut(&color).and_then(|v| v.pop()) {
-                result.basic_land_abilities_to_activate.push(land);
-                Some(())
-            } else {
-                None
-            };
-        }
-        ManaCostItem::Generic => {
-            let mut counts =
-                lands.iter().map(|(color, vec)| (vec.len(), *color)).collect::<Vec<_>>();
-            // We prioritize land types which the player controls more of
-            // first.
-            counts.sort_by(|a, b| b.cmp(a));
-            for (_, color) in counts {
-                if let Some((land, _)) = lands.get_mut(&color).and_then(|v| v.pop()) {
-                    result.basic_land_abilities_to_activate.push(land);
-                    return Some(());
-                }
-            }
-            None
-        }
+/// Returns the color of mana produced by the intrinsic ability of a basic land
+/// type.
+///
+/// > 305.6. An object with the land card type and a basic land type has the
+/// > intrinsic ability "{T}: Add [mana symbol]," even if the text box
+/// > doesn't actually contain that text or the object has no text box.
+/// <https://yawgatog.com/resources/magic-rules/#R3056>
+fn basic_land_color(land_type: LandType) -> Option<ManaColor> {
+    match land_type {
+        LandType::Plains => Some(ManaColor::White),
+        LandType::Island => Some(ManaColor::Blue),
+        LandType::Swamp => Some(ManaColor::Black),
+        LandType::Mountain => Some(ManaColor::Red),
+        LandType::Forest => Some(ManaColor::Green),
         _ => None,
     }
 }
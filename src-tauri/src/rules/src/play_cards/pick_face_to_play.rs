@@ -21,7 +21,9 @@ use data::printed_cards::printed_card::PrintedCardFace;
 use enumset::EnumSet;
 use primitives::game_primitives::{CardId, CardType, HasController, PlayerName, Source};
 
-use crate::queries::player_queries;
+use crate::legality::illegal_action::IllegalActionReason;
+use crate::legality::priority_window::PriorityWindow;
+use crate::queries::{card_queries, player_queries};
 
 /// Returns a list of [PlayCardPlan] options describing valid choices of faces
 /// and timings to play of the indicated card.
@@ -33,13 +35,25 @@ pub fn play_as(
     player: PlayerName,
     source: Source,
     card_id: CardId,
+) -> Vec<PlayCardPlan> {
+    play_as_in_window(game, &PriorityWindow::default(), player, source, card_id)
+}
+
+/// Equivalent to [play_as], reusing the timing checks cached in a
+/// [PriorityWindow].
+pub fn play_as_in_window(
+    game: &GameState,
+    window: &PriorityWindow,
+    player: PlayerName,
+    _source: Source,
+    card_id: CardId,
 ) -> Vec<PlayCardPlan> {
     let mut valid_faces = vec![];
     let Some(card) = game.card(card_id) else {
         return vec![];
     };
 
-    if let Some(play) = can_play_as(game, card, &card.printed().face) {
+    if let Some(play) = can_play_as(game, window, card, &card.printed().face) {
         valid_faces.push(play);
     }
 
@@ -47,34 +61,110 @@ pub fn play_as(
     | (CardLayout::ModalDfc, Some(face_b))
     | (CardLayout::Adventure, Some(face_b)) = (card.printed().layout, &card.printed().face_b)
     {
-        if let Some(play) = can_play_as(game, card, face_b) {
-            valid_faces.push(play);
+        // A card which is on an adventure can't be cast as an Adventure again.
+        let on_an_adventure = card_queries::on_an_adventure(game, card_id).is_some();
+        if !(card.printed().layout == CardLayout::Adventure && on_an_adventure) {
+            if let Some(play) = can_play_as(game, window, card, face_b) {
+                valid_faces.push(play);
+            }
         }
     };
 
     valid_faces.into_iter().map(|play_as| PlayCardPlan::new(player, play_as)).collect()
 }
 
+/// Returns a list of [PlayCardPlan] options describing the faces of the
+/// indicated card which could be cast while another spell or ability is
+/// resolving, e.g. via cascade.
+///
+/// Timing restrictions are ignored when a spell is cast during the resolution
+/// of another spell or ability, but lands cannot be cast.
+///
+/// > 608.2g. If an effect allows a player to take an action later during the
+/// > resolution, the player does so at that time. Casting a spell this way
+/// > ignores timing permissions.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6082g>
+pub fn cast_during_resolution(
+    game: &GameState,
+    player: PlayerName,
+    card_id: CardId,
+) -> Vec<PlayCardPlan> {
+    let Some(card) = game.card(card_id) else {
+        return vec![];
+    };
+
+    let mut faces = vec![&card.printed().face];
+    if let (CardLayout::Split, Some(face_b))
+    | (CardLayout::ModalDfc, Some(face_b))
+    | (CardLayout::Adventure, Some(face_b)) = (card.printed().layout, &card.printed().face_b)
+    {
+        faces.push(face_b);
+    }
+
+    faces
+        .into_iter()
+        .map(can_play_as_for_types)
+        .filter(|play_as| play_as.timing != PlayCardTiming::Land)
+        .map(|play_as| PlayCardPlan::new(player, play_as))
+        .collect()
+}
+
+/// Returns a list of [PlayCardPlan] options for casting the indicated
+/// transforming double-faced card "transformed", i.e. as its back face, while
+/// another spell or ability is resolving.
+///
+/// > 712.14. If an effect allows a player to cast a transforming double-faced
+/// > card "transformed," the back face's characteristics are evaluated to
+/// > determine if it can be cast, and the resulting spell has only the
+/// > characteristics of its back face.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R71214>
+pub fn cast_transformed_during_resolution(
+    game: &GameState,
+    player: PlayerName,
+    card_id: CardId,
+) -> Vec<PlayCardPlan> {
+    let Some(card) = game.card(card_id) else {
+        return vec![];
+    };
+
+    let (CardLayout::Transform | CardLayout::Battle, Some(face_b)) =
+        (card.printed().layout, &card.printed().face_b)
+    else {
+        return vec![];
+    };
+
+    let play_as = can_play_as_for_types(face_b);
+    if play_as.timing == PlayCardTiming::Land {
+        return vec![];
+    }
+    vec![PlayCardPlan::new(player, play_as)]
+}
+
 /// Returns a [CanPlayAs] indicating whether a [PlayerName] can play a given
 /// [PrintedCardFace] of a [CardState] in the current [GameState].
-fn can_play_as(game: &GameState, card: &CardState, face: &PrintedCardFace) -> Option<PlayAs> {
-    let player = card.controller();
+fn can_play_as(
+    game: &GameState,
+    window: &PriorityWindow,
+    card: &CardState,
+    face: &PrintedCardFace,
+) -> Option<PlayAs> {
+    let primitives = window.player(game, card.controller());
     let result = can_play_as_for_types(face);
     match result.timing {
         PlayCardTiming::Land => {
-            if in_main_phase_with_stack_empty(game, player)
-                && player_queries::land_plays_remaining(game, player) > 0
-            {
+            if primitives.sorcery_timing && primitives.has_land_plays {
                 return Some(result);
             }
         }
         PlayCardTiming::Instant => {
-            if game.priority == player {
+            if primitives.has_priority {
                 return Some(result);
             }
         }
         PlayCardTiming::Sorcery => {
-            if in_main_phase_with_stack_empty(game, player) {
+            if primitives.sorcery_timing {
                 return Some(result);
             }
         }
@@ -83,12 +173,39 @@ fn can_play_as(game: &GameState, card: &CardState, face: &PrintedCardFace) -> Op
     None
 }
 
-/// Returns true if the indicated player is currently the active player, in
+/// Explains why no face of the indicated card can currently be played based on
+/// its timing restrictions.
+///
+/// Returns None if some face of this card can be played at the current time.
+pub fn timing_restriction(
+    game: &GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+) -> Option<IllegalActionReason> {
+    if !play_as(game, player, source, card_id).is_empty() {
+        return None;
+    }
+
+    let card = game.card(card_id)?;
+    let controller = card.controller();
+    Some(match can_play_as_for_types(&card.printed().face).timing {
+        PlayCardTiming::Land if in_main_phase_with_stack_empty(game, controller) => {
+            IllegalActionReason::NoLandPlaysRemaining
+        }
+        PlayCardTiming::Land | PlayCardTiming::Sorcery => {
+            IllegalActionReason::RequiresSorceryTiming
+        }
+        PlayCardTiming::Instant => IllegalActionReason::NotYourPriority,
+    })
+}
+
+/// Returns true if the indicated player is currently on the active team, in
 /// their main phase, with the stack empty, while they have priority.
-fn in_main_phase_with_stack_empty(game: &GameState, player: PlayerName) -> bool {
+pub fn in_main_phase_with_stack_empty(game: &GameState, player: PlayerName) -> bool {
     game.stack().is_empty()
         && game.step.is_main_phase()
-        && game.turn.active_player == player
+        && player_queries::active_team(game).contains(player)
         && game.priority == player
 }
 
@@ -18,22 +18,30 @@ use color_eyre::owo_colors::OwoColorize;
 use data::card_definitions::ability_definition::{Ability, AbilityType};
 use data::card_definitions::definitions;
 use data::card_states::iter_matching::IterMatching;
-use data::card_states::play_card_plan::{PlayCardChoices, PlayCardPlan, PlayCardTiming};
+use data::card_states::play_card_plan::{
+    AlternativeCost, AlternativeCostKind, CastSpellPlanAdditionalChoice, ManaPaymentPlan,
+    OptionalCostKind, PlayCardChoices, PlayCardPlan, PlayCardTiming,
+};
 use data::card_states::zones::ZoneQueries;
-use data::game_states::game_state::GameState;
+use data::game_states::game_state::{GameOperationMode, GameState};
+use data::player_states::player_state::{PlayerQueries, PlayerType};
 use data::prompts::entity_choice_prompt::Choice;
+use data::prompts::pay_mana_prompt::PayManaPrompt;
 use data::text_strings::Text;
 use either::Either;
 use primitives::game_primitives::{
     AbilityId, CardId, EntityId, HasController, PlayerName, Source, Zone,
 };
 use tracing::instrument;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::core::debug_snapshot;
+use crate::legality::illegal_action::IllegalActionReason;
+use crate::legality::priority_window::PriorityWindow;
 use crate::planner::spell_planner;
 use crate::play_cards::{pick_face_to_play, play_card_executor};
 use crate::prompt_handling::prompts;
+use crate::queries::card_queries;
 /// Plays a card.
 ///
 /// This will prompt the player for all required choices to play the card, and
@@ -46,12 +54,137 @@ pub fn execute(
 ) -> Outcome {
     let mut plan = select_face(game, player, source, card_id);
     select_modes(game, player, card_id, &mut plan);
+    select_alternative_cost(game, player, source, card_id, &mut plan);
+    select_optional_costs(game, player, source, card_id, &mut plan);
     select_targets(game, player, card_id, &mut plan, Text::SelectTarget);
-    plan.mana_payment = spell_planner::mana_payment(game, source, card_id, &plan)
-        .expect("Unable to pay mana for card");
+    plan.mana_payment = select_mana_payment(game, player, source, card_id, &plan);
     play_card_executor::execute_plan(game, player, card_id, source, plan)
 }
 
+/// Casts the [CardId] card without paying its mana cost while another spell or
+/// ability is resolving, e.g. via cascade or discover.
+///
+/// The player is prompted for all required choices to cast the card. Timing
+/// restrictions are ignored, but the card must have a legal set of targets.
+/// Priority is not modified.
+///
+/// Returns None if the card cannot be cast in this way.
+pub fn cast_without_paying_mana_cost(
+    game: &mut GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+) -> Outcome {
+    let plans = pick_face_to_play::cast_during_resolution(game, player, card_id);
+    let plan = plans_without_paying_mana_cost(game, source, card_id, plans).next().or_skip()?;
+    execute_without_paying_mana_cost(game, player, source, card_id, plan)
+}
+
+/// Returns true if the [PlayerName] player could cast the [CardId] card
+/// without paying its mana cost while another spell or ability is resolving.
+///
+/// See [cast_without_paying_mana_cost].
+pub fn can_cast_without_paying_mana_cost(
+    game: &GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+) -> bool {
+    let plans = pick_face_to_play::cast_during_resolution(game, player, card_id);
+    plans_without_paying_mana_cost(game, source, card_id, plans).next().is_some()
+}
+
+/// Casts the [CardId] transforming double-faced card transformed without
+/// paying its mana cost while another spell or ability is resolving, e.g. when
+/// a Siege is defeated.
+///
+/// Returns None if the card cannot be cast in this way.
+pub fn cast_transformed_without_paying_mana_cost(
+    game: &mut GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+) -> Outcome {
+    let plans = pick_face_to_play::cast_transformed_during_resolution(game, player, card_id);
+    let plan = plans_without_paying_mana_cost(game, source, card_id, plans).next().or_skip()?;
+    execute_without_paying_mana_cost(game, player, source, card_id, plan)
+}
+
+/// Returns true if the [PlayerName] player could cast the [CardId] card
+/// transformed without paying its mana cost while another spell or ability is
+/// resolving.
+///
+/// See [cast_transformed_without_paying_mana_cost].
+pub fn can_cast_transformed_without_paying_mana_cost(
+    game: &GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+) -> bool {
+    let plans = pick_face_to_play::cast_transformed_during_resolution(game, player, card_id);
+    plans_without_paying_mana_cost(game, source, card_id, plans).next().is_some()
+}
+
+fn execute_without_paying_mana_cost(
+    game: &mut GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+    mut plan: PlayCardPlan,
+) -> Outcome {
+    select_modes(game, player, card_id, &mut plan);
+    select_optional_costs(game, player, source, card_id, &mut plan);
+    select_targets(game, player, card_id, &mut plan, Text::SelectTarget);
+    plan.mana_payment = select_mana_payment(game, player, source, card_id, &plan);
+    play_card_executor::cast_spell(game, player, card_id, source, plan)
+}
+
+fn plans_without_paying_mana_cost(
+    game: &GameState,
+    source: Source,
+    card_id: CardId,
+    plans: Vec<PlayCardPlan>,
+) -> impl Iterator<Item = PlayCardPlan> + '_ {
+    plans
+        .into_iter()
+        .map(|mut plan| {
+            plan.choices.without_paying_mana_cost = true;
+            plan
+        })
+        .filter(move |plan| {
+            has_valid_modes(game, &PriorityWindow::default(), source, card_id, &mut plan.clone())
+        })
+}
+
+/// Computes a suggested way of paying the mana cost of a card and, for human
+/// players, prompts them to confirm or override this suggestion.
+fn select_mana_payment(
+    game: &mut GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+    plan: &PlayCardPlan,
+) -> ManaPaymentPlan {
+    let suggested = spell_planner::mana_payment(game, source, card_id, plan)
+        .expect("Unable to pay mana for card");
+    let is_human = matches!(game.player(player).player_type, PlayerType::Human(_));
+    let searching = matches!(game.operation_mode, GameOperationMode::AgentSearch(_));
+    if !is_human || searching || suggested.basic_land_abilities_to_activate.is_empty() {
+        return suggested;
+    }
+
+    let cost = card_queries::mana_cost_for_casting_card(game, card_id, plan)
+        .expect("Card has no mana cost");
+    let selected = prompts::pay_mana(game, player, PayManaPrompt {
+        card_id,
+        cost: cost.items,
+        sources: spell_planner::mana_sources(game, player),
+        suggested: suggested.basic_land_abilities_to_activate.clone(),
+        selected: suggested.basic_land_abilities_to_activate,
+    });
+    ManaPaymentPlan { basic_land_abilities_to_activate: selected, mana_abilities: vec![] }
+}
+
 fn select_face(
     game: &mut GameState,
     player: PlayerName,
@@ -77,11 +210,12 @@ fn select_modes(
     assert!(iterator.next().is_none(), "Card cannot have multiple modal abilities");
     drop(iterator);
 
+    let window = PriorityWindow::default();
     let mut valid_choices = vec![];
     for mode in ability.modes() {
         plan.choices.modes.clear();
         plan.choices.modes.push(mode);
-        if has_valid_targets(game, source, card_id, plan) {
+        if has_valid_targets(game, &window, source, card_id, plan) {
             valid_choices.push(mode);
         }
     }
@@ -92,6 +226,111 @@ fn select_modes(
     plan.choices.modes.push(choice);
 }
 
+/// Prompts the player to choose whether to pay an alternative cost (such as
+/// mutate) instead of the mana cost of the [CardId] card, selecting a target
+/// for it if required.
+///
+/// > 601.2b. If the spell has alternative or additional costs that will be
+/// > paid as it's being cast such as buyback or kicker costs (see rules
+/// > 118.8 and 118.9), the player announces their intentions to pay any or
+/// > all of those costs (see rule 601.2f).
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6012b>
+fn select_alternative_cost(
+    game: &mut GameState,
+    prompted_player: PlayerName,
+    source: Source,
+    card_id: CardId,
+    plan: &mut PlayCardPlan,
+) {
+    let Some(alternative_costs) = game.card(card_id).map(|c| c.alternative_costs.clone()) else {
+        return;
+    };
+
+    for alternative in alternative_costs {
+        if alternative.kind == AlternativeCostKind::Foretell {
+            // A foretold card can only be cast from exile by paying its
+            // foretell cost, so no choice is offered.
+            if card_queries::can_cast_foretold(game, prompted_player, card_id) {
+                plan.choices.alternative_cost = Some(alternative.ability_id);
+                return;
+            }
+            continue;
+        }
+
+        let targets = alternative_cost_targets(game, source, card_id, &alternative);
+        plan.choices.alternative_cost = Some(alternative.ability_id);
+        if !targets.is_empty()
+            && can_pay_mana_costs(game, &PriorityWindow::default(), source, card_id, plan)
+            && prompts::multiple_choice(game, prompted_player, Text::MutatePrompt, vec![
+                Text::PayMutate,
+                Text::DoNotPayMutate,
+            ]) == Text::PayMutate
+        {
+            let choices = targets.into_iter().map(|entity_id| Choice { entity_id }).collect();
+            let target =
+                prompts::choose_entity(game, prompted_player, Text::SelectMutateTarget, choices);
+            plan.targets.push(target);
+            return;
+        }
+        plan.choices.alternative_cost = None;
+    }
+}
+
+/// Returns the valid targets for a spell cast using the provided
+/// [AlternativeCost].
+fn alternative_cost_targets(
+    game: &GameState,
+    source: Source,
+    card_id: CardId,
+    alternative: &AlternativeCost,
+) -> Vec<EntityId> {
+    match alternative.kind {
+        AlternativeCostKind::Mutate => {
+            card_queries::mutate_targets(game, source, card_id).map(EntityId::from).collect()
+        }
+        AlternativeCostKind::Foretell => vec![],
+    }
+}
+
+/// Prompts the player to choose which optional additional costs (such as
+/// kicker) they wish to pay for the [CardId] card.
+///
+/// Costs are only offered if the player could still pay the resulting total
+/// mana cost. Multikicker costs are offered repeatedly until the player
+/// declines or can no longer pay.
+fn select_optional_costs(
+    game: &mut GameState,
+    prompted_player: PlayerName,
+    source: Source,
+    card_id: CardId,
+    plan: &mut PlayCardPlan,
+) {
+    let Some(optional_costs) = game.card(card_id).map(|c| c.optional_costs.clone()) else {
+        return;
+    };
+
+    for optional in optional_costs {
+        loop {
+            let choice = CastSpellPlanAdditionalChoice::AdditionalCostChoice(optional.ability_id);
+            plan.choices.additional_choices.push(choice);
+            if !can_pay_mana_costs(game, &PriorityWindow::default(), source, card_id, plan)
+                || prompts::multiple_choice(game, prompted_player, Text::PayKickerPrompt, vec![
+                    Text::PayKicker,
+                    Text::DoNotPayKicker,
+                ]) != Text::PayKicker
+            {
+                plan.choices.additional_choices.pop();
+                break;
+            }
+
+            if optional.kind == OptionalCostKind::Kicker {
+                break;
+            }
+        }
+    }
+}
+
 /// Given a [PlayCardPlan] which has been populated with a set of
 /// [PlayCardChoices] (e.g. selected modes, face to play, etc), prompt the user
 /// to pick targets for the [CardId] card and populate the [PlayCardPlan] with
@@ -110,16 +349,36 @@ pub fn select_targets(
 ) {
     let prompt_lists = targeted_spell_abilities(game, card_id)
         .map(|(s, ability)| {
-            ability
-                .valid_targets(game, &plan.choices, s)
-                .map(|entity_id| Choice { entity_id })
-                .collect::<Vec<_>>()
+            (
+                ability.valid_targets(game, &plan.choices, s).collect::<Vec<_>>(),
+                ability.target_count(&plan.choices),
+                ability.divided_total(&plan.choices),
+            )
         })
         .collect::<Vec<_>>();
-    for choices in prompt_lists {
-        assert!(!choices.is_empty(), "No valid targets available");
-        let response = prompts::choose_entity(game, prompted_player, prompt_text, choices);
-        plan.targets.push(response);
+    for (choices, count, divided_total) in prompt_lists {
+        // Each target of a divided effect must be assigned at least 1, so no
+        // more targets than the total being divided can be chosen.
+        let maximum =
+            divided_total.map_or(count.maximum, |total| count.maximum.min(total as usize));
+        let minimum = count.minimum.min(maximum);
+        assert!(choices.len() >= minimum, "No valid targets available");
+        let targets =
+            prompts::choose_entities(game, prompted_player, prompt_text, choices, minimum, maximum);
+        if let Some(total) = divided_total {
+            // Each target of a divided effect must be assigned at least 1.
+            // See <https://yawgatog.com/resources/magic-rules/#R6012d>
+            let amounts = prompts::distribute(
+                game,
+                prompted_player,
+                Text::DivideAmongTargets,
+                total,
+                targets.clone(),
+                1,
+            );
+            plan.target_amounts.extend(amounts.into_iter().map(|(_, amount)| amount));
+        }
+        plan.targets.extend(targets);
     }
 }
 
@@ -136,51 +395,146 @@ pub fn can_play_card(
     player: PlayerName,
     source: Source,
     card_id: CardId,
+) -> bool {
+    can_play_card_in_window(game, &PriorityWindow::default(), player, source, card_id)
+}
+
+/// Equivalent to [can_play_card], reusing the legality primitives cached in a
+/// [PriorityWindow].
+///
+/// Prefer this when checking many cards against the same game state.
+pub fn can_play_card_in_window(
+    game: &GameState,
+    window: &PriorityWindow,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
 ) -> bool {
     let Some(card) = game.card(card_id) else {
         return false;
     };
 
-    if card.controller() != player || card.zone != Zone::Hand {
+    let in_playable_zone = match card.zone {
+        Zone::Hand => card.controller() == player,
+        Zone::Exiled => card_queries::can_play_from_exile(game, player, card_id),
+        _ => false,
+    };
+    if !in_playable_zone {
         return false;
     }
 
-    pick_face_to_play::play_as(game, player, source, card_id)
+    pick_face_to_play::play_as_in_window(game, window, player, source, card_id)
         .into_iter()
-        .any(|mut plan| can_play_card_as(game, source, card_id, &mut plan))
+        .any(|mut plan| can_play_card_as(game, window, source, card_id, &mut plan))
+}
+
+/// Explains why the [PlayerName] player cannot currently play the [CardId]
+/// card, checking the same requirements as [can_play_card].
+///
+/// Returns None if this card can be played.
+pub fn cannot_play_reason(
+    game: &GameState,
+    player: PlayerName,
+    source: Source,
+    card_id: CardId,
+) -> Option<IllegalActionReason> {
+    let card = game.card(card_id)?;
+    let in_playable_zone = match card.zone {
+        Zone::Hand => card.controller() == player,
+        Zone::Exiled => card_queries::can_play_from_exile(game, player, card_id),
+        _ => false,
+    };
+    if !in_playable_zone {
+        return Some(IllegalActionReason::NotInPlayableZone);
+    }
+
+    if let Some(reason) = pick_face_to_play::timing_restriction(game, player, source, card_id) {
+        return Some(reason);
+    }
+
+    let window = PriorityWindow::default();
+    let mut plans = pick_face_to_play::play_as_in_window(game, &window, player, source, card_id);
+    if plans.iter_mut().any(|plan| can_play_card_as(game, &window, source, card_id, plan)) {
+        return None;
+    }
+
+    let has_targets = targeted_spell_abilities(game, card_id).next().is_none()
+        || plans.iter().any(|plan| valid_targets(game, &plan.choices, card_id).next().is_some());
+    Some(if has_targets {
+        IllegalActionReason::InsufficientMana
+    } else {
+        IllegalActionReason::NoValidTargets
+    })
 }
 
 /// Check whether a [PlayCardPlan] could allow a card to be played
 /// when populated with a face to play & timing value.
 fn can_play_card_as(
     game: &GameState,
+    window: &PriorityWindow,
     source: Source,
     card_id: CardId,
     plan: &mut PlayCardPlan,
 ) -> bool {
     match plan.choices.play_as.timing {
         PlayCardTiming::Land => true,
-        _ => has_valid_modes(game, source, card_id, plan),
+        _ => {
+            // Foretold cards must be cast for their foretell cost
+            (card_queries::foretold_turn(game, card_id).is_none()
+                && has_valid_modes(game, window, source, card_id, plan))
+                || has_valid_alternative_cost(game, window, source, card_id, plan)
+        }
     }
 }
 
+/// Check whether a [PlayCardPlan] populated with a face to play could allow a
+/// card to be played by paying one of its alternative costs.
+fn has_valid_alternative_cost(
+    game: &GameState,
+    window: &PriorityWindow,
+    source: Source,
+    card_id: CardId,
+    plan: &mut PlayCardPlan,
+) -> bool {
+    let Some(card) = game.card(card_id) else {
+        return false;
+    };
+
+    card.alternative_costs.iter().any(|alternative| {
+        plan.choices.alternative_cost = Some(alternative.ability_id);
+        let result = match alternative.kind {
+            AlternativeCostKind::Mutate => {
+                !alternative_cost_targets(game, source, card_id, alternative).is_empty()
+                    && can_pay_mana_costs(game, window, source, card_id, plan)
+            }
+            AlternativeCostKind::Foretell => {
+                card_queries::can_cast_foretold(game, plan.choices.controller, card_id)
+                    && has_valid_modes(game, window, source, card_id, plan)
+            }
+        };
+        plan.choices.alternative_cost = None;
+        result
+    })
+}
+
 /// Check whether a [PlayCardPlan] which is populated with a face to play
 /// could allow a card to be played with valid modes.
 fn has_valid_modes(
     game: &GameState,
+    window: &PriorityWindow,
     source: Source,
     card_id: CardId,
     plan: &mut PlayCardPlan,
 ) -> bool {
     let Some((_, ability)) = modal_spell_abilities(game, card_id).next() else {
-        return has_valid_targets(game, source, card_id, plan);
+        return has_valid_targets(game, window, source, card_id, plan);
     };
 
     for mode in ability.modes() {
         // TODO: Handle selecting multiple modes.
         plan.choices.modes.clear();
         plan.choices.modes.push(mode);
-        if has_valid_targets(game, source, card_id, plan) {
+        if has_valid_targets(game, window, source, card_id, plan) {
             return true;
         }
     }
@@ -192,20 +546,31 @@ fn has_valid_modes(
 /// mode selection could allow a card to be played with valid targets.
 fn has_valid_targets(
     game: &GameState,
+    window: &PriorityWindow,
     source: Source,
     card_id: CardId,
     plan: &mut PlayCardPlan,
 ) -> bool {
-    if targeted_spell_abilities(game, card_id).next().is_some() {
-        for list in valid_target_lists(game, &plan.choices, card_id) {
-            plan.targets = list;
-            if can_pay_mana_costs(game, source, card_id, plan) {
-                return true;
+    let minimum = targeted_spell_abilities(game, card_id)
+        .map(|(_, ability)| ability.target_count(&plan.choices).minimum)
+        .max();
+    match minimum {
+        Some(minimum) if minimum > 0 => {
+            if valid_targets(game, &plan.choices, card_id).take(minimum).count() < minimum {
+                return false;
             }
+            for target in valid_targets(game, &plan.choices, card_id) {
+                // Reuse the existing target buffer instead of allocating a new
+                // list for each candidate.
+                plan.targets.clear();
+                plan.targets.push(target);
+                if can_pay_mana_costs(game, window, source, card_id, plan) {
+                    return true;
+                }
+            }
+            false
         }
-        false
-    } else {
-        can_pay_mana_costs(game, source, card_id, plan)
+        _ => can_pay_mana_costs(game, window, source, card_id, plan),
     }
 }
 
@@ -247,28 +612,33 @@ fn spell_abilities_matching(
     ))
 }
 
-/// Returns an iterator over valid target lists for spell abilities of this
-/// card.
-fn valid_target_lists<'a>(
+/// Returns an iterator over valid targets for spell abilities of this card.
+fn valid_targets<'a>(
     game: &'a GameState,
     choices: &'a PlayCardChoices,
     card_id: CardId,
-) -> impl Iterator<Item = Vec<EntityId>> + 'a {
-    let Some(card) = game.card(card_id) else {
-        return Either::Left(iter::empty());
-    };
-
-    Either::Right(targeted_spell_abilities(game, card_id).flat_map(move |(scope, ability)| {
-        ability.valid_targets(game, choices, scope).map(|entity_id| vec![entity_id])
-    }))
+) -> impl Iterator<Item = EntityId> + 'a {
+    targeted_spell_abilities(game, card_id)
+        .flat_map(move |(scope, ability)| ability.valid_targets(game, choices, scope))
 }
 
 fn can_pay_mana_costs(
     game: &GameState,
+    window: &PriorityWindow,
     source: Source,
     card_id: CardId,
     plan: &PlayCardPlan,
 ) -> bool {
-    let mana_payment_plan = spell_planner::mana_payment(game, source, card_id, plan);
+    let Some(card) = game.card(card_id) else {
+        return false;
+    };
+    let primitives = window.player(game, card.controller());
+    let mana_payment_plan = spell_planner::mana_payment_from_sources(
+        game,
+        source,
+        card_id,
+        plan,
+        primitives.mana_sources(game),
+    );
     mana_payment_plan.is_some()
 }
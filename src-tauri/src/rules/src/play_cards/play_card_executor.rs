@@ -15,12 +15,14 @@
 use data::card_states::play_card_plan::{PlayCardPlan, PlayCardTiming};
 use data::card_states::zones::ZoneQueries;
 use data::game_states::game_state::GameState;
+use data::game_states::history_data::HistoryEvent;
 use data::player_states::player_state::PlayerQueries;
 use primitives::game_primitives::{CardId, PlayerName, Source, Zone};
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::mutations::{move_card, permanents, priority};
+use crate::queries::card_queries;
 
 /// Plays a card, based on the set of choices in a completed [PlayCardPlan].
 ///
@@ -33,19 +35,16 @@ pub fn execute_plan(
     source: Source,
     plan: PlayCardPlan,
 ) -> Outcome {
-    for land in &plan.mana_payment.basic_land_abilities_to_activate {
-        permanents::tap(game, source, *land)?;
-    }
-
     if plan.choices.play_as.timing == PlayCardTiming::Land {
+        for land in &plan.mana_payment.basic_land_abilities_to_activate {
+            permanents::tap(game, source, *land)?;
+        }
         game.history_counters_mut(player).lands_played += 1;
         let face = plan.choices.play_as.single_face();
         move_card::run(game, source, card_id, Zone::Battlefield)?;
         permanents::turn_face_up(game, source, card_id, face)?;
     } else {
-        game.card_mut(card_id)?.cast_choices = Some(plan.choices);
-        game.card_mut(card_id)?.targets = plan.targets;
-        move_card::run(game, source, card_id, Zone::Stack)?;
+        cast_spell(game, player, card_id, source, plan)?;
 
         // Once a card is played, abilities trigger and then a new priority round is created:
         //
@@ -54,11 +53,40 @@ pub fn execute_plan(
         // > Any abilities that trigger when a spell is cast or put onto the stack trigger at this
         // > time. If the spell's controller had priority before casting it, they get priority.
         game.passed.clear();
-        if !game.player(player).options.hold_priority {
+        if game.player(player).options.hold_priority {
+            // Priority is only held for a single spell.
+            game.player_mut(player).options.hold_priority = false;
+        } else {
             // Automatically pass priority after putting something on the stack.
-            priority::pass(game, player);
+            priority::pass(game, player)?;
         }
     }
 
     outcome::OK
 }
+
+/// Pays the costs for a spell described in a completed [PlayCardPlan] and moves
+/// it to the stack.
+///
+/// Unlike [execute_plan], this does not modify priority, and is thus suitable
+/// for casting spells while another spell or ability is resolving.
+pub fn cast_spell(
+    game: &mut GameState,
+    player: PlayerName,
+    card_id: CardId,
+    source: Source,
+    plan: PlayCardPlan,
+) -> Outcome {
+    for land in &plan.mana_payment.basic_land_abilities_to_activate {
+        permanents::tap(game, source, *land)?;
+    }
+
+    game.card_mut(card_id).or_skip()?.cast_choices = Some(plan.choices);
+    game.card_mut(card_id).or_skip()?.targets = plan.targets;
+    game.card_mut(card_id).or_skip()?.target_amounts = plan.target_amounts;
+    move_card::run(game, source, card_id, Zone::Stack)?;
+    game.add_history_event(HistoryEvent::CastSpell(player, card_id));
+    let card_types = card_queries::card_types(game, source, card_id).or_skip()?;
+    game.history_counters_mut(player).spells_cast.push(card_types);
+    outcome::OK
+}
@@ -13,3 +13,5 @@
 // limitations under the License.
 
 pub mod card_predicates;
+pub mod static_conditions;
+pub mod trigger_conditions;
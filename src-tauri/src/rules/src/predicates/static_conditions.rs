@@ -0,0 +1,91 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conditions for static abilities which apply "as long as" some game state
+//! holds.
+//!
+//! Each function here can be used as a
+//! [ConditionFn](data::core::modifier_data::ConditionFn), and is invoked with
+//! the [CardId] of the card which has the ability.
+
+use data::card_states::zones::ZoneQueries;
+use data::core::function_types::CardPredicate;
+use data::game_states::game_state::GameState;
+use data::printed_cards::card_subtypes::CreatureType;
+use primitives::game_primitives::{CardId, CardType, HasController, PermanentId, Source};
+
+use crate::queries::card_queries;
+
+/// Returns true if the controller of the [CardId] card controls three or more
+/// artifacts.
+///
+/// > 207.2c. ... Metalcraft
+///
+/// <https://yawgatog.com/resources/magic-rules/#R2072c>
+pub fn metalcraft(game: &GameState, source: Source, id: CardId) -> Option<bool> {
+    let controller = game.card(id)?.controller();
+    let artifacts = game
+        .battlefield(controller)
+        .iter()
+        .filter(|&&permanent_id| {
+            card_queries::card_types(game, source, permanent_id)
+                .is_some_and(|types| types.contains(CardType::Artifact))
+        })
+        .count();
+    Some(artifacts >= 3)
+}
+
+/// Returns true if the controller of the [CardId] card has seven or more cards in
+/// their graveyard.
+///
+/// > 207.2c. ... Threshold
+///
+/// <https://yawgatog.com/resources/magic-rules/#R2072c>
+pub fn threshold(game: &GameState, _: Source, id: CardId) -> Option<bool> {
+    let card = game.card(id)?;
+    Some(game.graveyard(card.controller()).len() >= 7)
+}
+
+/// Returns true if the controller of the [CardId] card controls another
+/// permanent matching `predicate`.
+pub fn controls_another(
+    game: &GameState,
+    source: Source,
+    id: CardId,
+    predicate: impl CardPredicate<PermanentId>,
+) -> Option<bool> {
+    let controller = game.card(id)?.controller();
+    Some(game.battlefield(controller).iter().any(|&permanent_id| {
+        game.card(permanent_id).is_some_and(|card| card.id != id)
+            && predicate(game, source, permanent_id) == Some(true)
+    }))
+}
+
+/// Returns true if the controller of the [CardId] card controls another
+/// creature with the given [CreatureType].
+pub fn controls_another_of_type(
+    game: &GameState,
+    source: Source,
+    id: CardId,
+    creature_type: CreatureType,
+) -> Option<bool> {
+    let controller = game.card(id)?.controller();
+    Some(game.battlefield(controller).iter().any(|&permanent_id| {
+        game.card(permanent_id).is_some_and(|card| card.id != id)
+            && card_queries::card_types(game, source, permanent_id)
+                .is_some_and(|types| types.contains(CardType::Creature))
+            && card_queries::creature_subtypes(game, source, permanent_id)
+                .is_some_and(|types| types.contains(creature_type))
+    }))
+}
@@ -0,0 +1,152 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conditions for common triggered ability templates, for use with
+//! [TriggerExt::add_trigger] on the matching [GlobalEvents] event.
+//!
+//! For example, a card with "At the beginning of your upkeep, ..." would use
+//! `events.upkeep.add_trigger(scope, trigger_conditions::your_upkeep)`.
+
+use data::card_states::zones::ZoneQueries;
+use data::core::function_types::CardPredicate;
+use data::events::event_context::EventContext;
+#[allow(unused)] // Used in docs
+use data::events::game_events::GlobalEvents;
+use data::events::game_events::{LifeGainedEvent, LifeLostEvent, StepBeganEvent};
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{CardId, HasController, HasSource, PermanentId, PlayerName};
+
+#[allow(unused)] // Used in docs
+use crate::mutations::trigger_extension::TriggerExt;
+use crate::predicates::card_predicates;
+use crate::queries::player_queries;
+
+/// "At the beginning of your upkeep". Use with [GlobalEvents::upkeep].
+///
+/// In team games, the upkeep of a teammate is also considered to be "your"
+/// upkeep, since teammates share turns.
+pub fn your_upkeep(game: &GameState, context: EventContext, player: &PlayerName) -> Option<bool> {
+    Some(is_your_turn(game, context, *player))
+}
+
+/// "At the beginning of your `step`", e.g. "At the beginning of your end
+/// step". Use with [GlobalEvents::step_began].
+///
+/// As with [your_upkeep], steps of a teammate's turn are also "your" steps.
+pub fn beginning_of_your(
+    step: GamePhaseStep,
+) -> impl Fn(&GameState, EventContext, &StepBeganEvent) -> Option<bool> + Copy + Send + Sync + 'static
+{
+    move |game, context, event| {
+        Some(event.step == step && is_your_turn(game, context, event.active_player))
+    }
+}
+
+/// "At the beginning of each `step`", e.g. "At the beginning of the end step"
+/// or "At the beginning of each upkeep". Use with [GlobalEvents::step_began].
+pub fn beginning_of_each(
+    step: GamePhaseStep,
+) -> impl Fn(&GameState, EventContext, &StepBeganEvent) -> Option<bool> + Copy + Send + Sync + 'static
+{
+    move |_, _, event| Some(event.step == step)
+}
+
+/// "Whenever a permanent matching `predicate` enters the battlefield under
+/// your control". Use with [GlobalEvents::permanent_entered_battlefield].
+pub fn enters_under_your_control(
+    predicate: impl CardPredicate<PermanentId>,
+) -> impl Fn(&GameState, EventContext, &PermanentId) -> Option<bool> + Copy + Send + Sync + 'static
+{
+    move |game, context, &permanent_id| {
+        Some(
+            game.card(permanent_id)?.controller() == context.controller
+                && predicate(game, context.source(), permanent_id)?,
+        )
+    }
+}
+
+/// "Whenever a creature enters the battlefield under your control". Use with
+/// [GlobalEvents::permanent_entered_battlefield].
+pub fn creature_enters_under_your_control(
+    game: &GameState,
+    context: EventContext,
+    permanent_id: &PermanentId,
+) -> Option<bool> {
+    enters_under_your_control(card_predicates::creature)(game, context, permanent_id)
+}
+
+/// Landfall, "Whenever a land enters the battlefield under your control". Use
+/// with [GlobalEvents::permanent_entered_battlefield].
+///
+/// > 207.2c. An ability word appears in italics at the beginning of some
+/// > abilities. Ability words are similar to keywords in that they tie together
+/// > cards that have similar functionality, but they have no special rules
+/// > meaning and no individual entries in the Comprehensive Rules.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R2072c>
+pub fn landfall(
+    game: &GameState,
+    context: EventContext,
+    permanent_id: &PermanentId,
+) -> Option<bool> {
+    enters_under_your_control(card_predicates::land)(game, context, permanent_id)
+}
+
+/// "Whenever you gain life". Use with [GlobalEvents::life_gained].
+pub fn you_gain_life(
+    game: &GameState,
+    context: EventContext,
+    event: &LifeGainedEvent,
+) -> Option<bool> {
+    Some(event.player == context.controller)
+}
+
+/// "Whenever you lose life". Use with [GlobalEvents::life_lost].
+pub fn you_lose_life(
+    game: &GameState,
+    context: EventContext,
+    event: &LifeLostEvent,
+) -> Option<bool> {
+    Some(event.player == context.controller)
+}
+
+/// "Whenever an opponent loses life". Use with [GlobalEvents::life_lost].
+pub fn opponent_loses_life(
+    game: &GameState,
+    context: EventContext,
+    event: &LifeLostEvent,
+) -> Option<bool> {
+    Some(player_queries::all_opponents(game, context.controller).contains(event.player))
+}
+
+/// "Whenever chaos ensues", for the chaos ability of a plane card. Use with
+/// [GlobalEvents::chaos_ensues] and [TriggerExt::add_plane_trigger].
+pub fn chaos_ensues(_: &GameState, _: EventContext, _: &PlayerName) -> Option<bool> {
+    Some(true)
+}
+
+/// "When you planeswalk to [this card]" or "When you encounter [this
+/// phenomenon]". Use with [GlobalEvents::planeswalked_to] and
+/// [TriggerExt::add_plane_trigger].
+pub fn planeswalk_to_this(_: &GameState, context: EventContext, card_id: &CardId) -> Option<bool> {
+    Some(*card_id == context.this.card_id)
+}
+
+/// Returns true if it is currently the turn of the controller of the ability
+/// which created this callback or one of their teammates, given the
+/// `active_player`.
+fn is_your_turn(game: &GameState, context: EventContext, active_player: PlayerName) -> bool {
+    (player_queries::team(game, active_player) | active_player).contains(context.controller)
+}
@@ -14,24 +14,31 @@
 
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use data::actions::prompt_action::PromptAction;
 use data::card_states::zones::ZoneQueries;
 use data::game_states::game_state::{GameOperationMode, GameState};
 use data::player_states::player_state::{PlayerQueries, PlayerType};
 use data::printed_cards::card_subtypes::LandType;
+use data::prompts::distribute_prompt::DistributePrompt;
 use data::prompts::entity_choice_prompt::{Choice, EntityChoicePrompt};
 use data::prompts::game_update::GameUpdate;
+use data::prompts::multi_select_prompt::MultiSelectPrompt;
 use data::prompts::multiple_choice_prompt::MultipleChoicePrompt;
+use data::prompts::pay_mana_prompt::PayManaPrompt;
 use data::prompts::pick_number_prompt::PickNumberPrompt;
 use data::prompts::prompt::{Prompt, PromptResponse, PromptType};
 use data::prompts::select_order_prompt::{CardOrderLocation, Quantity, SelectOrderPrompt};
 use data::text_strings::Text;
 use enumset::EnumSet;
 use maplit::btreemap;
-use primitives::game_primitives::{CardId, EntityId, PlayerName};
+use primitives::game_primitives::{CardId, EntityId, PermanentId, PlayerName};
 use rand::prelude::SliceRandom;
 use tokio::sync::oneshot;
-use tracing::info;
+use tokio::sync::oneshot::error::TryRecvError;
+use tracing::{info, warn};
 
 use crate::action_handlers::prompt_actions;
 use crate::action_handlers::prompt_actions::PromptExecutionResult;
@@ -80,23 +87,124 @@ fn send_internal(game: &mut GameState, mut prompt: Prompt) -> PromptResponse {
             }
         }
     } else {
+        let pending = prompt.clone();
         let kind = prompt.prompt_type.kind();
         info!(immediate = true, ?kind, "Sending prompt");
         let (sender, receiver) = oneshot::channel();
+        let animations = game.animations.take();
         game.updates
             .as_ref()
             .expect("Game has no UpdateChannel attached")
-            .send(GameUpdate::new(game).prompt(prompt).response_channel(sender))
+            .send(
+                GameUpdate::new(game)
+                    .animations(animations)
+                    .prompt(prompt)
+                    .response_channel(sender),
+            )
             .expect("Unable to send prompt, receiver has dropped");
-        let result = receiver
-            .blocking_recv()
-            .expect("Unable to receive prompt response, sender has dropped");
+        let Some(result) = receive(game, receiver) else {
+            return default_response(pending);
+        };
         let result_kind = result.kind();
         info!(?result_kind, "Got prompt response");
         result
     }
 }
 
+/// Interval at which to check for a prompt response when a timeout is
+/// configured.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Waits for a human player to respond to a prompt.
+///
+/// Connected players are given as long as they need to respond. Returns None
+/// if the player's client disconnected and no response was received within
+/// the game's configured prompt timeout, or if the prompt can no longer be
+/// answered.
+fn receive(
+    game: &GameState,
+    mut receiver: oneshot::Receiver<PromptResponse>,
+) -> Option<PromptResponse> {
+    let Some(timeout) = game.configuration.prompt_timeout else {
+        let result = receiver.blocking_recv();
+        if result.is_err() {
+            warn!("Prompt response channel closed");
+        }
+        return result.ok();
+    };
+
+    let mut deadline = None;
+    loop {
+        match receiver.try_recv() {
+            Ok(response) => return Some(response),
+            Err(TryRecvError::Closed) => {
+                warn!("Prompt response channel closed");
+                return None;
+            }
+            Err(TryRecvError::Empty) => {
+                // The update channel closes when the client which requested this
+                // game action disconnects. The player may still respond to the
+                // prompt if they reconnect before the deadline.
+                if game.updates.as_ref().map_or(true, |updates| updates.is_closed()) {
+                    let deadline = *deadline.get_or_insert_with(|| Instant::now() + timeout);
+                    if Instant::now() >= deadline {
+                        warn!(?timeout, "Timed out waiting for prompt response");
+                        return None;
+                    }
+                }
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// Responds to a prompt on behalf of a player who was unable to respond.
+///
+/// Optional effects are declined where possible, otherwise a random legal
+/// choice is made.
+///
+/// The choice does not use the game's random number generator: the response
+/// is recorded in the game history, and replaying it must leave the game
+/// random state unchanged.
+fn default_response(mut prompt: Prompt) -> PromptResponse {
+    info!("Selecting default prompt response");
+    loop {
+        let action = default_action(&prompt);
+        match prompt_actions::execute(prompt, action) {
+            PromptExecutionResult::Prompt(p) => {
+                prompt = p;
+            }
+            PromptExecutionResult::PromptResponse(response) => {
+                return response;
+            }
+        }
+    }
+}
+
+fn default_action(prompt: &Prompt) -> PromptAction {
+    match &prompt.prompt_type {
+        PromptType::MultipleChoice(data) => {
+            if let Some(index) = data.choices().iter().position(Text::is_decline) {
+                return PromptAction::SelectChoice(index);
+            }
+        }
+        PromptType::EntityChoice(data) if data.optional => {
+            return PromptAction::SkipEntityChoice;
+        }
+        PromptType::MultiSelect(data) if data.is_valid_selection() => {
+            return PromptAction::SubmitMultiSelect;
+        }
+        PromptType::Distribute(data) if data.is_valid_distribution() => {
+            return PromptAction::SubmitDistribution;
+        }
+        _ => {}
+    }
+    let actions = legal_prompt_actions::compute(prompt, prompt.player, LegalActions {
+        for_human_player: false,
+    });
+    *actions.choose(&mut rand::thread_rng()).expect("No legal prompt actions available")
+}
+
 fn send(game: &mut GameState, prompt: Prompt) -> PromptResponse {
     match &mut game.operation_mode {
         GameOperationMode::SerializationReplay(prompts) => {
@@ -130,6 +238,41 @@ pub fn choose_entity(
     id
 }
 
+/// Prompt for the [PlayerName] player to pick between `minimum` and `maximum`
+/// distinct entities from `choices`, one at a time.
+///
+/// Once `minimum` entities have been picked, the player may stop choosing.
+/// Returns the selected entities in the order they were picked.
+pub fn choose_entities(
+    game: &mut GameState,
+    player: PlayerName,
+    description: Text,
+    mut choices: Vec<EntityId>,
+    minimum: usize,
+    maximum: usize,
+) -> Vec<EntityId> {
+    let mut result = vec![];
+    while result.len() < maximum && !choices.is_empty() {
+        let response = send(game, Prompt {
+            player,
+            label: Some(description),
+            prompt_type: PromptType::EntityChoice(EntityChoicePrompt {
+                optional: result.len() >= minimum,
+                choices: choices.iter().map(|&entity_id| Choice { entity_id }).collect(),
+            }),
+        });
+        match response {
+            PromptResponse::EntityChoice(id) => {
+                choices.retain(|&choice| choice != id);
+                result.push(id);
+            }
+            PromptResponse::SkipEntityChoice => break,
+            _ => panic!("Unexpected prompt response type!"),
+        }
+    }
+    result
+}
+
 /// Prompt for the [PlayerName] player to select and reorder cards based on a
 /// [SelectOrderPrompt].
 pub fn select_order(
@@ -212,3 +355,81 @@ pub fn multiple_choice<T: Into<Text> + Debug + Clone + Send + 'static>(
 
     choices[index].clone()
 }
+
+/// Prompt for the [PlayerName] player to pick between `minimum` and `maximum`
+/// of the provided choices, returning the selected choices in their original
+/// order.
+///
+/// Both bounds are limited to the number of choices available.
+pub fn multi_select<T: Into<Text> + Clone>(
+    game: &mut GameState,
+    player: PlayerName,
+    description: Text,
+    choices: Vec<T>,
+    minimum: usize,
+    maximum: usize,
+) -> Vec<T> {
+    let maximum = maximum.min(choices.len());
+    let PromptResponse::MultiSelect(indices) = send(game, Prompt {
+        player,
+        label: Some(description),
+        prompt_type: PromptType::MultiSelect(MultiSelectPrompt {
+            choices: choices.iter().map(|c| c.clone().into()).collect(),
+            selected: vec![],
+            minimum: minimum.min(maximum),
+            maximum,
+        }),
+    }) else {
+        panic!("Unexpected prompt response type!");
+    };
+
+    indices.into_iter().map(|i| choices[i].clone()).collect()
+}
+
+/// Prompt for the [PlayerName] player to divide `total` among `targets`, with
+/// each target receiving at least `minimum_per_target`.
+///
+/// Returns the amount assigned to each target.
+pub fn distribute(
+    game: &mut GameState,
+    player: PlayerName,
+    description: Text,
+    total: u32,
+    targets: Vec<EntityId>,
+    minimum_per_target: u32,
+) -> Vec<(EntityId, u32)> {
+    if targets.len() <= 1 {
+        return targets.into_iter().map(|target| (target, total)).collect();
+    }
+
+    let PromptResponse::Distribute(amounts) = send(game, Prompt {
+        player,
+        label: Some(description),
+        prompt_type: PromptType::Distribute(DistributePrompt::new(
+            total,
+            targets.clone(),
+            minimum_per_target,
+        )),
+    }) else {
+        panic!("Unexpected prompt response type!");
+    };
+
+    targets.into_iter().zip(amounts).collect()
+}
+
+/// Show a [PayManaPrompt], returning the mana sources the player selected to
+/// tap.
+pub fn pay_mana(
+    game: &mut GameState,
+    player: PlayerName,
+    prompt: PayManaPrompt,
+) -> Vec<PermanentId> {
+    let PromptResponse::PayMana(sources) = send(game, Prompt {
+        player,
+        label: Some(Text::PayManaCost),
+        prompt_type: PromptType::PayMana(prompt),
+    }) else {
+        panic!("Unexpected prompt response type!");
+    };
+    sources
+}
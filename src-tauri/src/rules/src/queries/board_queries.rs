@@ -0,0 +1,51 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use data::card_states::zones::ZoneQueries;
+use data::core::card_tags::CardTag;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{PlayerName, Source};
+
+use crate::queries::card_queries;
+
+/// Returns the number of permanents controlled by the indicated `player` which
+/// have each [CardTag].
+///
+/// Tags which do not appear on any permanent are omitted from the result.
+pub fn tag_counts(
+    game: &GameState,
+    source: Source,
+    player: PlayerName,
+) -> BTreeMap<CardTag, usize> {
+    let mut result = BTreeMap::new();
+    for &permanent_id in game.battlefield(player) {
+        for tag in card_queries::tags(game, source, permanent_id).unwrap_or_default() {
+            *result.entry(tag).or_default() += 1;
+        }
+    }
+    result
+}
+
+/// Returns the number of permanents controlled by the indicated `player` which
+/// have the given [CardTag].
+pub fn tag_count(game: &GameState, source: Source, player: PlayerName, tag: CardTag) -> usize {
+    game.battlefield(player)
+        .iter()
+        .filter(|&&permanent_id| {
+            card_queries::tags(game, source, permanent_id).is_some_and(|tags| tags.contains(tag))
+        })
+        .count()
+}
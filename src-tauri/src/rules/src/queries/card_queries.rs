@@ -13,18 +13,32 @@
 // limitations under the License.
 
 use data::card_states::card_state::CardState;
-use data::card_states::play_card_plan::PlayCardPlan;
+use data::card_states::counters::CounterType;
+use data::card_states::custom_card_state::CustomCardState;
+use data::card_states::play_card_plan::{
+    AlternativeCostKind, CastSpellPlanAdditionalChoice, PlayCardPlan,
+};
 use data::card_states::zones::{ToCardId, ZoneQueries};
-use data::core::numerics::{Power, Toughness};
-use data::game_states::game_state::GameState;
-use data::printed_cards::card_subtypes::{CreatureType, LandType};
+use data::core::card_tags::CardTag;
+use data::core::numerics::{ManaValue, Power, Toughness};
+use data::game_states::game_state::{GameState, TurnData};
+use data::printed_cards::card_subtypes::{
+    BattleSubtype, CreatureType, EnchantmentSubtype, LandType,
+};
 use data::printed_cards::layout::CardLayout;
 #[allow(unused)] // Used in docs
 use data::printed_cards::mana_cost::{ManaCost, ManaCostItem};
-use data::printed_cards::printed_card::{Face, PrintedCardFace};
+use data::printed_cards::printed_card::{Face, PrintedCard, PrintedCardFace};
 use data::printed_cards::printed_primitives::{PrintedPower, PrintedToughness};
-use enumset::EnumSet;
-use primitives::game_primitives::{CardId, CardType, Color, Source, Zone};
+use data::properties::card_property_data::{CanBeTargeted, CountersPlacedQuery};
+use enumset::{enum_set, EnumSet};
+use primitives::game_primitives::{
+    CardId, CardSupertype, CardType, Color, ExiledCardId, HasController, ManaColor, PermanentId,
+    PlayerName, Source, Zone,
+};
+use tracing::instrument;
+
+use crate::planner::spell_planner;
 
 pub enum CharacteristicFaces<'a> {
     FaceDown,
@@ -100,7 +114,10 @@ pub fn characteristic_faces(
     let card = game.card(id)?;
     Some(match card.zone {
         Zone::Battlefield => {
-            if let Some(face) = card.face_up_printed_face() {
+            // A merged permanent has the characteristics of its topmost
+            // component.
+            let top = card.merged_components.first().and_then(|&id| game.card(id)).unwrap_or(card);
+            if let Some(face) = top.face_up_printed_face() {
                 CharacteristicFaces::Face(face)
             } else {
                 CharacteristicFaces::FaceDown
@@ -139,6 +156,20 @@ pub fn card_types(
     game: &GameState,
     source: Source,
     id: impl ToCardId,
+) -> Option<EnumSet<CardType>> {
+    let card = game.card(id)?;
+    card.properties
+        .cache
+        .card_types(game.effects_generation, source, || compute_card_types(game, source, card.id))
+}
+
+/// Computes the card types of a card as in [card_types] without consulting its
+/// query cache, used to verify that cached results are up to date.
+#[instrument(name = "card_queries_card_types", level = "trace", skip_all)]
+pub fn compute_card_types(
+    game: &GameState,
+    source: Source,
+    id: CardId,
 ) -> Option<EnumSet<CardType>> {
     Some(match characteristic_faces(game, source, id)? {
         CharacteristicFaces::FaceDown => EnumSet::new(),
@@ -149,6 +180,272 @@ pub fn card_types(
     })
 }
 
+/// Returns the current defense of the [PermanentId] battle, i.e. the number of
+/// defense counters on it. Returns None if this permanent no longer exists.
+///
+/// > 310.4. Battles have defense. A battle's defense is equal to the number of
+/// > defense counters on it.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R3104>
+pub fn defense(game: &GameState, id: PermanentId) -> Option<u32> {
+    Some(game.card(id)?.counters.other_counters.get(&CounterType::Defense).copied().unwrap_or(0))
+}
+
+/// Returns the number of lore counters on the [PermanentId] permanent. Returns
+/// None if this permanent no longer exists.
+pub fn lore_counters(game: &GameState, id: PermanentId) -> Option<u32> {
+    Some(game.card(id)?.counters.other_counters.get(&CounterType::Lore).copied().unwrap_or(0))
+}
+
+/// Returns the number of counters of the given [CounterType] which would be put
+/// on the [PermanentId] permanent if an effect put `count` of them on it.
+///
+/// Replacement effects such as "If one or more +1/+1 counters would be put on
+/// a creature you control, twice that many +1/+1 counters are put on it
+/// instead" are applied by querying every permanent on the battlefield.
+/// Effects are currently applied in a fixed order rather than allowing the
+/// affected permanent's controller to choose the order in which they apply.
+pub fn counters_placed(
+    game: &GameState,
+    source: Source,
+    recipient: PermanentId,
+    counter: CounterType,
+    count: u32,
+) -> u32 {
+    game.configuration.all_players.iter().fold(count, |count, controller| {
+        game.battlefield(controller).iter().fold(count, |count, &permanent_id| {
+            game.card(permanent_id).map_or(count, |card| {
+                card.properties.counters_placed.query_with(
+                    game,
+                    source,
+                    &CountersPlacedQuery { permanent_id, recipient, counter },
+                    count,
+                )
+            })
+        })
+    })
+}
+
+/// Returns the mana value of a card's characteristic faces. Returns None if
+/// this card no longer exists.
+///
+/// > 202.3b. When calculating the mana value of an object with an {X} in its
+/// > mana cost, X is treated as 0 while the object is not on the stack, and X
+/// > is treated as the number chosen for it while the object is on the stack.
+///
+/// > 202.3c. When calculating the mana value of an object with a hybrid mana
+/// > symbol in its mana cost, use the largest component of each hybrid symbol.
+///
+/// > 708.4. In every zone except the stack, the characteristics of a split card
+/// > are those of its two halves combined.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R2023>
+///
+/// See [characteristic_faces] for more information.
+pub fn mana_value(game: &GameState, source: Source, id: impl ToCardId) -> Option<ManaValue> {
+    let card = game.card(id)?;
+    let x = card.cast_choices.as_ref().and_then(|choices| choices.variable).unwrap_or_default();
+    let value = |face: &PrintedCardFace| {
+        let variables =
+            face.mana_cost.items.iter().filter(|&&item| item == ManaCostItem::VariableX);
+        face.mana_value + x * variables.count() as ManaValue
+    };
+    Some(match characteristic_faces(game, source, id)? {
+        CharacteristicFaces::FaceDown => 0,
+        CharacteristicFaces::Face(face) if card.zone == Zone::Stack => value(face),
+        CharacteristicFaces::Face(face) => face.mana_value,
+        CharacteristicFaces::MultipleFaces(faces) if card.zone == Zone::Stack => {
+            faces.iter().map(|face| value(face)).sum()
+        }
+        CharacteristicFaces::MultipleFaces(faces) => faces.iter().map(|face| face.mana_value).sum(),
+    })
+}
+
+/// Returns the color identity of a printed card.
+///
+/// > 903.4. The Commander variant uses color identity to determine what cards
+/// > can be in a deck with a certain commander. The color identity of a card is
+/// > the color or colors of any mana symbols in that card's mana cost or rules
+/// > text, plus any colors defined by its characteristic-defining abilities or
+/// > color indicator.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R9034>
+///
+/// This considers all faces of the card, regardless of which face is currently
+/// up, and is intended for deck validation.
+pub fn color_identity(printed: &PrintedCard) -> EnumSet<Color> {
+    printed
+        .all_faces()
+        .flat_map(|face| {
+            let symbols = face.mana_cost.items.iter().flat_map(|&item| mana_symbol_colors(item));
+            let text = face.oracle_text.iter().flat_map(|text| text_symbol_colors(text));
+            face.colors.iter().chain(symbols).chain(text)
+        })
+        .collect()
+}
+
+/// Returns the set of supertypes on a card's characteristic faces. Returns
+/// None if this card no longer exists.
+///
+/// See [characteristic_faces] for more information.
+pub fn supertypes(
+    game: &GameState,
+    source: Source,
+    id: impl ToCardId,
+) -> Option<EnumSet<CardSupertype>> {
+    Some(match characteristic_faces(game, source, id)? {
+        CharacteristicFaces::FaceDown => EnumSet::new(),
+        CharacteristicFaces::Face(face) => face.supertypes,
+        CharacteristicFaces::MultipleFaces(faces) => {
+            faces.iter().flat_map(|face| face.supertypes.iter()).collect()
+        }
+    })
+}
+
+/// Returns true if a card is historic. Returns None if this card no longer
+/// exists.
+///
+/// > 700.6. An object is historic if it has the legendary supertype, the
+/// > artifact card type, or the Saga subtype.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7006>
+pub fn is_historic(game: &GameState, source: Source, id: impl ToCardId) -> Option<bool> {
+    let card_id = id.to_card_id(game)?;
+    let saga = match characteristic_faces(game, source, card_id)? {
+        CharacteristicFaces::FaceDown => false,
+        CharacteristicFaces::Face(face) => {
+            face.subtypes.enchantment.contains(EnchantmentSubtype::Saga)
+        }
+        CharacteristicFaces::MultipleFaces(faces) => {
+            faces.iter().any(|face| face.subtypes.enchantment.contains(EnchantmentSubtype::Saga))
+        }
+    };
+    Some(
+        saga || supertypes(game, source, card_id)?.contains(CardSupertype::Legendary)
+            || card_types(game, source, card_id)?.contains(CardType::Artifact),
+    )
+}
+
+/// Creature types which make a creature an outlaw.
+///
+/// > 700.14. "Outlaw" is a term used to refer to a permanent or card with at
+/// > least one of the creature types Assassin, Mercenary, Pirate, Rogue, and/or
+/// > Warlock.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70014>
+pub const OUTLAW_TYPES: EnumSet<CreatureType> = enum_set!(
+    CreatureType::Assassin
+        | CreatureType::Mercenary
+        | CreatureType::Pirate
+        | CreatureType::Rogue
+        | CreatureType::Warlock
+);
+
+/// Creature types which can be part of a player's party.
+///
+/// > 700.8. A player's party consists of up to one each of Cleric, Rogue,
+/// > Warrior, and Wizard among creatures that player controls.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7008>
+pub const PARTY_TYPES: EnumSet<CreatureType> = enum_set!(
+    CreatureType::Cleric | CreatureType::Rogue | CreatureType::Warrior | CreatureType::Wizard
+);
+
+/// Returns true if a card is an outlaw. Returns None if this card no longer
+/// exists.
+///
+/// See [OUTLAW_TYPES].
+pub fn is_outlaw(game: &GameState, source: Source, id: impl ToCardId) -> Option<bool> {
+    Some(!creature_subtypes(game, source, id)?.is_disjoint(OUTLAW_TYPES))
+}
+
+/// Returns the party classes (Cleric, Rogue, Warrior, and Wizard) a card has.
+/// Returns None if this card no longer exists.
+///
+/// See [PARTY_TYPES].
+pub fn party_classes(
+    game: &GameState,
+    source: Source,
+    id: impl ToCardId,
+) -> Option<EnumSet<CreatureType>> {
+    Some(creature_subtypes(game, source, id)? & PARTY_TYPES)
+}
+
+/// Returns the number of mana symbols in the mana cost of a card's
+/// characteristic faces which include at least one of the provided colors.
+/// Returns None if this card no longer exists.
+///
+/// > 700.5. A player's devotion to [color] is equal to the number of mana
+/// > symbols of that color among the mana costs of permanents that player
+/// > controls. ... Hybrid mana symbols, monocolored hybrid mana symbols, and
+/// > Phyrexian mana symbols do count toward a player's devotion to their
+/// > color(s).
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7005>
+pub fn devotion(
+    game: &GameState,
+    source: Source,
+    id: impl ToCardId,
+    colors: EnumSet<Color>,
+) -> Option<usize> {
+    let count = |face: &PrintedCardFace| {
+        face.mana_cost
+            .items
+            .iter()
+            .filter(|&&item| !mana_symbol_colors(item).is_disjoint(colors))
+            .count()
+    };
+    Some(match characteristic_faces(game, source, id)? {
+        CharacteristicFaces::FaceDown => 0,
+        CharacteristicFaces::Face(face) => count(face),
+        CharacteristicFaces::MultipleFaces(faces) => faces.iter().map(|face| count(face)).sum(),
+    })
+}
+
+/// Returns the colors of a single mana symbol.
+fn mana_symbol_colors(item: ManaCostItem) -> EnumSet<Color> {
+    match item {
+        ManaCostItem::Snow(color)
+        | ManaCostItem::Colored(color)
+        | ManaCostItem::MonoHybrid(color)
+        | ManaCostItem::Phyrexian(color) => mana_color(color),
+        ManaCostItem::Hybrid(a, b) | ManaCostItem::PhyrexianHybrid(a, b) => {
+            mana_color(a) | mana_color(b)
+        }
+        ManaCostItem::VariableX | ManaCostItem::Generic => EnumSet::new(),
+    }
+}
+
+/// Returns the [Color] of a [ManaColor], or an empty set for colorless mana.
+fn mana_color(mana: ManaColor) -> EnumSet<Color> {
+    match mana {
+        ManaColor::Colorless => EnumSet::new(),
+        ManaColor::White => Color::White.into(),
+        ManaColor::Blue => Color::Blue.into(),
+        ManaColor::Black => Color::Black.into(),
+        ManaColor::Red => Color::Red.into(),
+        ManaColor::Green => Color::Green.into(),
+    }
+}
+
+/// Returns the colors of all colored mana symbols such as "{G}" or "{W/U}"
+/// which appear in a card's rules text.
+fn text_symbol_colors(text: &str) -> EnumSet<Color> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|symbol| symbol.split_once('}'))
+        .flat_map(|(symbol, _)| symbol.split('/'))
+        .filter_map(|part| match part {
+            "W" => Some(Color::White),
+            "U" => Some(Color::Blue),
+            "B" => Some(Color::Black),
+            "R" => Some(Color::Red),
+            "G" => Some(Color::Green),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Returns the set of current land subtypes on a card's characteristic faces.
 /// Returns None if this card no longer exists.
 ///
@@ -199,25 +496,251 @@ pub fn creature_subtypes(
     Some(card.properties.creature_types.query(game, source, types))
 }
 
+/// Returns the set of current battle subtypes on a card's characteristic faces.
+/// Returns None if this card no longer exists.
+///
+/// See [characteristic_faces] for more information.
+pub fn battle_subtypes(
+    game: &GameState,
+    source: Source,
+    id: impl ToCardId,
+) -> Option<EnumSet<BattleSubtype>> {
+    Some(match characteristic_faces(game, source, id)? {
+        CharacteristicFaces::FaceDown => EnumSet::new(),
+        CharacteristicFaces::Face(face) => face.subtypes.battle,
+        CharacteristicFaces::MultipleFaces(faces) => {
+            faces.iter().flat_map(|face| face.subtypes.battle.iter()).collect()
+        }
+    })
+}
+
 /// Returns the current [ManaCost] that needs to be paid to cast the [CardId]
-/// card using the provided [PlayCardPlan]. Cost items are sorted in
-/// [ManaCostItem] order. Returns None if this card no longer exists.
+/// card using the provided [PlayCardPlan], including any alternative cost or
+/// optional additional costs the player has chosen to pay. Cost items are
+/// sorted in [ManaCostItem] order. Returns None if this card no longer exists.
 pub fn mana_cost_for_casting_card(
     game: &GameState,
     id: CardId,
     plan: &PlayCardPlan,
 ) -> Option<ManaCost> {
-    let mut cost =
-        game.card(id)?.printed().face(plan.choices.play_as.single_face()).mana_cost.clone();
+    let card = game.card(id)?;
+    let alternative = plan
+        .choices
+        .alternative_cost
+        .and_then(|ability_id| card.alternative_costs.iter().find(|c| c.ability_id == ability_id));
+    let mut cost = if plan.choices.without_paying_mana_cost {
+        ManaCost::default()
+    } else if let Some(alternative) = alternative {
+        alternative.cost.clone()
+    } else {
+        card.printed().face(plan.choices.play_as.single_face()).mana_cost.clone()
+    };
+    for choice in &plan.choices.additional_choices {
+        let CastSpellPlanAdditionalChoice::AdditionalCostChoice(ability_id) = choice else {
+            continue;
+        };
+        if let Some(optional) = card.optional_costs.iter().find(|c| c.ability_id == *ability_id) {
+            cost.items.extend(optional.cost.items.iter().copied());
+        }
+    }
     cost.items.sort();
     Some(cost)
 }
 
+/// Returns true if the [CardId] card is a spell which was cast as a mutating
+/// creature spell by paying its mutate cost.
+pub fn is_mutating_spell(game: &GameState, id: CardId) -> bool {
+    let Some(card) = game.card(id) else {
+        return false;
+    };
+    let Some(ability_id) = card.cast_choices.as_ref().and_then(|c| c.alternative_cost) else {
+        return false;
+    };
+    card.alternative_costs
+        .iter()
+        .any(|c| c.ability_id == ability_id && c.kind == AlternativeCostKind::Mutate)
+}
+
+/// Returns true if the [CardId] card could mutate onto the [PermanentId]
+/// permanent, i.e. if that permanent is a non-Human creature with the same
+/// owner. Returns None if either card no longer exists.
+///
+/// See <https://yawgatog.com/resources/magic-rules/#R702140a>
+pub fn can_mutate_onto(
+    game: &GameState,
+    source: Source,
+    id: CardId,
+    target: PermanentId,
+) -> Option<bool> {
+    Some(
+        game.card(id)?.owner == game.card(target)?.owner
+            && card_types(game, source, target)?.contains(CardType::Creature)
+            && !creature_subtypes(game, source, target)?.contains(CreatureType::Human),
+    )
+}
+
+/// Returns an iterator over permanents which the [CardId] card could
+/// currently mutate onto.
+pub fn mutate_targets(
+    game: &GameState,
+    source: Source,
+    id: CardId,
+) -> impl Iterator<Item = PermanentId> + '_ {
+    enum_iterator::all::<PlayerName>().flat_map(move |player| {
+        game.battlefield(player)
+            .iter()
+            .copied()
+            .filter(move |&target| can_mutate_onto(game, source, id, target) == Some(true))
+    })
+}
+
+/// Returns the cards which were exiled by the [CardId] card and linked to it
+/// which are still in exile.
+///
+/// A card which leaves exile becomes a new object with no memory of its
+/// previous existence, so cards which have left exile (even if they later
+/// return to exile) are not included.
+pub fn linked_exile_cards(game: &GameState, id: CardId) -> Vec<ExiledCardId> {
+    let Some(card) = game.card(id) else {
+        return vec![];
+    };
+    card.custom_state
+        .iter()
+        .filter_map(|state| match state {
+            CustomCardState::LinkedExile { exiled_card_id } => Some(*exiled_card_id),
+            _ => None,
+        })
+        .filter(|&exiled_card_id| game.has_card(exiled_card_id))
+        .collect()
+}
+
+/// Returns true if the [PlayerName] player currently has permission to play
+/// the [CardId] card from exile.
+pub fn can_play_from_exile(game: &GameState, player: PlayerName, id: CardId) -> bool {
+    game.card(id).is_some_and(|card| {
+        card.zone == Zone::Exiled
+            && (card.exile_play_permissions.iter().any(|permission| {
+                permission.player == player && permission.duration.is_active(game)
+            }) || on_an_adventure(game, id) == Some(player)
+                || can_cast_foretold(game, player, id))
+    })
+}
+
+/// Returns true if the [CardState] card is a spell which was cast as an
+/// Adventure.
+///
+/// > 715.3b. While on the stack as an Adventure, the spell has only its
+/// > alternative characteristics.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7153b>
+pub fn is_adventure_spell(card: &CardState) -> bool {
+    card.zone == Zone::Stack
+        && card.printed().layout == CardLayout::Adventure
+        && cast_as_faces(card) == EnumSet::only(Face::FaceB)
+}
+
+/// Returns the player who may cast the [CardId] card from exile if it is
+/// currently exiled "on an adventure", or None otherwise.
+///
+/// > 715.3d. Instead of putting a spell that was cast as an Adventure into its
+/// > owner's graveyard as it resolves, its controller exiles it. For as long as
+/// > that card remains exiled, that player may cast it. It can't be cast as an
+/// > Adventure this way.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7153d>
+pub fn on_an_adventure(game: &GameState, id: CardId) -> Option<PlayerName> {
+    let card = game.card(id)?;
+    let exiled_card_id = card.exiled_card_id()?;
+    card.custom_state.iter().find_map(|state| match state {
+        CustomCardState::OnAdventure { exiled_card_id: adventure_id, player }
+            if *adventure_id == exiled_card_id =>
+        {
+            Some(*player)
+        }
+        _ => None,
+    })
+}
+
+/// Returns true if the [CardState] card has foretell.
+///
+/// See <https://yawgatog.com/resources/magic-rules/#R702143a>
+pub fn has_foretell(card: &CardState) -> bool {
+    card.alternative_costs.iter().any(|c| c.kind == AlternativeCostKind::Foretell)
+}
+
+/// Returns the cost to foretell a card, i.e. the cost of the special action
+/// which exiles it face down from its owner's hand.
+///
+/// > 702.143a. Any time a player has priority during their turn, that player
+/// > may pay {2} and exile a card with foretell from their hand face down.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702143a>
+pub fn foretell_action_cost() -> ManaCost {
+    ManaCost { items: vec![ManaCostItem::Generic, ManaCostItem::Generic] }
+}
+
+/// Returns true if the [PlayerName] player can currently take the special
+/// action to foretell the [CardId] card in their hand.
+///
+/// See <https://yawgatog.com/resources/magic-rules/#R1162h>
+pub fn can_foretell(game: &GameState, player: PlayerName, id: CardId) -> bool {
+    let Some(card) = game.card(id) else {
+        return false;
+    };
+    card.zone == Zone::Hand
+        && card.controller() == player
+        && has_foretell(card)
+        && game.turn.active_player == player
+        && game.priority == player
+        && spell_planner::pay_cost(game, player, foretell_action_cost()).is_some()
+}
+
+/// Returns the turn during which the [CardId] card was foretold if it is
+/// currently in exile as a foretold card, or None otherwise.
+///
+/// The card stops being foretold once it leaves exile, since it becomes a new
+/// object.
+pub fn foretold_turn(game: &GameState, id: CardId) -> Option<TurnData> {
+    let card = game.card(id)?;
+    let exiled_card_id = card.exiled_card_id()?;
+    card.custom_state.iter().find_map(|state| match state {
+        CustomCardState::Foretold { exiled_card_id: foretold_id, turn }
+            if *foretold_id == exiled_card_id =>
+        {
+            Some(*turn)
+        }
+        _ => None,
+    })
+}
+
+/// Returns true if the [PlayerName] player may currently cast the [CardId]
+/// card from exile for its foretell cost.
+///
+/// > 702.143a. That player may look at that card as long as it remains in
+/// > exile. They may cast it on a later turn for its foretell cost rather than
+/// > its mana cost.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R702143a>
+pub fn can_cast_foretold(game: &GameState, player: PlayerName, id: CardId) -> bool {
+    game.card(id).is_some_and(|card| card.owner == player)
+        && foretold_turn(game, id).is_some_and(|turn| turn != game.turn)
+}
+
 /// Computes the current power on a card's characteristic faces. Returns None if
 /// this card no longer exists.
 ///
 /// See [characteristic_faces] for more information.
 pub fn power(game: &GameState, source: Source, id: impl ToCardId) -> Option<Power> {
+    let card = game.card(id)?;
+    card.properties
+        .cache
+        .power(game.effects_generation, source, || compute_power(game, source, card.id))
+}
+
+/// Computes the power of a card as in [power] without consulting its query
+/// cache, used to verify that cached results are up to date.
+#[instrument(name = "card_queries_power", level = "trace", skip_all)]
+pub fn compute_power(game: &GameState, source: Source, id: CardId) -> Option<Power> {
     let card = game.card(id)?;
     let result = match characteristic_faces(game, source, card.id)? {
         CharacteristicFaces::FaceDown => {
@@ -235,7 +758,7 @@ pub fn power(game: &GameState, source: Source, id: impl ToCardId) -> Option<Powe
     };
 
     let base = card.properties.base_power.query(game, source, result);
-    Some(card.properties.power.query(game, source, base))
+    Some(card.properties.power.query(game, source, base) + counter_modifier(card))
 }
 
 /// Computes the current toughness on card's characteristic faces. Returns None
@@ -243,6 +766,16 @@ pub fn power(game: &GameState, source: Source, id: impl ToCardId) -> Option<Powe
 ///
 /// See [characteristic_faces] for more information.
 pub fn toughness(game: &GameState, source: Source, id: impl ToCardId) -> Option<Toughness> {
+    let card = game.card(id)?;
+    card.properties
+        .cache
+        .toughness(game.effects_generation, source, || compute_toughness(game, source, card.id))
+}
+
+/// Computes the toughness of a card as in [toughness] without consulting its
+/// query cache, used to verify that cached results are up to date.
+#[instrument(name = "card_queries_toughness", level = "trace", skip_all)]
+pub fn compute_toughness(game: &GameState, source: Source, id: CardId) -> Option<Toughness> {
     let card = game.card(id)?;
     let result = match characteristic_faces(game, source, card.id)? {
         CharacteristicFaces::FaceDown => {
@@ -260,7 +793,15 @@ pub fn toughness(game: &GameState, source: Source, id: impl ToCardId) -> Option<
     };
 
     let base = card.properties.base_toughness.query(game, source, result);
-    Some(card.properties.toughness.query(game, source, base))
+    Some(card.properties.toughness.query(game, source, base) + counter_modifier(card))
+}
+
+/// Returns the total modification to power and toughness from +1/+1 and -1/-1
+/// counters on a card.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6134c>
+fn counter_modifier(card: &CardState) -> i64 {
+    card.counters.p1p1 as i64 - card.counters.m1m1 as i64
 }
 
 /// Returns the set of colors on a card's characteristic faces. Returns None if
@@ -278,3 +819,37 @@ pub fn colors(game: &GameState, source: Source, id: impl ToCardId) -> Option<Enu
     };
     Some(card.properties.colors.query(game, source, types))
 }
+
+/// Returns the set of [CardTag]s for a card, including the heuristic tags on
+/// its characteristic faces. Returns None if this card no longer exists.
+///
+/// See [characteristic_faces] for more information.
+pub fn tags(game: &GameState, source: Source, id: impl ToCardId) -> Option<EnumSet<CardTag>> {
+    let card = game.card(id)?;
+    let tags = match characteristic_faces(game, source, id)? {
+        CharacteristicFaces::FaceDown => EnumSet::new(),
+        CharacteristicFaces::Face(face) => face.tags,
+        CharacteristicFaces::MultipleFaces(faces) => {
+            faces.iter().flat_map(|face| face.tags.iter()).collect()
+        }
+    };
+    Some(card.properties.tags.query(game, source, tags))
+}
+
+/// Returns true if a card can be the target of a spell or ability from the
+/// given [Source]. Returns None if this card no longer exists.
+pub fn can_be_targeted(game: &GameState, source: Source, id: impl ToCardId) -> Option<bool> {
+    let card_id = id.to_card_id(game)?;
+    game.card(card_id)?.properties.can_be_targeted.query_with(
+        game,
+        source,
+        &CanBeTargeted { card_id, source },
+        true,
+    )
+}
+
+/// Returns true if the identity of the given card should be displayed to the
+/// [PlayerName] player, respecting the 'reveal all cards' debug option.
+pub fn is_visible_to(game: &GameState, player: PlayerName, card: &CardState) -> bool {
+    card.is_visible_to(player) || game.configuration.debug.reveal_all_cards
+}
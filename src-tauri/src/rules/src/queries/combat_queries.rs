@@ -19,13 +19,18 @@ use data::card_states::iter_matching::{IterMatching, IterOptional};
 use data::card_states::zones::ZoneQueries;
 use data::core::card_tags::CardTag;
 use data::game_states::combat_state::{
-    AttackTarget, AttackerId, BlockerId, BlockerMap, CombatState,
+    AttackTarget, AttackerId, BlockerId, BlockerMap, CombatSpecialAction, CombatState,
+    ProposedAttackers,
 };
 use data::game_states::game_state::GameState;
+use data::printed_cards::mana_cost::ManaCost;
 use data::properties::card_property_data::{CanAttackTarget, CanBeBlocked};
 use enumset::EnumSet;
-use primitives::game_primitives::{CardType, HasController, PermanentId, PlayerName, Source};
+use primitives::game_primitives::{
+    CardId, CardType, HasController, PermanentId, PlayerName, Source,
+};
 
+use crate::planner::spell_planner;
 use crate::predicates::card_predicates;
 use crate::queries::{card_queries, player_queries};
 
@@ -39,13 +44,11 @@ use crate::queries::{card_queries, player_queries};
 ///
 /// <https://yawgatog.com/resources/magic-rules/#R5081a>
 pub fn can_attack(game: &GameState, source: Source, attacker_id: AttackerId) -> Option<bool> {
-    let turn = game.turn;
     let card = game.card(attacker_id)?;
     let types = card_queries::card_types(game, source, card.id)?;
     let mut result = true;
-    result &= card.last_changed_control != turn || can_attack_same_turn(game, source, attacker_id)?;
-    result &= card.entered_current_zone != turn || can_attack_same_turn(game, source, attacker_id)?;
-    result &= card.controller() == turn.active_player;
+    result &= !has_summoning_sickness(game, source, attacker_id)?;
+    result &= player_queries::active_team(game).contains(card.controller());
     result &= card.tapped_state == TappedState::Untapped;
     result &= types.contains(CardType::Creature);
     result &= !types.contains(CardType::Battle);
@@ -55,6 +58,114 @@ pub fn can_attack(game: &GameState, source: Source, attacker_id: AttackerId) ->
     )
 }
 
+/// Returns true if the attacker in [CanAttackTarget] can attack its target.
+pub fn can_attack_target(
+    game: &GameState,
+    source: Source,
+    can_attack_target: CanAttackTarget,
+) -> Option<bool> {
+    game.card(can_attack_target.attacker_id)?.properties.can_attack_target.query_with(
+        game,
+        source,
+        &can_attack_target,
+        true,
+    )
+}
+
+/// Returns the set of players who have goaded the indicated permanent with a
+/// goad effect which is still active.
+pub fn goaded_by(game: &GameState, permanent_id: PermanentId) -> EnumSet<PlayerName> {
+    let Some(card) = game.card(permanent_id) else {
+        return EnumSet::empty();
+    };
+    card.goaded
+        .iter()
+        .filter(|goaded| goaded.duration.is_active(game))
+        .map(|goaded| goaded.player)
+        .collect()
+}
+
+/// Returns true if attacking `target` with the [AttackerId] creature is
+/// consistent with any goad effects on it.
+///
+/// A goaded creature must attack a player other than the goading players if
+/// able. Attacking any other target is only allowed if no such player can be
+/// attacked.
+pub fn satisfies_goad(
+    game: &GameState,
+    source: Source,
+    attacker_id: AttackerId,
+    target: AttackTarget,
+) -> bool {
+    let goaded_by = goaded_by(game, attacker_id);
+    let is_valid = |target: AttackTarget| match target {
+        AttackTarget::Player(player) => !goaded_by.contains(player),
+        _ => false,
+    };
+    if goaded_by.is_empty() || is_valid(target) {
+        return true;
+    }
+
+    !attack_targets(game, source).any(|target| {
+        is_valid(target)
+            && can_attack_target(game, source, CanAttackTarget { attacker_id, target })
+                == Some(true)
+    })
+}
+
+/// Returns true if a set of proposed attacks satisfies all attack requirements
+/// for the provided player.
+///
+/// > 508.1d. The active player checks each creature they control to see
+/// > whether it's affected by any requirements (effects that say a creature
+/// > attacks if able, or that it attacks if some condition is met). If the
+/// > number of requirements that are being obeyed is fewer than the maximum
+/// > possible number of requirements that could be obeyed without disobeying
+/// > any restrictions, the declaration of attackers is illegal.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R5081d>
+pub fn attack_requirements_met(
+    game: &GameState,
+    source: Source,
+    player: PlayerName,
+    attackers: &ProposedAttackers,
+) -> bool {
+    let goaded_attacking = legal_attackers(game, source, player)
+        .filter(|&id| !goaded_by(game, id).is_empty())
+        .all(|id| attackers.proposed_attacks.contains(id));
+    goaded_attacking
+        && attackers
+            .proposed_attacks
+            .all()
+            .all(|(&attacker_id, &target)| satisfies_goad(game, source, attacker_id, target))
+}
+
+/// Returns true if the indicated permanent is a creature which has not been
+/// controlled by its controller continuously since the turn began and does not
+/// have haste.
+///
+/// > 302.6. A creature's activated ability with the tap symbol or the untap
+/// > symbol in its activation cost can't be activated unless the creature has
+/// > been under its controller's control continuously since their most recent
+/// > turn began. A creature can't attack unless it has been under its
+/// > controller's control continuously since their most recent turn began.
+/// > This rule is informally called the "summoning sickness" rule.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R3026>
+pub fn has_summoning_sickness(
+    game: &GameState,
+    source: Source,
+    permanent_id: PermanentId,
+) -> Option<bool> {
+    let turn = game.turn;
+    let card = game.card(permanent_id)?;
+    if !card_queries::card_types(game, source, card.id)?.contains(CardType::Creature) {
+        return Some(false);
+    }
+    let changed_this_turn = card.last_changed_control == turn || card.entered_current_zone == turn;
+    Some(changed_this_turn && !can_attack_same_turn(game, source, permanent_id)?)
+}
+
 /// Returns true if the indicated permanent has the 'haste' ability.
 pub fn can_attack_same_turn(
     game: &GameState,
@@ -75,13 +186,40 @@ pub fn has_flying(game: &GameState, source: Source, permanent_id: PermanentId) -
     )
 }
 
+/// Returns true if the indicated permanent has the 'vigilance' ability.
+pub fn has_vigilance(game: &GameState, source: Source, permanent_id: PermanentId) -> Option<bool> {
+    Some(
+        game.card(permanent_id)?
+            .properties
+            .tags
+            .query(game, source, EnumSet::empty())
+            .contains(CardTag::Vigilance),
+    )
+}
+
+/// Returns true if the indicated permanent can be exerted as it attacks.
+pub fn can_exert(game: &GameState, source: Source, permanent_id: PermanentId) -> Option<bool> {
+    Some(
+        game.card(permanent_id)?
+            .properties
+            .tags
+            .query(game, source, EnumSet::empty())
+            .contains(CardTag::Exert),
+    )
+}
+
 /// Returns an iterator over all legal attackers for the provided player.
+///
+/// In team games, attackers are declared for the whole team at once, so this
+/// includes creatures controlled by the player's teammates.
 pub fn legal_attackers(
     game: &GameState,
     source: Source,
     player: PlayerName,
 ) -> impl Iterator<Item = AttackerId> + '_ {
-    game.battlefield(player).iter_matching(game, source, can_attack)
+    (player_queries::team(game, player) | player)
+        .iter()
+        .flat_map(move |p| game.battlefield(p).iter_matching(game, source, can_attack))
 }
 
 /// Returns true if the card with the provided [BlockerId] can block legally in
@@ -99,7 +237,7 @@ pub fn can_block(game: &GameState, source: Source, blocker_id: BlockerId) -> Opt
     let types = card_queries::card_types(game, source, blocker.id)?;
 
     let mut result = true;
-    result &= blocker.controller() != game.turn.active_player;
+    result &= !player_queries::active_team(game).contains(blocker.controller());
     result &= blocker.tapped_state != TappedState::Tapped;
     result &= types.contains(CardType::Creature);
     result &= !types.contains(CardType::Battle);
@@ -117,23 +255,94 @@ pub fn can_block(game: &GameState, source: Source, blocker_id: BlockerId) -> Opt
 }
 
 /// Returns an iterator over all legal blockers for the provided player.
+///
+/// In team games, blockers are declared for the whole defending team at once,
+/// so this includes creatures controlled by the player's teammates.
 pub fn legal_blockers(
     game: &GameState,
     source: Source,
     player: PlayerName,
 ) -> impl Iterator<Item = BlockerId> + '_ {
-    game.battlefield(player).iter_matching(game, source, can_block)
+    (player_queries::team(game, player) | player)
+        .iter()
+        .flat_map(move |p| game.battlefield(p).iter_matching(game, source, can_block))
+}
+
+/// Returns the unblocked attacking creatures controlled by the [PlayerName]
+/// player.
+///
+/// Attackers only become unblocked once blockers have been declared, so this
+/// is empty before that point.
+///
+/// > 509.1h. An attacking creature with one or more creatures declared as
+/// > blockers for it becomes a blocked creature; one with no creatures
+/// > declared as blockers for it becomes an unblocked creature.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R5091h>
+pub fn unblocked_attackers(game: &GameState, player: PlayerName) -> Vec<AttackerId> {
+    let Some(CombatState::ConfirmedBlockers(blockers)) = &game.combat else {
+        return vec![];
+    };
+    blockers
+        .attackers
+        .all_attackers()
+        .filter(|attacker| !blockers.blocked_attackers.contains_key(attacker))
+        .filter(|&attacker| game.card(attacker).is_some_and(|c| c.controller() == player))
+        .collect()
+}
+
+/// Returns the ninjutsu cost of the [CardId] card, if it has ninjutsu.
+pub fn ninjutsu_cost(game: &GameState, card_id: CardId) -> Option<ManaCost> {
+    game.card(card_id)?.combat_special_actions.first().map(|action| match action {
+        CombatSpecialAction::Ninjutsu(cost) => cost.clone(),
+    })
+}
+
+/// Returns true if the [PlayerName] player can currently activate the
+/// ninjutsu ability of the [CardId] card in their hand.
+pub fn can_activate_ninjutsu(game: &GameState, player: PlayerName, card_id: CardId) -> bool {
+    if !game.hand(player).contains(&card_id) || unblocked_attackers(game, player).is_empty() {
+        return false;
+    }
+    ninjutsu_cost(game, card_id)
+        .is_some_and(|cost| spell_planner::pay_cost(game, player, cost).is_some())
 }
 
 /// Returns an iterator over legal targets the active player could attack during
 /// combat.
+///
+/// > 508.1b. If the defending player controls any planeswalkers, is the
+/// > protector of any battles, or the game allows the active player to attack
+/// > multiple other players, the active player announces which player,
+/// > planeswalker, or battle each of the chosen creatures is attacking.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R5081b>
 pub fn attack_targets(game: &GameState, source: Source) -> impl Iterator<Item = AttackTarget> + '_ {
     player_queries::inactive_players(game).iter().flat_map(move |player| {
-        iter::once(AttackTarget::Player(player)).chain(
-            game.battlefield(player)
-                .iter_matching(game, source, card_predicates::planeswalker)
-                .map(move |id| AttackTarget::Planeswalker(player, id)),
-        )
+        iter::once(AttackTarget::Player(player))
+            .chain(
+                game.battlefield(player)
+                    .iter_matching(game, source, card_predicates::planeswalker)
+                    .map(move |id| AttackTarget::Planeswalker(player, id)),
+            )
+            .chain(
+                protected_battles(game, source, player)
+                    .map(move |id| AttackTarget::Battle(player, id)),
+            )
+    })
+}
+
+/// Returns an iterator over battles on the battlefield for which the
+/// [PlayerName] player is the protector.
+pub fn protected_battles(
+    game: &GameState,
+    source: Source,
+    protector: PlayerName,
+) -> impl Iterator<Item = PermanentId> + '_ {
+    player_queries::all_players(game).iter().flat_map(move |controller| {
+        game.battlefield(controller)
+            .iter_matching(game, source, card_predicates::battle)
+            .filter(move |&id| game.card(id).is_some_and(|card| card.protector == Some(protector)))
     })
 }
 
@@ -0,0 +1,82 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::{ToCardId, ZoneQueries};
+use data::core::card_tags::CardTag;
+use data::core::numerics::Damage;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{PermanentId, Source};
+
+use crate::queries::card_queries;
+
+/// Returns true if the `damage_source` card has deathtouch.
+///
+/// Returns false if this card no longer exists.
+pub fn has_deathtouch(game: &GameState, source: Source, damage_source: impl ToCardId) -> bool {
+    has_tag(game, source, damage_source, CardTag::Deathtouch)
+}
+
+/// Returns true if the `damage_source` card has infect.
+///
+/// Returns false if this card no longer exists.
+pub fn has_infect(game: &GameState, source: Source, damage_source: impl ToCardId) -> bool {
+    has_tag(game, source, damage_source, CardTag::Infect)
+}
+
+/// Returns true if damage dealt to creatures by the `damage_source` card
+/// results in -1/-1 counters instead of marked damage, i.e. because it has
+/// wither or infect.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70280>
+///
+/// <https://yawgatog.com/resources/magic-rules/#R70290>
+pub fn deals_damage_as_counters(
+    game: &GameState,
+    source: Source,
+    damage_source: impl ToCardId,
+) -> bool {
+    has_tag(game, source, damage_source, CardTag::Wither) || has_infect(game, source, damage_source)
+}
+
+/// Returns the amount of damage the `damage_source` card must deal to the
+/// [PermanentId] creature for that damage to be lethal.
+///
+/// This is the creature's toughness minus the damage already marked on it, or
+/// 1 if the damage source has deathtouch.
+///
+/// > 702.2c. Any nonzero amount of combat damage assigned to a creature by a
+/// > source with deathtouch is considered to be lethal damage for the purposes
+/// > of determining if a proposed combat damage assignment is valid,
+/// > regardless of that creature's toughness.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7022c>
+///
+/// Returns None if this creature no longer exists.
+pub fn lethal_damage(
+    game: &GameState,
+    source: Source,
+    damage_source: impl ToCardId,
+    creature_id: PermanentId,
+) -> Option<Damage> {
+    if has_deathtouch(game, source, damage_source) {
+        return Some(1);
+    }
+    let toughness = card_queries::toughness(game, source, creature_id)?;
+    let marked = game.card(creature_id)?.damage as i64;
+    Some((toughness - marked).max(0) as Damage)
+}
+
+fn has_tag(game: &GameState, source: Source, damage_source: impl ToCardId, tag: CardTag) -> bool {
+    game.card(damage_source).and_then(|card| card.has_tag(game, source, tag)).unwrap_or(false)
+}
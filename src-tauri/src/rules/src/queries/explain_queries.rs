@@ -0,0 +1,238 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Queries which describe *why* the game is in a given state, used to answer
+//! user questions about the rules engine.
+
+use std::fmt::Debug;
+use std::ops::Add;
+
+use data::card_states::card_state::TappedState;
+use data::card_states::zones::ZoneQueries;
+use data::core::rule_type::RuleType;
+use data::game_states::combat_state::{AttackerId, BlockerId};
+use data::game_states::game_state::GameState;
+use data::properties::card_modifier::CardModifier;
+use data::properties::card_property::CardProperty;
+use data::properties::card_property_data::{CanAttackTarget, CanBeBlocked};
+use data::properties::flag::Flag;
+use data::properties::property_value::Ints;
+use primitives::game_primitives::{CardType, HasController, PermanentId, Source};
+
+use crate::queries::{card_queries, combat_queries, player_queries};
+
+/// Describes the answer to a query about the game along with the rules and
+/// effects which contributed to it.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    /// Short answer to the query.
+    pub summary: String,
+
+    /// Rules and effects which contributed to the answer.
+    pub reasons: Vec<String>,
+}
+
+/// Explains the current power of a permanent. Returns None if this permanent
+/// no longer exists.
+pub fn power(game: &GameState, source: Source, id: PermanentId) -> Option<Explanation> {
+    let card = game.card(id)?;
+    let mut reasons = vec![];
+    int_reasons(game, &card.properties.base_power, "Base power", &mut reasons);
+    int_reasons(game, &card.properties.power, "Power", &mut reasons);
+    Some(Explanation {
+        summary: format!(
+            "{} has power {}",
+            card.displayed_name(),
+            card_queries::power(game, source, id)?
+        ),
+        reasons,
+    })
+}
+
+/// Explains the current toughness of a permanent. Returns None if this
+/// permanent no longer exists.
+pub fn toughness(game: &GameState, source: Source, id: PermanentId) -> Option<Explanation> {
+    let card = game.card(id)?;
+    let mut reasons = vec![];
+    int_reasons(game, &card.properties.base_toughness, "Base toughness", &mut reasons);
+    int_reasons(game, &card.properties.toughness, "Toughness", &mut reasons);
+    Some(Explanation {
+        summary: format!(
+            "{} has toughness {}",
+            card.displayed_name(),
+            card_queries::toughness(game, source, id)?
+        ),
+        reasons,
+    })
+}
+
+/// Explains whether a creature can currently attack. Returns None if this
+/// creature no longer exists.
+///
+/// See [combat_queries::can_attack].
+pub fn can_attack(
+    game: &GameState,
+    source: Source,
+    attacker_id: AttackerId,
+) -> Option<Explanation> {
+    let card = game.card(attacker_id)?;
+    let types = card_queries::card_types(game, source, card.id)?;
+    let turn = game.turn;
+    let mut reasons = vec![];
+    if (card.last_changed_control == turn || card.entered_current_zone == turn)
+        && !combat_queries::can_attack_same_turn(game, source, attacker_id)?
+    {
+        reasons.push("It has not been controlled continuously since the turn began".to_string());
+    }
+    if !player_queries::active_team(game).contains(card.controller()) {
+        reasons.push("Its controller is not on the active team".to_string());
+    }
+    if card.tapped_state != TappedState::Untapped {
+        reasons.push("It is tapped".to_string());
+    }
+    if !types.contains(CardType::Creature) {
+        reasons.push("It is not a creature".to_string());
+    }
+    if types.contains(CardType::Battle) {
+        reasons.push("It is a battle".to_string());
+    }
+    for target in combat_queries::attack_targets(game, source) {
+        flag_reasons(
+            game,
+            &card.properties.can_attack_target,
+            &CanAttackTarget { attacker_id, target },
+            &format!("Attacking {target:?}"),
+            &mut reasons,
+        );
+    }
+
+    let result = combat_queries::can_attack(game, source, attacker_id)?;
+    Some(Explanation {
+        summary: format!(
+            "{} {} attack",
+            card.displayed_name(),
+            if result { "can" } else { "can't" }
+        ),
+        reasons,
+    })
+}
+
+/// Explains whether a creature can currently block. Returns None if this
+/// creature no longer exists.
+///
+/// See [combat_queries::can_block].
+pub fn can_block(game: &GameState, source: Source, blocker_id: BlockerId) -> Option<Explanation> {
+    let card = game.card(blocker_id)?;
+    let types = card_queries::card_types(game, source, card.id)?;
+    let mut reasons = vec![];
+    if player_queries::active_team(game).contains(card.controller()) {
+        reasons.push("Its controller is on the active team".to_string());
+    }
+    if card.tapped_state == TappedState::Tapped {
+        reasons.push("It is tapped".to_string());
+    }
+    if !types.contains(CardType::Creature) {
+        reasons.push("It is not a creature".to_string());
+    }
+    if types.contains(CardType::Battle) {
+        reasons.push("It is a battle".to_string());
+    }
+
+    let attackers = game.combat.as_ref().and_then(|combat| combat.confirmed_attackers());
+    let Some(attackers) = attackers else {
+        reasons.push("There are no attacking creatures".to_string());
+        return Some(Explanation {
+            summary: format!("{} can't block", card.displayed_name()),
+            reasons,
+        });
+    };
+
+    for (&attacker_id, &target) in attackers.all() {
+        let Some(attacker) = game.card(attacker_id) else {
+            continue;
+        };
+        flag_reasons(
+            game,
+            &attacker.properties.can_be_blocked,
+            &CanBeBlocked { attacker_id, target, blocker_id },
+            &format!("Blocking {}", attacker.displayed_name()),
+            &mut reasons,
+        );
+    }
+
+    let result = combat_queries::can_block(game, source, blocker_id)?;
+    Some(Explanation {
+        summary: format!(
+            "{} {} block",
+            card.displayed_name(),
+            if result { "can" } else { "can't" }
+        ),
+        reasons,
+    })
+}
+
+/// Describes the origin of a [CardModifier], e.g. the card whose ability
+/// created it.
+pub fn modifier_origin<T>(game: &GameState, modifier: &CardModifier<T>) -> String {
+    match (modifier.rule_type, modifier.source) {
+        (RuleType::Ability(card_id), _) => format!(
+            "an ability of {}",
+            game.card(card_id).map_or("an unknown card", |card| card.displayed_name())
+        ),
+        (RuleType::Effect, Source::Ability(ability_id)) => format!(
+            "an effect from {}",
+            game.card(ability_id.card_id).map_or("an unknown card", |card| card.displayed_name())
+        ),
+        (RuleType::Effect, Source::Game) => "the rules of the game".to_string(),
+    }
+}
+
+fn int_reasons<T: Default + Copy + Debug + Add<Output = T>>(
+    game: &GameState,
+    property: &CardProperty<Ints<T>>,
+    name: &str,
+    reasons: &mut Vec<String>,
+) {
+    for modifier in property.active_modifiers(game) {
+        let origin = modifier_origin(game, modifier);
+        reasons.push(match modifier.effect {
+            Ints::Set(_, value) => format!("{name} set to {value:?} by {origin}"),
+            Ints::Add(value) => format!("{name} modified by {value:?} from {origin}"),
+        });
+    }
+}
+
+fn flag_reasons<T: 'static>(
+    game: &GameState,
+    property: &CardProperty<Flag<T>>,
+    arg: &T,
+    name: &str,
+    reasons: &mut Vec<String>,
+) {
+    for modifier in property.active_modifiers(game) {
+        let origin = modifier_origin(game, modifier);
+        match &modifier.effect {
+            Flag::Overwrite(_, value) => {
+                reasons.push(format!("{name} set to {value} by {origin}"));
+            }
+            Flag::And(condition) if !condition.invoke(game, modifier.source, arg) => {
+                reasons.push(format!("{name} prevented by {origin}"));
+            }
+            Flag::Or(condition) if condition.invoke(game, modifier.source, arg) => {
+                reasons.push(format!("{name} allowed by {origin}"));
+            }
+            _ => {}
+        }
+    }
+}
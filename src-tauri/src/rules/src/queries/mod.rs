@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod board_queries;
 pub mod card_queries;
 pub mod combat_queries;
+pub mod damage_queries;
+pub mod explain_queries;
+pub mod planechase_queries;
 pub mod player_queries;
 pub mod text_change_queries;
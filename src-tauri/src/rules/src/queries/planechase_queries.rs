@@ -0,0 +1,62 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_states::zones::ZoneQueries;
+use data::game_states::game_state::GameState;
+use data::printed_cards::mana_cost::{ManaCost, ManaCostItem};
+use primitives::game_primitives::{CardId, PlayerName};
+
+use crate::planner::spell_planner;
+
+/// Returns the planar controller of a Planechase game, who controls the
+/// face-up plane or phenomenon card. This is always the active player.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R901>
+pub fn planar_controller(game: &GameState) -> PlayerName {
+    game.turn.active_player
+}
+
+/// Returns the face-up plane or phenomenon card, if this game is using the
+/// Planechase variant.
+pub fn active_plane(game: &GameState) -> Option<CardId> {
+    game.planechase.as_ref()?.active_plane
+}
+
+/// Returns true if the [CardId] card is the face-up plane or phenomenon card.
+pub fn is_active_plane(game: &GameState, card_id: CardId) -> bool {
+    active_plane(game) == Some(card_id)
+}
+
+/// Returns the cost for the [PlayerName] player to roll the planar die.
+///
+/// The first roll each turn is free, and each additional roll costs {1} more
+/// than the previous one.
+pub fn planar_die_cost(game: &GameState, player: PlayerName) -> ManaCost {
+    let rolls = game.history_counters(player).planar_die_rolls;
+    ManaCost { items: vec![ManaCostItem::Generic; rolls] }
+}
+
+/// Returns true if the [PlayerName] player can currently take the special
+/// action to roll the planar die.
+///
+/// This is only possible for the active player while they have priority during
+/// a main phase of their turn with an empty stack.
+pub fn can_roll_planar_die(game: &GameState, player: PlayerName) -> bool {
+    game.planechase.is_some()
+        && game.turn.active_player == player
+        && game.priority == player
+        && game.step.is_main_phase()
+        && game.stack().is_empty()
+        && spell_planner::pay_cost(game, player, planar_die_cost(game, player)).is_some()
+}
@@ -12,34 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::iter;
+
+use data::card_states::counters::CounterType;
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::LifeValue;
 use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use data::printed_cards::card_subtypes::CreatureType;
+use data::properties::card_properties::CardProperties;
+use data::properties::card_property::CardProperty;
+use data::properties::card_property_data::{GameResultQuery, LifeGainQuery};
+use data::properties::flag::Flag;
 use enumset::EnumSet;
-use primitives::game_primitives::PlayerName;
+use primitives::game_primitives::{CardType, Color, PlayerName, Source};
+
+use crate::predicates::card_predicates;
+use crate::queries::card_queries;
 
 /// Returns the next player in turn order after the given [PlayerName].
 ///
 /// This may vary based on game configuration, e.g. in multiplayer games.
+/// Players who have left the game are skipped.
 pub fn next_player_after(game: &GameState, player: PlayerName) -> PlayerName {
-    match game.configuration.all_players.len() {
-        2 => match player {
-            PlayerName::One => PlayerName::Two,
-            PlayerName::Two => PlayerName::One,
-            _ => panic!("{player:?} is not a player in this game"),
-        },
-        3 => match player {
-            PlayerName::One => PlayerName::Two,
-            PlayerName::Two => PlayerName::Three,
-            PlayerName::Three => PlayerName::One,
-            _ => panic!("{player:?} is not a player in this game"),
-        },
-        4 => match player {
-            PlayerName::One => PlayerName::Two,
-            PlayerName::Two => PlayerName::Three,
-            PlayerName::Three => PlayerName::Four,
-            PlayerName::Four => PlayerName::One,
-        },
-        _ => panic!("Unsupported player count"),
+    let mut next = player;
+    for _ in 0..enum_iterator::cardinality::<PlayerName>() {
+        next = enum_iterator::next_cycle(&next);
+        if game.configuration.all_players.contains(next) {
+            return next;
+        }
     }
+    panic!("No players remaining in this game")
 }
 
 /// Returns the number of players currently participating in the provided game
@@ -48,9 +51,53 @@ pub fn player_count(game: &GameState) -> usize {
     game.configuration.all_players.len()
 }
 
-/// Returns the [next_player_after] the active player in this game.
+/// Returns the player who takes the turn after the given [PlayerName]'s turn.
+///
+/// Teams take turns together, so this is the [team_turn_player] of the next
+/// team in turn order after the player's team. In games without teams, this
+/// is equivalent to [next_player_after].
+pub fn next_opponent_after(game: &GameState, player: PlayerName) -> PlayerName {
+    let team = team(game, player);
+    let mut next = player;
+    for _ in 0..enum_iterator::cardinality::<PlayerName>() {
+        next = next_player_after(game, next);
+        if !team.contains(next) {
+            return team_turn_player(game, next);
+        }
+    }
+    next_player_after(game, player)
+}
+
+/// Returns the player who is the active player when the [PlayerName] player's
+/// team takes a turn, i.e. the first player on their team in turn order.
+pub fn team_turn_player(game: &GameState, player: PlayerName) -> PlayerName {
+    team(game, player).iter().next().unwrap_or(player)
+}
+
+/// Returns the [next_opponent_after] the active player in this game, i.e. the
+/// player who will take the next turn.
 pub fn next_player(game: &GameState) -> PlayerName {
-    next_player_after(game, game.turn.active_player)
+    next_opponent_after(game, game.turn.active_player)
+}
+
+/// Returns the set of players on the same team as the indicated player,
+/// including that player.
+///
+/// Players who are not on a team form a team of one. Players who have left the
+/// game are not included.
+pub fn team(game: &GameState, player: PlayerName) -> EnumSet<PlayerName> {
+    game.configuration
+        .teams
+        .and_then(|teams| teams.team(player))
+        .unwrap_or(EnumSet::only(player))
+        .intersection(all_players(game))
+}
+
+/// Returns the [team] of the active player in this game.
+///
+/// Every player on this team takes the current turn together.
+pub fn active_team(game: &GameState) -> EnumSet<PlayerName> {
+    team(game, game.turn.active_player)
 }
 
 /// Returns the names of all players currently playing in the provided game
@@ -60,21 +107,167 @@ pub fn all_players(game: &GameState) -> EnumSet<PlayerName> {
 }
 
 /// Returns the names of all players currently playing in the provided game
-/// who are opponents of the provided player.
+/// who are opponents of the provided player, i.e. who are not on their team.
 pub fn all_opponents(game: &GameState, player: PlayerName) -> EnumSet<PlayerName> {
-    game.configuration.all_players.difference(EnumSet::only(player))
+    game.configuration.all_players.difference(team(game, player) | player)
 }
 
 /// Returns the set of players who are not currently taking their turn.
 pub fn inactive_players(game: &GameState) -> EnumSet<PlayerName> {
-    all_players(game).difference(EnumSet::only(game.turn.active_player))
+    all_players(game).difference(active_team(game) | game.turn.active_player)
 }
 
 /// Returns the number of lands the indicated `player` can still play this turn.
 pub fn land_plays_remaining(game: &GameState, player: PlayerName) -> usize {
-    if game.turn.active_player == player {
+    if active_team(game).contains(player) {
         1usize.saturating_sub(game.history_counters(player).lands_played)
     } else {
         0
     }
 }
+
+/// Returns the number of spells the indicated `player` has cast this turn.
+pub fn spells_cast_this_turn(game: &GameState, player: PlayerName) -> usize {
+    game.history_counters(player).spells_cast_count()
+}
+
+/// Returns the number of spells with the [CardType] card type the indicated
+/// `player` has cast this turn.
+pub fn spells_cast_this_turn_of_type(
+    game: &GameState,
+    player: PlayerName,
+    card_type: CardType,
+) -> usize {
+    game.history_counters(player).spells_cast_of_type(card_type)
+}
+
+/// Returns the total number of spells cast this turn by all players.
+pub fn total_spells_cast_this_turn(game: &GameState) -> usize {
+    all_players(game).iter().map(|player| spells_cast_this_turn(game, player)).sum()
+}
+
+/// Returns the number of poison counters the indicated `player` has.
+pub fn poison_counters(game: &GameState, player: PlayerName) -> u32 {
+    game.player(player).counters.other_counters.get(&CounterType::Poison).copied().unwrap_or(0)
+}
+
+/// Returns true if the indicated `player` is currently able to lose the game.
+///
+/// Effects such as "You can't lose the game" are checked by querying every
+/// permanent on the battlefield.
+pub fn can_lose_game(game: &GameState, source: Source, player: PlayerName) -> bool {
+    query_all_permanents(game, source, player, |p| &p.can_lose_game)
+}
+
+/// Returns true if the indicated `player` is currently able to win the game.
+///
+/// Effects such as "Your opponents can't win the game" are checked by querying
+/// every permanent on the battlefield.
+pub fn can_win_game(game: &GameState, source: Source, player: PlayerName) -> bool {
+    query_all_permanents(game, source, player, |p| &p.can_win_game)
+}
+
+/// Returns the amount of life the indicated `player` would gain if an effect
+/// caused them to gain `amount` life.
+///
+/// Replacement effects such as "If you would gain life, you gain twice that
+/// much life instead" are applied by querying every permanent on the
+/// battlefield. Effects are currently applied in a fixed order rather than
+/// allowing the affected player to choose the order in which they apply.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6161>
+pub fn life_gain_amount(
+    game: &GameState,
+    source: Source,
+    player: PlayerName,
+    amount: LifeValue,
+) -> LifeValue {
+    all_players(game).iter().fold(amount, |amount, controller| {
+        game.battlefield(controller).iter().fold(amount, |amount, &permanent_id| {
+            game.card(permanent_id).map_or(amount, |card| {
+                card.properties.life_gain.query_with(
+                    game,
+                    source,
+                    &LifeGainQuery { permanent_id, player },
+                    amount,
+                )
+            })
+        })
+    })
+}
+
+/// Returns the [PlayerName] player's devotion to the provided colors.
+///
+/// > 700.5a. Devotion to [first color] and [second color] ... counts each mana
+/// > symbol that's one or more of the listed colors only once.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7005a>
+///
+/// See [card_queries::devotion].
+pub fn devotion(
+    game: &GameState,
+    source: Source,
+    player: PlayerName,
+    colors: EnumSet<Color>,
+) -> usize {
+    game.battlefield(player)
+        .iter()
+        .filter_map(|&id| card_queries::devotion(game, source, id, colors))
+        .sum()
+}
+
+/// Returns the number of creatures in the [PlayerName] player's party.
+///
+/// > 700.8a. If a player controls multiple creatures that are Clerics,
+/// > Rogues, Warriors, and/or Wizards, the player chooses which of those
+/// > creatures fill each role in their party so as to maximize the party size.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R7008a>
+///
+/// See [card_queries::PARTY_TYPES].
+pub fn party_size(game: &GameState, source: Source, player: PlayerName) -> usize {
+    let members = game
+        .battlefield(player)
+        .iter()
+        .filter(|&&id| card_predicates::creature(game, source, id) == Some(true))
+        .filter_map(|&id| card_queries::party_classes(game, source, id))
+        .filter(|classes| !classes.is_empty())
+        .collect::<Vec<_>>();
+    largest_party(&members, card_queries::PARTY_TYPES)
+}
+
+/// Returns the largest number of `members` which can each be assigned a
+/// distinct class from `open`.
+fn largest_party(members: &[EnumSet<CreatureType>], open: EnumSet<CreatureType>) -> usize {
+    let Some((first, rest)) = members.split_first() else {
+        return 0;
+    };
+    (*first & open)
+        .iter()
+        .map(|class| 1 + largest_party(rest, open - class))
+        .chain(iter::once(largest_party(rest, open)))
+        .max()
+        .unwrap_or_default()
+}
+
+fn query_all_permanents(
+    game: &GameState,
+    source: Source,
+    player: PlayerName,
+    property: impl Fn(&CardProperties) -> &CardProperty<Flag<GameResultQuery>>,
+) -> bool {
+    all_players(game).iter().all(|controller| {
+        game.battlefield(controller).iter().all(|&permanent_id| {
+            game.card(permanent_id)
+                .and_then(|card| {
+                    property(&card.properties).query_with(
+                        game,
+                        source,
+                        &GameResultQuery { permanent_id, player },
+                        true,
+                    )
+                })
+                .unwrap_or(true)
+        })
+    })
+}
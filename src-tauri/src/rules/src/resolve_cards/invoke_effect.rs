@@ -19,8 +19,7 @@ use data::game_states::game_state::GameState;
 use primitives::game_primitives::{
     AbilityId, EntityId, EventId, HasController, PlayerName, Source, StackAbilityId, StackItemId,
 };
-use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
 use crate::dispatcher::dispatch;
 
@@ -31,7 +30,7 @@ pub fn run(
     ability: &dyn Ability,
     choices: &Option<PlayCardChoices>,
 ) -> Outcome {
-    let mut context = dispatch::build_invocation_context(game, ability_id)?;
+    let mut context = dispatch::build_invocation_context(game, ability_id).or_skip()?;
     match stack_ability_id {
         Some(stack_ability_id) => {
             let stack_ability = game.stack_ability(stack_ability_id);
@@ -40,15 +39,14 @@ pub fn run(
                 // tied back to their origin.
                 context.event_id = custom_effect.event_id;
                 let effect = custom_effect.effect.clone();
-                effect.invoke(game, context);
+                effect.invoke(game, context)
             } else {
-                ability.invoke_effect(game, context, choices);
+                ability.invoke_effect(game, context, choices)
             }
         }
         _ => {
-            let card = game.card(ability_id)?;
-            ability.invoke_effect(game, context, choices);
+            let card = game.card(ability_id).or_skip()?;
+            ability.invoke_effect(game, context, choices)
         }
-    };
-    outcome::OK
+    }
 }
@@ -14,3 +14,4 @@
 
 pub mod invoke_effect;
 pub mod resolve;
+pub mod step_through;
@@ -12,22 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::card_definitions::ability_definition::AbilityType;
+use data::card_definitions::ability_definition::{Ability, AbilityType};
 use data::card_definitions::definitions;
 use data::card_states::card_kind::CardKind;
+use data::card_states::play_card_plan::PlayCardChoices;
 use data::card_states::zones::{ToCardId, ZoneQueries};
 use data::game_states::game_state::GameState;
+use data::game_states::resolution_step::ResolutionStep;
 use enumset::EnumSet;
 use primitives::game_primitives::{
-    AbilityId, CardId, Source, SpellId, StackAbilityId, StackItemId, Zone,
+    AbilityId, CardId, EntityId, HasController, Source, SpellId, StackAbilityId, StackItemId, Zone,
 };
 use tracing::{debug, info};
 use utils::outcome;
-use utils::outcome::Outcome;
+use utils::outcome::{OrSkip, Outcome};
 
-use crate::mutations::{move_card, permanents};
+use crate::mutations::{adventures, move_card, mutate, permanents, spells};
 use crate::queries::card_queries;
 use crate::resolve_cards::invoke_effect;
+use crate::resolve_cards::step_through::StepThrough;
 
 /// Resolve the top item on the stack and apply its effects. Has no effect if
 /// the stack is empty.
@@ -36,32 +39,82 @@ use crate::resolve_cards::invoke_effect;
 /// > top of the stack resolves.
 ///
 /// See <https://yawgatog.com/resources/magic-rules/#R608>
-pub fn resolve_top_of_stack(game: &mut GameState) {
+pub fn resolve_top_of_stack(game: &mut GameState) -> Outcome {
     match game.stack().last().copied() {
         Some(StackItemId::Spell(spell_id)) => {
-            resolve_top_card_of_stack(game, spell_id);
+            outcome::execute(|| resolve_top_card_of_stack(game, spell_id))
         }
         Some(StackItemId::StackAbility(stack_ability_id)) => {
-            resolve_top_ability_of_stack(game, stack_ability_id);
+            outcome::execute(|| resolve_top_ability_of_stack(game, stack_ability_id))
         }
-        _ => {}
+        _ => outcome::OK,
     }
 }
 
 fn resolve_top_card_of_stack(game: &mut GameState, spell_id: SpellId) -> Outcome {
     debug!(?spell_id, "Resolving top card of stack");
-    let card_id = spell_id.to_card_id(game)?;
-    let choices = game.card(card_id)?.cast_choices.clone();
-    let definition = definitions::get(game.card(card_id)?.card_name);
-    for (ability_number, ability) in definition.iterate_abilities() {
-        if ability.get_ability_type() == AbilityType::Spell {
-            let ability_id = AbilityId { card_id, number: ability_number };
-            invoke_effect::run(game, ability_id, None, ability, &choices);
+    let card_id = spell_id.to_card_id(game).or_skip()?;
+    let choices = game.card(card_id).or_skip()?.cast_choices.clone();
+    let definition = definitions::get(game.card(card_id).or_skip()?.card_name);
+    let mut steps = StepThrough::begin(game, game.card(card_id).or_skip()?.controller());
+
+    let abilities = definition
+        .iterate_abilities()
+        .filter(|(_, ability)| ability.get_ability_type() == AbilityType::Spell)
+        .map(|(number, ability)| (AbilityId { card_id, number }, ability))
+        .collect::<Vec<_>>();
+
+    // Targets are chosen for each targeted spell ability in order, but an
+    // ability may have a variable number of targets, so each target is checked
+    // against every targeted ability.
+    let targets = game.card(card_id).or_skip()?.targets.clone();
+    let has_targeted_abilities = abilities.iter().any(|(_, ability)| ability.requires_targets());
+    if let Some(choices) =
+        choices.as_ref().filter(|_| has_targeted_abilities && !targets.is_empty())
+    {
+        steps.pause(game, ResolutionStep::CheckTargets);
+        let legal = targets
+            .iter()
+            .map(|&target| {
+                abilities.iter().any(|&(ability_id, ability)| {
+                    ability.requires_targets()
+                        && is_legal_target(game, ability_id, ability, choices, target)
+                })
+            })
+            .collect::<Vec<_>>();
+        if !legal.contains(&true) {
+            info!(?card_id, "All targets are illegal, spell does not resolve");
+            steps.pause(game, ResolutionStep::AllTargetsIllegal);
+            return spells::counter(game, Source::Game, spell_id);
         }
+
+        let card = game.card_mut(card_id).or_skip()?;
+        card.targets = retain_legal(&card.targets, &legal);
+        card.target_amounts = retain_legal(&card.target_amounts, &legal);
     }
 
-    let card = game.card(card_id)?;
-    if card_queries::card_types(game, Source::Game, card_id)?.iter().any(|t| t.is_permanent()) {
+    let targets = game.card(card_id).or_skip()?.targets.clone();
+    for (ability_id, ability) in abilities {
+        if let Some(choices) = choices.as_ref().filter(|_| ability.requires_targets()) {
+            if ability.target_count(choices).minimum > 0
+                && !targets
+                    .iter()
+                    .any(|&target| is_legal_target(game, ability_id, ability, choices, target))
+            {
+                debug!(?ability_id, "Target is illegal, skipping spell ability");
+                steps.pause(game, ResolutionStep::SkipAbility(ability_id.number));
+                continue;
+            }
+        }
+        steps.pause(game, ResolutionStep::ApplyEffect(ability_id.number));
+        let mark = steps.mark(game);
+        outcome::execute(|| invoke_effect::run(game, ability_id, None, ability, &choices))?;
+        steps.describe_events(game, mark);
+    }
+
+    let card = game.card(card_id).or_skip()?;
+    let card_types = card_queries::card_types(game, Source::Game, card_id).or_skip()?;
+    if card_types.iter().any(|t| t.is_permanent()) {
         // > 608.3. If the object that's resolving is a permanent spell, its resolution may involve
         // > several steps. The instructions in rules 608.3a and b are always performed first. Then
         // > one of the steps in rule 608.3c-e is performed, if appropriate.
@@ -77,14 +130,22 @@ fn resolve_top_card_of_stack(game: &mut GameState, spell_id: SpellId) -> Outcome
                 panic!("Expected only a single face!");
             };
 
+            steps.pause(game, ResolutionStep::Finish(Zone::Battlefield));
             move_card::run(game, Source::Game, card_id, Zone::Battlefield)?;
             permanents::turn_face_up(game, Source::Game, card_id, face)?;
+        } else if card_queries::is_mutating_spell(game, card_id) {
+            steps.pause(game, ResolutionStep::Finish(Zone::Battlefield));
+            mutate::resolve_mutating_spell(game, card_id)?;
         } else {
             todo!("Implement targeting for permanents");
         }
+    } else if card_queries::is_adventure_spell(card) {
+        steps.pause(game, ResolutionStep::Finish(Zone::Exiled));
+        adventures::exile_on_adventure(game, Source::Game, card_id)?;
     } else {
         // > 608.2m. As the final part of an instant or sorcery spell's resolution, the spell
         // is put into its owner's graveyard.
+        steps.pause(game, ResolutionStep::Finish(Zone::Graveyard));
         move_card::run(game, Source::Game, card_id, Zone::Graveyard)?;
     }
 
@@ -95,9 +156,62 @@ fn resolve_top_ability_of_stack(game: &mut GameState, stack_ability_id: StackAbi
     debug!(?stack_ability_id, "Resolving top ability of stack");
     let choices = game.stack_ability(stack_ability_id).choices.clone();
     let ability_id = game.stack_ability(stack_ability_id).ability_id;
-    let ability_definition =
-        definitions::get(game.card(ability_id.card_id)?.card_name).get_ability(ability_id.number);
-    invoke_effect::run(game, ability_id, Some(stack_ability_id), ability_definition, &choices);
+    let ability_definition = definitions::get(game.card(ability_id.card_id).or_skip()?.card_name)
+        .get_ability(ability_id.number);
+    let mut steps = StepThrough::begin(game, game.stack_ability(stack_ability_id).controller);
+
+    if let Some(choices) = choices.as_ref().filter(|_| ability_definition.requires_targets()) {
+        steps.pause(game, ResolutionStep::CheckTargets);
+        let targets = game.stack_ability(stack_ability_id).targets.clone();
+        let legal_targets = targets
+            .iter()
+            .copied()
+            .filter(|&target| {
+                is_legal_target(game, ability_id, ability_definition, choices, target)
+            })
+            .collect::<Vec<_>>();
+        if !targets.is_empty() && legal_targets.is_empty() {
+            info!(?stack_ability_id, "All targets are illegal, ability does not resolve");
+            steps.pause(game, ResolutionStep::AllTargetsIllegal);
+            game.zones.remove_stack_ability(stack_ability_id);
+            return outcome::OK;
+        }
+        game.stack_ability_mut(stack_ability_id).targets = legal_targets;
+    }
+
+    steps.pause(game, ResolutionStep::ApplyEffect(ability_id.number));
+    let mark = steps.mark(game);
+    outcome::execute(|| {
+        invoke_effect::run(game, ability_id, Some(stack_ability_id), ability_definition, &choices)
+    })?;
+    steps.describe_events(game, mark);
     game.zones.remove_stack_ability(stack_ability_id);
     outcome::OK
 }
+
+/// Returns true if `target` is still a legal target for the [AbilityId]
+/// ability as it resolves.
+///
+/// > 608.2b. If the spell or ability specifies targets, it checks whether the
+/// > targets are still legal.
+///
+/// <https://yawgatog.com/resources/magic-rules/#R6082b>
+///
+/// A target is legal if it could currently be chosen for the ability, which
+/// checks the ability's target predicates, that the target has not changed
+/// zones, and properties such as hexproof and protection.
+fn is_legal_target(
+    game: &GameState,
+    ability_id: AbilityId,
+    ability: &dyn Ability,
+    choices: &PlayCardChoices,
+    target: EntityId,
+) -> bool {
+    ability.valid_targets(game, choices, Source::Ability(ability_id)).any(|valid| valid == target)
+}
+
+/// Returns the entries of `values` whose corresponding entry in `legal` is
+/// true.
+fn retain_legal<T: Copy>(values: &[T], legal: &[bool]) -> Vec<T> {
+    values.iter().zip(legal).filter(|(_, &legal)| legal).map(|(&value, _)| value).collect()
+}
@@ -0,0 +1,97 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[allow(unused)] // Used in docs
+use data::game_states::game_state::DebugConfiguration;
+use data::game_states::game_state::{GameOperationMode, GameState};
+use data::game_states::resolution_step::ResolutionStep;
+use data::player_states::player_state::{PlayerQueries, PlayerType};
+use data::text_strings::Text;
+use primitives::game_primitives::PlayerName;
+
+use crate::prompt_handling::prompts;
+use crate::queries::player_queries;
+
+/// Pauses the resolution of a spell or ability before each of its steps,
+/// prompting a human player to continue.
+///
+/// This is enabled via [DebugConfiguration::step_through_resolution] and is
+/// intended for learning the rules and debugging card implementations. The
+/// player can choose to resolve the remaining steps without pausing.
+pub struct StepThrough {
+    player: Option<PlayerName>,
+}
+
+impl StepThrough {
+    /// Begins stepping through the resolution of an object controlled by the
+    /// `controller` player.
+    ///
+    /// The controller is prompted if they are a human player, otherwise the
+    /// first human player in the game is. Stepping is disabled while AI agents
+    /// are searching.
+    pub fn begin(game: &GameState, controller: PlayerName) -> Self {
+        if !game.configuration.debug.step_through_resolution
+            || matches!(game.operation_mode, GameOperationMode::AgentSearch(_))
+        {
+            return Self { player: None };
+        }
+
+        let is_human =
+            |player: PlayerName| matches!(game.player(player).player_type, PlayerType::Human(_));
+        let player = if is_human(controller) {
+            Some(controller)
+        } else {
+            player_queries::all_players(game).iter().find(|&player| is_human(player))
+        };
+        Self { player }
+    }
+
+    /// Describes a [ResolutionStep] which is about to happen, waiting for the
+    /// player to continue.
+    pub fn pause(&mut self, game: &mut GameState, step: ResolutionStep) {
+        let Some(player) = self.player else {
+            return;
+        };
+        let choice = prompts::multiple_choice(game, player, Text::ResolutionStep(step), vec![
+            Text::Continue,
+            Text::Resolve,
+        ]);
+        if choice == Text::Resolve {
+            self.player = None;
+        }
+    }
+
+    /// Returns a marker for the current position in the game's pending
+    /// history events, to later describe events via [Self::describe_events].
+    pub fn mark(&self, game: &GameState) -> usize {
+        game.history.current_events().count()
+    }
+
+    /// Pauses to describe each history event which has occurred since the
+    /// provided [Self::mark].
+    pub fn describe_events(&mut self, game: &mut GameState, mark: usize) {
+        if self.player.is_none() {
+            return;
+        }
+        let steps = game
+            .history
+            .current_events()
+            .skip(mark)
+            .filter_map(ResolutionStep::for_event)
+            .collect::<Vec<_>>();
+        for step in steps {
+            self.pause(game, step);
+        }
+    }
+}
@@ -17,16 +17,26 @@ use std::collections::{BTreeMap, BTreeSet};
 use data::card_states::zones::ZoneQueries;
 use data::core::numerics;
 use data::core::numerics::Damage;
+use data::events::game_events::StepBeganEvent;
+use data::game_states::ability_state::DamageRecipient;
 use data::game_states::combat_state::{
     AttackTarget, AttackerMap, BlockerId, CombatState, ProposedAttackers, ProposedBlockers,
 };
 use data::game_states::game_phase_step::GamePhaseStep;
-use data::game_states::game_state::GameState;
-use primitives::game_primitives::{CardType, PlayerName, Source};
+use data::game_states::game_state::{GameState, GameStatus};
+use data::game_states::turn_schedule::SkippedTurnPart;
+#[allow(unused)] // Used in docs
+use data::game_states::turn_schedule::TurnSchedule;
+use data::player_states::player_state::PlayerQueries;
+use primitives::game_primitives::{CardType, PermanentId, PlayerName, Source};
 use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
 
-use crate::mutations::{change_controller, library, permanents, players, state_based_actions};
-use crate::queries::{card_queries, player_queries};
+use crate::dispatcher::dispatch;
+use crate::mutations::{
+    damage, delayed_triggers, expiring_effects, library, permanents, sagas, state_based_actions,
+};
+use crate::queries::{card_queries, damage_queries, player_queries};
 
 /// Advances the game state to the indicated `step`.
 ///
@@ -34,8 +44,11 @@ use crate::queries::{card_queries, player_queries};
 /// for exiting the previous [GamePhaseStep] and then performs actions
 /// which occur at the start of this step. Increments the turn number and active
 /// player when transitioning to the Untap step.
-pub fn advance(game: &mut GameState) {
-    let step = enum_iterator::next(&game.step).unwrap_or(GamePhaseStep::Untap);
+///
+/// Extra turns, additional combat phases, and skipped turns, phases, and steps
+/// are applied from the game's [TurnSchedule].
+pub fn advance(game: &mut GameState) -> Outcome {
+    let step = next_step(game);
     match step {
         GamePhaseStep::Untap => untap(game),
         GamePhaseStep::Upkeep => upkeep(game),
@@ -53,28 +66,162 @@ pub fn advance(game: &mut GameState) {
     }
 }
 
-fn begin_step(game: &mut GameState, step: GamePhaseStep) {
+/// Returns the step which begins after the current step ends, consuming any
+/// additional combat phases and skipped steps for the active player from the
+/// [TurnSchedule].
+fn next_step(game: &mut GameState) -> GamePhaseStep {
+    let player = game.turn.active_player;
+    let mut step = game.step;
+    loop {
+        step = match step {
+            GamePhaseStep::PostCombatMain if game.turn_schedule.additional_combat_phases > 0 => {
+                // > 505.1a. Only the first main phase of the turn is a precombat
+                // > main phase. All other main phases are postcombat main phases.
+                // <https://yawgatog.com/resources/magic-rules/#R5051a>
+                game.turn_schedule.additional_combat_phases -= 1;
+                GamePhaseStep::BeginCombat
+            }
+            _ => enum_iterator::next(&step).unwrap_or(GamePhaseStep::Untap),
+        };
+
+        if step == GamePhaseStep::Untap {
+            // Skipped turns and untap steps are handled once the next active
+            // player is known.
+            return step;
+        }
+
+        if step == GamePhaseStep::BeginCombat
+            && game.turn_schedule.take_skip(player, SkippedTurnPart::CombatPhase)
+        {
+            step = GamePhaseStep::EndCombat;
+        } else if !is_combat_step(step)
+            && game.turn_schedule.take_skip(player, SkippedTurnPart::Step(step))
+        {
+            continue;
+        } else {
+            return step;
+        }
+    }
+}
+
+/// Returns true if the given step is part of the combat phase.
+fn is_combat_step(step: GamePhaseStep) -> bool {
+    matches!(
+        step,
+        GamePhaseStep::BeginCombat
+            | GamePhaseStep::DeclareAttackers
+            | GamePhaseStep::DeclareBlockers
+            | GamePhaseStep::FirstStrikeDamage
+            | GamePhaseStep::CombatDamage
+            | GamePhaseStep::EndCombat
+    )
+}
+
+/// Returns true if [advance_to] can be used to reach the indicated `step`.
+///
+/// The untap and cleanup steps normally end without any player receiving
+/// priority, and the combat steps after declaring attackers can only be
+/// reached by declaring attackers.
+pub fn can_advance_to(step: GamePhaseStep) -> bool {
+    !matches!(
+        step,
+        GamePhaseStep::Untap
+            | GamePhaseStep::DeclareBlockers
+            | GamePhaseStep::FirstStrikeDamage
+            | GamePhaseStep::CombatDamage
+            | GamePhaseStep::Cleanup
+    )
+}
+
+/// Advances the game state until the indicated `step` begins, performing the
+/// turn-based actions of each intermediate step.
+///
+/// Intended for debugging. Any combat in progress is abandoned as if no
+/// creatures had been declared as attackers. Triggered abilities which
+/// trigger along the way are put on the stack the next time a player would
+/// receive priority. Does nothing if the game is already in this step, and
+/// stops early if the game ends.
+///
+/// Panics if [can_advance_to] returns false for this step.
+pub fn advance_to(game: &mut GameState, step: GamePhaseStep) -> Outcome {
+    assert!(can_advance_to(step), "Cannot advance to step {step:?}");
+    while game.step != step && !matches!(game.status, GameStatus::GameOver { .. }) {
+        skip_step(game)?;
+    }
+    outcome::OK
+}
+
+/// Advances the game state by the indicated number of turns, stopping at the
+/// start of the precombat main phase of the final turn.
+///
+/// See [advance_to].
+pub fn advance_turns(game: &mut GameState, turns: u32) -> Outcome {
+    for _ in 0..turns {
+        let turn = game.turn;
+        while game.turn == turn && !matches!(game.status, GameStatus::GameOver { .. }) {
+            skip_step(game)?;
+        }
+    }
+    advance_to(game, GamePhaseStep::PreCombatMain)
+}
+
+/// Ends the current step, skipping the remaining combat steps if combat is in
+/// progress.
+///
+/// Intended for debugging, see [advance_to].
+pub fn skip_step(game: &mut GameState) -> Outcome {
+    if matches!(
+        game.step,
+        GamePhaseStep::DeclareAttackers
+            | GamePhaseStep::DeclareBlockers
+            | GamePhaseStep::FirstStrikeDamage
+            | GamePhaseStep::CombatDamage
+    ) {
+        // > 508.8. If no creatures are declared as attackers or put onto the
+        // > battlefield attacking, skip the declare blockers and combat damage
+        // > steps.
+        // <https://yawgatog.com/resources/magic-rules/#R5088>
+        game.invalidate_query_caches();
+        game.combat = None;
+        end_combat(game)
+    } else {
+        advance(game)
+    }
+}
+
+fn begin_step(game: &mut GameState, step: GamePhaseStep) -> Outcome {
     game.step = step;
+    game.invalidate_query_caches();
     game.priority = game.turn.active_player;
     game.passed.clear();
+    delayed_triggers::on_begin_step(game);
+    let event = StepBeganEvent { active_player: game.turn.active_player, step };
+    dispatch::game_event(game, |e| &e.step_began, Source::Game, event)
 }
 
-fn untap(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::Untap);
-    let next = player_queries::next_player_after(game, game.turn.active_player);
-    if next == PlayerName::One {
-        game.turn.turn_number += 1;
+fn untap(game: &mut GameState) -> Outcome {
+    begin_turn(game);
+    for player in player_queries::active_team(game) {
+        game.player_mut(player).last_turn = Some(game.turn);
+    }
+    permanents::remove_expired_goads(game);
+    let active_player = game.turn.active_player;
+    if game.turn_schedule.take_skip(active_player, SkippedTurnPart::Step(GamePhaseStep::Untap)) {
+        game.step = GamePhaseStep::Untap;
+        return advance(game);
     }
-    game.turn.active_player = next;
+    begin_step(game, GamePhaseStep::Untap)?;
 
     // > 502.3. Third, the active player determines which permanents they control
     // > will untap. Then they untap them all simultaneously. This turn-based action
     // > doesn't use the stack. Normally, all of a player's permanents untap, but
     // > effects can keep one or more of a player's permanents from untapping.
     // <https://yawgatog.com/resources/magic-rules/#R5023>
-    let to_untap = game.battlefield(next).clone();
-    for &card_id in &to_untap {
-        permanents::untap(game, Source::Game, card_id);
+    for player in player_queries::active_team(game) {
+        let to_untap = game.battlefield(player).clone();
+        for &card_id in &to_untap {
+            outcome::execute(|| permanents::untap_during_untap_step(game, card_id))?;
+        }
     }
 
     // > 502.4. No player receives priority during the untap step, so no spells can
@@ -86,29 +233,68 @@ fn untap(game: &mut GameState) {
     advance(game)
 }
 
-fn upkeep(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::Upkeep);
+/// Updates the active player and turn number for the next turn.
+///
+/// Extra turns are taken first, most recently created first. Afterwards,
+/// normal turn order resumes after the player whose turn was in progress when
+/// the extra turns were created, skipping any turns players have been
+/// instructed to skip.
+///
+/// The turn number is incremented once for every turn taken, including extra
+/// turns, so that each turn has a distinct [TurnData].
+///
+/// [TurnData]: data::game_states::game_state::TurnData
+fn begin_turn(game: &mut GameState) {
+    game.turn_schedule.additional_combat_phases = 0;
+    game.turn.turn_number += 1;
+    if let Some(player) = game.turn_schedule.extra_turns.pop() {
+        let current = game.turn.active_player;
+        game.turn_schedule.resume_after.get_or_insert(current);
+        game.turn.active_player = player;
+        return;
+    }
+
+    let mut previous = game.turn_schedule.resume_after.take().unwrap_or(game.turn.active_player);
+    loop {
+        // Teams take turns together, so each turn passes to the next team.
+        let next = player_queries::next_opponent_after(game, previous);
+        if !game.turn_schedule.take_skip(next, SkippedTurnPart::Turn) {
+            game.turn.active_player = next;
+            return;
+        }
+        previous = next;
+    }
+}
+
+fn upkeep(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::Upkeep)?;
+    let player = game.turn.active_player;
+    dispatch::game_event(game, |e| &e.upkeep, Source::Game, player)
 }
 
-fn draw(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::Draw);
+fn draw(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::Draw)?;
 
     // > 504.1. First, the active player draws a card. This turn-based action
     // doesn't use the stack.
     // <https://yawgatog.com/resources/magic-rules/#R5041>
-    library::draw(game, Source::Game, game.turn.active_player);
+    for player in player_queries::active_team(game) {
+        outcome::execute(|| library::draw(game, Source::Game, player))?;
+    }
+    outcome::OK
 }
 
-fn pre_combat_main(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::PreCombatMain);
+fn pre_combat_main(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::PreCombatMain)?;
+    sagas::on_precombat_main(game)
 }
 
-fn begin_combat(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::BeginCombat);
+fn begin_combat(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::BeginCombat)
 }
 
-fn declare_attackers(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::DeclareAttackers);
+fn declare_attackers(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::DeclareAttackers)?;
     // > 508.1. First, the active player declares attackers. This turn-based action
     // > doesn't use the stack.
     //
@@ -118,14 +304,17 @@ fn declare_attackers(game: &mut GameState) {
     // > active player continuously since the turn began.
     // <https://yawgatog.com/resources/magic-rules/#R5081>
 
+    game.invalidate_query_caches();
+
     game.combat = Some(CombatState::ProposingAttackers(ProposedAttackers {
         proposed_attacks: AttackerMap::default(),
         selected_attackers: BTreeSet::new(),
     }));
+    outcome::OK
 }
 
-fn declare_blockers(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::DeclareBlockers);
+fn declare_blockers(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::DeclareBlockers)?;
     // > 509.1. First, the defending player declares blockers. This turn-based
     // > action doesn't use the stack.
     //
@@ -142,27 +331,30 @@ fn declare_blockers(game: &mut GameState) {
     let Some(CombatState::ConfirmedAttackers(attackers)) = game.combat.take() else {
         panic!("Not in the 'ConfirmedAttackers' state");
     };
+    game.invalidate_query_caches();
     game.combat = Some(CombatState::ProposingBlockers(ProposedBlockers {
         defender: next,
         attackers,
         selected_blockers: BTreeSet::new(),
         proposed_blocks: BTreeMap::new(),
     }));
+    outcome::OK
 }
 
-fn first_strike_damage(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::FirstStrikeDamage);
+fn first_strike_damage(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::FirstStrikeDamage)
 }
 
+/// Combat damage assigned by the first [PermanentId] creature to a recipient.
 pub enum CombatDamageAssignment {
-    Player(PlayerName, Damage),
-    Planeswalker(PlayerName, Damage),
-    Battle(PlayerName, Damage),
-    Creature(BlockerId, Damage),
+    Player(PermanentId, PlayerName, Damage),
+    Planeswalker(PermanentId, PlayerName, Damage),
+    Battle(PermanentId, PermanentId, Damage),
+    Creature(PermanentId, BlockerId, Damage),
 }
 
-fn combat_damage(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::CombatDamage);
+fn combat_damage(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::CombatDamage)?;
     let Some(CombatState::ConfirmedBlockers(blockers)) = &game.combat else {
         panic!("Not in the 'ConfirmedBlockers' state");
     };
@@ -179,29 +371,58 @@ fn combat_damage(game: &mut GameState) {
             // > damage this way don't assign combat damage at all.
             // <https://yawgatog.com/resources/magic-rules/#R5101>
             if blockers.blocked_attackers.contains_key(attacker_id) {
+                // > 510.1c. A blocked creature assigns its combat damage to the creatures
+                // > blocking it.
+                // <https://yawgatog.com/resources/magic-rules/#R5101c>
+                //
+                // Damage is currently assigned automatically: each blocker in turn is
+                // assigned lethal damage, and any remaining damage is assigned to the
+                // last blocker.
                 let blockers = &blockers.blocked_attackers[attacker_id];
-                if blockers.len() != 1 {
-                    // TODO: Implement support for multiple blockers
+                let mut remaining = numerics::power_to_damage(
+                    card_queries::power(game, Source::Game, *attacker_id).or_skip()?,
+                );
+                for (i, &blocker_id) in blockers.iter().enumerate() {
+                    let amount = if i == blockers.len() - 1 {
+                        remaining
+                    } else {
+                        remaining.min(
+                            damage_queries::lethal_damage(
+                                game,
+                                Source::Game,
+                                *attacker_id,
+                                blocker_id,
+                            )
+                            .unwrap_or_default(),
+                        )
+                    };
+                    if amount > 0 {
+                        damage_assignments.push(CombatDamageAssignment::Creature(
+                            *attacker_id,
+                            blocker_id,
+                            amount,
+                        ));
+                        remaining -= amount;
+                    }
                 }
-                let blocker_id = blockers[0];
-                damage_assignments.push(CombatDamageAssignment::Creature(
-                    blocker_id,
-                    numerics::power_to_damage(card_queries::power(
-                        game,
-                        Source::Game,
-                        *attacker_id,
-                    )?),
-                ));
             } else {
                 match target {
                     AttackTarget::Player(player) => {
                         damage_assignments.push(CombatDamageAssignment::Player(
+                            *attacker_id,
                             *player,
-                            numerics::power_to_damage(card_queries::power(
-                                game,
-                                Source::Game,
-                                *attacker_id,
-                            )?),
+                            numerics::power_to_damage(
+                                card_queries::power(game, Source::Game, *attacker_id).or_skip()?,
+                            ),
+                        ));
+                    }
+                    AttackTarget::Battle(_, battle_id) => {
+                        damage_assignments.push(CombatDamageAssignment::Battle(
+                            *attacker_id,
+                            *battle_id,
+                            numerics::power_to_damage(
+                                card_queries::power(game, Source::Game, *attacker_id).or_skip()?,
+                            ),
                         ));
                     }
                     _ => todo!("Implement attack target"),
@@ -209,7 +430,7 @@ fn combat_damage(game: &mut GameState) {
             }
 
             outcome::OK
-        });
+        })?;
     }
 
     for (blocker_id, attackers) in &blockers.reverse_lookup {
@@ -223,13 +444,15 @@ fn combat_damage(game: &mut GameState) {
                 todo!("Implement support for blocking multiple attackers");
             }
             let attacker_id = attackers[0];
+            let power = card_queries::power(game, Source::Game, *blocker_id).or_skip()?;
             damage_assignments.push(CombatDamageAssignment::Creature(
+                *blocker_id,
                 attacker_id,
-                numerics::power_to_damage(card_queries::power(game, Source::Game, *blocker_id)?),
+                numerics::power_to_damage(power),
             ));
 
             outcome::OK
-        });
+        })?;
     }
 
     // > 510.2. Second, all combat damage that's been assigned is dealt
@@ -237,42 +460,56 @@ fn combat_damage(game: &mut GameState) {
     // > the chance to cast spells or activate abilities between the time combat
     // > damage is assigned and the time it's dealt.
     // <https://yawgatog.com/resources/magic-rules/#R5102>
-    for assignment in damage_assignments {
-        match assignment {
-            CombatDamageAssignment::Player(player, damage) => {
-                players::deal_damage(game, Source::Game, player, damage);
-            }
-            CombatDamageAssignment::Planeswalker(player, damage) => {
-                todo!("Implement planeswalker damage");
-            }
-            CombatDamageAssignment::Battle(player, damage) => {
-                todo!("Implement battle damage");
-            }
-            CombatDamageAssignment::Creature(creature_id, damage) => {
-                permanents::deal_damage(game, Source::Game, creature_id, damage);
+    game.simultaneous_animations(|game| {
+        for assignment in damage_assignments {
+            match assignment {
+                CombatDamageAssignment::Player(source_id, player, damage) => {
+                    let recipient = DamageRecipient::Player(player);
+                    outcome::execute(|| {
+                        damage::deal_damage(game, Source::Game, source_id, recipient, damage)
+                    })?;
+                }
+                CombatDamageAssignment::Planeswalker(source_id, player, damage) => {
+                    todo!("Implement planeswalker damage");
+                }
+                CombatDamageAssignment::Battle(source_id, battle_id, damage) => {
+                    let recipient = DamageRecipient::Permanent(battle_id);
+                    outcome::execute(|| {
+                        damage::deal_damage(game, Source::Game, source_id, recipient, damage)
+                    })?;
+                }
+                CombatDamageAssignment::Creature(source_id, creature_id, damage) => {
+                    let recipient = DamageRecipient::Permanent(creature_id);
+                    outcome::execute(|| {
+                        damage::deal_damage(game, Source::Game, source_id, recipient, damage)
+                    })?;
+                }
             }
         }
-    }
+        outcome::OK
+    })
 
     // > 510.3. Third, the active player gets priority.
     // <https://yawgatog.com/resources/magic-rules/#R5103>
 }
 
-fn end_combat(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::EndCombat);
+fn end_combat(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::EndCombat)
 }
 
-fn post_combat_main(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::PostCombatMain);
+fn post_combat_main(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::PostCombatMain)?;
+    game.invalidate_query_caches();
     game.combat = None;
+    outcome::OK
 }
 
-fn end_step(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::EndStep);
+fn end_step(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::EndStep)
 }
 
-fn cleanup(game: &mut GameState) {
-    begin_step(game, GamePhaseStep::Cleanup);
+fn cleanup(game: &mut GameState) -> Outcome {
+    begin_step(game, GamePhaseStep::Cleanup)?;
 
     // > 514.1. First, if the active player's hand contains more cards than their
     // > maximum hand size (normally seven), they discard enough cards to reduce
@@ -291,9 +528,9 @@ fn cleanup(game: &mut GameState) {
         card.damage = 0;
     }
 
-    for (event_id, target_id) in game.ability_state.remove_control_changing_effects() {
-        change_controller::remove_control(game, event_id, target_id);
-    }
+    expiring_effects::revert_expired(game)?;
+    delayed_triggers::remove_expired(game);
+    damage::remove_expired(game);
 
     // > 514.3. Normally, no player receives priority during the cleanup step, so no
     // > spells can be cast and no abilities can be activated. However, this rule is
@@ -307,8 +544,10 @@ fn cleanup(game: &mut GameState) {
     // > gets priority.
     //
     // https://yawgatog.com/resources/magic-rules/#R5143
-    let anything_happened = state_based_actions::on_will_receive_priority(game);
+    let anything_happened = state_based_actions::on_will_receive_priority(game)?;
     if !anything_happened {
         advance(game)
+    } else {
+        outcome::OK
     }
 }
@@ -0,0 +1,141 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use abilities::keyword_abilities::protection;
+use data::core::numerics::{Damage, LifeValue};
+use data::events::event_context::EventContext;
+use data::game_states::ability_state::DamageRecipient;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{Color, HasSource, PermanentId};
+use rhai::{Engine, EvalAltResult};
+use rules::mutations::{damage, library, permanents, players};
+use utils::outcome::{OrSkip, Outcome};
+
+/// A change to the game state requested by a card script.
+///
+/// Scripts cannot mutate the game directly. Instead, a script's `resolve`
+/// function returns a list of commands, which are then applied in order.
+#[derive(Debug, Clone)]
+pub enum ScriptCommand {
+    /// The ability's controller draws this many cards.
+    DrawCards(usize),
+
+    /// The ability's controller gains this much life.
+    GainLife(LifeValue),
+
+    /// Deals this much damage to the ability's target.
+    DamageTarget(Damage),
+
+    /// Returns the ability's target to its owner's hand.
+    ReturnTargetToHand,
+
+    /// Taps the ability's target.
+    TapTarget,
+
+    /// Prevents the next this much damage that would be dealt to the ability's
+    /// target this turn.
+    PreventDamageToTarget(Damage),
+
+    /// Prevents all damage that would be dealt to the ability's controller
+    /// this turn.
+    PreventAllDamageToYou,
+
+    /// The next time a source would deal damage to the ability's controller
+    /// this turn, that damage is dealt to the ability's target instead.
+    RedirectNextDamageToTarget,
+
+    /// All damage that would be dealt to the ability's controller this turn is
+    /// dealt to the ability's target instead.
+    RedirectAllDamageToTarget,
+
+    /// The ability's target gains protection from this color until end of
+    /// turn.
+    TargetGainsProtectionFrom(Color),
+}
+
+/// Registers the functions scripts use to create [ScriptCommand]s.
+pub fn register(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<ScriptCommand>("Command")
+        .register_fn("draw_cards", |count: i64| ScriptCommand::DrawCards(count.max(0) as usize))
+        .register_fn("gain_life", |amount: i64| ScriptCommand::GainLife(amount))
+        .register_fn("damage_target", |amount: i64| {
+            ScriptCommand::DamageTarget(amount.max(0) as Damage)
+        })
+        .register_fn("return_target_to_hand", || ScriptCommand::ReturnTargetToHand)
+        .register_fn("tap_target", || ScriptCommand::TapTarget)
+        .register_fn("prevent_damage_to_target", |amount: i64| {
+            ScriptCommand::PreventDamageToTarget(amount.max(0) as Damage)
+        })
+        .register_fn("prevent_all_damage_to_you", || ScriptCommand::PreventAllDamageToYou)
+        .register_fn("redirect_next_damage_to_target", || ScriptCommand::RedirectNextDamageToTarget)
+        .register_fn("redirect_all_damage_to_target", || ScriptCommand::RedirectAllDamageToTarget)
+        .register_fn(
+            "target_gains_protection_from",
+            |color: &str| -> Result<ScriptCommand, Box<EvalAltResult>> {
+                let color =
+                    Color::from_word(color).ok_or_else(|| format!("Unknown color '{color}'"))?;
+                Ok(ScriptCommand::TargetGainsProtectionFrom(color))
+            },
+        );
+}
+
+/// Applies a [ScriptCommand] to the game.
+///
+/// Skipped if the command requires a target and `target` is None or no
+/// longer exists.
+pub fn apply(
+    game: &mut GameState,
+    context: EventContext,
+    command: &ScriptCommand,
+    target: Option<PermanentId>,
+) -> Outcome {
+    match command {
+        ScriptCommand::DrawCards(count) => {
+            library::draw_cards(game, context, context.controller, *count)
+        }
+        ScriptCommand::GainLife(amount) => {
+            players::gain_life(game, context.source(), context.controller, *amount)
+        }
+        ScriptCommand::DamageTarget(amount) => {
+            let recipient = DamageRecipient::Permanent(target.or_skip()?);
+            damage::deal_damage(game, context, context.this, recipient, *amount)
+        }
+        ScriptCommand::ReturnTargetToHand => {
+            permanents::return_to_hand(game, context, target.or_skip()?)
+        }
+        ScriptCommand::TapTarget => permanents::tap(game, context, target.or_skip()?),
+        ScriptCommand::PreventDamageToTarget(amount) => {
+            let recipient = DamageRecipient::Permanent(target.or_skip()?);
+            damage::prevent_next_this_turn(game, context, recipient, *amount)
+        }
+        ScriptCommand::PreventAllDamageToYou => {
+            let recipient = DamageRecipient::Player(context.controller);
+            damage::prevent_all_this_turn(game, context, recipient)
+        }
+        ScriptCommand::RedirectNextDamageToTarget => {
+            let recipient = DamageRecipient::Player(context.controller);
+            let redirect_to = DamageRecipient::Permanent(target.or_skip()?);
+            damage::redirect_next_this_turn(game, context, recipient, redirect_to)
+        }
+        ScriptCommand::RedirectAllDamageToTarget => {
+            let recipient = DamageRecipient::Player(context.controller);
+            let redirect_to = DamageRecipient::Permanent(target.or_skip()?);
+            damage::redirect_all_this_turn(game, context, recipient, redirect_to)
+        }
+        ScriptCommand::TargetGainsProtectionFrom(color) => {
+            protection::gain_this_turn(game, context, target.or_skip()?, *color)
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_definitions::ability_definition::{Ability, SpellAbility, TargetSelector};
+use data::card_states::play_card_plan::PlayCardChoices;
+use data::card_states::zones::ZoneQueries;
+use data::events::event_context::EventContext;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{
+    CardType, EntityId, HasController, PermanentId, PlayerName, Source,
+};
+use rhai::{Array, Dynamic, Map};
+use rules::queries::{card_queries, player_queries};
+use utils::outcome;
+use utils::outcome::{OrSkip, Outcome};
+
+use crate::script_commands::ScriptCommand;
+use crate::{script_commands, scripts};
+
+/// A spell ability whose effect is defined by the script with the given
+/// `name`.
+///
+/// The script must define a `resolve()` function which returns an array of
+/// commands to apply, e.g.
+///
+/// ```rhai
+/// fn resolve() {
+///     [draw_cards(2), gain_life(2)]
+/// }
+/// ```
+///
+/// See [scripts::directory] for where scripts are loaded from.
+pub fn spell(name: &'static str) -> impl Ability {
+    SpellAbility::new().effect(move |g, c| resolve(g, c, name, None))
+}
+
+/// A spell ability targeting a permanent whose effect is defined by the
+/// script with the given `name`.
+///
+/// In addition to `resolve()`, the script must define a `can_target(card)`
+/// function which returns true if the provided card may be targeted. The card
+/// is a map with the fields `name`, `is_creature`, `is_land`, `is_yours`,
+/// `power` and `toughness`, e.g.
+///
+/// ```rhai
+/// fn can_target(card) {
+///     card.is_creature && !card.is_yours
+/// }
+///
+/// fn resolve() {
+///     [damage_target(3)]
+/// }
+/// ```
+pub fn targeted_spell(name: &'static str) -> impl Ability {
+    SpellAbility::new()
+        .targets(ScriptedPermanentSelector { name })
+        .effect(move |g, c, target: PermanentId| resolve(g, c, name, Some(target)))
+}
+
+/// Target selector for permanents which are valid targets according to a
+/// script's `can_target` function.
+struct ScriptedPermanentSelector {
+    name: &'static str,
+}
+
+impl TargetSelector for ScriptedPermanentSelector {
+    type Target = PermanentId;
+
+    fn valid_targets<'a>(
+        &'a self,
+        game: &'a GameState,
+        choices: &'a PlayCardChoices,
+        source: Source,
+    ) -> Box<dyn Iterator<Item = EntityId> + 'a> {
+        Box::new(player_queries::all_players(game).iter().flat_map(move |player| {
+            game.battlefield(player).iter().filter_map(move |&permanent_id| {
+                let card = card_map(game, source, choices.controller, permanent_id)?;
+                scripts::call::<bool>(self.name, "can_target", (card,))
+                    .filter(|&valid| valid)
+                    .map(|_| permanent_id.into())
+            })
+        }))
+    }
+
+    fn build_target_data(&self, _game: &GameState, targets: &[EntityId]) -> Option<Self::Target> {
+        Self::Target::try_from(*targets.first()?).ok()
+    }
+}
+
+/// Runs the `resolve` function of the script with the given `name` and applies
+/// the resulting commands to the game.
+fn resolve(
+    game: &mut GameState,
+    context: EventContext,
+    name: &str,
+    target: Option<PermanentId>,
+) -> Outcome {
+    let commands = scripts::call::<Array>(name, "resolve", ()).or_skip()?;
+    for command in commands.into_iter().filter_map(Dynamic::try_cast::<ScriptCommand>) {
+        outcome::execute(|| script_commands::apply(game, context, &command, target))?;
+    }
+    outcome::OK
+}
+
+/// Describes a permanent to a script, from the perspective of `player`.
+fn card_map(game: &GameState, source: Source, player: PlayerName, id: PermanentId) -> Option<Map> {
+    let card = game.card(id)?;
+    let types = card_queries::card_types(game, source, id)?;
+    let mut map = Map::new();
+    map.insert("name".into(), card.displayed_name().to_string().into());
+    map.insert("is_creature".into(), types.contains(CardType::Creature).into());
+    map.insert("is_land".into(), types.contains(CardType::Land).into());
+    map.insert("is_yours".into(), (card.controller() == player).into());
+    map.insert("power".into(), card_queries::power(game, source, id).unwrap_or_default().into());
+    map.insert(
+        "toughness".into(),
+        card_queries::toughness(game, source, id).unwrap_or_default().into(),
+    );
+    Some(map)
+}
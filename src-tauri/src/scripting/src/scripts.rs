@@ -0,0 +1,125 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use rhai::{Engine, FuncArgs, Scope, AST};
+use tracing::{error, info};
+use utils::paths;
+
+use crate::script_commands;
+
+/// Maximum number of operations a single script call may perform before it is
+/// terminated, so that a script which loops forever cannot hang the game.
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// Engine used to compile and run all card scripts.
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    script_commands::register(&mut engine);
+    engine
+});
+
+/// Compiled scripts, keyed by script name.
+static SCRIPTS: Lazy<DashMap<String, Arc<AST>>> = Lazy::new(DashMap::new);
+
+/// Directory from which card scripts are loaded.
+///
+/// Each script is a file named `<name>.rhai` in this directory.
+pub fn directory() -> PathBuf {
+    paths::get_data_dir().join("scripts")
+}
+
+/// Returns the names of all scripts in the scripts [directory].
+pub fn all_names() -> Vec<String> {
+    let entries = match fs::read_dir(directory()) {
+        Ok(entries) => entries,
+        Err(e) => {
+            info!(directory = ?directory(), "No scripts loaded: {e}");
+            return vec![];
+        }
+    };
+    let mut names = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rhai"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Discards all compiled scripts and recompiles every script in the scripts
+/// [directory], so that card effects can be edited while the game is running.
+///
+/// Returns the number of scripts which were compiled successfully.
+pub fn reload() -> usize {
+    SCRIPTS.clear();
+    all_names().iter().filter(|name| load(name).is_some()).count()
+}
+
+/// Returns true if the script with the given `name` defines a function named
+/// `function`.
+pub fn has_function(name: &str, function: &str) -> bool {
+    load(name).is_some_and(|ast| ast.iter_functions().any(|f| f.name == function))
+}
+
+/// Calls the function named `function` in the script with the given `name`,
+/// returning its result.
+///
+/// The script is compiled the first time it is used. Changes to its source
+/// file are not picked up until scripts are [reload]ed. Returns None and logs
+/// an error if the script cannot be loaded, the function does not exist, it
+/// exceeds [MAX_OPERATIONS], or it does not return a value of type `T`.
+pub fn call<T: Clone + Send + Sync + 'static>(
+    name: &str,
+    function: &str,
+    args: impl FuncArgs,
+) -> Option<T> {
+    let ast = load(name)?;
+    match ENGINE.call_fn::<T>(&mut Scope::new(), &ast, function, args) {
+        Ok(result) => Some(result),
+        Err(e) => {
+            error!(?name, ?function, "Error running script: {e}");
+            None
+        }
+    }
+}
+
+/// Returns the compiled script with the given `name`, compiling it if it has
+/// not yet been loaded.
+fn load(name: &str) -> Option<Arc<AST>> {
+    if let Some(ast) = SCRIPTS.get(name) {
+        return Some(ast.clone());
+    }
+
+    let path = directory().join(format!("{name}.rhai"));
+    match ENGINE.compile_file(path.clone()) {
+        Ok(ast) => {
+            info!(?path, "Compiled script");
+            let ast = Arc::new(ast);
+            SCRIPTS.insert(name.to_string(), ast.clone());
+            Some(ast)
+        }
+        Err(e) => {
+            error!(?path, "Error compiling script: {e}");
+            None
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use ai::game::agents::AgentName;
+use enumset::EnumSet;
+use primitives::game_primitives::PlayerName;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_testing::run_matchup::MatchOutcome;
+
+/// Z-score for a 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+/// Upper bounds in milliseconds of the buckets used for decision time
+/// histograms. A final bucket holds all slower decisions.
+const HISTOGRAM_BUCKETS_MS: [u64; 8] = [1, 10, 50, 100, 250, 500, 1000, 5000];
+
+/// Machine-readable summary of a batch of matches between two agents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchupReport {
+    pub user: AgentName,
+    pub opponent: AgentName,
+    pub matches: u64,
+    pub user_wins: u64,
+    pub opponent_wins: u64,
+    pub draws: u64,
+    /// Fraction of matches won by the user, counting draws as half a win.
+    pub user_win_rate: f64,
+    /// Lower and upper bounds of the 95% Wilson score interval for
+    /// [Self::user_win_rate].
+    pub confidence_interval: (f64, f64),
+    pub average_turns: f64,
+    pub user_decision_times: DecisionTimeHistogram,
+    pub opponent_decision_times: DecisionTimeHistogram,
+    pub games: Vec<GameReport>,
+}
+
+/// Distribution of the time an agent took to select actions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionTimeHistogram {
+    pub decisions: u64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+    pub buckets: Vec<HistogramBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    /// Upper bound of this bucket in milliseconds, or None for the final
+    /// bucket.
+    pub max_ms: Option<u64>,
+    pub count: u64,
+}
+
+/// Result of a single match within a batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameReport {
+    /// Seed used for random choices in this match, if one was specified.
+    pub seed: Option<u64>,
+    pub winners: Vec<PlayerName>,
+    pub turns: u64,
+}
+
+impl MatchupReport {
+    pub fn new(
+        user: AgentName,
+        opponent: AgentName,
+        results: &[(Option<u64>, MatchOutcome)],
+    ) -> Self {
+        let matches = results.len() as u64;
+        let user_wins = count(results, |o| o.winners == EnumSet::only(PlayerName::One));
+        let opponent_wins = count(results, |o| o.winners == EnumSet::only(PlayerName::Two));
+        let draws = matches - user_wins - opponent_wins;
+        let score = user_wins as f64 + draws as f64 / 2.0;
+        let user_decisions =
+            results.iter().flat_map(|(_, o)| o.user_decision_times.iter().copied());
+        let opponent_decisions =
+            results.iter().flat_map(|(_, o)| o.opponent_decision_times.iter().copied());
+
+        Self {
+            user,
+            opponent,
+            matches,
+            user_wins,
+            opponent_wins,
+            draws,
+            user_win_rate: ratio(score, matches),
+            confidence_interval: wilson_interval(score, matches),
+            average_turns: ratio(results.iter().map(|(_, o)| o.turns as f64).sum(), matches),
+            user_decision_times: DecisionTimeHistogram::new(user_decisions),
+            opponent_decision_times: DecisionTimeHistogram::new(opponent_decisions),
+            games: results
+                .iter()
+                .map(|(seed, o)| GameReport {
+                    seed: *seed,
+                    winners: o.winners.iter().collect(),
+                    turns: o.turns,
+                })
+                .collect(),
+        }
+    }
+
+    /// Prints a human-readable summary of this report.
+    pub fn print(&self) {
+        println!(">>> Results after {} matches", self.matches);
+        println!(
+            "{:?} {} - {} {:?} ({} draws)",
+            self.user, self.user_wins, self.opponent_wins, self.opponent, self.draws
+        );
+        println!(
+            "{:?} win rate: {:.1}% (95% CI {:.1}% - {:.1}%)",
+            self.user,
+            self.user_win_rate * 100.0,
+            self.confidence_interval.0 * 100.0,
+            self.confidence_interval.1 * 100.0
+        );
+        println!("Average game length: {:.1} turns", self.average_turns);
+        self.user_decision_times.print(self.user);
+        self.opponent_decision_times.print(self.opponent);
+    }
+
+    /// Writes this report to the indicated path as JSON.
+    pub fn write(&self, path: &Path) {
+        let data = serde_json::to_vec_pretty(self)
+            .unwrap_or_else(|e| panic!("Error serializing matchup report {e:?}"));
+        fs::write(path, data).unwrap_or_else(|e| panic!("Error writing {path:?} {e:?}"));
+    }
+}
+
+impl DecisionTimeHistogram {
+    fn new(times: impl Iterator<Item = Duration>) -> Self {
+        let mut buckets = HISTOGRAM_BUCKETS_MS
+            .iter()
+            .map(|&max| HistogramBucket { max_ms: Some(max), count: 0 })
+            .chain([HistogramBucket { max_ms: None, count: 0 }])
+            .collect::<Vec<_>>();
+        let mut decisions = 0;
+        let mut total_ms = 0.0;
+        let mut max_ms = 0.0f64;
+        for time in times {
+            let ms = time.as_secs_f64() * 1000.0;
+            decisions += 1;
+            total_ms += ms;
+            max_ms = max_ms.max(ms);
+            let bucket = buckets
+                .iter_mut()
+                .find(|bucket| !bucket.max_ms.is_some_and(|max| ms > max as f64))
+                .expect("Final bucket should accept all times");
+            bucket.count += 1;
+        }
+
+        Self { decisions, mean_ms: ratio(total_ms, decisions), max_ms, buckets }
+    }
+
+    fn print(&self, agent: AgentName) {
+        println!(
+            "{agent:?} decision times: {} decisions, mean {:.1}ms, max {:.1}ms",
+            self.decisions, self.mean_ms, self.max_ms
+        );
+        for bucket in &self.buckets {
+            let label = match bucket.max_ms {
+                Some(max) => format!("<= {max}ms"),
+                None => "slower".to_string(),
+            };
+            println!("  {label:>10}: {}", bucket.count);
+        }
+    }
+}
+
+/// Returns the bounds of the Wilson score interval for a proportion of
+/// `successes` out of `trials`.
+pub fn wilson_interval(successes: f64, trials: u64) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+    let n = trials as f64;
+    let p = successes / n;
+    let z2 = CONFIDENCE_Z * CONFIDENCE_Z;
+    let center = p + z2 / (2.0 * n);
+    let margin = CONFIDENCE_Z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+    let denominator = 1.0 + z2 / n;
+    (((center - margin) / denominator).max(0.0), ((center + margin) / denominator).min(1.0))
+}
+
+fn count(
+    results: &[(Option<u64>, MatchOutcome)],
+    predicate: impl Fn(&MatchOutcome) -> bool,
+) -> u64 {
+    results.iter().filter(|(_, outcome)| predicate(outcome)).count() as u64
+}
+
+fn ratio(value: f64, count: u64) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        value / count as f64
+    }
+}
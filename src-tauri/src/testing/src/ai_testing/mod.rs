@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod matchup_report;
+pub mod random_positions;
 pub mod run_matchup;
+pub mod run_tournament;
+pub mod self_play_export;
 pub mod test_game_builder;
 pub mod test_games;
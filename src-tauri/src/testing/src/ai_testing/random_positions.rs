@@ -0,0 +1,312 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter;
+
+use data::card_definitions::definitions;
+use data::card_states::card_state::CardFacing;
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::TurnNumber;
+use data::decks::deck_name::DeckName;
+use data::decks::format;
+use data::game_states::board_position::{BoardPosition, CardPosition, PlayerPosition};
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::{GameState, TurnData};
+use data::game_states::oracle::Oracle;
+use data::player_states::player_state::PlayerQueries;
+use data::printed_cards::custom_card::{self, CustomCard};
+use data::printed_cards::layout::FaceLayout;
+use data::printed_cards::printed_card::{Face, PrintedCardFace};
+use once_cell::sync::Lazy;
+use oracle::oracle_impl::OracleImpl;
+use primitives::game_primitives::{CardId, CardSupertype, CardType, PlayerName, Source, Zone};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256StarStar;
+use rules::mutations::{move_card, permanents};
+
+use crate::ai_testing::test_games;
+
+/// Cards which random positions are drawn from.
+static CARD_POOL: Lazy<CardPool> = Lazy::new(card_pool);
+
+/// Number of basic lands in each player's library.
+const BASIC_LAND_COUNT: usize = 24;
+
+/// Number of other cards in each player's library.
+const NONBASIC_COUNT: usize = 36;
+
+/// Highest turn number a random position can be on.
+const MAX_TURN_NUMBER: TurnNumber = 15;
+
+/// Maximum number of lands each player can control.
+const MAX_LANDS: usize = 8;
+
+/// Maximum number of creatures each player can control.
+const MAX_CREATURES: usize = 6;
+
+/// Maximum number of cards in each player's hand.
+const MAX_HAND_SIZE: usize = 7;
+
+/// Every card with a card definition which can be included in a deck, so
+/// positions only contain cards the rules engine supports.
+struct CardPool {
+    basic_lands: Vec<CardPosition>,
+    nonbasic: Vec<CardPosition>,
+}
+
+/// Creates a randomized mid-game [GameState] in a player's precombat main
+/// phase, for use in AI evaluation tests and benchmarks.
+///
+/// Each player's library is made up of basic lands and up to 4 copies each of
+/// other cards drawn from the registered card definitions. Every random choice
+/// is made from `seed`, so the same seed always produces the same position.
+///
+/// The position is one which could have been reached by playing the game:
+///
+/// - Each player has between 1 and 20 life.
+/// - Each player controls no more lands than the number of turns they have
+///   taken, and only creatures whose total mana value they could have paid for
+///   over those turns.
+/// - Only the active player's permanents can have entered the battlefield this
+///   turn, including at most one land, and only the active player's lands can
+///   be tapped, as if they had spent mana during this main phase.
+/// - Each player has at most 7 cards in hand, and all other cards remain in
+///   their library.
+///
+/// Only lands and creatures are put onto the battlefield, since other
+/// permanents may need choices to be made as they enter, such as what an Aura
+/// is attached to.
+pub fn generate(seed: u64) -> GameState {
+    let mut rng = Xoshiro256StarStar::seed_from_u64(seed);
+    let turn_number = rng.gen_range(2..=MAX_TURN_NUMBER);
+    let position = BoardPosition {
+        step: GamePhaseStep::PreCombatMain,
+        active_player: active_player_on(turn_number),
+        turn_number,
+        players: [PlayerName::One, PlayerName::Two]
+            .into_iter()
+            .map(|player| PlayerPosition {
+                player,
+                deck_name: DeckName::default(),
+                life: 20,
+                library: random_library(&mut rng),
+                hand: vec![],
+                battlefield: vec![],
+                graveyard: vec![],
+                exile: vec![],
+                command_zone: vec![],
+            })
+            .collect(),
+        stack: vec![],
+    };
+
+    let mut game = test_games::create_from_position(position);
+    game.rng_seed = seed;
+    game.rng = Xoshiro256StarStar::seed_from_u64(seed);
+    for player in [PlayerName::One, PlayerName::Two] {
+        populate_player(&mut game, &mut rng, player);
+    }
+    game
+}
+
+/// Returns the player whose turn it is on the given turn number, since player
+/// one takes the first turn and players then alternate.
+fn active_player_on(turn_number: TurnNumber) -> PlayerName {
+    if turn_number.is_multiple_of(2) {
+        PlayerName::One
+    } else {
+        PlayerName::Two
+    }
+}
+
+/// Returns a shuffled library of cards from the [CARD_POOL].
+fn random_library(rng: &mut Xoshiro256StarStar) -> Vec<CardPosition> {
+    let pool = &*CARD_POOL;
+    let copies = pool
+        .nonbasic
+        .iter()
+        .flat_map(|card| iter::repeat_n(card, format::MAXIMUM_COPIES as usize))
+        .collect::<Vec<_>>();
+    let mut library = (0..BASIC_LAND_COUNT)
+        .filter_map(|_| pool.basic_lands.choose(rng).cloned())
+        .collect::<Vec<_>>();
+    library.extend(copies.choose_multiple(rng, NONBASIC_COUNT).map(|&card| card.clone()));
+    library.shuffle(rng);
+    library
+}
+
+fn card_pool() -> CardPool {
+    let database = test_games::initialize_cards();
+    let oracle = OracleImpl::new(database.clone());
+    let mut result = CardPool { basic_lands: vec![], nonbasic: vec![] };
+    for definition in definitions::all_cards() {
+        let name = definition.card_name();
+        let Some(id) = custom_card::all()
+            .find(|custom| custom.card_name() == name)
+            .map(CustomCard::printed_card_id)
+            .or_else(|| database.fetch_printed_card_id_for_name(name))
+        else {
+            continue;
+        };
+        let printed = oracle.card(id).printed_card_reference;
+        let face = printed.face(Face::Primary);
+        if !can_be_in_deck(face) {
+            continue;
+        }
+
+        let card = CardPosition {
+            name: face.displayed_name.clone(),
+            printed_card_id: Some(id),
+            facing: CardFacing::FaceDown,
+            tapped: false,
+            plus_one_counters: 0,
+            minus_one_counters: 0,
+            damage: 0,
+            entered_this_turn: false,
+        };
+        if face.supertypes.contains(CardSupertype::Basic)
+            && face.card_types.contains(CardType::Land)
+        {
+            result.basic_lands.push(card);
+        } else {
+            result.nonbasic.push(card);
+        }
+    }
+    result
+}
+
+/// Returns false for tokens, emblems, and cards used only by other game
+/// variants, such as planes.
+fn can_be_in_deck(face: &PrintedCardFace) -> bool {
+    let variant_types = CardType::Conspiracy
+        | CardType::Dungeon
+        | CardType::Phenomenon
+        | CardType::Plane
+        | CardType::Scheme
+        | CardType::Vanguard;
+    let excluded_layouts = [FaceLayout::DoubleFacedToken, FaceLayout::Emblem, FaceLayout::Token];
+    !face.card_types.is_empty()
+        && face.card_types.is_disjoint(variant_types)
+        && !excluded_layouts.contains(&face.layout)
+}
+
+/// Puts random lands and creatures from a player's library onto the
+/// battlefield, draws them a random hand, and sets their life total.
+fn populate_player(game: &mut GameState, rng: &mut Xoshiro256StarStar, player: PlayerName) {
+    let active = game.turn.active_player == player;
+    // Includes the current turn, so the active player can have played a land
+    // this turn.
+    let turns_taken =
+        (0..=game.turn.turn_number).filter(|&turn| active_player_on(turn) == player).count();
+
+    let land_count = rng.gen_range(0..=turns_taken.min(MAX_LANDS));
+    let lands = take_from_library(game, rng, player, land_count, |face| {
+        face.card_types.contains(CardType::Land)
+    });
+
+    // Treats each land as having produced one mana on every turn since it was
+    // played, which is an upper bound on the mana available for creatures.
+    let mut mana_budget = land_count * turns_taken;
+    let creature_count = rng.gen_range(0..=MAX_CREATURES);
+    let mut creatures = vec![];
+    for _ in 0..creature_count {
+        let candidates = library_cards(game, player, |face| {
+            is_creature(face) && face.mana_value as usize <= mana_budget
+        });
+        let Some(&id) = candidates.choose(rng) else {
+            break;
+        };
+        mana_budget -= printed_face(game, id).mana_value as usize;
+        creatures.push(id);
+        move_to_battlefield(game, id);
+    }
+
+    // Only one land can have been played this turn
+    let land_played_this_turn = active && rng.gen_bool(0.5);
+    for (i, &id) in lands.iter().enumerate() {
+        if !(land_played_this_turn && i == 0) {
+            entered_previous_turn(game, id);
+        }
+        if active && rng.gen_bool(0.5) {
+            permanents::tap(game, Source::Game, id).expect("Card not found");
+        }
+    }
+    for &id in &creatures {
+        if !active || rng.gen_bool(0.75) {
+            entered_previous_turn(game, id);
+        }
+    }
+
+    let hand_size = rng.gen_range(0..=MAX_HAND_SIZE);
+    for _ in 0..hand_size {
+        let Some(&id) = library_cards(game, player, |_| true).choose(rng) else {
+            break;
+        };
+        move_card::run(game, Source::Game, id, Zone::Hand).expect("Card not found");
+    }
+
+    game.player_mut(player).life = rng.gen_range(1..=20);
+}
+
+/// Moves up to `count` random cards matching `predicate` from a player's
+/// library to the battlefield, returning their [CardId]s.
+fn take_from_library(
+    game: &mut GameState,
+    rng: &mut Xoshiro256StarStar,
+    player: PlayerName,
+    count: usize,
+    predicate: impl Fn(&PrintedCardFace) -> bool,
+) -> Vec<CardId> {
+    let candidates = library_cards(game, player, predicate);
+    let result = candidates.choose_multiple(rng, count).copied().collect::<Vec<_>>();
+    for &id in &result {
+        move_to_battlefield(game, id);
+    }
+    result
+}
+
+/// Returns the [CardId]s of cards in a player's library whose primary face
+/// matches `predicate`, in library order.
+fn library_cards(
+    game: &GameState,
+    player: PlayerName,
+    predicate: impl Fn(&PrintedCardFace) -> bool,
+) -> Vec<CardId> {
+    game.library(player).iter().copied().filter(|&id| predicate(printed_face(game, id))).collect()
+}
+
+fn printed_face(game: &GameState, id: CardId) -> &PrintedCardFace {
+    game.card(id).expect("Card not found").printed().face(Face::Primary)
+}
+
+fn is_creature(face: &PrintedCardFace) -> bool {
+    let excluded = CardType::Land | CardType::Battle | CardType::Planeswalker;
+    face.card_types.contains(CardType::Creature) && face.card_types.is_disjoint(excluded)
+}
+
+fn move_to_battlefield(game: &mut GameState, id: CardId) {
+    move_card::run(game, Source::Game, id, Zone::Battlefield).expect("Card not found");
+    game.card_mut(id).expect("Card not found").facing = CardFacing::FaceUp(Face::Primary);
+}
+
+/// Marks a permanent as having been under its controller's control since the
+/// previous turn, e.g. so that a creature does not have summoning sickness.
+fn entered_previous_turn(game: &mut GameState, id: CardId) {
+    let turn_number = game.turn.turn_number - 1;
+    let previous = TurnData { turn_number, active_player: active_player_on(turn_number) };
+    let card = game.card_mut(id).expect("Card not found");
+    card.entered_current_zone = previous;
+    card.last_changed_control = previous;
+}
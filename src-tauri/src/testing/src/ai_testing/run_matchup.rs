@@ -12,16 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use ai::core::game_state_node::{GameStateNode, GameStatus};
 use ai::game::agents;
 use ai::game::agents::AgentName;
+use ai::monte_carlo::search_checkpoint::CheckpointOptions;
 use clap::{Parser, ValueEnum};
 use data::decks::deck_name;
 use data::game_states::game_state::GameState;
+use enumset::EnumSet;
 use primitives::game_primitives::PlayerName;
 
+use crate::ai_testing::matchup_report::MatchupReport;
 use crate::ai_testing::test_games;
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -44,33 +48,94 @@ pub struct MatchupArgs {
     /// Number of matches to run between these two named players
     #[arg(long, default_value_t = 1)]
     pub matches: u64,
+    /// Random seed for the first match. Each subsequent match uses the next
+    /// seed in sequence.
+    ///
+    /// If not specified, every match uses the default game seed.
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Path to write a JSON report of the results of all matches to.
+    #[arg(long)]
+    pub report: Option<PathBuf>,
     /// How much log output to produce while running
     #[arg(long, value_enum, default_value_t = Verbosity::Matches)]
     pub verbosity: Verbosity,
+    /// File to periodically save the user agent's search progress to, if it
+    /// is a Monte Carlo agent.
+    #[arg(long)]
+    pub checkpoint: Option<PathBuf>,
+    /// Number of search iterations between each checkpoint.
+    #[arg(long, default_value_t = 1000)]
+    pub checkpoint_interval: u32,
+}
+
+/// Result of a single match between two agents.
+#[derive(Debug, Clone)]
+pub struct MatchOutcome {
+    /// Players who won the match. An empty set indicates a draw.
+    pub winners: EnumSet<PlayerName>,
+    /// Number of turns taken over the course of the match.
+    pub turns: u64,
+    /// Time taken to select each action by the user agent.
+    pub user_decision_times: Vec<Duration>,
+    /// Time taken to select each action by the opponent agent.
+    pub opponent_decision_times: Vec<Duration>,
 }
 
 pub fn run_with_args(args: &MatchupArgs) {
+    let checkpoint = args.checkpoint.as_ref().map(|path| {
+        CheckpointOptions::new(path.clone(), args.checkpoint_interval, false)
+            .unwrap_or_else(|e| panic!("{e}"))
+    });
     let user = agents::get_agent(args.user);
     let opponent = agents::get_agent(args.opponent);
+    let mut results = vec![];
 
-    for i in 1..=args.matches {
+    for i in 0..args.matches {
         if args.verbosity >= Verbosity::Matches {
-            println!(">>> Running match {} between {} and {}", i, user.name(), opponent.name());
+            println!(">>> Running match {} between {} and {}", i + 1, user.name(), opponent.name());
         }
-        let mut game = test_games::create(deck_name::GREEN_VANILLA);
-        run_match(args.user, args.opponent, &mut game, args.move_time_ms, args.verbosity);
+        let seed = args.seed.map(|seed| seed.wrapping_add(i));
+        let mut game = match seed {
+            Some(seed) => test_games::create_with_seed(deck_name::GREEN_VANILLA, seed),
+            None => test_games::create(deck_name::GREEN_VANILLA),
+        };
+        let outcome = run_match(
+            args.user,
+            args.opponent,
+            &mut game,
+            args.move_time_ms,
+            args.verbosity,
+            checkpoint.clone(),
+        );
+        results.push((seed, outcome));
+    }
+
+    let report = MatchupReport::new(args.user, args.opponent, &results);
+    if args.matches > 1 {
+        report.print();
+    }
+    if let Some(path) = &args.report {
+        report.write(path);
     }
 }
 
+/// Plays a game between two agents.
+///
+/// The `user_agent` plays as [PlayerName::One], saving its search progress as
+/// described by `user_checkpoint` if provided.
 pub fn run_match(
     user_agent: AgentName,
     opponent_agent: AgentName,
     game: &mut GameState,
     move_time_ms: u64,
     verbosity: Verbosity,
-) -> AgentName {
-    let mut user = agents::get_agent(user_agent);
+    user_checkpoint: Option<CheckpointOptions>,
+) -> MatchOutcome {
+    let mut user = agents::get_agent_with_checkpoint(user_agent, user_checkpoint);
     let mut opponent = agents::get_agent(opponent_agent);
+    let mut user_decision_times = vec![];
+    let mut opponent_decision_times = vec![];
     if verbosity > Verbosity::None {
         println!("Starting game");
     }
@@ -78,9 +143,15 @@ pub fn run_match(
     loop {
         match game.status() {
             GameStatus::InProgress { current_turn } => {
-                let agent = if current_turn == PlayerName::One { &mut user } else { &mut opponent };
-                let deadline = Instant::now() + Duration::from_millis(move_time_ms);
+                let (agent, times) = if current_turn == PlayerName::One {
+                    (&mut user, &mut user_decision_times)
+                } else {
+                    (&mut opponent, &mut opponent_decision_times)
+                };
+                let start = Instant::now();
+                let deadline = start + Duration::from_millis(move_time_ms);
                 let action = agent.pick_action(deadline, game);
+                times.push(start.elapsed());
                 game.execute_action(current_turn, action);
                 clear_action_line(verbosity);
                 if verbosity > Verbosity::None {
@@ -92,7 +163,12 @@ pub fn run_match(
                     clear_action_line(verbosity);
                     println!("Match ended with winners {:?}", winners);
                 }
-                return if winners.contains(PlayerName::One) { user_agent } else { opponent_agent };
+                return MatchOutcome {
+                    winners,
+                    turns: game.turn.turn_number + 1,
+                    user_decision_times,
+                    opponent_decision_times,
+                };
             }
         }
     }
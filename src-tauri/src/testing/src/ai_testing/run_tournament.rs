@@ -0,0 +1,240 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ai::game::agents::AgentName;
+use clap::Parser;
+use data::decks::deck_name;
+use enumset::EnumSet;
+use primitives::game_primitives::PlayerName;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_testing::run_matchup::{self, Verbosity};
+use crate::ai_testing::test_games;
+
+/// Rating assigned to agents before they have played any matches.
+const INITIAL_RATING: f64 = 1500.0;
+
+#[derive(Parser)]
+#[clap()]
+pub struct TournamentArgs {
+    /// Agents to include in the tournament.
+    #[arg(value_enum, required = true, num_args = 2..)]
+    pub agents: Vec<AgentName>,
+    /// Maximum time in milliseconds for each agent to use for moves.
+    #[arg(long, default_value_t = 1000)]
+    pub move_time_ms: u64,
+    /// Number of times each agent plays every other agent from each seat.
+    #[arg(long, default_value_t = 1)]
+    pub rounds: u64,
+    /// Maximum rating change from a single match.
+    #[arg(long, default_value_t = 32.0)]
+    pub k_factor: f64,
+    /// File in which to save tournament progress after every match.
+    ///
+    /// If this file already exists, the tournament resumes from the saved
+    /// state. The same agents and number of rounds must be provided when
+    /// resuming.
+    #[arg(long)]
+    pub state_file: Option<PathBuf>,
+    /// Path to write final ratings to as CSV.
+    #[arg(long)]
+    pub csv: Option<PathBuf>,
+    /// Path to write final ratings and match results to as JSON.
+    #[arg(long)]
+    pub json: Option<PathBuf>,
+    /// How much log output to produce while running
+    #[arg(long, value_enum, default_value_t = Verbosity::Matches)]
+    pub verbosity: Verbosity,
+}
+
+/// Progress of a tournament, used to resume an interrupted run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TournamentState {
+    pub ratings: BTreeMap<AgentName, AgentRating>,
+    pub matches: Vec<TournamentMatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentRating {
+    pub rating: f64,
+    pub wins: u64,
+    pub losses: u64,
+    pub draws: u64,
+}
+
+impl Default for AgentRating {
+    fn default() -> Self {
+        Self { rating: INITIAL_RATING, wins: 0, losses: 0, draws: 0 }
+    }
+}
+
+/// Result of a single completed match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentMatch {
+    pub user: AgentName,
+    pub opponent: AgentName,
+    /// Match score for the user: 1.0 for a win, 0.5 for a draw and 0.0 for a
+    /// loss.
+    pub score: f64,
+}
+
+pub fn run_with_args(args: &TournamentArgs) {
+    let mut state = args.state_file.as_deref().map(load_state).unwrap_or_default();
+    for &agent in &args.agents {
+        state.ratings.entry(agent).or_default();
+    }
+
+    let schedule = schedule(&args.agents, args.rounds);
+    if !state.matches.is_empty() && args.verbosity >= Verbosity::Matches {
+        println!(">>> Resuming after {} of {} matches", state.matches.len(), schedule.len());
+    }
+
+    for (i, &(user, opponent)) in schedule.iter().enumerate().skip(state.matches.len()) {
+        if args.verbosity >= Verbosity::Matches {
+            println!(">>> Running match {} of {}: {user:?} vs {opponent:?}", i + 1, schedule.len());
+        }
+        let mut game = test_games::create(deck_name::GREEN_VANILLA);
+        let outcome = run_matchup::run_match(
+            user,
+            opponent,
+            &mut game,
+            args.move_time_ms,
+            args.verbosity,
+            None,
+        );
+        record_match(&mut state, user, opponent, score(outcome.winners), args.k_factor);
+
+        if let Some(path) = &args.state_file {
+            write_json(path, &state);
+        }
+    }
+
+    print_ratings(&state);
+    if let Some(path) = &args.csv {
+        fs::write(path, ratings_csv(&state))
+            .unwrap_or_else(|e| panic!("Error writing CSV to {path:?} {e:?}"));
+    }
+    if let Some(path) = &args.json {
+        write_json(path, &state);
+    }
+}
+
+/// Returns the expected score for an agent with rating `rating` against an
+/// opponent with rating `opponent_rating`, between 0 and 1.
+pub fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+/// Updates ratings and win/loss records to reflect the result of a match.
+pub fn record_match(
+    state: &mut TournamentState,
+    user: AgentName,
+    opponent: AgentName,
+    score: f64,
+    k_factor: f64,
+) {
+    let user_rating = state.ratings.entry(user).or_default().rating;
+    let opponent_rating = state.ratings.entry(opponent).or_default().rating;
+    let delta = k_factor * (score - expected_score(user_rating, opponent_rating));
+    update_rating(state.ratings.entry(user).or_default(), delta, score);
+    update_rating(state.ratings.entry(opponent).or_default(), -delta, 1.0 - score);
+    state.matches.push(TournamentMatch { user, opponent, score });
+}
+
+fn update_rating(rating: &mut AgentRating, delta: f64, score: f64) {
+    rating.rating += delta;
+    if score > 0.5 {
+        rating.wins += 1;
+    } else if score < 0.5 {
+        rating.losses += 1;
+    } else {
+        rating.draws += 1;
+    }
+}
+
+/// Returns the score for [PlayerName::One] given the winners of a match.
+fn score(winners: EnumSet<PlayerName>) -> f64 {
+    match (winners.contains(PlayerName::One), winners.contains(PlayerName::Two)) {
+        (true, false) => 1.0,
+        (false, true) => 0.0,
+        _ => 0.5,
+    }
+}
+
+/// Returns the ordered list of (user, opponent) pairings to play.
+///
+/// Every agent plays every other agent once from each seat in each round.
+fn schedule(agents: &[AgentName], rounds: u64) -> Vec<(AgentName, AgentName)> {
+    let mut result = vec![];
+    for _ in 0..rounds {
+        for &user in agents {
+            for &opponent in agents {
+                if user != opponent {
+                    result.push((user, opponent));
+                }
+            }
+        }
+    }
+    result
+}
+
+fn print_ratings(state: &TournamentState) {
+    println!(">>> Final ratings");
+    for (agent, rating) in sorted_ratings(state) {
+        println!(
+            "{agent:?}: {:.0} ({}-{}-{})",
+            rating.rating, rating.wins, rating.losses, rating.draws
+        );
+    }
+}
+
+fn ratings_csv(state: &TournamentState) -> String {
+    let mut result = "agent,rating,wins,losses,draws\n".to_string();
+    for (agent, rating) in sorted_ratings(state) {
+        writeln!(
+            result,
+            "{agent:?},{:.1},{},{},{}",
+            rating.rating, rating.wins, rating.losses, rating.draws
+        )
+        .expect("Error writing CSV");
+    }
+    result
+}
+
+/// Returns agent ratings ordered from highest to lowest.
+fn sorted_ratings(state: &TournamentState) -> Vec<(AgentName, &AgentRating)> {
+    let mut result =
+        state.ratings.iter().map(|(&agent, rating)| (agent, rating)).collect::<Vec<_>>();
+    result.sort_by(|(_, a), (_, b)| b.rating.total_cmp(&a.rating));
+    result
+}
+
+fn load_state(path: &Path) -> TournamentState {
+    if !path.exists() {
+        return TournamentState::default();
+    }
+    let data = fs::read(path).unwrap_or_else(|e| panic!("Error reading {path:?} {e:?}"));
+    serde_json::from_slice(&data).unwrap_or_else(|e| panic!("Error parsing {path:?} {e:?}"))
+}
+
+fn write_json(path: &Path, state: &TournamentState) {
+    let data = serde_json::to_vec_pretty(state)
+        .unwrap_or_else(|e| panic!("Error serializing tournament state {e:?}"));
+    fs::write(path, data).unwrap_or_else(|e| panic!("Error writing {path:?} {e:?}"));
+}
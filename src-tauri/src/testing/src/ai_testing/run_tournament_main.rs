@@ -0,0 +1,25 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Parser;
+use testing::ai_testing::run_tournament;
+use testing::ai_testing::run_tournament::TournamentArgs;
+use utils::command_line;
+use utils::command_line::CommandLine;
+
+pub fn main() {
+    command_line::FLAGS.set(CommandLine::default()).ok();
+    let args = TournamentArgs::parse();
+    run_tournament::run_with_args(&args)
+}
@@ -0,0 +1,223 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports training data for policy and value models from self-play games.
+//!
+//! Output is written in JSON Lines format: each line of the output file is a
+//! single JSON object describing one decision made by an agent, with the
+//! following fields:
+//!
+//! - `game`: Index of the game within this export, starting from 0.
+//! - `seed`: Random seed used to create the game.
+//! - `decision`: Index of this decision within the game, starting from 0.
+//! - `player`: Player who made the decision, e.g. `"One"`.
+//! - `features`: [StateFeatures] describing the game state before the decision,
+//!   from the perspective of the deciding player.
+//! - `legal_actions`: Number of legal actions available to the player.
+//! - `action`: The [AgentAction] selected, in its standard serde JSON form.
+//! - `outcome`: Final result of the game for the deciding player: 1.0 for a
+//!   win, 0.5 for a draw and 0.0 for a loss.
+//!
+//! Records for a game are only written once that game has ended.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use ai::core::game_state_node::{GameStateNode, GameStatus};
+use ai::game::agents;
+use ai::game::agents::AgentName;
+use clap::Parser;
+use data::actions::agent_action::AgentAction;
+use data::card_states::zones::ZoneQueries;
+use data::core::numerics::{LifeValue, Power, Toughness, TurnNumber};
+use data::decks::deck_name;
+use data::game_states::game_phase_step::GamePhaseStep;
+use data::game_states::game_state::GameState;
+use data::player_states::player_state::PlayerQueries;
+use enumset::EnumSet;
+use primitives::game_primitives::{PlayerName, Source};
+use rules::queries::{card_queries, player_queries};
+use serde::{Deserialize, Serialize};
+
+use crate::ai_testing::test_games;
+
+#[derive(Parser)]
+#[clap()]
+pub struct SelfPlayArgs {
+    /// Agent to play both sides of each game.
+    #[arg(value_enum)]
+    pub agent: AgentName,
+    /// Path of the JSON Lines file to write training data to.
+    #[arg(long)]
+    pub output: PathBuf,
+    /// Number of games to play.
+    #[arg(long, default_value_t = 1)]
+    pub games: u64,
+    /// Random seed for the first game. Each subsequent game uses the next
+    /// seed in sequence.
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+    /// Maximum time in milliseconds for the agent to use for moves.
+    #[arg(long, default_value_t = 100)]
+    pub move_time_ms: u64,
+}
+
+/// A single decision made during self-play, labeled with the final outcome of
+/// the game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingExample {
+    pub game: u64,
+    pub seed: u64,
+    pub decision: u64,
+    pub player: PlayerName,
+    pub features: StateFeatures,
+    pub legal_actions: u32,
+    pub action: AgentAction,
+    pub outcome: f64,
+}
+
+/// Summary of a game state from the perspective of one player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateFeatures {
+    pub turn_number: TurnNumber,
+    pub step: GamePhaseStep,
+    /// True if it is the deciding player's turn.
+    pub is_active_player: bool,
+    pub stack_size: u32,
+    /// Features of the deciding player.
+    pub player: PlayerFeatures,
+    /// Features of the deciding player's opponent.
+    pub opponent: PlayerFeatures,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerFeatures {
+    pub life: LifeValue,
+    pub hand_size: u32,
+    pub library_size: u32,
+    pub graveyard_size: u32,
+    pub permanents: u32,
+    /// Total power of creatures this player controls.
+    pub total_power: Power,
+    /// Total toughness of creatures this player controls.
+    pub total_toughness: Toughness,
+}
+
+pub fn run_with_args(args: &SelfPlayArgs) {
+    let file = File::create(&args.output)
+        .unwrap_or_else(|e| panic!("Error creating {:?} {e:?}", args.output));
+    let mut writer = BufWriter::new(file);
+    let mut total = 0;
+
+    for game_index in 0..args.games {
+        let seed = args.seed.wrapping_add(game_index);
+        println!(">>> Running self-play game {} of {}", game_index + 1, args.games);
+        let examples = play_game(args.agent, game_index, seed, args.move_time_ms);
+        for example in &examples {
+            serde_json::to_writer(&mut writer, example)
+                .unwrap_or_else(|e| panic!("Error serializing training example {e:?}"));
+            writeln!(writer).unwrap_or_else(|e| panic!("Error writing training example {e:?}"));
+        }
+        total += examples.len();
+    }
+
+    writer.flush().unwrap_or_else(|e| panic!("Error writing {:?} {e:?}", args.output));
+    println!(">>> Wrote {total} training examples to {:?}", args.output);
+}
+
+/// Plays a single game with `agent` controlling both players, returning a
+/// labeled [TrainingExample] for each decision made.
+pub fn play_game(
+    agent_name: AgentName,
+    game_index: u64,
+    seed: u64,
+    move_time_ms: u64,
+) -> Vec<TrainingExample> {
+    let mut game = test_games::create_with_seed(deck_name::GREEN_VANILLA, seed);
+    let agent = agents::get_agent(agent_name);
+    let mut decisions = vec![];
+
+    let winners = loop {
+        match game.status() {
+            GameStatus::InProgress { current_turn } => {
+                let features = features(&game, current_turn);
+                let legal_actions = game.legal_actions(current_turn).count() as u32;
+                let deadline = Instant::now() + Duration::from_millis(move_time_ms);
+                let action = agent.pick_action(deadline, &game);
+                decisions.push((current_turn, features, legal_actions, action));
+                game.execute_action(current_turn, action);
+            }
+            GameStatus::Completed { winners } => break winners,
+        }
+    };
+
+    decisions
+        .into_iter()
+        .enumerate()
+        .map(|(i, (player, features, legal_actions, action))| TrainingExample {
+            game: game_index,
+            seed,
+            decision: i as u64,
+            player,
+            features,
+            legal_actions,
+            action,
+            outcome: outcome(winners, player),
+        })
+        .collect()
+}
+
+/// Computes [StateFeatures] for the current state of `game` from the
+/// perspective of `player`.
+pub fn features(game: &GameState, player: PlayerName) -> StateFeatures {
+    StateFeatures {
+        turn_number: game.turn.turn_number,
+        step: game.step,
+        is_active_player: game.turn.active_player == player,
+        stack_size: game.stack().len() as u32,
+        player: player_features(game, player),
+        opponent: player_features(game, player_queries::next_opponent_after(game, player)),
+    }
+}
+
+fn player_features(game: &GameState, player: PlayerName) -> PlayerFeatures {
+    let battlefield = game.battlefield(player);
+    PlayerFeatures {
+        life: game.player(player).life,
+        hand_size: game.hand(player).len() as u32,
+        library_size: game.library(player).len() as u32,
+        graveyard_size: game.graveyard(player).len() as u32,
+        permanents: battlefield.len() as u32,
+        total_power: battlefield
+            .iter()
+            .filter_map(|&id| card_queries::power(game, Source::Game, id))
+            .sum(),
+        total_toughness: battlefield
+            .iter()
+            .filter_map(|&id| card_queries::toughness(game, Source::Game, id))
+            .sum(),
+    }
+}
+
+fn outcome(winners: EnumSet<PlayerName>, player: PlayerName) -> f64 {
+    if winners.is_empty() {
+        0.5
+    } else if winners.contains(player) {
+        1.0
+    } else {
+        0.0
+    }
+}
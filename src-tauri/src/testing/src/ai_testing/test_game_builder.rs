@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data::actions::debug_action::DebugGameAction;
 use data::card_definitions::card_name::CardName;
 use data::card_states::card_state::CardFacing;
 use data::card_states::zones::ZoneQueries;
@@ -19,6 +20,7 @@ use data::game_states::game_phase_step::GamePhaseStep;
 use data::game_states::game_state::GameState;
 use data::printed_cards::printed_card::Face;
 use primitives::game_primitives::{PlayerName, Source, Zone};
+use rules::action_handlers::debug_actions;
 use rules::mutations::move_card;
 
 #[derive(Debug, Clone, Default)]
@@ -26,11 +28,14 @@ pub struct TestGame {
     p1: TestPlayer,
     p2: TestPlayer,
     step: Option<GamePhaseStep>,
+    seed: Option<u64>,
+    skip_turns: Option<u32>,
+    skip_to_step: Option<GamePhaseStep>,
 }
 
 impl TestGame {
     pub fn new() -> Self {
-        Self { p1: TestPlayer::default(), p2: TestPlayer::default(), step: None }
+        Self::default()
     }
 
     pub fn player_1(mut self, player: TestPlayer) -> Self {
@@ -48,12 +53,48 @@ impl TestGame {
         self
     }
 
+    /// Re-seeds the game's random number generator.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Fast-forwards the game by the indicated number of turns after cards
+    /// have been placed, performing all intermediate turn-based actions.
+    pub fn skip_turns(mut self, turns: u32) -> Self {
+        self.skip_turns = Some(turns);
+        self
+    }
+
+    /// Advances the game to the indicated step after cards have been placed
+    /// and any turns have been skipped, performing all intermediate turn-based
+    /// actions.
+    ///
+    /// Unlike [Self::step], this runs the turn-based actions of each step
+    /// along the way, e.g. drawing a card in the draw step.
+    pub fn skip_to_step(mut self, step: GamePhaseStep) -> Self {
+        self.skip_to_step = Some(step);
+        self
+    }
+
     pub fn apply_to(self, game: &mut GameState) {
         if let Some(step) = self.step {
             game.step = step;
         }
+        if let Some(seed) = self.seed {
+            debug_actions::execute(game, PlayerName::One, DebugGameAction::SetRngSeed(seed))
+                .unwrap_or_else(|e| panic!("Error applying debug action: {e:?}"));
+        }
         self.p1.apply_to(game, PlayerName::One);
         self.p2.apply_to(game, PlayerName::Two);
+        if let Some(turns) = self.skip_turns {
+            debug_actions::execute(game, PlayerName::One, DebugGameAction::SkipTurns(turns))
+                .unwrap_or_else(|e| panic!("Error applying debug action: {e:?}"));
+        }
+        if let Some(step) = self.skip_to_step {
+            debug_actions::execute(game, PlayerName::One, DebugGameAction::SkipToStep(step))
+                .unwrap_or_else(|e| panic!("Error applying debug action: {e:?}"));
+        }
     }
 }
 
@@ -61,6 +102,8 @@ impl TestGame {
 pub struct TestPlayer {
     hand: Vec<CardName>,
     battlefield: Vec<CardName>,
+    stack: Vec<CardName>,
+    next_draws: Vec<CardName>,
 }
 
 impl TestPlayer {
@@ -78,6 +121,20 @@ impl TestPlayer {
         self
     }
 
+    /// Adds a card to the stack as a spell controlled by this player. Spells
+    /// are added in order, so the last card added is on top of the stack.
+    pub fn on_stack(mut self, card: CardName) -> Self {
+        self.stack.push(card);
+        self
+    }
+
+    /// Adds a card to the sequence of cards this player will draw next. The
+    /// card must be present in their library.
+    pub fn next_draw(mut self, card: CardName) -> Self {
+        self.next_draws.push(card);
+        self
+    }
+
     pub fn apply_to(self, state: &mut GameState, player_name: PlayerName) {
         for card in self.hand {
             Self::move_to_zone(state, player_name, card, Zone::Hand, false);
@@ -85,6 +142,17 @@ impl TestPlayer {
         for card in self.battlefield {
             Self::move_to_zone(state, player_name, card, Zone::Battlefield, true);
         }
+        for card in self.stack {
+            Self::move_to_zone(state, player_name, card, Zone::Stack, true);
+        }
+        for card in self.next_draws.into_iter().rev() {
+            debug_actions::execute(
+                state,
+                player_name,
+                DebugGameAction::RigNextDraw(player_name, card),
+            )
+            .unwrap_or_else(|e| panic!("Error applying debug action: {e:?}"));
+        }
     }
 
     fn move_to_zone(
@@ -12,17 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use all_cards::card_list;
+use std::path::Path;
+
+use all_cards::{card_list, generated_cards, scripted_cards};
 use data::card_definitions::card_name;
 use data::decks::deck_name;
 use data::decks::deck_name::DeckName;
+use data::game_states::board_position::BoardPosition;
 use data::game_states::game_phase_step::GamePhaseStep;
-use data::game_states::game_state::{DebugConfiguration, GameState, GameStatus};
+use data::game_states::game_state::{DebugConfiguration, GameState, GameStatus, TeamConfiguration};
+use data::player_states::player_map::PlayerMap;
 use data::player_states::player_state::PlayerType;
 use database::sqlite_database::SqliteDatabase;
-use game::game_creation::new_game;
-use primitives::game_primitives::GameId;
-use utils::paths;
+use game::game_creation::{board_positions, new_game};
+use primitives::game_primitives::{GameId, PlayerName};
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
+use utils::command_line::CommandLine;
+use utils::{command_line, paths};
 use uuid::Uuid;
 
 use crate::ai_testing::test_game_builder::{TestGame, TestPlayer};
@@ -53,20 +60,160 @@ pub fn vanilla_game_scenario() -> GameState {
     game
 }
 
+/// A game in the first main phase in which neither player controls any
+/// permanents.
+pub fn empty_board_scenario() -> GameState {
+    let mut game = create(deck_name::GRIZZLY_BEAR_GIANT_GROWTH);
+    TestGame::new()
+        .step(GamePhaseStep::PreCombatMain)
+        .player_1(bears_player(0, 0))
+        .player_2(bears_player(0, 0))
+        .apply_to(&mut game);
+    game
+}
+
+/// A game in the first main phase in which each player controls 5 Forests and
+/// 5 Grizzly Bears, for 20 permanents in total.
+pub fn large_board_scenario() -> GameState {
+    let mut game = create(deck_name::GRIZZLY_BEAR_GIANT_GROWTH);
+    TestGame::new()
+        .step(GamePhaseStep::PreCombatMain)
+        .player_1(bears_player(5, 0))
+        .player_2(bears_player(5, 0))
+        .apply_to(&mut game);
+    game
+}
+
+/// The board from [large_board_scenario] with 4 Grizzly Bears spells from
+/// each player on the stack.
+pub fn full_stack_scenario() -> GameState {
+    let mut game = create(deck_name::GRIZZLY_BEAR_GIANT_GROWTH);
+    TestGame::new()
+        .step(GamePhaseStep::PreCombatMain)
+        .player_1(bears_player(5, 4))
+        .player_2(bears_player(5, 4))
+        .apply_to(&mut game);
+    game
+}
+
+/// A game in the first main phase using the Green Vanilla deck, in which each
+/// player has the cards described by their [TestPlayer], for use in rules
+/// tests.
+pub fn green_vanilla_scenario(player_1: TestPlayer, player_2: TestPlayer) -> GameState {
+    command_line::FLAGS.set(CommandLine::default()).ok();
+    let mut game = create(deck_name::GREEN_VANILLA);
+    TestGame::new()
+        .step(GamePhaseStep::PreCombatMain)
+        .player_1(player_1)
+        .player_2(player_2)
+        .apply_to(&mut game);
+    game
+}
+
+/// Create a new [GameState] for use in benchmarking & AI testing, using the
+/// provided seed for random choices such as library shuffles.
+pub fn create_with_seed(deck_name: DeckName, seed: u64) -> GameState {
+    let mut game = create(deck_name);
+    game.rng_seed = seed;
+    game.rng = Xoshiro256StarStar::seed_from_u64(seed);
+    game.shuffle_library(PlayerName::One);
+    game.shuffle_library(PlayerName::Two);
+    game
+}
+
+/// Create a new [GameState] for use in AI testing from a position file, e.g.
+/// one written via the 'export' command in the debug panel.
+pub fn load_position(path: &Path) -> GameState {
+    create_from_position(board_positions::read(path).unwrap_or_else(|e| panic!("{e}")))
+}
+
+/// Create a new [GameState] for use in AI testing in which cards are placed as
+/// described in a [BoardPosition].
+pub fn create_from_position(mut position: BoardPosition) -> GameState {
+    let database = initialize_cards();
+    board_positions::validate(&database, &mut position).unwrap_or_else(|e| panic!("{e}"));
+    let mut game = new_game::create_from_position(
+        database,
+        GameId(Uuid::new_v4()),
+        PlayerType::None,
+        PlayerType::None,
+        &position,
+        DebugConfiguration::default(),
+    );
+    game.updates = None;
+    game
+}
+
 /// Create a new [GameState] for use in benchmarking & AI testing
 pub fn create(deck_name: DeckName) -> GameState {
-    card_list::initialize();
-    let database = SqliteDatabase::new(paths::get_data_dir());
+    create_with_decks(deck_name, deck_name)
+}
+
+/// Create a new [GameState] for use in benchmarking & AI testing in which
+/// each player uses a different deck.
+pub fn create_with_decks(p1_deck_name: DeckName, p2_deck_name: DeckName) -> GameState {
+    let database = initialize_cards();
     let mut game = new_game::create(
         database.clone(),
         GameId(Uuid::new_v4()),
         PlayerType::None,
-        deck_name,
+        p1_deck_name,
         PlayerType::None,
-        deck_name,
+        p2_deck_name,
         DebugConfiguration::default(),
-    );
+    )
+    .unwrap_or_else(|e| panic!("Error creating test game: {e}"));
     game.status = GameStatus::Playing;
     game.updates = None;
     game
 }
+
+/// Create a new Two-Headed Giant [GameState] for use in AI testing, in which
+/// every player uses the same deck and opening hands have been drawn.
+pub fn create_two_headed_giant(deck_name: DeckName) -> GameState {
+    let database = initialize_cards();
+    let mut game = new_game::create_team_game_and_start(
+        database,
+        GameId(Uuid::new_v4()),
+        PlayerMap {
+            one: PlayerType::None,
+            two: PlayerType::None,
+            three: PlayerType::None,
+            four: PlayerType::None,
+        },
+        PlayerMap { one: deck_name, two: deck_name, three: deck_name, four: deck_name },
+        TeamConfiguration::two_headed_giant(),
+        DebugConfiguration::default(),
+    )
+    .unwrap_or_else(|e| panic!("Error creating test game: {e}"));
+    game.updates = None;
+    game
+}
+
+/// Registers all card definitions, returning the card database they were
+/// read from.
+pub fn initialize_cards() -> SqliteDatabase {
+    card_list::initialize();
+    scripted_cards::initialize();
+    let database = SqliteDatabase::new(paths::get_data_dir());
+    generated_cards::initialize(&database);
+    database
+}
+
+/// A player from the Grizzly Bears deck with `permanents` Forests and Grizzly
+/// Bears on the battlefield, `spells` Grizzly Bears on the stack, and a mix of
+/// lands, creatures, and instants in hand.
+fn bears_player(permanents: usize, spells: usize) -> TestPlayer {
+    let mut player = TestPlayer::new()
+        .in_hand(card_name::FOREST)
+        .in_hand(card_name::GRIZZLY_BEARS)
+        .in_hand(card_name::GIANT_GROWTH)
+        .in_hand(card_name::GIANT_GROWTH);
+    for _ in 0..permanents {
+        player = player.on_battlefield(card_name::FOREST).on_battlefield(card_name::GRIZZLY_BEARS);
+    }
+    for _ in 0..spells {
+        player = player.on_stack(card_name::GRIZZLY_BEARS);
+    }
+    player
+}
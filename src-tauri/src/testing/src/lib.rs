@@ -14,3 +14,4 @@
 
 pub mod ai_testing;
 pub mod nim;
+pub mod shuffle_audit;
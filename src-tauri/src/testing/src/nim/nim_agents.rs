@@ -17,6 +17,7 @@ use std::marker::PhantomData;
 use ai::core::agent::AgentData;
 use ai::core::win_loss_evaluator::WinLossEvaluator;
 use ai::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use ai::monte_carlo::search_checkpoint::CheckpointOptions;
 use ai::monte_carlo::uct1::Uct1;
 use ai::tree_search::alpha_beta::AlphaBetaAlgorithm;
 use ai::tree_search::minimax::MinimaxAlgorithm;
@@ -34,7 +35,11 @@ pub const NIM_MINIMAX_AGENT: AgentData<MinimaxAlgorithm, WinLossEvaluator, NimSt
 pub const NIM_ALPHA_BETA_AGENT: AgentData<AlphaBetaAlgorithm, WinLossEvaluator, NimState> =
     AgentData::omniscient("ALPHA_BETA", AlphaBetaAlgorithm { search_depth: 25 }, WinLossEvaluator);
 
-pub fn nim_uct1_agent() -> AgentData<
+/// Returns a UCT1 agent for Nim, periodically saving search progress to disk
+/// as described by `checkpoint` if provided.
+pub fn nim_uct1_agent(
+    checkpoint: Option<CheckpointOptions>,
+) -> AgentData<
     MonteCarloAlgorithm<NimState, Uct1>,
     RandomPlayoutEvaluator<NimState, WinLossEvaluator>,
     NimState,
@@ -44,6 +49,7 @@ pub fn nim_uct1_agent() -> AgentData<
         MonteCarloAlgorithm {
             child_score_algorithm: Uct1 {},
             max_iterations: None,
+            checkpoint,
             phantom_data: PhantomData,
         },
         RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
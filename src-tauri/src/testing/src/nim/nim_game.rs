@@ -22,6 +22,7 @@ use ai::core::game_state_node::{GameStateNode, GameStatus};
 use ai::core::state_evaluator::StateEvaluator;
 use ai_core::core::agent_state::AgentState;
 use enumset::{EnumSet, EnumSetType};
+use serde::{Deserialize, Serialize};
 
 /// Asserts that a given `agent` picks an optimal game action for the provided
 /// game state.
@@ -66,13 +67,13 @@ impl StateEvaluator<NimState> for NimPerfectEvaluator {
     }
 }
 
-#[derive(Hash, Ord, PartialOrd, Debug, EnumSetType)]
+#[derive(Hash, Ord, PartialOrd, Debug, EnumSetType, Serialize, Deserialize)]
 pub enum NimPlayer {
     One,
     Two,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum NimPile {
     PileA,
     PileB,
@@ -89,7 +90,7 @@ impl Display for NimPile {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 pub struct NimAction {
     pub pile: NimPile,
     pub amount: u32,
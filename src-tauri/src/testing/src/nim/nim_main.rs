@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use std::io;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use ai::core::agent::{Agent, AgentData};
 use ai::core::game_state_node::{GameStateNode, GameStatus};
+use ai::monte_carlo::search_checkpoint::CheckpointOptions;
 use ai::tree_search::single_level::SingleLevel;
 use clap::{Parser, ValueEnum};
 use testing::nim::nim_agents;
@@ -38,6 +40,16 @@ pub struct Args {
     pub stack_size: u32,
     #[arg(long, default_value_t = 5)]
     pub move_time: u64,
+    /// File to periodically save player one's search progress to, if they are
+    /// a UCT1 agent.
+    #[arg(long)]
+    pub checkpoint: Option<PathBuf>,
+    /// Number of search iterations between each checkpoint.
+    #[arg(long, default_value_t = 1000)]
+    pub checkpoint_interval: u32,
+    /// Continue searching from an existing checkpoint file.
+    #[arg(long)]
+    pub resume: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -54,16 +66,28 @@ pub fn main() {
     let args = Args::parse();
     println!("Welcome to the Game of Nim");
     let nim = NimState::new(args.stack_size);
-    run_game_loop(nim, args.move_time, get_agent(args.player_one), get_agent(args.player_two))
+    let checkpoint = args.checkpoint.as_ref().map(|path| {
+        CheckpointOptions::new(path.clone(), args.checkpoint_interval, args.resume)
+            .unwrap_or_else(|e| panic!("{e}"))
+    });
+    run_game_loop(
+        nim,
+        args.move_time,
+        get_agent(args.player_one, checkpoint),
+        get_agent(args.player_two, None),
+    )
 }
 
-fn get_agent(name: NimAgentName) -> Box<dyn Agent<NimState>> {
+fn get_agent(
+    name: NimAgentName,
+    checkpoint: Option<CheckpointOptions>,
+) -> Box<dyn Agent<NimState>> {
     match name {
         NimAgentName::Human => Box::new(NimHumanAgent {}),
         NimAgentName::Perfect => Box::new(NIM_PERFECT_AGENT),
         NimAgentName::Minimax => Box::new(NIM_MINIMAX_AGENT),
         NimAgentName::AlphaBeta => Box::new(NIM_ALPHA_BETA_AGENT),
-        NimAgentName::UCT1 => Box::new(nim_agents::nim_uct1_agent()),
+        NimAgentName::UCT1 => Box::new(nim_agents::nim_uct1_agent(checkpoint)),
     }
 }
 
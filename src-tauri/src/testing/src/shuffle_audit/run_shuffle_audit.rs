@@ -0,0 +1,278 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use data::card_states::zones::ZoneQueries;
+use data::decks::deck_name;
+use data::game_states::game_state::GameState;
+use primitives::game_primitives::{CardType, PlayerName};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256StarStar;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_testing::test_games;
+
+/// Chi-squared critical value for a 0.1% significance level, indexed by
+/// degrees of freedom minus one.
+const CHI_SQUARED_CRITICAL: [f64; 10] =
+    [10.83, 13.82, 16.27, 18.47, 20.52, 22.46, 24.32, 26.12, 27.88, 29.59];
+
+#[derive(Parser)]
+#[clap()]
+pub struct ShuffleAuditArgs {
+    /// Number of library shuffles to perform
+    #[arg(long, default_value_t = 100_000)]
+    pub iterations: u64,
+    /// Random seed for the first shuffle. Each subsequent shuffle reseeds the
+    /// game with the next seed in sequence, matching how new games are
+    /// seeded.
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+    /// Number of cards in an opening hand
+    #[arg(long, default_value_t = 7)]
+    pub hand_size: usize,
+    /// Number of consecutive lands or nonlands in a library which counts as a
+    /// clump.
+    #[arg(long, default_value_t = 5)]
+    pub clump_length: usize,
+    /// Path to write a JSON report of the results to.
+    #[arg(long)]
+    pub report: Option<PathBuf>,
+}
+
+/// Machine-readable summary of a shuffle fairness audit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShuffleAuditReport {
+    pub iterations: u64,
+    pub library_size: usize,
+    pub lands: usize,
+    /// True if shuffling the same library twice with the same seed produced
+    /// the same order.
+    pub deterministic: bool,
+    /// Number of opening hands observed with each land count, indexed by land
+    /// count.
+    pub observed_hand_lands: Vec<u64>,
+    /// Number of opening hands expected with each land count under a
+    /// perfectly uniform shuffle, indexed by land count.
+    pub expected_hand_lands: Vec<f64>,
+    /// Pearson's chi-squared statistic comparing observed and expected opening
+    /// hand land counts.
+    pub chi_squared: f64,
+    /// Degrees of freedom for [Self::chi_squared].
+    pub degrees_of_freedom: usize,
+    /// True if [Self::chi_squared] is consistent with a uniform shuffle at the
+    /// 0.1% significance level.
+    pub hand_lands_uniform: bool,
+    /// Fraction of shuffled libraries containing a run of lands or nonlands at
+    /// least as long as the configured clump length.
+    pub clump_rate: f64,
+    /// Expected value of [Self::clump_rate] under a perfectly uniform shuffle,
+    /// estimated by shuffling the same library with an independent generator.
+    pub expected_clump_rate: f64,
+    /// Largest difference between the observed fraction of lands at any
+    /// single library position and the overall fraction of lands.
+    pub max_position_deviation: f64,
+}
+
+pub fn run_with_args(args: &ShuffleAuditArgs) {
+    let mut game = test_games::create(deck_name::GREEN_VANILLA);
+    let library_size = game.library(PlayerName::One).len();
+    let lands = is_land_by_position(&game).iter().filter(|&&land| land).count();
+    let hand_size = args.hand_size.min(library_size);
+
+    let mut observed_hand_lands = vec![0; hand_size + 1];
+    let mut position_lands = vec![0u64; library_size];
+    let mut clumps = 0;
+    for i in 0..args.iterations {
+        let seed = args.seed.wrapping_add(i);
+        game.rng_seed = seed;
+        game.rng = Xoshiro256StarStar::seed_from_u64(seed);
+        game.shuffle_library(PlayerName::One);
+
+        let library = is_land_by_position(&game);
+        observed_hand_lands[library.iter().take(hand_size).filter(|&&land| land).count()] += 1;
+        for (position, &land) in library.iter().enumerate() {
+            position_lands[position] += land as u64;
+        }
+        if longest_run(&library) >= args.clump_length {
+            clumps += 1;
+        }
+    }
+
+    let expected_hand_lands = (0..=hand_size)
+        .map(|k| args.iterations as f64 * hypergeometric(library_size, lands, hand_size, k))
+        .collect::<Vec<_>>();
+    let (chi_squared, degrees_of_freedom) = chi_squared(&observed_hand_lands, &expected_hand_lands);
+    let land_fraction = lands as f64 / library_size as f64;
+    let report = ShuffleAuditReport {
+        iterations: args.iterations,
+        library_size,
+        lands,
+        deterministic: check_determinism(&game, args.seed),
+        observed_hand_lands,
+        expected_hand_lands,
+        chi_squared,
+        degrees_of_freedom,
+        hand_lands_uniform: CHI_SQUARED_CRITICAL
+            .get(degrees_of_freedom.saturating_sub(1))
+            .map_or(true, |&critical| chi_squared < critical),
+        clump_rate: clumps as f64 / args.iterations as f64,
+        expected_clump_rate: reference_clump_rate(
+            library_size,
+            lands,
+            args.clump_length,
+            args.iterations,
+        ),
+        max_position_deviation: position_lands
+            .iter()
+            .map(|&count| (count as f64 / args.iterations as f64 - land_fraction).abs())
+            .fold(0.0, f64::max),
+    };
+
+    report.print();
+    if let Some(path) = &args.report {
+        report.write(path);
+    }
+}
+
+impl ShuffleAuditReport {
+    pub fn print(&self) {
+        println!(
+            ">>> Results after {} shuffles of a {} card library with {} lands",
+            self.iterations, self.library_size, self.lands
+        );
+        println!("Same seed produces same order: {}", self.deterministic);
+        println!("Opening hand land counts:");
+        for (lands, (observed, expected)) in
+            self.observed_hand_lands.iter().zip(&self.expected_hand_lands).enumerate()
+        {
+            println!("  {lands:>2} lands: {observed:>8} observed, {expected:>10.1} expected");
+        }
+        println!(
+            "Chi-squared: {:.2} with {} degrees of freedom ({})",
+            self.chi_squared,
+            self.degrees_of_freedom,
+            if self.hand_lands_uniform { "consistent with uniform" } else { "NOT uniform" }
+        );
+        println!(
+            "Clump rate: {:.2}% observed, {:.2}% expected",
+            self.clump_rate * 100.0,
+            self.expected_clump_rate * 100.0
+        );
+        println!(
+            "Largest deviation in land frequency at a library position: {:.3}",
+            self.max_position_deviation
+        );
+    }
+
+    pub fn write(&self, path: &Path) {
+        let data = serde_json::to_vec_pretty(self)
+            .unwrap_or_else(|e| panic!("Error serializing shuffle audit report {e:?}"));
+        fs::write(path, data).unwrap_or_else(|e| panic!("Error writing {path:?} {e:?}"));
+    }
+}
+
+/// Returns whether each card in player one's library is a land, ordered from
+/// the top of the library.
+fn is_land_by_position(game: &GameState) -> Vec<bool> {
+    game.library(PlayerName::One)
+        .iter()
+        .rev()
+        .map(|&id| {
+            game.card(id)
+                .is_some_and(|card| card.printed().face.card_types.contains(CardType::Land))
+        })
+        .collect()
+}
+
+/// Returns true if shuffling the same library twice with the same seed
+/// produces the same order.
+fn check_determinism(game: &GameState, seed: u64) -> bool {
+    let mut first = game.shallow_clone();
+    let mut second = game.shallow_clone();
+    for copy in [&mut first, &mut second] {
+        copy.rng = Xoshiro256StarStar::seed_from_u64(seed);
+        copy.shuffle_library(PlayerName::One);
+    }
+    first.library(PlayerName::One) == second.library(PlayerName::One)
+}
+
+/// Estimates the clump rate of a uniform shuffle using a generator which is
+/// independent of the game's shuffle implementation.
+fn reference_clump_rate(library_size: usize, lands: usize, clump_length: usize, n: u64) -> f64 {
+    let mut rng = Xoshiro256StarStar::seed_from_u64(u64::MAX);
+    let mut library = (0..library_size).map(|i| i < lands).collect::<Vec<_>>();
+    let mut clumps = 0;
+    for _ in 0..n {
+        // Fisher-Yates shuffle
+        for i in (1..library.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            library.swap(i, j);
+        }
+        if longest_run(&library) >= clump_length {
+            clumps += 1;
+        }
+    }
+    clumps as f64 / n as f64
+}
+
+/// Returns the length of the longest run of equal values in `library`.
+fn longest_run(library: &[bool]) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for (i, value) in library.iter().enumerate() {
+        current = if i > 0 && library[i - 1] == *value { current + 1 } else { 1 };
+        longest = longest.max(current);
+    }
+    longest
+}
+
+/// Probability of drawing exactly `k` lands in a hand of `hand_size` cards
+/// from a library of `library_size` cards containing `lands` lands.
+fn hypergeometric(library_size: usize, lands: usize, hand_size: usize, k: usize) -> f64 {
+    if k > lands || hand_size - k > library_size - lands {
+        return 0.0;
+    }
+    choose(lands, k) * choose(library_size - lands, hand_size - k) / choose(library_size, hand_size)
+}
+
+fn choose(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |result, i| result * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Computes Pearson's chi-squared statistic, merging bins with fewer than five
+/// expected observations into their neighbors. Returns the statistic and its
+/// degrees of freedom.
+fn chi_squared(observed: &[u64], expected: &[f64]) -> (f64, usize) {
+    let mut bins = vec![];
+    let (mut pending_observed, mut pending_expected) = (0.0, 0.0);
+    for (&o, &e) in observed.iter().zip(expected) {
+        pending_observed += o as f64;
+        pending_expected += e;
+        if pending_expected >= 5.0 {
+            bins.push((pending_observed, pending_expected));
+            (pending_observed, pending_expected) = (0.0, 0.0);
+        }
+    }
+    if let Some(last) = bins.last_mut() {
+        last.0 += pending_observed;
+        last.1 += pending_expected;
+    }
+
+    let statistic: f64 = bins.iter().map(|(o, e)| (o - e).powi(2) / e).sum();
+    (statistic, bins.len().saturating_sub(1))
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::PathBuf;
+
 use clap::ValueEnum;
 use once_cell::sync::OnceCell;
 
@@ -31,10 +33,14 @@ pub enum TracingStyle {
 #[derive(Clone, Debug)]
 pub struct CommandLine {
     pub tracing_style: TracingStyle,
+
+    /// Program to use as the opponent for games against the AI, in place of
+    /// the built-in agent.
+    pub external_agent: Option<PathBuf>,
 }
 
 impl Default for CommandLine {
     fn default() -> Self {
-        Self { tracing_style: TracingStyle::None }
+        Self { tracing_style: TracingStyle::None, external_agent: None }
     }
 }
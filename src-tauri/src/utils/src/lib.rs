@@ -16,3 +16,5 @@ pub mod bools;
 pub mod command_line;
 pub mod outcome;
 pub mod paths;
+pub mod scratch;
+pub mod span_timing;
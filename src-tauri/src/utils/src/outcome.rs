@@ -12,16 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use std::fmt::{Display, Formatter};
+
+use tracing::error;
+
+/// Reason an operation stopped before applying all of its effects.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HaltCondition {
+    /// The operation did not apply its effects, e.g. because the card in
+    /// question no longer exists. This is an expected part of resolving
+    /// effects.
+    Skipped,
+
     Cancel,
+
+    /// The game state is inconsistent, see [RuleError].
+    RuleError(RuleError),
 }
 
 /// Marker struct indicating that an operation applied its effects successfully.
 ///
-/// Typically, effect functions will return None if they are unable to apply
-/// their operation (e.g. because the card in question no longer exists) and
-/// Some(Success) if the operation occurred successfully.
+/// Typically, effect functions will return [SKIPPED] if they are unable to
+/// apply their operation (e.g. because the card in question no longer exists)
+/// and [OK] if the operation occurred successfully.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Success;
 
@@ -30,28 +43,71 @@ pub struct Success;
 /// This return type should only be used for low-level mutations which perform a
 /// single atomic change to game state like "move a card" or "tap a permanent".
 /// Anything involving larger steps like "untap your lands" or "assign combat
-/// damage" should *not* employ this system, as generally those operations are
-/// expected to continue even if some constituent part is missing.
+/// damage" should *not* stop when a single part is skipped, as generally
+/// those operations are expected to continue even if some constituent part is
+/// missing.
 ///
 /// For convenience in those cases, you can wrap individual blocks of logic in
 /// the [execute] function to have them apply atomically. This is often used in
-/// the bodies of for loops.
-pub type Outcome = Option<Success>;
+/// the bodies of for loops. Rule errors are still propagated by [execute] so
+/// that the operation stops.
+pub type Outcome = Result<Success, HaltCondition>;
 
 /// A constant representing a successful outcome.
-pub const OK: Outcome = Some(Success);
+pub const OK: Outcome = Ok(Success);
 
 /// A constant representing a skipped outcome.
-pub const SKIPPED: Outcome = None;
+pub const SKIPPED: Outcome = Err(HaltCondition::Skipped);
 
-pub trait IsSuccess {
-    fn is_success(&self) -> bool;
-}
-
-/// Executes a function and returns its outcome.
+/// Executes a function, treating a skipped outcome as success.
 ///
 /// Used to create a block of code which should return early if an output is
-/// skipped.
+/// skipped. Any other [HaltCondition], such as a [RuleError], is returned.
 pub fn execute(mut function: impl FnMut() -> Outcome) -> Outcome {
-    function()
+    allow_skip(function())
+}
+
+/// Treats a skipped outcome as success, returning any other [HaltCondition].
+pub fn allow_skip(outcome: Outcome) -> Outcome {
+    match outcome {
+        Err(HaltCondition::Skipped) => OK,
+        result => result,
+    }
+}
+
+/// Converts a missing value into a skipped [Outcome], e.g. to skip an
+/// operation via `?` if a card no longer exists.
+pub trait OrSkip<T> {
+    fn or_skip(self) -> Result<T, HaltCondition>;
+}
+
+impl<T> OrSkip<T> for Option<T> {
+    fn or_skip(self) -> Result<T, HaltCondition> {
+        self.ok_or(HaltCondition::Skipped)
+    }
+}
+
+/// Describes an inconsistency in game state which prevented a rules
+/// operation from being applied, e.g. a card which is not present in the zone
+/// it claims to be in.
+///
+/// Unlike a skipped [Outcome], which is an expected part of resolving effects,
+/// a rule error indicates a bug. Operations return these errors via [error]
+/// instead of panicking so that the game can be restored to its last saved
+/// state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleError(pub String);
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Returns an [Outcome] which halts the current operation with a
+/// [RuleError].
+pub fn error(message: impl Into<String>) -> Outcome {
+    let rule_error = RuleError(message.into());
+    error!(%rule_error, "Rule error");
+    Err(HaltCondition::RuleError(rule_error))
 }
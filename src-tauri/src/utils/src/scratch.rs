@@ -0,0 +1,107 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+
+/// Maximum number of buffers of a given type retained by the pool.
+const MAX_POOLED_BUFFERS: usize = 16;
+
+/// Buffers which have grown larger than this capacity are freed instead of
+/// being returned to the pool, to avoid holding on to large allocations.
+const MAX_RETAINED_CAPACITY: usize = 1024;
+
+thread_local! {
+    /// Pools of reusable buffers, keyed by the [TypeId] of their element type.
+    /// Each value is a `Vec<Vec<T>>` for the corresponding `T`.
+    static POOLS: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// A [Vec] borrowed from a thread-local pool of buffers.
+///
+/// Used for short-lived lists built during a single computation, e.g. legal
+/// actions or valid targets, which would otherwise allocate a new vector every
+/// time they are computed. The buffer is empty when created and is cleared and
+/// returned to the pool when dropped, retaining its capacity for reuse.
+///
+/// Values which need to outlive the computation should be copied into a
+/// regular [Vec] via [Self::into_vec].
+pub struct ScratchVec<T: 'static> {
+    vec: Vec<T>,
+}
+
+impl<T: 'static> ScratchVec<T> {
+    /// Takes an empty buffer from the pool, or creates a new one if the pool is
+    /// empty.
+    pub fn new() -> Self {
+        let vec =
+            POOLS.with(|pools| pool_for::<T>(&mut pools.borrow_mut()).pop()).unwrap_or_default();
+        Self { vec }
+    }
+
+    /// Moves the contents of this buffer into a new [Vec].
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.vec.drain(..).collect()
+    }
+}
+
+impl<T: 'static> Default for ScratchVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> Deref for ScratchVec<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<T: 'static> DerefMut for ScratchVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vec
+    }
+}
+
+impl<T: 'static> Drop for ScratchVec<T> {
+    fn drop(&mut self) {
+        if self.vec.capacity() == 0 || self.vec.capacity() > MAX_RETAINED_CAPACITY {
+            return;
+        }
+
+        let mut vec = std::mem::take(&mut self.vec);
+        vec.clear();
+        // Ignore errors from accessing the pool during thread shutdown
+        let _ = POOLS.try_with(|pools| {
+            if let Ok(mut pools) = pools.try_borrow_mut() {
+                let pool = pool_for::<T>(&mut pools);
+                if pool.len() < MAX_POOLED_BUFFERS {
+                    pool.push(vec);
+                }
+            }
+        });
+    }
+}
+
+fn pool_for<T: 'static>(pools: &mut HashMap<TypeId, Box<dyn Any>>) -> &mut Vec<Vec<T>> {
+    pools
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::new(Vec::<Vec<T>>::new()))
+        .downcast_mut::<Vec<Vec<T>>>()
+        .expect("Scratch buffer pool has incorrect type")
+}
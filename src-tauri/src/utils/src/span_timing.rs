@@ -0,0 +1,157 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use tracing::span::{Attributes, Id};
+use tracing::subscriber::Interest;
+use tracing::Subscriber;
+use tracing_subscriber::filter::DynFilterFn;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Names of the spans whose durations are recorded, in the order in which
+/// they are reported.
+pub const TIMED_SPANS: &[&str] = &[
+    "actions_execute",
+    "legal_actions_compute",
+    "card_queries_power",
+    "card_queries_toughness",
+    "card_queries_card_types",
+    "render_updates",
+];
+
+/// Number of recent durations retained for each span.
+const MAX_SAMPLES: usize = 500;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static SAMPLES: Lazy<Mutex<BTreeMap<&'static str, Samples>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Latency of a span, computed from its most recent durations.
+#[derive(Debug, Clone)]
+pub struct SpanTiming {
+    pub name: &'static str,
+
+    /// Total number of times this span has been recorded.
+    pub count: u64,
+
+    pub p50: Duration,
+
+    pub p95: Duration,
+}
+
+/// Returns a tracing layer which records the duration of each of the
+/// [TIMED_SPANS].
+///
+/// Durations are only recorded while recording is enabled via
+/// [set_enabled], so that spans which would otherwise be disabled are not
+/// created on hot paths like AI search.
+pub fn layer<S>() -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    SpanTimingLayer.with_filter(
+        DynFilterFn::new(|_, _| ENABLED.load(Ordering::Relaxed)).with_callsite_filter(|metadata| {
+            if metadata.is_span() && TIMED_SPANS.contains(&metadata.name()) {
+                Interest::sometimes()
+            } else {
+                Interest::never()
+            }
+        }),
+    )
+}
+
+/// Enables or disables recording span durations.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns the latency of each of the [TIMED_SPANS] which has been recorded
+/// at least once.
+pub fn summary() -> Vec<SpanTiming> {
+    let samples = SAMPLES.lock().expect("Span timing lock poisoned");
+    TIMED_SPANS
+        .iter()
+        .filter_map(|&name| {
+            let entry = samples.get(name)?;
+            let mut recent = entry.recent.iter().copied().collect::<Vec<_>>();
+            recent.sort();
+            Some(SpanTiming {
+                name,
+                count: entry.count,
+                p50: percentile(&recent, 0.5),
+                p95: percentile(&recent, 0.95),
+            })
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Samples {
+    count: u64,
+    recent: VecDeque<Duration>,
+}
+
+struct SpanTimingLayer;
+
+/// Time at which a span was created.
+struct SpanStart(Instant);
+
+impl<S> Layer<S> for SpanTimingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanStart(Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(elapsed) = span.extensions().get::<SpanStart>().map(|start| start.0.elapsed())
+        else {
+            return;
+        };
+        record(span.name(), elapsed);
+    }
+}
+
+fn record(name: &'static str, duration: Duration) {
+    let mut samples = SAMPLES.lock().expect("Span timing lock poisoned");
+    let entry = samples.entry(name).or_default();
+    entry.count += 1;
+    if entry.recent.len() == MAX_SAMPLES {
+        entry.recent.pop_front();
+    }
+    entry.recent.push_back(duration);
+}
+
+/// Returns the value at the given fraction of a sorted list of durations.
+fn percentile(sorted: &[Duration], fraction: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let index = ((sorted.len() - 1) as f64 * fraction).round() as usize;
+    sorted[index]
+}
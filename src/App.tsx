@@ -13,11 +13,43 @@
 // limitations under the License.
 
 import { ReactNode, createContext, useEffect, useState } from 'react';
-import { ClientData, GameMessage, GameResponse, ModalPanel, SceneView } from './generated_types';
+import {
+  AnimationStepView,
+  CardDetailsView,
+  CardSearchView,
+  ChatView,
+  ClientData,
+  DeckCatalogView,
+  GameLogView,
+  GameMessage,
+  GameResponse,
+  MatchStatisticsView,
+  ModalPanel,
+  PerformanceHudView,
+  RandomResultView,
+  RecentChangesView,
+  SceneView,
+} from './generated_types';
 import MainMenu from './MainMenu';
 import { Game } from './game_view/Game';
+import { AnimationContext, playAnimations } from './game_view/animations';
+import { applyGameViewDiff } from './game_view/game_view_diff';
+import { playSounds } from './game_view/sounds';
+import { CardDetails } from './game_view/CardDetails';
+import { CardSearch } from './game_view/CardSearch';
+import { Chat } from './game_view/Chat';
+import { GameLog } from './game_view/GameLog';
+import { PerformanceHud } from './game_view/PerformanceHud';
+import { RandomResult } from './game_view/RandomResult';
+import { RecentChanges } from './game_view/RecentChanges';
 import { connect, handleAction } from './server';
 import { DebugPanelContent } from './panels/DebugPanelContent';
+import { RulesConsoleContent } from './panels/RulesConsoleContent';
+import { SettingsContent } from './panels/SettingsContent';
+import { CampaignContent } from './panels/CampaignContent';
+import { ProfileContent } from './panels/ProfileContent';
+import { ZoneBrowserContent } from './panels/ZoneBrowserContent';
+import { KnownCardsContent } from './panels/KnownCardsContent';
 import { Modal, ModalBody, ModalContent, ModalHeader, useDisclosure } from '@nextui-org/react';
 import { Event, listen } from '@tauri-apps/api/event';
 
@@ -25,6 +57,8 @@ export const GlobalContext: React.Context<ClientData> = createContext({
   id: '',
   userId: '',
   scene: 'loading',
+  connectionId: '',
+  checksum: null,
 } as ClientData);
 
 export function App(): ReactNode {
@@ -32,35 +66,147 @@ export function App(): ReactNode {
   const [sceneView, setSceneView] = useState('loading' as SceneView);
   const [modalPanel, setModalPanel] = useState(null as ModalPanel | null);
   const [gameMessage, setGameMessage] = useState(null as GameMessage | null);
+  const [gameLog, setGameLog] = useState(null as GameLogView | null);
+  const [chat, setChat] = useState(null as ChatView | null);
+  const [cardDetails, setCardDetails] = useState(null as CardDetailsView | null);
+  const [deckCatalog, setDeckCatalog] = useState(null as DeckCatalogView | null);
+  const [matchStatistics, setMatchStatistics] = useState(null as MatchStatisticsView | null);
+  const [recentChanges, setRecentChanges] = useState(null as RecentChangesView | null);
+  const [cardSearch, setCardSearch] = useState(null as CardSearchView | null);
+  const [randomResult, setRandomResult] = useState(null as RandomResultView | null);
+  const [boardDescription, setBoardDescription] = useState(null as string | null);
+  const [performanceHud, setPerformanceHud] = useState(null as PerformanceHudView | null);
+  const [ruleError, setRuleError] = useState(null as string | null);
+  const [crashReport, setCrashReport] = useState(null as string | null);
+  const [animationSteps, setAnimationSteps] = useState([] as AnimationStepView[]);
 
   useEffect(() => {
     connect();
   }, []);
 
   useEffect(() => {
+    let queue = Promise.resolve();
     const unlisten = listen('game_response', (e: Event<GameResponse>) => {
       const state = e.payload;
-      setClientData(state.clientData);
-      if ('updateScene' in state.command) {
-        setSceneView(state.command.updateScene);
-      } else if ('setModalPanel' in state.command) {
-        setModalPanel(state.command.setModalPanel);
-      } else if ('displayGameMessage' in state.command) {
-        setGameMessage(state.command.displayGameMessage.message);
+      if ('playAnimations' in state.command) {
+        const timeline = state.command.playAnimations;
+        queue = queue.then(() => playAnimations(timeline, setAnimationSteps));
+        return;
       }
+
+      // Apply updates only once any preceding animations have finished playing
+      queue = queue.then(() => {
+        setClientData(state.clientData);
+        if ('updateScene' in state.command) {
+          setSceneView(state.command.updateScene);
+          if (state.command.updateScene !== 'loading' && 'gameView' in state.command.updateScene) {
+            // Refresh statistics when next returning to the main menu
+            setMatchStatistics(null);
+          }
+        } else if ('updateGameView' in state.command) {
+          const diff = state.command.updateGameView;
+          setSceneView((previous) =>
+            previous !== 'loading' && 'gameView' in previous
+              ? { gameView: applyGameViewDiff(previous.gameView, diff) }
+              : previous,
+          );
+        } else if ('playSounds' in state.command) {
+          playSounds(state.command.playSounds);
+        } else if ('updateBoardDescription' in state.command) {
+          setBoardDescription(state.command.updateBoardDescription);
+        } else if ('setModalPanel' in state.command) {
+          setModalPanel(state.command.setModalPanel);
+        } else if ('displayGameMessage' in state.command) {
+          setGameMessage(state.command.displayGameMessage.message);
+        } else if ('updateGameLog' in state.command) {
+          setGameLog(state.command.updateGameLog);
+        } else if ('updateChat' in state.command) {
+          setChat(state.command.updateChat);
+        } else if ('showCardDetails' in state.command) {
+          setCardDetails(state.command.showCardDetails);
+        } else if ('showDeckCatalog' in state.command) {
+          setDeckCatalog(state.command.showDeckCatalog);
+        } else if ('showMatchStatistics' in state.command) {
+          setMatchStatistics(state.command.showMatchStatistics);
+        } else if ('showRecentChanges' in state.command) {
+          setRecentChanges(state.command.showRecentChanges);
+        } else if ('showCardSearchResults' in state.command) {
+          setCardSearch(state.command.showCardSearchResults);
+        } else if ('showRandomResult' in state.command) {
+          setRandomResult(state.command.showRandomResult);
+        } else if ('updatePerformanceHud' in state.command) {
+          setPerformanceHud(state.command.updatePerformanceHud);
+        } else if ('showRuleError' in state.command) {
+          setRuleError(state.command.showRuleError);
+        } else if ('showCrashReport' in state.command) {
+          setCrashReport(state.command.showCrashReport);
+        }
+      });
     });
     return () => {
       unlisten.then((f) => f());
     };
   }, []);
 
+  useEffect(() => {
+    if (sceneView === 'loading' || !('gameView' in sceneView)) {
+      return;
+    }
+    const shortcuts: Record<string, unknown> = {
+      F2: { GameAction: 'PassPriority' },
+      F3: 'HoldPriority',
+      F4: 'PassUntilEndOfTurn',
+      F6: 'PassUntilNextTurn',
+      F7: 'ToggleFullControl',
+    };
+    const onKeyDown = (e: KeyboardEvent) => {
+      const action = shortcuts[e.key];
+      if (action != null) {
+        e.preventDefault();
+        handleAction(clientData, action);
+      }
+    };
+    window.addEventListener('keydown', onKeyDown);
+    return () => {
+      window.removeEventListener('keydown', onKeyDown);
+    };
+  }, [clientData, sceneView]);
+
   let scene;
   if (sceneView === 'loading') {
     scene = <h1>Loading...</h1>;
   } else if ('gameView' in sceneView) {
-    scene = <Game key={clientData.id} view={sceneView.gameView} />;
+    scene = (
+      <>
+        <AnimationContext.Provider value={animationSteps}>
+          <Game key={clientData.id} view={sceneView.gameView} />
+        </AnimationContext.Provider>
+        {boardDescription != null && (
+          <div className="sr-only" aria-live="polite" style={{ whiteSpace: 'pre-line' }}>
+            {boardDescription}
+          </div>
+        )}
+        {gameLog != null && <GameLog view={gameLog} />}
+        <Chat view={chat ?? { messages: [] }} />
+        {cardDetails != null && <CardDetails view={cardDetails} />}
+        {recentChanges != null && (
+          <RecentChanges view={recentChanges} onDismiss={() => setRecentChanges(null)} />
+        )}
+        <CardSearch view={cardSearch} onDismiss={() => setCardSearch(null)} />
+        {randomResult != null && (
+          <RandomResult view={randomResult} onDismiss={() => setRandomResult(null)} />
+        )}
+        {performanceHud != null && <PerformanceHud view={performanceHud} />}
+      </>
+    );
   } else if ('mainMenuView' in sceneView) {
-    scene = <MainMenu view={sceneView.mainMenuView} />;
+    scene = (
+      <MainMenu
+        view={sceneView.mainMenuView}
+        deckCatalog={deckCatalog}
+        matchStatistics={matchStatistics}
+      />
+    );
   }
 
   let message;
@@ -80,6 +226,37 @@ export function App(): ReactNode {
     );
   }
 
+  let error;
+  if (ruleError != null) {
+    error = (
+      <Modal isOpen={true} onClose={() => setRuleError(null)}>
+        <ModalContent>
+          <ModalHeader>Rules Error</ModalHeader>
+          <ModalBody>
+            <p>{ruleError}</p>
+            <p>The game has been restored to its last saved state.</p>
+          </ModalBody>
+        </ModalContent>
+      </Modal>
+    );
+  }
+
+  let crash;
+  if (crashReport != null) {
+    crash = (
+      <Modal isOpen={true} onClose={() => setCrashReport(null)}>
+        <ModalContent>
+          <ModalHeader>Unexpected Error</ModalHeader>
+          <ModalBody>
+            <p>A crash report was written to:</p>
+            <p className="break-all font-mono text-sm">{crashReport}</p>
+            <p>Please include this file when reporting the bug.</p>
+          </ModalBody>
+        </ModalContent>
+      </Modal>
+    );
+  }
+
   const { isOpen, onOpenChange } = useDisclosure({ isOpen: modalPanel != null });
   let modal;
   if (modalPanel != null) {
@@ -87,6 +264,18 @@ export function App(): ReactNode {
     const onCloseModal = modalPanel.on_close;
     if ('Debug' in modalPanel.data) {
       modalContent = <DebugPanelContent data={modalPanel.data.Debug} />;
+    } else if ('RulesConsole' in modalPanel.data) {
+      modalContent = <RulesConsoleContent data={modalPanel.data.RulesConsole} />;
+    } else if ('Settings' in modalPanel.data) {
+      modalContent = <SettingsContent data={modalPanel.data.Settings} />;
+    } else if ('Campaign' in modalPanel.data) {
+      modalContent = <CampaignContent data={modalPanel.data.Campaign} />;
+    } else if ('Profile' in modalPanel.data) {
+      modalContent = <ProfileContent data={modalPanel.data.Profile} />;
+    } else if ('ZoneBrowser' in modalPanel.data) {
+      modalContent = <ZoneBrowserContent data={modalPanel.data.ZoneBrowser} />;
+    } else if ('KnownCards' in modalPanel.data) {
+      modalContent = <KnownCardsContent data={modalPanel.data.KnownCards} />;
     }
 
     modal = (
@@ -110,6 +299,8 @@ export function App(): ReactNode {
       {scene}
       {modal}
       {message}
+      {error}
+      {crash}
     </GlobalContext.Provider>
   );
 }
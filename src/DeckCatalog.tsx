@@ -0,0 +1,57 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode, useContext, useEffect } from 'react';
+import { GlobalContext } from './App';
+import { DeckCatalogView } from './generated_types';
+import { fetchDeckCatalog } from './server';
+import { GameButton } from './core/GameButton';
+
+export function DeckCatalog({ view }: { view: DeckCatalogView | null }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  useEffect(() => {
+    if (clientData.id !== '' && view == null) {
+      fetchDeckCatalog(clientData);
+    }
+  }, [clientData, view]);
+
+  if (view == null) {
+    return null;
+  }
+
+  const decks = view.decks.map((deck) => (
+    <div key={deck.id} className="mb-2">
+      <div className="text-sm font-bold">
+        {deck.name}
+        <span className="ml-2 text-xs opacity-50">{deck.colors.join(', ')}</span>
+      </div>
+      <div className="text-xs">{deck.description}</div>
+      <div className="text-xs opacity-50">{deck.cardCount} cards</div>
+      {deck.legalFormats.length > 0 && (
+        <div className="text-xs opacity-50">Legal in: {deck.legalFormats.join(', ')}</div>
+      )}
+      {deck.select != null ? (
+        <GameButton className="mt-1" button={deck.select} />
+      ) : (
+        <div className="text-xs font-bold">Selected for campaign</div>
+      )}
+    </div>
+  ));
+  return (
+    <div className="absolute right-2 top-2 w-64 bg-black/50 p-2 rounded text-white">
+      <h2 className="text-lg font-bold font-title mb-2">Decks</h2>
+      {decks}
+    </div>
+  );
+}
@@ -13,10 +13,20 @@
 // limitations under the License.
 
 import { ReactNode } from 'react';
-import { MainMenuView } from './generated_types';
+import { DeckCatalogView, MainMenuView, MatchStatisticsView } from './generated_types';
 import { GameButton } from './core/GameButton';
+import { DeckCatalog } from './DeckCatalog';
+import { MatchStatistics } from './MatchStatistics';
 
-function MainMenu({ view }: { view: MainMenuView }): ReactNode {
+function MainMenu({
+  view,
+  deckCatalog,
+  matchStatistics,
+}: {
+  view: MainMenuView;
+  deckCatalog: DeckCatalogView | null;
+  matchStatistics: MatchStatisticsView | null;
+}): ReactNode {
   const imageHeight = 125;
   const imageAspectRatio = 1.4;
   return (
@@ -37,6 +47,8 @@ function MainMenu({ view }: { view: MainMenuView }): ReactNode {
         src="https://cards.scryfall.io/png/front/2/3/23c4e8fb-0bc2-4449-a8df-a455b1ea9be4.png"
       />
       <MenuItems view={view} />
+      <DeckCatalog view={deckCatalog} />
+      <MatchStatistics view={matchStatistics} />
       <Attribution />
     </div>
   );
@@ -0,0 +1,62 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode, useContext, useEffect } from 'react';
+import { GlobalContext } from './App';
+import { MatchStatisticsView, WinRateView } from './generated_types';
+import { fetchMatchStatistics } from './server';
+
+export function MatchStatistics({ view }: { view: MatchStatisticsView | null }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  useEffect(() => {
+    if (clientData.id !== '' && view == null) {
+      fetchMatchStatistics(clientData);
+    }
+  }, [clientData, view]);
+
+  if (view == null || view.recentMatches.length === 0) {
+    return null;
+  }
+
+  const recent = view.recentMatches.map((entry, i) => (
+    <div key={i} className="text-xs">
+      <span className="font-bold">{entry.result}</span> {entry.deck} vs {entry.opponentDeck} (
+      {entry.opponent}), {entry.turns} turns
+      {entry.durationSeconds != null && `, ${Math.round(entry.durationSeconds / 60)} min`}
+    </div>
+  ));
+  return (
+    <div className="absolute right-2 bottom-2 w-80 bg-black/50 p-2 rounded text-white">
+      <h2 className="text-lg font-bold font-title mb-2">Statistics</h2>
+      <WinRates title="By Deck" rates={view.decks} />
+      <WinRates title="By Opponent" rates={view.opponents} />
+      <h3 className="text-sm font-bold mt-2">Recent Games</h3>
+      {recent}
+    </div>
+  );
+}
+
+function WinRates({ title, rates }: { title: string; rates: WinRateView[] }): ReactNode {
+  const rows = rates.map((rate) => (
+    <div key={rate.name} className="text-xs">
+      {rate.name}: {Math.round(rate.winRate * 100)}% ({rate.wins}-{rate.losses}-{rate.draws})
+    </div>
+  ));
+  return (
+    <div className="mb-2">
+      <h3 className="text-sm font-bold">{title}</h3>
+      {rows}
+    </div>
+  );
+}
@@ -0,0 +1,74 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode } from 'react';
+import { TextRun } from '../generated_types';
+
+const SYMBOL_COLORS: Record<string, string> = {
+  W: 'bg-yellow-100 text-black',
+  U: 'bg-sky-400 text-black',
+  B: 'bg-neutral-500 text-white',
+  R: 'bg-red-400 text-black',
+  G: 'bg-green-500 text-black',
+};
+
+/**
+ * Renders a sequence of rules text runs, displaying mana and tap symbols as
+ * icons.
+ */
+export function RichText({ runs }: { runs: TextRun[] }): ReactNode {
+  return <>{runs.map((run, i) => renderRun(run, i))}</>;
+}
+
+function renderRun(run: TextRun, key: number): ReactNode {
+  if (run === 'lineBreak') {
+    return <br key={key} />;
+  } else if ('text' in run) {
+    return <span key={key}>{run.text}</span>;
+  } else if ('symbol' in run) {
+    return <Symbol key={key} symbol={run.symbol} />;
+  } else if ('reminder' in run) {
+    return (
+      <i key={key} className="opacity-75">
+        {splitSymbols(run.reminder)}
+      </i>
+    );
+  } else {
+    return <i key={key}>{run.abilityWord}</i>;
+  }
+}
+
+function Symbol({ symbol }: { symbol: string }): ReactNode {
+  const color = SYMBOL_COLORS[symbol] ?? 'bg-neutral-300 text-black';
+  return (
+    <span
+      className={`inline-flex items-center justify-center rounded-full w-4 h-4 mx-px text-[0.6rem] font-bold ${color}`}
+    >
+      {symbol === 'T' ? '⟳' : symbol}
+    </span>
+  );
+}
+
+/** Renders symbols which appear inside reminder text. */
+function splitSymbols(text: string): ReactNode[] {
+  return text
+    .split(/(\{[^}]*\})/)
+    .map((part, i) =>
+      part.startsWith('{') && part.endsWith('}') ? (
+        <Symbol key={i} symbol={part.slice(1, -1)} />
+      ) : (
+        <span key={i}>{part}</span>
+      ),
+    );
+}
@@ -22,11 +22,27 @@ import { TextInputView } from '../generated_types';
 export function TextInput({
   input,
   className,
+  submitOnEnter,
 }: {
   input: TextInputView;
   className?: string;
+  submitOnEnter?: boolean;
 }): ReactNode {
   const clientData = useContext(GlobalContext);
+  if (submitOnEnter) {
+    return (
+      <Input
+        className={className}
+        onKeyDown={(e) => {
+          if (e.key === 'Enter') {
+            const target = e.target as HTMLInputElement;
+            updateField(clientData, input.key, { string: target.value });
+            target.value = '';
+          }
+        }}
+      />
+    );
+  }
   return (
     <Input
       className={className}
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-import { ReactNode } from 'react';
+import { ReactNode, useContext } from 'react';
 import { ClientCardId } from '../generated_types';
+import { GlobalContext } from '../App';
+import { fetchCardDetails } from '../server';
 import { HiddenCard } from './HiddenCard';
 import { RevealedCard } from './RevealedCard';
 import { CardMap } from './PlayArea';
+import { AnimationContext, cardAnimationStyle } from './animations';
 
 interface Props {
   readonly cardId: ClientCardId;
@@ -24,6 +27,8 @@ interface Props {
 }
 
 export function Card({ cardId, map }: Props): ReactNode {
+  const clientData = useContext(GlobalContext);
+  const animations = useContext(AnimationContext);
   const card = map.cards.get(cardId);
   if (card == null) {
     throw new Error(`Card not found: ${cardId}`);
@@ -43,6 +48,13 @@ export function Card({ cardId, map }: Props): ReactNode {
         width: `${height * (5 / 7)}vh`,
         margin: '0.1vh',
         transform: card.tappedState === 'tapped' ? `rotate(90deg)` : undefined,
+        transition: 'translate 0.15s, filter 0.15s, opacity 0.15s',
+        ...cardAnimationStyle(animations, cardId),
+      }}
+      onMouseEnter={() => {
+        if (card.revealed != null) {
+          fetchCardDetails(clientData, card.id);
+        }
       }}
     >
       {body}
@@ -0,0 +1,56 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode } from 'react';
+import { CardDetailsView } from '../generated_types';
+import { RichText } from '../core/RichText';
+import { GameButton } from '../core/GameButton';
+
+export function CardDetails({ view }: { view: CardDetailsView }): ReactNode {
+  const faces = view.faces.map((face, i) => (
+    <div key={i} className="mb-2">
+      <div className="flex justify-between font-bold">
+        <span>{face.name}</span>
+        <span>
+          <RichText runs={face.manaCost.map((symbol) => ({ symbol }))} />
+        </span>
+      </div>
+      {face.typeLine != null && <div className="text-xs italic">{face.typeLine}</div>}
+      {face.richOracleText.length > 0 && (
+        <div className="text-xs mt-1">
+          <RichText runs={face.richOracleText} />
+        </div>
+      )}
+      {face.powerToughness != null && (
+        <div className="text-xs text-right">{face.powerToughness}</div>
+      )}
+    </div>
+  ));
+  const rulings = view.rulings.map((ruling, i) => (
+    <div key={i} className="text-xs mb-1">
+      <span className="opacity-50 mr-1">{ruling.date}</span>
+      {ruling.text}
+    </div>
+  ));
+  return (
+    <div className="fixed top-2 right-2 w-72 max-h-96 overflow-y-auto bg-black/75 p-2 rounded">
+      {faces}
+      {rulings.length > 0 && <div className="font-bold text-xs mt-2 mb-1">Rulings</div>}
+      {rulings}
+      {view.triggerButtons.map((button, i) => (
+        <GameButton key={i} button={button} className="mt-1 w-full" />
+      ))}
+    </div>
+  );
+}
@@ -0,0 +1,71 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+import { ReactNode, useContext, useState } from 'react';
+import { Button, Input } from '@nextui-org/react';
+import { GlobalContext } from '../App';
+import { CardSearchView } from '../generated_types';
+import { fetchCardDetails, searchCards } from '../server';
+
+export function CardSearch({
+  view,
+  onDismiss,
+}: {
+  view: CardSearchView | null;
+  onDismiss: () => void;
+}): ReactNode {
+  const clientData = useContext(GlobalContext);
+  const [query, setQuery] = useState('');
+
+  const sections = view?.sections.map((section, i) => (
+    <div key={i} className="mb-1">
+      <div className="font-bold text-xs">
+        {section.title} ({section.cards.length})
+      </div>
+      {section.cards.map((result, j) => (
+        <div
+          key={j}
+          className="text-xs cursor-pointer hover:bg-white/10"
+          onClick={() => fetchCardDetails(clientData, result.card)}
+        >
+          {result.name}
+          <span className="opacity-50 ml-1">{result.typeLine}</span>
+        </div>
+      ))}
+    </div>
+  ));
+  return (
+    <div className="fixed top-1/3 left-2 w-64 bg-black/75 p-2 rounded">
+      <Input
+        size="sm"
+        placeholder="Search graveyards & exile..."
+        value={query}
+        onValueChange={setQuery}
+        onKeyDown={(e) => {
+          if (e.key === 'Enter') {
+            searchCards(clientData, query);
+          }
+        }}
+      />
+      {view != null && (
+        <div className="max-h-64 overflow-y-auto mt-1">
+          {sections?.length === 0 && <div className="text-xs opacity-50">No matching cards</div>}
+          {sections}
+          <Button size="sm" variant="light" onPress={onDismiss}>
+            Close
+          </Button>
+        </div>
+      )}
+    </div>
+  );
+}
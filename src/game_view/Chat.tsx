@@ -0,0 +1,55 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode, useContext, useEffect, useRef, useState } from 'react';
+import { Input } from '@nextui-org/react';
+import { GlobalContext } from '../App';
+import { ChatView } from '../generated_types';
+import { sendChatMessage } from '../server';
+
+export function Chat({ view }: { view: ChatView }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  const [message, setMessage] = useState('');
+  const end = useRef<HTMLDivElement>(null);
+  useEffect(() => {
+    end.current?.scrollIntoView();
+  }, [view]);
+
+  const messages = view.messages.map((m, i) => (
+    <div key={i} className={m.fromSelf ? 'text-xs text-right' : 'text-xs'}>
+      <span className="opacity-50 mr-1">{m.fromSelf ? 'You' : 'Opponent'}:</span>
+      {m.text}
+    </div>
+  ));
+  return (
+    <div className="fixed bottom-2 right-2 w-64 bg-black/50 p-2 rounded">
+      <div className="max-h-40 overflow-y-auto">
+        {messages}
+        <div ref={end} />
+      </div>
+      <Input
+        size="sm"
+        placeholder="Say something..."
+        value={message}
+        onValueChange={setMessage}
+        onKeyDown={(e) => {
+          if (e.key === 'Enter' && message.trim() !== '') {
+            sendChatMessage(clientData, message);
+            setMessage('');
+          }
+        }}
+      />
+    </div>
+  );
+}
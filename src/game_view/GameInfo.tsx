@@ -14,6 +14,7 @@
 
 import { ReactNode } from 'react';
 import { PlayerInfo } from './PlayerInfo';
+import { StackSummary } from './StackSummary';
 import { GameView } from '../generated_types';
 import { GameControl } from '../core/GameControl';
 
@@ -29,6 +30,15 @@ export function GameInfo({ view }: { view: GameView }): ReactNode {
       <div className="flex flex-col">{topButtons}</div>
       <PlayerInfo name="Opponent" player={view.opponent} />
       <div className="items-center m-1 text-m">{view.statusDescription}</div>
+      <StackSummary stack={view.stack} />
+      {view.activePlane != null && (
+        <img
+          className="m-1 rounded"
+          src={view.activePlane.image}
+          alt={view.activePlane.name}
+          title={view.activePlane.name}
+        />
+      )}
       <PlayerInfo name="Viewer" player={view.viewer} />
       <div className="flex flex-col">{bottomButtons}</div>
     </div>
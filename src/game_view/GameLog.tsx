@@ -0,0 +1,36 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode, useEffect, useRef } from 'react';
+import { GameLogView } from '../generated_types';
+
+export function GameLog({ view }: { view: GameLogView }): ReactNode {
+  const end = useRef<HTMLDivElement>(null);
+  useEffect(() => {
+    end.current?.scrollIntoView();
+  }, [view]);
+
+  const entries = view.entries.map((entry, i) => (
+    <div key={i} className="text-xs">
+      <span className="opacity-50 mr-1">T{entry.turnNumber + 1}</span>
+      {entry.text}
+    </div>
+  ));
+  return (
+    <div className="fixed bottom-2 left-2 w-64 max-h-48 overflow-y-auto bg-black/50 p-2 rounded">
+      {entries}
+      <div ref={end} />
+    </div>
+  );
+}
@@ -26,6 +26,7 @@ export interface Props {
   readonly cardMap: CardMap;
   readonly dropTarget?: CardOrderLocation;
   readonly omitIfEmpty?: boolean;
+  readonly onNameClick?: () => void;
 }
 
 export function LinearCardDisplay({
@@ -34,6 +35,7 @@ export function LinearCardDisplay({
   cardMap,
   dropTarget,
   omitIfEmpty = false,
+  onNameClick,
 }: Props): ReactNode {
   const items = useContext(ItemsContext);
   const cardIds = items[positionKey] ?? [];
@@ -76,7 +78,16 @@ export function LinearCardDisplay({
       }}
     >
       {content}
-      <div className="w-32 text-center text-sm">{name}</div>
+      <div
+        className={
+          onNameClick == null
+            ? 'w-32 text-center text-sm'
+            : 'w-32 text-center text-sm cursor-pointer underline'
+        }
+        onClick={onNameClick}
+      >
+        {name}
+      </div>
     </div>
   );
 }
@@ -0,0 +1,46 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+import { ReactNode } from 'react';
+import { PerformanceHudView } from '../generated_types';
+
+function formatMillis(millis: number): string {
+  return millis < 10 ? millis.toFixed(2) : millis.toFixed(0);
+}
+
+export function PerformanceHud({ view }: { view: PerformanceHudView }): ReactNode {
+  return (
+    <div className="fixed top-2 left-2 bg-black/75 p-2 rounded text-xs font-mono">
+      <table>
+        <thead>
+          <tr>
+            <th className="text-left pr-2">Operation</th>
+            <th className="text-right pr-2">Count</th>
+            <th className="text-right pr-2">p50 ms</th>
+            <th className="text-right">p95 ms</th>
+          </tr>
+        </thead>
+        <tbody>
+          {view.timings.map((timing) => (
+            <tr key={timing.name}>
+              <td className="pr-2">{timing.name}</td>
+              <td className="text-right pr-2">{timing.count}</td>
+              <td className="text-right pr-2">{formatMillis(timing.p50Millis)}</td>
+              <td className="text-right">{formatMillis(timing.p95Millis)}</td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </div>
+  );
+}
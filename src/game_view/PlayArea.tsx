@@ -20,7 +20,7 @@ import { DragManager, Items } from '../draggables/DragManager';
 import { Card } from './Card';
 import { useContext } from 'react';
 import { GlobalContext } from '../App';
-import { dragCard } from '../server';
+import { dragCard, handleAction } from '../server';
 
 export type PositionKey = string;
 
@@ -73,6 +73,14 @@ export function PlayArea({ view }: Props): ReactNode {
             positionKey={keyForPosition({ battlefield: ['opponent', 'permanents'] })}
             cardMap={map}
           />
+          <LinearCardDisplay
+            key="oe"
+            name="Opponent Exile"
+            positionKey={keyForPosition({ exile: 'opponent' })}
+            cardMap={map}
+            omitIfEmpty={true}
+            onNameClick={() => handleAction(clientData, view.opponent.browseExile)}
+          />
           <LinearCardDisplay
             key="stack"
             name="Stack"
@@ -103,6 +111,14 @@ export function PlayArea({ view }: Props): ReactNode {
             omitIfEmpty={true}
             dropTarget={view.cardDragTargets.includes('topOfLibrary') ? 'topOfLibrary' : undefined}
           />
+          <LinearCardDisplay
+            key="ve"
+            name="Viewer Exile"
+            positionKey={keyForPosition({ exile: 'viewer' })}
+            cardMap={map}
+            omitIfEmpty={true}
+            onNameClick={() => handleAction(clientData, view.viewer.browseExile)}
+          />
           <LinearCardDisplay
             key="vp"
             name="Viewer Permanents"
@@ -123,16 +139,30 @@ export function PlayArea({ view }: Props): ReactNode {
           />
         </div>
         <div className="w-1/12 flex flex-col justify-between">
+          <div
+            className="text-xs text-center cursor-pointer underline"
+            onClick={() => handleAction(clientData, view.opponent.browseLibrary)}
+          >
+            Library
+          </div>
           <StackCardDisplay
             key="og"
             positionKey={keyForPosition({ discardPile: 'opponent' })}
             cardMap={map}
+            onClick={() => handleAction(clientData, view.opponent.browseGraveyard)}
           />
           <StackCardDisplay
             key="vg"
             positionKey={keyForPosition({ discardPile: 'viewer' })}
             cardMap={map}
+            onClick={() => handleAction(clientData, view.viewer.browseGraveyard)}
           />
+          <div
+            className="text-xs text-center cursor-pointer underline"
+            onClick={() => handleAction(clientData, view.viewer.browseLibrary)}
+          >
+            Library
+          </div>
         </div>
       </div>
     </DragManager>
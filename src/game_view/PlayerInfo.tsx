@@ -12,15 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-import { ReactNode } from 'react';
+import { ReactNode, useContext } from 'react';
 import { PlayerView } from '../generated_types';
+import { GlobalContext } from '../App';
+import { handleAction } from '../server';
 
 export function PlayerInfo({ name, player }: { name: string; player: PlayerView }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  const canSelect = player.selectAction != null;
   return (
-    <div className="m-1 text-center flex flex-col">
+    <div
+      className={`m-1 text-center flex flex-col ${canSelect ? 'cursor-pointer border-2 border-orange-600' : ''}`}
+      onClick={canSelect ? () => handleAction(clientData, player.selectAction) : undefined}
+    >
       <div className="text-lg">{name}</div>
       <div className="text-sm">Life: {player.life}</div>
+      {lifeChange(player)}
       <div className="text-sm">Can Act: {player.canAct ? 'true' : 'false'}</div>
     </div>
   );
 }
+
+function lifeChange(player: PlayerView): ReactNode {
+  if (player.lifeGainedThisTurn === 0 && player.lifeLostThisTurn === 0) {
+    return null;
+  }
+  return (
+    <div className="text-xs">
+      This turn: <span className="text-green-600">+{player.lifeGainedThisTurn}</span> /{' '}
+      <span className="text-red-600">-{player.lifeLostThisTurn}</span>
+    </div>
+  );
+}
@@ -0,0 +1,70 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+import { ReactNode } from 'react';
+import { Button } from '@nextui-org/react';
+import { DisplayPlayer, RandomResultView } from '../generated_types';
+
+function playerName(player: DisplayPlayer): string {
+  return player === 'viewer' ? 'You' : 'Opponent';
+}
+
+function outcome(won: boolean | null): string {
+  return won == null ? '' : won ? ' (won)' : ' (lost)';
+}
+
+export function RandomResult({
+  view,
+  onDismiss,
+}: {
+  view: RandomResultView;
+  onDismiss: () => void;
+}): ReactNode {
+  let lines: string[];
+  if ('coinFlip' in view) {
+    const flip = view.coinFlip;
+    lines = [
+      `${playerName(flip.player)} flipped a coin`,
+      ...(flip.call != null ? [`Called: ${flip.call}`] : []),
+      `Result: ${flip.result}${outcome(flip.won)}`,
+    ];
+  } else if ('dieRoll' in view) {
+    const roll = view.dieRoll;
+    lines = [`${playerName(roll.player)} rolled a d${roll.sides}`, `Result: ${roll.result}`];
+  } else if ('planarDie' in view) {
+    const roll = view.planarDie;
+    lines = [`${playerName(roll.player)} rolled the planar die`, `Result: ${roll.result}`];
+  } else {
+    const clash = view.clash;
+    lines = [
+      `${playerName(clash.player)} clashed${outcome(clash.won)}`,
+      ...clash.revealed.map(
+        (reveal) =>
+          `${playerName(reveal.player)}: ${reveal.name ?? 'No card'} (${reveal.manaValue})`,
+      ),
+    ];
+  }
+
+  return (
+    <div className="fixed top-1/3 left-1/2 -translate-x-1/2 w-64 bg-black/75 p-2 rounded">
+      {lines.map((line, i) => (
+        <div key={i} className={i === 0 ? 'font-bold text-sm mb-1' : 'text-xs'}>
+          {line}
+        </div>
+      ))}
+      <Button size="sm" variant="light" onPress={onDismiss}>
+        Dismiss
+      </Button>
+    </div>
+  );
+}
@@ -0,0 +1,47 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+import { ReactNode } from 'react';
+import { Button } from '@nextui-org/react';
+import { RecentChangesView } from '../generated_types';
+
+export function RecentChanges({
+  view,
+  onDismiss,
+}: {
+  view: RecentChangesView;
+  onDismiss: () => void;
+}): ReactNode {
+  const lines = [
+    ...view.lifeChanges.map((change) => `${change.label}: ${change.previous} → ${change.current}`),
+    ...view.movedCards.map((moved) => `${moved.name}: ${moved.from} → ${moved.to}`),
+    ...view.counterChanges.map(
+      (change) => `${change.name} counters: ${change.previous} → ${change.current}`,
+    ),
+  ];
+  return (
+    <div className="fixed top-2 left-2 w-64 max-h-64 overflow-y-auto bg-black/75 p-2 rounded">
+      <div className="flex justify-between items-center font-bold text-xs mb-1">
+        <span>What happened?</span>
+        <Button size="sm" variant="light" onPress={onDismiss}>
+          Dismiss
+        </Button>
+      </div>
+      {lines.map((line, i) => (
+        <div key={i} className="text-xs">
+          {line}
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -52,6 +52,9 @@ export function RevealedCard({ revealed }: Props): ReactNode {
   } else if (revealed.status != null && 'alocking' in revealed.status) {
     borderClass = 'border-2 border-purple-300';
     label = revealed.status.blocking;
+  } else if (revealed.status != null && 'goaded' in revealed.status) {
+    borderClass = 'border-2 border-red-500';
+    label = revealed.status.goaded;
   } else if (revealed.isAbility) {
     borderClass = 'border-2 border-pink-300';
     label = 'ability';
@@ -59,31 +62,71 @@ export function RevealedCard({ revealed }: Props): ReactNode {
     label = 'token';
   }
 
+  if (revealed.sagaChapter != null) {
+    const chapter = `chapter ${revealed.sagaChapter}`;
+    label = label === '' ? chapter : `${label}, ${chapter}`;
+  }
+
+  if (revealed.battleDefense != null) {
+    const defense = `defense ${revealed.battleDefense}`;
+    label = label === '' ? defense : `${label}, ${defense}`;
+  }
+
+  if (revealed.mergedWith.length > 0) {
+    const merged = `merged with ${revealed.mergedWith.join(', ')}`;
+    label = label === '' ? merged : `${label}, ${merged}`;
+  }
+
+  if (revealed.onAnAdventure) {
+    const adventure = 'on an adventure';
+    label = label === '' ? adventure : `${label}, ${adventure}`;
+  }
+
+  if (revealed.foretold) {
+    label = label === '' ? 'foretold' : `${label}, foretold`;
+  }
+
+  if (revealed.isCustom) {
+    label = label === '' ? 'custom' : `${label}, custom`;
+  }
+
   if (revealed.canDrag) {
     return (
       <div className={borderClass}>
-        <img
-          src={revealed.image}
-          style={{
-            width: '100%',
-            height: '100%',
-          }}
-        />
+        <CardImage revealed={revealed} />
         <span className="absolute bg-slate-900 text-white text-xs">{label}</span>
       </div>
     );
   } else {
     return (
-      <div className={borderClass} onClick={() => handleAction(clientData, revealed.clickAction)}>
-        <img
-          src={revealed.image}
-          style={{
-            width: '100%',
-            height: '100%',
-          }}
-        />
+      <div
+        className={borderClass}
+        title={revealed.disabledReason ?? undefined}
+        onClick={() => handleAction(clientData, revealed.clickAction)}
+      >
+        <CardImage revealed={revealed} />
         <span className="absolute bg-slate-900 text-white text-xs">{label}</span>
       </div>
     );
   }
 }
+
+function CardImage({ revealed }: Props): ReactNode {
+  return (
+    <>
+      <img
+        src={revealed.image}
+        style={{
+          width: '100%',
+          height: '100%',
+        }}
+      />
+      {revealed.isCustom && (
+        <div className="absolute top-1/3 w-full bg-white text-black text-xs text-center">
+          <div className="font-bold">{revealed.face.name}</div>
+          {revealed.face.rulesText}
+        </div>
+      )}
+    </>
+  );
+}
@@ -21,9 +21,10 @@ import { ItemsContext } from '../draggables/DragManager';
 export interface Props {
   readonly positionKey: PositionKey;
   readonly cardMap: CardMap;
+  readonly onClick?: () => void;
 }
 
-export function StackCardDisplay({ positionKey, cardMap }: Props): ReactNode {
+export function StackCardDisplay({ positionKey, cardMap, onClick }: Props): ReactNode {
   const items = useContext(ItemsContext);
   const cardIds = items[positionKey] ?? [];
   const cardViews = cardIds.map((cardId) => (
@@ -33,10 +34,13 @@ export function StackCardDisplay({ positionKey, cardMap }: Props): ReactNode {
   ));
   return (
     <div
-      className="bg-slate-300 m-1 rounded"
+      className={
+        onClick == null ? 'bg-slate-300 m-1 rounded' : 'bg-slate-300 m-1 rounded cursor-pointer'
+      }
       style={{
         height: '13.5vh',
       }}
+      onClick={onClick}
     >
       {cardViews}
     </div>
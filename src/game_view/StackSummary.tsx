@@ -0,0 +1,44 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode } from 'react';
+import { StackItemView } from '../generated_types';
+
+/**
+ * Lists the contents of the stack from top to bottom, so that both players
+ * can see what they are responding to.
+ */
+export function StackSummary({ stack }: { stack: StackItemView[] }): ReactNode {
+  if (stack.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="m-1 p-1 text-left text-xs border border-pink-300 rounded">
+      <div className="font-bold">Stack</div>
+      {stack.map((item, i) => (
+        <div key={i} className="mt-1" title={item.summary}>
+          <div>
+            {i === 0 ? '▶ ' : ''}
+            {item.name}
+            {item.isAbility ? ' (ability)' : ''}
+            {item.controller === 'viewer' ? ' — You' : ' — Opponent'}
+          </div>
+          {item.modes.length > 0 && <div>Modes: {item.modes.join(', ')}</div>}
+          {item.targets.length > 0 && <div>Targets: {item.targets.join(', ')}</div>}
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -0,0 +1,72 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { createContext } from 'react';
+import { AnimationStepView, AnimationTimelineView, ClientCardId } from '../generated_types';
+
+/**
+ * Duration of a single animation group, in milliseconds.
+ */
+const GROUP_DURATION_MS = 300;
+
+/**
+ * Animations which are currently playing.
+ */
+export const AnimationContext: React.Context<AnimationStepView[]> = createContext(
+  [] as AnimationStepView[],
+);
+
+/**
+ * Plays each group of animations in an animation timeline in sequence,
+ * invoking `setSteps` with the animations of the group which is currently
+ * playing. Resolves once all groups have finished.
+ */
+export async function playAnimations(
+  timeline: AnimationTimelineView,
+  setSteps: (steps: AnimationStepView[]) => void,
+): Promise<void> {
+  for (const group of timeline.groups) {
+    setSteps(group.steps);
+    await new Promise((resolve) => setTimeout(resolve, GROUP_DURATION_MS));
+  }
+  setSteps([]);
+}
+
+/**
+ * Returns a CSS animation style to apply to the card with the given ID based
+ * on the animations which are currently playing.
+ */
+export function cardAnimationStyle(
+  steps: AnimationStepView[],
+  cardId: ClientCardId,
+): React.CSSProperties {
+  const key = JSON.stringify(cardId);
+  for (const step of steps) {
+    if ('attackLunge' in step && JSON.stringify(step.attackLunge) === key) {
+      return { translate: '0 -2vh' };
+    } else if (
+      'damageFlash' in step &&
+      'card' in step.damageFlash.target &&
+      JSON.stringify(step.damageFlash.target.card) === key
+    ) {
+      return { filter: 'sepia(1) saturate(5) hue-rotate(-50deg)' };
+    } else if (
+      ('draw' in step && JSON.stringify(step.draw) === key) ||
+      ('zoneMove' in step && JSON.stringify(step.zoneMove.card) === key)
+    ) {
+      return { opacity: 0.5 };
+    }
+  }
+  return {};
+}
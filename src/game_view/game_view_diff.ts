@@ -0,0 +1,47 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { CardView, ClientCardId, GameView, GameViewDiff } from '../generated_types';
+
+/**
+ * Applies incremental changes from the server to the previous game view,
+ * returning the new game view.
+ */
+export function applyGameViewDiff(previous: GameView, diff: GameViewDiff): GameView {
+  const cards = new Map<string, CardView>();
+  for (const card of previous.cards) {
+    cards.set(cardKey(card.id), card);
+  }
+
+  for (const update of diff.cardUpdates) {
+    if ('updateCard' in update) {
+      cards.set(cardKey(update.updateCard.id), update.updateCard);
+    } else if ('moveCard' in update) {
+      const key = cardKey(update.moveCard.id);
+      const card = cards.get(key);
+      if (card == null) {
+        throw new Error(`Cannot move unknown card: ${key}`);
+      }
+      cards.set(key, { ...card, position: update.moveCard.position });
+    } else if ('removeCard' in update) {
+      cards.delete(cardKey(update.removeCard));
+    }
+  }
+
+  return { ...diff.view, cards: Array.from(cards.values()) };
+}
+
+function cardKey(id: ClientCardId): string {
+  return JSON.stringify(id);
+}
@@ -0,0 +1,26 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { SoundEffect } from '../generated_types';
+
+/**
+ * Plays the provided sound effects. Sounds are loaded from
+ * `/sounds/<name>.mp3` in the public assets directory.
+ */
+export function playSounds(sounds: SoundEffect[]): void {
+  for (const sound of sounds) {
+    const audio = new Audio(`/sounds/${sound}.mp3`);
+    audio.play().catch((e) => console.warn(`Failed to play sound ${sound}`, e));
+  }
+}
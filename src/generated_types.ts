@@ -10,6 +10,21 @@ export const commands = {
   async updateField(clientData: ClientData, key: FieldKey, value: FieldValue): Promise<void> {
     await TAURI_INVOKE('update_field', { clientData, key, value });
   },
+  async sendChatMessage(clientData: ClientData, message: string): Promise<void> {
+    await TAURI_INVOKE('send_chat_message', { clientData, message });
+  },
+  async fetchCardDetails(clientData: ClientData, cardId: ClientCardId): Promise<void> {
+    await TAURI_INVOKE('fetch_card_details', { clientData, cardId });
+  },
+  async searchCards(clientData: ClientData, query: string): Promise<void> {
+    await TAURI_INVOKE('search_cards', { clientData, query });
+  },
+  async fetchDeckCatalog(clientData: ClientData): Promise<void> {
+    await TAURI_INVOKE('fetch_deck_catalog', { clientData });
+  },
+  async fetchMatchStatistics(clientData: ClientData): Promise<void> {
+    await TAURI_INVOKE('fetch_match_statistics', { clientData });
+  },
   async dragCard(
     clientData: ClientData,
     cardId: ClientCardId,
@@ -28,10 +43,121 @@ export const events = __makeEvents__<{
 
 /** user-defined types **/
 
+/**
+ * Object affected by an animation.
+ */
+export type AnimationTarget = { card: ClientCardId } | { player: DisplayPlayer };
+/**
+ * Animations which play at the same time.
+ *
+ * Each group begins playing once all animations in the previous group have
+ * finished.
+ */
+export type AnimationGroupView = { steps: AnimationStepView[] };
+/**
+ * A single discrete animation.
+ */
+export type AnimationStepView =
+  /**
+   * A card was drawn into a player's hand.
+   */
+  | { draw: ClientCardId }
+  /**
+   * Damage was dealt to a card or player.
+   */
+  | { damageFlash: DamageFlashAnimation }
+  /**
+   * A creature was declared as an attacker.
+   */
+  | { attackLunge: ClientCardId }
+  /**
+   * A card moved from one position to its current position.
+   */
+  | { zoneMove: ZoneMoveAnimation };
+/**
+ * A sequence of animations for the client to play before displaying the next
+ * game view.
+ */
+export type AnimationTimelineView = {
+  /**
+   * Groups of animations, in the order in which they should be played.
+   */
+  groups: AnimationGroupView[];
+};
 /**
  * Sub-positions for objects within the battlefield.
  */
 export type BattlefieldPosition = 'mana' | 'permanents';
+/**
+ * Map of stages in the single-player campaign
+ */
+export type CampaignPanel = { stages: CampaignStageView[] };
+export type CampaignStageStatus = 'Locked' | 'Available' | 'Completed';
+export type CampaignStageView = {
+  name: string;
+  description: string;
+  /**
+   * Descriptions of special rules which apply in this stage
+   */
+  rules: string[];
+  /**
+   * True if completing this stage unlocks a new deck
+   */
+  has_reward: boolean;
+  status: CampaignStageStatus;
+  /**
+   * Button to start a game for this stage, if it can currently be played
+   */
+  play: GameButtonView | null;
+};
+/**
+ * Full oracle information about a card, displayed in a detail pane when the
+ * user hovers over a card.
+ */
+export type CardDetailsView = {
+  /**
+   * Card these details describe.
+   */
+  id: ClientCardId;
+  /**
+   * Faces of the card, primary face first.
+   */
+  faces: CardDetailsFaceView[];
+  /**
+   * Official rulings for this card, oldest first.
+   */
+  rulings: CardRulingView[];
+  /**
+   * Buttons to toggle whether the game should always stop for priority
+   * when each of this card's triggered abilities is on the stack. Only
+   * populated for cards controlled by the viewer.
+   */
+  triggerButtons: GameButtonView[];
+};
+export type CardDetailsFaceView = {
+  name: string;
+  /**
+   * Type line as printed, e.g. "Creature — Bear".
+   */
+  typeLine: string | null;
+  /**
+   * Mana symbols of this face's cost without braces, e.g. `["2", "G"]` for
+   * a cost of `{2}{G}`.
+   */
+  manaCost: string[];
+  /**
+   * Full oracle text of this face.
+   */
+  oracleText: string | null;
+  /**
+   * Oracle text split into styled runs, for display.
+   */
+  richOracleText: TextRun[];
+  /**
+   * Printed power & toughness, e.g. "2/2".
+   */
+  powerToughness: string | null;
+};
 /**
  * Facing for this card, corresponding to the [PrintedCard] faces.
  */
@@ -68,6 +194,60 @@ export type CardOrderLocation =
 /**
  * Represents the visual state of a card or ability in a game
  */
+export type CardRulingView = { date: string; text: string };
+export type CardSearchResultView = {
+  card: ClientCardId;
+  name: string;
+  /**
+   * Type line of the card's primary face, e.g. "Creature — Bear".
+   */
+  typeLine: string;
+};
+/**
+ * A group of matching cards in a single zone.
+ */
+export type CardSearchSectionView = {
+  /**
+   * Heading for this section, e.g. "Your Graveyard".
+   */
+  title: string;
+  /**
+   * Matching cards in this zone, top card first.
+   */
+  cards: CardSearchResultView[];
+};
+/**
+ * Results of searching the visible cards in graveyards, exile, and other
+ * revealed zones, displayed in a card browser panel.
+ */
+export type CardSearchView = {
+  /**
+   * Query these results were produced for. An empty query matches every
+   * visible card.
+   */
+  query: string;
+  /**
+   * Matching cards grouped by owner & zone, in the order they should be
+   * displayed in the browser.
+   */
+  sections: CardSearchSectionView[];
+};
+/**
+ * A change to a single card in a [GameViewDiff].
+ */
+export type CardUpdate =
+  /**
+   * Create a new card or replace the full state of an existing card.
+   */
+  | { updateCard: CardView }
+  /**
+   * Move an existing card to a new position without otherwise changing it.
+   */
+  | { moveCard: MoveCardUpdate }
+  /**
+   * Remove a card which is no longer visible.
+   */
+  | { removeCard: ClientCardId };
 export type CardView = {
   /**
    * Identifier for this card
@@ -126,6 +306,55 @@ export type CardView = {
  * Serialized u64, represented as string because JavaScript is a silly
  * language.
  */
+export type ChatMessageView = {
+  /**
+   * True if this message was sent by the viewing player.
+   */
+  fromSelf: boolean;
+  /**
+   * Turn number on which this message was sent.
+   */
+  turnNumber: number;
+  /**
+   * Text of the message.
+   */
+  text: string;
+};
+/**
+ * Chat messages exchanged between players in the current game.
+ */
+export type ChatView = {
+  /**
+   * Messages, oldest first.
+   */
+  messages: ChatMessageView[];
+};
+export type ClashRevealView = {
+  player: DisplayPlayer;
+  /**
+   * Revealed card, or None if the player's library was empty
+   */
+  card: ClientCardId | null;
+  /**
+   * Name of the revealed card
+   */
+  name: string | null;
+  manaValue: number;
+};
+export type ClashView = {
+  /**
+   * Player who initiated the clash
+   */
+  player: DisplayPlayer;
+  /**
+   * Cards revealed by each player in the clash
+   */
+  revealed: ClashRevealView[];
+  /**
+   * Whether the initiating player won the clash
+   */
+  won: boolean;
+};
 export type ClientCardId = { cardId: string } | { stackAbilityId: string };
 /**
  * Standard parameters for a client request & response
@@ -145,15 +374,67 @@ export type ClientData = {
    * Currently-displayed top level screen
    */
   scene: SceneIdentifier;
+  /**
+   * Identifies the connection this client data was issued for.
+   *
+   * A new connection ID is issued each time the user connects, and requests
+   * made with an older connection ID are rejected as stale.
+   */
+  connectionId: string;
+  /**
+   * Checksum of the most recent game view sent to this client, if it is
+   * displaying a game.
+   *
+   * Clients must echo this value with each request. A mismatch indicates
+   * that the client missed an update, and triggers a resync.
+   */
+  checksum: number | null;
 };
 /**
  * Represents an instruction to the client to perform some visual update.
  */
+export type CoinFlipView = {
+  /**
+   * Player who flipped the coin
+   */
+  player: DisplayPlayer;
+  /**
+   * Side called by the player, if any
+   */
+  call: string | null;
+  /**
+   * Side the coin landed on
+   */
+  result: string;
+  /**
+   * Whether the player won the flip, if they made a call
+   */
+  won: boolean | null;
+};
 export type Command =
   /**
    * Update the primary visual state of the game.
    */
   | { updateScene: SceneView }
+  /**
+   * Apply incremental changes to the [GameView] most recently sent via
+   * [Self::UpdateScene] or [Self::UpdateGameView].
+   */
+  | { updateGameView: GameViewDiff }
+  /**
+   * Play a sequence of animations before applying the next game view
+   * update.
+   */
+  | { playAnimations: AnimationTimelineView }
+  /**
+   * Play sound effects for recent changes to the game state.
+   */
+  | { playSounds: SoundEffect[] }
+  /**
+   * Replace the plain text description of the board used by screen
+   * readers.
+   */
+  | { updateBoardDescription: string }
   /**
    * Hide or show a modal panel on top of the scene view.
    */
@@ -161,11 +442,139 @@ export type Command =
   /**
    * Display a message to the player.
    */
-  | { displayGameMessage: DisplayGameMessageCommand };
+  | { displayGameMessage: DisplayGameMessageCommand }
+  /**
+   * Replace the contents of the game log with recent game events.
+   */
+  | { updateGameLog: GameLogView }
+  /**
+   * Replace the contents of the chat window.
+   */
+  | { updateChat: ChatView }
+  /**
+   * Display full oracle details for a card in the detail pane.
+   */
+  | { showCardDetails: CardDetailsView }
+  /**
+   * Display the list of available pre-constructed decks.
+   */
+  | { showDeckCatalog: DeckCatalogView }
+  /**
+   * Display aggregated results of the user's finished games.
+   */
+  | { showMatchStatistics: MatchStatisticsView }
+  /**
+   * Display a summary of what changed since the player last had the
+   * opportunity to act.
+   */
+  | { showRecentChanges: RecentChangesView }
+  /**
+   * Display cards matching a search in the card browser panel.
+   */
+  | { showCardSearchResults: CardSearchView }
+  /**
+   * Display the result of a coin flip, die roll, or clash.
+   */
+  | { showRandomResult: RandomResultView }
+  /**
+   * Hide or show the performance HUD overlay.
+   */
+  | { updatePerformanceHud: PerformanceHudView | null }
+  /**
+   * Display an error which prevented a game action from being applied.
+   *
+   * The game is restored to its last saved state when this occurs.
+   */
+  | { showRuleError: string }
+  /**
+   * Display the path to a crash report written after an unexpected error.
+   */
+  | { showCrashReport: string };
+export type CounterChangeView = {
+  /**
+   * Card whose counters changed, for highlighting.
+   */
+  card: ClientCardId;
+  name: string;
+  /**
+   * Total number of counters on the card previously.
+   */
+  previous: number;
+  /**
+   * Total number of counters on the card now.
+   */
+  current: number;
+};
+export type DamageFlashAnimation = {
+  /**
+   * Card or player which was dealt damage
+   */
+  target: AnimationTarget;
+  /**
+   * Amount of damage dealt
+   */
+  amount: number;
+};
 /**
  * Debug options
  */
-export type DebugPanel = { buttons: GameButtonView[] };
+export type DebugPanel = {
+  buttons: GameButtonView[];
+  /**
+   * Input for text commands which mutate the game state
+   */
+  input: TextInputView;
+  /**
+   * Result of the last command, followed by a description of every card in
+   * the game
+   */
+  lines: string[];
+};
+/**
+ * List of pre-constructed decks, displayed on the deck selection screen.
+ */
+export type DeckCatalogView = { decks: DeckSummaryView[] };
+/**
+ * Unique identifier for a deck.
+ */
+export type DeckName = string;
+/**
+ * Summary of a single pre-constructed deck.
+ */
+export type DeckSummaryView = {
+  id: DeckName;
+  name: string;
+  /**
+   * Names of the colors of cards in this deck, e.g. "Green".
+   */
+  colors: string[];
+  description: string;
+  /**
+   * Total number of cards in this deck.
+   */
+  cardCount: number;
+  /**
+   * Names of the constructed formats this deck is legal in, e.g.
+   * "Pauper".
+   */
+  legalFormats: string[];
+  /**
+   * Button to play with this deck in campaign games, or None if it is
+   * already selected.
+   */
+  select: GameButtonView | null;
+};
+export type DieRollView = {
+  /**
+   * Player who rolled the die
+   */
+  player: DisplayPlayer;
+  /**
+   * Number of sides on the die
+   */
+  sides: number;
+  result: number;
+};
 export type DisplayGameMessageCommand = {
   /**
    * Top-level status message to display to the player
@@ -216,8 +625,27 @@ export type FaceLayout =
   | 'token'
   | 'transform'
   | 'vanguard';
-export type FieldKey = 'pickNumberPrompt';
-export type FieldValue = { string: string };
+export type FieldKey =
+  | 'pickNumberPrompt'
+  | { distributeAmount: number }
+  | 'rulesConsoleQuery'
+  | 'debugCommand'
+  | { activeTurnStop: GamePhaseStep }
+  | { inactiveTurnStop: GamePhaseStep }
+  | 'fullControl'
+  | 'autoYieldOwnTriggers'
+  | 'reduceMotion'
+  | { playSound: SoundEffect }
+  | 'screenReader'
+  | 'performanceHud'
+  | { language: LanguageId }
+  | 'displayName'
+  | { startingLife: number }
+  | { freeMulligans: number }
+  | 'ignoreBannedList'
+  | 'alwaysAllowTakebacks'
+  | 'planechase';
+export type FieldValue = { string: string } | { bool: boolean };
 /**
  * Controls color for buttons
  */
@@ -239,6 +667,43 @@ export type GameControlView =
  * Unique identifier for a game
  */
 export type GameId = string;
+/**
+ * A single human-readable entry in the game log.
+ */
+export type GameLogEntryView = {
+  /**
+   * Turn number on which this event happened.
+   */
+  turnNumber: number;
+  /**
+   * Description of the event.
+   */
+  text: string;
+};
+/**
+ * Represents the recent history of an ongoing game, displayed to the user as
+ * a scrollable log.
+ */
+export type GameLogView = {
+  /**
+   * Log entries, oldest first.
+   */
+  entries: GameLogEntryView[];
+};
+export type GamePhaseStep =
+  | 'untap'
+  | 'upkeep'
+  | 'draw'
+  | 'preCombatMain'
+  | 'beginCombat'
+  | 'declareAttackers'
+  | 'declareBlockers'
+  | 'firstStrikeDamage'
+  | 'combatDamage'
+  | 'endCombat'
+  | 'postCombatMain'
+  | 'endStep'
+  | 'cleanup';
 export type GameMessage = 'yourTurn' | 'opponentTurn' | 'victory' | 'defeat';
 /**
  * A response to a user request.
@@ -270,6 +735,10 @@ export type GameView = {
    * Visual state of cards in the game
    */
   cards: CardView[];
+  /**
+   * Items on the stack, from top to bottom.
+   */
+  stack: StackItemView[];
   /**
    * Describes the status of the game, e.g. which phase & step the game is in
    */
@@ -293,6 +762,29 @@ export type GameView = {
    * Bottom user interaction options
    */
   bottomControls: GameControlView[];
+  /**
+   * Face-up plane or phenomenon card, if this game is using the Planechase
+   * variant.
+   */
+  activePlane: PlaneView | null;
+};
+/**
+ * Describes changes to the visual state of an ongoing game relative to the
+ * previous [GameView] sent to a client.
+ */
+export type GameViewDiff = {
+  /**
+   * New state of the game view.
+   *
+   * The [GameView::cards] field of this view is always empty. Changes to
+   * cards are described by [Self::card_updates] instead.
+   */
+  view: GameView;
+  /**
+   * Changes to cards since the previous view, in the order in which they
+   * should be applied.
+   */
+  cardUpdates: CardUpdate[];
 };
 export type GameViewState =
   | 'none'
@@ -300,15 +792,87 @@ export type GameViewState =
    * There is an ongoing combat phase
    */
   | 'combatActive';
+export type KnownCardView = {
+  card: ClientCardId;
+  name: string;
+  /**
+   * Description of the zone the card is in, e.g. "In hand"
+   */
+  location: string;
+  /**
+   * Description of when the card was revealed, e.g. "Revealed on turn 3"
+   */
+  revealed_on: string;
+};
+/**
+ * Cards in other players' hands & libraries which the viewer has seen
+ */
+export type KnownCardsPanel = { cards: KnownCardView[] };
+/**
+ * Identifies a language in which user interface text can be displayed.
+ */
+export type LanguageId =
+  | 'english'
+  | 'french'
+  | 'german'
+  | 'italian'
+  | 'japanese'
+  | 'korean'
+  | 'portuguese'
+  | 'simplifiedChinese'
+  | 'spanish';
 /**
  * Represents the visual state of the main menu
  */
+export type LifeChangeView = {
+  /**
+   * Describes whose life total changed, e.g. "Your life".
+   */
+  label: string;
+  previous: number;
+  current: number;
+};
 export type MainMenuView = {
   /**
    * Primary buttons to show
    */
   buttons: GameButtonView[];
 };
+/**
+ * Summary of a single finished game.
+ */
+export type MatchHistoryEntryView = {
+  deck: string;
+  opponentDeck: string;
+  opponent: string;
+  /**
+   * Outcome of the game, e.g. "Win".
+   */
+  result: string;
+  turns: number;
+  /**
+   * Length of the game in seconds, if known.
+   */
+  durationSeconds: number | null;
+};
+/**
+ * Aggregated results of the games a user has finished, displayed on the
+ * statistics screen.
+ */
+export type MatchStatisticsView = {
+  /**
+   * Results grouped by the deck the user played.
+   */
+  decks: WinRateView[];
+  /**
+   * Results grouped by the type of opponent the user faced.
+   */
+  opponents: WinRateView[];
+  /**
+   * Most recently finished games, newest first.
+   */
+  recentMatches: MatchHistoryEntryView[];
+};
 /**
  * Rendering options for a modal window which can be displayed on top of other
  * game content
@@ -317,6 +881,34 @@ export type ModalPanel = { title: string | null; on_close: unknown; data: PanelD
 /**
  * Represents the position of some object in the UI
  */
+export type MoveCardUpdate = {
+  /**
+   * Card to move
+   */
+  id: ClientCardId;
+  /**
+   * New position of this card
+   */
+  position: ObjectPosition;
+};
+export type MovedCardView = {
+  /**
+   * Card which moved, for highlighting.
+   */
+  card: ClientCardId;
+  /**
+   * Name of the card, or a placeholder if it is hidden from the viewer.
+   */
+  name: string;
+  /**
+   * Name of the zone the card moved from.
+   */
+  from: string;
+  /**
+   * Name of the zone the card moved to.
+   */
+  to: string;
+};
 export type ObjectPosition = {
   /**
    * Position category
@@ -331,10 +923,68 @@ export type ObjectPosition = {
    */
   sortingSubKey: number;
 };
+/**
+ * Latency of a single kind of operation, e.g. executing a game action.
+ */
+export type OperationTimingView = {
+  name: string;
+  /**
+   * Number of times this operation has been performed since recording
+   * was enabled.
+   */
+  count: number;
+  /**
+   * Median duration of recent operations, in milliseconds.
+   */
+  p50Millis: number;
+  /**
+   * 95th percentile duration of recent operations, in milliseconds.
+   */
+  p95Millis: number;
+};
 /**
  * Types of content which can appear in a modal panel
  */
-export type PanelData = { Debug: DebugPanel };
+export type PanelData =
+  | { Debug: DebugPanel }
+  | { RulesConsole: RulesConsolePanel }
+  | { Settings: SettingsPanel }
+  | { Campaign: CampaignPanel }
+  | { Profile: ProfilePanel }
+  | { ZoneBrowser: ZoneBrowserPanel }
+  | { KnownCards: KnownCardsPanel };
+/**
+ * Recent latency of rules engine operations, displayed in an overlay when
+ * the performance HUD option is enabled.
+ */
+export type PerformanceHudView = { timings: OperationTimingView[] };
+export type PlanarDieView = {
+  /**
+   * Player who rolled the planar die
+   */
+  player: DisplayPlayer;
+  /**
+   * Face the planar die landed on
+   */
+  result: string;
+};
+/**
+ * Represents the face-up card of the shared planar deck in a Planechase game.
+ */
+export type PlaneView = {
+  /**
+   * Identifies the plane or phenomenon card
+   */
+  card: ClientCardId;
+  /**
+   * Name of the card
+   */
+  name: string;
+  /**
+   * URL of the card image
+   */
+  image: string;
+};
 /**
  * Represents the visual state of a player in a game
  */
@@ -346,10 +996,38 @@ export type PlayerView = {
    * display layer we use floats for JavaScript compatibility.
    */
   life: number;
+  /**
+   * Total amount of life this player has gained during the current turn.
+   */
+  lifeGainedThisTurn: number;
+  /**
+   * Total amount of life this player has lost during the current turn,
+   * including life lost due to damage.
+   */
+  lifeLostThisTurn: number;
   /**
    * Can this player currently take a game action?
    */
   canAct: boolean;
+  /**
+   * Action to open a browser panel listing this player's graveyard.
+   */
+  browseGraveyard: unknown;
+  /**
+   * Action to open a browser panel listing this player's exiled cards.
+   */
+  browseExile: unknown;
+  /**
+   * Action to open a browser panel listing this player's library.
+   */
+  browseLibrary: unknown;
+  /**
+   * Action to choose this player in an entity choice prompt, e.g. as the
+   * target of a spell.
+   *
+   * None if this player is not currently a valid choice.
+   */
+  selectAction: unknown | null;
 };
 /**
  * Possible types of display positions
@@ -437,9 +1115,49 @@ export type Position =
    * Object is attached to the indicated card.
    */
   | { attachedToCard: ClientCardId };
+/**
+ * Display name and lifetime statistics for the current user
+ */
+export type ProfilePanel = {
+  display_name: string;
+  /**
+   * Input for changing the user's display name
+   */
+  input: TextInputView;
+  /**
+   * Descriptions of the user's game statistics
+   */
+  statistics: string[];
+};
 /**
  * Visual state of a revealed card face
  */
+/**
+ * Summary of changes to the game since the viewer last had the opportunity to
+ * act, displayed so they can see what happened in the meantime.
+ */
+/**
+ * Result of a coin flip, die roll, or clash, displayed to both players.
+ */
+export type RandomResultView =
+  | { coinFlip: CoinFlipView }
+  | { dieRoll: DieRollView }
+  | { planarDie: PlanarDieView }
+  | { clash: ClashView };
+export type RecentChangesView = {
+  /**
+   * Cards which moved between zones.
+   */
+  movedCards: MovedCardView[];
+  /**
+   * Players whose life total changed.
+   */
+  lifeChanges: LifeChangeView[];
+  /**
+   * Cards whose number of counters changed.
+   */
+  counterChanges: CounterChangeView[];
+};
 export type RevealedCardFace = {
   /**
    * Name of this face
@@ -453,13 +1171,18 @@ export type RevealedCardFace = {
    * Rules text_strings for this face, if any.
    */
   rulesText: string | null;
+  /**
+   * Rules text for this face split into styled runs, for display.
+   */
+  richRulesText: TextRun[];
 };
 export type RevealedCardStatus =
   | 'selected'
   | 'canSelect'
   | 'canPlay'
   | { attacking: string }
-  | { blocking: string };
+  | { blocking: string }
+  | { goaded: string };
 /**
  * Visual state of a revealed card
  */
@@ -485,6 +1208,12 @@ export type RevealedCardView = {
    */
   isAbility: boolean;
   isToken: boolean;
+  /**
+   * True if this is a user-defined card which does not exist in the Oracle
+   * card database. Custom cards have no card image, so their name should be
+   * displayed instead.
+   */
+  isCustom: boolean;
   /**
    * Action to take when this card is clicked, if any.
    */
@@ -504,7 +1233,42 @@ export type RevealedCardView = {
    * Visual style of this card, how the faces are displayed
    */
   layout: CardLayout;
+  /**
+   * Current chapter of this card if it is a Saga on the battlefield, i.e.
+   * the number of lore counters on it.
+   */
+  sagaChapter: number | null;
+  /**
+   * Current defense of this card if it is a battle on the battlefield, i.e.
+   * the number of defense counters on it.
+   */
+  battleDefense: number | null;
+  /**
+   * Names of the other cards which make up this permanent if it is a
+   * merged permanent, e.g. as a result of mutate, ordered from top to
+   * bottom.
+   */
+  mergedWith: string[];
+  /**
+   * True if this card is in exile "on an adventure", i.e. it was cast as
+   * an Adventure and may now be cast from exile.
+   */
+  onAnAdventure: boolean;
+  /**
+   * True if this card is in exile face down as a foretold card. Foretold
+   * cards are only revealed to their owner.
+   */
+  foretold: boolean;
+  /**
+   * Explanation of why the player can't currently play or attack with this
+   * card, displayed as a tooltip.
+   */
+  disabledReason: string | null;
 };
+/**
+ * Console for asking questions about the current game state
+ */
+export type RulesConsolePanel = { input: TextInputView; lines: string[] };
 /**
  * Top-level states the user interface can be in.
  *
@@ -512,6 +1276,70 @@ export type RevealedCardView = {
  */
 export type SceneIdentifier = 'loading' | 'mainMenu' | { game: GameId };
 export type SceneView = 'loading' | { gameView: GameView } | { mainMenuView: MainMenuView };
+/**
+ * Options controlling when the game stops to give the player priority
+ */
+export type SettingsPanel = { sections: SettingsSection[] };
+export type SettingsSection = { title: string; toggles: ToggleView[] };
+/**
+ * Semantic sound events which can be played by the client in response to
+ * changes in game state.
+ */
+export type SoundEffect =
+  /**
+   * A card was drawn from a library
+   */
+  | 'cardDrawn'
+  /**
+   * A spell was put onto the stack
+   */
+  | 'spellCast'
+  /**
+   * A creature was put into a graveyard from the battlefield
+   */
+  | 'creatureDies'
+  /**
+   * The game ended and this player won
+   */
+  | 'gameWon'
+  /**
+   * The game ended and this player lost
+   */
+  | 'gameLost';
+/**
+ * Describes a single spell or ability on the stack, so that players can
+ * evaluate how to respond to it.
+ */
+export type StackItemView = {
+  /**
+   * Card or ability this item is displayed as
+   */
+  card: ClientCardId;
+  /**
+   * Player who controls this item
+   */
+  controller: DisplayPlayer;
+  /**
+   * Name of the card which created this item
+   */
+  name: string;
+  /**
+   * True if this is an activated or triggered ability rather than a spell
+   */
+  isAbility: boolean;
+  /**
+   * Rules text of the card which created this item
+   */
+  summary: string;
+  /**
+   * Modes chosen for this item, if it is modal
+   */
+  modes: string[];
+  /**
+   * Describes each target chosen for this item
+   */
+  targets: string[];
+};
 /**
  * Whether a card is tapped or untapped.
  *
@@ -528,6 +1356,56 @@ export type TextInputView = {
    */
   key: FieldKey;
 };
+/**
+ * Data to render a checkbox which toggles a boolean field
+ */
+export type ToggleView = {
+  /**
+   * Unique identifier for this field
+   */
+  key: FieldKey;
+  /**
+   * Label to display next to the checkbox
+   */
+  label: string;
+  /**
+   * Current value of this field
+   */
+  value: boolean;
+  /**
+   * If true, this field cannot currently be changed
+   */
+  disabled: boolean;
+};
+/**
+ * A segment of rules text with a single visual style.
+ *
+ * Oracle text is converted into a sequence of runs so that the client can
+ * render e.g. mana symbols as icons instead of literal text like "{2}{U}".
+ */
+export type TextRun =
+  /**
+   * Plain rules text.
+   */
+  | { text: string }
+  /**
+   * A symbol which appeared between braces in oracle text, without the
+   * braces. For example "2", "U", "T", or "W/P".
+   */
+  | { symbol: string }
+  /**
+   * Parenthesized reminder text, including the parentheses.
+   */
+  | { reminder: string }
+  /**
+   * An ability word which has no rules meaning, such as "Landfall". The
+   * separating dash is not included.
+   */
+  | { abilityWord: string }
+  /**
+   * Start of a new paragraph.
+   */
+  | 'lineBreak';
 /**
  * Unique identifier for a user
  *
@@ -535,6 +1413,53 @@ export type TextInputView = {
  * A 'player' is a participate within a game who may or may not be a user.
  */
 export type UserId = string;
+/**
+ * Combined results for a group of games.
+ */
+export type WinRateView = {
+  name: string;
+  games: number;
+  wins: number;
+  losses: number;
+  draws: number;
+  /**
+   * Fraction of games in this group which were won, between 0 and 1.
+   */
+  winRate: number;
+};
+export type ZoneBrowserCardView = {
+  /**
+   * Card to show details for, or None if this card is hidden from the
+   * viewer
+   */
+  card: ClientCardId | null;
+  name: string;
+};
+/**
+ * Ordered list of the cards in a zone, one page at a time
+ */
+export type ZoneBrowserPanel = {
+  /**
+   * Cards on the current page, top card first
+   */
+  cards: ZoneBrowserCardView[];
+  /**
+   * Description of the current page, e.g. "Cards 1-20 of 45"
+   */
+  page_description: string;
+  previous: GameButtonView | null;
+  next: GameButtonView | null;
+};
+export type ZoneMoveAnimation = {
+  /**
+   * Card which moved
+   */
+  card: ClientCardId;
+  /**
+   * Position the card moved from
+   */
+  from: Position;
+};
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,37 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode } from 'react';
+import { CampaignPanel } from '../generated_types';
+import { GameButton } from '../core/GameButton';
+
+export function CampaignContent({ data }: { data: CampaignPanel }): ReactNode {
+  const stages = data.stages.map((stage, i) => (
+    <div key={i} className={stage.status === 'Locked' ? 'mb-3 opacity-50' : 'mb-3'}>
+      <div className="font-bold text-sm">
+        {i + 1}. {stage.name}
+        {stage.status === 'Completed' && <span className="ml-2 text-xs">✓</span>}
+      </div>
+      <div className="text-xs italic">{stage.description}</div>
+      {stage.rules.map((rule, j) => (
+        <div key={j} className="text-xs">
+          • {rule}
+        </div>
+      ))}
+      {stage.has_reward && <div className="text-xs opacity-75">Reward: a new deck</div>}
+      {stage.play != null && <GameButton className="mt-1" button={stage.play} />}
+    </div>
+  ));
+  return <div>{stages}</div>;
+}
@@ -15,10 +15,22 @@
 import { ReactNode } from 'react';
 import { DebugPanel } from '../generated_types';
 import { GameButton } from '../core/GameButton';
+import { TextInput } from '../core/TextInput';
 
 export function DebugPanelContent({ data }: { data: DebugPanel }): ReactNode {
   const buttons = data.buttons.map((button, i) => (
     <GameButton className="m-2" button={button} key={i} />
   ));
-  return <div>{buttons}</div>;
+  const lines = data.lines.map((line, i) => (
+    <div key={i} className="text-sm">
+      {line}
+    </div>
+  ));
+  return (
+    <div>
+      <div>{buttons}</div>
+      <TextInput className="mb-2" input={data.input} submitOnEnter={true} />
+      {lines}
+    </div>
+  );
 }
@@ -0,0 +1,37 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+import { ReactNode, useContext } from 'react';
+import { KnownCardsPanel } from '../generated_types';
+import { GlobalContext } from '../App';
+import { fetchCardDetails } from '../server';
+
+export function KnownCardsContent({ data }: { data: KnownCardsPanel }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  if (data.cards.length === 0) {
+    return <div className="text-sm opacity-50">No cards have been revealed to you</div>;
+  }
+  const cards = data.cards.map((view, i) => (
+    <div
+      key={i}
+      className="mb-1 cursor-pointer hover:bg-white/10"
+      onClick={() => fetchCardDetails(clientData, view.card)}
+    >
+      <div className="text-sm">{view.name}</div>
+      <div className="text-xs opacity-75">
+        {view.location} · {view.revealed_on}
+      </div>
+    </div>
+  ));
+  return <div className="max-h-96 overflow-y-auto">{cards}</div>;
+}
@@ -0,0 +1,32 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode } from 'react';
+import { ProfilePanel } from '../generated_types';
+import { TextInput } from '../core/TextInput';
+
+export function ProfileContent({ data }: { data: ProfilePanel }): ReactNode {
+  const statistics = data.statistics.map((line, i) => (
+    <div key={i} className="text-sm">
+      {line}
+    </div>
+  ));
+  return (
+    <div>
+      <div className="font-bold text-sm mb-1">{data.display_name}</div>
+      <TextInput className="mb-2" input={data.input} />
+      {statistics}
+    </div>
+  );
+}
@@ -0,0 +1,42 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { ReactNode, useContext } from 'react';
+import { Checkbox } from '@nextui-org/react';
+import { GlobalContext } from '../App';
+import { SettingsPanel } from '../generated_types';
+import { updateField } from '../server';
+
+export function SettingsContent({ data }: { data: SettingsPanel }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  const sections = data.sections.map((section) => (
+    <div key={section.title} className="mb-3">
+      <div className="font-bold text-sm mb-1">{section.title}</div>
+      <div className="flex flex-wrap gap-2">
+        {section.toggles.map((toggle) => (
+          <Checkbox
+            key={JSON.stringify(toggle.key)}
+            size="sm"
+            isSelected={toggle.value}
+            isDisabled={toggle.disabled}
+            onValueChange={(value) => updateField(clientData, toggle.key, { bool: value })}
+          >
+            {toggle.label}
+          </Checkbox>
+        ))}
+      </div>
+    </div>
+  ));
+  return <div>{sections}</div>;
+}
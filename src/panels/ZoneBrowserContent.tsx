@@ -0,0 +1,48 @@
+// Copyright © spellclash 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+import { ReactNode, useContext } from 'react';
+import { ZoneBrowserPanel } from '../generated_types';
+import { GameButton } from '../core/GameButton';
+import { GlobalContext } from '../App';
+import { fetchCardDetails } from '../server';
+
+export function ZoneBrowserContent({ data }: { data: ZoneBrowserPanel }): ReactNode {
+  const clientData = useContext(GlobalContext);
+  const cards = data.cards.map((view, i) => {
+    const card = view.card;
+    return card == null ? (
+      <div key={i} className="text-sm opacity-50">
+        {view.name}
+      </div>
+    ) : (
+      <div
+        key={i}
+        className="text-sm cursor-pointer hover:bg-white/10"
+        onClick={() => fetchCardDetails(clientData, card)}
+      >
+        {view.name}
+      </div>
+    );
+  });
+  return (
+    <div>
+      <div className="text-xs opacity-75 mb-1">{data.page_description}</div>
+      <div className="max-h-96 overflow-y-auto">{cards}</div>
+      <div className="flex flex-row">
+        {data.previous != null && <GameButton className="m-1" button={data.previous} />}
+        {data.next != null && <GameButton className="m-1" button={data.next} />}
+      </div>
+    </div>
+  );
+}
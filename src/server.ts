@@ -43,6 +43,29 @@ export async function updateField(
   await commands.updateField(clientData, key, value);
 }
 
+export async function sendChatMessage(clientData: ClientData, message: string): Promise<void> {
+  await commands.sendChatMessage(clientData, message);
+}
+
+export async function fetchCardDetails(
+  clientData: ClientData,
+  cardId: ClientCardId,
+): Promise<void> {
+  await commands.fetchCardDetails(clientData, cardId);
+}
+
+export async function searchCards(clientData: ClientData, query: string): Promise<void> {
+  await commands.searchCards(clientData, query);
+}
+
+export async function fetchDeckCatalog(clientData: ClientData): Promise<void> {
+  await commands.fetchDeckCatalog(clientData);
+}
+
+export async function fetchMatchStatistics(clientData: ClientData): Promise<void> {
+  await commands.fetchMatchStatistics(clientData);
+}
+
 export async function dragCard(
   clientData: ClientData,
   cardId: ClientCardId,